@@ -0,0 +1,21 @@
+"""Usage, cost, and history queries from caut (Coding Agent Usage Tracker)."""
+
+from ._caut import (
+    CautError,
+    __version__,
+    cost,
+    cost_records,
+    history_records,
+    providers,
+    usage,
+)
+
+__all__ = [
+    "CautError",
+    "__version__",
+    "cost",
+    "cost_records",
+    "history_records",
+    "providers",
+    "usage",
+]
@@ -0,0 +1,21 @@
+from typing import Any, Optional
+
+__version__: str
+
+class CautError(Exception): ...
+
+def usage(
+    provider: str = "both",
+    source: str = "auto",
+    timeout: Optional[float] = None,
+    status: bool = False,
+) -> list[dict[str, Any]]: ...
+def cost(provider: str = "both") -> list[dict[str, Any]]: ...
+def cost_records(provider: str = "both") -> list[dict[str, Any]]: ...
+def history_records(
+    provider: Optional[str] = None,
+    days: int = 30,
+    account: Optional[str] = None,
+    limit: Optional[int] = None,
+) -> list[dict[str, Any]]: ...
+def providers() -> list[str]: ...
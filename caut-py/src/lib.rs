@@ -0,0 +1,230 @@
+//! Python bindings for caut.
+//!
+//! Exposes the usage, cost, and history queries behind `caut usage`,
+//! `caut cost`, and `caut history` as functions returning plain Python
+//! objects. `usage` and `cost` return the same payloads as `--json`; the
+//! `*_records` functions return flat rows (one dict per day or snapshot) that
+//! load directly into `pandas.DataFrame`.
+//!
+//! Queries run on a shared Tokio runtime with the GIL released, so other
+//! Python threads keep running while providers are fetched.
+
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use caut::core::cost_scanner::CostScanner;
+use caut::core::fetch_plan::SourceMode;
+use caut::core::models::CostPayload;
+use caut::core::pipeline::{UsageRequest, run_usage};
+use caut::core::provider::{Provider, ProviderSelection};
+use caut::storage::history::{SnapshotFilter, StoredSnapshot};
+use caut::storage::{AppPaths, HistoryStore};
+use chrono::Utc;
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use serde_json::{Value, json};
+
+create_exception!(
+    _caut,
+    CautError,
+    PyException,
+    "Raised when caut cannot complete a query."
+);
+
+static RUNTIME: LazyLock<tokio::runtime::Runtime> = LazyLock::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("tokio runtime")
+});
+
+fn caut_error(e: &caut::CautError) -> PyErr {
+    CautError::new_err(e.to_string())
+}
+
+/// Parse a provider selection ("claude", "both", "all").
+fn select(provider: &str) -> PyResult<Vec<Provider>> {
+    ProviderSelection::from_arg(provider)
+        .map(|selection| selection.providers())
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+fn to_python<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    Ok(pythonize::pythonize(py, value)?)
+}
+
+/// Current usage for each provider, as in `caut usage --json`.
+///
+/// Providers that fail are returned as `{"provider": ..., "error": ...}`
+/// rather than raising, so one broken login does not hide the others.
+#[pyfunction]
+#[pyo3(signature = (provider = "both", source = "auto", timeout = None, status = false))]
+fn usage<'py>(
+    py: Python<'py>,
+    provider: &str,
+    source: &str,
+    timeout: Option<f64>,
+    status: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let mode = SourceMode::from_arg(source)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown source mode: {source}")))?;
+    let timeout_override = timeout
+        .map(Duration::try_from_secs_f64)
+        .transpose()
+        .map_err(|e| PyValueError::new_err(format!("Invalid timeout: {e}")))?;
+    let request = UsageRequest {
+        providers: select(provider)?,
+        mode,
+        timeout_override,
+        include_status: status,
+        record_history: false,
+        verify: false,
+    };
+
+    let results = py.detach(|| RUNTIME.block_on(run_usage(&request)));
+    let rows: Vec<Value> = results
+        .iter()
+        .map(|result| match &result.outcome {
+            Ok(payload) => serde_json::to_value(payload).unwrap_or(Value::Null),
+            Err(e) => json!({
+                "provider": result.provider.cli_name(),
+                "error": e.to_string(),
+            }),
+        })
+        .collect();
+    to_python(py, &Value::from(rows))
+}
+
+fn scan_costs(py: Python<'_>, provider: &str) -> PyResult<Vec<CostPayload>> {
+    let providers: Vec<Provider> = select(provider)?
+        .into_iter()
+        .filter(|p| p.supports_cost_scan())
+        .collect();
+    py.detach(|| {
+        RUNTIME.block_on(async {
+            let scanner = CostScanner::new();
+            let mut payloads = Vec::with_capacity(providers.len());
+            for provider in providers {
+                payloads.push(scanner.scan(provider, false).await?);
+            }
+            Ok::<_, caut::CautError>(payloads)
+        })
+    })
+    .map_err(|e| caut_error(&e))
+}
+
+/// Local cost scan for each provider that supports it, as in
+/// `caut cost --json`.
+///
+/// Raises `CautError` if a provider's local logs cannot be read.
+#[pyfunction]
+#[pyo3(signature = (provider = "both"))]
+fn cost<'py>(py: Python<'py>, provider: &str) -> PyResult<Bound<'py, PyAny>> {
+    let payloads = scan_costs(py, provider)?;
+    let value = serde_json::to_value(&payloads).map_err(|e| CautError::new_err(e.to_string()))?;
+    to_python(py, &value)
+}
+
+/// Daily cost rows: one dict per provider and day.
+///
+/// Raises `CautError` if a provider's local logs cannot be read.
+#[pyfunction]
+#[pyo3(signature = (provider = "both"))]
+fn cost_records<'py>(py: Python<'py>, provider: &str) -> PyResult<Bound<'py, PyAny>> {
+    let rows: Vec<Value> = scan_costs(py, provider)?
+        .iter()
+        .flat_map(|payload| {
+            payload.daily.iter().map(|day| {
+                json!({
+                    "provider": payload.provider,
+                    "date": day.date,
+                    "input_tokens": day.input_tokens,
+                    "output_tokens": day.output_tokens,
+                    "cache_read_tokens": day.cache_read_tokens,
+                    "cache_creation_tokens": day.cache_creation_tokens,
+                    "total_tokens": day.total_tokens,
+                    "total_cost_usd": day.total_cost,
+                    "models": day.models_used.as_ref().map(|models| models.join(",")),
+                })
+            })
+        })
+        .collect();
+    to_python(py, &Value::from(rows))
+}
+
+fn snapshot_record(snapshot: &StoredSnapshot) -> Value {
+    json!({
+        "provider": snapshot.provider.cli_name(),
+        "fetched_at": snapshot.fetched_at.to_rfc3339(),
+        "source": snapshot.source,
+        "primary_used_pct": snapshot.primary_used_pct,
+        "primary_window_minutes": snapshot.primary_window_minutes,
+        "primary_resets_at": snapshot.primary_resets_at.map(|t| t.to_rfc3339()),
+        "secondary_used_pct": snapshot.secondary_used_pct,
+        "secondary_window_minutes": snapshot.secondary_window_minutes,
+        "secondary_resets_at": snapshot.secondary_resets_at.map(|t| t.to_rfc3339()),
+        "tertiary_used_pct": snapshot.tertiary_used_pct,
+        "cost_today_usd": snapshot.cost_today_usd,
+        "cost_mtd_usd": snapshot.cost_mtd_usd,
+        "credits_remaining": snapshot.credits_remaining,
+        "account_email": snapshot.account_email,
+        "account_org": snapshot.account_org,
+    })
+}
+
+/// Recorded usage snapshots, newest first: one dict per snapshot.
+///
+/// Raises `CautError` if the history database cannot be opened or queried.
+#[pyfunction]
+#[pyo3(signature = (provider = None, days = 30, account = None, limit = None))]
+fn history_records<'py>(
+    py: Python<'py>,
+    provider: Option<&str>,
+    days: u32,
+    account: Option<String>,
+    limit: Option<usize>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let provider = provider
+        .map(Provider::from_cli_name)
+        .transpose()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let filter = SnapshotFilter {
+        provider,
+        account,
+        from: Some(Utc::now() - chrono::Duration::days(i64::from(days))),
+        limit,
+        ..SnapshotFilter::default()
+    };
+
+    let snapshots = py
+        .detach(|| {
+            HistoryStore::open(&AppPaths::new().history_db_file())
+                .and_then(|store| store.query_snapshots(&filter))
+        })
+        .map_err(|e| caut_error(&e))?;
+    let rows: Vec<Value> = snapshots.iter().map(snapshot_record).collect();
+    to_python(py, &Value::from(rows))
+}
+
+/// CLI names of every supported provider.
+#[pyfunction]
+fn providers() -> Vec<&'static str> {
+    Provider::ALL
+        .iter()
+        .copied()
+        .map(Provider::cli_name)
+        .collect()
+}
+
+#[pymodule]
+fn _caut(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add("CautError", m.py().get_type::<CautError>())?;
+    m.add_function(wrap_pyfunction!(usage, m)?)?;
+    m.add_function(wrap_pyfunction!(cost, m)?)?;
+    m.add_function(wrap_pyfunction!(cost_records, m)?)?;
+    m.add_function(wrap_pyfunction!(history_records, m)?)?;
+    m.add_function(wrap_pyfunction!(providers, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,6 @@
+//! WebAssembly package for caut.
+//!
+//! Re-exports the `wasm` feature of the main crate so `wasm-pack` can build
+//! it as an npm package. See `caut::wasm` for the exported functions.
+
+pub use caut::wasm::*;
@@ -0,0 +1,9 @@
+-- Migration 004: History filter indexes
+--
+-- Supports `caut history list` filtering by account. Provider and time range
+-- filters are already covered by idx_snapshots_provider_time.
+
+CREATE INDEX IF NOT EXISTS idx_snapshots_email_time
+    ON usage_snapshots(account_email COLLATE NOCASE, fetched_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_accounts_label ON accounts(label COLLATE NOCASE);
@@ -0,0 +1,30 @@
+-- Migration 005: Plan limit tracking
+--
+-- Providers change plan limits and window lengths without notice. The last
+-- observed definition of each provider window is kept in plan_limits, and
+-- every detected change is appended to plan_limit_changes.
+
+CREATE TABLE IF NOT EXISTS plan_limits (
+    provider TEXT NOT NULL,
+    window_slot TEXT NOT NULL,        -- primary, secondary, tertiary
+    window_minutes INTEGER,
+    limit_value REAL,                 -- Allowance parsed from the window description
+    limit_unit TEXT,                  -- e.g. "premium requests"
+    observed_at TEXT NOT NULL,        -- ISO8601 timestamp of the last observation
+    PRIMARY KEY (provider, window_slot)
+);
+
+CREATE TABLE IF NOT EXISTS plan_limit_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    provider TEXT NOT NULL,
+    window_slot TEXT NOT NULL,
+    detected_at TEXT NOT NULL,        -- ISO8601 timestamp of the first snapshot with the new limit
+    old_window_minutes INTEGER,
+    new_window_minutes INTEGER,
+    old_limit_value REAL,
+    new_limit_value REAL,
+    limit_unit TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_plan_limit_changes_time
+    ON plan_limit_changes(detected_at DESC);
@@ -0,0 +1,19 @@
+-- Migration 006: Local model throughput
+--
+-- Local model servers (Ollama) have no quota or bill, so their usage is
+-- tracked as generation throughput: each usage fetch appends one sample
+-- measured on the model that was loaded.
+
+CREATE TABLE IF NOT EXISTS local_model_stats (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    provider TEXT NOT NULL,
+    model TEXT NOT NULL,
+    fetched_at TEXT NOT NULL,         -- ISO8601 timestamp of the measurement
+    prompt_tokens INTEGER NOT NULL,   -- Prompt tokens evaluated by the probe
+    eval_tokens INTEGER NOT NULL,     -- Tokens generated by the probe
+    prompt_tokens_per_sec REAL,
+    eval_tokens_per_sec REAL
+);
+
+CREATE INDEX IF NOT EXISTS idx_local_model_stats_provider_time
+    ON local_model_stats(provider, fetched_at DESC);
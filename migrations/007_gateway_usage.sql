@@ -0,0 +1,21 @@
+-- Migration 007: Gateway usage imports
+--
+-- Requests logged by an LLM gateway (Helicone, or anything serving the
+-- OpenAI usage API) are imported as daily per-model rollups. Re-importing a
+-- day replaces its rows, so imports can be repeated safely.
+
+CREATE TABLE IF NOT EXISTS gateway_usage (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    gateway TEXT NOT NULL,            -- Import source, e.g. 'helicone'
+    day TEXT NOT NULL,                -- Calendar date of the requests (YYYY-MM-DD)
+    model TEXT NOT NULL,
+    requests INTEGER NOT NULL,
+    input_tokens INTEGER NOT NULL,
+    output_tokens INTEGER NOT NULL,
+    cost_usd REAL NOT NULL,
+    imported_at TEXT NOT NULL,        -- ISO8601 timestamp of the import
+    UNIQUE (gateway, day, model)
+);
+
+CREATE INDEX IF NOT EXISTS idx_gateway_usage_day
+    ON gateway_usage(day DESC);
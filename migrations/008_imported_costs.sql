@@ -0,0 +1,24 @@
+-- Migration 008: Cost history imported from other trackers
+--
+-- Daily per-model Claude/Codex costs taken over from ccusage or CodexBar, so
+-- switching to caut keeps earlier history. Both tools read the same local
+-- session logs, so an import replaces every row of each provider/day it
+-- covers: overlapping imports never count a day twice.
+
+CREATE TABLE IF NOT EXISTS imported_costs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    provider TEXT NOT NULL,
+    day TEXT NOT NULL,                -- Calendar date of the usage (YYYY-MM-DD)
+    model TEXT NOT NULL,              -- Empty when the source has no model breakdown
+    input_tokens INTEGER,
+    output_tokens INTEGER,
+    cache_read_tokens INTEGER,
+    cache_creation_tokens INTEGER,
+    cost_usd REAL NOT NULL,
+    source TEXT NOT NULL,             -- 'ccusage' or 'codexbar'
+    imported_at TEXT NOT NULL,        -- ISO8601 timestamp of the import
+    UNIQUE (provider, day, model)
+);
+
+CREATE INDEX IF NOT EXISTS idx_imported_costs_day
+    ON imported_costs(day DESC);
@@ -0,0 +1,15 @@
+-- Migration 009: Failed usage fetches
+--
+-- Snapshots record only successful fetches. Failures are recorded here so a
+-- provider's failure rate can be computed over any window. Providers that
+-- are not set up on the machine are not recorded: they never fetch at all.
+
+CREATE TABLE IF NOT EXISTS fetch_failures (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    provider TEXT NOT NULL,
+    failed_at TEXT NOT NULL,          -- ISO8601 timestamp of the attempt
+    error_code TEXT NOT NULL          -- caut error code, e.g. 'CAUT-N001'
+);
+
+CREATE INDEX IF NOT EXISTS idx_fetch_failures_provider_time
+    ON fetch_failures(provider, failed_at DESC);
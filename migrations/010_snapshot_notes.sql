@@ -0,0 +1,11 @@
+-- Migration 010: Snapshot notes
+--
+-- `caut snapshot` records explicit captures with trigger_type 'manual' and an
+-- optional free-form note. Routine captures from `caut usage` are 'periodic';
+-- until now they fell through to the column default of 'manual', so existing
+-- rows without an account (which only `caut usage` writes) are relabelled.
+
+ALTER TABLE usage_snapshots ADD COLUMN note TEXT;
+
+UPDATE usage_snapshots SET trigger_type = 'periodic'
+    WHERE trigger_type = 'manual' AND account_id IS NULL;
@@ -0,0 +1,100 @@
+//! Accounts command implementation.
+//!
+//! `caut accounts save` remembers the credentials a provider is signed in
+//! with under a name, and `caut accounts switch` swaps them back in (see
+//! [`crate::core::account_switch`]). Switches are recorded in the history
+//! database's switch log.
+
+use crate::cli::args::{AccountsCommand, AccountsSaveArgs, AccountsSwitchArgs, OutputFormat};
+use crate::core::account_switch;
+use crate::core::models::RobotOutput;
+use crate::core::provider::Provider;
+use crate::error::Result;
+use crate::render::robot;
+use crate::storage::{AppPaths, HistoryStore};
+
+/// Execute accounts commands.
+///
+/// # Errors
+/// Returns an error if the provider is unknown or cannot be switched, the
+/// account is not saved, the history database or keyring cannot be used, or
+/// a credential swap fails.
+pub fn execute(cmd: &AccountsCommand, format: OutputFormat, pretty: bool) -> Result<()> {
+    let store = HistoryStore::open(&AppPaths::new().history_db_file())?;
+    match cmd {
+        AccountsCommand::Save(args) => execute_save(args, &store, format, pretty),
+        AccountsCommand::Switch(args) => execute_switch(args, &store, format, pretty),
+    }
+}
+
+fn execute_save(
+    args: &AccountsSaveArgs,
+    store: &HistoryStore,
+    format: OutputFormat,
+    pretty: bool,
+) -> Result<()> {
+    let provider = Provider::from_cli_name(&args.provider)?;
+    let account = account_switch::save(&store.multi_account(), provider, &args.account)?;
+    if format == OutputFormat::Json {
+        return robot::print_json(
+            &RobotOutput::new(
+                "accounts",
+                serde_json::json!({
+                    "provider": provider.cli_name(),
+                    "saved": args.account,
+                    "id": account.id,
+                }),
+            ),
+            pretty,
+        );
+    }
+    println!(
+        "Saved the signed-in {} credentials as '{}'.",
+        provider.display_name(),
+        args.account
+    );
+    Ok(())
+}
+
+fn execute_switch(
+    args: &AccountsSwitchArgs,
+    store: &HistoryStore,
+    format: OutputFormat,
+    pretty: bool,
+) -> Result<()> {
+    let provider = Provider::from_cli_name(&args.provider)?;
+    let db = store.multi_account();
+    let outcome = match &args.account {
+        Some(name) => account_switch::switch(&db, provider, name)?,
+        None => account_switch::rollback(&db, provider)?,
+    };
+    if format == OutputFormat::Json {
+        return robot::print_json(&RobotOutput::new("accounts", &outcome), pretty);
+    }
+    if !outcome.changed {
+        println!(
+            "{} is already signed in as '{}'.",
+            provider.display_name(),
+            outcome.to
+        );
+        return Ok(());
+    }
+    let verb = if outcome.rollback {
+        "Rolled back"
+    } else {
+        "Switched"
+    };
+    match &outcome.from {
+        Some(from) => println!(
+            "{verb} {} from '{from}' to '{}'.",
+            provider.display_name(),
+            outcome.to
+        ),
+        None => println!("{verb} {} to '{}'.", provider.display_name(), outcome.to),
+    }
+    println!(
+        "Restart running {} sessions to pick up the change.",
+        provider.display_name()
+    );
+    Ok(())
+}
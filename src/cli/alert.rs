@@ -0,0 +1,153 @@
+//! Alert command implementation.
+//!
+//! Edits the rules in `alerts.toml` (see [`crate::core::alerts`]). Rules are
+//! evaluated by [`run_alerts`] after every usage fetch.
+
+use crate::cli::args::{AlertAddArgs, AlertCommand, OutputFormat};
+use crate::core::alerts::{self, AlertChannel, AlertMetric, AlertRule, AlertState, AlertsFile};
+use crate::core::fake_providers;
+use crate::core::models::{ProviderPayload, RobotOutput};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::render::robot;
+use crate::storage::AppPaths;
+
+/// Execute alert commands.
+///
+/// # Errors
+/// Returns an error if an argument is invalid, the alerts file cannot be
+/// read or written, the id is unknown, or output serialization fails.
+pub fn execute(cmd: &AlertCommand, format: OutputFormat, pretty: bool) -> Result<()> {
+    let path = AppPaths::new().alerts_file();
+    let mut file = AlertsFile::load(&path)?;
+    match cmd {
+        AlertCommand::Add(args) => {
+            let rule = add(&mut file, args)?;
+            file.save(&path)?;
+            if format == OutputFormat::Json {
+                return robot::print_json(&RobotOutput::new("alert", [rule]), pretty);
+            }
+            println!(
+                "Added alert {}: {} {} at {:.0}% -> {}",
+                rule.id,
+                rule.provider_label(),
+                rule.metric.label(),
+                rule.threshold,
+                rule.channel
+            );
+        }
+        AlertCommand::List => {
+            if format == OutputFormat::Json {
+                return robot::print_json(&RobotOutput::new("alert", &file.alerts), pretty);
+            }
+            if file.alerts.is_empty() {
+                println!(
+                    "No alerts. Add one with `caut alert add --threshold 80 --channel desktop`."
+                );
+            }
+            for rule in &file.alerts {
+                println!(
+                    "{:>3}  {:<10} {:<9} {:>4.0}%  {}",
+                    rule.id,
+                    rule.provider_label(),
+                    rule.metric.label(),
+                    rule.threshold,
+                    rule.channel
+                );
+            }
+        }
+        AlertCommand::Remove { id } => {
+            if !file.remove(*id) {
+                return Err(CautError::Config(format!("No alert with id {id}")));
+            }
+            file.save(&path)?;
+            if format == OutputFormat::Json {
+                return robot::print_json(&RobotOutput::new("alert", &file.alerts), pretty);
+            }
+            println!("Removed alert {id}.");
+        }
+    }
+    Ok(())
+}
+
+fn add(file: &mut AlertsFile, args: &AlertAddArgs) -> Result<AlertRule> {
+    let provider = args
+        .provider
+        .as_deref()
+        .map(|name| Provider::from_cli_name(name).map(|p| p.cli_name().to_string()))
+        .transpose()?;
+    let metric = AlertMetric::from_arg(&args.metric).ok_or_else(|| {
+        CautError::Config(format!(
+            "Invalid metric \"{}\". Valid metrics: primary, secondary, tertiary",
+            args.metric
+        ))
+    })?;
+    if !(0.0..=100.0).contains(&args.threshold) {
+        return Err(CautError::Config(format!(
+            "Invalid threshold {}: expected a percentage from 0 to 100",
+            args.threshold
+        )));
+    }
+    let channel = AlertChannel::from_arg(&args.channel)?;
+    Ok(file.add(provider, metric, args.threshold, channel))
+}
+
+/// Evaluate the alert rules against freshly fetched usage and send any that
+/// fired.
+///
+/// Alerts never fail the fetch: a broken alerts file, state file, or channel
+/// is logged and skipped.
+pub async fn run_alerts(payloads: &[ProviderPayload]) {
+    if fake_providers::is_enabled() || payloads.is_empty() {
+        return;
+    }
+    let paths = AppPaths::new();
+    let file = match AlertsFile::load(&paths.alerts_file()) {
+        Ok(file) if file.alerts.is_empty() => return,
+        Ok(file) => file,
+        Err(e) => {
+            tracing::warn!(error = %e, "Skipping alerts: alerts file did not load");
+            return;
+        }
+    };
+
+    let state_path = paths.alert_state_file();
+    let mut state = AlertState::load(&state_path);
+    let fired = alerts::evaluate(&file.alerts, payloads, &mut state, chrono::Utc::now());
+    if let Err(e) = state.save(&state_path) {
+        tracing::warn!(error = %e, "Failed to save alert state");
+    }
+    for alert in &fired {
+        tracing::info!("{}", alert.message());
+        if let Err(e) = alerts::deliver(alert).await {
+            tracing::warn!(alert = alert.id, channel = %alert.channel, error = %e, "Alert delivery failed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add_args(provider: Option<&str>, metric: &str, threshold: f64) -> AlertAddArgs {
+        AlertAddArgs {
+            provider: provider.map(str::to_string),
+            metric: metric.to_string(),
+            threshold,
+            channel: "desktop".to_string(),
+        }
+    }
+
+    #[test]
+    fn add_validates_and_normalizes_arguments() {
+        let mut file = AlertsFile::default();
+        let rule = add(&mut file, &add_args(Some("Claude"), "Secondary", 75.0)).unwrap();
+        assert_eq!(rule.provider.as_deref(), Some("claude"));
+        assert_eq!(rule.metric, AlertMetric::Secondary);
+
+        assert!(add(&mut file, &add_args(Some("nope"), "primary", 75.0)).is_err());
+        assert!(add(&mut file, &add_args(None, "weekly", 75.0)).is_err());
+        assert!(add(&mut file, &add_args(None, "primary", 150.0)).is_err());
+        assert_eq!(file.alerts.len(), 1);
+    }
+}
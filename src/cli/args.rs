@@ -3,7 +3,16 @@
 //! Matches `CodexBar` CLI semantics.
 //! See `EXISTING_CODEXBAR_STRUCTURE.md` section 2.
 
-use clap::{Parser, Subcommand, ValueEnum};
+use std::ffi::OsString;
+use std::str::FromStr;
+
+use clap::error::ErrorKind;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+
+use crate::core::usage_filter::{UsageField, UsageFilter};
+use crate::error::CautError;
+use crate::util::format::parse_size_bytes;
+use crate::util::time::parse_duration_secs;
 
 /// Coding Agent Usage Tracker - Monitor LLM provider usage.
 #[derive(Parser, Debug)]
@@ -32,6 +41,10 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_color: bool,
 
+    /// Config file path or remote config URL (overrides `CAUT_CONFIG`)
+    #[arg(long, value_name = "PATH|URL", global = true)]
+    pub config: Option<String>,
+
     /// Log level
     #[arg(long, value_name = "LEVEL", global = true)]
     pub log_level: Option<String>,
@@ -47,9 +60,32 @@ pub struct Cli {
     /// Print rich output diagnostics and exit
     #[arg(long, global = true)]
     pub debug_rich: bool,
+
+    /// Write output to this file, replacing it only on success ("-" for stdout)
+    #[arg(long, value_name = "PATH", global = true)]
+    pub output: Option<std::path::PathBuf>,
+
+    /// Also search this directory for session logs, e.g. a devcontainer home (repeatable)
+    #[arg(long, value_name = "PATH", global = true)]
+    pub log_root: Vec<std::path::PathBuf>,
 }
 
 impl Cli {
+    /// Parse arguments (program name first), also returning the subcommand
+    /// path that was run, e.g. `history export`.
+    ///
+    /// # Errors
+    /// Returns clap's error for invalid arguments, `--help`, and `--version`.
+    pub fn try_parse_with_path<I, T>(args: I) -> Result<(Self, String), clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let matches = Self::command().try_get_matches_from(args)?;
+        let cli = Self::from_arg_matches(&matches)?;
+        Ok((cli, command_path(&matches)))
+    }
+
     /// Resolve the effective output format.
     #[must_use]
     pub const fn effective_format(&self) -> OutputFormat {
@@ -61,6 +97,55 @@ impl Cli {
     }
 }
 
+fn command_path(matches: &ArgMatches) -> String {
+    let mut path = Vec::new();
+    let mut current = matches;
+    while let Some((name, sub)) = current.subcommand() {
+        path.push(name);
+        current = sub;
+    }
+    path.join(" ")
+}
+
+/// Whether raw arguments ask for JSON output (`--json`, `--format json`).
+///
+/// Used to report argument errors, which happen before the arguments are
+/// parsed, in the format the caller expects.
+#[must_use]
+pub fn args_request_json<T: AsRef<str>>(args: &[T]) -> bool {
+    args.iter()
+        .map(AsRef::as_ref)
+        .take_while(|arg| *arg != "--")
+        .zip(args.iter().map(AsRef::as_ref).skip(1).chain([""]))
+        .any(|(arg, next)| {
+            arg == "--json" || arg == "--format=json" || (arg == "--format" && next == "json")
+        })
+}
+
+/// Whether a clap error is really a request for help or version output.
+#[must_use]
+pub fn is_informational(error: &clap::Error) -> bool {
+    matches!(
+        error.kind(),
+        ErrorKind::DisplayHelp
+            | ErrorKind::DisplayVersion
+            | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand
+    )
+}
+
+/// An argument error as a caut error, with clap's first line as the message.
+#[must_use]
+pub fn usage_error(error: &clap::Error) -> CautError {
+    let rendered = error.render().to_string();
+    let message = rendered
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches("error: ")
+        .to_string();
+    CautError::Config(message)
+}
+
 /// Available commands.
 #[derive(Subcommand, Debug)]
 pub enum Commands {
@@ -73,20 +158,47 @@ pub enum Commands {
     /// Show session cost attribution
     Session(SessionArgs),
 
+    /// Browse recent Claude and Codex sessions turn by turn
+    #[command(subcommand)]
+    Sessions(SessionsCommand),
+
+    /// Live view of running Claude and Codex sessions: tokens/min, cost, model
+    Top(TopArgs),
+
     /// Manage usage history and retention
     #[command(subcommand)]
     History(HistoryCommand),
 
+    /// Preview and delete old history by age or database size
+    Prune(PruneArgs),
+
+    /// Fetch every provider once and record the result to history
+    Snapshot(SnapshotArgs),
+
     /// Manage token accounts
     #[command(subcommand)]
     TokenAccounts(TokenAccountsCommand),
 
+    /// Save signed-in provider accounts and switch between them
+    #[command(subcommand)]
+    Accounts(AccountsCommand),
+
     /// Diagnose caut setup and provider health
     Doctor(DoctorArgs),
 
+    /// Draft a GitHub issue for the last internal error
+    ReportBug(ReportBugArgs),
+
     /// Output usage for shell prompt integration (fast, cached)
     Prompt(PromptArgs),
 
+    /// One-line usage and budget summary for agent prompts (fast, cached)
+    Summary(SummaryArgs),
+
+    /// Output usage for editor and launcher statuslines (fast, cached)
+    #[command(subcommand)]
+    Statusline(StatuslineCommand),
+
     /// Launch interactive TUI dashboard
     Dashboard(DashboardArgs),
 
@@ -95,6 +207,82 @@ pub enum Commands {
 
     /// Query a running caut server and print JSON to stdout
     Query(QueryArgs),
+
+    /// Render synthetic usage to test thresholds, budgets, and alert rules
+    Simulate(SimulateArgs),
+
+    /// Estimate tokens and cost of sending files to a model
+    Estimate(EstimateArgs),
+
+    /// Project this month's bill from the current burn rate
+    Project(ProjectArgs),
+
+    /// Predict when each rate window runs out at the current burn rate
+    Forecast(ForecastArgs),
+
+    /// Organization-wide usage for Team and Enterprise admins
+    #[command(subcommand)]
+    Org(OrgCommand),
+
+    /// Interactive shell for running caut commands in one process
+    Repl(ReplArgs),
+
+    /// Render caut JSON output (from stdin or a file) in another format
+    Render(RenderArgs),
+
+    /// Combine usage JSON from several machines into one view
+    Merge(MergeArgs),
+
+    /// Compare token use at API rates with what your subscription costs
+    Value(ValueArgs),
+
+    /// Import usage history from another tool into the history store
+    Import(ImportArgs),
+
+    /// Export snapshots, daily costs, and accounts to CSV, JSON, or Parquet
+    Export(ExportArgs),
+
+    /// Set, show, or remove per-provider spending budgets
+    #[command(subcommand)]
+    Budget(BudgetCommand),
+
+    /// Notify a webhook, command, or the desktop when usage crosses a threshold
+    #[command(subcommand)]
+    Alert(AlertCommand),
+
+    /// Send the current usage status to the configured channels, e.g. from cron
+    Notify(NotifyArgs),
+
+    /// Sign in to a provider and store its token in the system keyring
+    Login(LoginArgs),
+
+    /// Remove a token stored by `caut login`
+    Logout(LogoutArgs),
+
+    /// Show, change, or check the config file
+    #[command(subcommand)]
+    Config(ConfigCommand),
+
+    /// Refresh the caches behind prompt and statusline, e.g. from shell init
+    Warm(WarmArgs),
+
+    /// Fetch usage on a schedule, recording history and checking alerts
+    Daemon(DaemonArgs),
+
+    /// Summarize a week or month of usage and spend
+    Report(ReportArgs),
+
+    /// Compare providers side by side: tokens, cost, limit pressure, failures
+    Compare(CompareArgs),
+
+    /// Show what was consumed between two points in recorded history
+    Diff(DiffArgs),
+
+    /// Walk through why a provider fails to fetch, offering fixes
+    Troubleshoot(TroubleshootArgs),
+
+    /// Print a shell completion script
+    Completions(CompletionsArgs),
 }
 
 /// History subcommands.
@@ -102,12 +290,18 @@ pub enum Commands {
 pub enum HistoryCommand {
     /// Display usage history with trend visualization
     Show(HistoryShowArgs),
+    /// List recorded snapshots matching filters
+    List(HistoryListArgs),
     /// Prune old history data according to retention policy
     Prune(HistoryPruneArgs),
     /// Show history database statistics
     Stats,
-    /// Export history data to JSON or CSV
+    /// Export history data to JSON, CSV, or Parquet
     Export(HistoryExportArgs),
+    /// Changelog of provider-side plan limit changes
+    Changes(HistoryChangesArgs),
+    /// Typical usage by weekday and hour, with today's usage overlaid
+    Profile(HistoryProfileArgs),
 }
 
 /// Arguments for `history show`.
@@ -126,6 +320,66 @@ pub struct HistoryShowArgs {
     pub ascii: bool,
 }
 
+/// Arguments for `history list`.
+#[derive(Parser, Debug)]
+pub struct HistoryListArgs {
+    /// Filter by provider
+    #[arg(short, long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Filter by account email or label
+    #[arg(short, long, value_name = "ACCOUNT")]
+    pub account: Option<String>,
+
+    /// Only snapshots where any window is at least this percent used
+    #[arg(long, value_name = "PERCENT")]
+    pub min_used: Option<f64>,
+
+    /// Time range, e.g. `--between "mon 9am" "fri 6pm"`
+    #[arg(long, num_args = 2, value_names = ["FROM", "TO"], conflicts_with_all = ["since", "until"])]
+    pub between: Option<Vec<String>>,
+
+    /// Only snapshots at or after this time, e.g. 3d, "last friday"
+    #[arg(long, value_name = "DATE")]
+    pub since: Option<String>,
+
+    /// Only snapshots at or before this time, e.g. 2h, "yesterday 6pm"
+    #[arg(long, value_name = "DATE")]
+    pub until: Option<String>,
+
+    /// Maximum number of snapshots to list
+    #[arg(short, long, value_name = "N", default_value = "50")]
+    pub limit: usize,
+}
+
+/// Arguments for `history changes`.
+#[derive(Parser, Debug)]
+pub struct HistoryChangesArgs {
+    /// Filter by provider
+    #[arg(short, long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Number of days to include (default: 7)
+    #[arg(short, long, value_name = "DAYS", default_value = "7")]
+    pub days: u32,
+}
+
+/// Arguments for `history profile`.
+#[derive(Parser, Debug)]
+pub struct HistoryProfileArgs {
+    /// Provider to profile (defaults to every provider with history)
+    #[arg(short, long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Weeks of history to average (default: 4)
+    #[arg(short, long, value_name = "WEEKS", default_value = "4", value_parser = clap::value_parser!(u32).range(1..=52))]
+    pub weeks: u32,
+
+    /// Use ASCII characters instead of Unicode
+    #[arg(long)]
+    pub ascii: bool,
+}
+
 /// Arguments for `history prune`.
 #[derive(Parser, Debug)]
 pub struct HistoryPruneArgs {
@@ -146,22 +400,38 @@ pub struct HistoryPruneArgs {
     pub max_size_mb: Option<u64>,
 }
 
+/// Arguments for `prune`.
+#[derive(Parser, Debug)]
+pub struct PruneArgs {
+    /// Show what would be deleted, table by table, without deleting
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Days to keep detailed snapshots (default: 30)
+    #[arg(long, value_name = "DAYS")]
+    pub keep_days: Option<i64>,
+
+    /// Shrink the database below this size, e.g. 100MB or 1.5GB (default: 100MB)
+    #[arg(long, value_name = "SIZE", value_parser = parse_size_bytes)]
+    pub max_size: Option<u64>,
+
+    /// Delete without asking for confirmation
+    #[arg(short = 'y', long)]
+    pub yes: bool,
+}
+
 /// Arguments for `history export`.
 #[derive(Parser, Debug)]
 pub struct HistoryExportArgs {
-    /// Export format (json or csv)
+    /// Export format (json, csv, or parquet)
     #[arg(short, long, value_name = "FORMAT", default_value = "json")]
     pub format: ExportFormat,
 
-    /// Output file path (defaults to stdout)
-    #[arg(short, long, value_name = "PATH")]
-    pub output: Option<std::path::PathBuf>,
-
-    /// Start date (RFC3339 or YYYY-MM-DD)
+    /// Start date or age, e.g. 3d, "last friday", 2026-01-15 (default: 1 year ago)
     #[arg(long, value_name = "DATE")]
     pub since: Option<String>,
 
-    /// End date (RFC3339 or YYYY-MM-DD)
+    /// End date or age, e.g. "yesterday 6pm", 2h, 2026-01-18 (default: now)
     #[arg(long, value_name = "DATE")]
     pub until: Option<String>,
 
@@ -180,6 +450,7 @@ pub enum ExportFormat {
     #[default]
     Json,
     Csv,
+    Parquet,
 }
 
 impl std::str::FromStr for ExportFormat {
@@ -189,7 +460,10 @@ impl std::str::FromStr for ExportFormat {
         match s.to_lowercase().as_str() {
             "json" => Ok(Self::Json),
             "csv" => Ok(Self::Csv),
-            _ => Err(format!("Unknown export format: {s}. Use 'json' or 'csv'.")),
+            "parquet" => Ok(Self::Parquet),
+            _ => Err(format!(
+                "Unknown export format: {s}. Use 'json', 'csv', or 'parquet'."
+            )),
         }
     }
 }
@@ -199,6 +473,7 @@ impl std::fmt::Display for ExportFormat {
         match self {
             Self::Json => write!(f, "json"),
             Self::Csv => write!(f, "csv"),
+            Self::Parquet => write!(f, "parquet"),
         }
     }
 }
@@ -227,6 +502,23 @@ pub struct UsageArgs {
     #[arg(long)]
     pub no_credits: bool,
 
+    /// Show long values in full instead of truncating them
+    #[arg(long)]
+    pub full: bool,
+
+    /// Show provider dashboard links (clickable in supporting terminals)
+    #[arg(long)]
+    pub links: bool,
+
+    /// Show how each number was computed and where it came from
+    #[arg(long)]
+    pub explain: bool,
+
+    /// Also fetch every other available source and flag windows where the
+    /// sources disagree
+    #[arg(long)]
+    pub verify: bool,
+
     /// Fetch provider status
     #[arg(long)]
     pub status: bool,
@@ -247,6 +539,10 @@ pub struct UsageArgs {
     #[arg(long, value_name = "SECONDS")]
     pub web_timeout: Option<u64>,
 
+    /// API key for an API-key provider, e.g. groq=gsk_... (repeatable)
+    #[arg(long = "api-key", value_name = "PROVIDER=KEY")]
+    pub api_keys: Vec<String>,
+
     /// Dump HTML for web debugging
     #[arg(long, hide = true)]
     pub web_debug_dump_html: bool,
@@ -255,14 +551,108 @@ pub struct UsageArgs {
     #[arg(long, short = 'w')]
     pub watch: bool,
 
-    /// Interval between updates in seconds (default: 30).
-    #[arg(long, default_value = "30")]
+    /// Interval between updates, e.g. 30, 45s, 5m (default: 30 seconds).
+    #[arg(long, value_name = "DURATION", default_value = "30", value_parser = parse_duration_secs)]
     pub interval: u64,
 
+    /// Record each watch refresh to an asciinema cast file. Implies --watch.
+    #[arg(long, value_name = "FILE", conflicts_with = "tui")]
+    pub record: Option<std::path::PathBuf>,
+
+    /// Play back a recorded watch session instead of fetching usage.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["watch", "record", "tui"])]
+    pub replay: Option<std::path::PathBuf>,
+
     /// Use TUI dashboard mode (interactive terminal UI with ratatui).
     /// Implies --watch mode.
     #[arg(long, short = 't')]
     pub tui: bool,
+
+    /// Order providers by what matters most: fullest window, highest spend
+    /// today, or soonest reset
+    #[arg(long, value_enum, value_name = "KEY")]
+    pub sort: Option<UsageSort>,
+
+    /// Only show providers matching, e.g. used>50, cost>=1, reset<2h
+    /// (repeatable; all must match)
+    #[arg(long, value_name = "EXPR", value_parser = UsageFilter::from_str)]
+    pub filter: Vec<UsageFilter>,
+
+    /// Show a table with these columns instead of panels, e.g.
+    /// provider,primary,weekly,cost (default: `output.columns` in config)
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "COLUMNS")]
+    pub columns: Vec<UsageColumn>,
+
+    /// Print the usage table as CSV
+    #[arg(long)]
+    pub csv: bool,
+
+    /// Fail when no provider is set up, even with --json (which otherwise
+    /// reports it and exits 0)
+    #[arg(long)]
+    pub strict: bool,
+}
+
+/// Column in the `usage --columns` table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum UsageColumn {
+    /// Provider name
+    Provider,
+    /// Data source used
+    Source,
+    /// Account email
+    Account,
+    /// Session (primary) window used
+    #[value(alias = "session")]
+    Primary,
+    /// Weekly window used
+    Weekly,
+    /// Opus/Sonnet (tertiary) window used
+    Tertiary,
+    /// Credits left
+    Credits,
+    /// Spend today
+    Cost,
+    /// Next window reset
+    Reset,
+    /// Provider status
+    Status,
+}
+
+impl UsageColumn {
+    /// Columns shown by `--csv` when none are chosen.
+    pub const DEFAULT: [Self; 7] = [
+        Self::Provider,
+        Self::Primary,
+        Self::Weekly,
+        Self::Credits,
+        Self::Cost,
+        Self::Reset,
+        Self::Status,
+    ];
+}
+
+/// Sort key for `usage --sort`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum UsageSort {
+    /// Fullest window first
+    Used,
+    /// Highest spend today first
+    Cost,
+    /// Soonest reset first
+    Reset,
+}
+
+impl UsageSort {
+    /// The usage number this key sorts by.
+    #[must_use]
+    pub const fn field(self) -> UsageField {
+        match self {
+            Self::Used => UsageField::Used,
+            Self::Cost => UsageField::Cost,
+            Self::Reset => UsageField::Reset,
+        }
+    }
 }
 
 impl UsageArgs {
@@ -297,9 +687,46 @@ impl UsageArgs {
             ));
         }
 
+        self.flag_api_keys()?;
+
         Ok(())
     }
 
+    /// Whether `--sort` or `--filter` needs today's spend.
+    #[must_use]
+    pub fn uses_cost(&self) -> bool {
+        self.sort == Some(UsageSort::Cost)
+            || self
+                .filter
+                .iter()
+                .any(|filter| filter.field == UsageField::Cost)
+    }
+
+    /// Parse `--api-key PROVIDER=KEY` values.
+    ///
+    /// # Errors
+    /// Returns an error if a value is not `PROVIDER=KEY` or names an unknown
+    /// provider.
+    pub fn flag_api_keys(
+        &self,
+    ) -> crate::error::Result<Vec<(crate::core::provider::Provider, String)>> {
+        use crate::error::CautError;
+
+        self.api_keys
+            .iter()
+            .map(|value| {
+                let (provider, key) = value
+                    .split_once('=')
+                    .filter(|(_, key)| !key.trim().is_empty())
+                    .ok_or_else(|| {
+                        CautError::Config("--api-key expects PROVIDER=KEY".to_string())
+                    })?;
+                let provider = crate::core::provider::Provider::from_cli_name(provider.trim())?;
+                Ok((provider, key.trim().to_string()))
+            })
+            .collect()
+    }
+
     /// Get effective source mode.
     #[must_use]
     pub fn effective_source(&self) -> crate::core::fetch_plan::SourceMode {
@@ -332,116 +759,760 @@ pub struct CostArgs {
     /// Refresh cached cost data
     #[arg(long)]
     pub refresh: bool,
+
+    /// Estimate agent cost per git commit and merged PR
+    #[arg(long)]
+    pub git: bool,
+
+    /// Repository to correlate with (default: current directory)
+    #[arg(long, value_name = "PATH", requires = "git")]
+    pub repo: Option<std::path::PathBuf>,
+
+    /// How far back to look, e.g. 30d, "last friday" (default: 30d)
+    #[arg(long, value_name = "DATE", requires = "git")]
+    pub since: Option<String>,
 }
 
-/// Arguments for the `doctor` command.
+/// Arguments for the `estimate` command.
 #[derive(Parser, Debug)]
-pub struct DoctorArgs {
-    /// Only check specific provider(s)
-    #[arg(short, long, value_name = "PROVIDER")]
-    pub provider: Option<Vec<String>>,
+pub struct EstimateArgs {
+    /// File to estimate (repeatable; reads stdin when omitted)
+    #[arg(long = "file", value_name = "PATH")]
+    pub files: Vec<std::path::PathBuf>,
+
+    /// Model to price against (full name or prefix, e.g. claude-sonnet)
+    #[arg(long, value_name = "MODEL", default_value = "claude-sonnet")]
+    pub model: String,
+
+    /// Expected response length in tokens, priced as output
+    #[arg(long, value_name = "TOKENS", default_value = "0")]
+    pub output_tokens: u64,
+}
 
-    /// Timeout for each provider check in seconds
-    #[arg(long, default_value = "5")]
-    pub timeout: u64,
+/// Arguments for the `render` command.
+#[derive(Parser, Debug)]
+pub struct RenderArgs {
+    /// JSON file to render (reads stdin when omitted or "-")
+    #[arg(value_name = "FILE")]
+    pub input: Option<std::path::PathBuf>,
+
+    /// Show a table of these columns instead of panels (usage output)
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "COLUMNS")]
+    pub columns: Vec<UsageColumn>,
 }
 
-/// Arguments for the `prompt` command.
+/// Arguments for the `merge` command.
 #[derive(Parser, Debug)]
-pub struct PromptArgs {
-    /// Provider to show (defaults to primary configured provider)
-    #[arg(short, long, value_name = "PROVIDER")]
+pub struct MergeArgs {
+    /// `caut usage --json` files to combine ("-" for stdin)
+    #[arg(value_name = "FILE", required = true)]
+    pub inputs: Vec<std::path::PathBuf>,
+
+    /// Show a table of these columns instead of panels
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "COLUMNS")]
+    pub columns: Vec<UsageColumn>,
+}
+
+/// Arguments for the `value` command.
+#[derive(Parser, Debug)]
+pub struct ValueArgs {
+    /// Provider to evaluate (name, "both", or "all")
+    #[arg(long, value_name = "PROVIDER")]
     pub provider: Option<String>,
 
-    /// Output format
-    #[arg(long, value_enum, default_value = "compact")]
-    pub prompt_format: PromptFormat,
+    /// Plan per provider, by name or monthly USD price (repeatable, e.g.
+    /// claude=max-20x, codex=200); detected from local sign-ins by default
+    #[arg(long = "plan", value_name = "[PROVIDER=]PLAN")]
+    pub plans: Vec<String>,
 
-    /// Include ANSI color codes
-    #[arg(long)]
-    pub color: bool,
+    /// Billing cycles to compare, including the current one
+    #[arg(long, value_name = "N", default_value = "3", value_parser = clap::value_parser!(u32).range(1..=24))]
+    pub cycles: u32,
 
-    /// Disable ANSI color codes
-    #[arg(long)]
-    pub no_color: bool,
+    /// Day of the month each billing cycle starts
+    #[arg(long, value_name = "DAY", default_value = "1", value_parser = clap::value_parser!(u32).range(1..=31))]
+    pub billing_day: u32,
+}
 
-    /// Maximum cache age in seconds before showing empty (default: 60)
-    /// Only used when --strict-freshness is enabled.
-    #[arg(long, value_name = "SECONDS", default_value = "60")]
-    pub cache_max_age: u64,
+/// Arguments for the `import` command.
+#[derive(Parser, Debug)]
+pub struct ImportArgs {
+    /// Where to import from
+    #[arg(long, value_enum, value_name = "SOURCE")]
+    pub from: ImportSource,
 
-    /// Strict freshness mode: show nothing if cache exceeds `max_age`.
-    /// Without this flag, stale data is shown with a staleness indicator (~/?).
-    #[arg(long)]
-    pub strict_freshness: bool,
+    /// API base URL (default: the source's hosted API)
+    #[arg(long, value_name = "URL")]
+    pub url: Option<String>,
 
-    /// Generate shell integration snippet and exit
-    #[arg(long, value_enum)]
-    pub install: Option<ShellType>,
+    /// Read a saved ccusage or `CodexBar` JSON report instead of running the
+    /// tool ("-" for stdin)
+    #[arg(long, value_name = "PATH")]
+    pub file: Option<std::path::PathBuf>,
+
+    /// Days of history to import
+    #[arg(long, value_name = "DAYS", default_value = "30", value_parser = clap::value_parser!(u32).range(1..=365))]
+    pub days: u32,
 }
 
-/// Arguments for the `dashboard` command.
+/// Source for `caut import`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportSource {
+    /// Helicone request logs
+    Helicone,
+    /// A gateway serving the OpenAI usage API
+    Gateway,
+    /// Claude cost history from `ccusage daily --json`
+    Ccusage,
+    /// Claude and Codex cost history from `codexbar cost --format json`
+    Codexbar,
+}
+
+/// Arguments for the `export` command.
 #[derive(Parser, Debug)]
-pub struct DashboardArgs {
-    /// Provider to query (name, "both", or "all")
-    #[arg(long, value_name = "PROVIDER")]
-    pub provider: Option<String>,
+pub struct ExportArgs {
+    /// Export format (json, csv, or parquet)
+    #[arg(short, long, value_name = "FORMAT", default_value = "csv")]
+    pub format: ExportFormat,
 
-    /// Data source (auto, web, cli, oauth)
-    #[arg(long, value_name = "SOURCE")]
-    pub source: Option<String>,
+    /// Table to export (default: all tables for JSON, snapshots otherwise)
+    #[arg(long, value_enum, value_name = "TABLE")]
+    pub table: Option<ExportTable>,
 
-    /// Interval between updates in seconds (default: 30)
-    #[arg(long, default_value = "30")]
-    pub interval: u64,
-}
+    /// Start date or age, e.g. 3d, "last friday", 2026-01-15 (default: 1 year ago)
+    #[arg(long, value_name = "DATE")]
+    pub since: Option<String>,
 
-/// Arguments for the `session` command.
-#[derive(Parser, Debug, Clone, Default)]
-pub struct SessionArgs {
-    /// Show specific session by ID (partial match supported)
-    #[arg(long, value_name = "ID")]
-    pub id: Option<String>,
+    /// End date or age, e.g. "yesterday 6pm", 2h, 2026-01-18 (default: now)
+    #[arg(long, value_name = "DATE")]
+    pub until: Option<String>,
+}
 
-    /// List recent sessions
-    #[arg(long, short = 'l')]
-    pub list: bool,
+/// History table for `caut export`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportTable {
+    /// Recorded usage snapshots
+    Snapshots,
+    /// Daily cost per provider, plus imported gateway usage
+    Costs,
+    /// Registered accounts
+    Accounts,
+}
 
-    /// Show all sessions from today
+/// Arguments for the `repl` command.
+#[derive(Parser, Debug)]
+pub struct ReplArgs {
+    /// Do not load or save command history
     #[arg(long)]
-    pub today: bool,
+    pub no_history: bool,
+}
 
-    /// Filter by provider (claude, codex)
-    #[arg(long, short = 'p', value_name = "PROVIDER")]
+/// Arguments for the `project` command.
+#[derive(Parser, Debug)]
+pub struct ProjectArgs {
+    /// Provider to project (name, "both", or "all")
+    #[arg(long, value_name = "PROVIDER")]
     pub provider: Option<String>,
 
-    /// Maximum number of sessions to show in list mode (default: 10)
-    #[arg(long, short = 'n', value_name = "N", default_value = "10")]
-    pub limit: usize,
+    /// Length of the billing period in days, starting at the 1st of the month
+    #[arg(long, value_name = "DAYS", default_value = "30", value_parser = clap::value_parser!(u32).range(1..=366))]
+    pub days: u32,
 }
 
-/// Arguments for the `serve` command (background daemon).
+/// Arguments for the `forecast` command.
 #[derive(Parser, Debug)]
-pub struct ServeArgs {
-    /// Port to listen on (default: 19485)
-    #[arg(long, default_value = "19485")]
-    pub port: u16,
-
-    /// Bind address (default: 127.0.0.1)
-    #[arg(long, default_value = "127.0.0.1")]
-    pub bind: String,
+pub struct ForecastArgs {
+    /// Provider to forecast (name, "both", or "all"); defaults to every
+    /// provider with recorded history
+    #[arg(long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
 
-    /// Background refresh interval in seconds (default: 30).
-    /// The server caches usage data and refreshes it at this interval.
-    #[arg(long, default_value = "30")]
-    pub interval: u64,
+    /// How far back to fit the burn rate (e.g. 90m, 3h, 1d)
+    #[arg(long, value_name = "DURATION", default_value = "3h", value_parser = parse_duration_secs)]
+    pub lookback: u64,
+}
 
-    /// Provider to query (name, "both", or "all")
+/// Arguments for the `snapshot` command.
+#[derive(Parser, Debug)]
+pub struct SnapshotArgs {
+    /// Provider to capture (name, "both", or "all"); defaults to all
     #[arg(long, value_name = "PROVIDER")]
     pub provider: Option<String>,
 
-    /// Data source (auto, web, cli, oauth)
-    #[arg(long, value_name = "SOURCE")]
+    /// Annotation stored with the snapshots, e.g. "before refactor"
+    #[arg(long, value_name = "TEXT")]
+    pub note: Option<String>,
+}
+
+/// Arguments for the `report` command.
+#[derive(Parser, Debug)]
+pub struct ReportArgs {
+    /// Period to cover, ending today
+    #[arg(long, value_enum, default_value = "week")]
+    pub period: ReportPeriod,
+
+    /// Provider to report on (name, "both", or "all")
+    #[arg(long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Render a standalone HTML page instead of text
+    #[arg(long)]
+    pub html: bool,
+}
+
+/// Arguments for the `diff` command.
+#[derive(Parser, Debug)]
+pub struct DiffArgs {
+    /// Provider to compare (defaults to every provider with history)
+    #[arg(short, long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Start time, e.g. "2h ago", "9am", "yesterday 18:00"
+    #[arg(long, value_name = "WHEN")]
+    pub from: String,
+
+    /// End time (default: now)
+    #[arg(long, value_name = "WHEN", default_value = "now")]
+    pub to: String,
+}
+
+/// Arguments for the `compare` command.
+#[derive(Parser, Debug)]
+pub struct CompareArgs {
+    /// Providers to compare (name, "both", or "all")
+    #[arg(long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Number of days to compare, ending today
+    #[arg(long, value_name = "DAYS", default_value = "7", value_parser = clap::value_parser!(u32).range(1..=30))]
+    pub days: u32,
+}
+
+/// Period covered by `caut report`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportPeriod {
+    /// The last 7 days
+    Week,
+    /// The last 30 days
+    Month,
+}
+
+impl ReportPeriod {
+    /// Days in the period.
+    #[must_use]
+    pub const fn days(self) -> i64 {
+        match self {
+            Self::Week => 7,
+            Self::Month => 30,
+        }
+    }
+
+    /// Lowercase label used in output.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+}
+
+/// Organization admin subcommands.
+#[derive(Subcommand, Debug)]
+pub enum OrgCommand {
+    /// Per-seat usage from the provider's Admin API
+    Usage(OrgUsageArgs),
+}
+
+/// Arguments for `org usage`.
+#[derive(Parser, Debug)]
+pub struct OrgUsageArgs {
+    /// Provider to report on (claude or openai)
+    #[arg(long, value_name = "PROVIDER")]
+    pub provider: String,
+
+    /// Number of days to include, ending today (UTC)
+    #[arg(long, value_name = "DAYS", default_value = "7", value_parser = clap::value_parser!(u32).range(1..=90))]
+    pub days: u32,
+
+    /// Column to sort seats by
+    #[arg(long, value_enum, default_value = "cost")]
+    pub sort: OrgSort,
+
+    /// Print CSV instead of a table
+    #[arg(long)]
+    pub csv: bool,
+
+    /// Also write the seats as CSV to this file
+    #[arg(long, value_name = "PATH")]
+    pub csv_file: Option<std::path::PathBuf>,
+}
+
+/// Budget subcommands.
+#[derive(Subcommand, Debug)]
+pub enum BudgetCommand {
+    /// Set daily, weekly, or monthly cost limits in USD
+    Set(BudgetSetArgs),
+    /// Show limits and spend so far this period
+    Show(BudgetShowArgs),
+    /// Remove cost limits
+    Remove(BudgetRemoveArgs),
+}
+
+/// Arguments for `budget set`.
+#[derive(Parser, Debug)]
+#[command(group(clap::ArgGroup::new("limit").required(true).multiple(true)))]
+pub struct BudgetSetArgs {
+    /// Provider the limits apply to, or "global" for every provider
+    pub provider: String,
+
+    /// Daily cost limit in USD
+    #[arg(long, value_name = "USD", group = "limit")]
+    pub daily: Option<f64>,
+
+    /// Weekly cost limit in USD (weeks start on Monday)
+    #[arg(long, value_name = "USD", group = "limit")]
+    pub weekly: Option<f64>,
+
+    /// Monthly cost limit in USD
+    #[arg(long, value_name = "USD", group = "limit")]
+    pub monthly: Option<f64>,
+}
+
+/// Arguments for `budget show`.
+#[derive(Parser, Debug)]
+pub struct BudgetShowArgs {
+    /// Only show this provider's budgets
+    pub provider: Option<String>,
+}
+
+/// Arguments for `budget remove`.
+#[derive(Parser, Debug)]
+pub struct BudgetRemoveArgs {
+    /// Provider to remove limits from, or "global"
+    pub provider: String,
+
+    /// Remove only the daily limit
+    #[arg(long)]
+    pub daily: bool,
+
+    /// Remove only the weekly limit
+    #[arg(long)]
+    pub weekly: bool,
+
+    /// Remove only the monthly limit
+    #[arg(long)]
+    pub monthly: bool,
+}
+
+/// Alert subcommands.
+#[derive(Subcommand, Debug)]
+pub enum AlertCommand {
+    /// Add an alert rule
+    Add(AlertAddArgs),
+    /// List alert rules
+    List,
+    /// Remove an alert rule by id
+    Remove {
+        /// Id shown by `caut alert list`
+        id: u32,
+    },
+}
+
+/// Arguments for `alert add`.
+#[derive(Parser, Debug)]
+pub struct AlertAddArgs {
+    /// Provider to watch (default: every provider)
+    #[arg(long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Rate window to watch: primary, secondary, or tertiary
+    #[arg(long, default_value = "primary")]
+    pub metric: String,
+
+    /// Used percentage at which to notify
+    #[arg(long, value_name = "PERCENT")]
+    pub threshold: f64,
+
+    /// Where to send it: webhook:<url>, command:<shell command>, email:<address>, or desktop
+    #[arg(long, value_name = "CHANNEL")]
+    pub channel: String,
+}
+
+/// Arguments for the `notify` command.
+#[derive(Parser, Debug)]
+pub struct NotifyArgs {
+    /// Provider to report (name, "both", or "all"); defaults to all
+    #[arg(long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Send here instead of the `[notify]` channels: webhook:<url>,
+    /// command:<shell command>, email:<address>, or desktop (repeatable)
+    #[arg(long = "channel", value_name = "CHANNEL")]
+    pub channels: Vec<String>,
+
+    /// Print the message without sending it
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl NotifyArgs {
+    /// Convert to `UsageArgs` for the fetch pipeline.
+    #[must_use]
+    pub fn to_usage_args(&self) -> UsageArgs {
+        UsageArgs {
+            provider: self.provider.clone(),
+            account: None,
+            account_index: None,
+            all_accounts: false,
+            no_credits: false,
+            full: false,
+            links: false,
+            explain: false,
+            verify: false,
+            status: true,
+            source: None,
+            web: false,
+            timeout: None,
+            web_timeout: None,
+            api_keys: Vec::new(),
+            web_debug_dump_html: false,
+            watch: false,
+            interval: 30,
+            record: None,
+            replay: None,
+            tui: false,
+            sort: None,
+            filter: Vec::new(),
+            columns: Vec::new(),
+            csv: false,
+            strict: false,
+        }
+    }
+}
+
+/// Arguments for the `login` command.
+#[derive(Parser, Debug)]
+pub struct LoginArgs {
+    /// Provider to sign in to (currently only claude)
+    #[arg(value_name = "PROVIDER")]
+    pub provider: String,
+
+    /// Print the sign-in URL without opening a browser
+    #[arg(long)]
+    pub no_browser: bool,
+}
+
+/// Arguments for the `logout` command.
+#[derive(Parser, Debug)]
+pub struct LogoutArgs {
+    /// Provider to sign out of (currently only claude)
+    #[arg(value_name = "PROVIDER")]
+    pub provider: String,
+}
+
+/// Config subcommands.
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Show the resolved settings, where each came from, and the config
+    Show,
+    /// Print the value of a key, e.g. general.timeout_seconds
+    Get {
+        /// Dotted key
+        key: String,
+    },
+    /// Set a key in the config file, e.g. output.format json
+    Set {
+        /// Dotted key
+        key: String,
+        /// New value, as TOML (bare words are strings)
+        value: String,
+    },
+    /// Open the config file in $VISUAL or $EDITOR
+    Edit,
+    /// Print the config file path
+    Path,
+    /// Check the config file for invalid values and unknown keys
+    Validate(ConfigValidateArgs),
+}
+
+/// Arguments for `config validate`.
+#[derive(Parser, Debug)]
+pub struct ConfigValidateArgs {
+    /// Fail on unknown keys instead of warning about them
+    #[arg(long)]
+    pub strict: bool,
+}
+
+/// Sort order for `org usage` seats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OrgSort {
+    /// Highest estimated cost first
+    #[default]
+    Cost,
+    /// Most tokens first
+    Tokens,
+    /// Most sessions first
+    Sessions,
+    /// Alphabetical by member
+    Member,
+}
+
+/// Arguments for the `simulate` command.
+#[derive(Parser, Debug)]
+pub struct SimulateArgs {
+    /// Provider to simulate
+    #[arg(long, value_name = "PROVIDER", default_value = "claude")]
+    pub provider: String,
+
+    /// Primary (session) window usage percent (0-100)
+    #[arg(long, value_name = "PERCENT", default_value = "0")]
+    pub primary: f64,
+
+    /// Secondary (weekly) window usage percent (0-100)
+    #[arg(long, value_name = "PERCENT")]
+    pub secondary: Option<f64>,
+
+    /// Tertiary (model-specific) window usage percent (0-100)
+    #[arg(long, value_name = "PERCENT")]
+    pub tertiary: Option<f64>,
+
+    /// Remaining credits to report
+    #[arg(long, value_name = "CREDITS")]
+    pub credits: Option<f64>,
+
+    /// Cost spent today in USD (checked against cost budgets)
+    #[arg(long, value_name = "USD")]
+    pub cost_today: Option<f64>,
+}
+
+impl SimulateArgs {
+    /// Validate simulated values.
+    ///
+    /// # Errors
+    /// Returns an error if a usage percentage is outside 0-100 or a value is negative.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        use crate::error::CautError;
+
+        let windows = [
+            ("--primary", Some(self.primary)),
+            ("--secondary", self.secondary),
+            ("--tertiary", self.tertiary),
+        ];
+        for (flag, value) in windows {
+            if let Some(value) = value
+                && !(0.0..=100.0).contains(&value)
+            {
+                return Err(CautError::Config(format!(
+                    "{flag} must be between 0 and 100 (got {value})"
+                )));
+            }
+        }
+
+        if self.credits.is_some_and(|c| c < 0.0) {
+            return Err(CautError::Config(
+                "--credits must not be negative".to_string(),
+            ));
+        }
+        if self.cost_today.is_some_and(|c| c < 0.0) {
+            return Err(CautError::Config(
+                "--cost-today must not be negative".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Arguments for the `report-bug` command.
+#[derive(Parser, Debug)]
+pub struct ReportBugArgs {
+    /// Open the pre-filled issue in the browser
+    #[arg(long)]
+    pub open: bool,
+}
+
+/// Arguments for the `doctor` command.
+#[derive(Parser, Debug)]
+pub struct DoctorArgs {
+    /// Only check specific provider(s)
+    #[arg(short, long, value_name = "PROVIDER")]
+    pub provider: Option<Vec<String>>,
+
+    /// Timeout for each provider check in seconds
+    #[arg(long, default_value = "5")]
+    pub timeout: u64,
+
+    /// Repair what can be fixed automatically (restrict credential and state
+    /// files to mode 600)
+    #[arg(long)]
+    pub fix: bool,
+}
+
+/// Arguments for the `summary` command.
+#[derive(Parser, Debug)]
+pub struct SummaryArgs {
+    /// Only summarize this provider
+    #[arg(long, short = 'p', value_name = "PROVIDER")]
+    pub provider: Option<String>,
+}
+
+/// Arguments for the `prompt` command.
+#[derive(Parser, Debug)]
+pub struct PromptArgs {
+    /// Provider to show (defaults to primary configured provider)
+    #[arg(short, long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "compact")]
+    pub prompt_format: PromptFormat,
+
+    /// Include ANSI color codes
+    #[arg(long)]
+    pub color: bool,
+
+    /// Disable ANSI color codes
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Maximum cache age before showing empty, e.g. 60, 90m (default: 60 seconds)
+    /// Only used when --strict-freshness is enabled.
+    #[arg(
+        long,
+        visible_alias = "max-age",
+        value_name = "DURATION",
+        default_value = "60",
+        value_parser = parse_duration_secs
+    )]
+    pub cache_max_age: u64,
+
+    /// Strict freshness mode: show nothing if cache exceeds `max_age`.
+    /// Without this flag, stale data is shown with a staleness indicator (~/?).
+    #[arg(long)]
+    pub strict_freshness: bool,
+
+    /// Generate shell integration snippet and exit
+    #[arg(long, value_enum)]
+    pub install: Option<ShellType>,
+}
+
+/// Statusline subcommands.
+#[derive(Subcommand, Debug)]
+pub enum StatuslineCommand {
+    /// Minimal JSON (text + severity) for VS Code / Neovim statuslines
+    LspJson(StatuslineArgs),
+    /// Per-provider list with quick actions for Raycast
+    Raycast(RaycastArgs),
+    /// Alfred Script Filter JSON with quick actions
+    Alfred(StatuslineArgs),
+    /// One short line for PS1, starship, or tmux, e.g. "cl:62% cx:18% $4.20"
+    Shell(ShellStatuslineArgs),
+}
+
+/// Arguments shared by statusline outputs.
+#[derive(Parser, Debug)]
+pub struct StatuslineArgs {
+    /// Only include this provider
+    #[arg(short, long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+}
+
+/// Arguments for `statusline shell`.
+#[derive(Parser, Debug)]
+pub struct ShellStatuslineArgs {
+    /// Only include this provider
+    #[arg(short, long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Refresh the cache in the background once it is older than this, and
+    /// mark the line stale, e.g. 60, 90s, 5m (default: 5 minutes)
+    #[arg(long, value_name = "DURATION", default_value = "300", value_parser = parse_duration_secs)]
+    pub max_age: u64,
+
+    /// Never start a background refresh; only read the cache
+    #[arg(long)]
+    pub no_refresh: bool,
+}
+
+/// Arguments for `statusline raycast`.
+#[derive(Parser, Debug)]
+pub struct RaycastArgs {
+    /// Only include this provider
+    #[arg(short, long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Print one line for a Raycast script command in inline mode
+    #[arg(long)]
+    pub inline: bool,
+
+    /// Print a ready-to-save Raycast script command and exit
+    #[arg(long, conflicts_with = "inline")]
+    pub install: bool,
+}
+
+/// Arguments for the `dashboard` command.
+#[derive(Parser, Debug)]
+pub struct DashboardArgs {
+    /// Provider to query (name, "both", or "all")
+    #[arg(long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Data source (auto, web, cli, oauth)
+    #[arg(long, value_name = "SOURCE")]
+    pub source: Option<String>,
+
+    /// Interval between updates, e.g. 30, 45s, 5m (default: 30 seconds)
+    #[arg(long, value_name = "DURATION", default_value = "30", value_parser = parse_duration_secs)]
+    pub interval: u64,
+}
+
+/// Arguments for the `session` command.
+#[derive(Parser, Debug, Clone, Default)]
+pub struct SessionArgs {
+    /// Show specific session by ID (partial match supported)
+    #[arg(long, value_name = "ID")]
+    pub id: Option<String>,
+
+    /// List recent sessions
+    #[arg(long, short = 'l')]
+    pub list: bool,
+
+    /// Show all sessions from today
+    #[arg(long)]
+    pub today: bool,
+
+    /// Filter by provider (claude, codex)
+    #[arg(long, short = 'p', value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Maximum number of sessions to show in list mode (default: 10)
+    #[arg(long, short = 'n', value_name = "N", default_value = "10")]
+    pub limit: usize,
+}
+
+/// Arguments for the `serve` command (background daemon).
+#[derive(Parser, Debug)]
+pub struct ServeArgs {
+    /// Port to listen on (default: 19485)
+    #[arg(long, default_value = "19485")]
+    pub port: u16,
+
+    /// Bind address (default: 127.0.0.1)
+    #[arg(long, default_value = "127.0.0.1")]
+    pub bind: String,
+
+    /// Background refresh interval, e.g. 30, 45s, 5m (default: 30 seconds).
+    /// The server caches usage data and refreshes it at this interval.
+    #[arg(long, value_name = "DURATION", default_value = "30", value_parser = parse_duration_secs)]
+    pub interval: u64,
+
+    /// Provider to query (name, "both", or "all")
+    #[arg(long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Data source (auto, web, cli, oauth)
+    #[arg(long, value_name = "SOURCE")]
     pub source: Option<String>,
 
     /// Write PID file to this path (default: <data_dir>/caut-server.pid)
@@ -451,6 +1522,10 @@ pub struct ServeArgs {
     /// Run in foreground (don't daemonize, default behavior)
     #[arg(long)]
     pub foreground: bool,
+
+    /// Don't run the weekly self-test of every fetch strategy
+    #[arg(long)]
+    pub no_canary: bool,
 }
 
 impl ServeArgs {
@@ -463,15 +1538,134 @@ impl ServeArgs {
             account_index: None,
             all_accounts: false,
             no_credits: false,
+            full: false,
+            links: false,
+            explain: false,
+            verify: false,
             status: true,
             source: self.source.clone(),
             web: false,
             timeout: None,
             web_timeout: None,
+            api_keys: Vec::new(),
+            web_debug_dump_html: false,
+            watch: false,
+            interval: self.interval,
+            record: None,
+            replay: None,
+            tui: false,
+            sort: None,
+            filter: Vec::new(),
+            columns: Vec::new(),
+            csv: false,
+            strict: false,
+        }
+    }
+}
+
+/// Arguments for the `warm` command.
+#[derive(Parser, Debug)]
+pub struct WarmArgs {
+    /// Provider to query (name, "both", or "all")
+    #[arg(long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Refresh in a detached process and return immediately
+    #[arg(long)]
+    pub background: bool,
+
+    /// Skip the refresh when the cache is younger than this, e.g. 60, 90s, 5m
+    #[arg(long, value_name = "DURATION", default_value = "60", value_parser = parse_duration_secs)]
+    pub max_age: u64,
+}
+
+impl WarmArgs {
+    /// Convert to `UsageArgs` for the fetch pipeline.
+    #[must_use]
+    pub fn to_usage_args(&self) -> UsageArgs {
+        UsageArgs {
+            provider: self.provider.clone(),
+            account: None,
+            account_index: None,
+            all_accounts: false,
+            no_credits: false,
+            full: false,
+            links: false,
+            explain: false,
+            verify: false,
+            status: false,
+            source: None,
+            web: false,
+            timeout: None,
+            web_timeout: None,
+            api_keys: Vec::new(),
+            web_debug_dump_html: false,
+            watch: false,
+            interval: 30,
+            record: None,
+            replay: None,
+            tui: false,
+            sort: None,
+            filter: Vec::new(),
+            columns: Vec::new(),
+            csv: false,
+            strict: false,
+        }
+    }
+}
+
+/// Arguments for the `daemon` command.
+#[derive(Parser, Debug)]
+pub struct DaemonArgs {
+    /// Time between fetches, e.g. 90s, 5m, 1h (default: 5 minutes)
+    #[arg(long, value_name = "DURATION", default_value = "300", value_parser = parse_duration_secs)]
+    pub interval: u64,
+
+    /// Provider to query (name, "both", or "all")
+    #[arg(long, value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Data source (auto, web, cli, oauth)
+    #[arg(long, value_name = "SOURCE")]
+    pub source: Option<String>,
+
+    /// Write a systemd user unit (Linux) or launchd agent (macOS) that runs
+    /// the daemon at login, instead of running it
+    #[arg(long)]
+    pub install: bool,
+}
+
+impl DaemonArgs {
+    /// Convert to `UsageArgs` for the fetch pipeline.
+    #[must_use]
+    pub fn to_usage_args(&self) -> UsageArgs {
+        UsageArgs {
+            provider: self.provider.clone(),
+            account: None,
+            account_index: None,
+            all_accounts: false,
+            no_credits: false,
+            full: false,
+            links: false,
+            explain: false,
+            verify: false,
+            status: false,
+            source: self.source.clone(),
+            web: false,
+            timeout: None,
+            web_timeout: None,
+            api_keys: Vec::new(),
             web_debug_dump_html: false,
             watch: false,
             interval: self.interval,
+            record: None,
+            replay: None,
             tui: false,
+            sort: None,
+            filter: Vec::new(),
+            columns: Vec::new(),
+            csv: false,
+            strict: false,
         }
     }
 }
@@ -479,7 +1673,9 @@ impl ServeArgs {
 /// Arguments for the `query` command (client for the background daemon).
 #[derive(Parser, Debug)]
 pub struct QueryArgs {
-    /// Endpoint to query: usage, cost, session, health (default: usage)
+    /// Endpoint to query: usage, cost, session, doctor, history, health
+    /// (default: usage), with an optional query string, e.g.
+    /// "history?provider=claude&since=3d"
     #[arg(default_value = "usage")]
     pub endpoint: String,
 
@@ -506,19 +1702,51 @@ impl DashboardArgs {
             account_index: None,
             all_accounts: false,
             no_credits: false,
+            full: false,
+            links: false,
+            explain: false,
+            verify: false,
             status: true, // Always show status in dashboard
             source: self.source.clone(),
             web: false,
             timeout: None,
             web_timeout: None,
+            api_keys: Vec::new(),
             web_debug_dump_html: false,
             watch: true,
             interval: self.interval,
+            record: None,
+            replay: None,
             tui: true,
+            sort: None,
+            filter: Vec::new(),
+            columns: Vec::new(),
+            csv: false,
+            strict: false,
         }
     }
 }
 
+/// Arguments for the `troubleshoot` command.
+#[derive(Parser, Debug)]
+pub struct TroubleshootArgs {
+    /// Provider to troubleshoot, e.g. claude
+    #[arg(value_name = "PROVIDER")]
+    pub provider: String,
+
+    /// Run offered fixes without asking
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+}
+
+/// Arguments for the `completions` command.
+#[derive(Parser, Debug)]
+pub struct CompletionsArgs {
+    /// Shell to generate completions for
+    #[arg(value_enum)]
+    pub shell: clap_complete::Shell,
+}
+
 /// Prompt output format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
 pub enum PromptFormat {
@@ -552,6 +1780,10 @@ pub enum TokenAccountsCommand {
         /// Provider to list accounts for
         #[arg(long)]
         provider: Option<String>,
+
+        /// Show long labels and IDs in full instead of truncating them
+        #[arg(long)]
+        full: bool,
     },
 
     /// Convert between `CodexBar` and caut formats
@@ -566,6 +1798,103 @@ pub enum TokenAccountsCommand {
     },
 }
 
+/// Account subcommands.
+#[derive(Subcommand, Debug)]
+pub enum AccountsCommand {
+    /// Save the credentials a provider is signed in with under a name
+    Save(AccountsSaveArgs),
+    /// Swap in a saved account's credentials, or undo the last switch
+    Switch(AccountsSwitchArgs),
+}
+
+/// Arguments for `accounts save`.
+#[derive(Parser, Debug)]
+pub struct AccountsSaveArgs {
+    /// Provider whose credentials to save (claude, codex)
+    #[arg(long)]
+    pub provider: String,
+
+    /// Name to save the account under, e.g. "work"
+    #[arg(long)]
+    pub account: String,
+}
+
+/// Arguments for `accounts switch`.
+#[derive(Parser, Debug)]
+pub struct AccountsSwitchArgs {
+    /// Provider to switch (claude, codex)
+    #[arg(long)]
+    pub provider: String,
+
+    /// Saved account to switch to
+    #[arg(
+        long,
+        required_unless_present = "rollback",
+        conflicts_with = "rollback"
+    )]
+    pub account: Option<String>,
+
+    /// Switch back to the account the last switch left
+    #[arg(long)]
+    pub rollback: bool,
+}
+
+/// Session log subcommands.
+#[derive(Subcommand, Debug)]
+pub enum SessionsCommand {
+    /// List recent sessions with tokens, cost, duration, and model
+    List(SessionsListArgs),
+    /// Show one session's per-turn token and cost breakdown
+    Show(SessionsShowArgs),
+}
+
+/// Arguments for `sessions list`.
+#[derive(Parser, Debug)]
+pub struct SessionsListArgs {
+    /// Only sessions from this provider (claude, codex)
+    #[arg(long, short = 'p', value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Sessions active in the last N days (default: 7)
+    #[arg(long, value_name = "DAYS", default_value = "7")]
+    pub days: i64,
+
+    /// Maximum number of sessions to list (default: 20)
+    #[arg(long, short = 'n', value_name = "N", default_value = "20")]
+    pub limit: usize,
+}
+
+/// Arguments for `sessions show`.
+#[derive(Parser, Debug)]
+pub struct SessionsShowArgs {
+    /// Session ID, or a unique prefix of one
+    pub id: String,
+
+    /// Only look in this provider's sessions (claude, codex)
+    #[arg(long, short = 'p', value_name = "PROVIDER")]
+    pub provider: Option<String>,
+}
+
+/// Arguments for the `top` command.
+#[derive(Parser, Debug)]
+pub struct TopArgs {
+    /// Only follow this provider's sessions (claude, codex)
+    #[arg(long, short = 'p', value_name = "PROVIDER")]
+    pub provider: Option<String>,
+
+    /// Time between refreshes, e.g. 3, 10s (default: 3 seconds)
+    #[arg(long, value_name = "DURATION", default_value = "3", value_parser = parse_duration_secs)]
+    pub interval: u64,
+
+    /// Sessions whose log changed within this long count as running
+    #[arg(long, value_name = "DURATION", default_value = "15m", value_parser = parse_duration_secs)]
+    pub active: u64,
+
+    /// Print one refresh and exit
+    #[arg(long)]
+    pub once: bool,
+}
+
 /// Output format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
 pub enum OutputFormat {
@@ -581,13 +1910,33 @@ pub enum OutputFormat {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use clap::CommandFactory;
 
     #[test]
     fn cli_parses() {
         Cli::command().debug_assert();
     }
 
+    #[test]
+    fn parse_reports_subcommand_path() {
+        let (_, path) = Cli::try_parse_with_path(["caut", "history", "export", "--json"]).unwrap();
+        assert_eq!(path, "history export");
+        let (_, path) = Cli::try_parse_with_path(["caut"]).unwrap();
+        assert_eq!(path, "");
+    }
+
+    #[test]
+    fn raw_args_requesting_json_are_detected() {
+        assert!(args_request_json(&["caut", "usage", "--json"]));
+        assert!(args_request_json(&["caut", "--format", "json", "cost"]));
+        assert!(args_request_json(&["caut", "cost", "--format=json"]));
+        assert!(!args_request_json(&["caut", "--format", "md"]));
+        assert!(!args_request_json(&["caut", "query", "--", "--json"]));
+
+        let error = Cli::try_parse_with_path(["caut", "usage", "--bogus"]).unwrap_err();
+        assert!(!is_informational(&error));
+        assert!(usage_error(&error).to_string().contains("--bogus"));
+    }
+
     #[test]
     fn usage_args_validate() {
         let args = UsageArgs {
@@ -596,16 +1945,72 @@ mod tests {
             account_index: None,
             all_accounts: true,
             no_credits: false,
+            full: false,
+            links: false,
+            explain: false,
+            verify: false,
             status: false,
             source: None,
             web: false,
             timeout: None,
             web_timeout: None,
+            api_keys: Vec::new(),
             web_debug_dump_html: false,
             watch: false,
             interval: 30,
+            record: None,
+            replay: None,
             tui: false,
+            sort: None,
+            filter: Vec::new(),
+            columns: Vec::new(),
+            csv: false,
+            strict: false,
         };
         assert!(args.validate().is_err());
     }
+
+    #[test]
+    fn usage_args_parse_api_keys() {
+        let cli = Cli::try_parse_from([
+            "caut",
+            "usage",
+            "--api-key",
+            "groq=gsk_test",
+            "--api-key",
+            "cohere=",
+        ])
+        .unwrap();
+        let Some(Commands::Usage(args)) = cli.command else {
+            panic!("expected usage command");
+        };
+        assert!(args.flag_api_keys().is_err());
+
+        let mut args = args;
+        args.api_keys.pop();
+        assert_eq!(
+            args.flag_api_keys().unwrap(),
+            vec![(
+                crate::core::provider::Provider::Groq,
+                "gsk_test".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn usage_args_parse_sort_and_filters() {
+        let cli = Cli::try_parse_from([
+            "caut", "usage", "--sort", "reset", "--filter", "used>50", "--filter", "cost>=1",
+        ])
+        .unwrap();
+        let Some(Commands::Usage(args)) = cli.command else {
+            panic!("expected usage command");
+        };
+        assert_eq!(args.sort, Some(UsageSort::Reset));
+        assert_eq!(args.filter.len(), 2);
+        assert!(args.uses_cost());
+
+        assert!(Cli::try_parse_from(["caut", "usage", "--filter", "tokens>5"]).is_err());
+        assert!(Cli::try_parse_from(["caut", "usage", "--sort", "name"]).is_err());
+    }
 }
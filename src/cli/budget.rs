@@ -0,0 +1,302 @@
+//! Budget command implementation.
+//!
+//! Edits the per-provider cost limits in `budgets.toml` (see
+//! [`crate::core::budgets`]) and shows spend so far against them. `caut usage`
+//! and `caut cost` show the same consumption bars after their own output.
+
+use std::fmt::Write;
+
+use chrono::Local;
+
+use crate::cli::args::{
+    BudgetCommand, BudgetRemoveArgs, BudgetSetArgs, BudgetShowArgs, OutputFormat,
+};
+use crate::core::budgets::{self, BudgetFileConfig, BudgetPeriod, BudgetStatus};
+use crate::core::cost_scanner::CostScanner;
+use crate::core::fake_providers;
+use crate::core::models::{CostPayload, RobotOutput};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::render::{human, robot};
+use crate::storage::AppPaths;
+use crate::util::format::format_cost;
+
+/// Execute budget commands.
+///
+/// # Errors
+/// Returns an error if the provider is unknown, a limit is not a
+/// non-negative amount, the budgets file cannot be read or written, or output
+/// serialization fails.
+pub async fn execute(
+    cmd: &BudgetCommand,
+    format: OutputFormat,
+    pretty: bool,
+    no_color: bool,
+) -> Result<()> {
+    match cmd {
+        BudgetCommand::Set(args) => execute_set(args, format, pretty, no_color).await,
+        BudgetCommand::Show(args) => execute_show(args, format, pretty, no_color).await,
+        BudgetCommand::Remove(args) => execute_remove(args, format, pretty),
+    }
+}
+
+async fn execute_set(
+    args: &BudgetSetArgs,
+    format: OutputFormat,
+    pretty: bool,
+    no_color: bool,
+) -> Result<()> {
+    let target = parse_target(&args.provider)?;
+    let path = AppPaths::new().budgets_file();
+    let mut file = BudgetFileConfig::load(&path)?;
+
+    let limits = [
+        (BudgetPeriod::Daily, args.daily),
+        (BudgetPeriod::Weekly, args.weekly),
+        (BudgetPeriod::Monthly, args.monthly),
+    ];
+    for (period, usd) in limits {
+        let Some(usd) = usd else { continue };
+        if !usd.is_finite() || usd < 0.0 {
+            return Err(CautError::Config(format!(
+                "Invalid {} budget {usd}: expected a non-negative amount in USD",
+                period.label()
+            )));
+        }
+        file.set_cost_limit(target, period, usd);
+    }
+    file.save(&path)?;
+
+    let providers = target.map_or_else(|| file.budgeted_providers(), |p| vec![p]);
+    let statuses = current_statuses(&file, &providers).await;
+    if format == OutputFormat::Human {
+        println!(
+            "Saved {} budget to {}.",
+            target_label(target),
+            path.display()
+        );
+    }
+    print_statuses(&statuses, format, pretty, no_color)
+}
+
+async fn execute_show(
+    args: &BudgetShowArgs,
+    format: OutputFormat,
+    pretty: bool,
+    no_color: bool,
+) -> Result<()> {
+    let file = BudgetFileConfig::load(&AppPaths::new().budgets_file())?;
+    let mut providers = file.budgeted_providers();
+    if let Some(name) = &args.provider {
+        let provider = Provider::from_cli_name(name)?;
+        providers.retain(|p| *p == provider);
+    }
+    let statuses = current_statuses(&file, &providers).await;
+    if statuses.is_empty() && format == OutputFormat::Human {
+        println!("No budgets set. Add one with `caut budget set <provider> --daily <usd>`.");
+        return Ok(());
+    }
+    print_statuses(&statuses, format, pretty, no_color)
+}
+
+fn execute_remove(args: &BudgetRemoveArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let target = parse_target(&args.provider)?;
+    let selected: Vec<BudgetPeriod> = [
+        (BudgetPeriod::Daily, args.daily),
+        (BudgetPeriod::Weekly, args.weekly),
+        (BudgetPeriod::Monthly, args.monthly),
+    ]
+    .into_iter()
+    .filter_map(|(period, chosen)| chosen.then_some(period))
+    .collect();
+    let periods = if selected.is_empty() {
+        BudgetPeriod::ALL.to_vec()
+    } else {
+        selected
+    };
+
+    let path = AppPaths::new().budgets_file();
+    let mut file = BudgetFileConfig::load(&path)?;
+    let removed = file.remove_cost_limits(target, &periods);
+    if removed {
+        file.save(&path)?;
+    }
+
+    match format {
+        OutputFormat::Json => {
+            let data = serde_json::json!({
+                "provider": target_label(target),
+                "periods": periods,
+                "removed": removed,
+            });
+            let output = RobotOutput::new("budget", data);
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Human | OutputFormat::Md if removed => {
+            println!("Removed {} budget.", target_label(target));
+        }
+        OutputFormat::Human | OutputFormat::Md => {
+            println!("No matching {} budget to remove.", target_label(target));
+        }
+    }
+    Ok(())
+}
+
+/// Load the budgets file for `caut usage` and `caut cost`.
+///
+/// Budgets never fail those commands: a broken file is logged and skipped,
+/// as is an empty one.
+#[must_use]
+pub fn load_budgets() -> Option<BudgetFileConfig> {
+    if fake_providers::is_enabled() {
+        return None;
+    }
+    match BudgetFileConfig::load(&AppPaths::new().budgets_file()) {
+        Ok(file) if file.budgeted_providers().is_empty() => None,
+        Ok(file) => Some(file),
+        Err(e) => {
+            tracing::warn!(error = %e, "Skipping budgets: budgets file did not load");
+            None
+        }
+    }
+}
+
+/// Budget status for the budgeted providers among `providers`, with spend
+/// from `costs`.
+#[must_use]
+pub fn statuses_for(
+    file: &BudgetFileConfig,
+    providers: &[Provider],
+    costs: &[CostPayload],
+) -> Vec<BudgetStatus> {
+    let budgeted: Vec<Provider> = file
+        .budgeted_providers()
+        .into_iter()
+        .filter(|p| providers.contains(p))
+        .collect();
+    budgets::statuses_for(file, &budgeted, costs, Local::now().date_naive())
+}
+
+/// Print budget bars after human or Markdown command output.
+pub fn print_budget_section(statuses: &[BudgetStatus], markdown: bool, no_color: bool) {
+    if statuses.is_empty() {
+        return;
+    }
+    if markdown {
+        println!("\n## Budgets\n");
+        print!("{}", render_markdown(statuses));
+    } else {
+        println!("\nBudgets:");
+        print!("{}", human::render_budgets(statuses, no_color));
+    }
+}
+
+/// Status of the budgets of `providers`, scanning local cost data for spend.
+async fn current_statuses(file: &BudgetFileConfig, providers: &[Provider]) -> Vec<BudgetStatus> {
+    let scanner = CostScanner::new();
+    let mut costs = Vec::new();
+    for provider in providers.iter().filter(|p| p.supports_cost_scan()) {
+        match scanner.scan(*provider, false).await {
+            Ok(payload) => costs.push(payload),
+            Err(e) => tracing::debug!(?provider, error = %e, "No cost data for budget"),
+        }
+    }
+    budgets::statuses_for(file, providers, &costs, Local::now().date_naive())
+}
+
+fn print_statuses(
+    statuses: &[BudgetStatus],
+    format: OutputFormat,
+    pretty: bool,
+    no_color: bool,
+) -> Result<()> {
+    match format {
+        OutputFormat::Human => print!("{}", human::render_budgets(statuses, no_color)),
+        OutputFormat::Md => print!("{}", render_markdown(statuses)),
+        OutputFormat::Json => {
+            let output = RobotOutput::new("budget", statuses);
+            robot::print_json(&output, pretty)?;
+        }
+    }
+    Ok(())
+}
+
+fn render_markdown(statuses: &[BudgetStatus]) -> String {
+    let mut out = String::from("| Provider | Period | Spent | Limit | Used |\n");
+    out.push_str("|---|---|---:|---:|---:|\n");
+    for status in statuses {
+        let spent = status
+            .spent_usd
+            .map_or_else(|| "-".to_string(), format_cost);
+        let used = status.percent_used.map_or_else(
+            || "-".to_string(),
+            |p| {
+                if status.exceeded {
+                    format!("**{p:.0}%**")
+                } else {
+                    format!("{p:.0}%")
+                }
+            },
+        );
+        let _ = writeln!(
+            out,
+            "| {} | {} | {spent} | {} | {used} |",
+            status.provider.cli_name(),
+            status.period.label(),
+            format_cost(status.limit_usd)
+        );
+    }
+    out
+}
+
+/// `None` for "global", otherwise the named provider.
+fn parse_target(name: &str) -> Result<Option<Provider>> {
+    if name.eq_ignore_ascii_case("global") {
+        return Ok(None);
+    }
+    Provider::from_cli_name(name).map(Some)
+}
+
+fn target_label(target: Option<Provider>) -> &'static str {
+    target.map_or("global", Provider::cli_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::budgets::BudgetPriority;
+
+    #[test]
+    fn parses_global_and_provider_targets() {
+        assert_eq!(parse_target("GLOBAL").unwrap(), None);
+        assert_eq!(parse_target("claude").unwrap(), Some(Provider::Claude));
+        assert!(parse_target("nope").is_err());
+    }
+
+    #[test]
+    fn markdown_marks_exceeded_budgets() {
+        let statuses = vec![
+            BudgetStatus {
+                provider: Provider::Claude,
+                period: BudgetPeriod::Daily,
+                limit_usd: 10.0,
+                spent_usd: Some(12.5),
+                percent_used: Some(125.0),
+                exceeded: true,
+                source: BudgetPriority::ProviderSpecific,
+            },
+            BudgetStatus {
+                provider: Provider::Cursor,
+                period: BudgetPeriod::Monthly,
+                limit_usd: 40.0,
+                spent_usd: None,
+                percent_used: None,
+                exceeded: false,
+                source: BudgetPriority::Global,
+            },
+        ];
+        let md = render_markdown(&statuses);
+        assert!(md.contains("| claude | daily | $12.50 | $10.00 | **125%** |"));
+        assert!(md.contains("| cursor | monthly | - | $40.00 | - |"));
+    }
+}
@@ -0,0 +1,208 @@
+//! Compare command implementation.
+//!
+//! `caut compare` puts providers side by side over the last few days (see
+//! [`crate::core::compare`]): tokens, cost, cost per thousand tokens, limit
+//! pressure, and fetch failure rate, from recorded history and local cost
+//! scans.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use chrono::NaiveDate;
+
+use crate::cli::args::{CompareArgs, OutputFormat};
+use crate::cli::report::{local_day_start, recorded_snapshots, scan_costs};
+use crate::core::compare::{self, ProviderComparison};
+use crate::core::fake_providers;
+use crate::core::models::RobotOutput;
+use crate::core::provider::{Provider, ProviderSelection};
+use crate::core::report;
+use crate::error::Result;
+use crate::render::robot;
+use crate::storage::{AppPaths, HistoryStore};
+use crate::util::format::{format_cost, format_tokens};
+
+/// Execute the compare command.
+///
+/// Providers without any data in the window are left out unless named.
+///
+/// # Errors
+/// Returns an error if the provider selection is invalid, the history
+/// database cannot be read, or output serialization fails.
+pub async fn execute(args: &CompareArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let selection = args
+        .provider
+        .as_deref()
+        .map(ProviderSelection::from_arg)
+        .transpose()?
+        .unwrap_or_default();
+    let providers = selection.providers();
+    let (from, to) = report::period_range(i64::from(args.days));
+
+    let snapshots = recorded_snapshots(&providers, from)?;
+    let failures = recorded_failures(from)?;
+    let costs = scan_costs(&providers).await;
+    let mut rows = compare::compare(&providers, from, to, &snapshots, &failures, &costs);
+    if !selection.is_single() {
+        rows.retain(|row| row.fetches > 0 || row.tokens.is_some() || row.cost_usd.is_some());
+    }
+
+    match format {
+        OutputFormat::Human => print!("{}", render_text(&rows, args.days)),
+        OutputFormat::Md => print!("{}", render_markdown(&rows, args.days)),
+        OutputFormat::Json => {
+            let output = RobotOutput::new(
+                "compare",
+                serde_json::json!({ "from": from, "to": to, "providers": rows }),
+            );
+            robot::print_json(&output, pretty)?;
+        }
+    }
+    Ok(())
+}
+
+/// Failed fetches per provider recorded since the start of `from`.
+fn recorded_failures(from: NaiveDate) -> Result<HashMap<Provider, usize>> {
+    let path = AppPaths::new().history_db_file();
+    if fake_providers::is_enabled() || !path.exists() {
+        return Ok(HashMap::new());
+    }
+    HistoryStore::open(&path)?.fetch_failure_counts(local_day_start(from))
+}
+
+/// Table cells for one row, in column order.
+fn cells(row: &ProviderComparison) -> [String; 7] {
+    let dash = || "-".to_string();
+    let percent = |p: Option<f64>| p.map_or_else(dash, |p| format!("{p:.0}%"));
+    [
+        row.provider.clone(),
+        row.tokens.map_or_else(dash, format_tokens),
+        row.cost_usd.map_or_else(dash, format_cost),
+        row.usd_per_1k_tokens
+            .map_or_else(dash, |price| format!("${price:.4}")),
+        percent(row.avg_used_percent),
+        percent(row.peak_used_percent),
+        row.failure_rate.map_or_else(dash, |rate| {
+            format!("{}/{} ({:.0}%)", row.failures, row.fetches, rate * 100.0)
+        }),
+    ]
+}
+
+const HEADERS: [&str; 7] = [
+    "Provider", "Tokens", "Cost", "$/1K", "Avg used", "Peak", "Failed",
+];
+
+fn render_text(rows: &[ProviderComparison], days: u32) -> String {
+    let mut out = format!("Provider comparison: last {days} day(s)\n\n");
+    if rows.is_empty() {
+        out.push_str(
+            "No usage data in this window; run `caut usage` or `caut daemon` regularly to record it.\n",
+        );
+        return out;
+    }
+    let table: Vec<[String; 7]> = rows.iter().map(cells).collect();
+    let widths: Vec<usize> = (0..HEADERS.len())
+        .map(|i| {
+            table
+                .iter()
+                .map(|row| row[i].len())
+                .chain([HEADERS[i].len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let header: Vec<String> = HEADERS
+        .iter()
+        .zip(&widths)
+        .enumerate()
+        .map(|(i, (h, w))| {
+            if i == 0 {
+                format!("{h:<w$}")
+            } else {
+                format!("{h:>w$}")
+            }
+        })
+        .collect();
+    let _ = writeln!(out, "{}", header.join("  ").trim_end());
+    for row in &table {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (c, w))| {
+                if i == 0 {
+                    format!("{c:<w$}")
+                } else {
+                    format!("{c:>w$}")
+                }
+            })
+            .collect();
+        let _ = writeln!(out, "{}", line.join("  ").trim_end());
+    }
+    out.push_str(
+        "\nAvg used and Peak are the most-used rate window per snapshot; Failed counts recorded fetches.\n",
+    );
+    out
+}
+
+fn render_markdown(rows: &[ProviderComparison], days: u32) -> String {
+    let mut out = format!(
+        "# Provider comparison: last {days} day(s)\n\n| {} |\n|{}\n",
+        HEADERS.join(" | "),
+        "---|".repeat(HEADERS.len())
+    );
+    for row in rows {
+        let _ = writeln!(out, "| {} |", cells(row).join(" | "));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_table_aligns_columns() {
+        let rows = [
+            ProviderComparison {
+                provider: "claude".to_string(),
+                tokens: Some(2_000_000),
+                cost_usd: Some(4.0),
+                usd_per_1k_tokens: Some(0.002),
+                avg_used_percent: Some(53.4),
+                peak_used_percent: Some(80.0),
+                fetches: 4,
+                failures: 1,
+                failure_rate: Some(0.25),
+            },
+            ProviderComparison {
+                provider: "codex".to_string(),
+                tokens: None,
+                cost_usd: None,
+                usd_per_1k_tokens: None,
+                avg_used_percent: None,
+                peak_used_percent: None,
+                fetches: 0,
+                failures: 0,
+                failure_rate: None,
+            },
+        ];
+        let text = render_text(&rows, 7);
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "Provider comparison: last 7 day(s)");
+        assert_eq!(
+            lines[2],
+            "Provider  Tokens   Cost     $/1K  Avg used  Peak     Failed"
+        );
+        assert_eq!(
+            lines[3],
+            "claude      2.0M  $4.00  $0.0020       53%   80%  1/4 (25%)"
+        );
+        assert_eq!(
+            lines[4],
+            "codex          -      -        -         -     -          -"
+        );
+    }
+}
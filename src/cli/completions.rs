@@ -0,0 +1,124 @@
+//! Completions command implementation.
+//!
+//! `caut completions <shell>` prints a completion script generated by
+//! `clap_complete`. Besides subcommands and flags, it completes `--provider`
+//! with provider names and `--account` with the labels in the token account
+//! store. The labels are read when the script is generated, so loading it
+//! from shell init (see the README) keeps them current in every new shell.
+
+use std::collections::BTreeSet;
+use std::io::Write;
+
+use clap::CommandFactory;
+use clap::builder::PossibleValuesParser;
+
+use crate::cli::args::{Cli, CompletionsArgs};
+use crate::core::provider::Provider;
+use crate::error::Result;
+use crate::storage::AppPaths;
+use crate::storage::token_accounts::TokenAccountStore;
+
+/// Execute the completions command.
+///
+/// An unreadable token account store only means no account labels are
+/// completed.
+///
+/// # Errors
+/// Returns an error if the script cannot be written to stdout.
+pub fn execute(args: &CompletionsArgs) -> Result<()> {
+    let mut command = with_candidates(Cli::command(), &provider_names(), &account_labels());
+    // Generate into a buffer: `generate` panics if the writer fails, e.g. on
+    // a closed pipe.
+    let mut script = Vec::new();
+    clap_complete::generate(args.shell, &mut command, "caut", &mut script);
+    std::io::stdout().write_all(&script)?;
+    Ok(())
+}
+
+/// Provider names plus the `both` and `all` selections.
+fn provider_names() -> Vec<String> {
+    Provider::ALL
+        .iter()
+        .map(|p| p.cli_name().to_string())
+        .chain(["both".to_string(), "all".to_string()])
+        .collect()
+}
+
+/// Account labels from the token account store, across providers.
+fn account_labels() -> Vec<String> {
+    let store = match TokenAccountStore::load(&AppPaths::new().token_accounts_file()) {
+        Ok(store) => store,
+        Err(e) => {
+            tracing::debug!(error = %e, "Token accounts not readable, skipping labels");
+            return Vec::new();
+        }
+    };
+    let labels: BTreeSet<String> = Provider::ALL
+        .iter()
+        .flat_map(|p| store.get_all(*p))
+        .map(|account| account.label.clone())
+        .filter(|label| !label.is_empty())
+        .collect();
+    labels.into_iter().collect()
+}
+
+/// Give every `provider` and `account` argument in the command tree the
+/// candidates as possible values. This only affects the generated script;
+/// parsing still uses the command as declared.
+fn with_candidates(
+    command: clap::Command,
+    providers: &[String],
+    accounts: &[String],
+) -> clap::Command {
+    command
+        .mut_args(|arg| {
+            if !arg.get_action().takes_values() {
+                return arg;
+            }
+            match arg.get_id().as_str() {
+                "provider" => arg.value_parser(PossibleValuesParser::new(providers)),
+                "account" if !accounts.is_empty() => {
+                    arg.value_parser(PossibleValuesParser::new(accounts))
+                }
+                _ => arg,
+            }
+        })
+        .mut_subcommands(|sub| with_candidates(sub, providers, accounts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provider_and_account_args_complete_candidates() {
+        let accounts = vec!["personal".to_string(), "work".to_string()];
+        let command = with_candidates(Cli::command(), &provider_names(), &accounts);
+
+        let usage = command.find_subcommand("usage").unwrap();
+        let values = |id: &str| -> Vec<String> {
+            usage
+                .get_arguments()
+                .find(|arg| arg.get_id() == id)
+                .unwrap()
+                .get_possible_values()
+                .iter()
+                .map(|v| v.get_name().to_string())
+                .collect()
+        };
+        assert!(values("provider").contains(&"claude".to_string()));
+        assert!(values("provider").contains(&"all".to_string()));
+        assert_eq!(values("account"), accounts);
+
+        let mut script = Vec::new();
+        let mut command = command;
+        clap_complete::generate(
+            clap_complete::Shell::Bash,
+            &mut command,
+            "caut",
+            &mut script,
+        );
+        let script = String::from_utf8(script).unwrap();
+        assert!(script.contains("personal work"));
+    }
+}
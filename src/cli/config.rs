@@ -0,0 +1,268 @@
+//! Config command implementation.
+//!
+//! `caut config show` prints the settings every command resolves from flags,
+//! environment variables, and the config file, with where each came from.
+//! `get`, `set`, `edit`, and `path` work on the config file itself, and
+//! `validate` checks it: TOML syntax, values (see [`Config::validate`]), and
+//! keys caut does not recognize (see [`crate::storage::config_schema`]).
+
+use serde::Serialize;
+
+use crate::cli::args::{Cli, ConfigCommand, ConfigValidateArgs, OutputFormat};
+use crate::core::models::RobotOutput;
+use crate::error::{CautError, Result};
+use crate::render::robot;
+use crate::storage::config_schema::UnknownKey;
+use crate::storage::{Config, ConfigSource, ResolvedConfig};
+
+/// Result of `caut config validate`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ValidateReport {
+    location: String,
+    strict: bool,
+    unknown_keys: Vec<UnknownKey>,
+}
+
+/// One resolved setting for `caut config show`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolvedSetting {
+    name: &'static str,
+    value: String,
+    source: String,
+}
+
+/// Execute config commands.
+///
+/// # Errors
+/// Returns an error if the config is not valid TOML, a value or key is
+/// invalid, a key is unknown under `--strict`, the editor cannot be run, the
+/// config file cannot be written, or output serialization fails.
+pub fn execute(cmd: &ConfigCommand, cli: &Cli, format: OutputFormat, pretty: bool) -> Result<()> {
+    match cmd {
+        ConfigCommand::Show => execute_show(cli, format, pretty),
+        ConfigCommand::Get { key } => execute_get(key, format, pretty),
+        ConfigCommand::Set { key, value } => execute_set(key, value, format, pretty),
+        ConfigCommand::Edit => execute_edit(),
+        ConfigCommand::Path => {
+            let path = Config::local_path();
+            if format == OutputFormat::Json {
+                return robot::print_json(
+                    &RobotOutput::new(
+                        "config",
+                        serde_json::json!({ "path": path, "exists": path.exists() }),
+                    ),
+                    pretty,
+                );
+            }
+            println!("{}", path.display());
+            Ok(())
+        }
+        ConfigCommand::Validate(args) => execute_validate(args, format, pretty),
+    }
+}
+
+fn execute_show(cli: &Cli, format: OutputFormat, pretty: bool) -> Result<()> {
+    let resolved = ResolvedConfig::resolve(cli, None)?;
+    let config = Config::load()?;
+    let settings = resolved_settings(&resolved);
+
+    if format == OutputFormat::Json {
+        return robot::print_json(
+            &RobotOutput::new(
+                "config",
+                serde_json::json!({
+                    "location": Config::location(),
+                    "resolved": settings,
+                    "config": config,
+                }),
+            ),
+            pretty,
+        );
+    }
+
+    println!("Config file: {}\n", Config::location());
+    println!("Resolved settings:");
+    for setting in &settings {
+        println!(
+            "  {:<16} {:<24} ({})",
+            setting.name, setting.value, setting.source
+        );
+    }
+    let content = toml::to_string_pretty(&config)
+        .map_err(|e| CautError::Config(format!("Failed to serialize config: {e}")))?;
+    if format == OutputFormat::Md {
+        println!("\n```toml\n{content}```");
+    } else {
+        println!("\nEffective config (file merged with defaults):\n\n{content}");
+    }
+    Ok(())
+}
+
+fn resolved_settings(resolved: &ResolvedConfig) -> Vec<ResolvedSetting> {
+    let setting = |name, value: String, source: ConfigSource| ResolvedSetting {
+        name,
+        value,
+        source: source.to_string(),
+    };
+    let providers: Vec<&str> = resolved.providers.iter().map(|p| p.cli_name()).collect();
+    let format = match resolved.format {
+        OutputFormat::Human => "human",
+        OutputFormat::Json => "json",
+        OutputFormat::Md => "md",
+    };
+    let sources = &resolved.sources;
+    vec![
+        setting("providers", providers.join(", "), sources.providers),
+        setting("format", format.to_string(), sources.format),
+        setting(
+            "timeout",
+            format!("{}s", resolved.timeout.as_secs()),
+            sources.timeout,
+        ),
+        setting("no_color", resolved.no_color.to_string(), sources.no_color),
+        setting("verbose", resolved.verbose.to_string(), sources.verbose),
+        setting("pretty", resolved.pretty.to_string(), sources.pretty),
+        setting(
+            "include_status",
+            resolved.include_status.to_string(),
+            sources.include_status,
+        ),
+    ]
+}
+
+fn execute_get(key: &str, format: OutputFormat, pretty: bool) -> Result<()> {
+    let value = Config::load()?.get_value(key)?;
+    if format == OutputFormat::Json {
+        return robot::print_json(
+            &RobotOutput::new("config", serde_json::json!({ "key": key, "value": value })),
+            pretty,
+        );
+    }
+    match value {
+        Some(toml::Value::String(s)) => println!("{s}"),
+        Some(value) => println!("{value}"),
+        None => println!("{key} is not set"),
+    }
+    Ok(())
+}
+
+fn execute_set(key: &str, raw: &str, format: OutputFormat, pretty: bool) -> Result<()> {
+    let path = Config::local_path();
+    let value = parse_value(raw);
+    Config::set_value(&path, key, &value)?;
+    if format == OutputFormat::Json {
+        return robot::print_json(
+            &RobotOutput::new(
+                "config",
+                serde_json::json!({ "key": key, "value": value, "path": path }),
+            ),
+            pretty,
+        );
+    }
+    println!("Set {key} = {value} in {}", path.display());
+    Ok(())
+}
+
+/// A value typed on the command line: TOML when it parses as TOML (numbers,
+/// booleans, arrays, quoted strings), otherwise a plain string.
+fn parse_value(raw: &str) -> toml::Value {
+    toml::from_str::<toml::Table>(&format!("value = {raw}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(raw.to_string()))
+}
+
+fn execute_edit() -> Result<()> {
+    let path = Config::local_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| if cfg!(windows) { "notepad" } else { "vi" }.to_string());
+
+    // Editors are often configured with arguments, e.g. "code --wait".
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vi");
+    let status = std::process::Command::new(program)
+        .args(words)
+        .arg(&path)
+        .status()
+        .map_err(|e| CautError::Config(format!("Failed to run editor \"{editor}\": {e}")))?;
+    if !status.success() {
+        return Err(CautError::Config(format!(
+            "Editor \"{editor}\" exited with {status}"
+        )));
+    }
+
+    for key in Config::unknown_keys()? {
+        eprintln!("Warning: {key}");
+    }
+    Config::load()?.validate()?;
+    println!("Config is valid: {}", path.display());
+    Ok(())
+}
+
+fn execute_validate(args: &ConfigValidateArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let location = Config::location();
+    let unknown_keys = Config::unknown_keys()?;
+    if format != OutputFormat::Json {
+        for key in &unknown_keys {
+            eprintln!("Warning: {key}");
+        }
+    }
+
+    let config = if args.strict {
+        Config::load_strict()?
+    } else {
+        Config::load()?
+    };
+    config.validate()?;
+
+    if format == OutputFormat::Json {
+        return robot::print_json(
+            &RobotOutput::new(
+                "config",
+                ValidateReport {
+                    location,
+                    strict: args.strict,
+                    unknown_keys,
+                },
+            ),
+            pretty,
+        );
+    }
+    if unknown_keys.is_empty() {
+        println!("Config is valid: {location}");
+    } else {
+        println!(
+            "Config is valid with {} unknown key(s) ignored: {location}",
+            unknown_keys.len()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_command_line_values_as_toml_or_string() {
+        assert_eq!(parse_value("60"), toml::Value::Integer(60));
+        assert_eq!(parse_value("false"), toml::Value::Boolean(false));
+        assert_eq!(parse_value("json"), toml::Value::String("json".to_string()));
+        assert_eq!(
+            parse_value("\"with space\""),
+            toml::Value::String("with space".to_string())
+        );
+        assert_eq!(
+            parse_value(r#"["claude", "codex"]"#),
+            toml::Value::Array(vec!["claude".into(), "codex".into()])
+        );
+    }
+}
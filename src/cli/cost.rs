@@ -1,17 +1,41 @@
 //! Cost command implementation.
 
+use std::path::Path;
+
+use chrono::{DateTime, Duration, Local, Utc};
+
 use crate::cli::args::{CostArgs, OutputFormat};
+use crate::cli::budget;
 use crate::core::cost_scanner::CostScanner;
+use crate::core::fake_providers;
+use crate::core::git_cost::{self, GitCostReport, SessionSpan};
 use crate::core::models::{CostPayload, RobotOutput};
-use crate::core::provider::ProviderSelection;
+use crate::core::policy::{self, PolicyViolation};
+use crate::core::pricing::SessionCostCalculator;
+use crate::core::provider::{Provider, ProviderSelection};
+use crate::core::session_logs::SessionLogFinder;
 use crate::error::{CautError, Result};
+use crate::providers::claude;
 use crate::render::{human, robot};
+use crate::storage::config::Config;
+use crate::util::format::{format_cost, truncate_to_width};
+use crate::util::time::parse_humane_datetime;
+
+/// Default look-back window for `cost --git`.
+const GIT_DEFAULT_SINCE: &str = "30d";
+
+/// Maximum display width of commit subjects in `cost --git` output.
+const GIT_SUBJECT_WIDTH: usize = 50;
+
+/// Days of session logs checked against policy rules (matches the cost window).
+const POLICY_WINDOW_DAYS: i64 = 30;
 
 /// Execute the cost command.
 ///
 /// # Errors
 /// Returns an error if no selected providers support cost scanning, if
-/// the cost scan fails, or if output serialization fails.
+/// the cost scan fails, if `--git` is used outside a git repository, or if
+/// output serialization fails.
 pub async fn execute(
     args: &CostArgs,
     format: OutputFormat,
@@ -35,11 +59,15 @@ pub async fn execute(
 
     if providers.is_empty() {
         return Err(CautError::Config(
-            "No selected providers support local cost scanning. Only Claude and Codex are supported."
+            "No selected providers support local cost scanning. Only Claude, Codex, Gemini, Aider, and Cline are supported."
                 .to_string(),
         ));
     }
 
+    if args.git {
+        return execute_git(args, &providers, format, pretty).await;
+    }
+
     tracing::debug!(
         ?providers,
         refresh = args.refresh,
@@ -53,6 +81,10 @@ pub async fn execute(
     let mut errors: Vec<String> = Vec::new();
 
     for provider in &providers {
+        if fake_providers::is_enabled() {
+            results.push(fake_providers::cost_payload(*provider));
+            continue;
+        }
         match scanner.scan(*provider, args.refresh).await {
             Ok(payload) => results.push(payload),
             Err(e) => {
@@ -60,13 +92,34 @@ pub async fn execute(
                 errors.push(format!("{}: {}", provider.cli_name(), e));
             }
         }
+        if *provider == Provider::Claude {
+            for install in claude::installations::extra() {
+                match scanner.scan_claude_installation(&install) {
+                    Ok(payload) => results.push(payload),
+                    Err(e) => errors.push(format!("{}: {}", install.label("claude"), e)),
+                }
+            }
+        }
     }
 
+    let violations = policy_violations(&providers);
+    let budgets = budget::load_budgets()
+        .map(|file| budget::statuses_for(&file, &providers, &results))
+        .unwrap_or_default();
+
     // Render output based on format
     match format {
         OutputFormat::Human => {
             let output = human::render_cost(&results, no_color)?;
             print!("{output}");
+            budget::print_budget_section(&budgets, false, no_color);
+
+            if !violations.is_empty() {
+                println!("\nPolicy violations:");
+                for violation in &violations {
+                    println!("  {}", violation.message());
+                }
+            }
 
             // Print errors to stderr
             for error in &errors {
@@ -74,18 +127,22 @@ pub async fn execute(
             }
         }
         OutputFormat::Json => {
-            let robot_output = RobotOutput::cost(results, errors);
-            let json = if pretty {
-                serde_json::to_string_pretty(&robot_output)
-            } else {
-                serde_json::to_string(&robot_output)
-            }
-            .map_err(|e| CautError::Config(format!("Failed to serialize JSON: {e}")))?;
-            println!("{json}");
+            let robot_output = RobotOutput::cost(results, errors)
+                .with_policy_violations(violations)
+                .with_budgets(budgets);
+            robot::print_json(&robot_output, pretty)?;
         }
         OutputFormat::Md => {
             let output = robot::render_markdown_cost(&results)?;
             print!("{output}");
+            budget::print_budget_section(&budgets, true, no_color);
+
+            if !violations.is_empty() {
+                println!("\n## Policy Violations\n");
+                for violation in &violations {
+                    println!("- {}", violation.message());
+                }
+            }
 
             // Print errors as markdown
             if !errors.is_empty() {
@@ -99,3 +156,192 @@ pub async fn execute(
 
     Ok(())
 }
+
+/// Check recent session logs against the configured policy rules.
+///
+/// Policy problems never fail the cost command; they are logged instead.
+fn policy_violations(providers: &[Provider]) -> Vec<PolicyViolation> {
+    if fake_providers::is_enabled() {
+        return Vec::new();
+    }
+    let rules = match Config::load() {
+        Ok(config) => config.policy.rules,
+        Err(e) => {
+            tracing::debug!(error = %e, "Skipping policy check: config did not load");
+            return Vec::new();
+        }
+    };
+    let since = Utc::now() - Duration::days(POLICY_WINDOW_DAYS);
+    policy::scan_sessions(&rules, providers, since).unwrap_or_else(|e| {
+        tracing::warn!(error = %e, "Failed to check session logs against policy");
+        Vec::new()
+    })
+}
+
+/// Attribute session cost to commits and PRs in a git repository.
+async fn execute_git(
+    args: &CostArgs,
+    providers: &[Provider],
+    format: OutputFormat,
+    pretty: bool,
+) -> Result<()> {
+    let since = parse_humane_datetime(args.since.as_deref().unwrap_or(GIT_DEFAULT_SINCE))?;
+    let start_dir = match &args.repo {
+        Some(path) => path.clone(),
+        None => std::env::current_dir()?,
+    };
+
+    let repo = git_cost::repo_root(&start_dir).await?;
+    let commits = git_cost::read_commits(&repo, since).await?;
+    let sessions = collect_repo_sessions(&repo, providers, since)?;
+    tracing::debug!(
+        repo = %repo.display(),
+        commits = commits.len(),
+        sessions = sessions.len(),
+        "Correlating session cost with git history"
+    );
+
+    let report = git_cost::attribute_costs(&repo, since, &commits, &sessions);
+
+    match format {
+        OutputFormat::Json => {
+            let output = RobotOutput::new("cost git", report);
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Md => print!("{}", render_git_markdown(&report)),
+        OutputFormat::Human => print!("{}", render_git_human(&report)),
+    }
+
+    Ok(())
+}
+
+/// Collect session spans whose working directory is inside `repo`.
+fn collect_repo_sessions(
+    repo: &Path,
+    providers: &[Provider],
+    since: DateTime<Utc>,
+) -> Result<Vec<SessionSpan>> {
+    let finder = SessionLogFinder::new()?;
+    let calc = SessionCostCalculator::new();
+    let mut spans = Vec::new();
+
+    for provider in providers {
+        for log in finder.find_sessions(*provider, Some(since), None) {
+            let usage = match log.parse() {
+                Ok(usage) => usage,
+                Err(e) => {
+                    tracing::debug!(path = %log.path.display(), error = %e, "Skipping session log");
+                    continue;
+                }
+            };
+            let (Some(cwd), Some(started_at), Some(ended_at)) =
+                (usage.cwd.as_ref(), usage.started_at, usage.ended_at)
+            else {
+                continue;
+            };
+            if !cwd.starts_with(repo) {
+                continue;
+            }
+            spans.push(SessionSpan {
+                started_at,
+                ended_at,
+                cost_usd: calc.calculate(&usage).total_usd,
+            });
+        }
+    }
+
+    Ok(spans)
+}
+
+fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
+
+fn local_date(at: DateTime<Utc>) -> String {
+    at.with_timezone(&Local).format("%Y-%m-%d").to_string()
+}
+
+fn render_git_human(report: &GitCostReport) -> String {
+    let mut out = format!(
+        "Agent cost by commit: {} (since {})\n\n",
+        report.repo,
+        local_date(report.since)
+    );
+
+    let credited: Vec<_> = report
+        .commits
+        .iter()
+        .filter(|c| c.session_count > 0)
+        .collect();
+    if credited.is_empty() {
+        out.push_str("  No commits overlap with agent sessions in this repository.\n");
+    }
+    for commit in &credited {
+        out.push_str(&format!(
+            "  {}  {}  {:>9}  {}\n",
+            short_sha(&commit.sha),
+            local_date(commit.committed_at),
+            format_cost(commit.cost_usd),
+            truncate_to_width(&commit.subject, GIT_SUBJECT_WIDTH)
+        ));
+    }
+
+    if !report.pull_requests.is_empty() {
+        out.push_str("\nMerged PRs:\n");
+        for pr in &report.pull_requests {
+            out.push_str(&format!(
+                "  #{:<6} {:>3} commit(s)  {:>9}\n",
+                pr.number,
+                pr.commit_count,
+                format_cost(pr.cost_usd)
+            ));
+        }
+    }
+
+    out.push_str(&format!(
+        "\nAttributed {} from {} session(s); {} had no matching commit.\n",
+        format_cost(report.attributed_cost_usd),
+        report.session_count,
+        format_cost(report.unattributed_cost_usd)
+    ));
+    out
+}
+
+fn render_git_markdown(report: &GitCostReport) -> String {
+    let mut out = format!(
+        "# Agent Cost by Commit\n\n**Repository:** `{}`  \n**Since:** {}\n\n",
+        report.repo,
+        local_date(report.since)
+    );
+
+    out.push_str("| Commit | Date | Cost | Sessions | Subject |\n");
+    out.push_str("|--------|------|------|----------|---------|\n");
+    for commit in report.commits.iter().filter(|c| c.session_count > 0) {
+        out.push_str(&format!(
+            "| `{}` | {} | {} | {} | {} |\n",
+            short_sha(&commit.sha),
+            local_date(commit.committed_at),
+            format_cost(commit.cost_usd),
+            commit.session_count,
+            commit.subject.replace('|', "\\|")
+        ));
+    }
+
+    if !report.pull_requests.is_empty() {
+        out.push_str("\n## Merged PRs\n\n| PR | Commits | Cost |\n|----|---------|------|\n");
+        for pr in &report.pull_requests {
+            out.push_str(&format!(
+                "| #{} | {} | {} |\n",
+                pr.number,
+                pr.commit_count,
+                format_cost(pr.cost_usd)
+            ));
+        }
+    }
+
+    out.push_str(&format!(
+        "\n- attributed_cost_usd: {:.2}\n- unattributed_cost_usd: {:.2}\n- sessions: {}\n",
+        report.attributed_cost_usd, report.unattributed_cost_usd, report.session_count
+    ));
+    out
+}
@@ -0,0 +1,281 @@
+//! Daemon command implementation.
+//!
+//! `caut daemon` fetches usage on a schedule without an HTTP server. Each
+//! fetch goes through the same path as `caut usage`, so it records snapshots
+//! to history, evaluates alerts, and refreshes the cache that `caut prompt`
+//! and `caut statusline` answer from instantly. Budgets that become exceeded
+//! are logged once per period.
+//!
+//! `--install` writes a systemd user unit (Linux) or launchd agent (macOS)
+//! that starts the daemon at login, instead of running it.
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use directories::BaseDirs;
+use tokio::time::{Duration, sleep};
+
+use crate::cli::args::DaemonArgs;
+use crate::cli::usage::fetch_usage;
+use crate::core::budgets::BudgetStatus;
+use crate::core::power::PowerScheduler;
+use crate::core::provider::ProviderSelection;
+use crate::error::{CautError, Result};
+use crate::storage::config::{Config, ENV_CONFIG};
+
+/// Name of the systemd unit.
+const SERVICE_NAME: &str = "caut";
+/// launchd label of the agent.
+const LAUNCHD_LABEL: &str = "com.steipete.caut";
+
+/// Execute the daemon command.
+///
+/// # Errors
+/// Returns an error if the provider selection is invalid, or with
+/// `--install`, if the platform has no supported service manager or the
+/// service file cannot be written.
+pub async fn execute(args: &DaemonArgs) -> Result<()> {
+    // Check the selection up front: a service retrying a typo forever helps
+    // nobody.
+    if let Some(provider) = &args.provider {
+        ProviderSelection::from_arg(provider)?;
+    }
+    if args.install {
+        return install(args);
+    }
+
+    let usage_args = args.to_usage_args();
+    let config = Config::load().unwrap_or_default();
+    let mut power = PowerScheduler::new(config.general.power_aware);
+    let interval = Duration::from_secs(args.interval);
+    let mut exceeded = BTreeSet::new();
+
+    eprintln!(
+        "caut daemon fetching every {}s. Press Ctrl+C to stop.",
+        args.interval
+    );
+    loop {
+        if let Some(message) = power.update().await {
+            eprintln!("{message}");
+        }
+        match fetch_usage(&usage_args).await {
+            Ok(results) => {
+                for error in &results.errors {
+                    tracing::warn!(%error, "Provider fetch failed");
+                }
+                for message in newly_exceeded(&results.budgets, &mut exceeded) {
+                    eprintln!("{message}");
+                }
+                tracing::info!(
+                    providers = results.payloads.len(),
+                    failed = results.errors.len(),
+                    "Daemon fetch complete"
+                );
+            }
+            Err(e) => tracing::warn!("Daemon fetch failed: {}", e),
+        }
+        sleep(power.interval(interval)).await;
+    }
+}
+
+/// Messages for budgets exceeded since the last fetch. `seen` holds the
+/// budgets already reported; one drops out when its period rolls over, so
+/// it is reported again if exceeded in the next.
+fn newly_exceeded(statuses: &[BudgetStatus], seen: &mut BTreeSet<String>) -> Vec<String> {
+    let mut messages = Vec::new();
+    let mut current = BTreeSet::new();
+    for status in statuses.iter().filter(|s| s.exceeded) {
+        let key = format!("{}:{}", status.provider.cli_name(), status.period.label());
+        if !seen.contains(&key) {
+            messages.push(format!(
+                "caut: {} {} budget exceeded (${:.2} of ${:.2})",
+                status.provider.cli_name(),
+                status.period.label(),
+                status.spent_usd.unwrap_or_default(),
+                status.limit_usd
+            ));
+        }
+        current.insert(key);
+    }
+    *seen = current;
+    messages
+}
+
+/// Write the service file for this platform and print how to start it.
+fn install(args: &DaemonArgs) -> Result<()> {
+    let mut command = vec![
+        std::env::current_exe()?.display().to_string(),
+        "daemon".to_string(),
+        "--interval".to_string(),
+        args.interval.to_string(),
+    ];
+    if let Some(provider) = &args.provider {
+        command.extend(["--provider".to_string(), provider.clone()]);
+    }
+    if let Some(source) = &args.source {
+        command.extend(["--source".to_string(), source.clone()]);
+    }
+    let config = Config::location();
+
+    let base = BaseDirs::new()
+        .ok_or_else(|| CautError::Config("Could not determine the home directory".to_string()))?;
+    let (path, content, start): (PathBuf, String, String) = if cfg!(target_os = "macos") {
+        let path = base
+            .home_dir()
+            .join("Library/LaunchAgents")
+            .join(format!("{LAUNCHD_LABEL}.plist"));
+        let start = format!("launchctl load -w {}", path.display());
+        (path, launchd_plist(&command, &config), start)
+    } else if cfg!(target_os = "linux") {
+        let path = base
+            .config_dir()
+            .join("systemd/user")
+            .join(format!("{SERVICE_NAME}.service"));
+        let start = format!(
+            "systemctl --user daemon-reload && systemctl --user enable --now {SERVICE_NAME}"
+        );
+        (path, systemd_unit(&command, &config), start)
+    } else {
+        return Err(CautError::Config(
+            "caut daemon --install supports systemd (Linux) and launchd (macOS); \
+             run `caut daemon` from your own service manager instead"
+                .to_string(),
+        ));
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, content)?;
+    println!("Wrote {}", path.display());
+    println!("Start it with: {start}");
+    Ok(())
+}
+
+/// A systemd user unit running `command`.
+fn systemd_unit(command: &[String], config: &str) -> String {
+    let exec = command
+        .iter()
+        .map(|arg| systemd_quote(arg))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "[Unit]\n\
+         Description=caut usage polling daemon\n\
+         \n\
+         [Service]\n\
+         ExecStart={exec}\n\
+         Environment={}\n\
+         Restart=on-failure\n\
+         RestartSec=30\n\
+         \n\
+         [Install]\n\
+         WantedBy=default.target\n",
+        systemd_quote(&format!("{ENV_CONFIG}={config}"))
+    )
+}
+
+/// Quote an argument for a systemd unit line when it needs it. `%` starts
+/// a specifier in unit files, so it is doubled either way.
+fn systemd_quote(arg: &str) -> String {
+    let arg = arg.replace('%', "%%");
+    if arg
+        .chars()
+        .any(|c| c.is_whitespace() || c == '"' || c == '\\')
+    {
+        format!("\"{}\"", arg.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        arg
+    }
+}
+
+/// A launchd agent plist running `command` at login.
+fn launchd_plist(command: &[String], config: &str) -> String {
+    let arguments: String = command
+        .iter()
+        .map(|arg| format!("        <string>{}</string>\n", xml_escape(arg)))
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{LAUNCHD_LABEL}</string>
+    <key>ProgramArguments</key>
+    <array>
+{arguments}    </array>
+    <key>EnvironmentVariables</key>
+    <dict>
+        <key>{ENV_CONFIG}</key>
+        <string>{}</string>
+    </dict>
+    <key>RunAtLoad</key>
+    <true/>
+    <key>KeepAlive</key>
+    <dict>
+        <key>SuccessfulExit</key>
+        <false/>
+    </dict>
+</dict>
+</plist>
+"#,
+        xml_escape(config)
+    )
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::budgets::{BudgetPeriod, BudgetPriority};
+    use crate::core::provider::Provider;
+
+    fn status(exceeded: bool) -> BudgetStatus {
+        BudgetStatus {
+            provider: Provider::Claude,
+            period: BudgetPeriod::Daily,
+            limit_usd: 10.0,
+            spent_usd: Some(if exceeded { 12.3 } else { 4.0 }),
+            percent_used: Some(if exceeded { 123.0 } else { 40.0 }),
+            exceeded,
+            source: BudgetPriority::ProviderSpecific,
+        }
+    }
+
+    #[test]
+    fn exceeded_budget_is_reported_once_per_period() {
+        let mut seen = BTreeSet::new();
+        assert_eq!(
+            newly_exceeded(&[status(true)], &mut seen),
+            vec!["caut: claude daily budget exceeded ($12.30 of $10.00)"]
+        );
+        assert!(newly_exceeded(&[status(true)], &mut seen).is_empty());
+
+        // A new period starts under budget, so the next overrun is reported.
+        assert!(newly_exceeded(&[status(false)], &mut seen).is_empty());
+        assert_eq!(newly_exceeded(&[status(true)], &mut seen).len(), 1);
+    }
+
+    #[test]
+    fn service_files_run_the_daemon() {
+        let command = vec![
+            "/Users/me/My Tools/caut".to_string(),
+            "daemon".to_string(),
+            "--interval".to_string(),
+            "300".to_string(),
+        ];
+        let unit = systemd_unit(&command, "/home/me/.config/caut/config.toml");
+        assert!(unit.contains("ExecStart=\"/Users/me/My Tools/caut\" daemon --interval 300\n"));
+        assert!(unit.contains("WantedBy=default.target"));
+
+        let plist = launchd_plist(&command, "/tmp/a&b.toml");
+        assert!(plist.contains("<string>/Users/me/My Tools/caut</string>"));
+        assert!(plist.contains("<string>/tmp/a&amp;b.toml</string>"));
+    }
+}
@@ -0,0 +1,307 @@
+//! Diff command implementation.
+//!
+//! `caut diff --from "2h ago"` compares the recorded snapshots nearest two
+//! points in time and prints what was consumed in between: percentage points
+//! of each rate window, spend, and credits. A window that reset in between
+//! is measured by adding up its rises rather than subtracting the ends, so a
+//! work session that spans a reset is still counted in full.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Local, Utc};
+use serde::Serialize;
+
+use crate::cli::args::{DiffArgs, OutputFormat};
+use crate::core::models::RobotOutput;
+use crate::core::prediction::UsageWindow;
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::render::robot;
+use crate::storage::{AppPaths, HistoryStore, StoredSnapshot};
+use crate::util::format::format_cost;
+use crate::util::time::parse_humane_datetime;
+
+/// A drop smaller than this between snapshots is noise, not a reset.
+const RESET_DROP_PCT: f64 = 1.0;
+
+/// Change in one rate window.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WindowDiff {
+    window: &'static str,
+    from_percent: f64,
+    to_percent: f64,
+    /// Percentage points used in between, counting across resets.
+    consumed: f64,
+    resets: usize,
+}
+
+/// What one provider consumed between two snapshots.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SnapshotDiff {
+    provider: String,
+    from_at: DateTime<Utc>,
+    to_at: DateTime<Utc>,
+    windows: Vec<WindowDiff>,
+    cost_usd: Option<f64>,
+    credits_used: Option<f64>,
+}
+
+/// Execute the diff command.
+///
+/// # Errors
+/// Returns an error if a time or provider cannot be parsed, `--from` is not
+/// before `--to`, or the history database is missing or cannot be read.
+pub fn execute(args: &DiffArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let from = parse_humane_datetime(&args.from)?;
+    let to = parse_humane_datetime(&args.to)?;
+    if from >= to {
+        return Err(CautError::Config("--from must be before --to".to_string()));
+    }
+    let provider = args
+        .provider
+        .as_deref()
+        .map(Provider::from_cli_name)
+        .transpose()?;
+
+    let history_path = AppPaths::new().history_db_file();
+    if !history_path.exists() {
+        return Err(CautError::Config(
+            "No history database found. Run `caut usage` to start collecting data.".to_string(),
+        ));
+    }
+    let store = HistoryStore::open(&history_path)?;
+    let mut providers: Vec<Provider> = match provider {
+        Some(provider) => vec![provider],
+        None => store.get_latest_all()?.into_keys().collect(),
+    };
+    providers.sort_by_key(|p| p.cli_name());
+
+    let mut diffs = Vec::new();
+    for provider in providers {
+        let Some(end) = store.get_snapshot_at(&provider, to)? else {
+            continue;
+        };
+        // With nothing recorded before `from`, start at the first snapshot
+        // after it.
+        let start = match store.get_snapshot_at(&provider, from)? {
+            Some(start) => start,
+            None => match store.get_snapshots(&provider, from, to)?.pop() {
+                Some(first) => first,
+                None => continue,
+            },
+        };
+        if start.fetched_at >= end.fetched_at {
+            continue;
+        }
+        let between = store.get_snapshots(&provider, start.fetched_at, end.fetched_at)?;
+        diffs.push(diff_snapshots(&start, &end, &between));
+    }
+
+    match format {
+        OutputFormat::Json => {
+            let output = RobotOutput::new("diff", &diffs);
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Human => print!("{}", render(&diffs, false)),
+        OutputFormat::Md => print!("{}", render(&diffs, true)),
+    }
+    Ok(())
+}
+
+fn diff_snapshots(
+    start: &StoredSnapshot,
+    end: &StoredSnapshot,
+    between: &[StoredSnapshot],
+) -> SnapshotDiff {
+    let mut points: Vec<&StoredSnapshot> = between.iter().collect();
+    points.sort_by_key(|s| s.fetched_at);
+
+    let windows = UsageWindow::ALL
+        .into_iter()
+        .filter_map(|kind| {
+            let from_percent = kind.used_pct(start)?;
+            let to_percent = kind.used_pct(end)?;
+            let readings: Vec<f64> = points.iter().filter_map(|s| kind.used_pct(s)).collect();
+            let mut consumed = 0.0;
+            let mut resets = 0;
+            for pair in readings.windows(2) {
+                if pair[1] + RESET_DROP_PCT < pair[0] {
+                    resets += 1;
+                    consumed += pair[1];
+                } else {
+                    consumed += (pair[1] - pair[0]).max(0.0);
+                }
+            }
+            Some(WindowDiff {
+                window: kind.label(),
+                from_percent,
+                to_percent,
+                consumed,
+                resets,
+            })
+        })
+        .collect();
+
+    // Month-to-date spend survives midnight; today's does not.
+    let same_day = start.fetched_at.with_timezone(&Local).date_naive()
+        == end.fetched_at.with_timezone(&Local).date_naive();
+    let cost_usd = match (start.cost_mtd_usd, end.cost_mtd_usd) {
+        (Some(a), Some(b)) if b >= a => Some(b - a),
+        _ => match (start.cost_today_usd, end.cost_today_usd) {
+            (Some(a), Some(b)) if same_day && b >= a => Some(b - a),
+            _ => None,
+        },
+    };
+    let credits_used = start
+        .credits_remaining
+        .zip(end.credits_remaining)
+        .map(|(a, b)| a - b);
+
+    SnapshotDiff {
+        provider: start.provider.cli_name().to_string(),
+        from_at: start.fetched_at,
+        to_at: end.fetched_at,
+        windows,
+        cost_usd,
+        credits_used,
+    }
+}
+
+fn render(diffs: &[SnapshotDiff], markdown: bool) -> String {
+    let mut out = String::new();
+    if diffs.is_empty() {
+        writeln!(out, "No snapshots recorded in that range.").ok();
+        return out;
+    }
+    for (i, diff) in diffs.iter().enumerate() {
+        if i > 0 {
+            writeln!(out).ok();
+        }
+        let span = format!(
+            "{} -> {} ({})",
+            local_time(diff.from_at),
+            local_time(diff.to_at),
+            format_span(diff.to_at - diff.from_at)
+        );
+        if markdown {
+            writeln!(out, "## {}\n\n{span}\n", diff.provider).ok();
+            writeln!(out, "| Window | From | To | Used |").ok();
+            writeln!(out, "|--------|------|----|------|").ok();
+        } else {
+            writeln!(out, "{}  {span}", diff.provider).ok();
+        }
+        for window in &diff.windows {
+            let used = used_text(window);
+            if markdown {
+                writeln!(
+                    out,
+                    "| {} | {:.0}% | {:.0}% | {used} |",
+                    window.window, window.from_percent, window.to_percent
+                )
+                .ok();
+            } else {
+                writeln!(
+                    out,
+                    "  {:<9} {:>4.0}% -> {:>3.0}%  {used}",
+                    window.window, window.from_percent, window.to_percent
+                )
+                .ok();
+            }
+        }
+        let extras = [
+            diff.cost_usd
+                .map(|c| ("cost", format!("+{}", format_cost(c)))),
+            diff.credits_used
+                .map(|c| ("credits", format!("{c:.2} used"))),
+        ];
+        for (label, value) in extras.into_iter().flatten() {
+            if markdown {
+                writeln!(out, "| {label} | | | {value} |").ok();
+            } else {
+                writeln!(out, "  {label:<9} {value}").ok();
+            }
+        }
+    }
+    out
+}
+
+fn used_text(window: &WindowDiff) -> String {
+    let resets = match window.resets {
+        0 => String::new(),
+        1 => " (1 reset)".to_string(),
+        n => format!(" ({n} resets)"),
+    };
+    format!("+{:.1} pts{resets}", window.consumed)
+}
+
+fn local_time(at: DateTime<Utc>) -> String {
+    at.with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+/// A span as "2h 05m" or "42m".
+fn format_span(span: chrono::Duration) -> String {
+    let minutes = span.num_minutes();
+    if minutes >= 60 {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{minutes}m")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn snapshot(at: DateTime<Utc>, primary: f64, cost_mtd: f64) -> StoredSnapshot {
+        StoredSnapshot {
+            id: 0,
+            provider: Provider::Claude,
+            fetched_at: at,
+            source: "test".to_string(),
+            primary_used_pct: Some(primary),
+            primary_window_minutes: Some(300),
+            primary_resets_at: None,
+            secondary_used_pct: None,
+            secondary_window_minutes: None,
+            secondary_resets_at: None,
+            tertiary_used_pct: None,
+            tertiary_window_minutes: None,
+            tertiary_resets_at: None,
+            cost_today_usd: None,
+            cost_mtd_usd: Some(cost_mtd),
+            credits_remaining: None,
+            account_email: None,
+            account_org: None,
+            fetch_duration_ms: None,
+            created_at: None,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn counts_consumption_across_a_reset() {
+        let now = Utc::now();
+        let points = vec![
+            snapshot(now - Duration::hours(2), 60.0, 10.0),
+            snapshot(now - Duration::hours(1), 90.0, 12.0),
+            snapshot(now - Duration::minutes(30), 5.0, 13.0),
+            snapshot(now, 20.0, 14.5),
+        ];
+
+        let diff = diff_snapshots(&points[0], &points[3], &points);
+
+        let session = &diff.windows[0];
+        assert_eq!(session.window, "session");
+        assert!((session.consumed - 50.0).abs() < f64::EPSILON);
+        assert_eq!(session.resets, 1);
+        assert_eq!(used_text(session), "+50.0 pts (1 reset)");
+        assert_eq!(diff.cost_usd, Some(4.5));
+        assert_eq!(diff.credits_used, None);
+        assert_eq!(format_span(Duration::minutes(125)), "2h 05m");
+    }
+}
@@ -1,14 +1,23 @@
 //! Doctor command implementation.
 
 use crate::cli::args::{DoctorArgs, OutputFormat};
+use crate::cli::repl;
+use crate::core::canary::CanaryState;
 use crate::core::doctor::checks::check_all_providers;
-use crate::core::doctor::{CheckStatus, DiagnosticCheck, DoctorReport};
+use crate::core::doctor::clock::check_clock_skew;
+use crate::core::doctor::permissions::check_file_permissions;
+use crate::core::doctor::{CheckStatus, DiagnosticCheck, DoctorReport, check_ids};
 use crate::core::provider::{Provider, ProviderSelection};
 use crate::error::Result;
 use crate::render::doctor;
 use crate::storage::config::Config;
+use crate::storage::{AppPaths, HistoryStore};
+use chrono::{Duration, Utc};
 use std::time::Instant;
 
+/// How far back `doctor` reports plan limit changes.
+const PLAN_LIMIT_WINDOW_DAYS: i64 = 30;
+
 /// Execute the doctor command.
 ///
 /// # Errors
@@ -20,39 +29,10 @@ pub async fn execute(
     pretty: bool,
     no_color: bool,
 ) -> Result<()> {
-    let start = Instant::now();
-
     tracing::debug!(?args.provider, ?args.timeout, "Starting doctor checks");
 
-    // Parse provider selection
-    let providers = if let Some(provider_args) = &args.provider {
-        let mut providers = Vec::new();
-        for name in provider_args {
-            let selection = ProviderSelection::from_arg(name)?;
-            providers.extend(selection.providers());
-        }
-        providers
-    } else {
-        // Default to primary providers (Codex + Claude)
-        Provider::PRIMARY.to_vec()
-    };
-
-    // Check config status
-    let config_status = check_config();
-
-    // Run provider checks in parallel
-    let provider_health = check_all_providers(&providers).await;
-
-    // Build report
-    let report = DoctorReport {
-        caut_version: env!("CARGO_PKG_VERSION").to_string(),
-        caut_git_sha: option_env!("VERGEN_GIT_SHA")
-            .unwrap_or("unknown")
-            .to_string(),
-        config_status,
-        providers: provider_health,
-        total_duration: start.elapsed(),
-    };
+    let providers = selected_providers(args.provider.as_deref())?;
+    let report = build_report(&providers, args.fix).await;
 
     // Render output
     let output = doctor::render_human(&report, no_color)?;
@@ -72,14 +52,59 @@ pub async fn execute(
 
     // Return exit code based on health status
     let (_, needs_attention) = report.summary();
-    if needs_attention > 0 {
-        // Non-zero exit for scripting
-        std::process::exit(1);
+    if needs_attention > 0 && !repl::is_active() {
+        // Non-zero exit for scripting; a REPL session keeps running
+        crate::cli::output::exit(1);
     }
 
     Ok(())
 }
 
+/// Providers named by `--provider` (names, "both", or "all"), defaulting to
+/// the primary providers.
+///
+/// # Errors
+/// Returns an error if a name is not a known provider.
+pub(crate) fn selected_providers(names: Option<&[String]>) -> Result<Vec<Provider>> {
+    let Some(names) = names else {
+        // Default to primary providers (Codex + Claude)
+        return Ok(Provider::PRIMARY.to_vec());
+    };
+    let mut providers = Vec::new();
+    for name in names {
+        let selection = ProviderSelection::from_arg(name)?;
+        providers.extend(selection.providers());
+    }
+    Ok(providers)
+}
+
+/// Run every check for `providers`. `fix` repairs file permissions that can
+/// be fixed automatically.
+pub(crate) async fn build_report(providers: &[Provider], fix: bool) -> DoctorReport {
+    let start = Instant::now();
+
+    // Check config status
+    let config_status = check_config();
+
+    // Run provider checks in parallel
+    let (provider_health, clock_skew) =
+        tokio::join!(check_all_providers(providers), check_clock_skew(providers));
+
+    DoctorReport {
+        caut_version: env!("CARGO_PKG_VERSION").to_string(),
+        caut_git_sha: option_env!("VERGEN_GIT_SHA")
+            .unwrap_or("unknown")
+            .to_string(),
+        config_status,
+        providers: provider_health,
+        plan_limits: check_plan_limits(),
+        canary: check_canary(),
+        file_permissions: Some(check_file_permissions(fix)),
+        clock_skew: Some(clock_skew),
+        total_duration: start.elapsed(),
+    }
+}
+
 /// Check if configuration is present and valid.
 fn check_config() -> DiagnosticCheck {
     let start = Instant::now();
@@ -89,6 +114,7 @@ fn check_config() -> DiagnosticCheck {
             let config_path = Config::config_path();
             if config_path.exists() {
                 DiagnosticCheck {
+                    id: check_ids::CONFIG.to_string(),
                     name: "Config".to_string(),
                     status: CheckStatus::Pass {
                         details: Some(format!("{}", config_path.display())),
@@ -98,6 +124,7 @@ fn check_config() -> DiagnosticCheck {
             } else {
                 // Config loaded but using defaults (file doesn't exist)
                 DiagnosticCheck {
+                    id: check_ids::CONFIG.to_string(),
                     name: "Config".to_string(),
                     status: CheckStatus::Pass {
                         details: Some("Using defaults".to_string()),
@@ -107,6 +134,7 @@ fn check_config() -> DiagnosticCheck {
             }
         }
         Err(e) => DiagnosticCheck {
+            id: check_ids::CONFIG.to_string(),
             name: "Config".to_string(),
             status: CheckStatus::Fail {
                 reason: format!("Failed to load: {e}"),
@@ -117,6 +145,84 @@ fn check_config() -> DiagnosticCheck {
     }
 }
 
+/// Report provider-side plan limit changes seen in recorded history.
+///
+/// Returns `None` when no history has been recorded yet.
+fn check_plan_limits() -> Option<DiagnosticCheck> {
+    let start = Instant::now();
+    let path = AppPaths::new().history_db_file();
+    if !path.exists() {
+        return None;
+    }
+
+    let since = Utc::now() - Duration::days(PLAN_LIMIT_WINDOW_DAYS);
+    let status = match HistoryStore::open(&path).and_then(|store| store.plan_limit_changes(since)) {
+        Ok(changes) if changes.is_empty() => CheckStatus::Pass {
+            details: Some(format!(
+                "No changes in the last {PLAN_LIMIT_WINDOW_DAYS} days"
+            )),
+        },
+        Ok(changes) => CheckStatus::Warning {
+            details: changes
+                .iter()
+                .map(crate::core::plan_limits::PlanLimitChange::message)
+                .collect::<Vec<_>>()
+                .join("; "),
+            suggestion: Some("Run: caut history changes".to_string()),
+        },
+        Err(e) => CheckStatus::Skipped {
+            reason: format!("Could not read history: {e}"),
+        },
+    };
+
+    Some(
+        DiagnosticCheck::new(check_ids::PLAN_LIMITS, "Plan limits", status)
+            .with_duration(start.elapsed()),
+    )
+}
+
+/// Report fetch strategies that failed the last canary run.
+///
+/// Returns `None` when the canary has never run.
+fn check_canary() -> Option<DiagnosticCheck> {
+    let start = Instant::now();
+    let path = AppPaths::new().canary_file();
+    if !path.exists() {
+        return None;
+    }
+
+    let state = CanaryState::load(&path);
+    let now = Utc::now();
+    let failing: Vec<String> = state
+        .failing()
+        .map(|(strategy, health)| health.failure_message(strategy, now))
+        .collect();
+    let status = if failing.is_empty() {
+        CheckStatus::Pass {
+            details: state.last_run.map(|last| {
+                format!(
+                    "All {} strategies passed (checked {})",
+                    state.strategies.len(),
+                    last.format("%Y-%m-%d")
+                )
+            }),
+        }
+    } else {
+        CheckStatus::Warning {
+            details: failing.join("; "),
+            suggestion: Some(
+                "Check that source's credentials, or update caut if the provider changed it"
+                    .to_string(),
+            ),
+        }
+    };
+
+    Some(
+        DiagnosticCheck::new(check_ids::CANARY, "Fetch canary", status)
+            .with_duration(start.elapsed()),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
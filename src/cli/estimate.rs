@@ -0,0 +1,210 @@
+//! Estimate command implementation.
+//!
+//! Approximates how many tokens a set of files (or stdin) would use as
+//! prompt input for a model and prices them with the session cost table, so
+//! spend can be predicted before handing the content to an agent.
+
+use std::io::Read;
+
+use serde::Serialize;
+
+use crate::cli::args::{EstimateArgs, OutputFormat};
+use crate::core::models::RobotOutput;
+use crate::core::pricing::PricingTable;
+use crate::core::token_estimate::{TokenizerFamily, estimate_tokens};
+use crate::error::{CautError, Result};
+use crate::render::robot;
+
+/// Label used for content read from stdin.
+const STDIN_LABEL: &str = "<stdin>";
+
+/// Token estimate for one input.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileEstimate {
+    pub path: String,
+    pub bytes: u64,
+    pub tokens: u64,
+}
+
+/// Token and cost estimate for all inputs.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimateReport {
+    /// Model as requested.
+    pub model: String,
+    /// Model whose prices were used.
+    pub pricing_model: String,
+    /// Whether `pricing_model` has known prices (otherwise Sonnet-tier).
+    pub pricing_known: bool,
+    pub tokenizer: TokenizerFamily,
+    pub files: Vec<FileEstimate>,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub input_cost_usd: f64,
+    pub output_cost_usd: f64,
+    pub total_cost_usd: f64,
+}
+
+/// Execute the estimate command.
+///
+/// # Errors
+/// Returns an error if an input cannot be read or rendering fails.
+pub fn execute(args: &EstimateArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let inputs = read_inputs(args)?;
+    let report = estimate(&inputs, &args.model, args.output_tokens);
+
+    match format {
+        OutputFormat::Human => println!("{}", render_report(&report, false)),
+        OutputFormat::Md => println!("{}", render_report(&report, true)),
+        OutputFormat::Json => {
+            let output = RobotOutput::new("estimate", report);
+            robot::print_json(&output, pretty)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn read_inputs(args: &EstimateArgs) -> Result<Vec<(String, String)>> {
+    if args.files.is_empty() {
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content)?;
+        return Ok(vec![(STDIN_LABEL.to_string(), content)]);
+    }
+
+    args.files
+        .iter()
+        .map(|path| {
+            let bytes = std::fs::read(path)
+                .map_err(|e| CautError::Config(format!("Cannot read {}: {e}", path.display())))?;
+            // Binary files still cost tokens if pasted; count them lossily.
+            let content = String::from_utf8_lossy(&bytes).into_owned();
+            Ok((path.display().to_string(), content))
+        })
+        .collect()
+}
+
+/// Estimate tokens and cost for named inputs sent to `model`.
+#[must_use]
+pub fn estimate(inputs: &[(String, String)], model: &str, output_tokens: u64) -> EstimateReport {
+    let tokenizer = TokenizerFamily::for_model(model);
+    let files: Vec<FileEstimate> = inputs
+        .iter()
+        .map(|(path, content)| FileEstimate {
+            path: path.clone(),
+            bytes: content.len() as u64,
+            tokens: estimate_tokens(content, tokenizer),
+        })
+        .collect();
+    let input_tokens = files.iter().map(|file| file.tokens).sum();
+
+    let table = PricingTable::current();
+    let (pricing, pricing_known) = table
+        .resolve(model)
+        .map_or_else(|| table.get_or_estimate(model), |p| (p.clone(), true));
+    let cost = pricing.calculate_cost(
+        i64::try_from(input_tokens).unwrap_or(i64::MAX),
+        i64::try_from(output_tokens).unwrap_or(i64::MAX),
+        0,
+        0,
+    );
+
+    EstimateReport {
+        model: model.to_string(),
+        pricing_model: pricing.model,
+        pricing_known,
+        tokenizer,
+        files,
+        input_tokens,
+        output_tokens,
+        input_cost_usd: cost.input_cost_usd,
+        output_cost_usd: cost.output_cost_usd,
+        total_cost_usd: cost.total_cost_usd,
+    }
+}
+
+fn render_report(report: &EstimateReport, markdown: bool) -> String {
+    let mut lines = Vec::new();
+    if markdown {
+        lines.push(format!("## Estimate for {}\n", report.pricing_model));
+        lines.push("| Input | Bytes | Tokens |".to_string());
+        lines.push("|-------|-------|--------|".to_string());
+        for file in &report.files {
+            lines.push(format!(
+                "| {} | {} | ~{} |",
+                file.path, file.bytes, file.tokens
+            ));
+        }
+        lines.push(String::new());
+    } else {
+        lines.push(format!("Estimate for {}:", report.pricing_model));
+        for file in &report.files {
+            lines.push(format!(
+                "  {}: ~{} tokens ({} bytes)",
+                file.path, file.tokens, file.bytes
+            ));
+        }
+    }
+
+    let bullet = if markdown { "- " } else { "  " };
+    lines.push(format!(
+        "{bullet}Input: ~{} tokens, ${:.4}",
+        report.input_tokens, report.input_cost_usd
+    ));
+    if report.output_tokens > 0 {
+        lines.push(format!(
+            "{bullet}Output: {} tokens, ${:.4}",
+            report.output_tokens, report.output_cost_usd
+        ));
+    }
+    lines.push(format!("{bullet}Total: ${:.4}", report.total_cost_usd));
+    if !report.pricing_known {
+        lines.push(format!(
+            "{bullet}Unknown model {}; priced at Sonnet-tier rates",
+            report.model
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs() -> Vec<(String, String)> {
+        vec![(
+            "src/lib.rs".to_string(),
+            "pub fn add(a: u64, b: u64) -> u64 {\n    a + b\n}\n".repeat(100),
+        )]
+    }
+
+    #[test]
+    fn prices_input_with_resolved_model() {
+        let report = estimate(&inputs(), "claude-sonnet", 0);
+        assert_eq!(report.pricing_model, "claude-sonnet-4");
+        assert!(report.pricing_known);
+        assert_eq!(report.tokenizer, TokenizerFamily::Claude);
+        assert_eq!(report.input_tokens, report.files[0].tokens);
+        assert!(report.input_cost_usd > 0.0);
+        assert!(report.output_cost_usd.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn includes_expected_output_tokens() {
+        let report = estimate(&inputs(), "claude-sonnet-4", 1_000_000);
+        assert!((report.output_cost_usd - 15.0).abs() < 1e-9);
+        assert!(
+            (report.total_cost_usd - report.input_cost_usd - report.output_cost_usd).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn unknown_model_falls_back_to_estimate() {
+        let report = estimate(&inputs(), "mystery-model", 0);
+        assert!(!report.pricing_known);
+        let rendered = render_report(&report, false);
+        assert!(rendered.contains("Unknown model mystery-model"));
+    }
+}
@@ -0,0 +1,389 @@
+//! Export command implementation.
+//!
+//! Dumps the history database for analysis in spreadsheets or notebooks:
+//! usage snapshots, daily costs (per provider, plus imported gateway usage
+//! and cost history),
+//! and registered accounts. JSON holds every table in one document; CSV and
+//! Parquet hold one table each, picked with `--table`.
+
+use std::io::Write;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::{Map, Value};
+
+use crate::cli::args::{ExportArgs, ExportFormat, ExportTable};
+use crate::cli::history::export_range;
+use crate::core::gateway_import::GatewayUsage;
+use crate::core::history_import::ImportedCost;
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::storage::parquet::{self, Column, ColumnData};
+use crate::storage::{Account, AppPaths, DailyCost, HistoryStore, StoredSnapshot};
+use crate::util::env::stdout_is_tty;
+use crate::util::format::csv_escape;
+
+/// Execute the export command.
+///
+/// # Errors
+/// Returns an error if there is no history database, the time range is
+/// invalid, Parquet would be written to a terminal, or a query or the write
+/// fails.
+pub fn execute(args: &ExportArgs) -> Result<()> {
+    let history_path = AppPaths::new().history_db_file();
+    if !history_path.exists() {
+        return Err(CautError::Config(
+            "No history database found. Run `caut usage` to start collecting data.".to_string(),
+        ));
+    }
+    if args.format == ExportFormat::Parquet && stdout_is_tty() {
+        return Err(CautError::Config(
+            "Parquet is a binary format; write it to a file with --output or a redirect"
+                .to_string(),
+        ));
+    }
+
+    let store = HistoryStore::open(&history_path)?;
+    let (from, to) = export_range(args.since.as_deref(), args.until.as_deref())?;
+
+    let tables = match (args.table, args.format) {
+        (Some(table), _) => vec![table],
+        (None, ExportFormat::Json) => vec![
+            ExportTable::Snapshots,
+            ExportTable::Costs,
+            ExportTable::Accounts,
+        ],
+        (None, _) => vec![ExportTable::Snapshots],
+    };
+    let mut exported = Vec::with_capacity(tables.len());
+    for table in tables {
+        exported.push((table, table_columns(&store, table, from, to)?));
+    }
+
+    // Written to stdout; `--output` sends it to a file
+    let file = match args.format {
+        ExportFormat::Json => render_json(&exported, from, to)?.into_bytes(),
+        ExportFormat::Csv => render_csv(&exported[0].1).into_bytes(),
+        ExportFormat::Parquet => parquet::write_table(&exported[0].1)?,
+    };
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(&file)?;
+    stdout.flush()?;
+    Ok(())
+}
+
+fn table_columns(
+    store: &HistoryStore,
+    table: ExportTable,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<Column>> {
+    Ok(match table {
+        ExportTable::Snapshots => {
+            let mut snapshots = Vec::new();
+            for provider in Provider::ALL {
+                snapshots.append(&mut store.get_snapshots(provider, from, to)?);
+            }
+            snapshots.sort_by_key(|s| std::cmp::Reverse(s.fetched_at));
+            snapshot_columns(&snapshots)
+        }
+        ExportTable::Costs => {
+            let daily = store.daily_costs(from, to)?;
+            let last_day = to.date_naive();
+            let gateway: Vec<GatewayUsage> = store
+                .gateway_usage(from.date_naive())?
+                .into_iter()
+                .filter(|row| row.day <= last_day)
+                .collect();
+            let imported: Vec<ImportedCost> = store
+                .imported_costs(from.date_naive())?
+                .into_iter()
+                .filter(|row| row.day <= last_day)
+                .collect();
+            cost_columns(&daily, &gateway, &imported)
+        }
+        ExportTable::Accounts => {
+            // Accounts seen at any point in the range
+            let accounts: Vec<Account> = store
+                .accounts()?
+                .into_iter()
+                .filter(|a| a.added_at <= to && a.last_seen_at.unwrap_or(a.added_at) >= from)
+                .collect();
+            account_columns(&accounts)
+        }
+    })
+}
+
+/// Snapshot columns, named and ordered like `caut history export`.
+pub(crate) fn snapshot_columns(snapshots: &[StoredSnapshot]) -> Vec<Column> {
+    let s = snapshots;
+    vec![
+        Column::new("id", int(s, |s| Some(s.id))),
+        Column::new(
+            "provider",
+            text(s, |s| Some(s.provider.cli_name().to_string())),
+        ),
+        Column::new("fetched_at", text(s, |s| Some(s.fetched_at.to_rfc3339()))),
+        Column::new("source", text(s, |s| Some(s.source.clone()))),
+        Column::new("primary_used_pct", float(s, |s| s.primary_used_pct)),
+        Column::new(
+            "primary_window_minutes",
+            int(s, |s| s.primary_window_minutes.map(i64::from)),
+        ),
+        Column::new(
+            "primary_resets_at",
+            text(s, |s| rfc3339(s.primary_resets_at)),
+        ),
+        Column::new("secondary_used_pct", float(s, |s| s.secondary_used_pct)),
+        Column::new(
+            "secondary_window_minutes",
+            int(s, |s| s.secondary_window_minutes.map(i64::from)),
+        ),
+        Column::new(
+            "secondary_resets_at",
+            text(s, |s| rfc3339(s.secondary_resets_at)),
+        ),
+        Column::new("tertiary_used_pct", float(s, |s| s.tertiary_used_pct)),
+        Column::new(
+            "tertiary_window_minutes",
+            int(s, |s| s.tertiary_window_minutes.map(i64::from)),
+        ),
+        Column::new(
+            "tertiary_resets_at",
+            text(s, |s| rfc3339(s.tertiary_resets_at)),
+        ),
+        Column::new("cost_today_usd", float(s, |s| s.cost_today_usd)),
+        Column::new("cost_mtd_usd", float(s, |s| s.cost_mtd_usd)),
+        Column::new("credits_remaining", float(s, |s| s.credits_remaining)),
+        Column::new("account_email", text(s, |s| s.account_email.clone())),
+        Column::new("account_org", text(s, |s| s.account_org.clone())),
+        Column::new("fetch_duration_ms", int(s, |s| s.fetch_duration_ms)),
+    ]
+}
+
+/// One row of the costs table: a provider's day from snapshots, an imported
+/// gateway day/model rollup, or an imported provider day/model cost.
+struct CostRow<'a> {
+    day: NaiveDate,
+    provider: Option<&'a str>,
+    gateway: Option<&'a str>,
+    model: Option<&'a str>,
+    requests: Option<i64>,
+    input_tokens: Option<i64>,
+    output_tokens: Option<i64>,
+    cost_usd: f64,
+}
+
+fn cost_columns(
+    daily: &[DailyCost],
+    gateway: &[GatewayUsage],
+    imported: &[ImportedCost],
+) -> Vec<Column> {
+    let mut rows: Vec<CostRow> = daily
+        .iter()
+        .map(|d| CostRow {
+            day: d.day,
+            provider: Some(&d.provider),
+            gateway: None,
+            model: None,
+            requests: None,
+            input_tokens: None,
+            output_tokens: None,
+            cost_usd: d.cost_usd,
+        })
+        .chain(imported.iter().map(|i| CostRow {
+            day: i.day,
+            provider: Some(&i.provider),
+            gateway: None,
+            model: Some(i.model.as_str()).filter(|m| !m.is_empty()),
+            requests: None,
+            input_tokens: i.input_tokens,
+            output_tokens: i.output_tokens,
+            cost_usd: i.cost_usd,
+        }))
+        .chain(gateway.iter().map(|g| CostRow {
+            day: g.day,
+            provider: None,
+            gateway: Some(&g.gateway),
+            model: Some(&g.model),
+            requests: Some(g.requests),
+            input_tokens: Some(g.input_tokens),
+            output_tokens: Some(g.output_tokens),
+            cost_usd: g.cost_usd,
+        }))
+        .collect();
+    // Stable, so providers stay ahead of gateways within a day
+    rows.sort_by_key(|row| row.day);
+
+    let r = &rows;
+    vec![
+        Column::new("day", text(r, |r| Some(r.day.to_string()))),
+        Column::new("provider", text(r, |r| r.provider.map(str::to_string))),
+        Column::new("gateway", text(r, |r| r.gateway.map(str::to_string))),
+        Column::new("model", text(r, |r| r.model.map(str::to_string))),
+        Column::new("requests", int(r, |r| r.requests)),
+        Column::new("input_tokens", int(r, |r| r.input_tokens)),
+        Column::new("output_tokens", int(r, |r| r.output_tokens)),
+        Column::new("cost_usd", float(r, |r| Some(r.cost_usd))),
+    ]
+}
+
+fn account_columns(accounts: &[Account]) -> Vec<Column> {
+    let a = accounts;
+    vec![
+        Column::new("id", text(a, |a| Some(a.id.clone()))),
+        Column::new("provider", text(a, |a| Some(a.provider.clone()))),
+        Column::new("email", text(a, |a| Some(a.email.clone()))),
+        Column::new("label", text(a, |a| a.label.clone())),
+        Column::new("added_at", text(a, |a| Some(a.added_at.to_rfc3339()))),
+        Column::new("last_seen_at", text(a, |a| rfc3339(a.last_seen_at))),
+        Column::new("is_active", int(a, |a| Some(i64::from(a.is_active)))),
+        Column::new("metadata", text(a, |a| a.metadata.clone())),
+    ]
+}
+
+fn text<T>(rows: &[T], value: impl Fn(&T) -> Option<String>) -> ColumnData {
+    ColumnData::Utf8(rows.iter().map(value).collect())
+}
+
+fn int<T>(rows: &[T], value: impl Fn(&T) -> Option<i64>) -> ColumnData {
+    ColumnData::Int64(rows.iter().map(value).collect())
+}
+
+fn float<T>(rows: &[T], value: impl Fn(&T) -> Option<f64>) -> ColumnData {
+    ColumnData::Double(rows.iter().map(value).collect())
+}
+
+fn rfc3339(time: Option<DateTime<Utc>>) -> Option<String> {
+    time.map(|t| t.to_rfc3339())
+}
+
+const fn table_key(table: ExportTable) -> &'static str {
+    match table {
+        ExportTable::Snapshots => "snapshots",
+        ExportTable::Costs => "costs",
+        ExportTable::Accounts => "accounts",
+    }
+}
+
+fn render_csv(columns: &[Column]) -> String {
+    let header: Vec<String> = columns.iter().map(|c| csv_escape(&c.name)).collect();
+    let mut out = header.join(",") + "\n";
+    let rows = columns.first().map_or(0, |c| c.data.len());
+    for row in 0..rows {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|c| match &c.data {
+                ColumnData::Int64(values) => values[row].map(|n| n.to_string()),
+                ColumnData::Double(values) => values[row].map(|n| n.to_string()),
+                ColumnData::Utf8(values) => values[row].as_deref().map(csv_escape),
+            })
+            .map(Option::unwrap_or_default)
+            .collect();
+        out.push_str(&cells.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn render_json(
+    tables: &[(ExportTable, Vec<Column>)],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<String> {
+    let mut doc = Map::new();
+    doc.insert("schemaVersion".to_string(), "caut.export.v1".into());
+    doc.insert("exported_at".to_string(), Utc::now().to_rfc3339().into());
+    doc.insert(
+        "range".to_string(),
+        serde_json::json!({ "start": from.to_rfc3339(), "end": to.to_rfc3339() }),
+    );
+    for (table, columns) in tables {
+        doc.insert(table_key(*table).to_string(), json_rows(columns).into());
+    }
+    Ok(serde_json::to_string_pretty(&doc)? + "\n")
+}
+
+fn json_rows(columns: &[Column]) -> Vec<Value> {
+    let rows = columns.first().map_or(0, |c| c.data.len());
+    (0..rows)
+        .map(|row| {
+            let object: Map<String, Value> = columns
+                .iter()
+                .map(|c| {
+                    let value = match &c.data {
+                        ColumnData::Int64(values) => values[row].into(),
+                        ColumnData::Double(values) => values[row].into(),
+                        ColumnData::Utf8(values) => values[row].clone().into(),
+                    };
+                    (c.name.clone(), value)
+                })
+                .collect();
+            Value::Object(object)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn costs() -> Vec<Column> {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        let daily = [DailyCost {
+            day: day(14),
+            provider: "claude".to_string(),
+            cost_usd: 4.25,
+        }];
+        let gateway = [GatewayUsage {
+            gateway: "helicone".to_string(),
+            day: day(13),
+            model: "gpt-4o, mini".to_string(),
+            requests: 12,
+            input_tokens: 50_000,
+            output_tokens: 2_000,
+            cost_usd: 0.5,
+        }];
+        let imported = [ImportedCost {
+            provider: "codex".to_string(),
+            day: day(13),
+            model: "gpt-5-codex".to_string(),
+            input_tokens: Some(9_000),
+            output_tokens: Some(300),
+            cache_read_tokens: None,
+            cache_creation_tokens: None,
+            cost_usd: 1.5,
+            source: "codexbar".to_string(),
+        }];
+        cost_columns(&daily, &gateway, &imported)
+    }
+
+    #[test]
+    fn costs_csv_merges_provider_and_gateway_days() {
+        assert_eq!(
+            render_csv(&costs()),
+            "day,provider,gateway,model,requests,input_tokens,output_tokens,cost_usd\n\
+             2026-10-13,codex,,gpt-5-codex,,9000,300,1.5\n\
+             2026-10-13,,helicone,\"gpt-4o, mini\",12,50000,2000,0.5\n\
+             2026-10-14,claude,,,,,,4.25\n"
+        );
+    }
+
+    #[test]
+    fn json_rows_keep_nulls_and_types() {
+        let rows = json_rows(&costs());
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[2],
+            serde_json::json!({
+                "day": "2026-10-14",
+                "provider": "claude",
+                "gateway": null,
+                "model": null,
+                "requests": null,
+                "input_tokens": null,
+                "output_tokens": null,
+                "cost_usd": 4.25,
+            })
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Forecast command implementation.
+//!
+//! Fits the recent burn rate of each rate window from recorded history (see
+//! [`crate::core::prediction`]) and reports when the window would hit 100%
+//! at that rate, next to when it resets. Only recorded snapshots are read,
+//! so forecasts need `caut usage` (or `caut daemon`) to have run a few times
+//! within the lookback.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use crate::cli::args::{ForecastArgs, OutputFormat};
+use crate::core::models::RobotOutput;
+use crate::core::prediction::{UsageWindow, time_to_exhaustion, window_velocity};
+use crate::core::provider::{Provider, ProviderSelection};
+use crate::error::Result;
+use crate::render::robot;
+use crate::storage::{AppPaths, HistoryStore, StoredSnapshot};
+use crate::util::time::format_countdown;
+
+/// Forecast for one rate window.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowForecast {
+    pub provider: String,
+    pub window: &'static str,
+    pub used_percent: f64,
+    /// Percentage points per hour over the lookback; None without enough
+    /// history.
+    pub velocity_per_hour: Option<f64>,
+    /// When the window hits 100% at the current rate; None when not rising.
+    pub exhausts_at: Option<DateTime<Utc>>,
+    pub resets_at: Option<DateTime<Utc>>,
+    /// Whether the limit is reached before the next reset, when both are
+    /// known.
+    pub exhausts_before_reset: Option<bool>,
+    /// When the latest snapshot was recorded.
+    pub as_of: DateTime<Utc>,
+}
+
+/// Execute the forecast command.
+///
+/// # Errors
+/// Returns an error if the provider selection is invalid, the history
+/// database cannot be read, or output serialization fails.
+pub fn execute(args: &ForecastArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let selection = args
+        .provider
+        .as_deref()
+        .map(ProviderSelection::from_arg)
+        .transpose()?;
+    let history_path = AppPaths::new().history_db_file();
+    let now = Utc::now();
+    #[allow(clippy::cast_possible_wrap)] // lookbacks are far below i64::MAX seconds
+    let lookback = Duration::seconds(args.lookback as i64);
+
+    let mut forecasts = Vec::new();
+    if history_path.exists() {
+        let store = HistoryStore::open(&history_path)?;
+        let mut latest: Vec<(Provider, StoredSnapshot)> = store
+            .get_latest_all()?
+            .into_iter()
+            .filter(|(provider, _)| {
+                selection
+                    .as_ref()
+                    .is_none_or(|s| s.providers().contains(provider))
+            })
+            .collect();
+        latest.sort_by_key(|(provider, _)| provider.cli_name());
+
+        for (provider, snapshot) in &latest {
+            let history = store.get_snapshots(provider, now - lookback, now)?;
+            forecasts.extend(forecast_provider(
+                *provider, snapshot, &history, lookback, now,
+            ));
+        }
+    }
+
+    match format {
+        OutputFormat::Json => {
+            let output = RobotOutput::new("forecast", &forecasts);
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Human => print!("{}", render(&forecasts, false)),
+        OutputFormat::Md => print!("{}", render(&forecasts, true)),
+    }
+    Ok(())
+}
+
+/// Forecast every window the latest snapshot reports.
+fn forecast_provider(
+    provider: Provider,
+    latest: &StoredSnapshot,
+    history: &[StoredSnapshot],
+    lookback: Duration,
+    now: DateTime<Utc>,
+) -> Vec<WindowForecast> {
+    UsageWindow::ALL
+        .into_iter()
+        .filter_map(|kind| {
+            let used_percent = kind.used_pct(latest)?;
+            let velocity_per_hour = window_velocity(history, lookback, kind);
+            let exhausts_at =
+                velocity_per_hour.and_then(|v| time_to_exhaustion(used_percent, v, now));
+            let resets_at = next_reset(kind, latest, now);
+            let exhausts_before_reset = match (exhausts_at, resets_at) {
+                (Some(full), Some(reset)) => Some(full < reset),
+                (None, Some(_)) if velocity_per_hour.is_some() => Some(false),
+                _ => None,
+            };
+            Some(WindowForecast {
+                provider: provider.cli_name().to_string(),
+                window: kind.label(),
+                used_percent,
+                velocity_per_hour,
+                exhausts_at,
+                resets_at,
+                exhausts_before_reset,
+                as_of: latest.fetched_at,
+            })
+        })
+        .collect()
+}
+
+/// The window's next reset, rolling a recorded reset that has since passed
+/// forward by whole window lengths.
+fn next_reset(
+    kind: UsageWindow,
+    snapshot: &StoredSnapshot,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let mut reset = kind.resets_at(snapshot)?;
+    if reset > now {
+        return Some(reset);
+    }
+    let length = Duration::minutes(i64::from(kind.window_minutes(snapshot)?));
+    if length <= Duration::zero() {
+        return None;
+    }
+    while reset <= now {
+        reset += length;
+    }
+    Some(reset)
+}
+
+fn render(forecasts: &[WindowForecast], markdown: bool) -> String {
+    let mut out = String::new();
+    if forecasts.is_empty() {
+        writeln!(
+            out,
+            "No usage history to forecast from. Run `caut usage` a few times, or `caut daemon`."
+        )
+        .ok();
+        return out;
+    }
+
+    if markdown {
+        writeln!(out, "# Usage Forecast\n").ok();
+        writeln!(
+            out,
+            "| Provider | Window | Used | Rate | Full | Resets | Outlook |"
+        )
+        .ok();
+        writeln!(
+            out,
+            "|----------|--------|------|------|------|--------|---------|"
+        )
+        .ok();
+    } else {
+        writeln!(
+            out,
+            "{:<10} {:<9} {:>5} {:>9} {:<14} {:<14} OUTLOOK",
+            "PROVIDER", "WINDOW", "USED", "RATE", "FULL", "RESETS"
+        )
+        .ok();
+    }
+    for forecast in forecasts {
+        let used = format!("{:.0}%", forecast.used_percent);
+        let rate = forecast
+            .velocity_per_hour
+            .map_or_else(|| "-".to_string(), |v| format!("{v:+.1}%/h"));
+        let full = forecast
+            .exhausts_at
+            .map_or_else(|| "-".to_string(), format_countdown);
+        let resets = forecast
+            .resets_at
+            .map_or_else(|| "-".to_string(), format_countdown);
+        let outlook = outlook(forecast);
+        if markdown {
+            writeln!(
+                out,
+                "| {} | {} | {used} | {rate} | {full} | {resets} | {outlook} |",
+                forecast.provider, forecast.window
+            )
+            .ok();
+        } else {
+            writeln!(
+                out,
+                "{:<10} {:<9} {used:>5} {rate:>9} {full:<14} {resets:<14} {outlook}",
+                forecast.provider, forecast.window
+            )
+            .ok();
+        }
+    }
+    out
+}
+
+fn outlook(forecast: &WindowForecast) -> &'static str {
+    match (forecast.velocity_per_hour, forecast.exhausts_before_reset) {
+        (None, _) => "not enough history",
+        (Some(_), Some(true)) => "limit before reset",
+        (Some(_), Some(false)) => "resets first",
+        (Some(_), None) if forecast.exhausts_at.is_some() => "rising",
+        (Some(_), None) => "steady",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(at: DateTime<Utc>, primary: f64, resets_at: DateTime<Utc>) -> StoredSnapshot {
+        StoredSnapshot {
+            id: 0,
+            provider: Provider::Claude,
+            fetched_at: at,
+            source: "test".to_string(),
+            primary_used_pct: Some(primary),
+            primary_window_minutes: Some(300),
+            primary_resets_at: Some(resets_at),
+            secondary_used_pct: None,
+            secondary_window_minutes: None,
+            secondary_resets_at: None,
+            tertiary_used_pct: None,
+            tertiary_window_minutes: None,
+            tertiary_resets_at: None,
+            cost_today_usd: None,
+            cost_mtd_usd: None,
+            credits_remaining: None,
+            account_email: None,
+            account_org: None,
+            fetch_duration_ms: None,
+            created_at: None,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn forecasts_exhaustion_against_reset() {
+        let now = Utc::now();
+        let reset = now + Duration::hours(3);
+        let history = vec![
+            snapshot(now - Duration::hours(2), 40.0, reset),
+            snapshot(now, 60.0, reset),
+        ];
+
+        let forecasts = forecast_provider(
+            Provider::Claude,
+            &history[1],
+            &history,
+            Duration::hours(4),
+            now,
+        );
+
+        assert_eq!(forecasts.len(), 1);
+        let session = &forecasts[0];
+        assert_eq!(session.window, "session");
+        // 10%/h from 60% reaches 100% in 4h, after the 3h reset.
+        let full_in = session.exhausts_at.expect("rising") - now;
+        assert!((full_in - Duration::hours(4)).num_seconds().abs() <= 1);
+        assert_eq!(session.exhausts_before_reset, Some(false));
+        assert_eq!(outlook(session), "resets first");
+
+        let stale = snapshot(now - Duration::hours(6), 10.0, now - Duration::hours(1));
+        assert_eq!(
+            next_reset(UsageWindow::Primary, &stale, now),
+            Some(now + Duration::hours(4))
+        );
+    }
+}
@@ -1,31 +1,42 @@
 //! History command implementation.
 //!
 //! Manages usage history database: pruning old data, showing statistics,
-//! displaying usage trends with ASCII/Unicode visualizations, and exporting
-//! historical data to JSON or CSV formats.
+//! displaying usage trends with ASCII/Unicode visualizations, listing
+//! snapshots matching filters, listing provider plan limit changes, and
+//! exporting historical data to JSON, CSV, or Parquet.
 
-use std::fs::File;
 use std::io::{BufWriter, Write};
 
-use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
 
 use crate::cli::args::{
-    ExportFormat, HistoryCommand, HistoryExportArgs, HistoryPruneArgs, HistoryShowArgs,
-    OutputFormat,
+    ExportFormat, HistoryChangesArgs, HistoryCommand, HistoryExportArgs, HistoryListArgs,
+    HistoryProfileArgs, HistoryPruneArgs, HistoryShowArgs, OutputFormat,
 };
+use crate::cli::export;
+use crate::core::plan_limits::PlanLimitChange;
+use crate::core::prediction::UsageWindow;
 use crate::core::provider::Provider;
+use crate::core::usage_profile::{UsageProfile, WeekdayProfile, build_profile};
 use crate::error::{CautError, Result};
 use crate::render::human::{HistoryDay, HistoryRenderOptions, render_history_chart};
+use crate::render::robot;
+use crate::storage::parquet;
 use crate::storage::{
     AppPaths, DEFAULT_AGGREGATE_RETENTION_DAYS, DEFAULT_DETAILED_RETENTION_DAYS,
-    DEFAULT_MAX_SIZE_BYTES, HistoryStore, RetentionPolicy, StoredSnapshot,
+    DEFAULT_MAX_SIZE_BYTES, HistoryStore, RetentionPolicy, SnapshotFilter, StoredSnapshot,
 };
+use crate::util::format::{csv_escape, format_bytes, pad_to_width, truncate_middle};
+use crate::util::time::{parse_humane_datetime, parse_humane_range};
+
+/// Column width for account values in `history list`.
+const LIST_ACCOUNT_WIDTH: usize = 28;
 
 /// Execute history commands.
 ///
 /// # Errors
 /// Returns an error if the history database cannot be opened, or if the
-/// requested subcommand (show, prune, stats, export) fails.
+/// requested subcommand (show, list, prune, stats, export, changes) fails.
 pub fn execute(
     cmd: &HistoryCommand,
     format: OutputFormat,
@@ -34,9 +45,12 @@ pub fn execute(
 ) -> Result<()> {
     match cmd {
         HistoryCommand::Show(args) => execute_show(args, format, pretty, no_color),
+        HistoryCommand::List(args) => execute_list(args, format, pretty),
         HistoryCommand::Prune(args) => execute_prune(args, format, pretty),
         HistoryCommand::Stats => execute_stats(format, pretty),
         HistoryCommand::Export(args) => execute_export(args),
+        HistoryCommand::Changes(args) => execute_changes(args, format, pretty),
+        HistoryCommand::Profile(args) => execute_profile(args, format, pretty),
     }
 }
 
@@ -96,6 +110,18 @@ fn execute_show(
 
     let mut any_data = false;
 
+    // Imported gateway usage is not tied to a provider
+    let gateways = if args.provider.is_none() {
+        gateway_history(&store, from.with_timezone(&Local).date_naive())?
+    } else {
+        Vec::new()
+    };
+    let imported = imported_cost_history(
+        &store,
+        from.with_timezone(&Local).date_naive(),
+        args.provider.is_some().then(|| providers[0]),
+    )?;
+
     // Handle JSON format
     if format == OutputFormat::Json {
         let mut provider_data = Vec::new();
@@ -122,6 +148,45 @@ fn execute_show(
             }
         }
 
+        let gateway_data: Vec<_> = gateways
+            .iter()
+            .map(|(gateway, days)| {
+                let day_json: Vec<_> = days
+                    .iter()
+                    .map(|d| {
+                        serde_json::json!({
+                            "date": d.date.to_string(),
+                            "requests": d.requests,
+                            "totalCost": d.cost_usd
+                        })
+                    })
+                    .collect();
+                serde_json::json!({
+                    "gateway": gateway,
+                    "days": day_json
+                })
+            })
+            .collect();
+
+        let imported_data: Vec<_> = imported
+            .iter()
+            .map(|(provider, days)| {
+                let day_json: Vec<_> = days
+                    .iter()
+                    .map(|d| {
+                        serde_json::json!({
+                            "date": d.date.to_string(),
+                            "totalCost": d.cost_usd
+                        })
+                    })
+                    .collect();
+                serde_json::json!({
+                    "provider": provider,
+                    "days": day_json
+                })
+            })
+            .collect();
+
         let output = serde_json::json!({
             "schemaVersion": "caut.v1",
             "command": "history show",
@@ -131,7 +196,9 @@ fn execute_show(
                     "to": to.format("%Y-%m-%d").to_string(),
                     "days": args.days
                 },
-                "providers": provider_data
+                "providers": provider_data,
+                "gateways": gateway_data,
+                "importedCosts": imported_data
             }
         });
         println!("{}", serde_json::to_string_pretty(&output)?);
@@ -169,6 +236,33 @@ fn execute_show(
             }
         }
 
+        for (gateway, days) in &gateways {
+            any_data = true;
+            println!("## {gateway} (imported)\n");
+            println!("| Date | Requests | Cost |");
+            println!("|------|----------|------|");
+            for day in days {
+                println!(
+                    "| {} | {} | ${:.2} |",
+                    day.date.format("%a %m/%d"),
+                    day.requests,
+                    day.cost_usd
+                );
+            }
+            println!();
+        }
+
+        for (provider, days) in &imported {
+            any_data = true;
+            println!("## {provider} cost (imported)\n");
+            println!("| Date | Cost |");
+            println!("|------|------|");
+            for day in days {
+                println!("| {} | ${:.2} |", day.date.format("%a %m/%d"), day.cost_usd);
+            }
+            println!();
+        }
+
         if !any_data {
             println!("No usage data found for the specified period.");
         }
@@ -186,6 +280,16 @@ fn execute_show(
         }
     }
 
+    for (gateway, days) in &gateways {
+        any_data = true;
+        println!("{}", render_gateway_history(gateway, days));
+    }
+
+    for (provider, days) in &imported {
+        any_data = true;
+        println!("{}", render_imported_history(provider, days));
+    }
+
     if !any_data {
         println!("No usage data found for the specified period.");
         println!("Run `caut usage` to start collecting usage data.");
@@ -194,6 +298,498 @@ fn execute_show(
     Ok(())
 }
 
+/// Execute the list subcommand - list snapshots matching filters.
+fn execute_list(args: &HistoryListArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let (filter, snapshots) = query_list(args)?;
+
+    match format {
+        OutputFormat::Json => {
+            let output = list_json(&filter, &snapshots);
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Md => {
+            println!("# Usage Snapshots\n");
+            if snapshots.is_empty() {
+                println!("No snapshots match the given filters.");
+                return Ok(());
+            }
+            println!("| Time | Provider | Account | Session % | Weekly % | Note |");
+            println!("|------|----------|---------|-----------|----------|------|");
+            for s in &snapshots {
+                println!(
+                    "| {} | {} | {} | {} | {} | {} |",
+                    format_list_time(s.fetched_at),
+                    s.provider.cli_name(),
+                    s.account_email.as_deref().unwrap_or("-"),
+                    format_list_pct(s.primary_used_pct),
+                    format_list_pct(s.secondary_used_pct),
+                    s.note.as_deref().unwrap_or("")
+                );
+            }
+        }
+        OutputFormat::Human => {
+            if snapshots.is_empty() {
+                println!("No snapshots match the given filters.");
+                return Ok(());
+            }
+            println!(
+                "{}  {}  {}  {:>8}  {:>8}",
+                pad_to_width("Time", 16),
+                pad_to_width("Provider", 10),
+                pad_to_width("Account", LIST_ACCOUNT_WIDTH),
+                "Session",
+                "Weekly"
+            );
+            for s in &snapshots {
+                let account = truncate_middle(
+                    s.account_email.as_deref().unwrap_or("-"),
+                    LIST_ACCOUNT_WIDTH,
+                );
+                let note = s
+                    .note
+                    .as_deref()
+                    .map_or_else(String::new, |note| format!("  {note}"));
+                println!(
+                    "{}  {}  {}  {:>8}  {:>8}{note}",
+                    pad_to_width(&format_list_time(s.fetched_at), 16),
+                    pad_to_width(s.provider.cli_name(), 10),
+                    pad_to_width(&account, LIST_ACCOUNT_WIDTH),
+                    format_list_pct(s.primary_used_pct),
+                    format_list_pct(s.secondary_used_pct)
+                );
+            }
+            println!();
+            println!("{} snapshot(s)", snapshots.len());
+        }
+    }
+
+    Ok(())
+}
+
+/// The `history list --json` output for `args`; also served by `caut serve`
+/// at `/history`.
+///
+/// # Errors
+/// Returns an error if the arguments are invalid or there is no history
+/// database to query.
+pub(crate) fn list_output(args: &HistoryListArgs) -> Result<serde_json::Value> {
+    let (filter, snapshots) = query_list(args)?;
+    Ok(list_json(&filter, &snapshots))
+}
+
+fn query_list(args: &HistoryListArgs) -> Result<(SnapshotFilter, Vec<StoredSnapshot>)> {
+    let filter = build_list_filter(args)?;
+
+    let paths = AppPaths::new();
+    let history_path = paths.history_db_file();
+    if !history_path.exists() {
+        return Err(CautError::Config(
+            "No history database found. Run `caut usage` to start collecting data.".to_string(),
+        ));
+    }
+
+    let store = HistoryStore::open(&history_path)?;
+    let snapshots = store.query_snapshots(&filter)?;
+    Ok((filter, snapshots))
+}
+
+fn list_json(filter: &SnapshotFilter, snapshots: &[StoredSnapshot]) -> serde_json::Value {
+    serde_json::json!({
+        "schemaVersion": "caut.v1",
+        "command": "history list",
+        "data": {
+            "filters": {
+                "provider": filter.provider.map(Provider::cli_name),
+                "account": filter.account,
+                "minUsedPct": filter.min_used_pct,
+                "from": filter.from.map(|dt| dt.to_rfc3339()),
+                "to": filter.to.map(|dt| dt.to_rfc3339()),
+                "limit": filter.limit,
+            },
+            "snapshots": snapshots.iter().map(snapshot_json).collect::<Vec<_>>(),
+        }
+    })
+}
+
+/// Execute the changes subcommand - list detected plan limit changes.
+fn execute_changes(args: &HistoryChangesArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let provider = args
+        .provider
+        .as_deref()
+        .map(Provider::from_cli_name)
+        .transpose()?;
+
+    let history_path = AppPaths::new().history_db_file();
+    if !history_path.exists() {
+        return Err(CautError::Config(
+            "No history database found. Run `caut usage` to start collecting data.".to_string(),
+        ));
+    }
+
+    let store = HistoryStore::open(&history_path)?;
+    let since = Utc::now() - Duration::days(i64::from(args.days));
+    let changes: Vec<PlanLimitChange> = store
+        .plan_limit_changes(since)?
+        .into_iter()
+        .filter(|change| provider.is_none_or(|p| change.provider == p))
+        .collect();
+
+    match format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "schemaVersion": "caut.v1",
+                "command": "history changes",
+                "data": {
+                    "days": args.days,
+                    "changes": changes,
+                }
+            });
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Md => {
+            println!("# Plan Limit Changes (last {} days)\n", args.days);
+            if changes.is_empty() {
+                println!("No plan limit changes detected.");
+            }
+            for change in &changes {
+                println!("- {}", change.message());
+            }
+        }
+        OutputFormat::Human => {
+            if changes.is_empty() {
+                println!("No plan limit changes in the last {} days.", args.days);
+                return Ok(());
+            }
+            for change in &changes {
+                println!("{}", change.message());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Execute the profile subcommand - typical usage by weekday and hour.
+fn execute_profile(args: &HistoryProfileArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let provider = args
+        .provider
+        .as_deref()
+        .map(Provider::from_cli_name)
+        .transpose()?;
+
+    let history_path = AppPaths::new().history_db_file();
+    if !history_path.exists() {
+        return Err(CautError::Config(
+            "No history database found. Run `caut usage` to start collecting data.".to_string(),
+        ));
+    }
+
+    let store = HistoryStore::open(&history_path)?;
+    let mut providers: Vec<Provider> = match provider {
+        Some(provider) => vec![provider],
+        None => store.get_latest_all()?.into_keys().collect(),
+    };
+    providers.sort_by_key(|p| p.cli_name());
+    let now = Local::now();
+    let profiles = providers
+        .iter()
+        .map(|provider| load_profile(&store, *provider, args.weeks, &now))
+        .collect::<Result<Vec<_>>>()?;
+
+    match format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "schemaVersion": "caut.v1",
+                "command": "history profile",
+                "data": {
+                    "weeks": args.weeks,
+                    "profiles": profiles,
+                }
+            });
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Md => {
+            println!("# Usage Profile (last {} weeks)\n", args.weeks);
+            if profiles.is_empty() {
+                println!("No history data available.");
+            }
+            for profile in &profiles {
+                println!("```text");
+                for line in render_profile(profile, args.ascii) {
+                    println!("{line}");
+                }
+                println!("```\n");
+            }
+        }
+        OutputFormat::Human => {
+            if profiles.is_empty() {
+                println!("No history data available.");
+                println!("Run `caut usage` to start collecting usage data.");
+            }
+            for (i, profile) in profiles.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                for line in render_profile(profile, args.ascii) {
+                    println!("{line}");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Build the session-window profile of `provider` from the last `weeks`.
+pub(crate) fn load_profile(
+    store: &HistoryStore,
+    provider: Provider,
+    weeks: u32,
+    now: &DateTime<Local>,
+) -> Result<UsageProfile> {
+    let to = now.with_timezone(&Utc);
+    let history = store.get_snapshots(&provider, to - Duration::weeks(i64::from(weeks)), to)?;
+    Ok(build_profile(
+        provider.cli_name(),
+        &history,
+        UsageWindow::Primary,
+        now,
+    ))
+}
+
+/// Render a profile as an hour-by-weekday heatmap with today's row below it,
+/// followed by how today compares and the usually quiet hours left.
+pub(crate) fn render_profile(profile: &UsageProfile, ascii: bool) -> Vec<String> {
+    let shades = if ascii {
+        ['.', '-', '+', '*', '#']
+    } else {
+        ['·', '░', '▒', '▓', '█']
+    };
+    let max = profile
+        .typical
+        .iter()
+        .chain(std::iter::once(&profile.today))
+        .flat_map(|day| day.hours.iter().flatten())
+        .fold(0.0_f64, |a, b| a.max(*b));
+    let row = |day: &WeekdayProfile| -> String {
+        day.hours
+            .iter()
+            .map(|hour| hour.map_or(' ', |v| profile_shade(v, max, &shades)))
+            .collect()
+    };
+
+    let mut lines = vec![
+        format!(
+            "{} - {} window, % used per hour (darkest: {max:.0}%)",
+            profile.provider, profile.window
+        ),
+        format!("{:<7}{:<6}{:<6}{:<6}18", "", "0", "6", "12"),
+    ];
+    for day in &profile.typical {
+        lines.push(format!(
+            "{:<6} {}  {} days",
+            day.weekday,
+            row(day),
+            day.days
+        ));
+    }
+    lines.push(format!("{:<6} {}", "Today", row(&profile.today)));
+
+    let typical = profile.typical_today();
+    if profile.today.days == 0 {
+        lines.push("No snapshots yet today.".to_string());
+    } else if typical.days > 0 {
+        lines.push(format!(
+            "So far today: {:.0}% used; a typical {} by this hour: {:.0}%.",
+            profile.today.through(profile.hour),
+            typical.weekday,
+            typical.through(profile.hour)
+        ));
+    }
+    let quiet = typical.quietest_after(profile.hour, 3);
+    if typical.days > 0 && !quiet.is_empty() {
+        let hours: Vec<String> = quiet.iter().map(|h| format!("{h:02}:00")).collect();
+        lines.push(format!(
+            "Usually quietest later today: {}.",
+            hours.join(", ")
+        ));
+    }
+    lines
+}
+
+/// Shade for an hour's usage relative to the busiest hour.
+fn profile_shade(value: f64, max: f64, shades: &[char; 5]) -> char {
+    if value < 0.5 || max <= 0.0 {
+        return shades[0];
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // clamped to 1..=4
+    let level = (value / max * 4.0).ceil().clamp(1.0, 4.0) as usize;
+    shades[level]
+}
+
+/// Translate `history list` arguments into a store filter.
+fn build_list_filter(args: &HistoryListArgs) -> Result<SnapshotFilter> {
+    if let Some(min_used) = args.min_used
+        && !(0.0..=100.0).contains(&min_used)
+    {
+        return Err(CautError::Config(format!(
+            "--min-used must be between 0 and 100 (got {min_used})"
+        )));
+    }
+
+    let (from, to) = match args.between.as_deref() {
+        Some([start, end]) => {
+            let (from, to) = parse_humane_range(start, end)?;
+            (Some(from), Some(to))
+        }
+        Some(_) => {
+            return Err(CautError::Config(
+                "--between expects exactly two values: FROM TO".to_string(),
+            ));
+        }
+        None => (
+            args.since
+                .as_deref()
+                .map(parse_humane_datetime)
+                .transpose()?,
+            args.until
+                .as_deref()
+                .map(parse_humane_datetime)
+                .transpose()?,
+        ),
+    };
+
+    Ok(SnapshotFilter {
+        provider: args
+            .provider
+            .as_deref()
+            .map(Provider::from_cli_name)
+            .transpose()?,
+        account: args.account.clone(),
+        min_used_pct: args.min_used,
+        from,
+        to,
+        limit: Some(args.limit),
+    })
+}
+
+fn format_list_time(at: DateTime<Utc>) -> String {
+    at.with_timezone(&Local)
+        .format("%a %Y-%m-%d %H:%M")
+        .to_string()
+}
+
+fn format_list_pct(value: Option<f64>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| format!("{v:.1}%"))
+}
+
+/// One day of imported gateway usage, summed over models.
+struct GatewayDay {
+    date: NaiveDate,
+    requests: i64,
+    cost_usd: f64,
+}
+
+/// Imported gateway usage since `from`, per gateway and day.
+fn gateway_history(
+    store: &HistoryStore,
+    from: NaiveDate,
+) -> Result<Vec<(String, Vec<GatewayDay>)>> {
+    let mut gateways: Vec<(String, Vec<GatewayDay>)> = Vec::new();
+    // Rows arrive ordered by gateway, then day.
+    for row in store.gateway_usage(from)? {
+        if gateways
+            .last()
+            .is_none_or(|(gateway, _)| *gateway != row.gateway)
+        {
+            gateways.push((row.gateway.clone(), Vec::new()));
+        }
+        let Some((_, days)) = gateways.last_mut() else {
+            continue;
+        };
+        match days.last_mut() {
+            Some(day) if day.date == row.day => {
+                day.requests += row.requests;
+                day.cost_usd += row.cost_usd;
+            }
+            _ => days.push(GatewayDay {
+                date: row.day,
+                requests: row.requests,
+                cost_usd: row.cost_usd,
+            }),
+        }
+    }
+    Ok(gateways)
+}
+
+fn render_gateway_history(gateway: &str, days: &[GatewayDay]) -> String {
+    let mut out = format!("{gateway} gateway (imported)\n");
+    for day in days {
+        out.push_str(&format!(
+            "{}: {:>7} requests  ${:.2}\n",
+            day.date.format("%a %m/%d"),
+            day.requests,
+            day.cost_usd
+        ));
+    }
+    let total: f64 = days.iter().map(|d| d.cost_usd).sum();
+    out.push_str(&format!("Total: ${total:.2}\n"));
+    out
+}
+
+/// One day of imported cost history, summed over models.
+struct ImportedDay {
+    date: NaiveDate,
+    cost_usd: f64,
+}
+
+/// Cost history imported from ccusage or `CodexBar` since `from`, per
+/// provider and day, limited to `provider` when given.
+fn imported_cost_history(
+    store: &HistoryStore,
+    from: NaiveDate,
+    provider: Option<Provider>,
+) -> Result<Vec<(String, Vec<ImportedDay>)>> {
+    let mut providers: Vec<(String, Vec<ImportedDay>)> = Vec::new();
+    // Rows arrive ordered by provider, then day.
+    for row in store.imported_costs(from)? {
+        if provider.is_some_and(|p| p.cli_name() != row.provider) {
+            continue;
+        }
+        if providers
+            .last()
+            .is_none_or(|(provider, _)| *provider != row.provider)
+        {
+            providers.push((row.provider.clone(), Vec::new()));
+        }
+        let Some((_, days)) = providers.last_mut() else {
+            continue;
+        };
+        match days.last_mut() {
+            Some(day) if day.date == row.day => day.cost_usd += row.cost_usd,
+            _ => days.push(ImportedDay {
+                date: row.day,
+                cost_usd: row.cost_usd,
+            }),
+        }
+    }
+    Ok(providers)
+}
+
+fn render_imported_history(provider: &str, days: &[ImportedDay]) -> String {
+    let mut out = format!("{provider} cost (imported)\n");
+    for day in days {
+        out.push_str(&format!(
+            "{}: ${:.2}\n",
+            day.date.format("%a %m/%d"),
+            day.cost_usd
+        ));
+    }
+    let total: f64 = days.iter().map(|d| d.cost_usd).sum();
+    out.push_str(&format!("Total: ${total:.2}\n"));
+    out
+}
+
 /// Get daily history data for a provider, suitable for chart rendering.
 fn get_daily_history(
     store: &HistoryStore,
@@ -272,11 +868,7 @@ fn execute_prune(args: &HistoryPruneArgs, format: OutputFormat, pretty: bool) ->
                 "data": null,
                 "message": "No history database found"
             });
-            if pretty {
-                println!("{}", serde_json::to_string_pretty(&output)?);
-            } else {
-                println!("{}", serde_json::to_string(&output)?);
-            }
+            robot::print_json(&output, pretty)?;
         } else {
             println!("No history database found at: {}", history_path.display());
             println!("Nothing to prune.");
@@ -331,11 +923,7 @@ fn execute_prune(args: &HistoryPruneArgs, format: OutputFormat, pretty: bool) ->
                 }
             });
 
-            if pretty {
-                println!("{}", serde_json::to_string_pretty(&output)?);
-            } else {
-                println!("{}", serde_json::to_string(&output)?);
-            }
+            robot::print_json(&output, pretty)?;
         }
         OutputFormat::Md => {
             println!("# History Prune Results\n");
@@ -469,11 +1057,7 @@ fn execute_stats(format: OutputFormat, pretty: bool) -> Result<()> {
                 "data": null,
                 "message": "No history database found"
             });
-            if pretty {
-                println!("{}", serde_json::to_string_pretty(&output)?);
-            } else {
-                println!("{}", serde_json::to_string(&output)?);
-            }
+            robot::print_json(&output, pretty)?;
         } else {
             println!("No history database found at: {}", history_path.display());
         }
@@ -487,6 +1071,8 @@ fn execute_stats(format: OutputFormat, pretty: bool) -> Result<()> {
     let snapshot_count = count_table(&store, "usage_snapshots")?;
     let aggregate_count = count_table(&store, "daily_aggregates")?;
     let prune_count = count_table(&store, "prune_history")?;
+    let gateway_count = count_table(&store, "gateway_usage")?;
+    let imported_count = count_table(&store, "imported_costs")?;
 
     match format {
         OutputFormat::Json => {
@@ -499,6 +1085,8 @@ fn execute_stats(format: OutputFormat, pretty: bool) -> Result<()> {
                     "snapshotCount": snapshot_count,
                     "aggregateCount": aggregate_count,
                     "pruneHistoryCount": prune_count,
+                    "gatewayUsageCount": gateway_count,
+                    "importedCostCount": imported_count,
                     "defaults": {
                         "detailedRetentionDays": DEFAULT_DETAILED_RETENTION_DAYS,
                         "aggregateRetentionDays": DEFAULT_AGGREGATE_RETENTION_DAYS,
@@ -506,11 +1094,7 @@ fn execute_stats(format: OutputFormat, pretty: bool) -> Result<()> {
                     }
                 }
             });
-            if pretty {
-                println!("{}", serde_json::to_string_pretty(&output)?);
-            } else {
-                println!("{}", serde_json::to_string(&output)?);
-            }
+            robot::print_json(&output, pretty)?;
         }
         OutputFormat::Md => {
             println!("# History Database Statistics\n");
@@ -521,6 +1105,8 @@ fn execute_stats(format: OutputFormat, pretty: bool) -> Result<()> {
             println!("| Snapshots | {snapshot_count} |");
             println!("| Daily aggregates | {aggregate_count} |");
             println!("| Prune operations | {prune_count} |");
+            println!("| Imported gateway rows | {gateway_count} |");
+            println!("| Imported cost rows | {imported_count} |");
             println!("\n## Default Retention Policy\n");
             println!("- Detailed retention: {DEFAULT_DETAILED_RETENTION_DAYS} days");
             println!("- Aggregate retention: {DEFAULT_AGGREGATE_RETENTION_DAYS} days");
@@ -536,6 +1122,8 @@ fn execute_stats(format: OutputFormat, pretty: bool) -> Result<()> {
             println!("  Snapshots: {snapshot_count}");
             println!("  Daily aggregates: {aggregate_count}");
             println!("  Prune history: {prune_count}");
+            println!("  Imported gateway rows: {gateway_count}");
+            println!("  Imported cost rows: {imported_count}");
             println!();
             println!("Default retention policy:");
             println!("  Detailed: {DEFAULT_DETAILED_RETENTION_DAYS} days");
@@ -547,7 +1135,7 @@ fn execute_stats(format: OutputFormat, pretty: bool) -> Result<()> {
     Ok(())
 }
 
-/// Execute the export subcommand - export history data to JSON or CSV.
+/// Execute the export subcommand - export history data to JSON, CSV, or Parquet.
 fn execute_export(args: &HistoryExportArgs) -> Result<()> {
     let paths = AppPaths::new();
     let history_path = paths.history_db_file();
@@ -560,26 +1148,7 @@ fn execute_export(args: &HistoryExportArgs) -> Result<()> {
     }
 
     let store = HistoryStore::open(&history_path)?;
-
-    // Parse time range
-    let to = if let Some(ref until_str) = args.until {
-        parse_date_arg(until_str)?
-    } else {
-        Utc::now()
-    };
-
-    let from = if let Some(ref since_str) = args.since {
-        parse_date_arg(since_str)?
-    } else {
-        // Default to all time (1 year ago)
-        to - Duration::days(365)
-    };
-
-    if from > to {
-        return Err(CautError::Config(
-            "Start date (--since) must be before end date (--until)".to_string(),
-        ));
-    }
+    let (from, to) = export_range(args.since.as_deref(), args.until.as_deref())?;
 
     // Parse provider filter
     let providers: Vec<Provider> = if let Some(ref provider_name) = args.provider {
@@ -603,55 +1172,37 @@ fn execute_export(args: &HistoryExportArgs) -> Result<()> {
         all_snapshots.truncate(limit);
     }
 
-    // Create writer (file or stdout)
-    let writer: Box<dyn Write> = if let Some(ref path) = args.output {
-        let file = File::create(path).map_err(|e| {
-            CautError::Config(format!(
-                "Failed to create output file '{}': {e}",
-                path.display()
-            ))
-        })?;
-        Box::new(BufWriter::new(file))
-    } else {
-        Box::new(std::io::stdout())
-    };
+    // Written to stdout; `--output` sends it to a file
+    let mut writer: Box<dyn Write> = Box::new(BufWriter::new(std::io::stdout()));
 
     // Export in the requested format
     match args.format {
         ExportFormat::Json => export_json(writer, &all_snapshots, from, to)?,
-        ExportFormat::Csv => export_csv(writer, &all_snapshots)?,
-    }
-
-    // Print summary to stderr if writing to file
-    if let Some(ref output_path) = args.output {
-        eprintln!(
-            "Exported {} snapshots to {}",
-            all_snapshots.len(),
-            output_path.display()
-        );
+        ExportFormat::Csv => export_csv(&mut *writer, &all_snapshots)?,
+        ExportFormat::Parquet => {
+            let file = parquet::write_table(&export::snapshot_columns(&all_snapshots))?;
+            writer.write_all(&file)?;
+            writer.flush()?;
+        }
     }
 
     Ok(())
 }
 
-/// Parse a date argument (RFC3339 or YYYY-MM-DD format).
-fn parse_date_arg(s: &str) -> Result<DateTime<Utc>> {
-    // Try RFC3339 first
-    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
-        return Ok(dt.with_timezone(&Utc));
-    }
+/// Parse an export's `--since`/`--until`, defaulting to the last year.
+pub(crate) fn export_range(
+    since: Option<&str>,
+    until: Option<&str>,
+) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let to = until.map_or_else(|| Ok(Utc::now()), parse_humane_datetime)?;
+    let from = since.map_or_else(|| Ok(to - Duration::days(365)), parse_humane_datetime)?;
 
-    // Try YYYY-MM-DD format
-    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
-        let datetime = date
-            .and_hms_opt(0, 0, 0)
-            .ok_or_else(|| CautError::Config(format!("Invalid date: {s}")))?;
-        return Ok(Utc.from_utc_datetime(&datetime));
+    if from > to {
+        return Err(CautError::Config(
+            "Start date (--since) must be before end date (--until)".to_string(),
+        ));
     }
-
-    Err(CautError::Config(format!(
-        "Invalid date format: '{s}'. Use RFC3339 (2026-01-18T12:00:00Z) or YYYY-MM-DD (2026-01-18)."
-    )))
+    Ok((from, to))
 }
 
 /// Export snapshots to JSON format.
@@ -672,33 +1223,7 @@ fn export_json(
     // Calculate date range in days
     let date_range_days = (to - from).num_days();
 
-    // Build snapshot data
-    let snapshot_data: Vec<serde_json::Value> = snapshots
-        .iter()
-        .map(|s| {
-            serde_json::json!({
-                "id": s.id,
-                "provider": s.provider.cli_name(),
-                "fetched_at": s.fetched_at.to_rfc3339(),
-                "source": s.source,
-                "primary_used_pct": s.primary_used_pct,
-                "primary_window_minutes": s.primary_window_minutes,
-                "primary_resets_at": s.primary_resets_at.map(|dt| dt.to_rfc3339()),
-                "secondary_used_pct": s.secondary_used_pct,
-                "secondary_window_minutes": s.secondary_window_minutes,
-                "secondary_resets_at": s.secondary_resets_at.map(|dt| dt.to_rfc3339()),
-                "tertiary_used_pct": s.tertiary_used_pct,
-                "tertiary_window_minutes": s.tertiary_window_minutes,
-                "tertiary_resets_at": s.tertiary_resets_at.map(|dt| dt.to_rfc3339()),
-                "cost_today_usd": s.cost_today_usd,
-                "cost_mtd_usd": s.cost_mtd_usd,
-                "credits_remaining": s.credits_remaining,
-                "account_email": s.account_email,
-                "account_org": s.account_org,
-                "fetch_duration_ms": s.fetch_duration_ms,
-            })
-        })
-        .collect();
+    let snapshot_data: Vec<serde_json::Value> = snapshots.iter().map(snapshot_json).collect();
 
     let output = serde_json::json!({
         "schemaVersion": "caut.export.v1",
@@ -723,8 +1248,34 @@ fn export_json(
     Ok(())
 }
 
+/// Serialize a stored snapshot as a snake_case JSON object.
+fn snapshot_json(s: &StoredSnapshot) -> serde_json::Value {
+    serde_json::json!({
+        "id": s.id,
+        "provider": s.provider.cli_name(),
+        "fetched_at": s.fetched_at.to_rfc3339(),
+        "source": s.source,
+        "primary_used_pct": s.primary_used_pct,
+        "primary_window_minutes": s.primary_window_minutes,
+        "primary_resets_at": s.primary_resets_at.map(|dt| dt.to_rfc3339()),
+        "secondary_used_pct": s.secondary_used_pct,
+        "secondary_window_minutes": s.secondary_window_minutes,
+        "secondary_resets_at": s.secondary_resets_at.map(|dt| dt.to_rfc3339()),
+        "tertiary_used_pct": s.tertiary_used_pct,
+        "tertiary_window_minutes": s.tertiary_window_minutes,
+        "tertiary_resets_at": s.tertiary_resets_at.map(|dt| dt.to_rfc3339()),
+        "cost_today_usd": s.cost_today_usd,
+        "cost_mtd_usd": s.cost_mtd_usd,
+        "credits_remaining": s.credits_remaining,
+        "account_email": s.account_email,
+        "account_org": s.account_org,
+        "fetch_duration_ms": s.fetch_duration_ms,
+        "note": s.note,
+    })
+}
+
 /// Export snapshots to CSV format.
-fn export_csv(mut writer: Box<dyn Write>, snapshots: &[StoredSnapshot]) -> Result<()> {
+fn export_csv(writer: &mut dyn Write, snapshots: &[StoredSnapshot]) -> Result<()> {
     // Write CSV header
     writeln!(
         writer,
@@ -766,15 +1317,6 @@ fn export_csv(mut writer: Box<dyn Write>, snapshots: &[StoredSnapshot]) -> Resul
     Ok(())
 }
 
-/// Escape a string for CSV (quote if contains comma, quote, or newline).
-fn csv_escape(s: &str) -> String {
-    if s.contains(',') || s.contains('"') || s.contains('\n') {
-        format!("\"{}\"", s.replace('"', "\"\""))
-    } else {
-        s.to_string()
-    }
-}
-
 /// Format optional f64 for CSV.
 fn csv_opt_f64(v: Option<f64>) -> String {
     v.map_or(String::new(), |n| format!("{n}"))
@@ -805,20 +1347,184 @@ fn count_table(store: &HistoryStore, table: &str) -> Result<i64> {
     store.count_rows(table)
 }
 
-/// Format bytes in human-readable form.
-#[allow(clippy::cast_precision_loss)] // byte sizes fit comfortably in f64
-fn format_bytes(bytes: u64) -> String {
-    const KB: u64 = 1024;
-    const MB: u64 = KB * 1024;
-    const GB: u64 = MB * 1024;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn stored_snapshot(id: i64, provider: Provider, hour: u32) -> StoredSnapshot {
+        StoredSnapshot {
+            id,
+            provider,
+            fetched_at: Utc.with_ymd_and_hms(2026, 1, 15, hour, 0, 0).unwrap(),
+            source: "cli".to_string(),
+            primary_used_pct: None,
+            primary_window_minutes: None,
+            primary_resets_at: None,
+            secondary_used_pct: None,
+            secondary_window_minutes: None,
+            secondary_resets_at: None,
+            tertiary_used_pct: None,
+            tertiary_window_minutes: None,
+            tertiary_resets_at: None,
+            cost_today_usd: None,
+            cost_mtd_usd: None,
+            credits_remaining: None,
+            account_email: None,
+            account_org: None,
+            fetch_duration_ms: None,
+            created_at: None,
+            note: None,
+        }
+    }
+
+    fn render_csv(snapshots: &[StoredSnapshot]) -> String {
+        let mut buf = Vec::new();
+        export_csv(&mut buf, snapshots).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
 
-    if bytes >= GB {
-        format!("{:.2} GB", bytes as f64 / GB as f64)
-    } else if bytes >= MB {
-        format!("{:.2} MB", bytes as f64 / MB as f64)
-    } else if bytes >= KB {
-        format!("{:.2} KB", bytes as f64 / KB as f64)
-    } else {
-        format!("{bytes} bytes")
+    #[test]
+    fn export_csv_snapshot() {
+        let mut full = stored_snapshot(1, Provider::Claude, 12);
+        full.source = "oauth".to_string();
+        full.primary_used_pct = Some(28.5);
+        full.primary_window_minutes = Some(300);
+        full.primary_resets_at = Some(Utc.with_ymd_and_hms(2026, 1, 15, 15, 0, 0).unwrap());
+        full.secondary_used_pct = Some(61.0);
+        full.secondary_window_minutes = Some(10_080);
+        full.cost_today_usd = Some(3.25);
+        full.cost_mtd_usd = Some(41.5);
+        full.account_email = Some("dev@example.com".to_string());
+        full.account_org = Some("Acme, Inc.".to_string());
+        full.fetch_duration_ms = Some(412);
+
+        let mut partial = stored_snapshot(2, Provider::Codex, 11);
+        partial.primary_used_pct = Some(90.0);
+
+        insta::assert_snapshot!(render_csv(&[full, partial]), @r#"
+        id,provider,fetched_at,source,primary_used_pct,primary_window_minutes,primary_resets_at,secondary_used_pct,secondary_window_minutes,secondary_resets_at,tertiary_used_pct,tertiary_window_minutes,tertiary_resets_at,cost_today_usd,cost_mtd_usd,credits_remaining,account_email,account_org,fetch_duration_ms
+        1,claude,2026-01-15T12:00:00+00:00,oauth,28.5,300,2026-01-15T15:00:00+00:00,61,10080,,,,,3.25,41.5,,dev@example.com,"Acme, Inc.",412
+        2,codex,2026-01-15T11:00:00+00:00,cli,90,,,,,,,,,,,,,,
+        "#);
+    }
+
+    #[test]
+    fn export_csv_empty_snapshot() {
+        insta::assert_snapshot!(render_csv(&[]), @"id,provider,fetched_at,source,primary_used_pct,primary_window_minutes,primary_resets_at,secondary_used_pct,secondary_window_minutes,secondary_resets_at,tertiary_used_pct,tertiary_window_minutes,tertiary_resets_at,cost_today_usd,cost_mtd_usd,credits_remaining,account_email,account_org,fetch_duration_ms");
+    }
+
+    fn list_args() -> HistoryListArgs {
+        HistoryListArgs {
+            provider: None,
+            account: None,
+            min_used: None,
+            between: None,
+            since: None,
+            until: None,
+            limit: 50,
+        }
+    }
+
+    #[test]
+    fn list_filter_maps_arguments() {
+        let mut args = list_args();
+        args.provider = Some("claude".to_string());
+        args.account = Some("work".to_string());
+        args.min_used = Some(80.0);
+        args.between = Some(vec![
+            "2026-01-12 9am".to_string(),
+            "2026-01-16 6pm".to_string(),
+        ]);
+
+        let filter = build_list_filter(&args).unwrap();
+        assert_eq!(filter.provider, Some(Provider::Claude));
+        assert_eq!(filter.account.as_deref(), Some("work"));
+        assert_eq!(filter.min_used_pct, Some(80.0));
+        assert_eq!(filter.limit, Some(50));
+        assert!(filter.from.unwrap() < filter.to.unwrap());
+    }
+
+    #[test]
+    fn list_filter_rejects_bad_values() {
+        let mut args = list_args();
+        args.min_used = Some(150.0);
+        assert!(build_list_filter(&args).is_err());
+
+        let mut args = list_args();
+        args.between = Some(vec!["fri 6pm".to_string(), "someday".to_string()]);
+        assert!(build_list_filter(&args).is_err());
+
+        let mut args = list_args();
+        args.provider = Some("nope".to_string());
+        assert!(build_list_filter(&args).is_err());
+    }
+
+    #[test]
+    fn list_filter_accepts_relative_since() {
+        let mut args = list_args();
+        args.since = Some("3d".to_string());
+
+        let filter = build_list_filter(&args).unwrap();
+        let age = Utc::now() - filter.from.unwrap();
+        assert!(age >= Duration::days(3));
+        assert!(age < Duration::days(3) + Duration::minutes(1));
+        assert!(filter.to.is_none());
+    }
+
+    #[test]
+    fn gateway_history_sums_models_per_day() {
+        use crate::core::gateway_import::GatewayUsage;
+
+        let store = HistoryStore::open_in_memory().unwrap();
+        let day = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let row = |gateway: &str, model: &str, requests, cost_usd| GatewayUsage {
+            gateway: gateway.to_string(),
+            day,
+            model: model.to_string(),
+            requests,
+            input_tokens: 0,
+            output_tokens: 0,
+            cost_usd,
+        };
+        store
+            .record_gateway_usage(
+                &[
+                    row("helicone", "gpt-4o", 30, 1.5),
+                    row("helicone", "claude-sonnet-4", 12, 2.25),
+                    row("gateway", "gpt-4o", 5, 0.5),
+                ],
+                Utc::now(),
+            )
+            .unwrap();
+
+        let gateways = gateway_history(&store, day).unwrap();
+        assert_eq!(gateways.len(), 2);
+        assert_eq!(gateways[0].0, "gateway");
+        let (gateway, days) = &gateways[1];
+        assert_eq!(
+            render_gateway_history(gateway, days),
+            "helicone gateway (imported)\nWed 10/14:      42 requests  $3.75\nTotal: $3.75\n"
+        );
+    }
+
+    #[test]
+    fn profile_heatmap_lines_up_with_hour_labels() {
+        let profile = build_profile(
+            "claude",
+            &[],
+            UsageWindow::Primary,
+            &Utc.with_ymd_and_hms(2026, 10, 15, 9, 0, 0).unwrap(),
+        );
+        let lines = render_profile(&profile, true);
+
+        assert_eq!(lines[1].find('6'), Some(13));
+        assert!(lines[2].starts_with("Mon    "));
+        assert_eq!(
+            lines.last().map(String::as_str),
+            Some("No snapshots yet today.")
+        );
+        assert_eq!(profile_shade(10.0, 10.0, &['.', '-', '+', '*', '#']), '#');
+        assert_eq!(profile_shade(3.0, 10.0, &['.', '-', '+', '*', '#']), '+');
     }
 }
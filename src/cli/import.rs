@@ -0,0 +1,251 @@
+//! Import command implementation.
+//!
+//! Pulls usage history kept by other tools into the history store so
+//! `caut history` covers it. Gateway sources (Helicone, or anything serving
+//! the OpenAI usage API) are imported as daily per-model rollups; see
+//! [`crate::core::gateway_import`]. Cost history from ccusage and `CodexBar`
+//! is imported per provider, day, and model; see
+//! [`crate::core::history_import`].
+
+use std::collections::BTreeSet;
+use std::io::Read;
+use std::path::Path;
+
+use chrono::{Duration, Local, NaiveDate, NaiveTime, Utc};
+use serde::Serialize;
+
+use crate::cli::args::{ImportArgs, ImportSource, OutputFormat};
+use crate::core::gateway_import::{self, GatewayUsage};
+use crate::core::history_import::{self, ImportedCost};
+use crate::core::models::RobotOutput;
+use crate::error::{CautError, Result};
+use crate::render::robot;
+use crate::storage::{AppPaths, HistoryStore};
+use crate::util::format::{format_cost, format_tokens};
+
+/// What an import wrote to the history store.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSummary {
+    pub source: String,
+    /// First day imported.
+    pub since: String,
+    /// Day/model rows written.
+    pub rows: usize,
+    /// Distinct provider days, for cost history imports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days: Option<usize>,
+    /// Requests logged, for gateway imports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requests: Option<i64>,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cost_usd: f64,
+}
+
+impl ImportSummary {
+    fn from_gateway(source: &str, since: String, rows: &[GatewayUsage]) -> Self {
+        Self {
+            source: source.to_string(),
+            since,
+            rows: rows.len(),
+            days: None,
+            requests: Some(rows.iter().map(|r| r.requests).sum()),
+            input_tokens: rows.iter().map(|r| r.input_tokens).sum(),
+            output_tokens: rows.iter().map(|r| r.output_tokens).sum(),
+            cost_usd: rows.iter().map(|r| r.cost_usd).sum(),
+        }
+    }
+
+    fn from_costs(source: &str, since: String, rows: &[ImportedCost]) -> Self {
+        let days: BTreeSet<_> = rows.iter().map(|r| (&r.provider, r.day)).collect();
+        Self {
+            source: source.to_string(),
+            since,
+            rows: rows.len(),
+            days: Some(days.len()),
+            requests: None,
+            input_tokens: rows.iter().filter_map(|r| r.input_tokens).sum(),
+            output_tokens: rows.iter().filter_map(|r| r.output_tokens).sum(),
+            cost_usd: rows.iter().map(|r| r.cost_usd).sum(),
+        }
+    }
+}
+
+/// Execute the import command.
+///
+/// # Errors
+/// Returns an error if the source's credentials are missing, the source tool
+/// is not installed, fetching from it fails, or the history database cannot
+/// be written.
+pub async fn execute(args: &ImportArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let since_day = Local::now().date_naive() - Duration::days(i64::from(args.days) - 1);
+    let since = since_day
+        .and_time(NaiveTime::MIN)
+        .and_local_timezone(Local)
+        .earliest()
+        .map_or_else(
+            || Utc::now() - Duration::days(i64::from(args.days)),
+            |t| t.to_utc(),
+        );
+
+    let since_label = since_day.to_string();
+    let summary = match args.from {
+        ImportSource::Helicone => {
+            let rows = gateway_import::fetch_helicone(args.url.as_deref(), since).await?;
+            open_store()?.record_gateway_usage(&rows, Utc::now())?;
+            ImportSummary::from_gateway("helicone", since_label, &rows)
+        }
+        ImportSource::Gateway => {
+            let rows = gateway_import::fetch_openai_usage(args.url.as_deref(), since).await?;
+            open_store()?.record_gateway_usage(&rows, Utc::now())?;
+            ImportSummary::from_gateway("gateway", since_label, &rows)
+        }
+        ImportSource::Ccusage | ImportSource::Codexbar => {
+            let (source, rows) = import_costs(args, since_day).await?;
+            open_store()?.record_imported_costs(&rows, Utc::now())?;
+            ImportSummary::from_costs(source, since_label, &rows)
+        }
+    };
+
+    match format {
+        OutputFormat::Json => {
+            let output = RobotOutput::new("import", summary);
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Human | OutputFormat::Md => println!("{}", render_summary(&summary)),
+    }
+    Ok(())
+}
+
+fn open_store() -> Result<HistoryStore> {
+    HistoryStore::open(&AppPaths::new().history_db_file())
+}
+
+/// Cost history from a saved report, or from running the tool.
+async fn import_costs(
+    args: &ImportArgs,
+    since: NaiveDate,
+) -> Result<(&'static str, Vec<ImportedCost>)> {
+    let ccusage = args.from == ImportSource::Ccusage;
+    let source = if ccusage { "ccusage" } else { "codexbar" };
+    let rows = match &args.file {
+        Some(path) => {
+            let json = read_report(path)?;
+            if ccusage {
+                history_import::parse_ccusage(&json, since)?
+            } else {
+                history_import::parse_codexbar(&json, since)?
+            }
+        }
+        None if ccusage => history_import::fetch_ccusage(since).await?,
+        None => history_import::fetch_codexbar(since).await?,
+    };
+    Ok((source, rows))
+}
+
+fn read_report(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut json = String::new();
+        std::io::stdin().read_to_string(&mut json)?;
+        return Ok(json);
+    }
+    std::fs::read_to_string(path)
+        .map_err(|e| CautError::Config(format!("cannot read {}: {e}", path.display())))
+}
+
+fn render_summary(summary: &ImportSummary) -> String {
+    if summary.rows == 0 {
+        return format!("No {} usage found since {}.", summary.source, summary.since);
+    }
+    if let Some(days) = summary.days {
+        return format!(
+            "Imported {days} days of cost history from {} since {}: {} ({} day/model rows).\n\
+             Days already stored were replaced. See them with `caut history show`.",
+            summary.source,
+            summary.since,
+            format_cost(summary.cost_usd),
+            summary.rows
+        );
+    }
+    format!(
+        "Imported {} requests from {} since {}: {} in, {} out, {} ({} day/model rows).\n\
+         See them with `caut history show`.",
+        summary.requests.unwrap_or(0),
+        summary.source,
+        summary.since,
+        format_tokens(summary.input_tokens),
+        format_tokens(summary.output_tokens),
+        format_cost(summary.cost_usd),
+        summary.rows
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn summary_totals_imported_rows() {
+        let day = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let row = |model: &str, requests, cost_usd| GatewayUsage {
+            gateway: "helicone".to_string(),
+            day,
+            model: model.to_string(),
+            requests,
+            input_tokens: 1_200_000,
+            output_tokens: 40_000,
+            cost_usd,
+        };
+        let summary = ImportSummary::from_gateway(
+            "helicone",
+            "2026-09-17".to_string(),
+            &[row("gpt-4o", 30, 3.5), row("claude-sonnet-4", 12, 1.25)],
+        );
+
+        assert_eq!(summary.requests, Some(42));
+        assert_eq!(
+            render_summary(&summary),
+            "Imported 42 requests from helicone since 2026-09-17: 2.4M in, 80.0K out, $4.75 (2 day/model rows).\n\
+             See them with `caut history show`."
+        );
+        let empty = ImportSummary::from_gateway("gateway", "2026-09-17".to_string(), &[]);
+        assert_eq!(
+            render_summary(&empty),
+            "No gateway usage found since 2026-09-17."
+        );
+    }
+
+    #[test]
+    fn summary_counts_provider_days_for_cost_history() {
+        let row = |provider: &str, day, model: &str| ImportedCost {
+            provider: provider.to_string(),
+            day: NaiveDate::from_ymd_opt(2026, 9, day).unwrap(),
+            model: model.to_string(),
+            input_tokens: None,
+            output_tokens: None,
+            cache_read_tokens: None,
+            cache_creation_tokens: None,
+            cost_usd: 1.5,
+            source: "codexbar".to_string(),
+        };
+        let summary = ImportSummary::from_costs(
+            "codexbar",
+            "2026-09-01".to_string(),
+            &[
+                row("claude", 1, "claude-opus-4"),
+                row("claude", 1, "claude-sonnet-4"),
+                row("codex", 1, ""),
+                row("claude", 2, "claude-opus-4"),
+            ],
+        );
+
+        assert_eq!(summary.days, Some(3));
+        assert_eq!(
+            render_summary(&summary),
+            "Imported 3 days of cost history from codexbar since 2026-09-01: $6.00 (4 day/model rows).\n\
+             Days already stored were replaced. See them with `caut history show`."
+        );
+    }
+}
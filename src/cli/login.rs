@@ -0,0 +1,126 @@
+//! Login and logout command implementation.
+//!
+//! Only Claude is supported: caut runs its OAuth flow (see
+//! [`crate::providers::claude::login`]). Other providers are signed in to
+//! with their own tools, which caut reads from.
+
+use std::io::{BufRead, Write};
+
+use crate::cli::args::{LoginArgs, LogoutArgs, OutputFormat};
+use crate::core::models::RobotOutput;
+use crate::core::provider::Provider;
+use crate::core::web_session;
+use crate::error::{CautError, Result};
+use crate::providers::claude;
+use crate::providers::claude::login::{self, LoginRequest};
+use crate::render::robot;
+
+/// Execute the login command.
+///
+/// # Errors
+/// Returns an error if the provider is not supported, no code is entered,
+/// the token exchange fails, or the keyring cannot be written.
+pub async fn execute_login(args: &LoginArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    supported_provider(&args.provider)?;
+    let request = LoginRequest::new()?;
+
+    // Prompts go to stderr so `--json` output stays parseable.
+    eprintln!("Open this URL to sign in to Claude:\n\n  {}\n", request.url);
+    if !args.no_browser {
+        open_browser(&request.url);
+    }
+    eprint!("Paste the authorization code: ");
+    std::io::stderr().flush()?;
+    let mut code = String::new();
+    std::io::stdin().lock().read_line(&mut code)?;
+
+    let session = request.complete(&code).await?;
+    if format == OutputFormat::Json {
+        return robot::print_json(
+            &RobotOutput::new(
+                "login",
+                serde_json::json!({
+                    "provider": "claude",
+                    "signedIn": true,
+                    "expiresAt": session.expires_at,
+                    "refreshable": session.refresh_token.is_some(),
+                }),
+            ),
+            pretty,
+        );
+    }
+    println!("Signed in to Claude. The token is stored in the system keyring.");
+    Ok(())
+}
+
+/// Execute the logout command.
+///
+/// # Errors
+/// Returns an error if the provider is not supported or the keyring cannot be
+/// accessed.
+pub fn execute_logout(args: &LogoutArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    supported_provider(&args.provider)?;
+    let tokens = login::logout()?;
+    // The imported claude.ai cookie is a sign-in caut stored too.
+    let cookie = web_session::forget(&claude::WEB_SESSION)?;
+    let removed = tokens || cookie;
+    if format == OutputFormat::Json {
+        return robot::print_json(
+            &RobotOutput::new(
+                "logout",
+                serde_json::json!({ "provider": "claude", "removed": removed }),
+            ),
+            pretty,
+        );
+    }
+    if removed {
+        println!("Signed out of Claude.");
+    } else {
+        println!("No Claude token stored by caut.");
+    }
+    println!("Claude Code's own sign-in is unchanged; use `claude auth logout` for that.");
+    Ok(())
+}
+
+fn supported_provider(name: &str) -> Result<Provider> {
+    let provider = Provider::from_cli_name(name)?;
+    if provider != Provider::Claude {
+        return Err(CautError::Config(format!(
+            "caut login supports claude only. {}",
+            provider.auth_suggestion()
+        )));
+    }
+    Ok(provider)
+}
+
+/// Best effort: the URL is printed either way.
+pub(crate) fn open_browser(url: &str) {
+    let (program, args): (&str, Vec<&str>) = if cfg!(target_os = "macos") {
+        ("open", vec![url])
+    } else if cfg!(windows) {
+        ("cmd", vec!["/C", "start", "", url])
+    } else {
+        ("xdg-open", vec![url])
+    };
+    if let Err(e) = std::process::Command::new(program)
+        .args(args)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+    {
+        tracing::debug!(program, error = %e, "Could not open browser");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_claude_is_supported() {
+        assert_eq!(supported_provider("Claude").unwrap(), Provider::Claude);
+        let err = supported_provider("codex").unwrap_err().to_string();
+        assert!(err.contains("codex auth login"));
+        assert!(supported_provider("nope").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+//! Merge command implementation.
+//!
+//! Combines `caut usage --json` output saved on several machines into one
+//! view, for a quick multi-machine overview without running a sync server.
+//! Payloads are keyed by provider and account: the same account seen on two
+//! machines appears once, from whichever snapshot is newer, while different
+//! accounts of one provider are all kept.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+
+use crate::cli::args::{MergeArgs, OutputFormat};
+use crate::cli::render::{Document, parse_documents};
+use crate::cli::usage::{UsageResults, render_usage_results};
+use crate::core::models::ProviderPayload;
+use crate::error::{CautError, Result};
+use crate::render::UsageRenderOptions;
+
+/// Execute the merge command.
+///
+/// # Errors
+/// Returns an error if an input cannot be read or is not caut usage JSON,
+/// or rendering fails.
+pub fn execute(args: &MergeArgs, format: OutputFormat, pretty: bool, no_color: bool) -> Result<()> {
+    let mut inputs = Vec::new();
+    for path in &args.inputs {
+        let label = path.display().to_string();
+        for document in parse_documents(&read_input(path)?)? {
+            match document {
+                Document::Usage { payloads, errors } => {
+                    inputs.push((label.clone(), payloads, errors));
+                }
+                Document::Cost { .. } => {
+                    return Err(CautError::Config(format!(
+                        "{label} holds cost output; merge combines caut usage --json output"
+                    )));
+                }
+            }
+        }
+    }
+
+    let results = merge(inputs);
+    let options = UsageRenderOptions {
+        no_color,
+        columns: (!args.columns.is_empty()).then(|| args.columns.clone()),
+        ..UsageRenderOptions::default()
+    };
+    render_usage_results(&results, format, pretty, &options)
+}
+
+fn read_input(path: &Path) -> Result<String> {
+    if path.as_os_str() == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+    std::fs::read_to_string(path)
+        .map_err(|e| CautError::Config(format!("Failed to read {}: {e}", path.display())))
+}
+
+/// Key a payload is merged under: provider and account, if known.
+fn merge_key(payload: &ProviderPayload) -> (String, Option<String>) {
+    let account = payload.account.clone().or_else(|| {
+        payload
+            .usage
+            .identity
+            .as_ref()
+            .and_then(|identity| identity.account_email.clone())
+    });
+    (payload.provider.clone(), account)
+}
+
+/// Union labelled inputs, keeping the newest payload per provider and
+/// account in first-seen order.
+///
+/// An input's errors are kept, prefixed with its label, unless another
+/// input returned usage for that provider.
+fn merge(inputs: Vec<(String, Vec<ProviderPayload>, Vec<String>)>) -> UsageResults {
+    let mut payloads: Vec<ProviderPayload> = Vec::new();
+    let mut index: HashMap<(String, Option<String>), usize> = HashMap::new();
+    let mut errors = Vec::new();
+
+    for (label, input_payloads, input_errors) in inputs {
+        for payload in input_payloads {
+            let key = merge_key(&payload);
+            match index.get(&key) {
+                Some(&at) => {
+                    if payload.usage.updated_at > payloads[at].usage.updated_at {
+                        payloads[at] = payload;
+                    }
+                }
+                None => {
+                    index.insert(key, payloads.len());
+                    payloads.push(payload);
+                }
+            }
+        }
+        errors.extend(input_errors.into_iter().map(|error| (label.clone(), error)));
+    }
+
+    let errors = errors
+        .into_iter()
+        .filter(|(_, error)| {
+            let provider = error.split(':').next().unwrap_or_default();
+            !payloads.iter().any(|payload| payload.provider == provider)
+        })
+        .map(|(label, error)| format!("{label}: {error}"))
+        .collect();
+
+    UsageResults {
+        payloads,
+        errors,
+        costs: Vec::new(),
+        budgets: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::make_test_provider_payload;
+    use chrono::Duration;
+
+    #[test]
+    fn keeps_newest_payload_per_provider_and_account() {
+        let laptop_claude = make_test_provider_payload("claude", "oauth");
+        let mut desktop_claude = make_test_provider_payload("claude", "oauth");
+        desktop_claude.usage.updated_at = laptop_claude.usage.updated_at + Duration::minutes(5);
+        desktop_claude.version = Some("desktop".to_string());
+        let mut work_claude = make_test_provider_payload("claude", "oauth");
+        work_claude.account = Some("work@example.com".to_string());
+
+        let results = merge(vec![
+            (
+                "laptop.json".to_string(),
+                vec![laptop_claude, make_test_provider_payload("codex", "cli")],
+                Vec::new(),
+            ),
+            (
+                "desktop.json".to_string(),
+                vec![desktop_claude, work_claude],
+                Vec::new(),
+            ),
+        ]);
+
+        let providers: Vec<(&str, Option<&str>)> = results
+            .payloads
+            .iter()
+            .map(|p| (p.provider.as_str(), p.account.as_deref()))
+            .collect();
+        assert_eq!(
+            providers,
+            [
+                ("claude", Some("test@example.com")),
+                ("codex", Some("test@example.com")),
+                ("claude", Some("work@example.com")),
+            ]
+        );
+        assert_eq!(results.payloads[0].version.as_deref(), Some("desktop"));
+    }
+
+    #[test]
+    fn drops_errors_another_machine_answered() {
+        let results = merge(vec![
+            (
+                "laptop.json".to_string(),
+                Vec::new(),
+                vec![
+                    "claude: timed out".to_string(),
+                    "gemini: not signed in".to_string(),
+                ],
+            ),
+            (
+                "desktop.json".to_string(),
+                vec![make_test_provider_payload("claude", "oauth")],
+                Vec::new(),
+            ),
+        ]);
+
+        assert_eq!(results.payloads.len(), 1);
+        assert_eq!(results.errors, ["laptop.json: gemini: not signed in"]);
+    }
+}
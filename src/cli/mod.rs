@@ -1,14 +1,46 @@
 //! CLI argument parsing and command dispatch.
 
+pub mod accounts;
+pub mod alert;
 pub mod args;
+pub mod budget;
+pub mod compare;
+pub mod completions;
+pub mod config;
 pub mod cost;
+pub mod daemon;
+pub mod diff;
 pub mod doctor;
+pub mod estimate;
+pub mod export;
+pub mod forecast;
 pub mod history;
+pub mod import;
+pub mod login;
+pub mod merge;
+pub mod notify;
+pub mod org;
+pub mod output;
+pub mod project;
 pub mod prompt;
+pub mod prune;
 pub mod query;
+pub mod render;
+pub mod repl;
+pub mod report;
+pub mod report_bug;
 pub mod serve;
 pub mod session;
+pub mod sessions;
+pub mod simulate;
+pub mod snapshot;
+pub mod statusline;
+pub mod summary;
+pub mod top;
+pub mod troubleshoot;
 pub mod usage;
+pub mod value;
+pub mod warm;
 pub mod watch;
 
 pub use args::{Cli, Commands, OutputFormat};
@@ -0,0 +1,120 @@
+//! Notify command implementation.
+//!
+//! `caut notify` fetches usage once, as `caut usage` does, and sends a short
+//! plain-text status (see [`crate::core::notify`]) to the `[notify]`
+//! channels, or to `--channel` instead. Every channel is tried; the command
+//! fails if any delivery did, so cron mails the error.
+
+use chrono::Utc;
+use serde::Serialize;
+
+use crate::cli::args::{NotifyArgs, OutputFormat};
+use crate::cli::usage;
+use crate::core::alerts::{self, AlertChannel};
+use crate::core::models::RobotOutput;
+use crate::core::notify::StatusMessage;
+use crate::core::summary::UsageSummary;
+use crate::error::{CautError, Result};
+use crate::render::robot;
+use crate::storage::config::Config;
+
+/// The outcome of sending to one channel.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Delivery {
+    channel: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Execute the notify command.
+///
+/// # Errors
+/// Returns an error if a channel is invalid or none is configured, the
+/// usage fetch fails outright, output serialization fails, or any channel
+/// could not be reached.
+pub async fn execute(args: &NotifyArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let channels = if args.channels.is_empty() {
+        Config::load()?.notify.channels
+    } else {
+        args.channels
+            .iter()
+            .map(|channel| AlertChannel::from_arg(channel))
+            .collect::<Result<Vec<_>>>()?
+    };
+    if channels.is_empty() && !args.dry_run {
+        return Err(CautError::Config(
+            "No notify channels configured. Add `channels` under [notify] in the config file, or pass --channel."
+                .to_string(),
+        ));
+    }
+
+    let results = usage::fetch_usage(&args.to_usage_args()).await?;
+    let summary = UsageSummary::build(
+        &results.payloads,
+        &results.costs,
+        results.errors.len(),
+        Utc::now(),
+    );
+    let message = StatusMessage::new(&results.payloads, &summary, &results.errors);
+
+    let mut deliveries = Vec::new();
+    if !args.dry_run {
+        let body = serde_json::json!({
+            "subject": message.subject,
+            "summary": summary,
+        });
+        let env = [
+            ("CAUT_NOTIFY_STATUS", summary.status.label().to_string()),
+            ("CAUT_NOTIFY_SUBJECT", message.subject.clone()),
+            ("CAUT_NOTIFY_MESSAGE", message.text.clone()),
+        ];
+        for channel in &channels {
+            let error = alerts::send(channel, &message.subject, &message.text, body.clone(), &env)
+                .await
+                .err();
+            if let Some(e) = &error {
+                tracing::warn!(%channel, error = %e, "Notify delivery failed");
+            }
+            deliveries.push(Delivery {
+                channel: channel.to_string(),
+                error: error.map(|e| e.to_string()),
+            });
+        }
+    }
+
+    match format {
+        OutputFormat::Json => {
+            let output = RobotOutput::new(
+                "notify",
+                serde_json::json!({
+                    "message": message,
+                    "summary": summary,
+                    "deliveries": deliveries,
+                    "dryRun": args.dry_run,
+                }),
+            );
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Human | OutputFormat::Md => {
+            if args.dry_run {
+                print!("{}", message.text);
+            }
+            for delivery in &deliveries {
+                match &delivery.error {
+                    Some(error) => println!("Failed: {} ({error})", delivery.channel),
+                    None => println!("Sent: {}", delivery.channel),
+                }
+            }
+        }
+    }
+
+    let failed = deliveries.iter().filter(|d| d.error.is_some()).count();
+    if failed > 0 {
+        return Err(CautError::Network(format!(
+            "{failed} of {} notify channel(s) failed",
+            deliveries.len()
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,221 @@
+//! Org command implementation.
+//!
+//! Shows per-seat consumption for a whole organization from a provider's
+//! Admin API (see [`crate::core::org_usage`]), as a table, JSON, or CSV.
+
+use std::cmp::Ordering;
+
+use chrono::{Duration, Utc};
+
+use crate::cli::args::{OrgCommand, OrgSort, OrgUsageArgs, OutputFormat};
+use crate::core::models::RobotOutput;
+use crate::core::org_usage::{OrgUsageReport, SeatUsage};
+use crate::error::{CautError, Result};
+use crate::providers::{claude, codex};
+use crate::render::robot;
+use crate::util::format::{format_cost, format_tokens, pad_to_width, truncate_middle};
+
+/// Column width for members in the human table.
+const MEMBER_WIDTH: usize = 32;
+
+/// Execute org commands.
+///
+/// # Errors
+/// Returns an error if the provider has no admin usage API, the admin key is
+/// missing or rejected, the CSV file cannot be written, or output
+/// serialization fails.
+pub async fn execute(cmd: &OrgCommand, format: OutputFormat, pretty: bool) -> Result<()> {
+    match cmd {
+        OrgCommand::Usage(args) => execute_usage(args, format, pretty).await,
+    }
+}
+
+async fn execute_usage(args: &OrgUsageArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let end = Utc::now().date_naive();
+    let start = end - Duration::days(i64::from(args.days) - 1);
+
+    let mut report = match args.provider.to_lowercase().as_str() {
+        "claude" | "anthropic" => claude::admin::fetch_org_usage(start, end).await?,
+        "openai" | "codex" => codex::admin::fetch_org_usage(start, end).await?,
+        _ => {
+            return Err(CautError::Config(format!(
+                "Organization usage is not available for {}. Use claude or openai.",
+                args.provider
+            )));
+        }
+    };
+    sort_seats(&mut report.seats, args.sort);
+
+    if let Some(path) = &args.csv_file {
+        std::fs::write(path, report.to_csv()).map_err(|e| {
+            CautError::Config(format!(
+                "Failed to write output file '{}': {e}",
+                path.display()
+            ))
+        })?;
+    }
+
+    if args.csv {
+        print!("{}", report.to_csv());
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Human => print!("{}", render_report(&report, false)),
+        OutputFormat::Md => print!("{}", render_report(&report, true)),
+        OutputFormat::Json => {
+            let output = RobotOutput::new("org usage", report);
+            robot::print_json(&output, pretty)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Sort seats; numeric columns descend, ties fall back to member name.
+fn sort_seats(seats: &mut [SeatUsage], sort: OrgSort) {
+    seats.sort_by(|a, b| {
+        let primary = match sort {
+            OrgSort::Cost => b
+                .cost_usd
+                .partial_cmp(&a.cost_usd)
+                .unwrap_or(Ordering::Equal),
+            OrgSort::Tokens => b.total_tokens().cmp(&a.total_tokens()),
+            OrgSort::Sessions => b.sessions.cmp(&a.sessions),
+            OrgSort::Member => Ordering::Equal,
+        };
+        primary.then_with(|| a.member.cmp(&b.member))
+    });
+}
+
+fn render_report(report: &OrgUsageReport, markdown: bool) -> String {
+    let title = format!(
+        "{} organization usage ({} to {})",
+        report.provider_name(),
+        report.start,
+        report.end
+    );
+    if report.seats.is_empty() {
+        return format!("{title}: no usage recorded.\n");
+    }
+
+    let mut out = if markdown {
+        format!(
+            "## {title}\n\n| Member | Sessions | Tokens | Cost | Models |\n|--------|----------|--------|------|--------|\n"
+        )
+    } else {
+        format!(
+            "{title}:\n\n  {} {:>8} {:>9} {:>10}  Models\n",
+            pad_to_width("Member", MEMBER_WIDTH),
+            "Sessions",
+            "Tokens",
+            "Cost"
+        )
+    };
+
+    for seat in &report.seats {
+        let models = seat.models.iter().cloned().collect::<Vec<_>>().join(", ");
+        if markdown {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                seat.member,
+                seat.sessions,
+                format_tokens(seat.total_tokens()),
+                format_cost(seat.cost_usd),
+                models
+            ));
+        } else {
+            out.push_str(&format!(
+                "  {} {:>8} {:>9} {:>10}  {}\n",
+                pad_to_width(&truncate_middle(&seat.member, MEMBER_WIDTH), MEMBER_WIDTH),
+                seat.sessions,
+                format_tokens(seat.total_tokens()),
+                format_cost(seat.cost_usd),
+                models
+            ));
+        }
+    }
+
+    let seats = report.seats.len();
+    let plural = if seats == 1 { "" } else { "s" };
+    if markdown {
+        out.push_str(&format!(
+            "\n**Total:** {} across {seats} seat{plural} ({} tokens)\n",
+            format_cost(report.total_cost()),
+            format_tokens(report.total_tokens())
+        ));
+    } else {
+        out.push_str(&format!(
+            "\n  Total: {} across {seats} seat{plural} ({} tokens)\n",
+            format_cost(report.total_cost()),
+            format_tokens(report.total_tokens())
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn seat(member: &str, sessions: i64, tokens: i64, cost: f64) -> SeatUsage {
+        SeatUsage {
+            member: member.to_string(),
+            sessions,
+            input_tokens: tokens,
+            cost_usd: cost,
+            ..SeatUsage::default()
+        }
+    }
+
+    fn report() -> OrgUsageReport {
+        let day = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        OrgUsageReport::new(
+            "claude",
+            day,
+            day + Duration::days(6),
+            [
+                seat("bo@example.com", 9, 1_000, 3.0),
+                seat("ana@example.com", 2, 50_000, 12.5),
+                seat("cy@example.com", 4, 2_000, 3.0),
+            ],
+        )
+    }
+
+    #[test]
+    fn sorts_by_each_column() {
+        let mut seats = report().seats;
+        let members = |seats: &[SeatUsage]| {
+            seats
+                .iter()
+                .map(|s| s.member.split('@').next().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        sort_seats(&mut seats, OrgSort::Cost);
+        assert_eq!(members(&seats), ["ana", "bo", "cy"]);
+        sort_seats(&mut seats, OrgSort::Sessions);
+        assert_eq!(members(&seats), ["bo", "cy", "ana"]);
+        sort_seats(&mut seats, OrgSort::Tokens);
+        assert_eq!(members(&seats), ["ana", "cy", "bo"]);
+        sort_seats(&mut seats, OrgSort::Member);
+        assert_eq!(members(&seats), ["ana", "bo", "cy"]);
+    }
+
+    #[test]
+    fn human_table_lists_seats_and_total() {
+        let rendered = render_report(&report(), false);
+        assert!(rendered.starts_with("Claude organization usage (2026-10-01 to 2026-10-07):"));
+        assert!(rendered.contains("ana@example.com"));
+        assert!(rendered.contains("Total: $18.50 across 3 seats (53.0K tokens)"));
+    }
+
+    #[test]
+    fn markdown_table_has_a_row_per_seat() {
+        let rendered = render_report(&report(), true);
+        assert!(rendered.contains("| bo@example.com | 9 | 1.0K | $3.00 |  |"));
+        assert_eq!(rendered.matches("@example.com |").count(), 3);
+    }
+}
@@ -0,0 +1,207 @@
+//! Global `--output PATH`: write a command's stdout to a file atomically.
+//!
+//! Standard output is redirected to a temporary file next to the target for
+//! the duration of the command. The file is renamed over the target only if
+//! the command succeeds, so readers (cron jobs, report pipelines) never see a
+//! partial or failed report; on error the target is left untouched. `-`
+//! means stdout. Errors and diagnostics still go to stderr.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use gag::Redirect;
+
+use crate::cli::args::Commands;
+use crate::error::{CautError, Result};
+
+/// Output being captured for the current command, for [`exit`].
+static ACTIVE: Mutex<Option<OutputFile>> = Mutex::new(None);
+
+/// Stdout redirected into a temporary file until [`OutputFile::commit`].
+///
+/// Dropping it without committing restores stdout and removes the
+/// temporary file.
+pub struct OutputFile {
+    target: PathBuf,
+    temp: PathBuf,
+    redirect: Option<Redirect<File>>,
+}
+
+impl OutputFile {
+    /// Start redirecting stdout for `command` to `path`. Returns `None` when
+    /// there is nothing to redirect (no `--output`, or `-`).
+    ///
+    /// # Errors
+    /// Returns an error if the command is interactive or long-running, or
+    /// the temporary file cannot be created.
+    pub fn begin(path: Option<&Path>, command: Option<&Commands>) -> Result<Option<Self>> {
+        let Some(target) = path.filter(|path| *path != Path::new("-")) else {
+            return Ok(None);
+        };
+        if let Some(name) = command.and_then(unsupported_name) {
+            return Err(CautError::Config(format!(
+                "--output is not supported for `caut {name}`"
+            )));
+        }
+
+        let temp = temp_path(target);
+        let file = File::create(&temp).map_err(|e| {
+            CautError::Config(format!(
+                "Failed to create output file '{}': {e}",
+                target.display()
+            ))
+        })?;
+        std::io::stdout().flush()?;
+        let redirect = Redirect::stdout(file).map_err(|e| {
+            let _ = std::fs::remove_file(&temp);
+            CautError::Config(format!("Failed to redirect output: {e}"))
+        })?;
+
+        Ok(Some(Self {
+            target: target.to_path_buf(),
+            temp,
+            redirect: Some(redirect),
+        }))
+    }
+
+    /// Start redirecting stdout like [`OutputFile::begin`], holding the
+    /// output where [`finish`] and [`exit`] can reach it.
+    ///
+    /// # Errors
+    /// Returns an error if the redirect cannot be started.
+    pub fn begin_active(path: Option<&Path>, command: Option<&Commands>) -> Result<()> {
+        let output = Self::begin(path, command)?;
+        *ACTIVE
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = output;
+        Ok(())
+    }
+
+    /// Restore stdout and move the finished output into place.
+    ///
+    /// # Errors
+    /// Returns an error if the output cannot be flushed or renamed.
+    pub fn commit(mut self) -> Result<()> {
+        std::io::stdout().flush()?;
+        if let Some(redirect) = self.redirect.take() {
+            redirect.into_inner().sync_all()?;
+        }
+        std::fs::rename(&self.temp, &self.target).map_err(|e| {
+            CautError::Config(format!(
+                "Failed to write output file '{}': {e}",
+                self.target.display()
+            ))
+        })
+    }
+}
+
+impl Drop for OutputFile {
+    fn drop(&mut self) {
+        if let Some(redirect) = self.redirect.take() {
+            let _ = std::io::stdout().flush();
+            drop(redirect);
+            let _ = std::fs::remove_file(&self.temp);
+        }
+    }
+}
+
+/// Finish the active output: commit it if the command succeeded, discard
+/// it otherwise.
+///
+/// # Errors
+/// Returns the command's error, or an error if the output cannot be moved
+/// into place.
+pub fn finish(result: Result<()>) -> Result<()> {
+    let output = ACTIVE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    match output {
+        Some(output) if result.is_ok() => output.commit(),
+        _ => result,
+    }
+}
+
+/// Exit the process with `code` after a complete report, keeping the
+/// output written so far (e.g. `caut doctor` signalling problems).
+pub fn exit(code: i32) -> ! {
+    if let Err(e) = finish(Ok(())) {
+        eprintln!("Error: {e}");
+    }
+    std::process::exit(code)
+}
+
+/// Commands whose output cannot be captured into a finished file: they run
+/// until stopped, redraw the screen, or prompt on stdout.
+fn unsupported_name(command: &Commands) -> Option<&'static str> {
+    match command {
+        Commands::Dashboard(_) => Some("dashboard"),
+        Commands::Serve(_) => Some("serve"),
+        Commands::Repl(_) => Some("repl"),
+        Commands::Usage(args) if args.watch => Some("usage --watch"),
+        Commands::Top(args) if !args.once => Some("top"),
+        Commands::Daemon(args) if !args.install => Some("daemon"),
+        Commands::Troubleshoot(args) if !args.yes => Some("troubleshoot"),
+        _ => None,
+    }
+}
+
+/// Hidden temporary file in the target's directory, so the final rename
+/// stays on one filesystem.
+fn temp_path(target: &Path) -> PathBuf {
+    let dir = target
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let name = target
+        .file_name()
+        .map_or_else(|| "output".into(), |name| name.to_string_lossy());
+    dir.join(format!(".{name}.tmp.{}", std::process::id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stdout_marker_and_missing_flag_redirect_nothing() {
+        assert!(OutputFile::begin(None, None).unwrap().is_none());
+        assert!(
+            OutputFile::begin(Some(Path::new("-")), None)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn refuses_commands_that_run_until_stopped_or_prompt() {
+        use crate::cli::args::Cli;
+        use clap::Parser;
+
+        let name = |args: &[&str]| {
+            let cli = Cli::parse_from(std::iter::once("caut").chain(args.iter().copied()));
+            cli.command.as_ref().and_then(unsupported_name)
+        };
+        assert_eq!(name(&["usage", "--watch"]), Some("usage --watch"));
+        assert_eq!(name(&["top"]), Some("top"));
+        assert_eq!(name(&["troubleshoot", "claude"]), Some("troubleshoot"));
+        assert_eq!(name(&["daemon"]), Some("daemon"));
+        assert_eq!(name(&["usage"]), None);
+        assert_eq!(name(&["top", "--once"]), None);
+        assert_eq!(name(&["troubleshoot", "claude", "--yes"]), None);
+    }
+
+    #[test]
+    fn temp_file_sits_next_to_target() {
+        assert_eq!(
+            temp_path(Path::new("reports/daily.md")),
+            PathBuf::from(format!("reports/.daily.md.tmp.{}", std::process::id()))
+        );
+        assert_eq!(
+            temp_path(Path::new("daily.md")),
+            PathBuf::from(format!("./.daily.md.tmp.{}", std::process::id()))
+        );
+    }
+}
@@ -0,0 +1,196 @@
+//! Project command implementation.
+//!
+//! Prices this month's local session logs per day and extrapolates them into
+//! a projected bill for the billing period (see [`crate::core::cost_projection`]).
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Local, NaiveDate, TimeZone, Utc};
+
+use crate::cli::args::{OutputFormat, ProjectArgs};
+use crate::core::cost_projection::{self, CostProjection};
+use crate::core::models::RobotOutput;
+use crate::core::pricing::SessionCostCalculator;
+use crate::core::provider::{Provider, ProviderSelection};
+use crate::core::session_logs::SessionLogFinder;
+use crate::error::{CautError, Result};
+use crate::render::robot;
+use crate::util::format::format_cost;
+
+/// Execute the project command.
+///
+/// # Errors
+/// Returns an error if no selected provider has local session logs, the
+/// home directory cannot be determined, or output serialization fails.
+pub fn execute(args: &ProjectArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let selection = args
+        .provider
+        .as_deref()
+        .map(ProviderSelection::from_arg)
+        .transpose()?
+        .unwrap_or_default();
+    let providers: Vec<_> = selection
+        .providers()
+        .into_iter()
+        .filter(|p| p.supports_session_logs())
+        .collect();
+    if providers.is_empty() {
+        return Err(CautError::Config(
+            "No selected providers have local session logs. Only Claude, Codex, and Aider are supported."
+                .to_string(),
+        ));
+    }
+
+    let today = Local::now().date_naive();
+    let period_start = today.with_day(1).unwrap_or(today);
+    let finder = SessionLogFinder::new()?;
+    let projections: Vec<CostProjection> = providers
+        .iter()
+        .map(|provider| {
+            let daily = daily_costs(&finder, *provider, period_start);
+            cost_projection::project(provider.cli_name(), &daily, period_start, today, args.days)
+        })
+        .collect();
+
+    match format {
+        OutputFormat::Human => print!("{}", render_projections(&projections, false)),
+        OutputFormat::Md => print!("{}", render_projections(&projections, true)),
+        OutputFormat::Json => {
+            let output = RobotOutput::new("project", projections);
+            robot::print_json(&output, pretty)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Session cost per local day, by the day each session started.
+pub(crate) fn daily_costs(
+    finder: &SessionLogFinder,
+    provider: Provider,
+    since: NaiveDate,
+) -> BTreeMap<NaiveDate, f64> {
+    let since_utc = Local
+        .from_local_datetime(&since.and_time(chrono::NaiveTime::MIN))
+        .earliest()
+        .map_or_else(Utc::now, |at| at.with_timezone(&Utc));
+    let calc = SessionCostCalculator::new();
+    let mut daily = BTreeMap::new();
+
+    for log in finder.find_sessions(provider, Some(since_utc), None) {
+        let usage = match log.parse() {
+            Ok(usage) => usage,
+            Err(e) => {
+                tracing::debug!(path = %log.path.display(), error = %e, "Skipping session log");
+                continue;
+            }
+        };
+        let Some(started_at) = usage.started_at else {
+            continue;
+        };
+        let date = started_at.with_timezone(&Local).date_naive();
+        if date >= since {
+            *daily.entry(date).or_insert(0.0) += calc.calculate(&usage).total_usd;
+        }
+    }
+
+    daily
+}
+
+fn render_projections(projections: &[CostProjection], markdown: bool) -> String {
+    let Some(first) = projections.first() else {
+        return String::new();
+    };
+    let mut out = if markdown {
+        format!(
+            "## Projected bill ({} to {})\n\n| Provider | Spent | Low | Expected | High | Weekday/day | Weekend/day |\n|----------|-------|-----|----------|------|-------------|-------------|\n",
+            first.period_start, first.period_end
+        )
+    } else {
+        format!(
+            "Projected bill ({} to {}, {} days left):\n\n",
+            first.period_start, first.period_end, first.days_remaining
+        )
+    };
+
+    for p in projections {
+        if markdown {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} | {} |\n",
+                p.provider,
+                format_cost(p.spent_usd),
+                format_cost(p.low_usd),
+                format_cost(p.expected_usd),
+                format_cost(p.high_usd),
+                format_cost(p.weekday_rate_usd),
+                format_cost(p.weekend_rate_usd)
+            ));
+        } else {
+            out.push_str(&format!(
+                "  {:<8} {} expected ({} - {}), {} spent so far\n           {}/weekday, {}/weekend day\n",
+                p.provider,
+                format_cost(p.expected_usd),
+                format_cost(p.low_usd),
+                format_cost(p.high_usd),
+                format_cost(p.spent_usd),
+                format_cost(p.weekday_rate_usd),
+                format_cost(p.weekend_rate_usd)
+            ));
+        }
+    }
+
+    if projections.len() > 1 {
+        let total = |f: fn(&CostProjection) -> f64| projections.iter().map(f).sum::<f64>();
+        let (low, expected, high) = (
+            total(|p| p.low_usd),
+            total(|p| p.expected_usd),
+            total(|p| p.high_usd),
+        );
+        if markdown {
+            out.push_str(&format!(
+                "\n**Total:** {} expected ({} - {})\n",
+                format_cost(expected),
+                format_cost(low),
+                format_cost(high)
+            ));
+        } else {
+            out.push_str(&format!(
+                "\n  Total    {} expected ({} - {})\n",
+                format_cost(expected),
+                format_cost(low),
+                format_cost(high)
+            ));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn projection(provider: &str, spent: f64) -> CostProjection {
+        let start = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let daily = BTreeMap::from([(start, spent)]);
+        cost_projection::project(provider, &daily, start, start, 30)
+    }
+
+    #[test]
+    fn human_output_totals_multiple_providers() {
+        let rendered = render_projections(
+            &[projection("claude", 2.0), projection("codex", 1.0)],
+            false,
+        );
+        assert!(rendered.contains("2026-10-01 to 2026-10-30"));
+        assert!(rendered.contains("claude   $60.00 expected"));
+        assert!(rendered.contains("Total    $90.00 expected"));
+    }
+
+    #[test]
+    fn markdown_output_is_a_table() {
+        let rendered = render_projections(&[projection("claude", 2.0)], true);
+        assert!(rendered.contains("| claude | $2.00 | $60.00 | $60.00 | $60.00 | $2.00 | $2.00 |"));
+        assert!(!rendered.contains("Total"));
+    }
+}
@@ -41,6 +41,12 @@ pub struct ProviderPromptData {
     pub primary_pct: Option<f64>,
     /// Secondary usage percentage (weekly window).
     pub secondary_pct: Option<f64>,
+    /// When the primary window resets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_resets_at: Option<DateTime<Utc>>,
+    /// When the secondary window resets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_resets_at: Option<DateTime<Utc>>,
     /// Credits remaining (if applicable).
     pub credits_remaining: Option<f64>,
     /// Today's cost in USD (if applicable).
@@ -127,7 +133,7 @@ fn format_prompt(
 }
 
 /// Format prompt output with staleness indicator.
-fn format_prompt_with_staleness(
+pub(crate) fn format_prompt_with_staleness(
     providers: &[&ProviderPromptData],
     format: PromptFormat,
     use_color: bool,
@@ -293,7 +299,7 @@ fn format_provider_full(provider: &ProviderPromptData, use_color: bool) -> Strin
 }
 
 /// Get short provider name for prompt display.
-fn short_provider_name(name: &str) -> &str {
+pub(crate) fn short_provider_name(name: &str) -> &str {
     match name {
         "claude" => "cl",
         "codex" => "cx",
@@ -479,6 +485,8 @@ mod tests {
             provider: "claude".to_string(),
             primary_pct: Some(45.5),
             secondary_pct: Some(32.0),
+            primary_resets_at: None,
+            secondary_resets_at: None,
             credits_remaining: None,
             cost_today_usd: Some(12.34),
         }
@@ -515,6 +523,8 @@ mod tests {
             provider: "claude".to_string(),
             primary_pct: Some(30.0),
             secondary_pct: None,
+            primary_resets_at: None,
+            secondary_resets_at: None,
             credits_remaining: None,
             cost_today_usd: None,
         };
@@ -0,0 +1,186 @@
+//! Prune command implementation.
+//!
+//! `caut prune` previews what the retention policy would remove from each
+//! history table, then asks before deleting. `--dry-run` stops after the
+//! preview.
+
+use std::io::{BufRead, Write};
+
+use crate::cli::args::{OutputFormat, PruneArgs};
+use crate::core::models::RobotOutput;
+use crate::error::Result;
+use crate::render::robot;
+use crate::storage::{AppPaths, HistoryStore, PrunePreview, PruneResult, RetentionPolicy};
+use crate::util::format::format_bytes;
+
+/// Execute the prune command.
+///
+/// # Errors
+/// Returns an error if the policy is invalid, the history database cannot be
+/// opened or pruned, or the confirmation prompt cannot be read.
+pub fn execute(args: &PruneArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let history_path = AppPaths::new().history_db_file();
+    if !history_path.exists() {
+        if format == OutputFormat::Json {
+            return robot::print_json(
+                &RobotOutput::new(
+                    "prune",
+                    serde_json::json!({ "preview": null, "result": null }),
+                ),
+                pretty,
+            );
+        }
+        println!("No history database found at: {}", history_path.display());
+        println!("Nothing to prune.");
+        return Ok(());
+    }
+
+    let store = HistoryStore::open(&history_path)?;
+    let mut policy = RetentionPolicy::default();
+    if let Some(days) = args.keep_days {
+        policy = policy.with_detailed_days(days);
+    }
+    if let Some(bytes) = args.max_size {
+        policy = policy.with_max_size(bytes);
+    }
+
+    let preview = store.prune_preview(&policy)?;
+    let nothing_to_do = preview.total_rows() == 0 && preview.aggregates_to_create == 0;
+
+    if format == OutputFormat::Json {
+        // JSON callers cannot answer a prompt, so deleting needs --yes.
+        let result = if args.dry_run || nothing_to_do || !args.yes {
+            None
+        } else {
+            Some(store.prune(&policy, false)?)
+        };
+        return robot::print_json(
+            &RobotOutput::new(
+                "prune",
+                serde_json::json!({
+                    "dryRun": result.is_none(),
+                    "preview": preview,
+                    "result": result.map(|r| result_json(&r)),
+                }),
+            ),
+            pretty,
+        );
+    }
+
+    print_preview(&preview, &policy, format);
+    if nothing_to_do {
+        println!("\nNothing to prune.");
+        return Ok(());
+    }
+    if args.dry_run {
+        println!("\nDry run - no changes made.");
+        return Ok(());
+    }
+    if !args.yes && !confirm("\nDelete these rows?")? {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let result = store.prune(&policy, false)?;
+    println!(
+        "\nDeleted {} detailed and {} aggregate rows; created {} daily aggregates.",
+        result.detailed_deleted, result.aggregates_deleted, result.aggregates_created
+    );
+    if result.size_limit_triggered {
+        println!(
+            "Size limit ({}) removed additional oldest snapshots.",
+            format_bytes(policy.max_size_bytes)
+        );
+    }
+    if result.bytes_freed > 0 {
+        println!("Freed {}.", format_bytes(result.bytes_freed));
+    }
+    Ok(())
+}
+
+fn print_preview(preview: &PrunePreview, policy: &RetentionPolicy, format: OutputFormat) {
+    let rows: Vec<_> = preview.tables.iter().filter(|t| t.rows > 0).collect();
+
+    if format == OutputFormat::Md {
+        println!("# Prune Preview\n");
+        println!("| Table | Rows | Oldest | Newest |");
+        println!("|-------|------|--------|--------|");
+        for table in &rows {
+            println!(
+                "| {} | {} | {} | {} |",
+                table.table,
+                table.rows,
+                day(table.oldest.as_deref()),
+                day(table.newest.as_deref())
+            );
+        }
+        if preview.size_limit_snapshots > 0 {
+            println!(
+                "| usage_snapshots (size limit) | ~{} | | |",
+                preview.size_limit_snapshots
+            );
+        }
+    } else {
+        println!("Prune Preview");
+        println!("-------------");
+        for table in &rows {
+            println!(
+                "  {:<20} {:>8} rows  {} .. {}",
+                table.table,
+                table.rows,
+                day(table.oldest.as_deref()),
+                day(table.newest.as_deref())
+            );
+        }
+        if preview.size_limit_snapshots > 0 {
+            let count = format!("~{}", preview.size_limit_snapshots);
+            println!(
+                "  {:<20} {count:>8} rows  (oldest, to fit the size limit)",
+                "usage_snapshots"
+            );
+        }
+        if rows.is_empty() && preview.size_limit_snapshots == 0 {
+            println!("  No rows past the retention cutoffs.");
+        }
+    }
+
+    println!();
+    println!(
+        "Daily aggregates to create: {}",
+        preview.aggregates_to_create
+    );
+    println!(
+        "Database size: {} -> ~{} (limit {})",
+        format_bytes(preview.db_size_bytes),
+        format_bytes(preview.estimated_size_bytes),
+        format_bytes(policy.max_size_bytes)
+    );
+    println!(
+        "Keeping {} days of snapshots and {} days of aggregates.",
+        policy.detailed_retention_days, policy.aggregate_retention_days
+    );
+}
+
+/// The date part of a timestamp or day column.
+fn day(value: Option<&str>) -> &str {
+    value.map_or("-", |v| v.get(..10).unwrap_or(v))
+}
+
+fn result_json(result: &PruneResult) -> serde_json::Value {
+    serde_json::json!({
+        "detailedDeleted": result.detailed_deleted,
+        "aggregatesCreated": result.aggregates_created,
+        "aggregatesDeleted": result.aggregates_deleted,
+        "bytesFreed": result.bytes_freed,
+        "durationMs": result.duration_ms,
+        "sizeLimitTriggered": result.size_limit_triggered,
+    })
+}
+
+fn confirm(question: &str) -> Result<bool> {
+    eprint!("{question} [y/N] ");
+    std::io::stderr().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
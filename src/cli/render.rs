@@ -0,0 +1,217 @@
+//! Render command implementation.
+//!
+//! Reads caut JSON output (the `caut.v1` envelope from `caut usage --json`
+//! or `caut cost --json`, or a bare array of provider or cost payloads) and
+//! prints it with the regular renderers, so data collected elsewhere (a
+//! server, a cron job, another machine) gets the same panels and tables.
+//! Several JSON documents in one input, such as a saved `--watch --json`
+//! stream, are rendered one after another.
+
+use std::io::Read;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::cli::args::{OutputFormat, RenderArgs};
+use crate::cli::usage::{UsageResults, render_usage_results};
+use crate::core::models::{CostPayload, ProviderPayload, RobotOutput};
+use crate::error::{CautError, Result};
+use crate::render::{UsageRenderOptions, human, robot};
+
+/// Schema version this command understands.
+const SCHEMA_VERSION: &str = "caut.v1";
+
+/// A parsed input document.
+#[derive(Debug)]
+pub(crate) enum Document {
+    Usage {
+        payloads: Vec<ProviderPayload>,
+        errors: Vec<String>,
+    },
+    Cost {
+        costs: Vec<CostPayload>,
+        errors: Vec<String>,
+    },
+}
+
+/// Execute the render command.
+///
+/// # Errors
+/// Returns an error if the input cannot be read, is not caut JSON, or
+/// rendering fails.
+pub fn execute(
+    args: &RenderArgs,
+    format: OutputFormat,
+    pretty: bool,
+    no_color: bool,
+) -> Result<()> {
+    let input = read_input(args)?;
+    let documents = parse_documents(&input)?;
+
+    let options = UsageRenderOptions {
+        no_color,
+        columns: (!args.columns.is_empty()).then(|| args.columns.clone()),
+        ..UsageRenderOptions::default()
+    };
+    for document in documents {
+        render_document(document, format, pretty, &options)?;
+    }
+    Ok(())
+}
+
+fn read_input(args: &RenderArgs) -> Result<String> {
+    match &args.input {
+        Some(path) if path.as_os_str() != "-" => std::fs::read_to_string(path)
+            .map_err(|e| CautError::Config(format!("Failed to read {}: {e}", path.display()))),
+        _ => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+/// Parse every JSON document in `input`.
+pub(crate) fn parse_documents(input: &str) -> Result<Vec<Document>> {
+    let documents = serde_json::Deserializer::from_str(input)
+        .into_iter::<Value>()
+        .map(|value| {
+            let value = value
+                .map_err(|e| CautError::ParseResponse(format!("Input is not valid JSON: {e}")))?;
+            parse_document(value)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if documents.is_empty() {
+        return Err(CautError::Config(
+            "No input to render (pipe in the output of e.g. caut usage --json)".to_string(),
+        ));
+    }
+    Ok(documents)
+}
+
+fn parse_document(value: Value) -> Result<Document> {
+    let mut envelope = match value {
+        Value::Object(envelope) => envelope,
+        // A bare array: provider payloads, or cost payloads.
+        value => {
+            return decode::<Vec<ProviderPayload>>(value.clone())
+                .map(|payloads| Document::Usage {
+                    payloads,
+                    errors: Vec::new(),
+                })
+                .or_else(|_| {
+                    decode::<Vec<CostPayload>>(value).map(|costs| Document::Cost {
+                        costs,
+                        errors: Vec::new(),
+                    })
+                });
+        }
+    };
+
+    if let Some(version) = envelope.get("schemaVersion").and_then(Value::as_str)
+        && version != SCHEMA_VERSION
+    {
+        return Err(CautError::Config(format!(
+            "Unsupported schema version '{version}' (expected {SCHEMA_VERSION})"
+        )));
+    }
+    let errors: Vec<String> = envelope
+        .remove("errors")
+        .map(decode)
+        .transpose()?
+        .unwrap_or_default();
+    let data = envelope.remove("data").unwrap_or(Value::Null);
+
+    match envelope.get("command").and_then(Value::as_str) {
+        Some("usage") => Ok(Document::Usage {
+            payloads: decode(data)?,
+            errors,
+        }),
+        Some("cost") => Ok(Document::Cost {
+            costs: decode(data)?,
+            errors,
+        }),
+        Some(command) => Err(CautError::Config(format!(
+            "Cannot render '{command}' output (only usage and cost are supported)"
+        ))),
+        None => Err(CautError::Config(
+            "Input is not caut JSON output (no command field)".to_string(),
+        )),
+    }
+}
+
+fn decode<T: DeserializeOwned>(value: Value) -> Result<T> {
+    serde_json::from_value(value)
+        .map_err(|e| CautError::ParseResponse(format!("Input does not match the caut schema: {e}")))
+}
+
+fn render_document(
+    document: Document,
+    format: OutputFormat,
+    pretty: bool,
+    options: &UsageRenderOptions,
+) -> Result<()> {
+    match document {
+        Document::Usage { payloads, errors } => {
+            let results = UsageResults {
+                payloads,
+                errors,
+                costs: Vec::new(),
+                budgets: Vec::new(),
+            };
+            render_usage_results(&results, format, pretty, options)
+        }
+        Document::Cost { costs, errors } => {
+            match format {
+                OutputFormat::Human => print!("{}", human::render_cost(&costs, options.no_color)?),
+                OutputFormat::Md => print!("{}", robot::render_markdown_cost(&costs)?),
+                OutputFormat::Json => {
+                    let output = RobotOutput::cost(costs, errors.clone());
+                    robot::print_json(&output, pretty)?;
+                    return Ok(());
+                }
+            }
+            for error in &errors {
+                eprintln!("Error: {error}");
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::make_test_provider_payload;
+
+    #[test]
+    fn parses_envelopes_and_bare_arrays() {
+        let payloads = vec![make_test_provider_payload("codex", "cli")];
+        let envelope = serde_json::to_string(&RobotOutput::usage(
+            payloads.clone(),
+            vec!["claude: timed out".to_string()],
+        ))
+        .unwrap();
+        let bare = serde_json::to_string(&payloads).unwrap();
+
+        let documents = parse_documents(&format!("{envelope}\n{bare}\n")).unwrap();
+        assert_eq!(documents.len(), 2);
+        let Document::Usage { payloads, errors } = &documents[0] else {
+            panic!("expected usage: {:?}", documents[0]);
+        };
+        assert_eq!(payloads[0].provider, "codex");
+        assert_eq!(errors, &["claude: timed out"]);
+        assert!(matches!(&documents[1], Document::Usage { payloads, .. } if payloads.len() == 1));
+    }
+
+    #[test]
+    fn rejects_other_commands_and_versions() {
+        let doctor = r#"{"schemaVersion": "caut.v1", "command": "doctor", "data": {}}"#;
+        assert!(parse_documents(doctor).is_err());
+        let future = r#"{"schemaVersion": "caut.v9", "command": "usage", "data": []}"#;
+        assert!(parse_documents(future).is_err());
+        assert!(parse_documents("  \n").is_err());
+        assert!(parse_documents("not json").is_err());
+    }
+}
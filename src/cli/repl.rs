@@ -0,0 +1,387 @@
+//! Interactive REPL (`caut repl`).
+//!
+//! Runs caut commands line by line in one process. Each query skips process
+//! startup and the remote config refresh, the history database is opened and
+//! migrated once and held for the session, and provider caches stay warm
+//! between commands. Lines are parsed exactly like command-line arguments
+//! (`usage --provider claude --json`). Tab completes subcommands, flags, and
+//! provider names, and command history persists across sessions.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use clap::CommandFactory;
+use futures::future::LocalBoxFuture;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{CompletionType, Config, Context, Editor, Helper};
+
+use crate::cli::args::{Cli, Commands, ReplArgs};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::render::error::render_command_error;
+use crate::storage::{AppPaths, HistoryStore};
+
+/// Runs one parsed command line (the binary's command dispatch).
+pub type Dispatch = fn(Cli) -> LocalBoxFuture<'static, Result<()>>;
+
+/// Commands handled by the REPL itself.
+const BUILTINS: [&str; 3] = ["exit", "quit", "help"];
+
+/// Selection keywords accepted wherever a provider is.
+const PROVIDER_KEYWORDS: [&str; 2] = ["both", "all"];
+
+const PROMPT: &str = "caut> ";
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Whether commands are running inside a REPL session, where they must
+/// report failure by returning rather than exiting the process.
+#[must_use]
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+}
+
+/// Execute the repl command.
+///
+/// # Errors
+/// Returns an error if the terminal cannot be set up for line editing.
+pub async fn execute(args: &ReplArgs, dispatch: Dispatch) -> Result<()> {
+    let config = Config::builder()
+        .completion_type(CompletionType::List)
+        .build();
+    let mut editor: Editor<ReplHelper, _> = Editor::with_config(config).map_err(readline_error)?;
+    editor.set_helper(Some(ReplHelper::new()));
+
+    let paths = AppPaths::new();
+    let history_file = paths.repl_history_file();
+    if !args.no_history {
+        // A missing history file just means a first session.
+        let _ = editor.load_history(&history_file);
+    }
+
+    let _history_db = HistoryStore::open(&paths.history_db_file())
+        .map_err(|e| tracing::debug!("History database unavailable: {}", e))
+        .ok();
+
+    ACTIVE.store(true, Ordering::Relaxed);
+    println!(
+        "caut {} — Tab completes, `help` for usage, `exit` to quit",
+        env!("CARGO_PKG_VERSION")
+    );
+
+    loop {
+        let line = match editor.readline(PROMPT) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => {
+                ACTIVE.store(false, Ordering::Relaxed);
+                return Err(readline_error(e));
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let _ = editor.add_history_entry(line);
+
+        match line {
+            "exit" | "quit" => break,
+            "help" => {
+                print_help();
+                continue;
+            }
+            _ => {}
+        }
+
+        run_line(line, dispatch).await;
+    }
+
+    ACTIVE.store(false, Ordering::Relaxed);
+    if !args.no_history {
+        if let Some(parent) = history_file.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        if let Err(e) = editor.save_history(&history_file) {
+            tracing::warn!("Failed to save REPL history: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Parse and run one line, printing any error.
+async fn run_line(line: &str, dispatch: Dispatch) {
+    let words = match split_args(line) {
+        Ok(words) => words,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return;
+        }
+    };
+    let (cli, command) =
+        match Cli::try_parse_with_path(std::iter::once("caut".to_string()).chain(words)) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                let _ = e.print();
+                return;
+            }
+        };
+
+    match cli.command {
+        None => {
+            print_help();
+            return;
+        }
+        Some(Commands::Repl(_)) => {
+            eprintln!("Already in the REPL");
+            return;
+        }
+        Some(_) => {}
+    }
+
+    if let Some(location) = &cli.config {
+        crate::storage::config::set_config_location(location.clone());
+    }
+    let format = cli.effective_format();
+    let no_color = cli.no_color;
+    let pretty = cli.pretty;
+
+    // Ctrl-C stops a long-running command (watch, serve) and returns here.
+    let result = tokio::select! {
+        result = dispatch(cli) => result,
+        _ = tokio::signal::ctrl_c() => {
+            println!();
+            Ok(())
+        }
+    };
+    if let Err(e) = result {
+        eprintln!(
+            "{}",
+            render_command_error(&e, Some(&command), format, no_color, pretty)
+        );
+    }
+}
+
+fn readline_error(e: ReadlineError) -> CautError {
+    CautError::Io(std::io::Error::other(e))
+}
+
+fn print_help() {
+    println!(
+        "Type any caut command without the `caut` prefix, e.g.:
+
+    usage --provider claude
+    cost --json
+    history show --days 30
+
+`<command> --help` lists a command's options. Ctrl-C stops a running
+command; `exit`, `quit`, or Ctrl-D leaves the REPL."
+    );
+}
+
+/// Split a line into arguments like a POSIX shell: whitespace separates
+/// words, quotes group them, and backslash escapes the next character
+/// outside single quotes.
+///
+/// # Errors
+/// Returns an error for an unterminated quote or trailing backslash.
+pub fn split_args(line: &str) -> std::result::Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => return Err("unterminated single quote".to_string()),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\')) => current.push(c),
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => return Err("unterminated double quote".to_string()),
+                        },
+                        Some(c) => current.push(c),
+                        None => return Err("unterminated double quote".to_string()),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                current.push(chars.next().ok_or("trailing backslash")?);
+            }
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            c => {
+                in_word = true;
+                current.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(current);
+    }
+    Ok(words)
+}
+
+// =============================================================================
+// Completion
+// =============================================================================
+
+/// Completes subcommands, flags, and provider names from the clap definition.
+struct ReplHelper {
+    command: clap::Command,
+}
+
+impl ReplHelper {
+    fn new() -> Self {
+        let mut command = Cli::command();
+        // Propagates global flags into every subcommand.
+        command.build();
+        Self { command }
+    }
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let before = &line[..pos];
+        let start = before.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+        let preceding: Vec<&str> = before[..start].split_whitespace().collect();
+        Ok((
+            start,
+            candidates(&self.command, &preceding, &before[start..]),
+        ))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+/// Completions for `word`, given the words before it on the line.
+fn candidates(root: &clap::Command, preceding: &[&str], word: &str) -> Vec<String> {
+    let mut matches: Vec<String> = if matches!(preceding.last(), Some(&("--provider" | "-p"))) {
+        Provider::ALL
+            .iter()
+            .copied()
+            .map(Provider::cli_name)
+            .chain(PROVIDER_KEYWORDS)
+            .map(str::to_string)
+            .collect()
+    } else {
+        let mut command = root;
+        for name in preceding {
+            if let Some(sub) = command.find_subcommand(name) {
+                command = sub;
+            }
+        }
+
+        if word.starts_with('-') {
+            command
+                .get_arguments()
+                .filter_map(|arg| arg.get_long())
+                .chain(["help"])
+                .map(|long| format!("--{long}"))
+                .collect()
+        } else {
+            let builtins = preceding
+                .is_empty()
+                .then_some(BUILTINS)
+                .into_iter()
+                .flatten();
+            command
+                .get_subcommands()
+                .map(clap::Command::get_name)
+                .filter(|name| *name != "help")
+                .chain(builtins)
+                .map(str::to_string)
+                .collect()
+        }
+    };
+
+    matches.retain(|candidate| candidate.starts_with(word));
+    matches.sort();
+    matches.dedup();
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complete(preceding: &[&str], word: &str) -> Vec<String> {
+        candidates(&ReplHelper::new().command, preceding, word)
+    }
+
+    #[test]
+    fn splits_quoted_arguments() {
+        assert_eq!(
+            split_args(r#"usage --provider "claude" --note 'two words' a\ b"#).unwrap(),
+            [
+                "usage",
+                "--provider",
+                "claude",
+                "--note",
+                "two words",
+                "a b"
+            ]
+        );
+        assert_eq!(split_args("  ").unwrap(), Vec::<String>::new());
+        assert_eq!(split_args(r#"x """#).unwrap(), ["x", ""]);
+        assert!(split_args("usage 'open").is_err());
+    }
+
+    #[test]
+    fn completes_subcommands_and_builtins() {
+        assert_eq!(complete(&[], "us"), ["usage"]);
+        assert!(complete(&[], "").contains(&"exit".to_string()));
+        assert_eq!(complete(&["history"], "sh"), ["show"]);
+        assert!(complete(&["history"], "").iter().all(|c| c != "exit"));
+    }
+
+    #[test]
+    fn completes_flags_and_providers() {
+        let flags = complete(&["usage"], "--pro");
+        assert!(flags.contains(&"--provider".to_string()));
+        assert!(complete(&["usage"], "--js").contains(&"--json".to_string()));
+
+        let providers = complete(&["usage", "--provider"], "cl");
+        assert_eq!(providers, ["claude", "cline"]);
+        assert_eq!(complete(&["cost", "-p"], "al"), ["all"]);
+    }
+}
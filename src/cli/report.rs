@@ -0,0 +1,289 @@
+//! Report command implementation.
+//!
+//! `caut report` summarizes the last week or month from recorded history and
+//! local cost scans (see [`crate::core::report`]) as text, Markdown, JSON,
+//! or with `--html` a standalone page to share or archive.
+
+use std::fmt::Write as _;
+
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+
+use crate::cli::args::{OutputFormat, ReportArgs};
+use crate::core::cost_scanner::CostScanner;
+use crate::core::fake_providers;
+use crate::core::models::{CostPayload, RobotOutput};
+use crate::core::provider::{Provider, ProviderSelection};
+use crate::core::report::{self, NEAR_MISS_PERCENT, UsageReport};
+use crate::error::Result;
+use crate::render::robot;
+use crate::storage::{AppPaths, HistoryStore, SnapshotFilter, StoredSnapshot};
+use crate::util::format::format_cost;
+
+/// Execute the report command.
+///
+/// A provider whose cost scan fails is reported without spend.
+///
+/// # Errors
+/// Returns an error if the provider selection is invalid, the history
+/// database cannot be read, or output serialization fails.
+pub async fn execute(args: &ReportArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let providers = args
+        .provider
+        .as_deref()
+        .map(ProviderSelection::from_arg)
+        .transpose()?
+        .unwrap_or_default()
+        .providers();
+    let (from, to) = report::period_range(args.period.days());
+
+    let snapshots = recorded_snapshots(&providers, from)?;
+    let costs = scan_costs(&providers).await;
+    let report = UsageReport::build(args.period.label(), from, to, &snapshots, &costs);
+
+    if args.html {
+        print!("{}", render_html(&report));
+        return Ok(());
+    }
+    match format {
+        OutputFormat::Human => print!("{}", render_text(&report)),
+        OutputFormat::Md => print!("{}", render_markdown(&report)),
+        OutputFormat::Json => {
+            let output = RobotOutput::new("report", &report);
+            robot::print_json(&output, pretty)?;
+        }
+    }
+    Ok(())
+}
+
+/// Snapshots of `providers` recorded since the start of `from` (local time).
+pub(crate) fn recorded_snapshots(
+    providers: &[Provider],
+    from: NaiveDate,
+) -> Result<Vec<StoredSnapshot>> {
+    let path = AppPaths::new().history_db_file();
+    if fake_providers::is_enabled() || !path.exists() {
+        return Ok(Vec::new());
+    }
+    let filter = SnapshotFilter {
+        from: Some(local_day_start(from)),
+        ..SnapshotFilter::default()
+    };
+    Ok(HistoryStore::open(&path)?
+        .query_snapshots(&filter)?
+        .into_iter()
+        .filter(|s| providers.contains(&s.provider))
+        .collect())
+}
+
+/// The start of `date` in local time, as UTC.
+pub(crate) fn local_day_start(date: NaiveDate) -> DateTime<Utc> {
+    Local
+        .from_local_datetime(&date.and_time(NaiveTime::MIN))
+        .earliest()
+        .map_or_else(Utc::now, |at| at.with_timezone(&Utc))
+}
+
+/// Local cost data of the `providers` that support a cost scan.
+pub(crate) async fn scan_costs(providers: &[Provider]) -> Vec<CostPayload> {
+    let scanner = CostScanner::new();
+    let mut costs = Vec::new();
+    for &provider in providers.iter().filter(|p| p.supports_cost_scan()) {
+        if fake_providers::is_enabled() {
+            costs.push(fake_providers::cost_payload(provider));
+            continue;
+        }
+        match scanner.scan(provider, false).await {
+            Ok(cost) => costs.push(cost),
+            Err(e) => tracing::debug!(provider = %provider.cli_name(), "Cost scan failed: {}", e),
+        }
+    }
+    costs
+}
+
+fn title(report: &UsageReport) -> String {
+    format!(
+        "Usage report: last {} ({} to {})",
+        report.period, report.from, report.to
+    )
+}
+
+fn peak(percent: Option<f64>) -> String {
+    percent.map_or_else(|| "-".to_string(), |p| format!("{p:.0}%"))
+}
+
+fn render_text(report: &UsageReport) -> String {
+    let mut out = format!("{}\n\n", title(report));
+    for line in &report.narrative {
+        let _ = writeln!(out, "{line}");
+    }
+
+    if !report.providers.is_empty() {
+        out.push_str("\nProviders:\n");
+        for p in &report.providers {
+            let _ = writeln!(
+                out,
+                "  {:<12} {:>10}  peak {:>4}  {} snapshot(s)",
+                p.provider,
+                format_cost(p.cost_usd),
+                peak(p.peak_used_percent),
+                p.snapshots
+            );
+        }
+    }
+    if !report.busiest_days.is_empty() {
+        out.push_str("\nBusiest days:\n");
+        for day in &report.busiest_days {
+            let _ = writeln!(
+                out,
+                "  {}  {:>10}",
+                day.date.format("%a %Y-%m-%d"),
+                format_cost(day.cost_usd)
+            );
+        }
+    }
+    if !report.near_misses.is_empty() {
+        let _ = writeln!(out, "\nNear-misses (>= {NEAR_MISS_PERCENT:.0}%):");
+        for miss in &report.near_misses {
+            let _ = writeln!(
+                out,
+                "  {:<12} {:<9} {:>4.0}%  {}",
+                miss.provider,
+                miss.window,
+                miss.used_percent,
+                miss.at.with_timezone(&Local).format("%a %Y-%m-%d %H:%M")
+            );
+        }
+    }
+    out
+}
+
+fn render_markdown(report: &UsageReport) -> String {
+    let mut out = format!("# {}\n\n{}\n", title(report), report.narrative.join(" "));
+
+    if !report.providers.is_empty() {
+        out.push_str("\n## Providers\n\n| Provider | Cost | Peak | Snapshots |\n|----------|------|------|-----------|\n");
+        for p in &report.providers {
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} |",
+                p.provider,
+                format_cost(p.cost_usd),
+                peak(p.peak_used_percent),
+                p.snapshots
+            );
+        }
+    }
+    if !report.busiest_days.is_empty() {
+        out.push_str("\n## Busiest days\n\n| Day | Cost |\n|-----|------|\n");
+        for day in &report.busiest_days {
+            let _ = writeln!(
+                out,
+                "| {} | {} |",
+                day.date.format("%a %Y-%m-%d"),
+                format_cost(day.cost_usd)
+            );
+        }
+    }
+    if !report.near_misses.is_empty() {
+        let _ = write!(
+            out,
+            "\n## Near-misses (>= {NEAR_MISS_PERCENT:.0}%)\n\n| Provider | Window | Used | When |\n|----------|--------|------|------|\n"
+        );
+        for miss in &report.near_misses {
+            let _ = writeln!(
+                out,
+                "| {} | {} | {:.0}% | {} |",
+                miss.provider,
+                miss.window,
+                miss.used_percent,
+                miss.at.with_timezone(&Local).format("%a %Y-%m-%d %H:%M")
+            );
+        }
+    }
+    out
+}
+
+fn render_html(report: &UsageReport) -> String {
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>\nbody {{ font-family: system-ui, sans-serif; max-width: 48rem; margin: 2rem auto; padding: 0 1rem; color: #222; }}\n\
+         table {{ border-collapse: collapse; margin: 0.5rem 0 1.5rem; }}\n\
+         th, td {{ padding: 0.3rem 0.8rem; border-bottom: 1px solid #ddd; text-align: left; }}\n\
+         td.num {{ text-align: right; font-variant-numeric: tabular-nums; }}\n</style>\n</head>\n<body>\n<h1>{title}</h1>\n",
+        title = html_escape(&title(report))
+    );
+    for line in &report.narrative {
+        let _ = writeln!(out, "<p>{}</p>", html_escape(line));
+    }
+
+    if !report.providers.is_empty() {
+        out.push_str("<h2>Providers</h2>\n<table>\n<tr><th>Provider</th><th>Cost</th><th>Peak</th><th>Snapshots</th></tr>\n");
+        for p in &report.providers {
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td></tr>",
+                html_escape(&p.provider),
+                format_cost(p.cost_usd),
+                peak(p.peak_used_percent),
+                p.snapshots
+            );
+        }
+        out.push_str("</table>\n");
+    }
+    if !report.busiest_days.is_empty() {
+        out.push_str("<h2>Busiest days</h2>\n<table>\n<tr><th>Day</th><th>Cost</th></tr>\n");
+        for day in &report.busiest_days {
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td class=\"num\">{}</td></tr>",
+                day.date.format("%a %Y-%m-%d"),
+                format_cost(day.cost_usd)
+            );
+        }
+        out.push_str("</table>\n");
+    }
+    if !report.near_misses.is_empty() {
+        let _ = writeln!(
+            out,
+            "<h2>Near-misses (&ge; {NEAR_MISS_PERCENT:.0}%)</h2>\n<table>\n<tr><th>Provider</th><th>Window</th><th>Used</th><th>When</th></tr>"
+        );
+        for miss in &report.near_misses {
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td class=\"num\">{:.0}%</td><td>{}</td></tr>",
+                html_escape(&miss.provider),
+                html_escape(&miss.window),
+                miss.used_percent,
+                miss.at.with_timezone(&Local).format("%a %Y-%m-%d %H:%M")
+            );
+        }
+        out.push_str("</table>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_report_is_a_standalone_escaped_page() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 4).unwrap();
+        let report = UsageReport::build("week", day, day, &[], &[]);
+        let html = render_html(&report);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h1>Usage report: last week (2026-03-04 to 2026-03-04)</h1>"));
+        assert!(html.contains("run `caut usage` or `caut daemon`"));
+        assert!(html.trim_end().ends_with("</html>"));
+        assert_eq!(html_escape("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+}
@@ -0,0 +1,56 @@
+//! Report-bug command implementation.
+//!
+//! `caut report-bug` drafts a GitHub issue for the last internal error (see
+//! [`crate::core::bug_report`]): the error code and message, the failing
+//! command, and the caut version and platform, all redacted. Nothing is
+//! sent; the draft is printed as a pre-filled new-issue URL to review and
+//! submit.
+
+use crate::cli::args::{OutputFormat, ReportBugArgs};
+use crate::cli::login::open_browser;
+use crate::core::bug_report::{CrashRecord, IssueDraft};
+use crate::core::models::RobotOutput;
+use crate::error::Result;
+use crate::render::robot;
+use crate::storage::AppPaths;
+
+/// Execute the report-bug command.
+///
+/// # Errors
+/// Returns an error if the saved error record cannot be read or output
+/// serialization fails.
+pub fn execute(args: &ReportBugArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let record = CrashRecord::load(&AppPaths::new().last_error_file())?;
+    let draft = IssueDraft::new(record.as_ref());
+
+    match format {
+        OutputFormat::Json => {
+            let output = RobotOutput::new(
+                "report-bug",
+                serde_json::json!({ "error": record, "issue": draft }),
+            );
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Human | OutputFormat::Md => {
+            match &record {
+                Some(record) => println!(
+                    "Issue draft for {} from {}:\n",
+                    record.code,
+                    record.occurred_at.format("%Y-%m-%d %H:%M UTC")
+                ),
+                None => println!(
+                    "No internal error recorded; this is a blank report with your environment.\n"
+                ),
+            }
+            println!("Title: {}\n", draft.title);
+            println!("{}", draft.body);
+            println!("Review it, then file it here (nothing is sent until you submit):\n");
+            println!("{}", draft.url);
+        }
+    }
+
+    if args.open {
+        open_browser(&draft.url);
+    }
+    Ok(())
+}
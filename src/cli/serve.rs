@@ -4,6 +4,20 @@
 //! caches usage data and responds to queries from other processes.
 //! This enables plugins and scripts to query caut data without launching
 //! the full TUI or paying cold-start penalties on each invocation.
+//!
+//! `/usage` is served from the cache; `/cost`, `/session`, `/doctor`, and
+//! `/history` run on each request and return the same JSON as the matching
+//! command with `--json`.
+//!
+//! Each refresh also runs the [watchdog](crate::core::watchdog) when limits
+//! are configured, logging runaway agent sessions to stderr. On battery or a
+//! metered connection, refreshes are spaced out and skip web sources (see
+//! [`crate::core::power`]).
+//!
+//! Unless `--no-canary` is given, the server also runs the
+//! [canary](crate::core::canary) once a week: every available fetch strategy
+//! is tried, not just the first that works, and `caut doctor` reports the
+//! ones that have been failing.
 
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -17,13 +31,22 @@ use hyper_util::rt::TokioIo;
 use serde::Serialize;
 use tokio::net::TcpListener;
 use tokio::sync::RwLock;
-use tokio::time::{Duration, interval};
+use tokio::time::{Duration, sleep};
 
-use crate::cli::args::ServeArgs;
+use crate::cli::args::{HistoryListArgs, ServeArgs};
 use crate::cli::usage::fetch_usage;
+use crate::cli::{doctor, history};
+use crate::core::canary::{self, CanaryState};
 use crate::core::models::ProviderPayload;
-use crate::error::{CautError, Result};
+use crate::core::power::PowerScheduler;
+use crate::core::provider::{Provider, ProviderSelection};
+use crate::core::watchdog::Watchdog;
+use crate::error::{CautError, ErrorCategory, Result};
 use crate::storage::AppPaths;
+use crate::storage::config::Config;
+
+/// Snapshots returned by `/history` without `limit`, as in `history list`.
+const DEFAULT_HISTORY_LIMIT: usize = 50;
 
 /// Cached state shared between the refresh loop and HTTP handlers.
 #[derive(Debug, Clone, Default)]
@@ -91,6 +114,7 @@ async fn handle_request(
 ) -> std::result::Result<Response<Full<Bytes>>, std::convert::Infallible> {
     let path = req.uri().path().to_string();
     let method = req.method().clone();
+    let params = query_params(req.uri());
 
     tracing::debug!(%method, %path, "Handling request");
 
@@ -129,6 +153,18 @@ async fn handle_request(
             }
         }
 
+        (Method::GET, "/doctor") => match fetch_doctor_data(&params).await {
+            Ok(output_json) => ok_raw_json(output_json),
+            Err(e) => error_response(&e),
+        },
+
+        (Method::GET, "/history") => {
+            match history_args(&params).and_then(|args| history::list_output(&args)) {
+                Ok(output) => json_response(StatusCode::OK, &output),
+                Err(e) => error_response(&e),
+            }
+        }
+
         (Method::GET, "/health") => {
             let state = state.read().await;
             let health = HealthResponse {
@@ -150,6 +186,8 @@ async fn handle_request(
                     { "path": "/usage", "method": "GET", "description": "Cached provider usage data (JSON)" },
                     { "path": "/cost", "method": "GET", "description": "On-demand local cost scan (JSON)" },
                     { "path": "/session", "method": "GET", "description": "Recent session cost data (JSON)" },
+                    { "path": "/doctor", "method": "GET", "description": "Setup and provider health checks (JSON); ?provider=" },
+                    { "path": "/history", "method": "GET", "description": "Recorded usage snapshots (JSON); ?provider=&account=&since=&until=&min_used=&limit=" },
                     { "path": "/health", "method": "GET", "description": "Server health and uptime" },
                 ],
                 "version": env!("CARGO_PKG_VERSION"),
@@ -178,6 +216,31 @@ fn json_response<T: Serialize>(status: StatusCode, body: &T) -> Response<Full<By
         .expect("response builder should not fail")
 }
 
+/// An error as JSON: 400 for bad parameters and configuration, 500 otherwise.
+fn error_response(error: &CautError) -> Response<Full<Bytes>> {
+    let status = if error.category() == ErrorCategory::Configuration {
+        StatusCode::BAD_REQUEST
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+    json_response(status, &serde_json::json!({ "error": error.to_string() }))
+}
+
+/// Percent-decoded query string pairs, in order.
+fn query_params(uri: &hyper::Uri) -> Vec<(String, String)> {
+    let mut url = reqwest::Url::parse("http://localhost/").expect("static URL is valid");
+    url.set_query(uri.query());
+    url.query_pairs().into_owned().collect()
+}
+
+/// The last value given for `name`.
+fn query_param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .rfind(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
 /// Build a response from pre-serialized JSON.
 fn ok_raw_json(json: String) -> Response<Full<Bytes>> {
     Response::builder()
@@ -228,6 +291,49 @@ async fn fetch_session_data() -> Result<String> {
         .map_err(|e| CautError::Config(format!("Failed to serialize session data: {e}")))
 }
 
+/// Run doctor checks on demand. `provider` may repeat or hold a
+/// comma-separated list, as with `caut doctor --provider`.
+async fn fetch_doctor_data(params: &[(String, String)]) -> Result<String> {
+    let names: Vec<String> = params
+        .iter()
+        .filter(|(key, _)| key == "provider")
+        .flat_map(|(_, value)| value.split(','))
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    let providers = doctor::selected_providers((!names.is_empty()).then_some(names.as_slice()))?;
+    let report = doctor::build_report(&providers, false).await;
+    crate::render::doctor::render_json(&report, false)
+}
+
+/// `history list` arguments from `/history` query parameters.
+fn history_args(params: &[(String, String)]) -> Result<HistoryListArgs> {
+    let owned = |name: &str| query_param(params, name).map(str::to_string);
+    let min_used = query_param(params, "min_used")
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| CautError::Config(format!("Invalid min_used: {value}")))
+        })
+        .transpose()?;
+    let limit = query_param(params, "limit")
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| CautError::Config(format!("Invalid limit: {value}")))
+        })
+        .transpose()?;
+    Ok(HistoryListArgs {
+        provider: owned("provider"),
+        account: owned("account"),
+        min_used,
+        between: None,
+        since: owned("since"),
+        until: owned("until"),
+        limit: limit.unwrap_or(DEFAULT_HISTORY_LIMIT),
+    })
+}
+
 /// Perform the initial usage fetch and populate the shared state.
 async fn initial_fetch(usage_args: &crate::cli::args::UsageArgs, state: &Arc<RwLock<ServerState>>) {
     tracing::info!("Performing initial usage fetch...");
@@ -254,14 +360,16 @@ fn spawn_refresh_task(
     state: Arc<RwLock<ServerState>>,
     refresh_interval: Duration,
     usage_args: crate::cli::args::UsageArgs,
+    mut watchdog: Option<Watchdog>,
+    mut power: PowerScheduler,
 ) {
     tokio::spawn(async move {
-        let mut ticker = interval(refresh_interval);
-        // Skip the first tick (we already did the initial fetch)
-        ticker.tick().await;
-
+        // The initial fetch already ran, so wait before the first refresh.
         loop {
-            ticker.tick().await;
+            sleep(power.interval(refresh_interval)).await;
+            if let Some(message) = power.update().await {
+                eprintln!("{message}");
+            }
             tracing::debug!("Background refresh tick");
             match fetch_usage(&usage_args).await {
                 Ok(results) => {
@@ -277,6 +385,39 @@ fn spawn_refresh_task(
                     tracing::warn!("Background refresh failed: {}", e);
                 }
             }
+            if let Some(watchdog) = &mut watchdog {
+                for runaway in watchdog.check(chrono::Utc::now()) {
+                    eprintln!("{}", runaway.message());
+                    tracing::warn!(session = %runaway.session_id, "Runaway session");
+                    watchdog.trip(&runaway);
+                }
+            }
+        }
+    });
+}
+
+/// How often the canary task checks whether a run is due.
+const CANARY_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Spawn the task that runs the canary when a week has passed since the last
+/// run, logging the strategies that fail.
+fn spawn_canary_task(providers: Vec<Provider>, paths: &AppPaths) {
+    let path = paths.canary_file();
+    tokio::spawn(async move {
+        loop {
+            if CanaryState::load(&path).is_due(chrono::Utc::now()) {
+                tracing::info!("Running fetch strategy canary");
+                match canary::run(&providers, &path).await {
+                    Ok(state) => {
+                        let now = chrono::Utc::now();
+                        for (strategy, health) in state.failing() {
+                            eprintln!("Canary: {}", health.failure_message(strategy, now));
+                        }
+                    }
+                    Err(e) => tracing::warn!("Canary run failed: {}", e),
+                }
+            }
+            sleep(CANARY_CHECK_INTERVAL).await;
         }
     });
 }
@@ -358,7 +499,7 @@ pub async fn execute(args: &ServeArgs) -> Result<()> {
         .map_err(|e| CautError::Config(format!("Failed to bind to {addr}: {e}")))?;
 
     eprintln!("caut server listening on http://{addr}");
-    eprintln!("Endpoints: /usage /cost /session /health");
+    eprintln!("Endpoints: /usage /cost /session /doctor /history /health");
     eprintln!("Refresh interval: {}s", args.interval);
     eprintln!("Press Ctrl+C to stop.");
 
@@ -367,13 +508,41 @@ pub async fn execute(args: &ServeArgs) -> Result<()> {
     let state = Arc::new(RwLock::new(ServerState::default()));
     let started_at = std::time::Instant::now();
     let usage_args = args.to_usage_args();
+    let config = Config::load().unwrap_or_default();
+    let watchdog = Watchdog::from_config(config.watchdog);
+    if watchdog.is_some() {
+        eprintln!("Watchdog: checking running sessions each refresh");
+    }
+    let mut power = PowerScheduler::new(config.general.power_aware);
+    if let Some(message) = power.update().await {
+        eprintln!("{message}");
+    }
+
+    let canary_providers = if args.no_canary {
+        None
+    } else {
+        Some(
+            usage_args
+                .provider
+                .as_deref()
+                .map(ProviderSelection::from_arg)
+                .transpose()?
+                .unwrap_or_default()
+                .providers(),
+        )
+    };
 
     initial_fetch(&usage_args, &state).await;
     spawn_refresh_task(
         Arc::clone(&state),
         Duration::from_secs(args.interval),
         usage_args,
+        watchdog,
+        power,
     );
+    if let Some(providers) = canary_providers {
+        spawn_canary_task(providers, &paths);
+    }
     let shutdown_rx = spawn_shutdown_handler(args.pid_file.clone(), paths);
 
     accept_loop(listener, state, started_at, shutdown_rx).await;
@@ -401,6 +570,29 @@ mod tests {
         assert!(json.contains("\"uptimeSeconds\":42"));
     }
 
+    #[test]
+    fn history_params_map_to_list_args() {
+        let uri: hyper::Uri = "/history?provider=claude&since=3d&limit=10&account=a%40b.com"
+            .parse()
+            .unwrap();
+        let args = history_args(&query_params(&uri)).unwrap();
+        assert_eq!(args.provider.as_deref(), Some("claude"));
+        assert_eq!(args.account.as_deref(), Some("a@b.com"));
+        assert_eq!(args.since.as_deref(), Some("3d"));
+        assert_eq!(args.limit, 10);
+        assert_eq!(args.min_used, None);
+
+        let defaults = history_args(&[]).unwrap();
+        assert_eq!(defaults.limit, DEFAULT_HISTORY_LIMIT);
+
+        let bad = history_args(&[("limit".to_string(), "ten".to_string())]).unwrap_err();
+        assert_eq!(
+            error_response(&bad).status(),
+            StatusCode::BAD_REQUEST,
+            "bad parameters are the client's fault"
+        );
+    }
+
     #[test]
     fn server_info_serializes() {
         let info = ServerInfo {
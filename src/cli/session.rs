@@ -6,10 +6,9 @@
 use crate::cli::args::{OutputFormat, SessionArgs};
 use crate::core::pricing::SessionCostCalculator;
 use crate::core::provider::Provider;
-use crate::core::session_logs::{
-    ClaudeSessionParser, CodexSessionParser, SessionLogFinder, SessionLogPath, SessionUsage,
-};
-use crate::error::{CautError, Result};
+use crate::core::session_logs::{SessionLogFinder, SessionLogPath, SessionUsage};
+use crate::error::Result;
+use crate::render::robot;
 use chrono::{DateTime, Duration, Local, Utc};
 use serde::Serialize;
 
@@ -33,10 +32,13 @@ pub struct SessionSummary {
     pub confidence: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project_path: Option<String>,
+    /// Side-by-side install channel (e.g. `preview`), if not the default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installation: Option<String>,
 }
 
 impl SessionSummary {
-    fn from_usage(usage: &SessionUsage, provider: Provider) -> Self {
+    pub(crate) fn from_usage(usage: &SessionUsage, provider: Provider) -> Self {
         let calc = SessionCostCalculator::new();
         let cost = calc.calculate(usage);
 
@@ -63,6 +65,7 @@ impl SessionSummary {
             primary_model: cost.model,
             confidence: cost.confidence.description().to_string(),
             project_path: usage.project_path.as_ref().map(|p| p.display().to_string()),
+            installation: None,
         }
     }
 }
@@ -142,7 +145,9 @@ pub fn build_session_output(args: &SessionArgs) -> Result<SessionOutput> {
                     {
                         continue;
                     }
-                    sessions.push(SessionSummary::from_usage(&usage, provider));
+                    let mut summary = SessionSummary::from_usage(&usage, provider);
+                    summary.installation = log.installation.clone();
+                    sessions.push(summary);
                 }
                 Err(e) => {
                     errors.push(format!("{}: {}", log.path.display(), e));
@@ -192,12 +197,7 @@ pub async fn execute(
     // Render output
     match format {
         OutputFormat::Json => {
-            let json = if pretty {
-                serde_json::to_string_pretty(&output)?
-            } else {
-                serde_json::to_string(&output)?
-            };
-            println!("{json}");
+            robot::print_json(&output, pretty)?;
         }
         OutputFormat::Md => {
             println!("{}", render_markdown(&output));
@@ -212,14 +212,7 @@ pub async fn execute(
 
 /// Parse a session log file.
 fn parse_log(log: &SessionLogPath) -> Result<SessionUsage> {
-    match log.provider {
-        Provider::Claude => ClaudeSessionParser.parse(&log.path),
-        Provider::Codex => CodexSessionParser.parse(&log.path),
-        _ => Err(CautError::Config(format!(
-            "Session logs not supported for provider: {}",
-            log.provider.cli_name()
-        ))),
-    }
+    log.parse()
 }
 
 /// Calculate aggregate totals.
@@ -297,11 +290,15 @@ fn render_human(output: &SessionOutput, no_color: bool) -> String {
 fn render_session(buf: &mut String, session: &SessionSummary, no_color: bool) {
     use std::fmt::Write;
 
-    let provider_display = match session.provider.as_str() {
+    let provider_name = match session.provider.as_str() {
         "claude" => "Claude",
         "codex" => "Codex",
         _ => &session.provider,
     };
+    let provider_display = session.installation.as_ref().map_or_else(
+        || provider_name.to_string(),
+        |channel| format!("{provider_name} {channel}"),
+    );
 
     // Session header
     if no_color {
@@ -520,6 +517,7 @@ mod tests {
         SessionUsage {
             session_id: "test-session-123".to_string(),
             project_path: Some("/home/user/project".into()),
+            cwd: None,
             started_at: Some(Utc::now() - Duration::hours(1)),
             ended_at: Some(Utc::now()),
             input_tokens: 100_000,
@@ -532,6 +530,7 @@ mod tests {
                 set
             },
             message_count: 10,
+            reported_cost_usd: None,
         }
     }
 
@@ -0,0 +1,352 @@
+//! Sessions command implementation.
+//!
+//! `caut sessions list` gives one line per recent Claude or Codex session,
+//! and `caut sessions show` breaks a single session down by turn: every
+//! model response with its tokens and estimated cost.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use chrono::{DateTime, Duration, Local, Utc};
+use serde::Serialize;
+
+use crate::cli::args::{OutputFormat, SessionsCommand, SessionsListArgs, SessionsShowArgs};
+use crate::cli::session::SessionSummary;
+use crate::core::models::RobotOutput;
+use crate::core::pricing::SessionCostCalculator;
+use crate::core::provider::Provider;
+use crate::core::session_logs::{SessionLogFinder, SessionLogPath, SessionTurn, SessionUsage};
+use crate::error::{CautError, Result};
+use crate::render::robot;
+use crate::util::format::{format_cost, format_tokens, pad_to_width, truncate_middle};
+
+/// Width of the model column.
+const MODEL_WIDTH: usize = 24;
+
+/// One turn of `sessions show`, priced.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TurnRow {
+    index: usize,
+    timestamp: Option<DateTime<Utc>>,
+    model: Option<String>,
+    input_tokens: i64,
+    output_tokens: i64,
+    cache_read_tokens: i64,
+    cache_creation_tokens: i64,
+    cost_usd: f64,
+}
+
+/// Execute sessions commands.
+///
+/// # Errors
+/// Returns an error if a provider name is unknown, session logs cannot be
+/// located or read, or `show` matches no session or more than one.
+pub fn execute(cmd: &SessionsCommand, format: OutputFormat, pretty: bool) -> Result<()> {
+    match cmd {
+        SessionsCommand::List(args) => execute_list(args, format, pretty),
+        SessionsCommand::Show(args) => execute_show(args, format, pretty),
+    }
+}
+
+fn providers(name: Option<&str>) -> Result<Vec<Provider>> {
+    match name {
+        Some(name) => Ok(vec![Provider::from_cli_name(name)?]),
+        None => Ok(vec![Provider::Claude, Provider::Codex]),
+    }
+}
+
+fn execute_list(args: &SessionsListArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let finder = SessionLogFinder::new()?;
+    let since = Utc::now() - Duration::days(args.days);
+    let mut sessions = Vec::new();
+    for provider in providers(args.provider.as_deref())? {
+        for log in finder.find_sessions(provider, Some(since), None) {
+            // Unreadable logs are skipped; `caut session` reports them.
+            if let Ok(usage) = log.parse() {
+                let mut summary = SessionSummary::from_usage(&usage, provider);
+                summary.installation.clone_from(&log.installation);
+                sessions.push(summary);
+            }
+        }
+    }
+    sessions.sort_by_key(|s| std::cmp::Reverse(s.ended_at));
+    sessions.truncate(args.limit);
+
+    if format == OutputFormat::Json {
+        return robot::print_json(
+            &RobotOutput::new("sessions", serde_json::to_value(&sessions)?),
+            pretty,
+        );
+    }
+    if sessions.is_empty() {
+        println!("No sessions in the last {} days.", args.days);
+        return Ok(());
+    }
+
+    let md = format == OutputFormat::Md;
+    if md {
+        println!("| Started | Provider | Duration | Model | Tokens | Cost | Session |");
+        println!("|---------|----------|----------|-------|--------|------|---------|");
+    } else {
+        println!(
+            "{:<16}  {:<8}  {:>8}  {}  {:>8}  {:>8}  SESSION",
+            "STARTED",
+            "PROVIDER",
+            "DURATION",
+            pad_to_width("MODEL", MODEL_WIDTH),
+            "TOKENS",
+            "COST"
+        );
+    }
+    for session in &sessions {
+        let started = session.started_at.map_or_else(
+            || "-".to_string(),
+            |at| {
+                at.with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            },
+        );
+        let duration = session
+            .duration_minutes
+            .map_or_else(|| "-".to_string(), format_minutes);
+        let tokens = format_tokens(session.input_tokens + session.output_tokens);
+        let cost = format_cost(session.total_cost_usd);
+        if md {
+            println!(
+                "| {started} | {} | {duration} | {} | {tokens} | {cost} | `{}` |",
+                session.provider, session.primary_model, session.session_id
+            );
+        } else {
+            println!(
+                "{started:<16}  {:<8}  {duration:>8}  {}  {tokens:>8}  {cost:>8}  {}",
+                session.provider,
+                pad_to_width(
+                    &truncate_middle(&session.primary_model, MODEL_WIDTH),
+                    MODEL_WIDTH
+                ),
+                session.session_id
+            );
+        }
+    }
+    Ok(())
+}
+
+fn execute_show(args: &SessionsShowArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let log = find_session(&args.id, args.provider.as_deref())?;
+    let usage = log.parse()?;
+    let summary = SessionSummary::from_usage(&usage, log.provider);
+    let turns = price_turns(&log.turns()?);
+
+    if format == OutputFormat::Json {
+        return robot::print_json(
+            &RobotOutput::new(
+                "sessions",
+                serde_json::json!({ "session": summary, "turns": turns }),
+            ),
+            pretty,
+        );
+    }
+
+    let md = format == OutputFormat::Md;
+    let mut out = String::new();
+    if md {
+        writeln!(out, "# Session `{}`\n", summary.session_id).ok();
+    } else {
+        writeln!(out, "Session {}", summary.session_id).ok();
+    }
+    let prefix = if md { "- " } else { "  " };
+    writeln!(out, "{prefix}Provider: {}", log.provider.display_name()).ok();
+    if let Some(project) = &summary.project_path {
+        writeln!(out, "{prefix}Project: {project}").ok();
+    }
+    if !summary.models_used.is_empty() {
+        writeln!(out, "{prefix}Models: {}", summary.models_used.join(", ")).ok();
+    }
+    if let (Some(start), Some(minutes)) = (summary.started_at, summary.duration_minutes) {
+        writeln!(
+            out,
+            "{prefix}Started: {} ({})",
+            start.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+            format_minutes(minutes)
+        )
+        .ok();
+    }
+    writeln!(
+        out,
+        "{prefix}Total: {} in, {} out, {} cache read, {} cache write, {}",
+        format_tokens(summary.input_tokens),
+        format_tokens(summary.output_tokens),
+        format_tokens(summary.cache_read_tokens),
+        format_tokens(summary.cache_creation_tokens),
+        format_cost(summary.total_cost_usd)
+    )
+    .ok();
+    writeln!(out).ok();
+
+    render_turns(&mut out, &turns, md);
+    print!("{out}");
+    Ok(())
+}
+
+fn render_turns(out: &mut String, turns: &[TurnRow], md: bool) {
+    if turns.is_empty() {
+        writeln!(out, "No turns with token usage in this log.").ok();
+    } else if md {
+        writeln!(
+            out,
+            "| # | Time | Model | Input | Output | Cache read | Cache write | Cost |"
+        )
+        .ok();
+        writeln!(
+            out,
+            "|---|------|-------|-------|--------|------------|-------------|------|"
+        )
+        .ok();
+        for turn in turns {
+            writeln!(
+                out,
+                "| {} | {} | {} | {} | {} | {} | {} | {} |",
+                turn.index,
+                turn_time(turn.timestamp),
+                turn.model.as_deref().unwrap_or("-"),
+                format_tokens(turn.input_tokens),
+                format_tokens(turn.output_tokens),
+                format_tokens(turn.cache_read_tokens),
+                format_tokens(turn.cache_creation_tokens),
+                format_cost(turn.cost_usd)
+            )
+            .ok();
+        }
+    } else {
+        writeln!(
+            out,
+            "{:>4}  {:<8}  {}  {:>8}  {:>8}  {:>10}  {:>11}  {:>8}",
+            "#",
+            "TIME",
+            pad_to_width("MODEL", MODEL_WIDTH),
+            "INPUT",
+            "OUTPUT",
+            "CACHE READ",
+            "CACHE WRITE",
+            "COST"
+        )
+        .ok();
+        for turn in turns {
+            writeln!(
+                out,
+                "{:>4}  {:<8}  {}  {:>8}  {:>8}  {:>10}  {:>11}  {:>8}",
+                turn.index,
+                turn_time(turn.timestamp),
+                pad_to_width(
+                    &truncate_middle(turn.model.as_deref().unwrap_or("-"), MODEL_WIDTH),
+                    MODEL_WIDTH
+                ),
+                format_tokens(turn.input_tokens),
+                format_tokens(turn.output_tokens),
+                format_tokens(turn.cache_read_tokens),
+                format_tokens(turn.cache_creation_tokens),
+                format_cost(turn.cost_usd)
+            )
+            .ok();
+        }
+    }
+}
+
+/// The one session whose ID starts with `id`, searching all time.
+fn find_session(id: &str, provider: Option<&str>) -> Result<SessionLogPath> {
+    let finder = SessionLogFinder::new()?;
+    let mut matches = Vec::new();
+    for provider in providers(provider)? {
+        matches.extend(
+            finder
+                .find_sessions(provider, None, None)
+                .into_iter()
+                .filter(|log| log.session_id.starts_with(id)),
+        );
+    }
+    // The same session can be logged by more than one install channel.
+    let ids: HashSet<&str> = matches.iter().map(|log| log.session_id.as_str()).collect();
+    match ids.len() {
+        0 => Err(CautError::Config(format!(
+            "No session matches '{id}'. Run `caut sessions list` to see recent IDs."
+        ))),
+        1 => Ok(matches.swap_remove(0)),
+        n => Err(CautError::Config(format!(
+            "'{id}' matches {n} sessions; give more of the ID."
+        ))),
+    }
+}
+
+/// Price each turn with the model it names.
+fn price_turns(turns: &[SessionTurn]) -> Vec<TurnRow> {
+    let calc = SessionCostCalculator::new();
+    turns
+        .iter()
+        .enumerate()
+        .map(|(i, turn)| TurnRow {
+            index: i + 1,
+            timestamp: turn.timestamp,
+            model: turn.model.clone(),
+            input_tokens: turn.input_tokens,
+            output_tokens: turn.output_tokens,
+            cache_read_tokens: turn.cache_read_tokens,
+            cache_creation_tokens: turn.cache_creation_tokens,
+            cost_usd: turn_cost(&calc, turn),
+        })
+        .collect()
+}
+
+/// Estimated cost of one turn, priced with the model it names.
+pub(crate) fn turn_cost(calc: &SessionCostCalculator, turn: &SessionTurn) -> f64 {
+    let usage = SessionUsage {
+        input_tokens: turn.input_tokens,
+        output_tokens: turn.output_tokens,
+        cache_read_tokens: turn.cache_read_tokens,
+        cache_creation_tokens: turn.cache_creation_tokens,
+        models_used: turn.model.iter().cloned().collect(),
+        ..SessionUsage::default()
+    };
+    calc.calculate(&usage).total_usd
+}
+
+fn turn_time(timestamp: Option<DateTime<Utc>>) -> String {
+    timestamp.map_or_else(
+        || "-".to_string(),
+        |at| at.with_timezone(&Local).format("%H:%M:%S").to_string(),
+    )
+}
+
+/// Minutes as "2h 05m" or "42m".
+fn format_minutes(minutes: i64) -> String {
+    if minutes >= 60 {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{minutes}m")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_are_numbered_and_priced() {
+        let turns = price_turns(&[
+            SessionTurn {
+                model: Some("claude-sonnet-4".to_string()),
+                input_tokens: 1_000_000,
+                ..SessionTurn::default()
+            },
+            SessionTurn::default(),
+        ]);
+
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[1].index, 2);
+        assert!(turns[0].cost_usd > 0.0);
+        assert!(turns[1].cost_usd.abs() < f64::EPSILON);
+        assert_eq!(format_minutes(125), "2h 05m");
+        assert_eq!(format_minutes(42), "42m");
+    }
+}
@@ -0,0 +1,300 @@
+//! Simulate command implementation.
+//!
+//! Feeds a synthetic payload through severity classification, budget checks,
+//! alert rules, and the regular renderers so alert configuration can be
+//! verified without waiting to hit real limits. Alerts are evaluated dry:
+//! nothing is sent, and nothing is written to history, caches, or alert
+//! state.
+
+use crate::cli::args::{OutputFormat, SimulateArgs};
+use crate::cli::usage::{UsageResults, render_usage_results};
+use crate::core::alerts::{self, AlertState, AlertsFile};
+use crate::core::budgets::{
+    BudgetFileConfig, BudgetViolation, CurrentUsage, check_budget_violations, resolve_budget,
+};
+use crate::core::models::{
+    CreditsSnapshot, ProviderPayload, RateWindow, RobotOutput, UsageSnapshot,
+};
+use crate::core::provider::Provider;
+use crate::core::severity::{WindowSeverity, evaluate_windows};
+use crate::error::Result;
+use crate::render::{UsageRenderOptions, robot};
+use crate::storage::AppPaths;
+use chrono::Utc;
+use serde::Serialize;
+use std::path::Path;
+
+/// Source label attached to simulated payloads.
+pub const SIMULATED_SOURCE: &str = "simulated";
+
+/// Full result of a simulation run.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationReport {
+    /// The synthetic payload that was rendered.
+    pub payload: ProviderPayload,
+    /// Severity of each simulated window.
+    pub windows: Vec<WindowSeverity>,
+    /// Budget violations triggered by the simulated values.
+    pub budget_violations: Vec<BudgetViolation>,
+    /// Alert rules that would fire on the simulated values.
+    pub alerts: Vec<SimulatedAlert>,
+}
+
+/// An alert rule that would fire, and where it would be sent.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedAlert {
+    /// The message the channel would receive.
+    pub message: String,
+    pub channel: String,
+    #[serde(flatten)]
+    pub alert: alerts::FiredAlert,
+}
+
+/// Execute the simulate command.
+///
+/// # Errors
+/// Returns an error if the provider is unknown, simulated values are out of
+/// range, the budgets or alerts file cannot be parsed, or rendering fails.
+pub fn execute(
+    args: &SimulateArgs,
+    format: OutputFormat,
+    pretty: bool,
+    no_color: bool,
+) -> Result<()> {
+    let paths = AppPaths::new();
+    let report = simulate(args, &paths.budgets_file(), &paths.alerts_file())?;
+
+    match format {
+        OutputFormat::Human | OutputFormat::Md => {
+            let results = UsageResults {
+                payloads: vec![report.payload.clone()],
+                errors: Vec::new(),
+                costs: Vec::new(),
+                budgets: Vec::new(),
+            };
+            let options = UsageRenderOptions {
+                no_color,
+                ..UsageRenderOptions::default()
+            };
+            render_usage_results(&results, format, pretty, &options)?;
+            println!("{}", render_findings(&report, format == OutputFormat::Md));
+        }
+        OutputFormat::Json => {
+            let output = RobotOutput::new("simulate", report);
+            robot::print_json(&output, pretty)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Build a simulation report from arguments and the budgets and alerts
+/// files, either of which may be missing.
+///
+/// # Errors
+/// Returns an error if the provider is unknown, values are out of range, or
+/// the budgets or alerts file exists but cannot be parsed.
+pub fn simulate(
+    args: &SimulateArgs,
+    budgets_path: &Path,
+    alerts_path: &Path,
+) -> Result<SimulationReport> {
+    args.validate()?;
+    let provider = Provider::from_cli_name(&args.provider)?;
+
+    let payload = build_payload(provider, args);
+    let windows = evaluate_windows(&payload.usage);
+
+    let configs = BudgetFileConfig::load(budgets_path)?.to_configs();
+    let budget = resolve_budget(provider, &configs);
+    let usage = CurrentUsage {
+        daily_cost_usd: args.cost_today,
+        daily_usage_percent: Some(args.primary),
+        weekly_usage_percent: args.secondary,
+        ..CurrentUsage::default()
+    };
+    let budget_violations = check_budget_violations(&budget, &usage);
+
+    // A fresh state, so every rule over its threshold shows as firing.
+    let rules = AlertsFile::load(alerts_path)?.alerts;
+    let alerts = alerts::evaluate(
+        &rules,
+        std::slice::from_ref(&payload),
+        &mut AlertState::default(),
+        Utc::now(),
+    )
+    .into_iter()
+    .map(|alert| SimulatedAlert {
+        message: alert.message(),
+        channel: alert.channel.to_string(),
+        alert,
+    })
+    .collect();
+
+    Ok(SimulationReport {
+        payload,
+        windows,
+        budget_violations,
+        alerts,
+    })
+}
+
+fn build_payload(provider: Provider, args: &SimulateArgs) -> ProviderPayload {
+    let mut usage = UsageSnapshot::new(RateWindow::new(args.primary));
+    usage.secondary = args.secondary.map(RateWindow::new);
+    usage.tertiary = args.tertiary.map(RateWindow::new);
+
+    ProviderPayload {
+        provider: provider.cli_name().to_string(),
+        account: None,
+        version: None,
+        source: SIMULATED_SOURCE.to_string(),
+        status: None,
+        usage,
+        credits: args.credits.map(|remaining| CreditsSnapshot {
+            remaining,
+            events: Vec::new(),
+            updated_at: Utc::now(),
+        }),
+        antigravity_plan_info: None,
+        openai_dashboard: None,
+        copilot_premium: None,
+        task_spend: None,
+        local_model: None,
+        divergences: Vec::new(),
+        auth_warning: None,
+    }
+}
+
+fn render_findings(report: &SimulationReport, markdown: bool) -> String {
+    let mut lines = Vec::new();
+    if markdown {
+        lines.push("## Simulation\n".to_string());
+    } else {
+        lines.push("Simulation:".to_string());
+    }
+
+    for window in &report.windows {
+        let marker = if window.severity.is_alert() { "!" } else { "-" };
+        lines.push(format!(
+            "{marker} {}: {:.0}% used, {:.0}% left ({})",
+            window.window,
+            window.used_percent,
+            window.remaining_percent,
+            window.severity.label()
+        ));
+    }
+
+    if report.budget_violations.is_empty() {
+        lines.push("- budgets: no violations".to_string());
+    } else {
+        for violation in &report.budget_violations {
+            lines.push(format!(
+                "! budget: {} ({:.1} of {:.1}, {} limit)",
+                violation.violation_type, violation.current, violation.limit, violation.source
+            ));
+        }
+    }
+
+    if report.alerts.is_empty() {
+        lines.push("- alerts: none would fire".to_string());
+    } else {
+        for alert in &report.alerts {
+            lines.push(format!(
+                "! alert {}: {} -> {} (not sent)",
+                alert.alert.id, alert.message, alert.channel
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::severity::Severity;
+    use crate::error::CautError;
+    use tempfile::TempDir;
+
+    fn make_args(primary: f64) -> SimulateArgs {
+        SimulateArgs {
+            provider: "claude".to_string(),
+            primary,
+            secondary: None,
+            tertiary: None,
+            credits: None,
+            cost_today: None,
+        }
+    }
+
+    fn simulate_in(dir: &TempDir, args: &SimulateArgs) -> Result<SimulationReport> {
+        simulate(
+            args,
+            &dir.path().join("budgets.toml"),
+            &dir.path().join("alerts.toml"),
+        )
+    }
+
+    #[test]
+    fn simulate_marks_payload_as_simulated() {
+        let dir = TempDir::new().unwrap();
+        let report = simulate_in(&dir, &make_args(95.0)).unwrap();
+
+        assert_eq!(report.payload.provider, "claude");
+        assert_eq!(report.payload.source, SIMULATED_SOURCE);
+        assert_eq!(report.windows.len(), 1);
+        assert_eq!(report.windows[0].severity, Severity::Critical);
+        assert!(report.budget_violations.is_empty());
+        assert!(report.alerts.is_empty());
+    }
+
+    #[test]
+    fn simulate_evaluates_alert_rules_without_state() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("alerts.toml"),
+            "[[alert]]\nid = 1\nprovider = \"claude\"\nmetric = \"primary\"\nthreshold = 80.0\nchannel = \"desktop\"\n\n\
+             [[alert]]\nid = 2\nmetric = \"primary\"\nthreshold = 99.0\nchannel = \"desktop\"\n",
+        )
+        .unwrap();
+
+        let report = simulate_in(&dir, &make_args(95.0)).unwrap();
+        assert_eq!(report.alerts.len(), 1);
+        assert_eq!(report.alerts[0].alert.id, 1);
+        assert_eq!(report.alerts[0].channel, "desktop");
+        assert!(render_findings(&report, false).contains("(not sent)"));
+        assert!(!dir.path().join("alert-state.json").exists());
+    }
+
+    #[test]
+    fn simulate_checks_budgets_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("budgets.toml");
+        std::fs::write(&path, "[claude]\ndaily_usage_percent = 80\n").unwrap();
+
+        let report = simulate_in(&dir, &make_args(95.0)).unwrap();
+        assert_eq!(report.budget_violations.len(), 1);
+        assert_eq!(
+            report.budget_violations[0].violation_type,
+            crate::core::budgets::ViolationType::DailyUsage
+        );
+    }
+
+    #[test]
+    fn simulate_rejects_out_of_range_percent() {
+        let dir = TempDir::new().unwrap();
+        let err = simulate_in(&dir, &make_args(120.0)).unwrap_err();
+        assert!(matches!(err, CautError::Config(_)));
+    }
+
+    #[test]
+    fn simulate_rejects_unknown_provider() {
+        let dir = TempDir::new().unwrap();
+        let mut args = make_args(50.0);
+        args.provider = "nope".to_string();
+        assert!(simulate_in(&dir, &args).is_err());
+    }
+}
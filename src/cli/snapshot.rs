@@ -0,0 +1,205 @@
+//! Snapshot command implementation.
+//!
+//! `caut snapshot` fetches every provider once and records the result to
+//! history with the `manual` trigger, optionally annotated with `--note`.
+//! It is the explicit counterpart to the periodic captures `caut usage`,
+//! `caut watch`, and `caut daemon` make, for marking a point worth finding
+//! again: before a refactor, after a plan change, at the end of a sprint.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::cli::args::{OutputFormat, SnapshotArgs};
+use crate::cli::usage::nothing_configured;
+use crate::core::fetch_plan::SourceMode;
+use crate::core::models::RobotOutput;
+use crate::core::pipeline::{ProviderResult, UsageRequest, record_results, run_usage};
+use crate::core::provider::ProviderSelection;
+use crate::error::{CautError, Result};
+use crate::render::robot;
+use crate::storage::{AppPaths, HistoryStore, SnapshotTrigger};
+
+/// One provider's part of a capture.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CapturedProvider {
+    provider: String,
+    primary_percent: Option<f64>,
+    secondary_percent: Option<f64>,
+    /// Why the provider could not be captured.
+    error: Option<String>,
+}
+
+/// What `caut snapshot` recorded.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Capture {
+    captured_at: DateTime<Utc>,
+    note: Option<String>,
+    /// Snapshots written to history.
+    recorded: usize,
+    providers: Vec<CapturedProvider>,
+}
+
+/// Execute the snapshot command.
+///
+/// # Errors
+/// Returns an error if the provider selection is invalid, no provider is
+/// set up, the history database cannot be opened, or output serialization
+/// fails.
+pub async fn execute(args: &SnapshotArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let selection = args
+        .provider
+        .as_deref()
+        .map(ProviderSelection::from_arg)
+        .transpose()?
+        .unwrap_or_default();
+    let note = args
+        .note
+        .as_deref()
+        .map(str::trim)
+        .filter(|note| !note.is_empty());
+
+    let request = UsageRequest {
+        providers: selection.providers(),
+        mode: SourceMode::Auto,
+        timeout_override: None,
+        include_status: false,
+        record_history: false,
+        verify: false,
+    };
+    let results = run_usage(&request).await;
+    if !selection.is_single() && nothing_configured(&results) {
+        return Err(CautError::NoProvidersConfigured {
+            checked: results.len(),
+        });
+    }
+
+    let store = HistoryStore::open(&AppPaths::new().history_db_file())?;
+    let recorded = record_results(&store, &results, SnapshotTrigger::Manual, note);
+    let capture = Capture {
+        captured_at: Utc::now(),
+        note: note.map(str::to_string),
+        recorded,
+        providers: captured_providers(&results),
+    };
+
+    match format {
+        OutputFormat::Json => {
+            let output = RobotOutput::new("snapshot", &capture);
+            robot::print_json(&output, pretty)?;
+        }
+        OutputFormat::Human => print!("{}", render(&capture, false)),
+        OutputFormat::Md => print!("{}", render(&capture, true)),
+    }
+    Ok(())
+}
+
+/// Providers that were captured or that failed; providers that are not set
+/// up on this machine are left out.
+fn captured_providers(results: &[ProviderResult]) -> Vec<CapturedProvider> {
+    results
+        .iter()
+        .filter(|result| !result.is_not_configured())
+        .map(|result| match &result.outcome {
+            Ok(payload) => CapturedProvider {
+                provider: payload.provider.clone(),
+                primary_percent: payload.usage.primary.as_ref().map(|w| w.used_percent),
+                secondary_percent: payload.usage.secondary.as_ref().map(|w| w.used_percent),
+                error: None,
+            },
+            Err(error) => CapturedProvider {
+                provider: result.provider.cli_name().to_string(),
+                primary_percent: None,
+                secondary_percent: None,
+                error: Some(error.to_string()),
+            },
+        })
+        .collect()
+}
+
+fn render(capture: &Capture, markdown: bool) -> String {
+    let mut out = String::new();
+    let note = capture
+        .note
+        .as_deref()
+        .map_or_else(String::new, |note| format!(" \"{note}\""));
+    if markdown {
+        writeln!(out, "# Snapshot{note}\n").ok();
+        writeln!(out, "| Provider | Session | Weekly | Status |").ok();
+        writeln!(out, "|----------|---------|--------|--------|").ok();
+    } else {
+        writeln!(out, "Recorded {} snapshot(s){note}", capture.recorded).ok();
+    }
+    for provider in &capture.providers {
+        let session = percent_text(provider.primary_percent);
+        let weekly = percent_text(provider.secondary_percent);
+        let status = provider
+            .error
+            .as_deref()
+            .map_or_else(|| "recorded".to_string(), |e| format!("failed: {e}"));
+        if markdown {
+            writeln!(
+                out,
+                "| {} | {session} | {weekly} | {status} |",
+                provider.provider
+            )
+            .ok();
+        } else if provider.error.is_some() {
+            writeln!(out, "  {:<10} {status}", provider.provider).ok();
+        } else {
+            writeln!(
+                out,
+                "  {:<10} session {session:>4}  weekly {weekly:>4}",
+                provider.provider
+            )
+            .ok();
+        }
+    }
+    out
+}
+
+fn percent_text(percent: Option<f64>) -> String {
+    percent.map_or_else(|| "-".to_string(), |p| format!("{p:.0}%"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_recorded_and_failed_providers() {
+        let capture = Capture {
+            captured_at: Utc::now(),
+            note: Some("before refactor".to_string()),
+            recorded: 1,
+            providers: vec![
+                CapturedProvider {
+                    provider: "claude".to_string(),
+                    primary_percent: Some(62.4),
+                    secondary_percent: Some(18.0),
+                    error: None,
+                },
+                CapturedProvider {
+                    provider: "codex".to_string(),
+                    primary_percent: None,
+                    secondary_percent: None,
+                    error: Some("timed out".to_string()),
+                },
+            ],
+        };
+
+        let human = render(&capture, false);
+        assert_eq!(
+            human,
+            "Recorded 1 snapshot(s) \"before refactor\"\n  \
+             claude     session  62%  weekly  18%\n  \
+             codex      failed: timed out\n"
+        );
+        let markdown = render(&capture, true);
+        assert!(markdown.starts_with("# Snapshot \"before refactor\"\n"));
+        assert!(markdown.contains("| codex | - | - | failed: timed out |"));
+    }
+}
@@ -0,0 +1,676 @@
+//! Statusline integrations for editors and launchers.
+//!
+//! Like `caut prompt`, these outputs read only from the prompt cache that
+//! `caut usage` keeps up to date, so they never touch the network and return
+//! in a few milliseconds. Editors can poll them on a timer without blocking
+//! the UI; keep the cache warm with a periodic `caut usage --json`.
+//!
+//! # Fast Path
+//!
+//! `caut statusline lsp-json` prints exactly one JSON object per call, even
+//! when no data is cached, so callers never have to handle a failure mode:
+//!
+//! ```json
+//! {"text":"cl:46%|$12.3","severity":"info","lspSeverity":3,"stale":false}
+//! ```
+//!
+//! # Launchers
+//!
+//! `caut statusline raycast` emits a list of per-provider items with quick
+//! actions (open the provider dashboard, run `caut doctor`), shaped like
+//! Raycast `List.Item` props; `--inline` prints a single line for Raycast
+//! script commands in inline mode, and `--install` prints such a script.
+//! `caut statusline alfred` emits Alfred Script Filter JSON with the same
+//! items, routing actions through an `action` workflow variable.
+//!
+//! # Shell Prompts
+//!
+//! `caut statusline shell` prints one short line (`cl:62% cx:18% $4.20`) for
+//! PS1, starship, or tmux. It still only reads the cache, but once the cache
+//! is older than `--max-age` it marks the line stale and starts a detached
+//! `caut warm`, so the next prompt is fresh without this one waiting.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::cli::args::{
+    PromptFormat, RaycastArgs, ShellStatuslineArgs, StatuslineCommand, WarmArgs,
+};
+use crate::cli::prompt::{
+    PromptCache, ProviderPromptData, format_prompt_with_staleness, short_provider_name,
+};
+use crate::cli::warm;
+use crate::core::provider::{Provider, ProviderRegistry};
+use crate::error::Result;
+use crate::render::robot;
+use crate::storage::AppPaths;
+use crate::storage::cache::{Staleness, get_age_secs, read_with_staleness};
+use crate::util::format::format_cost;
+use crate::util::time::humanize_reset;
+
+/// Usage percentage at which the statusline turns to a warning.
+const WARNING_PERCENT: f64 = 70.0;
+
+/// Usage percentage at which the statusline turns to an error.
+const ERROR_PERCENT: f64 = 90.0;
+
+/// Command offered as the "run doctor" quick action.
+const DOCTOR_COMMAND: &str = "caut doctor";
+
+/// Command offered as the "refresh" quick action.
+const REFRESH_COMMAND: &str = "caut usage --json";
+
+/// Hint shown when there is no cached data.
+const NO_DATA_HINT: &str = "No cached usage. Run `caut usage` to refresh.";
+
+/// Statusline severity, ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl StatusSeverity {
+    /// Classify a usage percentage.
+    #[must_use]
+    pub fn from_used_percent(used_percent: f64) -> Self {
+        if used_percent >= ERROR_PERCENT {
+            Self::Error
+        } else if used_percent >= WARNING_PERCENT {
+            Self::Warning
+        } else {
+            Self::Info
+        }
+    }
+
+    /// Traffic-light glyph for launcher items.
+    #[must_use]
+    pub const fn icon(self) -> &'static str {
+        match self {
+            Self::Info => "🟢",
+            Self::Warning => "🟡",
+            Self::Error => "🔴",
+        }
+    }
+
+    /// LSP `DiagnosticSeverity` code (1 = Error, 2 = Warning, 3 = Information).
+    #[must_use]
+    pub const fn lsp_code(self) -> u8 {
+        match self {
+            Self::Error => 1,
+            Self::Warning => 2,
+            Self::Info => 3,
+        }
+    }
+}
+
+/// Minimal statusline payload for editor plugins.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LspStatus {
+    /// Short text for the statusbar item.
+    pub text: String,
+    /// Highest severity across the shown providers.
+    pub severity: StatusSeverity,
+    /// Same severity as an LSP `DiagnosticSeverity` code.
+    pub lsp_severity: u8,
+    /// Longer per-provider description for hover text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tooltip: Option<String>,
+    /// Whether the cached data is older than the fresh threshold (or missing).
+    pub stale: bool,
+    /// Age of the cached data in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_secs: Option<u64>,
+}
+
+/// Execute statusline subcommands.
+///
+/// # Errors
+/// Returns an error only if the output cannot be serialized.
+pub fn execute(cmd: &StatuslineCommand, pretty: bool) -> Result<()> {
+    match cmd {
+        StatuslineCommand::LspJson(args) => {
+            let cached = load_cached(args.provider.as_deref());
+            robot::print_json(&build_lsp_status(cached.as_ref()), pretty)
+        }
+        StatuslineCommand::Raycast(args) => execute_raycast(args, pretty),
+        StatuslineCommand::Alfred(args) => {
+            let cached = load_cached(args.provider.as_deref());
+            robot::print_json(&build_alfred(cached.as_ref()), pretty)
+        }
+        StatuslineCommand::Shell(args) => {
+            execute_shell(args);
+            Ok(())
+        }
+    }
+}
+
+fn execute_shell(args: &ShellStatuslineArgs) {
+    let cached = load_cached(args.provider.as_deref());
+    let expired = cached
+        .as_ref()
+        .and_then(|c| c.age_secs)
+        .is_none_or(|age| age > args.max_age);
+    if expired && !args.no_refresh {
+        let warm_args = WarmArgs {
+            provider: args.provider.clone(),
+            background: true,
+            max_age: args.max_age,
+        };
+        if let Err(e) = warm::start_background(&warm_args) {
+            tracing::debug!(error = %e, "Could not start background refresh");
+        }
+    }
+    if let Some(cached) = &cached {
+        print!("{}", shell_line(cached, args.max_age));
+    }
+}
+
+fn execute_raycast(args: &RaycastArgs, pretty: bool) -> Result<()> {
+    if args.install {
+        print!("{}", raycast_script(args.provider.as_deref()));
+        return Ok(());
+    }
+
+    let cached = load_cached(args.provider.as_deref());
+    if args.inline {
+        let status = build_lsp_status(cached.as_ref());
+        if status.text.is_empty() {
+            println!("caut: no data");
+        } else {
+            println!("{} {}", status.severity.icon(), status.text);
+        }
+        return Ok(());
+    }
+
+    robot::print_json(&build_raycast(cached.as_ref()), pretty)
+}
+
+/// Cached provider data selected for a statusline.
+#[derive(Debug, Clone)]
+struct CachedStatus {
+    providers: Vec<ProviderPromptData>,
+    staleness: Staleness,
+    age_secs: Option<u64>,
+}
+
+/// Read the prompt cache, keeping only the requested provider.
+fn load_cached(provider: Option<&str>) -> Option<CachedStatus> {
+    let paths = AppPaths::new();
+    let cache_path = paths.prompt_cache_file();
+
+    let (cache, staleness): (PromptCache, Staleness) =
+        read_with_staleness(&cache_path).unwrap_or(None)?;
+
+    let providers = cache
+        .providers
+        .into_iter()
+        .filter(|p| provider.is_none_or(|name| p.provider == name))
+        .collect();
+
+    Some(CachedStatus {
+        providers,
+        staleness,
+        age_secs: get_age_secs(&cache_path),
+    })
+}
+
+/// Highest usage percentage reported by a provider.
+fn max_used_percent(provider: &ProviderPromptData) -> Option<f64> {
+    match (provider.primary_pct, provider.secondary_pct) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn severity_of(provider: &ProviderPromptData) -> StatusSeverity {
+    max_used_percent(provider).map_or(StatusSeverity::Info, StatusSeverity::from_used_percent)
+}
+
+/// Human-readable usage facts for a provider.
+fn usage_parts(provider: &ProviderPromptData) -> Vec<String> {
+    let mut parts = Vec::new();
+    let reset = |at: Option<DateTime<Utc>>| {
+        at.map_or_else(String::new, |at| format!(" ({})", humanize_reset(at)))
+    };
+    if let Some(pct) = provider.primary_pct {
+        parts.push(format!(
+            "{pct:.0}% session{}",
+            reset(provider.primary_resets_at)
+        ));
+    }
+    if let Some(pct) = provider.secondary_pct {
+        parts.push(format!(
+            "{pct:.0}% weekly{}",
+            reset(provider.secondary_resets_at)
+        ));
+    }
+    if let Some(cost) = provider.cost_today_usd {
+        parts.push(format!("${cost:.2} today"));
+    }
+    if let Some(credits) = provider.credits_remaining {
+        parts.push(format!("{credits:.1} credits left"));
+    }
+    if parts.is_empty() {
+        parts.push("no usage data".to_string());
+    }
+    parts
+}
+
+fn describe_provider(provider: &ProviderPromptData) -> String {
+    format!(
+        "{}: {}",
+        provider.provider,
+        usage_parts(provider).join(", ")
+    )
+}
+
+fn display_name(provider: &ProviderPromptData) -> String {
+    Provider::from_cli_name(&provider.provider).map_or_else(
+        |_| provider.provider.clone(),
+        |p| p.display_name().to_string(),
+    )
+}
+
+fn dashboard_url(
+    registry: &ProviderRegistry,
+    provider: &ProviderPromptData,
+) -> Option<&'static str> {
+    let provider = Provider::from_cli_name(&provider.provider).ok()?;
+    registry.get(provider)?.metadata.dashboard_url
+}
+
+fn staleness_note(staleness: Staleness) -> Option<&'static str> {
+    match staleness {
+        Staleness::Fresh => None,
+        Staleness::Stale => Some("cached data is a few minutes old"),
+        Staleness::VeryStale | Staleness::Missing => Some("cached data is out of date"),
+    }
+}
+
+fn build_lsp_status(cached: Option<&CachedStatus>) -> LspStatus {
+    let Some(cached) = cached.filter(|c| !c.providers.is_empty()) else {
+        return LspStatus {
+            text: String::new(),
+            severity: StatusSeverity::Info,
+            lsp_severity: StatusSeverity::Info.lsp_code(),
+            tooltip: Some(NO_DATA_HINT.to_string()),
+            stale: true,
+            age_secs: None,
+        };
+    };
+
+    let refs: Vec<&ProviderPromptData> = cached.providers.iter().collect();
+    let text = format_prompt_with_staleness(&refs, PromptFormat::Compact, false, cached.staleness);
+
+    let severity = cached
+        .providers
+        .iter()
+        .map(severity_of)
+        .max()
+        .unwrap_or(StatusSeverity::Info);
+
+    let tooltip = cached
+        .providers
+        .iter()
+        .map(describe_provider)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    LspStatus {
+        text,
+        severity,
+        lsp_severity: severity.lsp_code(),
+        tooltip: Some(tooltip),
+        stale: cached.staleness != Staleness::Fresh,
+        age_secs: cached.age_secs,
+    }
+}
+
+/// "cl:62% cx:18% $4.20": each provider's session usage, then today's spend
+/// across them. A line older than `max_age` gets the staleness prefix.
+fn shell_line(cached: &CachedStatus, max_age: u64) -> String {
+    let mut parts: Vec<String> = cached
+        .providers
+        .iter()
+        .filter_map(|p| {
+            let pct = p.primary_pct?;
+            Some(format!("{}:{pct:.0}%", short_provider_name(&p.provider)))
+        })
+        .collect();
+    let costs: Vec<f64> = cached
+        .providers
+        .iter()
+        .filter_map(|p| p.cost_today_usd)
+        .collect();
+    if !costs.is_empty() {
+        parts.push(format_cost(costs.iter().sum()));
+    }
+
+    let line = parts.join(" ");
+    if line.is_empty() || cached.age_secs.is_some_and(|age| age <= max_age) {
+        return line;
+    }
+    // Past `max_age` but still inside the cache's own fresh window.
+    let prefix = match cached.staleness {
+        Staleness::Fresh => Staleness::Stale.prefix(),
+        staleness => staleness.prefix(),
+    };
+    format!("{prefix}{line}")
+}
+
+/// Quick actions shared by every launcher list.
+fn global_actions() -> [(&'static str, &'static str, &'static str); 2] {
+    [
+        ("doctor", "Run caut doctor", DOCTOR_COMMAND),
+        ("refresh", "Refresh usage cache", REFRESH_COMMAND),
+    ]
+}
+
+/// Raycast list payload (`List.Item`-shaped items with actions).
+fn build_raycast(cached: Option<&CachedStatus>) -> serde_json::Value {
+    let status = build_lsp_status(cached);
+    let registry = ProviderRegistry::new();
+    let providers = cached.map_or(&[][..], |c| c.providers.as_slice());
+
+    let mut items: Vec<serde_json::Value> = providers
+        .iter()
+        .map(|p| {
+            let severity = severity_of(p);
+            let mut actions = Vec::new();
+            if let Some(url) = dashboard_url(&registry, p) {
+                actions.push(json!({"type": "openUrl", "title": "Open Usage Dashboard", "url": url}));
+            }
+            actions.push(json!({"type": "runCommand", "title": "Run caut doctor", "command": DOCTOR_COMMAND}));
+            json!({
+                "id": p.provider,
+                "title": display_name(p),
+                "subtitle": usage_parts(p).join(" · "),
+                "icon": severity.icon(),
+                "accessories": max_used_percent(p)
+                    .map(|pct| vec![json!({"text": format!("{pct:.0}%")})])
+                    .unwrap_or_default(),
+                "severity": severity,
+                "actions": actions,
+            })
+        })
+        .collect();
+
+    if items.is_empty() {
+        items.push(json!({"id": "no-data", "title": "No cached usage", "subtitle": NO_DATA_HINT}));
+    }
+
+    let actions: Vec<serde_json::Value> = global_actions()
+        .iter()
+        .map(|(id, title, command)| json!({"id": id, "type": "runCommand", "title": title, "command": command}))
+        .collect();
+
+    json!({
+        "title": if status.text.is_empty() { "caut: no data".to_string() } else { status.text },
+        "subtitle": cached.and_then(|c| staleness_note(c.staleness)),
+        "severity": status.severity,
+        "items": items,
+        "actions": actions,
+    })
+}
+
+/// Alfred Script Filter payload.
+///
+/// Each item sets an `action` variable (`open` or `run`) so the workflow can
+/// route `arg` to "Open URL" or "Run Script"; holding Cmd switches any
+/// provider row to running `caut doctor`.
+fn build_alfred(cached: Option<&CachedStatus>) -> serde_json::Value {
+    let registry = ProviderRegistry::new();
+    let providers = cached.map_or(&[][..], |c| c.providers.as_slice());
+    let note = cached.and_then(|c| staleness_note(c.staleness));
+
+    let mut items: Vec<serde_json::Value> = providers
+        .iter()
+        .map(|p| {
+            let severity = severity_of(p);
+            let subtitle = match note {
+                Some(note) => format!("{} ({note})", usage_parts(p).join(" · ")),
+                None => usage_parts(p).join(" · "),
+            };
+            let (arg, action) =
+                dashboard_url(&registry, p).map_or((DOCTOR_COMMAND, "run"), |url| (url, "open"));
+            json!({
+                "uid": format!("caut-{}", p.provider),
+                "title": format!("{} {}", severity.icon(), display_name(p)),
+                "subtitle": subtitle,
+                "arg": arg,
+                "valid": true,
+                "variables": {"action": action},
+                "mods": {
+                    "cmd": {
+                        "arg": DOCTOR_COMMAND,
+                        "subtitle": "Run caut doctor",
+                        "variables": {"action": "run"},
+                    }
+                },
+            })
+        })
+        .collect();
+
+    if providers.is_empty() {
+        items.push(json!({
+            "uid": "caut-no-data",
+            "title": "No cached usage",
+            "subtitle": NO_DATA_HINT,
+            "valid": false,
+        }));
+    }
+
+    for (id, title, command) in global_actions() {
+        items.push(json!({
+            "uid": format!("caut-{id}"),
+            "title": title,
+            "subtitle": command,
+            "arg": command,
+            "valid": true,
+            "variables": {"action": "run"},
+        }));
+    }
+
+    json!({ "items": items })
+}
+
+/// Raycast script command that shows usage inline.
+fn raycast_script(provider: Option<&str>) -> String {
+    let provider_arg = provider
+        .map(|p| format!(" --provider {p}"))
+        .unwrap_or_default();
+    format!(
+        r"#!/bin/bash
+
+# Save as caut-usage.sh in your Raycast script commands directory.
+
+# Required parameters:
+# @raycast.schemaVersion 1
+# @raycast.title Agent Usage
+# @raycast.mode inline
+# @raycast.refreshTime 5m
+
+# Optional parameters:
+# @raycast.icon ⚡
+# @raycast.packageName caut
+
+caut statusline raycast --inline{provider_arg}
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provider(name: &str, primary: Option<f64>, secondary: Option<f64>) -> ProviderPromptData {
+        ProviderPromptData {
+            provider: name.to_string(),
+            primary_pct: primary,
+            secondary_pct: secondary,
+            primary_resets_at: None,
+            secondary_resets_at: None,
+            credits_remaining: None,
+            cost_today_usd: None,
+        }
+    }
+
+    fn cached(providers: Vec<ProviderPromptData>, staleness: Staleness) -> CachedStatus {
+        CachedStatus {
+            providers,
+            staleness,
+            age_secs: Some(12),
+        }
+    }
+
+    #[test]
+    fn severity_thresholds() {
+        assert_eq!(
+            StatusSeverity::from_used_percent(10.0),
+            StatusSeverity::Info
+        );
+        assert_eq!(
+            StatusSeverity::from_used_percent(70.0),
+            StatusSeverity::Warning
+        );
+        assert_eq!(
+            StatusSeverity::from_used_percent(95.0),
+            StatusSeverity::Error
+        );
+        assert_eq!(StatusSeverity::Error.lsp_code(), 1);
+    }
+
+    #[test]
+    fn usage_parts_include_humanized_resets() {
+        let resets_at = Utc::now() + chrono::Duration::minutes(30);
+        let data = ProviderPromptData {
+            primary_resets_at: Some(resets_at),
+            ..provider("claude", Some(62.0), Some(40.0))
+        };
+        assert_eq!(
+            usage_parts(&data)[..2],
+            [
+                format!("62% session ({})", humanize_reset(resets_at)),
+                "40% weekly".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_cache_yields_empty_stale_status() {
+        let status = build_lsp_status(None);
+        assert!(status.text.is_empty());
+        assert!(status.stale);
+        assert_eq!(status.severity, StatusSeverity::Info);
+    }
+
+    #[test]
+    fn severity_uses_worst_window_across_providers() {
+        let data = cached(
+            vec![
+                provider("claude", Some(20.0), Some(75.0)),
+                provider("codex", Some(40.0), None),
+            ],
+            Staleness::Fresh,
+        );
+        let status = build_lsp_status(Some(&data));
+
+        assert_eq!(status.severity, StatusSeverity::Warning);
+        assert_eq!(status.lsp_severity, 2);
+        assert!(status.text.contains("cl:20%"));
+        assert!(!status.stale);
+        assert!(status.tooltip.unwrap().contains("75% weekly"));
+    }
+
+    #[test]
+    fn stale_cache_is_prefixed_and_flagged() {
+        let data = cached(vec![provider("claude", Some(50.0), None)], Staleness::Stale);
+        let status = build_lsp_status(Some(&data));
+
+        assert!(status.text.starts_with('~'));
+        assert!(status.stale);
+    }
+
+    #[test]
+    fn raycast_items_carry_dashboard_and_doctor_actions() {
+        let data = cached(vec![provider("claude", Some(95.0), None)], Staleness::Fresh);
+        let output = build_raycast(Some(&data));
+
+        assert_eq!(output["severity"], "error");
+        let item = &output["items"][0];
+        assert_eq!(item["id"], "claude");
+        assert_eq!(item["accessories"][0]["text"], "95%");
+        assert_eq!(item["actions"][0]["type"], "openUrl");
+        assert_eq!(item["actions"][1]["command"], DOCTOR_COMMAND);
+        assert_eq!(output["actions"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn alfred_script_filter_has_items_and_actions() {
+        let data = cached(
+            vec![provider("claude", Some(30.0), None)],
+            Staleness::VeryStale,
+        );
+        let output = build_alfred(Some(&data));
+        let items = output["items"].as_array().unwrap();
+
+        // One provider row plus doctor and refresh actions.
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0]["variables"]["action"], "open");
+        assert_eq!(items[0]["mods"]["cmd"]["arg"], DOCTOR_COMMAND);
+        assert!(
+            items[0]["subtitle"]
+                .as_str()
+                .unwrap()
+                .contains("out of date")
+        );
+        assert_eq!(items[1]["arg"], DOCTOR_COMMAND);
+    }
+
+    #[test]
+    fn launchers_handle_missing_cache() {
+        let raycast = build_raycast(None);
+        assert_eq!(raycast["title"], "caut: no data");
+        assert_eq!(raycast["items"][0]["id"], "no-data");
+
+        let alfred = build_alfred(None);
+        assert_eq!(alfred["items"][0]["valid"], false);
+    }
+
+    #[test]
+    fn raycast_script_has_required_metadata() {
+        let script = raycast_script(Some("claude"));
+        assert!(script.contains("@raycast.schemaVersion 1"));
+        assert!(script.contains("@raycast.mode inline"));
+        assert!(script.ends_with("caut statusline raycast --inline --provider claude\n"));
+    }
+
+    #[test]
+    fn json_shape_is_minimal() {
+        let data = cached(vec![provider("claude", Some(50.0), None)], Staleness::Fresh);
+        let json = serde_json::to_value(build_lsp_status(Some(&data))).unwrap();
+
+        assert_eq!(json["severity"], "info");
+        assert_eq!(json["lspSeverity"], 3);
+        assert_eq!(json["ageSecs"], 12);
+        assert!(json["text"].is_string());
+    }
+
+    #[test]
+    fn shell_line_is_compact_and_marks_expired_cache() {
+        let mut claude = provider("claude", Some(62.4), None);
+        claude.cost_today_usd = Some(3.0);
+        let mut codex = provider("codex", Some(18.0), None);
+        codex.cost_today_usd = Some(1.2);
+        let data = cached(vec![claude, codex], Staleness::Fresh);
+
+        assert_eq!(shell_line(&data, 300), "cl:62% cx:18% $4.20");
+        assert_eq!(shell_line(&data, 5), "~cl:62% cx:18% $4.20");
+        assert_eq!(shell_line(&cached(Vec::new(), Staleness::Fresh), 5), "");
+    }
+}
@@ -0,0 +1,280 @@
+//! Ultra-compact summary for agent context windows.
+//!
+//! `caut summary` condenses remaining capacity and daily budget state into
+//! one short line, or a tiny JSON object with `--json`, that an agent
+//! harness can paste into a system prompt every turn. Like `caut prompt`, it
+//! reads only the prompt cache, so it never touches the network.
+//!
+//! Output never exceeds [`MAX_SUMMARY_BYTES`]: providers closest to their
+//! limits come first, and the rest are dropped (and counted) when space runs
+//! out. JSON output is not wrapped in the usual `caut.v1` envelope.
+
+use std::fmt::Write;
+
+use chrono::Utc;
+use serde::Serialize;
+
+use crate::cli::args::{OutputFormat, SummaryArgs};
+use crate::cli::budget::load_budgets;
+use crate::cli::prompt::{PromptCache, ProviderPromptData};
+use crate::core::budgets::{BudgetFileConfig, CurrentUsage, budget_status, resolve_budget};
+use crate::core::provider::Provider;
+use crate::error::Result;
+use crate::storage::AppPaths;
+use crate::storage::cache::{get_age_secs, read_with_staleness};
+
+/// Hard cap on the output size, in bytes.
+pub const MAX_SUMMARY_BYTES: usize = 300;
+
+/// Remaining capacity of one provider.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProviderLine {
+    id: String,
+    /// Percent left in the session window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    left: Option<u8>,
+    /// Percent left in the weekly window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    week_left: Option<u8>,
+    /// Minutes until the session window resets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reset_min: Option<i64>,
+}
+
+impl ProviderLine {
+    fn tightest(&self) -> u8 {
+        self.left
+            .into_iter()
+            .chain(self.week_left)
+            .min()
+            .unwrap_or(100)
+    }
+}
+
+/// Today's spend against a daily cost limit.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BudgetLine {
+    id: String,
+    /// Percent of the daily limit spent.
+    used: u32,
+    limit_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Summary {
+    providers: Vec<ProviderLine>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    budgets: Vec<BudgetLine>,
+    /// Providers left out to stay under the size cap.
+    #[serde(skip_serializing_if = "is_zero")]
+    more: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    age_min: Option<u64>,
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // serde's skip_serializing_if passes a reference
+const fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+/// Execute the summary command.
+///
+/// # Errors
+/// Returns an error if the summary cannot be serialized.
+pub fn execute(args: &SummaryArgs, format: OutputFormat) -> Result<()> {
+    let cache_path = AppPaths::new().prompt_cache_file();
+    let Some((cache, _)) = read_with_staleness::<PromptCache>(&cache_path).unwrap_or(None) else {
+        if format == OutputFormat::Json {
+            println!(r#"{{"providers":[]}}"#);
+        } else {
+            println!("caut: no cached usage; run `caut usage`.");
+        }
+        return Ok(());
+    };
+
+    let providers: Vec<ProviderPromptData> = cache
+        .providers
+        .into_iter()
+        .filter(|p| {
+            args.provider
+                .as_deref()
+                .is_none_or(|name| p.provider == name)
+        })
+        .collect();
+    let age_min = get_age_secs(&cache_path).map(|s| s / 60);
+    let summary = build(&providers, load_budgets().as_ref(), age_min);
+    println!("{}", fit(summary, format)?);
+    Ok(())
+}
+
+/// Render `summary`, dropping the least constrained providers until it fits
+/// in [`MAX_SUMMARY_BYTES`].
+fn fit(mut summary: Summary, format: OutputFormat) -> Result<String> {
+    loop {
+        let text = match format {
+            OutputFormat::Json => serde_json::to_string(&summary)?,
+            OutputFormat::Human | OutputFormat::Md => render_line(&summary),
+        };
+        if text.len() <= MAX_SUMMARY_BYTES {
+            return Ok(text);
+        }
+        let Some(dropped) = summary.providers.pop() else {
+            return Ok(text);
+        };
+        summary.budgets.retain(|b| b.id != dropped.id);
+        summary.more += 1;
+    }
+}
+
+fn build(
+    providers: &[ProviderPromptData],
+    budgets: Option<&BudgetFileConfig>,
+    age_min: Option<u64>,
+) -> Summary {
+    let now = Utc::now();
+    let mut lines: Vec<ProviderLine> = providers
+        .iter()
+        .map(|p| ProviderLine {
+            id: p.provider.clone(),
+            left: p.primary_pct.map(percent_left),
+            week_left: p.secondary_pct.map(percent_left),
+            reset_min: p
+                .primary_resets_at
+                .map(|at| (at - now).num_minutes().max(0)),
+        })
+        .collect();
+    lines.sort_by_key(ProviderLine::tightest);
+
+    let budgets = budgets.map_or_else(Vec::new, |file| {
+        let configs = file.to_configs();
+        providers
+            .iter()
+            .filter_map(|p| {
+                let provider = Provider::from_cli_name(&p.provider).ok()?;
+                let usage = CurrentUsage {
+                    daily_cost_usd: p.cost_today_usd,
+                    ..CurrentUsage::default()
+                };
+                // The cache holds today's spend only, so only daily limits
+                // can be checked.
+                let status = budget_status(&resolve_budget(provider, &configs), Some(&usage))
+                    .into_iter()
+                    .find(|s| s.percent_used.is_some())?;
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                // rounded, non-negative percentage
+                let used = status.percent_used?.round().max(0.0) as u32;
+                Some(BudgetLine {
+                    id: p.provider.clone(),
+                    used,
+                    limit_usd: status.limit_usd,
+                })
+            })
+            .collect()
+    });
+
+    Summary {
+        providers: lines,
+        budgets,
+        more: 0,
+        age_min,
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // clamped to 0..=100
+fn percent_left(used: f64) -> u8 {
+    (100.0 - used).clamp(0.0, 100.0).round() as u8
+}
+
+/// One line, e.g. `caut: codex 12% left (reset 45m), week 60%; claude 80%
+/// left. Budget: codex 92% of $10/day. 3m old.`
+fn render_line(summary: &Summary) -> String {
+    let mut out = String::from("caut: ");
+    if summary.providers.is_empty() {
+        out.push_str("no providers cached.");
+        return out;
+    }
+    let parts: Vec<String> = summary
+        .providers
+        .iter()
+        .map(|p| {
+            let left = p.left.map_or_else(
+                || " no session data".to_string(),
+                |left| format!(" {left}% left"),
+            );
+            let reset = p.reset_min.map_or_else(String::new, |min| {
+                format!(" (reset {})", short_minutes(min))
+            });
+            let week = p
+                .week_left
+                .map_or_else(String::new, |week| format!(", week {week}%"));
+            format!("{}{left}{reset}{week}", p.id)
+        })
+        .collect();
+    out.push_str(&parts.join("; "));
+    if summary.more > 0 {
+        write!(out, "; +{} more", summary.more).ok();
+    }
+    out.push('.');
+    if !summary.budgets.is_empty() {
+        let budgets: Vec<String> = summary
+            .budgets
+            .iter()
+            .map(|b| format!("{} {}% of ${}/day", b.id, b.used, b.limit_usd))
+            .collect();
+        write!(out, " Budget: {}.", budgets.join(", ")).ok();
+    }
+    if let Some(age) = summary.age_min {
+        write!(out, " {age}m old.").ok();
+    }
+    out
+}
+
+/// Minutes as `45m`, `3h`, or `2d`.
+fn short_minutes(minutes: i64) -> String {
+    match minutes {
+        m if m < 60 => format!("{m}m"),
+        m if m < 48 * 60 => format!("{}h", m / 60),
+        m => format!("{}d", m / (24 * 60)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provider(name: &str, primary: f64, secondary: f64) -> ProviderPromptData {
+        ProviderPromptData {
+            provider: name.to_string(),
+            primary_pct: Some(primary),
+            secondary_pct: Some(secondary),
+            primary_resets_at: None,
+            secondary_resets_at: None,
+            credits_remaining: None,
+            cost_today_usd: None,
+        }
+    }
+
+    #[test]
+    fn tightest_provider_comes_first_and_output_stays_small() {
+        let providers: Vec<_> = (0..12)
+            .map(|i| provider(&format!("provider{i}"), f64::from(i) * 8.0, 10.0))
+            .collect();
+        let summary = build(&providers, None, Some(3));
+
+        assert_eq!(summary.providers[0].id, "provider11");
+        assert_eq!(summary.providers[0].left, Some(12));
+        let line = render_line(&summary);
+        assert!(line.starts_with("caut: provider11 12% left, week 90%; "));
+        assert!(line.ends_with(" 3m old."));
+
+        for format in [OutputFormat::Human, OutputFormat::Json] {
+            let text = fit(summary.clone(), format).unwrap();
+            assert!(text.len() <= MAX_SUMMARY_BYTES, "{text}");
+            assert!(text.contains("provider11"));
+            assert!(text.contains("more"));
+        }
+    }
+}
@@ -0,0 +1,387 @@
+//! Top command implementation.
+//!
+//! `caut top` follows the Claude and Codex session logs written to within
+//! the last few minutes and shows, per running session, the current model,
+//! tokens per minute, and cost so far. Each refresh reads only what was
+//! appended to a log since the previous one, so long sessions stay cheap to
+//! follow.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write as _};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Local, Utc};
+use serde::Serialize;
+use tokio::time::sleep;
+
+use crate::cli::args::{OutputFormat, TopArgs};
+use crate::cli::sessions::turn_cost;
+use crate::core::models::RobotOutput;
+use crate::core::pricing::SessionCostCalculator;
+use crate::core::provider::Provider;
+use crate::core::session_logs::{
+    SessionLogFinder, SessionLogPath, SessionTurn, parse_session_turns,
+};
+use crate::error::{CautError, Result};
+use crate::render::robot;
+use crate::util::format::{format_cost, format_tokens, pad_to_width, truncate_middle};
+
+/// Tokens per minute are averaged over this trailing window.
+const RATE_WINDOW_MINUTES: i64 = 5;
+
+/// Width of the model column.
+const MODEL_WIDTH: usize = 24;
+
+/// Width of the project column.
+const PROJECT_WIDTH: usize = 32;
+
+/// Clears the screen and homes the cursor before each refresh.
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// One running session, as shown in a refresh.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SessionRow {
+    provider: String,
+    session_id: String,
+    project: Option<String>,
+    model: Option<String>,
+    /// Input and output tokens per minute over the last few minutes.
+    tokens_per_minute: f64,
+    /// Input and output tokens since the session started.
+    total_tokens: i64,
+    cost_usd: f64,
+    turns: usize,
+    last_turn_at: Option<DateTime<Utc>>,
+}
+
+/// A session log being followed.
+struct TrackedSession {
+    provider: Provider,
+    session_id: String,
+    project: Option<String>,
+    /// Bytes of the log already parsed.
+    offset: u64,
+    /// Model of the latest turn, carried into turns that do not name one.
+    model: Option<String>,
+    turns: Vec<SessionTurn>,
+    cost_usd: f64,
+}
+
+impl TrackedSession {
+    fn new(log: &SessionLogPath) -> Self {
+        Self {
+            provider: log.provider,
+            session_id: log.session_id.clone(),
+            project: log.project_path.as_ref().map(|p| p.display().to_string()),
+            offset: 0,
+            model: None,
+            turns: Vec::new(),
+            cost_usd: 0.0,
+        }
+    }
+
+    /// Parse the complete lines appended to `path` since the last read.
+    fn read_appended(&mut self, path: &Path, calc: &SessionCostCalculator) -> Result<()> {
+        let mut file = File::open(path)?;
+        if file.metadata()?.len() < self.offset {
+            // Truncated or replaced; start over.
+            self.offset = 0;
+            self.model = None;
+            self.turns.clear();
+            self.cost_usd = 0.0;
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut appended = Vec::new();
+        file.read_to_end(&mut appended)?;
+        // A line still being written is picked up on the next refresh.
+        let Some(end) = appended.iter().rposition(|b| *b == b'\n') else {
+            return Ok(());
+        };
+        self.offset += end as u64 + 1;
+
+        for mut turn in parse_session_turns(&appended[..=end]) {
+            if turn.model.is_some() {
+                self.model.clone_from(&turn.model);
+            } else {
+                turn.model.clone_from(&self.model);
+            }
+            self.cost_usd += turn_cost(calc, &turn);
+            self.turns.push(turn);
+        }
+        Ok(())
+    }
+
+    fn row(&self, now: DateTime<Utc>) -> SessionRow {
+        let since = now - Duration::minutes(RATE_WINDOW_MINUTES);
+        let recent: i64 = self
+            .turns
+            .iter()
+            .filter(|t| t.timestamp.is_some_and(|at| at >= since))
+            .map(|t| t.input_tokens + t.output_tokens)
+            .sum();
+        #[allow(clippy::cast_precision_loss)] // token counts are far below 2^52
+        let tokens_per_minute = recent as f64 / RATE_WINDOW_MINUTES as f64;
+        SessionRow {
+            provider: self.provider.cli_name().to_string(),
+            session_id: self.session_id.clone(),
+            project: self.project.clone(),
+            model: self.model.clone(),
+            tokens_per_minute,
+            total_tokens: self
+                .turns
+                .iter()
+                .map(|t| t.input_tokens + t.output_tokens)
+                .sum(),
+            cost_usd: self.cost_usd,
+            turns: self.turns.len(),
+            last_turn_at: self.turns.iter().filter_map(|t| t.timestamp).max(),
+        }
+    }
+}
+
+/// Follows every recently written session log.
+struct SessionTail {
+    finder: SessionLogFinder,
+    providers: Vec<Provider>,
+    calc: SessionCostCalculator,
+    sessions: HashMap<PathBuf, TrackedSession>,
+}
+
+impl SessionTail {
+    /// Pick up new and appended logs, drop logs that went quiet, and return
+    /// the busiest sessions first.
+    fn refresh(&mut self, active: Duration, now: DateTime<Utc>) -> Vec<SessionRow> {
+        let logs: Vec<SessionLogPath> = self
+            .providers
+            .iter()
+            .flat_map(|p| self.finder.find_sessions(*p, Some(now - active), None))
+            .collect();
+        self.sessions
+            .retain(|path, _| logs.iter().any(|log| log.path == *path));
+        for log in &logs {
+            let session = self
+                .sessions
+                .entry(log.path.clone())
+                .or_insert_with(|| TrackedSession::new(log));
+            // A log that cannot be read right now is retried next refresh.
+            if let Err(e) = session.read_appended(&log.path, &self.calc) {
+                tracing::debug!("Skipping {}: {e}", log.path.display());
+            }
+        }
+
+        let mut rows: Vec<SessionRow> = self.sessions.values().map(|s| s.row(now)).collect();
+        rows.sort_by(|a, b| {
+            b.tokens_per_minute
+                .total_cmp(&a.tokens_per_minute)
+                .then(b.last_turn_at.cmp(&a.last_turn_at))
+        });
+        rows
+    }
+}
+
+/// Execute the top command.
+///
+/// Refreshes until interrupted, or once with `--once`. JSON output prints
+/// one line per refresh.
+///
+/// # Errors
+/// Returns an error if the provider is unknown or has no session logs, the
+/// home directory cannot be determined, or writing output fails.
+pub async fn execute(args: &TopArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let providers = match args.provider.as_deref() {
+        Some(name) => {
+            let provider = Provider::from_cli_name(name)?;
+            if !matches!(provider, Provider::Claude | Provider::Codex) {
+                return Err(CautError::Config(format!(
+                    "caut top follows Claude and Codex session logs, not {}",
+                    provider.display_name()
+                )));
+            }
+            vec![provider]
+        }
+        None => vec![Provider::Claude, Provider::Codex],
+    };
+    let mut tail = SessionTail {
+        finder: SessionLogFinder::new()?,
+        providers,
+        calc: SessionCostCalculator::new(),
+        sessions: HashMap::new(),
+    };
+    #[allow(clippy::cast_possible_wrap)] // durations are far below i64::MAX seconds
+    let active = Duration::seconds(args.active as i64);
+    let interval = std::time::Duration::from_secs(args.interval.max(1));
+
+    // Ctrl+C handler for clean shutdown.
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+    tokio::spawn(async move {
+        let _ = tokio::signal::ctrl_c().await;
+        let _ = shutdown_tx.send(());
+    });
+
+    loop {
+        let now = Utc::now();
+        let rows = tail.refresh(active, now);
+        let frame = match format {
+            OutputFormat::Json => {
+                let output = RobotOutput::new("top", &rows);
+                if pretty && args.once {
+                    robot::render_json_pretty(&output)?
+                } else {
+                    robot::render_json(&output)?
+                }
+            }
+            OutputFormat::Human => {
+                let clear = if args.once { "" } else { CLEAR_SCREEN };
+                format!("{clear}{}", render(&rows, now, args.once, false))
+            }
+            OutputFormat::Md => render(&rows, now, true, true),
+        };
+        let mut stdout = std::io::stdout();
+        writeln!(stdout, "{frame}")?;
+        stdout.flush()?;
+
+        if args.once || format == OutputFormat::Md {
+            break;
+        }
+        tokio::select! {
+            () = sleep(interval) => {}
+            _ = &mut shutdown_rx => break,
+        }
+    }
+    Ok(())
+}
+
+fn render(rows: &[SessionRow], now: DateTime<Utc>, once: bool, md: bool) -> String {
+    let mut out = String::new();
+    if md {
+        writeln!(out, "# Running Sessions\n").ok();
+    } else {
+        let hint = if once { "" } else { "  (Ctrl+C to quit)" };
+        writeln!(
+            out,
+            "caut top - {} running, {}{hint}\n",
+            rows.len(),
+            now.with_timezone(&Local).format("%H:%M:%S")
+        )
+        .ok();
+    }
+    if rows.is_empty() {
+        write!(out, "No Claude or Codex session logs changed recently.").ok();
+        return out;
+    }
+
+    if md {
+        writeln!(
+            out,
+            "| Provider | Session | Model | Tokens/min | Tokens | Cost | Idle | Project |"
+        )
+        .ok();
+        write!(
+            out,
+            "|----------|---------|-------|------------|--------|------|------|---------|"
+        )
+        .ok();
+    } else {
+        write!(
+            out,
+            "{:<8}  {:<8}  {}  {:>8}  {:>8}  {:>8}  {:>5}  PROJECT",
+            "PROVIDER",
+            "SESSION",
+            pad_to_width("MODEL", MODEL_WIDTH),
+            "TOK/MIN",
+            "TOKENS",
+            "COST",
+            "IDLE"
+        )
+        .ok();
+    }
+    for row in rows {
+        let session: String = row.session_id.chars().take(8).collect();
+        let model = row.model.as_deref().unwrap_or("-");
+        #[allow(clippy::cast_possible_truncation)] // rounded token rate
+        let rate = format_tokens(row.tokens_per_minute.round() as i64);
+        let tokens = format_tokens(row.total_tokens);
+        let cost = format_cost(row.cost_usd);
+        let idle = row
+            .last_turn_at
+            .map_or_else(|| "-".to_string(), |at| format_idle(now - at));
+        let project = row.project.as_deref().unwrap_or("-");
+        if md {
+            write!(
+                out,
+                "\n| {} | `{session}` | {model} | {rate} | {tokens} | {cost} | {idle} | {project} |",
+                row.provider
+            )
+            .ok();
+        } else {
+            write!(
+                out,
+                "\n{:<8}  {session:<8}  {}  {rate:>8}  {tokens:>8}  {cost:>8}  {idle:>5}  {}",
+                row.provider,
+                pad_to_width(&truncate_middle(model, MODEL_WIDTH), MODEL_WIDTH),
+                truncate_middle(project, PROJECT_WIDTH)
+            )
+            .ok();
+        }
+    }
+    out
+}
+
+/// Time since the last turn as "12s", "4m", or "1h".
+fn format_idle(idle: Duration) -> String {
+    let secs = idle.num_seconds().max(0);
+    match secs {
+        s if s < 60 => format!("{s}s"),
+        s if s < 3600 => format!("{}m", s / 60),
+        s => format!("{}h", s / 3600),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_only_complete_appended_lines() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("session.jsonl");
+        let now = Utc::now();
+        let line = |tokens: i64| {
+            format!(
+                r#"{{"timestamp":"{}","message":{{"model":"claude-sonnet-4","usage":{{"input_tokens":{tokens},"output_tokens":0}}}}}}"#,
+                now.to_rfc3339()
+            )
+        };
+        std::fs::write(&path, format!("{}\n", line(500))).expect("write");
+
+        let calc = SessionCostCalculator::new();
+        let mut session = TrackedSession {
+            provider: Provider::Claude,
+            session_id: "s1".to_string(),
+            project: None,
+            offset: 0,
+            model: None,
+            turns: Vec::new(),
+            cost_usd: 0.0,
+        };
+        session.read_appended(&path, &calc).expect("first read");
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .expect("open");
+        write!(file, "{}\n{}", line(1000), &line(9)[..20]).expect("append");
+        session.read_appended(&path, &calc).expect("second read");
+
+        let row = session.row(now);
+        assert_eq!(row.turns, 2);
+        assert_eq!(row.total_tokens, 1500);
+        assert!((row.tokens_per_minute - 300.0).abs() < f64::EPSILON);
+        assert_eq!(row.model.as_deref(), Some("claude-sonnet-4"));
+        assert!(row.cost_usd > 0.0);
+        assert_eq!(format_idle(Duration::seconds(125)), "2m");
+    }
+}
@@ -0,0 +1,292 @@
+//! Troubleshoot command implementation.
+//!
+//! `caut troubleshoot <provider>` walks through why a provider fails to
+//! fetch, one question at a time: is its CLI installed, are there
+//! credentials, is the token still valid, is the network up, and does a real
+//! fetch work. A failing step says what is wrong and, where caut knows the
+//! fix (installing the CLI, signing in again), offers to run it.
+//!
+//! The last step tells a rejected token apart from a slow provider. Both
+//! surface from `caut usage` as a failed fetch, and a timeout is easily
+//! mistaken for bad credentials.
+
+use std::io::{BufRead, Write};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::cli::args::TroubleshootArgs;
+use crate::core::doctor::CheckStatus;
+use crate::core::doctor::checks;
+use crate::core::fetch_plan::SourceMode;
+use crate::core::http::{STATUS_TIMEOUT, build_client};
+use crate::core::pipeline;
+use crate::core::provider::Provider;
+use crate::error::{CautError, ErrorCategory, Result};
+
+/// How much longer than the default the slow-provider retry waits.
+const RETRY_TIMEOUT_FACTOR: u32 = 3;
+
+/// Execute the troubleshoot command.
+///
+/// # Errors
+/// Returns an error if the provider is unknown or the terminal cannot be
+/// read from or written to.
+pub async fn execute(args: &TroubleshootArgs) -> Result<()> {
+    let provider = Provider::from_cli_name(&args.provider)?;
+    let wizard = Wizard { yes: args.yes };
+    println!("Troubleshooting {}\n", provider.display_name());
+
+    let mut problems = 0;
+
+    step(
+        1,
+        &format!("Is the {} CLI installed?", provider.executable()),
+    );
+    let (check, _) = checks::check_cli_installed(provider).await;
+    if !report(&check.status) {
+        problems += 1;
+        wizard.offer_fix(provider.install_suggestion())?;
+    }
+
+    step(2, "Are there credentials to read?");
+    let check = checks::check_authenticated(provider).await;
+    let signed_in = report(&check.status);
+    if !signed_in {
+        problems += 1;
+        wizard.offer_fix(provider.auth_suggestion())?;
+    }
+
+    step(3, "Is the token still valid?");
+    match checks::check_credential_health(provider) {
+        Some(check) => {
+            if !report(&check.status) {
+                problems += 1;
+                wizard.offer_fix(provider.auth_suggestion())?;
+            }
+        }
+        None if signed_in => println!("  - No token expiry to check"),
+        None => println!("  - Skipped: no credentials found"),
+    }
+
+    step(4, "Can caut reach the network?");
+    if !check_network(provider).await {
+        problems += 1;
+    }
+
+    step(
+        5,
+        &format!("Does a {} fetch succeed?", provider.display_name()),
+    );
+    if !check_fetch(provider, &wizard).await? {
+        problems += 1;
+    }
+
+    println!();
+    match problems {
+        0 => println!(
+            "Everything checks out. If `caut usage -p {}` still fails, run it with --verbose.",
+            provider.cli_name()
+        ),
+        n => println!(
+            "{n} step(s) failed. Run `caut troubleshoot {}` again after fixing them.",
+            provider.cli_name()
+        ),
+    }
+    Ok(())
+}
+
+fn step(number: usize, question: &str) {
+    println!("[{number}/5] {question}");
+}
+
+/// Print a check result; true when it passed or only warned.
+fn report(status: &CheckStatus) -> bool {
+    match status {
+        CheckStatus::Pass { details } => {
+            println!("  \u{2713} {}", details.as_deref().unwrap_or("OK"));
+            true
+        }
+        CheckStatus::Warning {
+            details,
+            suggestion,
+        } => {
+            println!("  \u{26A0} {details}");
+            if let Some(suggestion) = suggestion {
+                println!("    {suggestion}");
+            }
+            true
+        }
+        CheckStatus::Fail { reason, .. } => {
+            println!("  \u{2717} {reason}");
+            false
+        }
+        CheckStatus::Timeout { after } => {
+            println!("  \u{2717} Timed out after {}s", after.as_secs());
+            false
+        }
+        CheckStatus::Skipped { reason } => {
+            println!("  - Skipped: {reason}");
+            true
+        }
+    }
+}
+
+/// Probe the provider's status page, or a primary provider's when it has
+/// none. A proxy in the environment is named, since it is the usual cause.
+async fn check_network(provider: Provider) -> bool {
+    let Some(url) = provider
+        .status_page_url()
+        .or_else(|| Provider::Claude.status_page_url())
+    else {
+        println!("  - Skipped: no status page to probe");
+        return true;
+    };
+    let response = match build_client(STATUS_TIMEOUT) {
+        Ok(client) => client.head(url).send().await.map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    match response {
+        Ok(response) => {
+            println!("  \u{2713} {url} answered with {}", response.status());
+            true
+        }
+        Err(e) => {
+            println!("  \u{2717} Could not reach {url}: {e}");
+            for name in ["HTTPS_PROXY", "HTTP_PROXY", "ALL_PROXY"] {
+                if let Ok(value) = std::env::var(name) {
+                    println!("    {name} is set to {value}; check that the proxy is up.");
+                }
+            }
+            false
+        }
+    }
+}
+
+/// Run a real fetch. On a timeout, retry with a longer one to tell a slow
+/// provider from a stuck one; on an auth error, offer to sign in again.
+async fn check_fetch(provider: Provider, wizard: &Wizard) -> Result<bool> {
+    let timeout = provider.default_timeout();
+    let error = match fetch(provider, timeout).await {
+        Ok(source) => {
+            println!("  \u{2713} Fetched usage via {source}");
+            return Ok(true);
+        }
+        Err(e) => e,
+    };
+
+    if is_timeout(&error) {
+        println!(
+            "  \u{2717} No answer within {}s. This is a timeout, not a credentials problem.",
+            timeout.as_secs()
+        );
+        let longer = timeout * RETRY_TIMEOUT_FACTOR;
+        println!("    Retrying with {}s...", longer.as_secs());
+        match fetch(provider, longer).await {
+            Ok(source) => println!(
+                "    \u{2713} Fetched via {source} with more time. Use `caut usage -p {} --timeout {}`.",
+                provider.cli_name(),
+                longer.as_secs()
+            ),
+            Err(e) if is_timeout(&e) => {
+                println!("    \u{2717} Still no answer; the provider or a proxy is hanging.");
+            }
+            Err(e) => println!("    \u{2717} {e}"),
+        }
+        return Ok(false);
+    }
+
+    println!("  \u{2717} {error}");
+    if error.category() == ErrorCategory::Authentication {
+        println!("    The provider rejected the credentials; signing in again usually fixes this.");
+        wizard.offer_fix(provider.auth_suggestion())?;
+    }
+    Ok(false)
+}
+
+/// Fetch with `timeout`, returning the source used.
+async fn fetch(provider: Provider, timeout: Duration) -> Result<String> {
+    let outcome = pipeline::fetch_provider_with_timeout(provider, SourceMode::Auto, timeout).await;
+    outcome.result.map(|_| outcome.source_label)
+}
+
+const fn is_timeout(error: &CautError) -> bool {
+    matches!(
+        error,
+        CautError::Timeout(_) | CautError::TimeoutWithProvider { .. }
+    )
+}
+
+/// Asks before running a fix, unless `--yes` was given.
+struct Wizard {
+    yes: bool,
+}
+
+impl Wizard {
+    /// Offer to run the command in `suggestion`. Suggestions without one
+    /// ("Open Cursor and sign in") are printed for the user to follow.
+    fn offer_fix(&self, suggestion: &str) -> Result<()> {
+        let Some(command) = runnable_fix(suggestion) else {
+            println!("    {suggestion}");
+            return Ok(());
+        };
+        if !self.yes && !confirm(&format!("    Run `{command}` now?"))? {
+            println!("    Skipped. {suggestion}");
+            return Ok(());
+        }
+        let status = if cfg!(windows) {
+            Command::new("cmd").args(["/C", command]).status()
+        } else {
+            Command::new("sh").args(["-c", command]).status()
+        };
+        match status {
+            Ok(status) if status.success() => println!("    \u{2713} `{command}` finished"),
+            Ok(status) => println!("    \u{2717} `{command}` exited with {status}"),
+            Err(e) => println!("    \u{2717} Could not run `{command}`: {e}"),
+        }
+        Ok(())
+    }
+}
+
+/// Ask a yes/no question on the terminal; anything but "y" is no.
+fn confirm(question: &str) -> Result<bool> {
+    print!("{question} [y/N] ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+/// The command in an install or auth suggestion, e.g. "claude auth login"
+/// from "Run: claude auth login (or caut login claude)". Suggestions with
+/// more than one step are left to the user.
+fn runnable_fix(suggestion: &str) -> Option<&str> {
+    let command = suggestion
+        .strip_prefix("Install with: ")
+        .or_else(|| suggestion.strip_prefix("Run: "))?;
+    let command = command.split(" (or ").next().unwrap_or(command).trim();
+    (!command.is_empty() && !command.contains(',')).then_some(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixes_are_taken_from_suggestions() {
+        assert_eq!(
+            runnable_fix(Provider::Claude.auth_suggestion()),
+            Some("claude auth login")
+        );
+        assert_eq!(
+            runnable_fix(Provider::Codex.install_suggestion()),
+            Some("npm install -g @openai/codex")
+        );
+        assert_eq!(runnable_fix(Provider::Cursor.auth_suggestion()), None);
+        assert_eq!(runnable_fix(Provider::Groq.auth_suggestion()), None);
+        assert_eq!(
+            runnable_fix(Provider::Qwen.auth_suggestion()),
+            None,
+            "multi-step suggestions are left to the user"
+        );
+    }
+}
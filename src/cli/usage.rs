@@ -1,22 +1,60 @@
 //! Usage command implementation.
 
-use crate::cli::args::{OutputFormat, UsageArgs};
+use crate::cli::alert::run_alerts;
+use crate::cli::args::{OutputFormat, UsageArgs, UsageColumn, UsageSort};
+use crate::cli::budget;
 use crate::cli::prompt::{ProviderPromptData, update_cache as update_prompt_cache};
 use crate::cli::watch;
-use crate::core::credential_health::AuthHealthAggregator;
-use crate::core::models::{ProviderPayload, RobotOutput};
-use crate::core::pipeline::fetch_providers_with_timeout;
-use crate::core::provider::ProviderSelection;
-use crate::core::status::StatusFetcher;
+use crate::core::budgets::BudgetStatus;
+use crate::core::clock_skew::{self, ClockSkew};
+use crate::core::cost_scanner::CostScanner;
+use crate::core::explain::explain_payload;
+use crate::core::models::{CostPayload, ProviderPayload, RobotOutput};
+use crate::core::pipeline::{ProviderResult, UsageRequest, run_usage};
+use crate::core::provider::{Provider, ProviderSelection};
+use crate::core::summary::UsageSummary;
+use crate::core::usage_filter;
 use crate::error::{CautError, Result};
-use crate::render::{human, robot};
+use crate::providers::api_key;
+use crate::render::table::{self, TableStyle};
+use crate::render::{UsageRenderOptions, human, robot};
+use crate::rich::TerminalFeatures;
+use crate::storage::config::Config;
 use crate::storage::{AppPaths, HistoryStore, RetentionPolicy};
+use chrono::Utc;
+use clap::ValueEnum;
 use tokio::time::Duration;
 
+/// `notice` in `usage --json` output when no provider is set up.
+pub const NO_PROVIDERS_NOTICE: &str = "no_providers_configured";
+
 #[derive(Debug, Clone)]
 pub(crate) struct UsageResults {
     pub payloads: Vec<ProviderPayload>,
     pub errors: Vec<String>,
+    /// Local cost scans feeding the summary's spend totals.
+    pub costs: Vec<CostPayload>,
+    /// Spend against configured budgets (see `caut budget`).
+    pub budgets: Vec<BudgetStatus>,
+}
+
+impl From<Vec<ProviderResult>> for UsageResults {
+    fn from(results: Vec<ProviderResult>) -> Self {
+        let mut payloads = Vec::new();
+        let mut errors = Vec::new();
+        for result in results {
+            match result.outcome {
+                Ok(payload) => payloads.push(payload),
+                Err(e) => errors.push(format!("{}: {}", result.provider.cli_name(), e)),
+            }
+        }
+        Self {
+            payloads,
+            errors,
+            costs: Vec::new(),
+            budgets: Vec::new(),
+        }
+    }
 }
 
 /// Execute the usage command.
@@ -52,7 +90,11 @@ pub async fn execute(
         return crate::tui::run_dashboard(args, interval).await;
     }
 
-    if args.watch {
+    if let Some(path) = &args.replay {
+        return watch::replay(path).await;
+    }
+
+    if args.watch || args.record.is_some() {
         let interval = Duration::from_secs(args.interval);
         if interval.is_zero() {
             return Err(CautError::Config(
@@ -62,8 +104,20 @@ pub async fn execute(
         return watch::run_watch(args, format, pretty, no_color, interval).await;
     }
 
-    let results = fetch_usage(args).await?;
-    render_usage_results(&results, format, pretty, no_color)?;
+    let results = match fetch_usage(args).await {
+        Err(e @ CautError::NoProvidersConfigured { .. })
+            if format == OutputFormat::Json && !args.strict =>
+        {
+            return print_no_providers_json(&e, pretty);
+        }
+        results => results?,
+    };
+    render_usage_results(
+        &results,
+        format,
+        pretty,
+        &render_options(args, format, no_color)?,
+    )?;
 
     if !results.errors.is_empty() {
         return Err(CautError::PartialFailure {
@@ -83,85 +137,56 @@ pub(crate) async fn fetch_usage(args: &UsageArgs) -> Result<UsageResults> {
         .transpose()?
         .unwrap_or_default();
 
-    let providers = selection.providers();
-    let source_mode = args.effective_source();
-
-    tracing::debug!(?providers, ?source_mode, "Starting usage fetch");
-
-    // Fetch usage data from providers
-    let timeout_override = args.effective_timeout_override().map(Duration::from_secs);
-    let outcomes = fetch_providers_with_timeout(&providers, source_mode, timeout_override).await;
-
-    // Optionally fetch status
-    let status_fetcher = if args.status {
-        Some(StatusFetcher::new())
-    } else {
-        None
+    let request = UsageRequest {
+        providers: selection.providers(),
+        mode: args.effective_source(),
+        timeout_override: args.effective_timeout_override().map(Duration::from_secs),
+        include_status: args.status,
+        record_history: true,
+        verify: args.verify,
     };
+    api_key::set_flag_keys(args.flag_api_keys()?);
+    clock_skew::set_correction(
+        Config::load().is_ok_and(|config| config.general.correct_clock_skew),
+    );
 
-    // Build payloads
-    let mut payloads = Vec::new();
-    let mut errors = Vec::new();
+    tracing::debug!(providers = ?request.providers, mode = ?request.mode, "Starting usage fetch");
 
-    let paths = AppPaths::new();
-    let auth_checker = AuthHealthAggregator::new();
-
-    for outcome in outcomes {
-        match outcome.result {
-            Ok(snapshot) => {
-                // Record to history
-                if let Ok(store) = HistoryStore::open(&paths.history_db_file())
-                    && let Err(e) = store.record_snapshot(&snapshot, &outcome.provider)
-                {
-                    tracing::warn!("Failed to record snapshot: {}", e);
-                }
+    let provider_results = run_usage(&request).await;
+    // A first run with nothing set up gets a guide rather than one error per
+    // provider. Naming a single provider still reports its own error.
+    if !selection.is_single() && nothing_configured(&provider_results) {
+        return Err(CautError::NoProvidersConfigured {
+            checked: provider_results.len(),
+        });
+    }
 
-                // Get status if requested
-                let status = if let Some(ref fetcher) = status_fetcher {
-                    if let Some(status_url) = outcome.provider.status_page_url() {
-                        fetcher.fetch(status_url).await.ok()
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
-
-                // Check auth health for this provider
-                let auth_health = auth_checker.check_provider(outcome.provider);
-                let auth_warning = auth_health.warning_message();
-
-                let payload = ProviderPayload {
-                    provider: outcome.provider.cli_name().to_string(),
-                    account: snapshot
-                        .identity
-                        .as_ref()
-                        .and_then(|i| i.account_email.clone()),
-                    version: None, // TODO: Get from CLI version
-                    source: outcome.source_label,
-                    status,
-                    usage: snapshot,
-                    credits: None, // TODO: Fetch credits
-                    antigravity_plan_info: None,
-                    openai_dashboard: None,
-                    auth_warning,
-                };
-                payloads.push(payload);
-            }
-            Err(e) => {
-                errors.push(format!("{}: {}", outcome.provider.cli_name(), e));
-            }
-        }
+    let mut results = UsageResults::from(provider_results);
+    let budgets = budget::load_budgets();
+    if results.payloads.len() + results.errors.len() > 1 || args.uses_cost() || budgets.is_some() {
+        results.costs = scan_costs(&results.payloads).await;
+    }
+    if let Some(file) = &budgets {
+        let providers: Vec<Provider> = results
+            .payloads
+            .iter()
+            .filter_map(|p| Provider::from_cli_name(&p.provider).ok())
+            .collect();
+        results.budgets = budget::statuses_for(file, &providers, &results.costs);
     }
+    run_alerts(&results.payloads).await;
 
     // Update prompt cache with successful results
-    if !payloads.is_empty() {
-        let prompt_data: Vec<ProviderPromptData> = payloads
+    if !results.payloads.is_empty() {
+        let prompt_data: Vec<ProviderPromptData> = results
+            .payloads
             .iter()
             .map(|p| ProviderPromptData {
                 provider: p.provider.clone(),
                 primary_pct: p.usage.primary.as_ref().map(|w| w.used_percent),
                 secondary_pct: p.usage.secondary.as_ref().map(|w| w.used_percent),
+                primary_resets_at: p.usage.primary.as_ref().and_then(|w| w.resets_at),
+                secondary_resets_at: p.usage.secondary.as_ref().and_then(|w| w.resets_at),
                 credits_remaining: p.credits.as_ref().map(|c| c.remaining),
                 cost_today_usd: None, // TODO: Extract cost from payload if available
             })
@@ -172,37 +197,227 @@ pub(crate) async fn fetch_usage(args: &UsageArgs) -> Result<UsageResults> {
         }
     }
 
-    Ok(UsageResults { payloads, errors })
+    usage_filter::apply(
+        &mut results.payloads,
+        args.sort.map(UsageSort::field),
+        &args.filter,
+        &results.costs,
+        Utc::now(),
+    );
+
+    Ok(results)
+}
+
+/// Whether no provider produced data because none is set up. Providers caut
+/// cannot fetch in the requested mode at all are ignored.
+pub(crate) fn nothing_configured(results: &[ProviderResult]) -> bool {
+    results.iter().any(ProviderResult::is_not_configured)
+        && results.iter().all(|result| {
+            result.is_not_configured()
+                || matches!(result.error(), Some(CautError::UnsupportedSource { .. }))
+        })
+}
+
+/// The `--json` report for a machine with no provider set up: empty data,
+/// a `no_providers_configured` notice, and the first-run guide as warnings.
+fn print_no_providers_json(error: &CautError, pretty: bool) -> Result<()> {
+    let output = RobotOutput::usage(Vec::new(), Vec::new())
+        .with_notice(NO_PROVIDERS_NOTICE)
+        .with_warnings(first_run_guide(error));
+    robot::print_json(&output, pretty)
+}
+
+/// The error followed by each suggestion's explanation and commands.
+fn first_run_guide(error: &CautError) -> Vec<String> {
+    let mut guide = vec![error.to_string()];
+    guide.extend(error.fix_suggestions().into_iter().map(|suggestion| {
+        format!(
+            "{} Try: {}",
+            suggestion.context,
+            suggestion.commands.join("; ")
+        )
+    }));
+    guide
+}
+
+/// Scan local costs for the providers in `payloads` that support it, for
+/// the summary's spend totals. Providers whose scan fails are left out.
+async fn scan_costs(payloads: &[ProviderPayload]) -> Vec<CostPayload> {
+    let scanner = CostScanner::new();
+    let mut costs = Vec::new();
+    for payload in payloads {
+        let Ok(provider) = Provider::from_cli_name(&payload.provider) else {
+            continue;
+        };
+        if !provider.supports_cost_scan() {
+            continue;
+        }
+        match scanner.scan(provider, false).await {
+            Ok(cost) => costs.push(cost),
+            Err(e) => tracing::debug!(provider = %payload.provider, "Cost scan failed: {}", e),
+        }
+    }
+    costs
+}
+
+/// The all-providers summary, shown only when more than one provider was
+/// queried.
+fn summary(results: &UsageResults) -> Option<UsageSummary> {
+    (results.payloads.len() + results.errors.len() > 1).then(|| {
+        UsageSummary::build(
+            &results.payloads,
+            &results.costs,
+            results.errors.len(),
+            Utc::now(),
+        )
+    })
+}
+
+/// Human-readable panels (or table) followed by the all-providers summary.
+///
+/// # Errors
+/// Returns an error if panel rendering fails.
+pub(crate) fn render_human(results: &UsageResults, options: &UsageRenderOptions) -> Result<String> {
+    let mut output = match &options.columns {
+        Some(columns) => {
+            table::render_usage_table(&results.payloads, &results.costs, columns, TableStyle::Text)
+        }
+        None => human::render_usage_with_options(&results.payloads, options)?,
+    };
+    if let Some(summary) = summary(results) {
+        output.push('\n');
+        output.push_str(&human::render_usage_summary(&summary, options.no_color));
+    }
+    Ok(output)
+}
+
+/// Build usage render options from arguments.
+///
+/// Dashboard links become OSC 8 hyperlinks only when the terminal supports
+/// them; piped and agent-facing output never carries escape sequences.
+///
+/// # Errors
+/// Returns an error if `output.columns` in the config names an unknown column.
+pub(crate) fn render_options(
+    args: &UsageArgs,
+    format: OutputFormat,
+    no_color: bool,
+) -> Result<UsageRenderOptions> {
+    Ok(UsageRenderOptions {
+        no_color,
+        full: args.full,
+        links: args.links,
+        hyperlinks: args.links && TerminalFeatures::detect(format, no_color).hyperlinks,
+        explain: args.explain,
+        columns: table_columns(args)?,
+        csv: args.csv,
+    })
+}
+
+/// Columns for the usage table: `--columns`, else `output.columns` from the
+/// config, else the CSV defaults under `--csv`. `None` means panels.
+fn table_columns(args: &UsageArgs) -> Result<Option<Vec<UsageColumn>>> {
+    if !args.columns.is_empty() {
+        return Ok(Some(args.columns.clone()));
+    }
+
+    let configured = Config::load()
+        .map(|config| config.output.columns)
+        .unwrap_or_default();
+    let columns = configured
+        .iter()
+        .map(|name| {
+            UsageColumn::from_str(name.trim(), true).map_err(|_| {
+                CautError::Config(format!("Unknown column '{name}' in output.columns"))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(if !columns.is_empty() {
+        Some(columns)
+    } else if args.csv {
+        Some(UsageColumn::DEFAULT.to_vec())
+    } else {
+        None
+    })
 }
 
 pub(crate) fn render_usage_results(
     results: &UsageResults,
     format: OutputFormat,
     pretty: bool,
-    no_color: bool,
+    options: &UsageRenderOptions,
 ) -> Result<()> {
+    let warnings: Vec<String> = clock_skew::significant()
+        .iter()
+        .map(ClockSkew::message)
+        .collect();
+    let summary = summary(results);
+
+    if options.csv {
+        let columns = options.columns.as_deref().unwrap_or(&UsageColumn::DEFAULT);
+        print!(
+            "{}",
+            table::render_usage_table(&results.payloads, &results.costs, columns, TableStyle::Csv)
+        );
+        for warning in &warnings {
+            eprintln!("Warning: {warning}");
+        }
+        for error in &results.errors {
+            eprintln!("Error: {error}");
+        }
+        return Ok(());
+    }
+
     match format {
         OutputFormat::Human => {
-            let output = human::render_usage(&results.payloads, no_color)?;
-            println!("{output}");
+            println!("{}", render_human(results, options)?);
+            budget::print_budget_section(&results.budgets, false, options.no_color);
 
+            for warning in &warnings {
+                eprintln!("Warning: {warning}");
+            }
             for error in &results.errors {
                 eprintln!("Error: {error}");
             }
         }
         OutputFormat::Json => {
-            let robot_output = RobotOutput::usage(results.payloads.clone(), results.errors.clone());
-            let output = if pretty {
-                robot::render_json_pretty(&robot_output)?
-            } else {
-                robot::render_json(&robot_output)?
-            };
-            println!("{output}");
+            let robot_output = RobotOutput::usage(results.payloads.clone(), results.errors.clone())
+                .with_warnings(warnings)
+                .with_summary(summary)
+                .with_budgets(results.budgets.clone());
+            robot::print_json(&robot_output, pretty)?;
         }
         OutputFormat::Md => {
-            let output = robot::render_markdown_usage(&results.payloads)?;
+            let output = match &options.columns {
+                Some(columns) => table::render_usage_table(
+                    &results.payloads,
+                    &results.costs,
+                    columns,
+                    TableStyle::Markdown,
+                ),
+                None => robot::render_usage_md_with_links(&results.payloads, options.links)?,
+            };
             println!("{output}");
 
+            for warning in &warnings {
+                println!("\n> Warning: {warning}");
+            }
+
+            if options.explain {
+                for payload in &results.payloads {
+                    println!("\n### {} explanation\n", payload.provider);
+                    for line in explain_payload(payload) {
+                        println!("- {line}");
+                    }
+                }
+            }
+
+            if let Some(summary) = &summary {
+                println!("\n{}", robot::render_usage_summary_md(summary));
+            }
+            budget::print_budget_section(&results.budgets, true, options.no_color);
+
             if !results.errors.is_empty() {
                 println!("\n## Errors\n");
                 for error in &results.errors {
@@ -0,0 +1,270 @@
+//! Value command implementation.
+//!
+//! Answers "is my subscription worth it?": prices each billing cycle's local
+//! session logs at API rates and compares them with the plan price (see
+//! [`crate::core::plan_value`]). Plans are detected from Claude Code's and
+//! Codex's local sign-ins, or given with `--plan`.
+
+use std::fmt::Write;
+
+use chrono::Local;
+
+use crate::cli::args::{OutputFormat, ValueArgs};
+use crate::cli::project::daily_costs;
+use crate::core::models::RobotOutput;
+use crate::core::plan_value::{self, PlanValue};
+use crate::core::provider::{Provider, ProviderSelection};
+use crate::core::session_logs::SessionLogFinder;
+use crate::error::{CautError, Result};
+use crate::providers::{claude, codex};
+use crate::render::robot;
+use crate::util::format::format_cost;
+
+/// Execute the value command.
+///
+/// # Errors
+/// Returns an error if a `--plan` is invalid, no selected provider has both
+/// session logs and a known plan, or output serialization fails.
+pub fn execute(args: &ValueArgs, format: OutputFormat, pretty: bool) -> Result<()> {
+    let selection = args
+        .provider
+        .as_deref()
+        .map(ProviderSelection::from_arg)
+        .transpose()?
+        .unwrap_or_default();
+    let providers: Vec<Provider> = selection
+        .providers()
+        .into_iter()
+        .filter(|p| p.supports_session_logs())
+        .collect();
+    if providers.is_empty() {
+        return Err(CautError::Config(
+            "No selected providers have local session logs. Only Claude, Codex, and Aider are supported."
+                .to_string(),
+        ));
+    }
+    let plans = parse_plans(&args.plans)?;
+
+    let today = Local::now().date_naive();
+    let cycles = plan_value::billing_cycles(today, args.billing_day, args.cycles);
+    let Some(first) = cycles.first() else {
+        return Ok(());
+    };
+    let finder = SessionLogFinder::new()?;
+
+    let mut values = Vec::new();
+    for provider in providers {
+        let spec = plans
+            .iter()
+            .rev()
+            .find(|(p, _)| p.is_none_or(|p| p == provider))
+            .map(|(_, spec)| spec.clone())
+            .or_else(|| detected_plan(provider));
+        let Some(spec) = spec else {
+            if selection.is_single() {
+                return Err(CautError::Config(format!(
+                    "Could not detect your {} plan; pass --plan {}=<plan> ({} or a monthly price)",
+                    provider.display_name(),
+                    provider.cli_name(),
+                    plan_value::plan_names(provider).join(", ")
+                )));
+            }
+            tracing::info!(provider = provider.cli_name(), "No plan known, skipping");
+            continue;
+        };
+        let (label, price) = resolve_plan(provider, &spec)?;
+        let daily = daily_costs(&finder, provider, first.start);
+        values.push(plan_value::evaluate(
+            provider.cli_name(),
+            &label,
+            price,
+            &daily,
+            &cycles,
+            today,
+        ));
+    }
+    if values.is_empty() {
+        return Err(CautError::Config(
+            "No subscription plan detected; pass e.g. --plan claude=max-20x or --plan codex=200"
+                .to_string(),
+        ));
+    }
+
+    match format {
+        OutputFormat::Human => print!("{}", render_values(&values, false)),
+        OutputFormat::Md => print!("{}", render_values(&values, true)),
+        OutputFormat::Json => {
+            let output = RobotOutput::new("value", values);
+            robot::print_json(&output, pretty)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse `--plan` values of the form `[PROVIDER=]PLAN`.
+fn parse_plans(values: &[String]) -> Result<Vec<(Option<Provider>, String)>> {
+    values
+        .iter()
+        .map(|value| match value.split_once('=') {
+            Some((provider, plan)) => Ok((
+                Some(Provider::from_cli_name(provider.trim())?),
+                plan.trim().to_string(),
+            )),
+            None => Ok((None, value.trim().to_string())),
+        })
+        .collect()
+}
+
+fn detected_plan(provider: Provider) -> Option<String> {
+    match provider {
+        Provider::Claude => claude::local_plan(),
+        Provider::Codex => codex::local_plan(),
+        _ => None,
+    }
+}
+
+/// Label and monthly price for a plan name or a price such as `200` or `$200`.
+fn resolve_plan(provider: Provider, spec: &str) -> Result<(String, f64)> {
+    if let Ok(price) = spec.trim_start_matches('$').parse::<f64>()
+        && price.is_finite()
+        && price >= 0.0
+    {
+        return Ok(("custom".to_string(), price));
+    }
+    plan_value::find_plan(provider, spec)
+        .map(|plan| (plan.label.to_string(), plan.monthly_usd))
+        .ok_or_else(|| {
+            CautError::Config(format!(
+                "Unknown {} plan '{spec}' (expected {} or a monthly price)",
+                provider.display_name(),
+                plan_value::plan_names(provider).join(", ")
+            ))
+        })
+}
+
+fn plan_name(value: &PlanValue) -> String {
+    if value.plan == "custom" {
+        format!("{}/mo", format_cost(value.plan_price_usd))
+    } else {
+        value.plan.clone()
+    }
+}
+
+/// One-line verdict on the last completed cycle.
+fn verdict(value: &PlanValue) -> String {
+    let name = plan_name(value);
+    match value.last_completed() {
+        Some(cycle) if cycle.savings_usd >= 0.0 => format!(
+            "Your {name} plan saved {} last cycle.",
+            format_cost(cycle.savings_usd)
+        ),
+        Some(cycle) => format!(
+            "Last cycle's usage was worth {} at API rates; the {name} plan cost {} more.",
+            format_cost(cycle.api_value_usd),
+            format_cost(-cycle.savings_usd)
+        ),
+        None => "No completed billing cycle yet.".to_string(),
+    }
+}
+
+fn cycle_outcome(savings_usd: f64, in_progress: bool) -> String {
+    if in_progress {
+        "so far".to_string()
+    } else if savings_usd >= 0.0 {
+        format!("saved {}", format_cost(savings_usd))
+    } else {
+        format!("cost {} more", format_cost(-savings_usd))
+    }
+}
+
+fn render_values(values: &[PlanValue], markdown: bool) -> String {
+    let mut out = String::new();
+    for value in values {
+        if markdown {
+            let _ = writeln!(
+                out,
+                "## {} ({}, {}/mo)\n\n| Cycle | API value | Outcome |\n|-------|-----------|---------|",
+                value.provider,
+                value.plan,
+                format_cost(value.plan_price_usd)
+            );
+            for cycle in &value.cycles {
+                let _ = writeln!(
+                    out,
+                    "| {} – {} | {} | {} |",
+                    cycle.start,
+                    cycle.end,
+                    format_cost(cycle.api_value_usd),
+                    cycle_outcome(cycle.savings_usd, cycle.in_progress)
+                );
+            }
+            let _ = writeln!(out, "\n{}\n", verdict(value));
+        } else {
+            let _ = writeln!(
+                out,
+                "{} ({}, {}/mo), priced at API rates:",
+                value.provider,
+                value.plan,
+                format_cost(value.plan_price_usd)
+            );
+            for cycle in &value.cycles {
+                let _ = writeln!(
+                    out,
+                    "  {} – {}  {:>10}  {}",
+                    cycle.start,
+                    cycle.end,
+                    format_cost(cycle.api_value_usd),
+                    cycle_outcome(cycle.savings_usd, cycle.in_progress)
+                );
+            }
+            let _ = writeln!(out, "  {}\n", verdict(value));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use std::collections::BTreeMap;
+
+    fn value(september_usd: f64) -> PlanValue {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        let daily = BTreeMap::from([
+            (NaiveDate::from_ymd_opt(2026, 9, 10).unwrap(), september_usd),
+            (NaiveDate::from_ymd_opt(2026, 10, 2).unwrap(), 40.0),
+        ]);
+        let cycles = plan_value::billing_cycles(today, 1, 2);
+        plan_value::evaluate("claude", "Max 20x", 200.0, &daily, &cycles, today)
+    }
+
+    #[test]
+    fn human_output_states_the_saving() {
+        let rendered = render_values(&[value(512.4)], false);
+        assert!(rendered.starts_with("claude (Max 20x, $200.00/mo), priced at API rates:\n"));
+        assert!(rendered.contains("saved $312.40"));
+        assert!(rendered.contains("so far"));
+        assert!(rendered.contains("Your Max 20x plan saved $312.40 last cycle."));
+
+        let rendered = render_values(&[value(150.0)], true);
+        assert!(rendered.contains("| 2026-09-01 – 2026-09-30 | $150.00 | cost $50.00 more |"));
+        assert!(rendered.contains("the Max 20x plan cost $50.00 more."));
+    }
+
+    #[test]
+    fn plans_resolve_by_name_or_price() {
+        let plans = parse_plans(&["claude=MAX-5x".to_string(), "$150".to_string()]).unwrap();
+        assert_eq!(plans[0].0, Some(Provider::Claude));
+        assert_eq!(plans[1].0, None);
+
+        let (label, price) = resolve_plan(Provider::Claude, &plans[0].1).unwrap();
+        assert_eq!(label, "Max 5x");
+        assert!((price - 100.0).abs() < f64::EPSILON);
+        let (label, price) = resolve_plan(Provider::Codex, &plans[1].1).unwrap();
+        assert_eq!(label, "custom");
+        assert!((price - 150.0).abs() < f64::EPSILON);
+        assert!(resolve_plan(Provider::Claude, "max").is_err());
+    }
+}
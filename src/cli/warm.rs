@@ -0,0 +1,188 @@
+//! Warm command implementation.
+//!
+//! `caut warm` runs the same fetch as `caut usage` to refresh the prompt
+//! cache, so `caut prompt` and `caut statusline` read fresh data. With
+//! `--background` it re-runs itself as a detached process and returns at
+//! once, which keeps shell startup fast.
+//!
+//! A lock file keeps concurrent warms (every new terminal runs one) from
+//! stampeding the providers: a warm that finds the lock held does nothing.
+
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::cli::args::WarmArgs;
+use crate::cli::{prompt, usage};
+use crate::error::Result;
+use crate::storage::config::{Config, ENV_CONFIG};
+use crate::storage::{AppPaths, cache};
+
+/// A lock older than this is left by a warm that died and is taken over.
+const LOCK_STALE_AFTER: Duration = Duration::from_secs(300);
+
+/// Execute the warm command.
+///
+/// # Errors
+/// Returns an error if the lock file cannot be created, the background
+/// process cannot be started, or the fetch fails outright.
+pub async fn execute(args: &WarmArgs) -> Result<()> {
+    if prompt::cache_is_fresh(args.max_age) {
+        tracing::debug!(max_age = args.max_age, "Prompt cache is fresh, not warming");
+        return Ok(());
+    }
+
+    if args.background {
+        return start_background(args);
+    }
+
+    let lock_path = AppPaths::new().warm_lock_file();
+    let Some(_lock) = WarmLock::acquire(&lock_path)? else {
+        tracing::debug!("Another caut warm is running");
+        return Ok(());
+    };
+    let results = usage::fetch_usage(&args.to_usage_args()).await?;
+    for error in &results.errors {
+        tracing::debug!(%error, "Provider not warmed");
+    }
+    eprintln!(
+        "Warmed {} provider(s){}",
+        results.payloads.len(),
+        if results.errors.is_empty() {
+            String::new()
+        } else {
+            format!(", {} failed", results.errors.len())
+        }
+    );
+    Ok(())
+}
+
+/// Start `caut warm` in a detached process, unless one is already running.
+///
+/// # Errors
+/// Returns an error if the process cannot be started.
+pub(crate) fn start_background(args: &WarmArgs) -> Result<()> {
+    if lock_is_held(&AppPaths::new().warm_lock_file()) {
+        tracing::debug!("Another caut warm is running");
+        return Ok(());
+    }
+    spawn_detached(args)
+}
+
+/// Start `caut warm` without `--background` as a detached process.
+fn spawn_detached(args: &WarmArgs) -> Result<()> {
+    let mut command = Command::new(std::env::current_exe()?);
+    command
+        .arg("warm")
+        .arg("--max-age")
+        .arg(args.max_age.to_string());
+    if let Some(provider) = &args.provider {
+        command.arg("--provider").arg(provider);
+    }
+    command
+        .env(ENV_CONFIG, Config::location())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    detach(&mut command);
+    command.spawn()?;
+    Ok(())
+}
+
+/// Keep the child out of the shell's process group, so closing the terminal
+/// or pressing Ctrl+C does not stop it.
+#[cfg(unix)]
+fn detach(command: &mut Command) {
+    use std::os::unix::process::CommandExt;
+    command.process_group(0);
+}
+
+#[cfg(windows)]
+fn detach(command: &mut Command) {
+    use std::os::windows::process::CommandExt;
+    const DETACHED_PROCESS: u32 = 0x0000_0008;
+    const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+    command.creation_flags(DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP);
+}
+
+#[cfg(not(any(unix, windows)))]
+const fn detach(_command: &mut Command) {}
+
+fn lock_is_held(path: &Path) -> bool {
+    path.exists() && !lock_is_stale(path)
+}
+
+fn lock_is_stale(path: &Path) -> bool {
+    cache::get_age_secs(path).is_none_or(|age| age >= LOCK_STALE_AFTER.as_secs())
+}
+
+/// The warm lock, removed when dropped.
+#[derive(Debug)]
+struct WarmLock(PathBuf);
+
+impl WarmLock {
+    /// Take the lock, or `None` if another warm holds it.
+    fn acquire(path: &Path) -> Result<Option<Self>> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Two attempts: the second follows removing a stale lock.
+        for _ in 0..2 {
+            match OpenOptions::new().write(true).create_new(true).open(path) {
+                Ok(mut file) => {
+                    let _ = writeln!(file, "{}", std::process::id());
+                    return Ok(Some(Self(path.to_path_buf())));
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    if !lock_is_stale(path) {
+                        return Ok(None);
+                    }
+                    tracing::debug!(?path, "Taking over stale warm lock");
+                    let _ = fs::remove_file(path);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Drop for WarmLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn lock_is_exclusive_until_dropped() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("warm.lock");
+
+        let lock = WarmLock::acquire(&path).unwrap().expect("first acquire");
+        assert!(lock_is_held(&path));
+        assert!(WarmLock::acquire(&path).unwrap().is_none());
+
+        drop(lock);
+        assert!(!path.exists());
+        assert!(WarmLock::acquire(&path).unwrap().is_some());
+    }
+
+    #[test]
+    fn stale_lock_is_taken_over() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("warm.lock");
+        let file = fs::File::create(&path).unwrap();
+        let old = std::time::SystemTime::now() - LOCK_STALE_AFTER - Duration::from_secs(1);
+        file.set_modified(old).unwrap();
+
+        assert!(!lock_is_held(&path));
+        assert!(WarmLock::acquire(&path).unwrap().is_some());
+    }
+}
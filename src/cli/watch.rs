@@ -1,13 +1,38 @@
 //! Watch mode support for the usage command.
 //!
 //! Provides the core loop and state management for continuous updates.
+//! When a provider's worst usage window crosses into a worse severity, or
+//! live usage starts breaking a policy rule, an OSC 9 desktop notification
+//! is raised in terminals that support it. Runaway agent sessions caught by
+//! the [watchdog](crate::core::watchdog) are reported the same way, and on
+//! stderr. On battery or a metered connection, updates are spaced out and
+//! skip web sources (see [`crate::core::power`]).
+//!
+//! With `--record`, each refresh is also appended to an asciinema cast file
+//! (see [`crate::render::cast`]), which `--replay` plays back.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::Path;
 
-use chrono::{DateTime, Utc};
-use tokio::time::{Duration, interval};
+use chrono::{DateTime, Local, Utc};
+use tokio::time::{Duration, sleep};
 
 use crate::cli::args::{OutputFormat, UsageArgs};
-use crate::cli::usage::{UsageResults, fetch_usage, render_usage_results};
+use crate::cli::usage::{
+    UsageResults, fetch_usage, render_human, render_options, render_usage_results,
+};
+use crate::core::models::ProviderPayload;
+use crate::core::policy::{self, PolicyRule};
+use crate::core::power::PowerScheduler;
+use crate::core::severity::{Severity, evaluate_windows};
+use crate::core::watchdog::Watchdog;
 use crate::error::{CautError, Result};
+use crate::render::UsageRenderOptions;
+use crate::render::cast::{CastWriter, read_cast};
+use crate::rich::{TerminalFeatures, osc9_notification};
+use crate::storage::config::Config;
+use crate::util::time::humanize_reset;
 
 /// State tracking across watch iterations.
 #[derive(Debug, Default)]
@@ -18,6 +43,14 @@ pub struct WatchState {
     pub fetch_count: u64,
     pub error_count: u64,
     pub last_error: Option<CautError>,
+    /// Alerts raised by the most recent successful update.
+    pub alerts: Vec<String>,
+    /// Worst window severity last seen per provider.
+    severities: HashMap<String, Severity>,
+    /// Policy rules checked against live usage.
+    policy: Vec<PolicyRule>,
+    /// Policy violations seen in the last update, as alert messages.
+    violations: HashSet<String>,
 }
 
 impl WatchState {
@@ -27,11 +60,21 @@ impl WatchState {
         Self::default()
     }
 
+    /// Create a watch state that checks live usage against policy rules.
+    #[must_use]
+    pub fn with_policy(policy: Vec<PolicyRule>) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+
     /// Update state with the latest fetch results.
     pub(crate) fn update(&mut self, results: Result<UsageResults>) {
         self.fetch_count += 1;
         match results {
             Ok(results) => {
+                self.alerts = self.detect_alerts(&results.payloads);
                 self.last_results = Some(results.payloads);
                 self.last_errors = results.errors;
                 self.last_fetch_at = Some(Utc::now());
@@ -47,12 +90,57 @@ impl WatchState {
             Err(e) => {
                 self.error_count += 1;
                 self.last_error = Some(e);
+                self.alerts.clear();
                 // Preserve last_results/last_errors for stale display.
             }
         }
     }
+
+    /// Record each provider's worst severity and report those that got worse.
+    fn detect_alerts(&mut self, payloads: &[ProviderPayload]) -> Vec<String> {
+        let mut alerts = Vec::new();
+        for payload in payloads {
+            let Some(worst) = evaluate_windows(&payload.usage)
+                .into_iter()
+                .min_by(|a, b| a.remaining_percent.total_cmp(&b.remaining_percent))
+            else {
+                continue;
+            };
+            let severity = worst.severity;
+            let previous = self
+                .severities
+                .insert(payload.provider.clone(), severity)
+                .unwrap_or(Severity::Ok);
+            if severity.is_alert() && severity > previous {
+                let reset = worst
+                    .resets_at
+                    .map_or_else(String::new, |at| format!(", {}", humanize_reset(at)));
+                alerts.push(format!(
+                    "caut: {} {} {:.0}% left ({}){reset}",
+                    payload.provider,
+                    worst.window,
+                    worst.remaining_percent,
+                    severity.label()
+                ));
+            }
+        }
+
+        let violations: HashSet<String> = policy::check_live(&self.policy, payloads, Local::now())
+            .iter()
+            .map(|violation| format!("caut: policy {}", violation.message()))
+            .collect();
+        let mut new: Vec<_> = violations.difference(&self.violations).cloned().collect();
+        new.sort();
+        alerts.extend(new);
+        self.violations = violations;
+
+        alerts
+    }
 }
 
+/// Longest pause between replayed frames, like asciinema's idle time limit.
+const REPLAY_MAX_PAUSE: Duration = Duration::from_secs(2);
+
 /// Run watch mode for the usage command.
 ///
 /// # Errors
@@ -65,8 +153,26 @@ pub async fn run_watch(
     no_color: bool,
     interval_duration: Duration,
 ) -> Result<()> {
-    let mut state = WatchState::new();
-    let mut ticker = interval(interval_duration);
+    let config = Config::load().unwrap_or_default();
+    let mut state = WatchState::with_policy(config.policy.rules);
+    let mut watchdog = Watchdog::from_config(config.watchdog);
+    let mut power = PowerScheduler::new(config.general.power_aware);
+    // The first update runs immediately.
+    let mut next_update = Duration::ZERO;
+    let options = render_options(args, format, no_color)?;
+    let notify = TerminalFeatures::detect(format, no_color).notifications;
+    let mut recorder = match &args.record {
+        Some(path) => {
+            let (width, height) = crossterm::terminal::size().unwrap_or((80, 24));
+            Some(CastWriter::create(
+                path,
+                width,
+                height,
+                "caut usage --watch",
+            )?)
+        }
+        None => None,
+    };
 
     // Ctrl+C handler for clean shutdown.
     let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
@@ -77,13 +183,33 @@ pub async fn run_watch(
 
     loop {
         tokio::select! {
-            _ = ticker.tick() => {
+            () = sleep(next_update) => {
+                if let Some(message) = power.update().await {
+                    eprintln!("{message}");
+                }
                 let results = fetch_usage(args).await;
                 state.update(results);
-                render_watch_frame(&state, format, pretty, no_color)?;
+                render_watch_frame(&state, format, pretty, &options)?;
+                if let Some(recorder) = &mut recorder {
+                    record_frame(recorder, &state, &options)?;
+                }
+                if let Some(watchdog) = &mut watchdog {
+                    for runaway in watchdog.check(Utc::now()) {
+                        eprintln!("{}", runaway.message());
+                        watchdog.trip(&runaway);
+                        state.alerts.push(runaway.message());
+                    }
+                }
+                if notify && !state.alerts.is_empty() {
+                    for alert in &state.alerts {
+                        print!("{}", osc9_notification(alert));
+                    }
+                    let _ = std::io::stdout().flush();
+                }
+                next_update = power.interval(interval_duration);
             }
             _ = &mut shutdown_rx => {
-                render_final_snapshot(&state, format, pretty, no_color)?;
+                render_final_snapshot(&state, format, pretty, &options)?;
                 break;
             }
         }
@@ -96,14 +222,16 @@ fn render_watch_frame(
     state: &WatchState,
     format: OutputFormat,
     pretty: bool,
-    no_color: bool,
+    options: &UsageRenderOptions,
 ) -> Result<()> {
     if let Some(payloads) = &state.last_results {
         let results = UsageResults {
             payloads: payloads.clone(),
             errors: state.last_errors.clone(),
+            costs: Vec::new(),
+            budgets: Vec::new(),
         };
-        render_usage_results(&results, format, pretty, no_color)?;
+        render_usage_results(&results, format, pretty, options)?;
     }
 
     if let Some(err) = &state.last_error {
@@ -113,13 +241,58 @@ fn render_watch_frame(
     Ok(())
 }
 
+/// Append the latest results to the recording, rendered as human output.
+fn record_frame(
+    recorder: &mut CastWriter,
+    state: &WatchState,
+    options: &UsageRenderOptions,
+) -> Result<()> {
+    let Some(payloads) = &state.last_results else {
+        return Ok(());
+    };
+    let results = UsageResults {
+        payloads: payloads.clone(),
+        errors: state.last_errors.clone(),
+        costs: Vec::new(),
+        budgets: Vec::new(),
+    };
+    let mut frame = render_human(&results, options)?;
+    let errors = state.last_errors.iter().cloned();
+    for error in errors.chain(state.last_error.iter().map(ToString::to_string)) {
+        frame.push_str("\nError: ");
+        frame.push_str(&error);
+    }
+    recorder.frame(&frame)
+}
+
+/// Play back a recorded watch session.
+///
+/// Frames keep their recorded spacing, with pauses capped at
+/// [`REPLAY_MAX_PAUSE`] so half-hour sessions replay in seconds.
+///
+/// # Errors
+/// Returns an error if the recording cannot be read or stdout fails.
+pub async fn replay(path: &Path) -> Result<()> {
+    let events = read_cast(path)?;
+    let mut previous = 0.0;
+    let mut stdout = std::io::stdout();
+    for event in events {
+        let pause = Duration::try_from_secs_f64(event.time - previous).unwrap_or_default();
+        previous = event.time;
+        sleep(pause.min(REPLAY_MAX_PAUSE)).await;
+        stdout.write_all(event.data.as_bytes())?;
+        stdout.flush()?;
+    }
+    Ok(())
+}
+
 fn render_final_snapshot(
     state: &WatchState,
     format: OutputFormat,
     pretty: bool,
-    no_color: bool,
+    options: &UsageRenderOptions,
 ) -> Result<()> {
-    render_watch_frame(state, format, pretty, no_color)
+    render_watch_frame(state, format, pretty, options)
 }
 
 #[cfg(test)]
@@ -133,6 +306,8 @@ mod tests {
         let results = UsageResults {
             payloads: vec![make_test_provider_payload("codex", "cli")],
             errors: vec!["codex: warning".to_string()],
+            costs: Vec::new(),
+            budgets: Vec::new(),
         };
 
         state.update(Ok(results));
@@ -154,6 +329,8 @@ mod tests {
         let results = UsageResults {
             payloads: vec![make_test_provider_payload("codex", "cli")],
             errors: Vec::new(),
+            costs: Vec::new(),
+            budgets: Vec::new(),
         };
 
         state.update(Ok(results));
@@ -169,4 +346,65 @@ mod tests {
             Some(before_len)
         );
     }
+
+    fn results_at(used_percent: f64) -> UsageResults {
+        let mut payload = make_test_provider_payload("claude", "oauth");
+        payload.usage.primary = Some(crate::core::models::RateWindow::new(used_percent));
+        payload.usage.secondary = None;
+        payload.usage.tertiary = None;
+        UsageResults {
+            payloads: vec![payload],
+            errors: Vec::new(),
+            costs: Vec::new(),
+            budgets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn watch_state_alerts_only_when_severity_worsens() {
+        let mut state = WatchState::new();
+
+        state.update(Ok(results_at(50.0)));
+        assert!(state.alerts.is_empty());
+
+        state.update(Ok(results_at(80.0)));
+        assert_eq!(
+            state.alerts,
+            vec!["caut: claude session 20% left (warning)"]
+        );
+
+        // Same severity again: no repeat notification.
+        state.update(Ok(results_at(82.0)));
+        assert!(state.alerts.is_empty());
+
+        state.update(Ok(results_at(95.0)));
+        assert_eq!(state.alerts.len(), 1);
+        assert!(state.alerts[0].contains("critical"));
+
+        // Recovering and worsening again re-alerts.
+        state.update(Ok(results_at(10.0)));
+        assert!(state.alerts.is_empty());
+        state.update(Ok(results_at(80.0)));
+        assert_eq!(state.alerts.len(), 1);
+    }
+
+    #[test]
+    fn watch_state_alerts_on_new_policy_violation() {
+        let mut state = WatchState::with_policy(vec![PolicyRule {
+            name: Some("no-claude".to_string()),
+            action: policy::PolicyAction::Forbid,
+            provider: Some("claude".to_string()),
+            ..PolicyRule::default()
+        }]);
+
+        state.update(Ok(results_at(10.0)));
+        assert_eq!(
+            state.alerts,
+            vec!["caut: policy [forbid] no-claude: claude"]
+        );
+
+        // Still violating: no repeat notification.
+        state.update(Ok(results_at(12.0)));
+        assert!(state.alerts.is_empty());
+    }
 }
@@ -0,0 +1,477 @@
+//! Switching between saved provider accounts.
+//!
+//! A provider CLI is signed in to one account at a time through its
+//! credentials file (Claude Code's `.credentials.json`, Codex's `auth.json`)
+//! and, for Claude, caut's own keyring token and the macOS Keychain entry
+//! Claude Code uses there. `caut accounts save` copies the current
+//! credentials into the system keyring under an account name, and `caut
+//! accounts switch` puts a saved set back in place so the provider CLI and
+//! caut both use the other account.
+//!
+//! Every switch is recorded in the switch log, which is also how a rollback
+//! finds the account to go back to. If any part of a swap fails, the
+//! credentials that were in place are written back before the error is
+//! returned.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::core::credential_hash::CredentialHasher;
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::providers::{claude, codex};
+use crate::storage::{Account, MultiAccountDb, SwitchTrigger, private_file};
+
+/// A provider's credentials: slot name to content. Empty slots are absent.
+type Credentials = BTreeMap<String, String>;
+
+/// Somewhere a provider keeps part of its sign-in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Slot {
+    File(PathBuf),
+    Keyring {
+        service: &'static str,
+        account: String,
+    },
+}
+
+/// The result of a switch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchOutcome {
+    pub provider: String,
+    /// The account signed in before, if it was a saved one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    pub to: String,
+    /// False when `to` was already signed in.
+    pub changed: bool,
+    pub rollback: bool,
+}
+
+/// Save the credentials `provider` is signed in with as account `name`,
+/// replacing what was saved under that name before.
+///
+/// # Errors
+/// Returns an error if there are no credentials to save, they are already
+/// saved under another name, or the keyring or database cannot be written.
+pub fn save(db: &MultiAccountDb<'_>, provider: Provider, name: &str) -> Result<Account> {
+    let live = read_credentials(&slots(provider)?)?;
+    if live.is_empty() {
+        return Err(CautError::Config(format!(
+            "No {} credentials to save; sign in first. {}",
+            provider.display_name(),
+            provider.auth_suggestion()
+        )));
+    }
+    let fingerprint = fingerprint(&live);
+    let existing = find_account(db, provider, name)?;
+    if let Some(other) = db
+        .list_accounts(Some(provider.cli_name()))?
+        .into_iter()
+        .find(|a| {
+            fingerprint.is_some()
+                && a.credential_hash == fingerprint
+                && existing.as_ref().is_none_or(|e| e.id != a.id)
+        })
+    {
+        return Err(CautError::Config(format!(
+            "These {} credentials are already saved as '{}'",
+            provider.display_name(),
+            display_name(&other)
+        )));
+    }
+
+    let mut account =
+        existing.unwrap_or_else(|| Account::new(provider.cli_name(), name).with_label(name));
+    if let Some(fingerprint) = &fingerprint {
+        account = account.with_credential_hash(fingerprint);
+    }
+    account.id = db.upsert_account(&account)?;
+    store_saved(&account.id, &live)?;
+    Ok(account)
+}
+
+/// Switch `provider` to the saved account `name`.
+///
+/// # Errors
+/// Returns an error if no account by that name is saved, the signed-in
+/// credentials are not saved under any account (they would be lost), or the
+/// swap fails. A failed swap is logged and the previous credentials restored.
+pub fn switch(db: &MultiAccountDb<'_>, provider: Provider, name: &str) -> Result<SwitchOutcome> {
+    let target = find_account(db, provider, name)?.ok_or_else(|| {
+        CautError::Config(format!(
+            "No saved {} account '{name}'. Sign in to it, then run `caut accounts save --provider {} --account {name}`.",
+            provider.display_name(),
+            provider.cli_name()
+        ))
+    })?;
+    switch_to(db, provider, &target, false)
+}
+
+/// Switch `provider` back to the account its last switch left.
+///
+/// # Errors
+/// Returns an error if there is no switch to undo or the swap fails.
+pub fn rollback(db: &MultiAccountDb<'_>, provider: Provider) -> Result<SwitchOutcome> {
+    let previous = db
+        .last_switch(provider.cli_name())?
+        .and_then(|entry| entry.from_account_id)
+        .ok_or_else(|| {
+            CautError::Config(format!(
+                "No {} account switch to roll back",
+                provider.display_name()
+            ))
+        })?;
+    let target = db.get_account(&previous)?.ok_or_else(|| {
+        CautError::Config(format!(
+            "The {} account switched away from is no longer saved",
+            provider.display_name()
+        ))
+    })?;
+    switch_to(db, provider, &target, true)
+}
+
+fn switch_to(
+    db: &MultiAccountDb<'_>,
+    provider: Provider,
+    target: &Account,
+    rollback: bool,
+) -> Result<SwitchOutcome> {
+    let slots = slots(provider)?;
+    let live = read_credentials(&slots)?;
+    let current = current_account(db, provider, &live)?;
+    let mut outcome = SwitchOutcome {
+        provider: provider.cli_name().to_string(),
+        from: current.as_ref().map(display_name),
+        to: display_name(target),
+        changed: true,
+        rollback,
+    };
+
+    if current.as_ref().is_some_and(|c| c.id == target.id) {
+        // Keep tokens the provider CLI refreshed since the last save.
+        store_saved(&target.id, &live)?;
+        outcome.changed = false;
+        return Ok(outcome);
+    }
+    if current.is_none() && !live.is_empty() {
+        return Err(CautError::Config(format!(
+            "The signed-in {} credentials are not saved under any account. Save them first with `caut accounts save --provider {} --account <name>` so you can switch back (use the account's own name if they are a saved account whose tokens were refreshed).",
+            provider.display_name(),
+            provider.cli_name()
+        )));
+    }
+    let saved = load_saved(&target.id)?.ok_or_else(|| {
+        CautError::Config(format!(
+            "No credentials are stored for '{}'; sign in to it and save it again",
+            display_name(target)
+        ))
+    })?;
+    if let Some(current) = &current {
+        store_saved(&current.id, &live)?;
+    }
+
+    let from_id = current.as_ref().map(|a| a.id.as_str());
+    if let Err(e) = swap(&slots, &saved, &live) {
+        let _ = db.log_switch(
+            provider.cli_name(),
+            from_id,
+            &target.id,
+            SwitchTrigger::Manual,
+            None,
+            false,
+            rollback,
+            Some(&e.to_string()),
+        );
+        return Err(e);
+    }
+    db.log_switch(
+        provider.cli_name(),
+        from_id,
+        &target.id,
+        SwitchTrigger::Manual,
+        None,
+        true,
+        rollback,
+        None,
+    )?;
+    db.touch_account(&target.id)?;
+    Ok(outcome)
+}
+
+/// The saved account the signed-in credentials belong to: the one whose
+/// fingerprint (identity, or content when they name no account) matches.
+///
+/// The account the last switch moved to is not assumed: the user may have
+/// signed in to another account since, and a switch saves the signed-in
+/// credentials back over the current account's.
+fn current_account(
+    db: &MultiAccountDb<'_>,
+    provider: Provider,
+    live: &Credentials,
+) -> Result<Option<Account>> {
+    let Some(fingerprint) = fingerprint(live) else {
+        return Ok(None);
+    };
+    Ok(db
+        .list_accounts(Some(provider.cli_name()))?
+        .into_iter()
+        .find(|a| a.credential_hash.as_ref() == Some(&fingerprint)))
+}
+
+fn find_account(
+    db: &MultiAccountDb<'_>,
+    provider: Provider,
+    name: &str,
+) -> Result<Option<Account>> {
+    Ok(db
+        .list_accounts(Some(provider.cli_name()))?
+        .into_iter()
+        .find(|a| a.label.as_deref() == Some(name) || a.email == name))
+}
+
+fn display_name(account: &Account) -> String {
+    account
+        .label
+        .clone()
+        .unwrap_or_else(|| account.email.clone())
+}
+
+/// Identifies whose credentials these are: the identity hash when the
+/// credentials name their account, else a hash of the content.
+fn fingerprint(credentials: &Credentials) -> Option<String> {
+    let content = credentials
+        .get("file")
+        .or_else(|| credentials.get("keychain"))
+        .or_else(|| credentials.get("caut-token"))?;
+    match CredentialHasher::new().hash_content(content) {
+        Ok(hashes) if hashes.identity_fields.has_identity() => Some(hashes.identity_hash),
+        Ok(hashes) => Some(hashes.content_hash),
+        Err(_) => Some(hex::encode(Sha256::digest(content.as_bytes()))),
+    }
+}
+
+/// The slots holding `provider`'s credentials, by name. Saved sets are
+/// keyed by these names.
+fn slots(provider: Provider) -> Result<Vec<(&'static str, Slot)>> {
+    let file = match provider {
+        Provider::Claude => claude::credentials_file(),
+        Provider::Codex => codex::auth_file(),
+        _ => provider
+            .credentials_path()
+            .zip(directories::BaseDirs::new())
+            .map(|(path, dirs)| dirs.home_dir().join(path)),
+    };
+    let Some(file) = file else {
+        return Err(CautError::Config(format!(
+            "caut cannot switch {} accounts: it has no credentials file to swap",
+            provider.display_name()
+        )));
+    };
+    let mut slots = vec![("file", Slot::File(file))];
+    if provider == Provider::Claude {
+        slots.push((
+            "caut-token",
+            Slot::Keyring {
+                service: "caut",
+                account: "claude-oauth-token".to_string(),
+            },
+        ));
+        if cfg!(target_os = "macos") {
+            slots.push((
+                "keychain",
+                Slot::Keyring {
+                    service: "Claude Code-credentials",
+                    account: std::env::var("USER").unwrap_or_default(),
+                },
+            ));
+        }
+    }
+    Ok(slots)
+}
+
+fn read_credentials(slots: &[(&'static str, Slot)]) -> Result<Credentials> {
+    let mut credentials = Credentials::new();
+    for (name, slot) in slots {
+        if let Some(content) = read_slot(slot)? {
+            credentials.insert((*name).to_string(), content);
+        }
+    }
+    Ok(credentials)
+}
+
+/// Write `target` into every slot that differs from `live`. If a write
+/// fails, the slots are put back to `live`.
+fn swap(slots: &[(&'static str, Slot)], target: &Credentials, live: &Credentials) -> Result<()> {
+    for (name, slot) in slots {
+        let wanted = target.get(*name);
+        if wanted == live.get(*name) {
+            continue;
+        }
+        if let Err(e) = write_slot(slot, wanted.map(String::as_str)) {
+            for (name, slot) in slots {
+                let _ = write_slot(slot, live.get(*name).map(String::as_str));
+            }
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+fn read_slot(slot: &Slot) -> Result<Option<String>> {
+    match slot {
+        Slot::File(path) => match fs::read_to_string(path) {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        },
+        Slot::Keyring { service, account } => match keyring_entry(service, account)?.get_password()
+        {
+            Ok(content) => Ok(Some(content).filter(|c| !c.is_empty())),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(CautError::Config(format!("Keyring error: {e}"))),
+        },
+    }
+}
+
+/// Put `content` in a slot, or empty it for `None`.
+fn write_slot(slot: &Slot, content: Option<&str>) -> Result<()> {
+    match (slot, content) {
+        // Written owner-only and renamed into place, so readers never see a
+        // half-written credentials file.
+        (Slot::File(path), Some(content)) => Ok(private_file::write(path, content)?),
+        (Slot::File(path), None) => match fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        },
+        (Slot::Keyring { service, account }, Some(content)) => keyring_entry(service, account)?
+            .set_password(content)
+            .map_err(|e| CautError::Config(format!("Keyring error: {e}"))),
+        (Slot::Keyring { service, account }, None) => {
+            match keyring_entry(service, account)?.delete_credential() {
+                Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+                Err(e) => Err(CautError::Config(format!("Keyring error: {e}"))),
+            }
+        }
+    }
+}
+
+fn keyring_entry(service: &str, account: &str) -> Result<keyring::Entry> {
+    keyring::Entry::new(service, account)
+        .map_err(|e| CautError::Config(format!("Keyring error: {e}")))
+}
+
+/// Keyring account holding an account's saved credentials.
+fn saved_keyring_account(account_id: &str) -> String {
+    format!("account-{account_id}")
+}
+
+fn store_saved(account_id: &str, credentials: &Credentials) -> Result<()> {
+    let payload = serde_json::to_string(credentials)?;
+    keyring_entry("caut", &saved_keyring_account(account_id))?
+        .set_password(&payload)
+        .map_err(|e| CautError::Config(format!("Failed to store account credentials: {e}")))
+}
+
+fn load_saved(account_id: &str) -> Result<Option<Credentials>> {
+    match keyring_entry("caut", &saved_keyring_account(account_id))?.get_password() {
+        Ok(payload) => Ok(Some(serde_json::from_str(&payload)?)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(CautError::Config(format!("Keyring error: {e}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::run_migrations;
+
+    fn credentials(content: &str) -> Credentials {
+        Credentials::from([("file".to_string(), content.to_string())])
+    }
+
+    #[test]
+    fn other_signed_in_account_is_not_the_last_switch_target() {
+        let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+        run_migrations(&mut conn).unwrap();
+        let db = MultiAccountDb::new(&conn);
+        let work = credentials(r#"{"email": "work@example.com", "token": "a"}"#);
+        let account = Account::new("claude", "work")
+            .with_label("work")
+            .with_credential_hash(&fingerprint(&work).unwrap());
+        let id = db.upsert_account(&account).unwrap();
+        db.log_switch(
+            "claude",
+            None,
+            &id,
+            SwitchTrigger::Manual,
+            None,
+            true,
+            false,
+            None,
+        )
+        .unwrap();
+
+        // Refreshed tokens for the same identity still match.
+        let refreshed = credentials(r#"{"email": "work@example.com", "token": "b"}"#);
+        let current = current_account(&db, Provider::Claude, &refreshed).unwrap();
+        assert_eq!(current.map(|a| a.id), Some(id));
+
+        // Signed in by hand to another account: not saved, so nothing is
+        // saved back over "work".
+        for manual in [
+            r#"{"email": "personal@example.com", "token": "c"}"#,
+            r#"{"token": "d"}"#,
+        ] {
+            assert!(
+                current_account(&db, Provider::Claude, &credentials(manual))
+                    .unwrap()
+                    .is_none()
+            );
+        }
+        assert!(
+            current_account(&db, Provider::Claude, &Credentials::new())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn failed_swap_restores_live_credentials() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("auth.json");
+        fs::write(&first, "personal").unwrap();
+        // A file where the second slot's directory should be makes it unwritable.
+        let blocker = dir.path().join("blocked");
+        fs::write(&blocker, "").unwrap();
+        let slots = [
+            ("file", Slot::File(first.clone())),
+            ("other", Slot::File(blocker.join("creds.json"))),
+        ];
+        let live = Credentials::from([("file".to_string(), "personal".to_string())]);
+        let target = Credentials::from([
+            ("file".to_string(), "work".to_string()),
+            ("other".to_string(), "work".to_string()),
+        ]);
+
+        assert!(swap(&slots, &target, &live).is_err());
+        assert_eq!(fs::read_to_string(&first).unwrap(), "personal");
+        // Only auth.json and the blocker: no temporary file left behind.
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 2);
+
+        let target = Credentials::from([("file".to_string(), "work".to_string())]);
+        swap(&slots[..1], &target, &live).unwrap();
+        assert_eq!(fs::read_to_string(&first).unwrap(), "work");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&first).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode, private_file::PRIVATE_MODE);
+        }
+    }
+}
@@ -0,0 +1,626 @@
+//! Usage alerts with notification channels.
+//!
+//! Rules live in `alerts.toml` in the config directory and are edited with
+//! `caut alert add` and `caut alert remove`:
+//!
+//! ```toml
+//! [[alert]]
+//! id = 1
+//! provider = "claude"   # omit for every provider
+//! metric = "primary"
+//! threshold = 80.0
+//! channel = "webhook:https://hooks.slack.com/services/..."
+//! ```
+//!
+//! Every usage fetch (`caut usage`, watch mode, `caut serve`) evaluates the
+//! rules. A rule fires when a window's used percentage reaches its threshold
+//! and re-arms once usage drops back below it, so a window sitting above the
+//! threshold notifies once rather than on every refresh. Which rules have
+//! fired is kept in `alert-state.json` in the data directory, so separate
+//! `caut usage` runs share it.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::http;
+use crate::core::models::ProviderPayload;
+use crate::error::{CautError, Result};
+use crate::storage::private_file;
+
+/// Rate window an alert watches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertMetric {
+    Primary,
+    Secondary,
+    Tertiary,
+}
+
+impl AlertMetric {
+    /// Parse from a CLI argument.
+    #[must_use]
+    pub fn from_arg(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "primary" => Some(Self::Primary),
+            "secondary" => Some(Self::Secondary),
+            "tertiary" => Some(Self::Tertiary),
+            _ => None,
+        }
+    }
+
+    /// Lowercase label used in output.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Secondary => "secondary",
+            Self::Tertiary => "tertiary",
+        }
+    }
+
+    /// Used percentage of this window in `payload`, if it has one.
+    #[must_use]
+    pub fn value(self, payload: &ProviderPayload) -> Option<f64> {
+        let window = match self {
+            Self::Primary => payload.usage.primary.as_ref(),
+            Self::Secondary => payload.usage.secondary.as_ref(),
+            Self::Tertiary => payload.usage.tertiary.as_ref(),
+        };
+        window.map(|w| w.used_percent)
+    }
+}
+
+/// Where a fired alert is sent. Written as `webhook:<url>`,
+/// `command:<shell command>`, `email:<address>`, or `desktop`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum AlertChannel {
+    /// POST a JSON body to the URL.
+    Webhook(String),
+    /// Run through the shell without waiting for it.
+    Command(String),
+    /// Mail through the local `sendmail`.
+    Email(String),
+    /// A desktop notification (`osascript` on macOS, `notify-send` elsewhere).
+    Desktop,
+}
+
+impl AlertChannel {
+    /// Parse from a CLI argument or the alerts file.
+    ///
+    /// # Errors
+    /// Returns an error if the channel kind is unknown, a webhook URL is not
+    /// http(s), a command is empty, or an email address is malformed.
+    pub fn from_arg(s: &str) -> Result<Self> {
+        if s == "desktop" {
+            return Ok(Self::Desktop);
+        }
+        if let Some(url) = s.strip_prefix("webhook:") {
+            if !(url.starts_with("https://") || url.starts_with("http://")) {
+                return Err(CautError::Config(format!(
+                    "Invalid webhook URL \"{url}\": expected http:// or https://"
+                )));
+            }
+            return Ok(Self::Webhook(url.to_string()));
+        }
+        if let Some(command) = s.strip_prefix("command:") {
+            if command.trim().is_empty() {
+                return Err(CautError::Config("Alert command is empty".to_string()));
+            }
+            return Ok(Self::Command(command.to_string()));
+        }
+        if let Some(address) = s.strip_prefix("email:") {
+            if !is_valid_email(address) {
+                return Err(CautError::Config(format!(
+                    "Invalid email address \"{address}\""
+                )));
+            }
+            return Ok(Self::Email(address.to_string()));
+        }
+        Err(CautError::Config(format!(
+            "Invalid alert channel \"{s}\". Use webhook:<url>, command:<command>, email:<address>, or desktop"
+        )))
+    }
+}
+
+impl TryFrom<String> for AlertChannel {
+    type Error = CautError;
+
+    fn try_from(s: String) -> Result<Self> {
+        Self::from_arg(&s)
+    }
+}
+
+impl From<AlertChannel> for String {
+    fn from(channel: AlertChannel) -> Self {
+        channel.to_string()
+    }
+}
+
+impl std::fmt::Display for AlertChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Webhook(url) => write!(f, "webhook:{url}"),
+            Self::Command(command) => write!(f, "command:{command}"),
+            Self::Email(address) => write!(f, "email:{address}"),
+            Self::Desktop => f.write_str("desktop"),
+        }
+    }
+}
+
+/// One alert rule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertRule {
+    pub id: u32,
+    /// Provider CLI name; `None` watches every provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    pub metric: AlertMetric,
+    /// Used percentage at which the alert fires.
+    pub threshold: f64,
+    pub channel: AlertChannel,
+}
+
+impl AlertRule {
+    /// Provider label for output.
+    #[must_use]
+    pub fn provider_label(&self) -> &str {
+        self.provider.as_deref().unwrap_or("all")
+    }
+}
+
+/// Contents of `alerts.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AlertsFile {
+    #[serde(default, rename = "alert")]
+    pub alerts: Vec<AlertRule>,
+}
+
+impl AlertsFile {
+    /// Load the alerts file, or no alerts if it doesn't exist.
+    ///
+    /// # Errors
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        toml::from_str(&content)
+            .map_err(|e| CautError::Config(format!("Invalid alerts file {}: {e}", path.display())))
+    }
+
+    /// Write the alerts file owner-only, creating its directory if needed.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails or the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| CautError::Config(format!("Failed to serialize alerts: {e}")))?;
+        private_file::write(path, content)?;
+        Ok(())
+    }
+
+    /// Add a rule with the next free id and return it.
+    pub fn add(
+        &mut self,
+        provider: Option<String>,
+        metric: AlertMetric,
+        threshold: f64,
+        channel: AlertChannel,
+    ) -> AlertRule {
+        let rule = AlertRule {
+            id: self.alerts.iter().map(|a| a.id).max().unwrap_or(0) + 1,
+            provider,
+            metric,
+            threshold,
+            channel,
+        };
+        self.alerts.push(rule.clone());
+        rule
+    }
+
+    /// Remove the rule with `id`. Returns whether it existed.
+    pub fn remove(&mut self, id: u32) -> bool {
+        let before = self.alerts.len();
+        self.alerts.retain(|a| a.id != id);
+        self.alerts.len() != before
+    }
+}
+
+/// Which rule/provider pairs have fired and not yet re-armed, keyed by
+/// `"<id>:<provider>"`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AlertState {
+    #[serde(default)]
+    pub fired: BTreeMap<String, DateTime<Utc>>,
+}
+
+impl AlertState {
+    /// Load the state file; a missing or unreadable one starts fresh.
+    #[must_use]
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the state file owner-only.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        private_file::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+/// An alert that fired.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiredAlert {
+    pub id: u32,
+    pub provider: String,
+    pub metric: AlertMetric,
+    pub threshold: f64,
+    pub value: f64,
+    pub fired_at: DateTime<Utc>,
+    #[serde(skip)]
+    pub channel: AlertChannel,
+}
+
+impl FiredAlert {
+    /// One-line description, e.g.
+    /// "caut: claude primary usage at 82% (alert at 80%)".
+    #[must_use]
+    pub fn message(&self) -> String {
+        format!(
+            "caut: {} {} usage at {:.0}% (alert at {:.0}%)",
+            self.provider,
+            self.metric.label(),
+            self.value,
+            self.threshold
+        )
+    }
+}
+
+/// Check `payloads` against `rules`, updating `state`, and return the alerts
+/// that crossed their threshold since the last evaluation.
+pub fn evaluate(
+    rules: &[AlertRule],
+    payloads: &[ProviderPayload],
+    state: &mut AlertState,
+    now: DateTime<Utc>,
+) -> Vec<FiredAlert> {
+    let mut fired = Vec::new();
+    for rule in rules {
+        for payload in payloads {
+            if rule
+                .provider
+                .as_deref()
+                .is_some_and(|p| p != payload.provider)
+            {
+                continue;
+            }
+            let Some(value) = rule.metric.value(payload) else {
+                continue;
+            };
+            let key = format!("{}:{}", rule.id, payload.provider);
+            if value < rule.threshold {
+                state.fired.remove(&key);
+                continue;
+            }
+            if state.fired.contains_key(&key) {
+                continue;
+            }
+            state.fired.insert(key, now);
+            fired.push(FiredAlert {
+                id: rule.id,
+                provider: payload.provider.clone(),
+                metric: rule.metric,
+                threshold: rule.threshold,
+                value,
+                fired_at: now,
+                channel: rule.channel.clone(),
+            });
+        }
+    }
+    fired
+}
+
+/// Send `alert` to its channel.
+///
+/// # Errors
+/// Returns an error if delivery fails; see [`send`].
+pub async fn deliver(alert: &FiredAlert) -> Result<()> {
+    let message = alert.message();
+    let env = [
+        ("CAUT_ALERT_ID", alert.id.to_string()),
+        ("CAUT_PROVIDER", alert.provider.clone()),
+        ("CAUT_ALERT_METRIC", alert.metric.label().to_string()),
+        ("CAUT_ALERT_THRESHOLD", alert.threshold.to_string()),
+        ("CAUT_ALERT_VALUE", format!("{:.1}", alert.value)),
+        ("CAUT_ALERT_MESSAGE", message.clone()),
+    ];
+    send(
+        &alert.channel,
+        &message,
+        &message,
+        serde_json::to_value(alert)?,
+        &env,
+    )
+    .await
+}
+
+/// Send a message to `channel`.
+///
+/// `subject` is the email subject and desktop notification text, and `text`
+/// the full message. Webhooks receive `body` with `text` added; commands get
+/// `env`. Webhooks and email are awaited so one-shot runs deliver before
+/// exiting; commands and desktop notifications are started without waiting.
+///
+/// # Errors
+/// Returns an error if the webhook request fails or is rejected, `sendmail`
+/// is missing or fails, or the command cannot be started.
+pub async fn send(
+    channel: &AlertChannel,
+    subject: &str,
+    text: &str,
+    mut body: serde_json::Value,
+    env: &[(&str, String)],
+) -> Result<()> {
+    match channel {
+        AlertChannel::Webhook(url) => {
+            // Slack and Mattermost incoming webhooks display `text`.
+            body["text"] = serde_json::Value::String(text.to_string());
+            let response = http::default_client()?
+                .post(url)
+                .json(&body)
+                .send()
+                .await
+                .map_err(|e| CautError::Network(e.to_string()))?;
+            if !response.status().is_success() {
+                return Err(CautError::Network(format!(
+                    "HTTP {} from webhook",
+                    response.status()
+                )));
+            }
+        }
+        AlertChannel::Command(command) => {
+            let (shell, flag) = if cfg!(windows) {
+                ("cmd", "/C")
+            } else {
+                ("sh", "-c")
+            };
+            tokio::process::Command::new(shell)
+                .args([flag, command])
+                .envs(env.iter().map(|(key, value)| (*key, value)))
+                .stdin(std::process::Stdio::null())
+                .spawn()?;
+        }
+        AlertChannel::Email(address) => send_mail(address, subject, text).await?,
+        AlertChannel::Desktop => {
+            desktop_notification(subject)
+                .stdin(std::process::Stdio::null())
+                .spawn()?;
+        }
+    }
+    Ok(())
+}
+
+/// The command that shows `subject` as a desktop notification.
+///
+/// On macOS the text reaches the script as an argument of its run handler
+/// rather than being spliced into the AppleScript source, so quotes and
+/// backslashes in it are shown as-is.
+fn desktop_notification(subject: &str) -> tokio::process::Command {
+    if cfg!(target_os = "macos") {
+        let mut command = tokio::process::Command::new("osascript");
+        command.args([
+            "-e",
+            "on run argv",
+            "-e",
+            "display notification (item 1 of argv) with title \"caut\"",
+            "-e",
+            "end run",
+            subject,
+        ]);
+        command
+    } else {
+        let mut command = tokio::process::Command::new("notify-send");
+        command.args(["caut", subject]);
+        command
+    }
+}
+
+/// Hand a plain-text mail to `sendmail -t`, which cron hosts usually have
+/// even when nothing else is set up for mail.
+async fn send_mail(address: &str, subject: &str, text: &str) -> Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let sendmail = ["/usr/sbin/sendmail", "/usr/lib/sendmail"]
+        .into_iter()
+        .find(|path| Path::new(path).exists())
+        .unwrap_or("sendmail");
+    let mut child = tokio::process::Command::new(sendmail)
+        .arg("-t")
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| CautError::Config(format!("Cannot run sendmail for email: {e}")))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(mail_message(address, subject, text).as_bytes())
+            .await?;
+    }
+    let status = child.wait().await?;
+    if !status.success() {
+        return Err(CautError::Config(format!(
+            "sendmail exited with {status} sending to {address}"
+        )));
+    }
+    Ok(())
+}
+
+/// Whether `address` is a single plain `user@host.tld` address.
+///
+/// The address goes into the `To:` header of a message `sendmail -t` reads
+/// its recipients from, so CR, LF, other whitespace, and the separators of
+/// an address list would let it add headers or recipients.
+fn is_valid_email(address: &str) -> bool {
+    let forbidden = |c: char| c.is_whitespace() || c.is_control() || ",;<>\"()".contains(c);
+    address
+        .split_once('@')
+        .is_some_and(|(user, host)| !user.is_empty() && host.contains('.') && !host.contains('@'))
+        && !address.chars().any(forbidden)
+}
+
+/// An RFC 5322 message for `sendmail -t`.
+fn mail_message(address: &str, subject: &str, text: &str) -> String {
+    let address: String = address.chars().filter(|c| !c.is_control()).collect();
+    let subject: String = subject.chars().filter(|c| !c.is_control()).collect();
+    format!(
+        "To: {address}\nSubject: {subject}\nContent-Type: text/plain; charset=utf-8\n\n{text}\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::RateWindow;
+    use crate::test_utils::make_test_provider_payload;
+
+    fn payload(provider: &str, primary: f64) -> ProviderPayload {
+        let mut payload = make_test_provider_payload(provider, "oauth");
+        payload.usage.primary = Some(RateWindow::new(primary));
+        payload
+    }
+
+    #[test]
+    fn fires_once_per_crossing_and_rearms() {
+        let mut file = AlertsFile::default();
+        file.add(
+            Some("claude".to_string()),
+            AlertMetric::Primary,
+            80.0,
+            AlertChannel::Desktop,
+        );
+        let mut state = AlertState::default();
+        let now = Utc::now();
+
+        let fired = evaluate(
+            &file.alerts,
+            &[payload("claude", 85.0), payload("codex", 95.0)],
+            &mut state,
+            now,
+        );
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].provider, "claude");
+        assert_eq!(
+            fired[0].message(),
+            "caut: claude primary usage at 85% (alert at 80%)"
+        );
+
+        // Still above: no repeat. Below: re-armed. Above again: fires.
+        assert!(evaluate(&file.alerts, &[payload("claude", 90.0)], &mut state, now).is_empty());
+        assert!(evaluate(&file.alerts, &[payload("claude", 10.0)], &mut state, now).is_empty());
+        assert_eq!(
+            evaluate(&file.alerts, &[payload("claude", 81.0)], &mut state, now).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn alerts_file_round_trips_channels() {
+        let mut file = AlertsFile::default();
+        let channel = AlertChannel::from_arg("webhook:https://example.com/hook").unwrap();
+        assert_eq!(file.add(None, AlertMetric::Secondary, 90.0, channel).id, 1);
+        let command = AlertChannel::from_arg("command:say usage").unwrap();
+        assert_eq!(
+            file.add(
+                Some("codex".to_string()),
+                AlertMetric::Primary,
+                50.0,
+                command
+            )
+            .id,
+            2
+        );
+
+        let saved = toml::to_string_pretty(&file).unwrap();
+        assert!(saved.contains("channel = \"webhook:https://example.com/hook\""));
+        let reloaded: AlertsFile = toml::from_str(&saved).unwrap();
+        assert_eq!(reloaded.alerts, file.alerts);
+
+        assert!(file.remove(1));
+        assert!(!file.remove(1));
+        assert!(AlertChannel::from_arg("webhook:ftp://x").is_err());
+        assert!(AlertChannel::from_arg("pager").is_err());
+        assert_eq!(
+            AlertChannel::from_arg("email:ops@example.com")
+                .unwrap()
+                .to_string(),
+            "email:ops@example.com"
+        );
+    }
+
+    #[test]
+    fn email_addresses_cannot_add_headers_or_recipients() {
+        for bad in [
+            "ops@example.com\nBcc: x@y.z",
+            "ops@example.com\r\nBcc: x@y.z",
+            "ops@example.com\r",
+            "ops@example.com,x@y.z",
+            "Ops <ops@example.com>",
+            "ops@x@example.com",
+            "@example.com",
+            "ops@localhost",
+        ] {
+            assert!(
+                AlertChannel::from_arg(&format!("email:{bad}")).is_err(),
+                "{bad:?}"
+            );
+        }
+        assert!(
+            mail_message("ops@example.com\r\nBcc: x@y.z", "usage\nBcc: x@y.z", "hi")
+                .starts_with("To: ops@example.comBcc: x@y.z\nSubject: usageBcc: x@y.z\n")
+        );
+    }
+
+    #[test]
+    fn desktop_notification_passes_text_as_an_argument() {
+        let subject = r#"caut: "claude" at 85% \ done"#;
+        let command = desktop_notification(subject);
+        let args: Vec<_> = command.as_std().get_args().collect();
+        assert_eq!(args.last().copied(), Some(std::ffi::OsStr::new(subject)));
+        assert!(
+            args[..args.len() - 1]
+                .iter()
+                .all(|arg| !arg.to_string_lossy().contains("85%"))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn saves_rules_and_state_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let rules = dir.path().join("alerts.toml");
+        let state = dir.path().join("alert-state.json");
+        let mut file = AlertsFile::default();
+        file.add(None, AlertMetric::Primary, 80.0, AlertChannel::Desktop);
+        file.save(&rules).unwrap();
+        AlertState::default().save(&state).unwrap();
+
+        for path in [&rules, &state] {
+            let mode = std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode, private_file::PRIVATE_MODE, "{}", path.display());
+        }
+        assert_eq!(AlertsFile::load(&rules).unwrap().alerts, file.alerts);
+    }
+}
@@ -27,10 +27,17 @@
 //! [claude.override]
 //! daily_cost_usd = 5.0  # Temporary stricter limit
 //! ```
+//!
+//! The file is `budgets.toml` in the config directory; `caut budget set`
+//! and `caut budget remove` edit it.
 
 use crate::core::Provider;
+use crate::core::models::{CostDailyEntry, CostPayload};
+use crate::error::{CautError, Result};
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::path::Path;
 
 // =============================================================================
 // Budget Priority
@@ -88,19 +95,25 @@ impl std::fmt::Display for BudgetPriority {
 #[serde(default)]
 pub struct BudgetLimits {
     /// Daily cost limit in USD.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub daily_cost_usd: Option<f64>,
     /// Weekly cost limit in USD.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub weekly_cost_usd: Option<f64>,
     /// Monthly cost limit in USD.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub monthly_cost_usd: Option<f64>,
     /// Daily usage percentage limit (0-100).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub daily_usage_percent: Option<f64>,
     /// Weekly usage percentage limit (0-100).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub weekly_usage_percent: Option<f64>,
     /// Daily credit limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub daily_credits: Option<f64>,
     /// Alert thresholds as percentages (e.g., [50, 75, 90]).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub alert_at_percent: Vec<u8>,
 }
 
@@ -116,6 +129,49 @@ impl BudgetLimits {
             && self.daily_credits.is_none()
             && self.alert_at_percent.is_empty()
     }
+
+    /// The cost limit for `period`.
+    #[must_use]
+    pub const fn cost_limit(&self, period: BudgetPeriod) -> Option<f64> {
+        match period {
+            BudgetPeriod::Daily => self.daily_cost_usd,
+            BudgetPeriod::Weekly => self.weekly_cost_usd,
+            BudgetPeriod::Monthly => self.monthly_cost_usd,
+        }
+    }
+
+    const fn cost_limit_mut(&mut self, period: BudgetPeriod) -> &mut Option<f64> {
+        match period {
+            BudgetPeriod::Daily => &mut self.daily_cost_usd,
+            BudgetPeriod::Weekly => &mut self.weekly_cost_usd,
+            BudgetPeriod::Monthly => &mut self.monthly_cost_usd,
+        }
+    }
+}
+
+/// Period a cost limit covers. Weeks start on Monday; months are calendar
+/// months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetPeriod {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl BudgetPeriod {
+    /// All periods, shortest first.
+    pub const ALL: [Self; 3] = [Self::Daily, Self::Weekly, Self::Monthly];
+
+    /// Lowercase label used in output.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Monthly => "monthly",
+        }
+    }
 }
 
 // =============================================================================
@@ -597,6 +653,124 @@ pub fn check_budget_violations(
     violations
 }
 
+// =============================================================================
+// Budget Status
+// =============================================================================
+
+/// Spend against one cost limit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetStatus {
+    pub provider: Provider,
+    pub period: BudgetPeriod,
+    pub limit_usd: f64,
+    /// Spend so far this period; unknown for providers without local cost
+    /// data.
+    pub spent_usd: Option<f64>,
+    pub percent_used: Option<f64>,
+    pub exceeded: bool,
+    /// Where the limit came from.
+    pub source: BudgetPriority,
+}
+
+/// Spend for today, this week, and this month from daily cost entries.
+#[must_use]
+pub fn period_spend(daily: &[CostDailyEntry], today: NaiveDate) -> CurrentUsage {
+    let week_start =
+        today - chrono::Duration::days(i64::from(today.weekday().num_days_from_monday()));
+    let mut usage = CurrentUsage {
+        daily_cost_usd: Some(0.0),
+        weekly_cost_usd: Some(0.0),
+        monthly_cost_usd: Some(0.0),
+        ..CurrentUsage::default()
+    };
+    for entry in daily {
+        let (Ok(day), Some(cost)) = (
+            NaiveDate::parse_from_str(&entry.date, "%Y-%m-%d"),
+            entry.total_cost,
+        ) else {
+            continue;
+        };
+        if day > today {
+            continue;
+        }
+        let add = |total: &mut Option<f64>| *total = total.map(|t| t + cost);
+        if day == today {
+            add(&mut usage.daily_cost_usd);
+        }
+        if day >= week_start {
+            add(&mut usage.weekly_cost_usd);
+        }
+        if day.year() == today.year() && day.month() == today.month() {
+            add(&mut usage.monthly_cost_usd);
+        }
+    }
+    usage
+}
+
+/// Status of each cost limit in `budget`, measured against `usage`.
+#[must_use]
+pub fn budget_status(budget: &ResolvedBudget, usage: Option<&CurrentUsage>) -> Vec<BudgetStatus> {
+    BudgetPeriod::ALL
+        .into_iter()
+        .filter_map(|period| {
+            let limit_usd = budget.limits.cost_limit(period)?;
+            let (spent_usd, source) = match period {
+                BudgetPeriod::Daily => (
+                    usage.and_then(|u| u.daily_cost_usd),
+                    budget.sources.daily_cost_usd,
+                ),
+                BudgetPeriod::Weekly => (
+                    usage.and_then(|u| u.weekly_cost_usd),
+                    budget.sources.weekly_cost_usd,
+                ),
+                BudgetPeriod::Monthly => (
+                    usage.and_then(|u| u.monthly_cost_usd),
+                    budget.sources.monthly_cost_usd,
+                ),
+            };
+            let percent_used = spent_usd.map(|spent| {
+                if limit_usd > 0.0 {
+                    spent / limit_usd * 100.0
+                } else {
+                    100.0
+                }
+            });
+            Some(BudgetStatus {
+                provider: budget.provider,
+                period,
+                limit_usd,
+                spent_usd,
+                percent_used,
+                exceeded: spent_usd.is_some_and(|spent| spent >= limit_usd),
+                source: source.unwrap_or(BudgetPriority::Global),
+            })
+        })
+        .collect()
+}
+
+/// Status of every budgeted provider's cost limits, with spend from
+/// `costs` (local cost scans) where a provider has one.
+#[must_use]
+pub fn statuses_for(
+    file: &BudgetFileConfig,
+    providers: &[Provider],
+    costs: &[CostPayload],
+    today: NaiveDate,
+) -> Vec<BudgetStatus> {
+    let configs = file.to_configs();
+    providers
+        .iter()
+        .flat_map(|provider| {
+            let usage = costs
+                .iter()
+                .find(|c| c.provider == provider.cli_name())
+                .map(|c| period_spend(&c.daily, today));
+            budget_status(&resolve_budget(*provider, &configs), usage.as_ref())
+        })
+        .collect()
+}
+
 // =============================================================================
 // TOML Configuration Support
 // =============================================================================
@@ -609,7 +783,7 @@ pub struct BudgetFileConfig {
     pub global: Option<BudgetLimits>,
     /// Per-provider configurations keyed by CLI name.
     #[serde(flatten)]
-    pub providers: HashMap<String, ProviderBudgetConfig>,
+    pub providers: BTreeMap<String, ProviderBudgetConfig>,
 }
 
 /// Provider-specific budget configuration with optional override section.
@@ -620,11 +794,114 @@ pub struct ProviderBudgetConfig {
     #[serde(flatten)]
     pub limits: BudgetLimits,
     /// Override limits (highest priority).
-    #[serde(rename = "override")]
+    #[serde(rename = "override", skip_serializing_if = "Option::is_none")]
     pub override_limits: Option<BudgetLimits>,
 }
 
 impl BudgetFileConfig {
+    /// Load a budgets file, or an empty config if it doesn't exist.
+    ///
+    /// # Errors
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        toml::from_str(&content)
+            .map_err(|e| CautError::Config(format!("Invalid budgets file {}: {e}", path.display())))
+    }
+
+    /// Write the budgets file owner-only, creating its directory if needed.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails or the file cannot be written.
+    #[cfg(feature = "native")]
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| CautError::Config(format!("Failed to serialize budgets: {e}")))?;
+        crate::storage::private_file::write(path, content)?;
+        Ok(())
+    }
+
+    /// Set the cost limit for `period`, for `provider` or globally (`None`).
+    pub fn set_cost_limit(&mut self, provider: Option<Provider>, period: BudgetPeriod, usd: f64) {
+        let limits = match provider {
+            None => self.global.get_or_insert_with(BudgetLimits::default),
+            Some(provider) => {
+                let key = self.provider_key(provider);
+                &mut self.providers.entry(key).or_default().limits
+            }
+        };
+        *limits.cost_limit_mut(period) = Some(usd);
+    }
+
+    /// Remove the cost limits for `periods`, for `provider` or globally
+    /// (`None`). Providers left without limits are dropped from the file.
+    /// Returns whether anything was removed.
+    pub fn remove_cost_limits(
+        &mut self,
+        provider: Option<Provider>,
+        periods: &[BudgetPeriod],
+    ) -> bool {
+        let key = provider.map(|p| self.provider_key(p));
+        let limits = match &key {
+            None => self.global.as_mut(),
+            Some(key) => self.providers.get_mut(key).map(|c| &mut c.limits),
+        };
+        let Some(limits) = limits else {
+            return false;
+        };
+        let mut removed = false;
+        for period in periods {
+            removed |= limits.cost_limit_mut(*period).take().is_some();
+        }
+
+        match key {
+            None if self.global.as_ref().is_some_and(BudgetLimits::is_empty) => {
+                self.global = None;
+            }
+            Some(key)
+                if self.providers.get(&key).is_some_and(|c| {
+                    c.limits.is_empty()
+                        && c.override_limits
+                            .as_ref()
+                            .is_none_or(BudgetLimits::is_empty)
+                }) =>
+            {
+                self.providers.remove(&key);
+            }
+            _ => {}
+        }
+        removed
+    }
+
+    /// Providers with limits of their own, plus every provider with local
+    /// cost data when global limits are set.
+    #[must_use]
+    pub fn budgeted_providers(&self) -> Vec<Provider> {
+        let named: Vec<Provider> = self
+            .providers
+            .keys()
+            .filter_map(|name| parse_provider_name(name))
+            .collect();
+        let global = self.global.as_ref().is_some_and(|g| !g.is_empty());
+        Provider::ALL
+            .iter()
+            .copied()
+            .filter(|p| named.contains(p) || (global && p.supports_cost_scan()))
+            .collect()
+    }
+
+    /// The key `provider` is stored under, reusing an existing alias.
+    fn provider_key(&self, provider: Provider) -> String {
+        self.providers
+            .keys()
+            .find(|name| parse_provider_name(name) == Some(provider))
+            .cloned()
+            .unwrap_or_else(|| provider.cli_name().to_string())
+    }
+
     /// Convert file config to a list of `BudgetConfig` entries.
     #[must_use]
     pub fn to_configs(&self) -> Vec<BudgetConfig> {
@@ -689,6 +966,26 @@ fn parse_provider_name(name: &str) -> Option<Provider> {
         "vertexai" | "vertex_ai" => Some(Provider::VertexAI),
         "jetbrains" | "jetbrainsai" => Some(Provider::JetBrainsAI),
         "amp" => Some(Provider::Amp),
+        "perplexity" => Some(Provider::Perplexity),
+        "groq" => Some(Provider::Groq),
+        "together" => Some(Provider::Together),
+        "cohere" => Some(Provider::Cohere),
+        "fireworks" => Some(Provider::Fireworks),
+        "windsurf" => Some(Provider::Windsurf),
+        "aider" => Some(Provider::Aider),
+        "cline" | "roo" | "roo-code" => Some(Provider::Cline),
+        "openrouter" => Some(Provider::OpenRouter),
+        "amazonq" | "amazon-q" => Some(Provider::AmazonQ),
+        "cody" => Some(Provider::Cody),
+        "deepseek" => Some(Provider::DeepSeek),
+        "xai" | "grok" => Some(Provider::Xai),
+        "continue" => Some(Provider::Continue),
+        "ollama" => Some(Provider::Ollama),
+        "qwen" => Some(Provider::Qwen),
+        "goose" => Some(Provider::Goose),
+        "azure-openai" => Some(Provider::AzureOpenAI),
+        "bedrock" => Some(Provider::Bedrock),
+        "litellm" => Some(Provider::LiteLLM),
         _ => None,
     }
 }
@@ -931,7 +1228,7 @@ mod tests {
 
     #[test]
     fn test_budget_file_config_to_configs() {
-        let mut providers = HashMap::new();
+        let mut providers = BTreeMap::new();
         providers.insert(
             "claude".to_string(),
             ProviderBudgetConfig {
@@ -957,4 +1254,92 @@ mod tests {
         let configs = file_config.to_configs();
         assert_eq!(configs.len(), 3); // global + claude + claude.override
     }
+
+    #[test]
+    fn test_set_and_remove_cost_limits_round_trip() {
+        let mut file: BudgetFileConfig =
+            toml::from_str("[Claude]\nweekly_cost_usd = 50.0\n").unwrap();
+        file.set_cost_limit(Some(Provider::Claude), BudgetPeriod::Daily, 10.0);
+        file.set_cost_limit(None, BudgetPeriod::Monthly, 200.0);
+
+        // The existing key is reused rather than adding a second claude entry.
+        assert_eq!(file.providers.len(), 1);
+        let saved = toml::to_string_pretty(&file).unwrap();
+        let reloaded: BudgetFileConfig = toml::from_str(&saved).unwrap();
+        assert_eq!(
+            reloaded.providers["Claude"].limits.daily_cost_usd,
+            Some(10.0)
+        );
+        assert_eq!(reloaded.global.unwrap().monthly_cost_usd, Some(200.0));
+
+        assert!(file.remove_cost_limits(Some(Provider::Claude), &BudgetPeriod::ALL));
+        assert!(file.providers.is_empty());
+        assert!(!file.remove_cost_limits(Some(Provider::Codex), &BudgetPeriod::ALL));
+        assert!(file.remove_cost_limits(None, &[BudgetPeriod::Monthly]));
+        assert!(file.global.is_none());
+    }
+
+    #[cfg(all(feature = "native", unix))]
+    #[test]
+    fn test_save_writes_owner_only_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("budgets.toml");
+        let mut file = BudgetFileConfig::default();
+        file.set_cost_limit(None, BudgetPeriod::Daily, 10.0);
+        file.save(&path).unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o600);
+        let reloaded = BudgetFileConfig::load(&path).unwrap();
+        assert_eq!(reloaded.global.unwrap().daily_cost_usd, Some(10.0));
+    }
+
+    #[test]
+    fn test_budget_status_from_daily_costs() {
+        let entry = |date: &str, cost: f64| CostDailyEntry {
+            date: date.to_string(),
+            input_tokens: None,
+            output_tokens: None,
+            cache_read_tokens: None,
+            cache_creation_tokens: None,
+            total_tokens: None,
+            total_cost: Some(cost),
+            models_used: None,
+        };
+        // Wednesday 2026-09-16: the week starts Monday the 14th.
+        let today = NaiveDate::from_ymd_opt(2026, 9, 16).unwrap();
+        let daily = vec![
+            entry("2026-08-31", 100.0),
+            entry("2026-09-01", 3.0),
+            entry("2026-09-13", 4.0),
+            entry("2026-09-14", 5.0),
+            entry("2026-09-16", 6.0),
+        ];
+        let usage = period_spend(&daily, today);
+        assert_eq!(usage.daily_cost_usd, Some(6.0));
+        assert_eq!(usage.weekly_cost_usd, Some(11.0));
+        assert_eq!(usage.monthly_cost_usd, Some(18.0));
+
+        let budget = resolve_budget(
+            Provider::Claude,
+            &[BudgetConfig::global(BudgetLimits {
+                daily_cost_usd: Some(4.0),
+                weekly_cost_usd: Some(20.0),
+                ..Default::default()
+            })],
+        );
+        let statuses = budget_status(&budget, Some(&usage));
+        assert_eq!(statuses.len(), 2);
+        assert!(statuses[0].exceeded);
+        assert_eq!(statuses[0].percent_used, Some(150.0));
+        assert_eq!(statuses[1].period, BudgetPeriod::Weekly);
+        assert!(!statuses[1].exceeded);
+        assert_eq!(statuses[1].source, BudgetPriority::Global);
+
+        let unknown = budget_status(&budget, None);
+        assert_eq!(unknown[0].spent_usd, None);
+        assert!(!unknown[0].exceeded);
+    }
 }
@@ -0,0 +1,245 @@
+//! Bug reports for internal errors.
+//!
+//! When a command fails with an internal (`CAUT-X*`) error, `main` saves a
+//! [`CrashRecord`] of it. `caut report-bug` turns the latest record into a
+//! pre-filled GitHub issue. Everything that leaves the machine goes through
+//! [`redact`] first: home directories, email addresses, and anything that
+//! looks like a key or token are replaced, and the values of secret-bearing
+//! flags are dropped from the recorded command line.
+
+use std::path::Path;
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CautError, ErrorCategory, Result};
+
+/// Where issues are filed.
+pub const ISSUES_URL: &str =
+    "https://github.com/Dicklesworthstone/coding_agent_usage_tracker/issues/new";
+
+/// Flags whose values are never recorded.
+const SECRET_FLAGS: &[&str] = &[
+    "--api-key",
+    "--cookie",
+    "--key",
+    "--password",
+    "--secret",
+    "--token",
+];
+
+static EMAIL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").unwrap());
+
+/// Known key prefixes, JWTs, and long mixed-case-and-digit runs.
+static TOKEN_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\b(?:sk-[A-Za-z0-9_-]{8,}|(?:gh[pousr]|xai|AIza)[A-Za-z0-9_-]{10,}|eyJ[A-Za-z0-9_-]+\.[A-Za-z0-9_.-]+|[A-Za-z0-9_-]*[0-9][A-Za-z0-9_-]*[A-Za-z][A-Za-z0-9_-]{22,})\b",
+    )
+    .unwrap()
+});
+
+/// An internal error, as saved for `caut report-bug`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashRecord {
+    pub code: String,
+    pub message: String,
+    /// The failing command line, redacted.
+    pub command: Vec<String>,
+    pub version: String,
+    pub os: String,
+    pub arch: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl CrashRecord {
+    /// Record `error` from the command line `argv`, if it is an internal
+    /// error. Other categories are user-fixable and have their own
+    /// suggestions.
+    #[must_use]
+    pub fn capture(error: &CautError, argv: &[String]) -> Option<Self> {
+        if error.category() != ErrorCategory::Internal {
+            return None;
+        }
+        Some(Self {
+            code: error.error_code().to_string(),
+            message: redact(&error.to_string()),
+            command: redact_args(argv),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            occurred_at: Utc::now(),
+        })
+    }
+
+    /// Save the record, replacing any earlier one.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Load the saved record, if there is one.
+    ///
+    /// # Errors
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        match std::fs::read(path) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// A GitHub issue ready to file.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueDraft {
+    pub title: String,
+    pub body: String,
+    /// The new-issue URL with title and body filled in.
+    pub url: String,
+}
+
+impl IssueDraft {
+    /// Draft an issue for `record`, or a blank bug report without one.
+    #[must_use]
+    pub fn new(record: Option<&CrashRecord>) -> Self {
+        let (title, body) = record.map_or_else(
+            || {
+                (
+                    "Bug: ".to_string(),
+                    format!(
+                        "**What happened**\n\n\n**Steps to reproduce**\n\n\n**Environment**\n\n- caut {}\n- {} ({})\n",
+                        env!("CARGO_PKG_VERSION"),
+                        std::env::consts::OS,
+                        std::env::consts::ARCH
+                    ),
+                )
+            },
+            |record| {
+                let command = record.command.join(" ");
+                let summary: String = record.message.chars().take(80).collect();
+                (
+                    format!("{} in `{command}`: {summary}", record.code),
+                    format!(
+                        "**What happened**\n\n`{command}` failed with an internal error.\n\n```\n{}: {}\n```\n\n**Steps to reproduce**\n\n\n**Environment**\n\n- caut {}\n- {} ({})\n- Occurred at {}\n\n_Drafted by `caut report-bug`; paths, emails, and tokens were redacted._\n",
+                        record.code,
+                        record.message,
+                        record.version,
+                        record.os,
+                        record.arch,
+                        record.occurred_at.to_rfc3339()
+                    ),
+                )
+            },
+        );
+        let url = reqwest::Url::parse_with_params(
+            ISSUES_URL,
+            &[
+                ("title", title.as_str()),
+                ("body", body.as_str()),
+                ("labels", "bug"),
+            ],
+        )
+        .map_or_else(|_| ISSUES_URL.to_string(), String::from);
+        Self { title, body, url }
+    }
+}
+
+/// Replace home directories, email addresses, and token-like strings.
+#[must_use]
+pub fn redact(text: &str) -> String {
+    let mut out = text.to_string();
+    if let Some(home) = directories::BaseDirs::new()
+        .map(|d| d.home_dir().display().to_string())
+        .filter(|h| h.len() > 1)
+    {
+        out = out.replace(&home, "~");
+    }
+    let out = EMAIL_REGEX.replace_all(&out, "<email>");
+    TOKEN_REGEX.replace_all(&out, "<redacted>").into_owned()
+}
+
+/// Redact a command line, dropping the values of secret-bearing flags.
+fn redact_args(argv: &[String]) -> Vec<String> {
+    let mut out = Vec::with_capacity(argv.len());
+    let mut hide_next = false;
+    for (i, arg) in argv.iter().enumerate() {
+        if hide_next {
+            out.push("<redacted>".to_string());
+            hide_next = false;
+            continue;
+        }
+        if i == 0 {
+            // The binary path says nothing useful and may name the user.
+            out.push("caut".to_string());
+            continue;
+        }
+        if let Some((flag, _)) = arg.split_once('=')
+            && SECRET_FLAGS.contains(&flag)
+        {
+            out.push(format!("{flag}=<redacted>"));
+            continue;
+        }
+        hide_next = SECRET_FLAGS.contains(&arg.as_str());
+        out.push(redact(arg));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_only_internal_errors_and_redacts_them() {
+        let argv: Vec<String> = [
+            "/usr/local/bin/caut",
+            "usage",
+            "--token",
+            "abc",
+            "--api-key=xyz",
+            "--account",
+            "me@example.com",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+        let error = CautError::Other(anyhow::anyhow!(
+            "bad response for me@example.com with sk-ant-REDACTED"
+        ));
+
+        let record = CrashRecord::capture(&error, &argv).expect("internal error");
+        assert_eq!(record.code, "CAUT-X099");
+        assert_eq!(
+            record.command,
+            [
+                "caut",
+                "usage",
+                "--token",
+                "<redacted>",
+                "--api-key=<redacted>",
+                "--account",
+                "<email>"
+            ]
+        );
+        assert!(!record.message.contains("example.com"));
+        assert!(!record.message.contains("abcdefghijklmnop"));
+        assert!(CrashRecord::capture(&CautError::Config("x".into()), &argv).is_none());
+
+        let draft = IssueDraft::new(Some(&record));
+        assert!(draft.title.starts_with("CAUT-X099 in `caut usage"));
+        assert!(draft.url.starts_with(ISSUES_URL));
+        assert!(!draft.url.contains(' '));
+    }
+}
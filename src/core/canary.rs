@@ -0,0 +1,204 @@
+//! Canary self-test of fetch strategies.
+//!
+//! A usage fetch stops at the first strategy that works, so a broken
+//! fallback (say, the web source after a site change) goes unnoticed until
+//! the primary path breaks too. The canary runs every available strategy
+//! once a week from `caut serve`, records how each did, and lets
+//! `caut doctor` report "claude-web has been failing for 6 days" while there
+//! is still time to fix it.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::fetch_plan::FetchAttempt;
+use super::pipeline;
+use super::provider::Provider;
+use crate::error::Result;
+
+/// Days between canary runs.
+pub const CANARY_INTERVAL_DAYS: i64 = 7;
+
+/// The last canary run and how each strategy did, keyed by strategy id.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanaryState {
+    #[serde(default)]
+    pub last_run: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub strategies: BTreeMap<String, StrategyHealth>,
+}
+
+/// How one strategy did in the canary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StrategyHealth {
+    pub provider: String,
+    pub last_checked: DateTime<Utc>,
+    pub latency_ms: u64,
+    pub last_success: Option<DateTime<Utc>>,
+    /// When the current run of failures started; `None` while passing.
+    pub failing_since: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+impl StrategyHealth {
+    /// One-line description of a failing strategy, e.g.
+    /// "claude-web has been failing for 6 days: HTTP 403".
+    #[must_use]
+    pub fn failure_message(&self, strategy: &str, now: DateTime<Utc>) -> String {
+        let since = self.failing_since.unwrap_or(self.last_checked);
+        let days = (now - since).num_days();
+        let what = match days {
+            0 => format!("{strategy} failed its last check"),
+            1 => format!("{strategy} has been failing for 1 day"),
+            n => format!("{strategy} has been failing for {n} days"),
+        };
+        match &self.last_error {
+            Some(error) => format!("{what}: {error}"),
+            None => what,
+        }
+    }
+}
+
+impl CanaryState {
+    /// Load the state file; a missing or unreadable one starts fresh.
+    #[must_use]
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the state file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Whether a week has passed since the last run.
+    #[must_use]
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.last_run
+            .is_none_or(|last| now - last >= Duration::days(CANARY_INTERVAL_DAYS))
+    }
+
+    /// Record one strategy attempt.
+    pub fn record(&mut self, provider: Provider, attempt: &FetchAttempt) {
+        let previous = self.strategies.remove(&attempt.strategy_id);
+        let (last_success, failing_since) = if attempt.success {
+            (Some(attempt.started_at), None)
+        } else {
+            (
+                previous.as_ref().and_then(|p| p.last_success),
+                previous
+                    .as_ref()
+                    .and_then(|p| p.failing_since)
+                    .or(Some(attempt.started_at)),
+            )
+        };
+        self.strategies.insert(
+            attempt.strategy_id.clone(),
+            StrategyHealth {
+                provider: provider.cli_name().to_string(),
+                last_checked: attempt.started_at,
+                latency_ms: attempt.duration_ms,
+                last_success,
+                failing_since,
+                last_error: attempt.error.clone(),
+            },
+        );
+    }
+
+    /// Strategies whose last check failed.
+    pub fn failing(&self) -> impl Iterator<Item = (&str, &StrategyHealth)> {
+        self.strategies
+            .iter()
+            .filter(|(_, health)| health.failing_since.is_some())
+            .map(|(id, health)| (id.as_str(), health))
+    }
+}
+
+/// Probe every available strategy of `providers`, record the results in the
+/// state file at `path`, and return the updated state.
+///
+/// # Errors
+/// Returns an error if the state file cannot be written.
+pub async fn run(providers: &[Provider], path: &Path) -> Result<CanaryState> {
+    let mut state = CanaryState::load(path);
+    for &provider in providers {
+        for attempt in pipeline::probe_strategies(provider, provider.default_timeout()).await {
+            tracing::debug!(
+                provider = %provider.cli_name(),
+                strategy = %attempt.strategy_id,
+                success = attempt.success,
+                duration_ms = attempt.duration_ms,
+                "Canary probe"
+            );
+            state.record(provider, &attempt);
+        }
+    }
+    state.last_run = Some(Utc::now());
+    state.save(path)?;
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fetch_plan::FetchKind;
+
+    fn attempt(success: bool, started_at: DateTime<Utc>) -> FetchAttempt {
+        FetchAttempt {
+            strategy_id: "claude-web".to_string(),
+            kind: FetchKind::Web,
+            started_at,
+            duration_ms: 120,
+            success,
+            error: (!success).then(|| "HTTP 403".to_string()),
+        }
+    }
+
+    #[test]
+    fn failures_keep_their_start_until_a_success() {
+        let start = Utc::now() - Duration::days(6);
+        let mut state = CanaryState::default();
+
+        state.record(Provider::Claude, &attempt(false, start));
+        state.record(Provider::Claude, &attempt(false, start + Duration::days(3)));
+        let (id, health) = state.failing().next().unwrap();
+        assert_eq!(id, "claude-web");
+        assert_eq!(health.failing_since, Some(start));
+        assert_eq!(
+            health.failure_message(id, start + Duration::days(6)),
+            "claude-web has been failing for 6 days: HTTP 403"
+        );
+
+        state.record(Provider::Claude, &attempt(true, start + Duration::days(7)));
+        assert_eq!(state.failing().count(), 0);
+        assert_eq!(
+            state.strategies["claude-web"].last_success,
+            Some(start + Duration::days(7))
+        );
+    }
+
+    #[test]
+    fn run_is_due_weekly() {
+        let now = Utc::now();
+        let mut state = CanaryState::default();
+        assert!(state.is_due(now));
+        state.last_run = Some(now - Duration::days(2));
+        assert!(!state.is_due(now));
+        state.last_run = Some(now - Duration::days(CANARY_INTERVAL_DAYS));
+        assert!(state.is_due(now));
+    }
+}
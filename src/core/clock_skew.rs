@@ -0,0 +1,194 @@
+//! Clock skew detection.
+//!
+//! Reset countdowns compare a provider's absolute reset time with the local
+//! clock, so a system clock that is minutes off shifts every countdown by the
+//! same amount. Provider responses carry the server's `Date` header; each one
+//! is compared with the local clock as it arrives and the latest offset kept
+//! for the rest of the run. Offsets beyond [`SKEW_THRESHOLD_SECS`] are
+//! reported as warnings in usage output and by `caut doctor`.
+//!
+//! With `general.correct_clock_skew` set in the config, [`now`] returns
+//! server time instead and countdowns are computed against it.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, RwLock};
+
+use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "native")]
+use reqwest::header::{DATE, HeaderMap};
+
+/// Offset, in seconds, beyond which the local clock is reported as skewed.
+///
+/// `Date` headers have one-second resolution and arrive after network
+/// latency, so only offsets well beyond that are meaningful.
+pub const SKEW_THRESHOLD_SECS: i64 = 60;
+
+static LATEST: LazyLock<RwLock<Option<ClockSkew>>> = LazyLock::new(|| RwLock::new(None));
+
+static CORRECT: AtomicBool = AtomicBool::new(false);
+
+/// Difference between a server's clock and the local clock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClockSkew {
+    /// Server time minus local time, in seconds. Positive when the local
+    /// clock is behind.
+    pub offset_secs: i64,
+    /// Host whose `Date` header was compared.
+    pub host: String,
+}
+
+impl ClockSkew {
+    /// Compare an HTTP `Date` header value with the local time it arrived at.
+    #[must_use]
+    pub fn from_date_header(value: &str, host: &str, local: DateTime<Utc>) -> Option<Self> {
+        let server = DateTime::parse_from_rfc2822(value.trim()).ok()?;
+        Some(Self {
+            offset_secs: server
+                .with_timezone(&Utc)
+                .signed_duration_since(local)
+                .num_seconds(),
+            host: host.to_string(),
+        })
+    }
+
+    /// Whether the offset is large enough to throw countdowns off.
+    #[must_use]
+    pub const fn is_significant(&self) -> bool {
+        self.offset_secs.abs() > SKEW_THRESHOLD_SECS
+    }
+
+    /// Warning describing the skew, e.g. "System clock is 5m 12s behind
+    /// api.anthropic.com; reset countdowns may be off".
+    #[must_use]
+    pub fn message(&self) -> String {
+        let direction = if self.offset_secs > 0 {
+            "behind"
+        } else {
+            "ahead of"
+        };
+        let effect = if correction_enabled() {
+            "reset countdowns use server time"
+        } else {
+            "reset countdowns may be off"
+        };
+        format!(
+            "System clock is {} {direction} {}; {effect}",
+            format_offset(self.offset_secs.unsigned_abs()),
+            self.host
+        )
+    }
+}
+
+fn format_offset(secs: u64) -> String {
+    match (secs / 3600, secs % 3600 / 60, secs % 60) {
+        (0, 0, s) => format!("{s}s"),
+        (0, m, 0) => format!("{m}m"),
+        (0, m, s) => format!("{m}m {s}s"),
+        (h, 0, _) => format!("{h}h"),
+        (h, m, _) => format!("{h}h {m}m"),
+    }
+}
+
+/// Record the skew shown by a response's `Date` header, if it has one.
+#[cfg(feature = "native")]
+pub fn observe(response: &reqwest::Response) {
+    record(
+        response.headers(),
+        response.url().host_str().unwrap_or("server"),
+    );
+}
+
+/// Record the skew shown by a set of response headers.
+///
+/// # Panics
+/// Panics if the skew lock is poisoned.
+#[cfg(feature = "native")]
+pub fn record(headers: &HeaderMap, host: &str) {
+    let Some(skew) = headers
+        .get(DATE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| ClockSkew::from_date_header(value, host, Utc::now()))
+    else {
+        return;
+    };
+    if skew.is_significant() {
+        tracing::debug!(host = %skew.host, offset_secs = skew.offset_secs, "Clock skew detected");
+    }
+    *LATEST.write().expect("clock skew lock") = Some(skew);
+}
+
+/// Most recent skew observed this run.
+///
+/// # Panics
+/// Panics if the skew lock is poisoned.
+#[must_use]
+pub fn latest() -> Option<ClockSkew> {
+    LATEST.read().expect("clock skew lock").clone()
+}
+
+/// Most recent skew observed this run, if it is significant.
+#[must_use]
+pub fn significant() -> Option<ClockSkew> {
+    latest().filter(ClockSkew::is_significant)
+}
+
+/// Correct countdowns for observed skew for the rest of the run.
+pub fn set_correction(enabled: bool) {
+    CORRECT.store(enabled, Ordering::Relaxed);
+}
+
+fn correction_enabled() -> bool {
+    CORRECT.load(Ordering::Relaxed)
+}
+
+/// Current time for countdowns: server time when correction is enabled and
+/// significant skew has been observed, otherwise the local clock.
+#[must_use]
+pub fn now() -> DateTime<Utc> {
+    let now = Utc::now();
+    match significant() {
+        Some(skew) if correction_enabled() => now + Duration::seconds(skew.offset_secs),
+        _ => now,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn date_header_offset_is_server_minus_local() {
+        let local = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        let skew = ClockSkew::from_date_header(
+            "Fri, 16 Oct 2026 12:05:12 GMT",
+            "api.anthropic.com",
+            local,
+        )
+        .unwrap();
+
+        assert_eq!(skew.offset_secs, 312);
+        assert!(skew.is_significant());
+        assert!(
+            skew.message()
+                .starts_with("System clock is 5m 12s behind api.anthropic.com")
+        );
+        assert!(ClockSkew::from_date_header("not a date", "x", local).is_none());
+    }
+
+    #[test]
+    fn small_offsets_are_not_significant() {
+        let local = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        let skew = ClockSkew::from_date_header("Fri, 16 Oct 2026 11:59:58 GMT", "api.x.ai", local)
+            .unwrap();
+        assert_eq!(skew.offset_secs, -2);
+        assert!(!skew.is_significant());
+    }
+
+    #[test]
+    fn offsets_use_the_largest_units() {
+        assert_eq!(format_offset(45), "45s");
+        assert_eq!(format_offset(180), "3m");
+        assert_eq!(format_offset(2 * 3600 + 15 * 60 + 9), "2h 15m");
+    }
+}
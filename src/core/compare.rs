@@ -0,0 +1,220 @@
+//! Side-by-side provider comparison.
+//!
+//! For each provider over a window: tokens and spend from local cost data,
+//! the price per thousand tokens that gives, how hard its rate limits were
+//! pushed, and how often fetches failed. Together they answer "where should
+//! this work go?". Gathering the data and rendering it are left to
+//! `caut compare`.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::core::models::CostPayload;
+use crate::core::provider::Provider;
+use crate::storage::StoredSnapshot;
+
+/// One provider's row of a comparison.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderComparison {
+    pub provider: String,
+    /// Tokens used in the window; `None` without local cost data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usd_per_1k_tokens: Option<f64>,
+    /// Mean of the most-used window across recorded snapshots.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_used_percent: Option<f64>,
+    /// Highest usage seen in any window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_used_percent: Option<f64>,
+    /// Fetches recorded in the window, successful or not.
+    pub fetches: usize,
+    pub failures: usize,
+    /// Share of fetches that failed, 0.0 to 1.0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_rate: Option<f64>,
+}
+
+/// Compare `providers` over `from..=to` (local dates), from the snapshots
+/// recorded in the window, failed fetches per provider, and cost payloads
+/// covering it. Rows keep the order of `providers`.
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // counts are far below 2^52
+pub fn compare(
+    providers: &[Provider],
+    from: NaiveDate,
+    to: NaiveDate,
+    snapshots: &[StoredSnapshot],
+    failures: &HashMap<Provider, usize>,
+    costs: &[CostPayload],
+) -> Vec<ProviderComparison> {
+    providers
+        .iter()
+        .map(|&provider| {
+            let name = provider.cli_name();
+
+            let mut tokens = None;
+            let mut cost_usd = None;
+            for payload in costs.iter().filter(|c| c.provider == name) {
+                for entry in &payload.daily {
+                    let Ok(date) = NaiveDate::parse_from_str(&entry.date, "%Y-%m-%d") else {
+                        continue;
+                    };
+                    if date < from || date > to {
+                        continue;
+                    }
+                    if let Some(total) = entry.total_tokens {
+                        *tokens.get_or_insert(0) += total;
+                    }
+                    if let Some(cost) = entry.total_cost {
+                        *cost_usd.get_or_insert(0.0) += cost;
+                    }
+                }
+            }
+            let usd_per_1k_tokens = tokens
+                .zip(cost_usd)
+                .filter(|(tokens, _)| *tokens > 0)
+                .map(|(tokens, cost)| cost / tokens as f64 * 1000.0);
+
+            let peaks: Vec<f64> = snapshots
+                .iter()
+                .filter(|s| s.provider == provider)
+                .filter_map(|s| {
+                    [
+                        s.primary_used_pct,
+                        s.secondary_used_pct,
+                        s.tertiary_used_pct,
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .reduce(f64::max)
+                })
+                .collect();
+            let avg_used_percent =
+                (!peaks.is_empty()).then(|| peaks.iter().sum::<f64>() / peaks.len() as f64);
+            let peak_used_percent = peaks.iter().copied().reduce(f64::max);
+
+            let successes = snapshots.iter().filter(|s| s.provider == provider).count();
+            let failures = failures.get(&provider).copied().unwrap_or_default();
+            let fetches = successes + failures;
+
+            ProviderComparison {
+                provider: name.to_string(),
+                tokens,
+                cost_usd,
+                usd_per_1k_tokens,
+                avg_used_percent,
+                peak_used_percent,
+                fetches,
+                failures,
+                failure_rate: (fetches > 0).then(|| failures as f64 / fetches as f64),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CostDailyEntry;
+    use chrono::{TimeZone, Utc};
+
+    fn snapshot(provider: Provider, primary: f64, secondary: Option<f64>) -> StoredSnapshot {
+        let at = Utc.with_ymd_and_hms(2026, 3, 4, 10, 0, 0).unwrap();
+        StoredSnapshot {
+            id: 0,
+            provider,
+            fetched_at: at,
+            source: "oauth".to_string(),
+            primary_used_pct: Some(primary),
+            primary_window_minutes: Some(300),
+            primary_resets_at: None,
+            secondary_used_pct: secondary,
+            secondary_window_minutes: None,
+            secondary_resets_at: None,
+            tertiary_used_pct: None,
+            tertiary_window_minutes: None,
+            tertiary_resets_at: None,
+            cost_today_usd: None,
+            cost_mtd_usd: None,
+            credits_remaining: None,
+            account_email: None,
+            account_org: None,
+            fetch_duration_ms: None,
+            created_at: Some(at),
+            note: None,
+        }
+    }
+
+    fn daily(date: &str, tokens: i64, cost: f64) -> CostDailyEntry {
+        CostDailyEntry {
+            date: date.to_string(),
+            input_tokens: None,
+            output_tokens: None,
+            cache_read_tokens: None,
+            cache_creation_tokens: None,
+            total_tokens: Some(tokens),
+            total_cost: Some(cost),
+            models_used: None,
+        }
+    }
+
+    #[test]
+    fn comparison_combines_cost_pressure_and_failures() {
+        let from = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2026, 3, 7).unwrap();
+        let snapshots = [
+            snapshot(Provider::Claude, 40.0, Some(80.0)),
+            snapshot(Provider::Claude, 60.0, None),
+            snapshot(Provider::Claude, 20.0, None),
+        ];
+        let failures = HashMap::from([(Provider::Claude, 1)]);
+        let costs = [CostPayload {
+            provider: "claude".to_string(),
+            source: "local".to_string(),
+            updated_at: Utc::now(),
+            session_tokens: None,
+            session_cost_usd: None,
+            last_30_days_tokens: None,
+            last_30_days_cost_usd: None,
+            daily: vec![
+                daily("2026-03-02", 1_500_000, 3.0),
+                daily("2026-03-05", 500_000, 1.0),
+                daily("2026-02-20", 9_000_000, 90.0),
+            ],
+            totals: None,
+        }];
+
+        let rows = compare(
+            &[Provider::Claude, Provider::Codex],
+            from,
+            to,
+            &snapshots,
+            &failures,
+            &costs,
+        );
+
+        let claude = &rows[0];
+        assert_eq!(
+            claude.tokens,
+            Some(2_000_000),
+            "outside the window is left out"
+        );
+        assert!((claude.usd_per_1k_tokens.unwrap() - 0.002).abs() < 1e-12);
+        assert!((claude.avg_used_percent.unwrap() - 160.0 / 3.0).abs() < 1e-9);
+        assert_eq!(claude.peak_used_percent, Some(80.0));
+        assert_eq!((claude.fetches, claude.failures), (4, 1));
+        assert_eq!(claude.failure_rate, Some(0.25));
+
+        let codex = &rows[1];
+        assert_eq!(codex.tokens, None);
+        assert_eq!(codex.fetches, 0);
+        assert_eq!(codex.failure_rate, None);
+    }
+}
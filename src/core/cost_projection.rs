@@ -0,0 +1,198 @@
+//! Month-end cost projection from daily spend.
+//!
+//! Agent use differs sharply between weekdays and weekends, so the current
+//! month's completed days are split into the two groups and each remaining
+//! day of the projection period is priced at its group's average. The
+//! low/high band is an 80% interval assuming each day varies independently
+//! with its group's observed spread.
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::Serialize;
+
+/// z-score for the two-sided 80% band.
+const BAND_Z: f64 = 1.2816;
+
+/// Projected bill for one provider.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CostProjection {
+    pub provider: String,
+    /// First day of the projection period.
+    pub period_start: NaiveDate,
+    /// Last day of the projection period.
+    pub period_end: NaiveDate,
+    /// Spend so far in the period, including today.
+    pub spent_usd: f64,
+    /// Completed days used to estimate the daily rates.
+    pub days_observed: u32,
+    /// Days left in the period after today.
+    pub days_remaining: u32,
+    pub weekday_rate_usd: f64,
+    pub weekend_rate_usd: f64,
+    pub low_usd: f64,
+    pub expected_usd: f64,
+    pub high_usd: f64,
+}
+
+/// Mean and variance of one group of days.
+#[derive(Debug, Clone, Copy, Default)]
+struct DayStats {
+    mean: f64,
+    variance: f64,
+    samples: u32,
+}
+
+impl DayStats {
+    fn from_costs(costs: &[f64]) -> Self {
+        if costs.is_empty() {
+            return Self::default();
+        }
+        #[allow(clippy::cast_precision_loss)] // at most a month of days
+        let n = costs.len() as f64;
+        let mean = costs.iter().sum::<f64>() / n;
+        let variance = if costs.len() > 1 {
+            costs.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+        Self {
+            mean,
+            variance,
+            samples: u32::try_from(costs.len()).unwrap_or(u32::MAX),
+        }
+    }
+}
+
+/// Whether a date falls on a weekend.
+#[must_use]
+pub fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Project spend for a period of `days` days starting at `period_start`.
+///
+/// `daily` maps dates to spend; missing dates count as zero. Rates come from
+/// completed days (`period_start` up to yesterday), or from today alone on
+/// the first day of the period. A group with no observed days borrows the
+/// other group's rate.
+#[must_use]
+pub fn project(
+    provider: &str,
+    daily: &BTreeMap<NaiveDate, f64>,
+    period_start: NaiveDate,
+    today: NaiveDate,
+    days: u32,
+) -> CostProjection {
+    let period_end = period_start + Duration::days(i64::from(days.max(1)) - 1);
+    let cost_on = |date: NaiveDate| daily.get(&date).copied().unwrap_or(0.0);
+
+    let spent_usd = daily
+        .range(period_start..=today.min(period_end))
+        .map(|(_, cost)| cost)
+        .sum();
+
+    let observed_end = if today > period_start {
+        today - Duration::days(1)
+    } else {
+        today
+    };
+    let (mut weekday_costs, mut weekend_costs) = (Vec::new(), Vec::new());
+    for date in period_start.iter_days().take_while(|d| *d <= observed_end) {
+        if is_weekend(date) {
+            weekend_costs.push(cost_on(date));
+        } else {
+            weekday_costs.push(cost_on(date));
+        }
+    }
+    let mut weekday = DayStats::from_costs(&weekday_costs);
+    let mut weekend = DayStats::from_costs(&weekend_costs);
+    if weekday.samples == 0 {
+        weekday = weekend;
+    } else if weekend.samples == 0 {
+        weekend = weekday;
+    }
+
+    let (mut remaining_mean, mut remaining_variance, mut days_remaining) = (0.0, 0.0, 0_u32);
+    let mut date = today + Duration::days(1);
+    while date <= period_end {
+        let stats = if is_weekend(date) { weekend } else { weekday };
+        remaining_mean += stats.mean;
+        remaining_variance += stats.variance;
+        days_remaining += 1;
+        date += Duration::days(1);
+    }
+    let margin = BAND_Z * remaining_variance.sqrt();
+
+    CostProjection {
+        provider: provider.to_string(),
+        period_start,
+        period_end,
+        spent_usd,
+        days_observed: u32::try_from(weekday_costs.len() + weekend_costs.len()).unwrap_or(u32::MAX),
+        days_remaining,
+        weekday_rate_usd: weekday.mean,
+        weekend_rate_usd: weekend.mean,
+        low_usd: spent_usd + (remaining_mean - margin).max(0.0),
+        expected_usd: spent_usd + remaining_mean,
+        high_usd: spent_usd + remaining_mean + margin,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        // October 2026 starts on a Thursday.
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    #[test]
+    fn flat_spend_projects_linearly() {
+        let daily: BTreeMap<_, _> = (1..=10).map(|d| (date(d), 2.0)).collect();
+        let projection = project("claude", &daily, date(1), date(10), 30);
+
+        assert_eq!(projection.period_end, date(30));
+        assert_eq!(projection.days_observed, 9);
+        assert_eq!(projection.days_remaining, 20);
+        assert!((projection.spent_usd - 20.0).abs() < 1e-9);
+        assert!((projection.expected_usd - 60.0).abs() < 1e-9);
+        assert!((projection.low_usd - projection.high_usd).abs() < 1e-9);
+    }
+
+    #[test]
+    fn weekends_are_modeled_separately() {
+        let daily: BTreeMap<_, _> = (1..=14)
+            .map(|d| (date(d), if is_weekend(date(d)) { 0.0 } else { 10.0 }))
+            .collect();
+        let projection = project("codex", &daily, date(1), date(14), 21);
+
+        assert!((projection.weekday_rate_usd - 10.0).abs() < 1e-9);
+        assert!(projection.weekend_rate_usd.abs() < 1e-9);
+        // Oct 15-21: five weekdays, two weekend days.
+        assert!((projection.expected_usd - projection.spent_usd - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn variable_spend_widens_the_band() {
+        let daily: BTreeMap<_, _> = (1..=10)
+            .map(|d| (date(d), if d % 2 == 0 { 1.0 } else { 9.0 }))
+            .collect();
+        let projection = project("claude", &daily, date(1), date(10), 30);
+
+        assert!(projection.low_usd < projection.expected_usd);
+        assert!(projection.high_usd > projection.expected_usd);
+        assert!(projection.low_usd >= projection.spent_usd);
+    }
+
+    #[test]
+    fn first_day_uses_today() {
+        let daily = BTreeMap::from([(date(1), 4.0)]);
+        let projection = project("claude", &daily, date(1), date(1), 3);
+
+        assert_eq!(projection.days_observed, 1);
+        assert!((projection.expected_usd - 12.0).abs() < 1e-9);
+    }
+}
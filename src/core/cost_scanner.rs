@@ -1,18 +1,33 @@
-//! Local cost scanning for Claude and Codex.
+//! Local cost scanning for Claude, Codex, Gemini, Aider, and Cline.
 //!
-//! Scans local JSONL history files and stats caches to compute
-//! usage statistics for the cost command.
+//! Scans local JSONL history files, stats caches, Gemini CLI telemetry logs,
+//! Aider chat histories, and Cline task histories to compute usage
+//! statistics for the cost command. Ollama's cost is estimated from the time
+//! a model was loaded (see [`crate::core::local_cost`]).
 
+use crate::core::local_cost;
 use crate::core::models::{CostDailyEntry, CostPayload, CostTotals};
+use crate::core::pricing::{PricingTable, SessionCostCalculator};
 use crate::core::provider::Provider;
+use crate::core::session_logs::{AiderSessionParser, SessionLogFinder, SessionUsage};
 use crate::error::{CautError, Result};
+use crate::providers::claude::installations::Installation;
+use crate::providers::{bedrock, cline, continue_dev, goose, litellm};
+use crate::storage::HistoryStore;
+use crate::storage::config::Config;
 use crate::storage::paths::AppPaths;
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, Local, Utc};
 use serde::Deserialize;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable Gemini CLI reads for its telemetry outfile.
+const GEMINI_TELEMETRY_OUTFILE_ENV: &str = "GEMINI_TELEMETRY_OUTFILE";
+
+/// Telemetry event emitted by Gemini CLI for every model response.
+const GEMINI_API_RESPONSE_EVENT: &str = "gemini_cli.api_response";
 
 /// Claude stats cache format (from ~/.claude/stats-cache.json).
 #[derive(Debug, Deserialize)]
@@ -49,7 +64,6 @@ struct CodexEvent {
 
 /// Cost scanner for local usage data.
 pub struct CostScanner {
-    #[allow(dead_code)] // reserved for future path-based scanning
     paths: AppPaths,
 }
 
@@ -71,6 +85,14 @@ impl CostScanner {
         match provider {
             Provider::Claude => self.scan_claude().await,
             Provider::Codex => self.scan_codex().await,
+            Provider::Gemini => self.scan_gemini().await,
+            Provider::Aider => self.scan_aider(),
+            Provider::Cline => self.scan_cline(),
+            Provider::Continue => self.scan_continue(),
+            Provider::Goose => self.scan_goose(),
+            Provider::Bedrock => self.scan_bedrock().await,
+            Provider::LiteLLM => self.scan_litellm().await,
+            Provider::Ollama => self.scan_ollama(),
             _ => Err(CautError::Config(format!(
                 "Provider {} does not support local cost scanning",
                 provider.cli_name()
@@ -83,12 +105,26 @@ impl CostScanner {
         let claude_dir = dirs::home_dir()
             .ok_or_else(|| CautError::Config("Cannot determine home directory".to_string()))?
             .join(".claude");
+        self.scan_claude_stats(&claude_dir, "local")
+    }
+
+    /// Scan the stats cache of a preview/nightly Claude Code install; the
+    /// payload's source is labelled with its channel, e.g. `local (preview)`.
+    ///
+    /// # Errors
+    /// Returns an error if the stats cache exists but cannot be read or parsed.
+    pub fn scan_claude_installation(&self, install: &Installation) -> Result<CostPayload> {
+        self.scan_claude_stats(&install.config_dir, &install.label("local"))
+    }
 
+    fn scan_claude_stats(&self, claude_dir: &Path, source: &str) -> Result<CostPayload> {
         let stats_path = claude_dir.join("stats-cache.json");
 
         if !stats_path.exists() {
             tracing::debug!(?stats_path, "Claude stats cache not found");
-            return Ok(self.empty_cost_payload("claude"));
+            let mut payload = self.empty_cost_payload("claude");
+            payload.source = source.to_string();
+            return Ok(payload);
         }
 
         tracing::debug!(?stats_path, "Reading Claude stats cache");
@@ -140,7 +176,7 @@ impl CostScanner {
 
         Ok(CostPayload {
             provider: "claude".to_string(),
-            source: "local".to_string(),
+            source: source.to_string(),
             updated_at: Utc::now(),
             session_tokens: Some(today_messages),
             session_cost_usd: None, // Cannot determine without pricing data
@@ -362,6 +398,170 @@ impl CostScanner {
         }
     }
 
+    /// Scan Gemini CLI's local telemetry log for token counts.
+    ///
+    /// Gemini CLI only writes this log when telemetry is enabled with the
+    /// local target (`"telemetry": {"enabled": true, "target": "local"}` in
+    /// `~/.gemini/settings.json`). Costs are priced per model from the
+    /// bundled pricing table.
+    async fn scan_gemini(&self) -> Result<CostPayload> {
+        let log_path = match std::env::var_os(GEMINI_TELEMETRY_OUTFILE_ENV) {
+            Some(path) => PathBuf::from(path),
+            None => dirs::home_dir()
+                .ok_or_else(|| CautError::Config("Cannot determine home directory".to_string()))?
+                .join(".gemini")
+                .join("telemetry.log"),
+        };
+
+        if !log_path.exists() {
+            tracing::debug!(?log_path, "Gemini telemetry log not found");
+            return Ok(self.empty_cost_payload("gemini"));
+        }
+
+        tracing::debug!(?log_path, "Reading Gemini telemetry log");
+
+        let file = File::open(&log_path)
+            .map_err(|e| CautError::Config(format!("Failed to open Gemini telemetry log: {e}")))?;
+
+        let cutoff_date = (Utc::now() - Duration::days(30))
+            .format("%Y-%m-%d")
+            .to_string();
+        let days = parse_gemini_telemetry(BufReader::new(file), &cutoff_date);
+        let today = Local::now().format("%Y-%m-%d").to_string();
+
+        Ok(gemini_cost_payload(days, &today, &PricingTable::current()))
+    }
+
+    /// Scan Aider chat histories, or its analytics log when configured.
+    #[allow(clippy::unused_self)]
+    fn scan_aider(&self) -> Result<CostPayload> {
+        let cutoff = Utc::now() - Duration::days(30);
+        let sessions: Vec<SessionUsage> = SessionLogFinder::new()?
+            .find_sessions(Provider::Aider, Some(cutoff), None)
+            .iter()
+            .filter_map(|log| match AiderSessionParser.parse_sessions(&log.path) {
+                Ok(sessions) => Some(sessions),
+                Err(e) => {
+                    tracing::debug!(path = ?log.path, error = %e, "Failed to read Aider log");
+                    None
+                }
+            })
+            .flatten()
+            .collect();
+
+        let cutoff_date = cutoff.with_timezone(&Local).format("%Y-%m-%d").to_string();
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        Ok(sessions_cost_payload(
+            "aider",
+            &sessions,
+            &cutoff_date,
+            &today,
+            &SessionCostCalculator::new(),
+        ))
+    }
+
+    /// Scan Cline and Roo Code task histories in editor extension storage.
+    #[allow(clippy::unused_self)]
+    fn scan_cline(&self) -> Result<CostPayload> {
+        let sessions = cline::task_sessions();
+        let cutoff_date = (Local::now() - Duration::days(30))
+            .format("%Y-%m-%d")
+            .to_string();
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        Ok(sessions_cost_payload(
+            "cline",
+            &sessions,
+            &cutoff_date,
+            &today,
+            &SessionCostCalculator::new(),
+        ))
+    }
+
+    /// Scan model calls logged in Continue's dev data.
+    #[allow(clippy::unused_self)]
+    fn scan_continue(&self) -> Result<CostPayload> {
+        let sessions = continue_dev::token_sessions();
+        let cutoff_date = (Local::now() - Duration::days(30))
+            .format("%Y-%m-%d")
+            .to_string();
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        Ok(sessions_cost_payload(
+            "continue",
+            &sessions,
+            &cutoff_date,
+            &today,
+            &SessionCostCalculator::new(),
+        ))
+    }
+
+    /// Scan token totals in Goose's session store.
+    #[allow(clippy::unused_self)]
+    fn scan_goose(&self) -> Result<CostPayload> {
+        let sessions = goose::sessions();
+        let cutoff_date = (Local::now() - Duration::days(30))
+            .format("%Y-%m-%d")
+            .to_string();
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        Ok(sessions_cost_payload(
+            "goose",
+            &sessions,
+            &cutoff_date,
+            &today,
+            &SessionCostCalculator::new(),
+        ))
+    }
+
+    /// Price Bedrock's daily CloudWatch token metrics.
+    #[allow(clippy::unused_self)]
+    async fn scan_bedrock(&self) -> Result<CostPayload> {
+        let sessions = bedrock::daily_usage().await?;
+        let cutoff_date = (Local::now() - Duration::days(30))
+            .format("%Y-%m-%d")
+            .to_string();
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let mut payload = sessions_cost_payload(
+            "bedrock",
+            &sessions,
+            &cutoff_date,
+            &today,
+            &SessionCostCalculator::new(),
+        );
+        payload.source = "api".to_string();
+        Ok(payload)
+    }
+
+    /// Read the LiteLLM key's daily spend from the proxy's spend logs.
+    #[allow(clippy::unused_self)]
+    async fn scan_litellm(&self) -> Result<CostPayload> {
+        let daily = litellm::daily_spend().await?;
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        Ok(daily_cost_payload("litellm", "api", daily, &today))
+    }
+
+    /// Estimate Ollama's cost from the time a model was loaded, at the
+    /// `[local_cost]` rate.
+    fn scan_ollama(&self) -> Result<CostPayload> {
+        let Some(rate) = Config::load()?.local_cost.hourly_rate() else {
+            return Err(CautError::Config(
+                "No local runtime rate: set local_cost.hourly_usd, or local_cost.power_watts \
+                 and local_cost.kwh_usd, in the config"
+                    .to_string(),
+            ));
+        };
+        let store = HistoryStore::open(&self.paths.history_db_file())?;
+        let samples =
+            store.throughput_samples(&Provider::Ollama, Utc::now() - Duration::days(30))?;
+        let sessions =
+            local_cost::sessions(samples.iter().map(|s| (s.fetched_at, s.model.as_str())));
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        Ok(daily_cost_payload(
+            "ollama",
+            "local runtime",
+            local_cost::daily_costs(&sessions, rate),
+            &today,
+        ))
+    }
+
     /// Create an empty cost payload for a provider.
     #[allow(clippy::unused_self)]
     fn empty_cost_payload(&self, provider: &str) -> CostPayload {
@@ -391,6 +591,241 @@ struct DailyCount {
     events: i64,
 }
 
+/// Token totals for one day of Gemini telemetry.
+#[derive(Debug, Default)]
+struct GeminiDay {
+    input_tokens: i64,
+    output_tokens: i64,
+    cache_read_tokens: i64,
+    cost_usd: f64,
+    models: BTreeSet<String>,
+}
+
+/// Per-model token counts from one `gemini_cli.api_response` event.
+#[derive(Debug, PartialEq, Eq)]
+struct GeminiResponse {
+    date: String,
+    model: String,
+    /// Prompt tokens not served from cache.
+    input_tokens: i64,
+    /// Output plus thinking tokens (both billed at the output rate).
+    output_tokens: i64,
+    cache_read_tokens: i64,
+}
+
+/// Parse Gemini CLI telemetry into per-day token counts, keyed by date.
+///
+/// The local exporter writes a stream of (often pretty-printed) JSON log
+/// records; only `gemini_cli.api_response` events carry token counts. Pricing
+/// is applied later so parsing stays independent of the pricing table.
+fn parse_gemini_telemetry(
+    reader: impl Read,
+    cutoff_date: &str,
+) -> HashMap<String, Vec<GeminiResponse>> {
+    let mut days: HashMap<String, Vec<GeminiResponse>> = HashMap::new();
+
+    for record in serde_json::Deserializer::from_reader(reader).into_iter::<serde_json::Value>() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                // A partially written trailing record is expected while
+                // Gemini CLI is running; keep what was parsed so far.
+                tracing::debug!(error = %e, "Stopped reading Gemini telemetry");
+                break;
+            }
+        };
+        if let Some(response) = gemini_response(&record)
+            && response.date.as_str() >= cutoff_date
+        {
+            days.entry(response.date.clone())
+                .or_default()
+                .push(response);
+        }
+    }
+
+    days
+}
+
+/// Extract token counts from a telemetry record, if it is an API response.
+fn gemini_response(record: &serde_json::Value) -> Option<GeminiResponse> {
+    let attrs = record.get("attributes")?;
+    if attrs.get("event.name")?.as_str()? != GEMINI_API_RESPONSE_EVENT {
+        return None;
+    }
+
+    let count = |key: &str| {
+        attrs
+            .get(key)
+            .and_then(serde_json::Value::as_i64)
+            .unwrap_or(0)
+    };
+    let timestamp = attrs.get("event.timestamp")?.as_str()?;
+    let date = DateTime::parse_from_rfc3339(timestamp)
+        .ok()?
+        .with_timezone(&Local)
+        .format("%Y-%m-%d")
+        .to_string();
+
+    // The prompt count includes cached tokens, which bill at the cache rate.
+    let cached = count("cached_content_token_count");
+    Some(GeminiResponse {
+        date,
+        model: attrs
+            .get("model")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("unknown")
+            .to_string(),
+        input_tokens: (count("input_token_count") - cached).max(0),
+        output_tokens: count("output_token_count") + count("thoughts_token_count"),
+        cache_read_tokens: cached,
+    })
+}
+
+/// Price parsed Gemini responses and build the cost payload.
+fn gemini_cost_payload(
+    responses: HashMap<String, Vec<GeminiResponse>>,
+    today: &str,
+    pricing: &PricingTable,
+) -> CostPayload {
+    let daily: Vec<CostDailyEntry> = responses
+        .into_iter()
+        .map(|(date, responses)| {
+            let day = responses
+                .iter()
+                .fold(GeminiDay::default(), |mut day, response| {
+                    let (price, _) = pricing.get_or_estimate(&response.model);
+                    day.cost_usd += price
+                        .calculate_cost(
+                            response.input_tokens,
+                            response.output_tokens,
+                            response.cache_read_tokens,
+                            0,
+                        )
+                        .total_cost_usd;
+                    day.input_tokens += response.input_tokens;
+                    day.output_tokens += response.output_tokens;
+                    day.cache_read_tokens += response.cache_read_tokens;
+                    day.models.insert(response.model.clone());
+                    day
+                });
+            CostDailyEntry {
+                date,
+                input_tokens: Some(day.input_tokens),
+                output_tokens: Some(day.output_tokens),
+                cache_read_tokens: Some(day.cache_read_tokens),
+                cache_creation_tokens: None,
+                total_tokens: Some(day.input_tokens + day.output_tokens + day.cache_read_tokens),
+                total_cost: Some(day.cost_usd),
+                models_used: Some(day.models.into_iter().collect()),
+            }
+        })
+        .collect();
+
+    daily_cost_payload("gemini", "telemetry", daily, today)
+}
+
+/// Token and cost totals for one day of sessions.
+#[derive(Debug, Default)]
+struct SessionDay {
+    input_tokens: i64,
+    output_tokens: i64,
+    cache_read_tokens: i64,
+    cache_creation_tokens: i64,
+    cost_usd: f64,
+    models: BTreeSet<String>,
+}
+
+/// Build a cost payload from parsed sessions, bucketed by local start date.
+///
+/// Costs the agent reported itself (Aider replies, Cline tasks) are used as
+/// is; sessions without one are priced from their token counts.
+fn sessions_cost_payload(
+    provider: &str,
+    sessions: &[SessionUsage],
+    cutoff_date: &str,
+    today: &str,
+    calculator: &SessionCostCalculator,
+) -> CostPayload {
+    let mut days: HashMap<String, SessionDay> = HashMap::new();
+    for session in sessions {
+        let Some(started_at) = session.started_at else {
+            continue;
+        };
+        let date = started_at
+            .with_timezone(&Local)
+            .format("%Y-%m-%d")
+            .to_string();
+        if date.as_str() < cutoff_date {
+            continue;
+        }
+        let day = days.entry(date).or_default();
+        day.input_tokens += session.input_tokens;
+        day.output_tokens += session.output_tokens;
+        day.cache_read_tokens += session.cache_read_tokens;
+        day.cache_creation_tokens += session.cache_creation_tokens;
+        day.cost_usd += calculator.calculate(session).total_usd;
+        day.models.extend(session.models_used.iter().cloned());
+    }
+
+    let daily = days
+        .into_iter()
+        .map(|(date, day)| CostDailyEntry {
+            date,
+            input_tokens: Some(day.input_tokens),
+            output_tokens: Some(day.output_tokens),
+            cache_read_tokens: Some(day.cache_read_tokens),
+            cache_creation_tokens: Some(day.cache_creation_tokens),
+            total_tokens: Some(
+                day.input_tokens
+                    + day.output_tokens
+                    + day.cache_read_tokens
+                    + day.cache_creation_tokens,
+            ),
+            total_cost: Some(day.cost_usd),
+            models_used: Some(day.models.into_iter().collect()),
+        })
+        .collect();
+
+    daily_cost_payload(provider, "local", daily, today)
+}
+
+/// Sum daily entries into a cost payload, newest day first.
+fn daily_cost_payload(
+    provider: &str,
+    source: &str,
+    mut daily: Vec<CostDailyEntry>,
+    today: &str,
+) -> CostPayload {
+    daily.sort_by(|a, b| b.date.cmp(&a.date));
+
+    let add = |acc: Option<i64>, value: Option<i64>| match (acc, value) {
+        (acc, None) => acc,
+        (acc, Some(value)) => Some(acc.unwrap_or(0) + value),
+    };
+    let totals = daily.iter().fold(CostTotals::default(), |mut acc, day| {
+        acc.input_tokens = add(acc.input_tokens, day.input_tokens);
+        acc.output_tokens = add(acc.output_tokens, day.output_tokens);
+        acc.cache_read_tokens = add(acc.cache_read_tokens, day.cache_read_tokens);
+        acc.cache_creation_tokens = add(acc.cache_creation_tokens, day.cache_creation_tokens);
+        acc.total_tokens = add(acc.total_tokens, day.total_tokens);
+        acc.total_cost = Some(acc.total_cost.unwrap_or(0.0) + day.total_cost.unwrap_or(0.0));
+        acc
+    });
+    let today_entry = daily.iter().find(|d| d.date == today);
+
+    CostPayload {
+        provider: provider.to_string(),
+        source: source.to_string(),
+        updated_at: Utc::now(),
+        session_tokens: Some(today_entry.and_then(|d| d.total_tokens).unwrap_or(0)),
+        session_cost_usd: Some(today_entry.and_then(|d| d.total_cost).unwrap_or(0.0)),
+        last_30_days_tokens: Some(totals.total_tokens.unwrap_or(0)),
+        last_30_days_cost_usd: Some(totals.total_cost.unwrap_or(0.0)),
+        daily,
+        totals: Some(totals),
+    }
+}
+
 /// Module-level access to dirs crate.
 mod dirs {
     use std::path::PathBuf;
@@ -403,6 +838,7 @@ mod dirs {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
     use std::fmt::Write;
     use tempfile::TempDir;
 
@@ -460,6 +896,135 @@ mod tests {
         assert_eq!(payload.source, "local");
     }
 
+    // =========================================================================
+    // Gemini Telemetry Tests
+    // =========================================================================
+
+    fn gemini_record(event: &str, timestamp: &str, model: &str) -> String {
+        format!(
+            r#"{{
+  "body": "API response from {model}.",
+  "attributes": {{
+    "event.name": "{event}",
+    "event.timestamp": "{timestamp}",
+    "model": "{model}",
+    "input_token_count": 1000,
+    "output_token_count": 200,
+    "cached_content_token_count": 400,
+    "thoughts_token_count": 50,
+    "total_token_count": 1250
+  }}
+}}
+"#
+        )
+    }
+
+    #[test]
+    fn test_gemini_telemetry_counts_api_responses_only() {
+        let mut log = String::new();
+        log.push_str(&gemini_record(
+            GEMINI_API_RESPONSE_EVENT,
+            "2026-01-15T12:00:00Z",
+            "gemini-2.5-pro",
+        ));
+        log.push_str(&gemini_record(
+            "gemini_cli.user_prompt",
+            "2026-01-15T12:00:00Z",
+            "gemini-2.5-pro",
+        ));
+        log.push_str(&gemini_record(
+            GEMINI_API_RESPONSE_EVENT,
+            "2025-11-01T12:00:00Z",
+            "gemini-2.5-pro",
+        ));
+
+        let days = parse_gemini_telemetry(log.as_bytes(), "2026-01-01");
+        let responses: Vec<&GeminiResponse> = days.values().flatten().collect();
+
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].model, "gemini-2.5-pro");
+        assert_eq!(responses[0].input_tokens, 600);
+        assert_eq!(responses[0].output_tokens, 250);
+        assert_eq!(responses[0].cache_read_tokens, 400);
+    }
+
+    #[test]
+    fn test_gemini_telemetry_keeps_records_before_truncation() {
+        let mut log = gemini_record(
+            GEMINI_API_RESPONSE_EVENT,
+            "2026-01-15T12:00:00Z",
+            "gemini-2.5-flash",
+        );
+        log.push_str("{\"attributes\": {\"event.name\": ");
+
+        let days = parse_gemini_telemetry(log.as_bytes(), "2026-01-01");
+        assert_eq!(days.values().map(Vec::len).sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn test_gemini_cost_payload_prices_by_model() {
+        let log = gemini_record(
+            GEMINI_API_RESPONSE_EVENT,
+            "2026-01-15T12:00:00Z",
+            "gemini-2.5-pro",
+        );
+        let days = parse_gemini_telemetry(log.as_bytes(), "2026-01-01");
+        let date = days.keys().next().unwrap().clone();
+
+        let payload = gemini_cost_payload(days, &date, &PricingTable::current());
+
+        assert_eq!(payload.provider, "gemini");
+        assert_eq!(payload.source, "telemetry");
+        assert_eq!(payload.daily.len(), 1);
+        assert_eq!(payload.session_tokens, Some(1250));
+        let cost = payload.session_cost_usd.unwrap();
+        assert!(cost > 0.0);
+        assert_eq!(payload.last_30_days_cost_usd, Some(cost));
+        assert_eq!(
+            payload.daily[0].models_used,
+            Some(vec!["gemini-2.5-pro".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_sessions_cost_payload_uses_reported_cost() {
+        let started = Local.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let session = |input, output, cost| SessionUsage {
+            started_at: Some(started.with_timezone(&Utc)),
+            input_tokens: input,
+            output_tokens: output,
+            cache_read_tokens: 500,
+            models_used: std::iter::once("gpt-4o".to_string()).collect(),
+            reported_cost_usd: cost,
+            ..SessionUsage::default()
+        };
+        let old = SessionUsage {
+            started_at: Some(Utc.with_ymd_and_hms(2025, 11, 1, 12, 0, 0).unwrap()),
+            input_tokens: 9_999,
+            ..SessionUsage::default()
+        };
+        let sessions = vec![
+            session(1000, 200, Some(0.25)),
+            session(500, 100, Some(0.5)),
+            old,
+        ];
+
+        let payload = sessions_cost_payload(
+            "aider",
+            &sessions,
+            "2026-01-01",
+            "2026-01-15",
+            &SessionCostCalculator::new(),
+        );
+
+        assert_eq!(payload.provider, "aider");
+        assert_eq!(payload.daily.len(), 1);
+        assert_eq!(payload.daily[0].input_tokens, Some(1500));
+        assert_eq!(payload.session_tokens, Some(2800));
+        assert!((payload.session_cost_usd.unwrap() - 0.75).abs() < 1e-9);
+        assert_eq!(payload.totals.unwrap().cache_creation_tokens, Some(0));
+    }
+
     // =========================================================================
     // ClaudeStatsCache Parsing Tests
     // =========================================================================
@@ -0,0 +1,91 @@
+//! Cross-source verification (`caut usage --verify`).
+//!
+//! Providers with more than one source (OAuth API, CLI, web dashboard) can
+//! report the same window through each. A parser bug or a provider-side
+//! format change usually shows up first as two sources disagreeing, so verify
+//! mode fetches every other available source once the preferred one has
+//! succeeded and records windows whose used percentages differ by more than
+//! [`DIVERGENCE_THRESHOLD`] points. The payload itself always comes from the
+//! preferred source.
+
+use crate::core::models::{RateWindow, SourceDivergence, UsageSnapshot};
+
+/// Difference in used percentage points that counts as a divergence.
+///
+/// Sources are fetched seconds apart, so small drift between them is
+/// expected and not reported.
+pub const DIVERGENCE_THRESHOLD: f64 = 10.0;
+
+/// Windows present in both snapshots whose used percentages diverge.
+#[must_use]
+pub fn compare(
+    preferred_source: &str,
+    preferred: &UsageSnapshot,
+    other_source: &str,
+    other: &UsageSnapshot,
+) -> Vec<SourceDivergence> {
+    let windows: [(&str, &Option<RateWindow>, &Option<RateWindow>); 3] = [
+        ("primary", &preferred.primary, &other.primary),
+        ("secondary", &preferred.secondary, &other.secondary),
+        ("tertiary", &preferred.tertiary, &other.tertiary),
+    ];
+
+    windows
+        .into_iter()
+        .filter_map(|(window, preferred, other)| {
+            let (preferred, other) = (preferred.as_ref()?, other.as_ref()?);
+            let divergence = SourceDivergence {
+                window: window.to_string(),
+                preferred_source: preferred_source.to_string(),
+                preferred_percent: preferred.used_percent,
+                other_source: other_source.to_string(),
+                other_percent: other.used_percent,
+            };
+            (divergence.delta() > DIVERGENCE_THRESHOLD).then_some(divergence)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn snapshot(primary: Option<f64>, secondary: Option<f64>) -> UsageSnapshot {
+        UsageSnapshot {
+            primary: primary.map(RateWindow::new),
+            secondary: secondary.map(RateWindow::new),
+            tertiary: None,
+            updated_at: Utc::now(),
+            identity: None,
+        }
+    }
+
+    #[test]
+    fn reports_windows_beyond_the_threshold() {
+        let divergences = compare(
+            "oauth",
+            &snapshot(Some(38.0), Some(20.0)),
+            "web",
+            &snapshot(Some(52.0), Some(24.0)),
+        );
+
+        assert_eq!(divergences.len(), 1);
+        assert_eq!(divergences[0].window, "primary");
+        assert_eq!(
+            divergences[0].message(),
+            "Session: oauth reports 38% used, web reports 52% (14 pts apart)"
+        );
+    }
+
+    #[test]
+    fn ignores_windows_missing_from_either_source() {
+        let divergences = compare(
+            "oauth",
+            &snapshot(None, Some(10.0)),
+            "cli",
+            &snapshot(Some(90.0), None),
+        );
+        assert!(divergences.is_empty());
+    }
+}
@@ -3,11 +3,12 @@
 //! Implements diagnostic checks for CLI installation, authentication,
 //! and API reachability for each provider.
 
-use super::{CheckStatus, DiagnosticCheck, ProviderHealth};
+use super::{CheckStatus, DiagnosticCheck, ProviderHealth, check_ids};
 use crate::core::cli_runner::run_command;
 use crate::core::credential_health::{AuthHealthAggregator, OverallHealth};
 use crate::core::provider::Provider;
 use crate::error::CautError;
+use crate::providers::{amazon_q, xai};
 use std::time::{Duration, Instant};
 
 /// Default timeout for API reachability checks.
@@ -18,7 +19,7 @@ const CLI_VERSION_TIMEOUT: Duration = Duration::from_secs(3);
 
 /// Check if a CLI binary is available and get its version.
 pub async fn check_cli_installed(provider: Provider) -> (DiagnosticCheck, Option<String>) {
-    let cli_name = provider.cli_name();
+    let cli_name = provider.executable();
     let start = Instant::now();
 
     match which::which(cli_name) {
@@ -32,6 +33,7 @@ pub async fn check_cli_installed(provider: Provider) -> (DiagnosticCheck, Option
 
             (
                 DiagnosticCheck {
+                    id: check_ids::for_provider(provider, check_ids::CLI_INSTALLED),
                     name: format!("{cli_name} CLI installed"),
                     status: CheckStatus::Pass {
                         details: Some(details),
@@ -43,6 +45,7 @@ pub async fn check_cli_installed(provider: Provider) -> (DiagnosticCheck, Option
         }
         Err(_) => (
             DiagnosticCheck {
+                id: check_ids::for_provider(provider, check_ids::CLI_INSTALLED),
                 name: format!("{cli_name} CLI installed"),
                 status: CheckStatus::Fail {
                     reason: "CLI not found in PATH".to_string(),
@@ -127,10 +130,13 @@ pub async fn check_authenticated(provider: Provider) -> DiagnosticCheck {
         Provider::Codex => check_codex_auth().await,
         Provider::Gemini => check_gemini_auth().await,
         Provider::Cursor => check_cursor_auth().await,
+        Provider::AmazonQ => check_amazon_q_auth(),
+        Provider::Xai => check_xai_auth(),
         _ => check_generic_auth(provider).await,
     };
 
     DiagnosticCheck {
+        id: check_ids::for_provider(provider, check_ids::AUTH_STATUS),
         name: format!("{} authenticated", provider.display_name()),
         status,
         duration: Some(start.elapsed()),
@@ -339,6 +345,36 @@ async fn check_cursor_auth() -> CheckStatus {
     }
 }
 
+/// Check Amazon Q sign-in (SSO token from `q` or the AWS SSO cache).
+fn check_amazon_q_auth() -> CheckStatus {
+    match amazon_q::find_token() {
+        Some(token) if token.is_expired() => CheckStatus::Fail {
+            reason: "SSO token expired".to_string(),
+            suggestion: Some(Provider::AmazonQ.auth_suggestion().to_string()),
+        },
+        Some(token) => CheckStatus::Pass {
+            details: Some(format!("Signed in ({})", token.login_method())),
+        },
+        None => CheckStatus::Fail {
+            reason: "No SSO token found".to_string(),
+            suggestion: Some(Provider::AmazonQ.auth_suggestion().to_string()),
+        },
+    }
+}
+
+/// Check xAI authentication: an API key must be configured.
+fn check_xai_auth() -> CheckStatus {
+    xai::key_source().map_or_else(
+        || CheckStatus::Fail {
+            reason: "No API key configured".to_string(),
+            suggestion: Some(Provider::Xai.auth_suggestion().to_string()),
+        },
+        |source| CheckStatus::Pass {
+            details: Some(format!("API key found ({})", source.label())),
+        },
+    )
+}
+
 /// Generic auth check for providers without specific implementation.
 #[allow(clippy::unused_async)]
 async fn check_generic_auth(provider: Provider) -> CheckStatus {
@@ -422,6 +458,7 @@ pub fn check_credential_health(provider: Provider) -> Option<DiagnosticCheck> {
     };
 
     Some(DiagnosticCheck {
+        id: check_ids::for_provider(provider, check_ids::AUTH_TOKEN_EXPIRY),
         name: format!("{} credential health", provider.display_name()),
         status,
         duration: Some(start.elapsed()),
@@ -435,6 +472,7 @@ pub async fn check_api_reachable(provider: Provider) -> DiagnosticCheck {
     let status = check_reachability(provider).await;
 
     DiagnosticCheck {
+        id: check_ids::for_provider(provider, check_ids::API_REACHABLE),
         name: format!("{} API reachable", provider.display_name()),
         status,
         duration: Some(start.elapsed()),
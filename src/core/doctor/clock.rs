@@ -0,0 +1,103 @@
+//! System clock check.
+//!
+//! Compares the local clock with the `Date` header of a provider status page
+//! (see [`crate::core::clock_skew`]). A clock more than a minute off makes
+//! every reset countdown wrong by the same amount, so it is flagged with a
+//! warning. When no status page answers, the check is skipped.
+
+use std::time::Instant;
+
+use chrono::Utc;
+use reqwest::header::DATE;
+
+use super::{CheckStatus, DiagnosticCheck, check_ids};
+use crate::core::clock_skew::{self, ClockSkew};
+use crate::core::http::{STATUS_TIMEOUT, build_client};
+use crate::core::provider::Provider;
+
+/// Status pages tried before giving up.
+const MAX_PROBES: usize = 3;
+
+/// Compare the local clock with the status pages of `providers`.
+pub async fn check_clock_skew(providers: &[Provider]) -> DiagnosticCheck {
+    let start = Instant::now();
+    let status = match probe(providers).await {
+        Some(skew) => skew_status(&skew),
+        None => CheckStatus::Skipped {
+            reason: "No provider server answered to compare clocks".to_string(),
+        },
+    };
+    DiagnosticCheck::new(check_ids::CLOCK_SKEW, "System clock", status)
+        .with_duration(start.elapsed())
+}
+
+async fn probe(providers: &[Provider]) -> Option<ClockSkew> {
+    let client = build_client(STATUS_TIMEOUT).ok()?;
+    let urls = providers
+        .iter()
+        .chain(Provider::PRIMARY.iter())
+        .filter_map(|p| p.status_page_url())
+        .take(MAX_PROBES);
+
+    for url in urls {
+        let Ok(response) = client.head(url).send().await else {
+            continue;
+        };
+        clock_skew::observe(&response);
+        let host = response.url().host_str().unwrap_or(url);
+        if let Some(skew) = response
+            .headers()
+            .get(DATE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| ClockSkew::from_date_header(value, host, Utc::now()))
+        {
+            return Some(skew);
+        }
+    }
+    None
+}
+
+fn skew_status(skew: &ClockSkew) -> CheckStatus {
+    if skew.is_significant() {
+        CheckStatus::Warning {
+            details: skew.message(),
+            suggestion: Some(
+                "Enable network time sync, or set general.correct_clock_skew = true".to_string(),
+            ),
+        }
+    } else {
+        CheckStatus::Pass {
+            details: Some(format!(
+                "Within {}s of {}",
+                skew.offset_secs.abs().max(1),
+                skew.host
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skew(offset_secs: i64) -> ClockSkew {
+        ClockSkew {
+            offset_secs,
+            host: "status.claude.com".to_string(),
+        }
+    }
+
+    #[test]
+    fn large_offsets_warn() {
+        assert!(matches!(
+            skew_status(&skew(-600)),
+            CheckStatus::Warning { .. }
+        ));
+        assert_eq!(
+            skew_status(&skew(0)),
+            CheckStatus::Pass {
+                details: Some("Within 1s of status.claude.com".to_string())
+            }
+        );
+    }
+}
@@ -3,6 +3,8 @@
 //! Defines the core data structures for health checks and reporting.
 
 pub mod checks;
+pub mod clock;
+pub mod permissions;
 
 use crate::core::provider::Provider;
 use serde::{Deserialize, Serialize};
@@ -90,10 +92,50 @@ impl fmt::Display for CheckStatus {
     }
 }
 
+// =============================================================================
+// Check IDs
+// =============================================================================
+
+/// Stable machine IDs for diagnostic checks.
+///
+/// IDs are part of the `doctor --json` contract: scripts and CI can assert on
+/// them, so they never change once published. Provider checks are prefixed
+/// with the provider's CLI name (e.g. `claude.auth.token_expiry`).
+pub mod check_ids {
+    use crate::core::provider::Provider;
+
+    /// Configuration file loads.
+    pub const CONFIG: &str = "config.load";
+    /// Provider CLI is on `PATH`.
+    pub const CLI_INSTALLED: &str = "cli.installed";
+    /// Provider credentials are present.
+    pub const AUTH_STATUS: &str = "auth.status";
+    /// Stored token is not expired or expiring soon.
+    pub const AUTH_TOKEN_EXPIRY: &str = "auth.token_expiry";
+    /// Provider API is reachable.
+    pub const API_REACHABLE: &str = "api.reachable";
+    /// Provider plan limits changed recently.
+    pub const PLAN_LIMITS: &str = "history.plan_limits";
+    /// Every fetch strategy passed its last canary run.
+    pub const CANARY: &str = "history.fetch_canary";
+    /// Credential and state files are not readable by other users.
+    pub const FILE_PERMISSIONS: &str = "security.file_permissions";
+    /// System clock agrees with provider servers.
+    pub const CLOCK_SKEW: &str = "system.clock_skew";
+
+    /// ID of a provider-scoped check.
+    #[must_use]
+    pub fn for_provider(provider: Provider, check: &str) -> String {
+        format!("{}.{check}", provider.cli_name())
+    }
+}
+
 /// A single diagnostic check result.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct DiagnosticCheck {
+    /// Stable machine ID (see [`check_ids`]).
+    pub id: String,
     pub name: String,
     pub status: CheckStatus,
     pub duration: Option<Duration>,
@@ -102,8 +144,9 @@ pub struct DiagnosticCheck {
 impl DiagnosticCheck {
     /// Create a new diagnostic check.
     #[must_use]
-    pub fn new(name: impl Into<String>, status: CheckStatus) -> Self {
+    pub fn new(id: impl Into<String>, name: impl Into<String>, status: CheckStatus) -> Self {
         Self {
+            id: id.into(),
             name: name.into(),
             status,
             duration: None,
@@ -132,6 +175,18 @@ pub struct ProviderHealth {
 }
 
 impl ProviderHealth {
+    /// All checks for this provider, in display order.
+    pub fn checks(&self) -> impl Iterator<Item = &DiagnosticCheck> {
+        [
+            Some(&self.cli_installed),
+            Some(&self.authenticated),
+            self.credential_health.as_ref(),
+            Some(&self.api_reachable),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
     /// Whether all checks for this provider are ready.
     #[must_use]
     pub fn is_ready(&self) -> bool {
@@ -165,6 +220,20 @@ pub struct DoctorReport {
     pub caut_git_sha: String,
     pub config_status: DiagnosticCheck,
     pub providers: Vec<ProviderHealth>,
+    /// Recent provider-side plan limit changes (informational; not counted
+    /// in [`summary`](Self::summary)).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan_limits: Option<DiagnosticCheck>,
+    /// Fetch strategies failing the weekly canary run by `caut serve`
+    /// (informational; not counted in [`summary`](Self::summary)).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canary: Option<DiagnosticCheck>,
+    /// Permissions of credential and state files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_permissions: Option<DiagnosticCheck>,
+    /// Local clock compared with provider servers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clock_skew: Option<DiagnosticCheck>,
     pub total_duration: Duration,
 }
 
@@ -172,7 +241,8 @@ impl DoctorReport {
     /// Returns (`ready_count`, `needs_attention_count`).
     ///
     /// Counts providers as ready only when all checks are pass/skip.
-    /// Adds one needs-attention entry if `config_status` is not ready.
+    /// Adds one needs-attention entry each if `config_status`,
+    /// `file_permissions`, or `clock_skew` needs attention.
     #[must_use]
     pub fn summary(&self) -> (usize, usize) {
         let mut ready = 0;
@@ -181,6 +251,14 @@ impl DoctorReport {
         if self.config_status.status.needs_attention() {
             needs_attention += 1;
         }
+        for check in [&self.file_permissions, &self.clock_skew]
+            .into_iter()
+            .flatten()
+        {
+            if check.status.needs_attention() {
+                needs_attention += 1;
+            }
+        }
 
         for provider in &self.providers {
             if provider.is_ready() {
@@ -192,6 +270,22 @@ impl DoctorReport {
 
         (ready, needs_attention)
     }
+
+    /// All checks in the report, config first.
+    pub fn checks(&self) -> impl Iterator<Item = &DiagnosticCheck> {
+        std::iter::once(&self.config_status)
+            .chain(self.plan_limits.as_ref())
+            .chain(self.canary.as_ref())
+            .chain(self.file_permissions.as_ref())
+            .chain(self.clock_skew.as_ref())
+            .chain(self.providers.iter().flat_map(|p| p.checks()))
+    }
+
+    /// Find a check by its stable ID.
+    #[must_use]
+    pub fn check(&self, id: &str) -> Option<&DiagnosticCheck> {
+        self.checks().find(|check| check.id == id)
+    }
 }
 
 #[cfg(test)]
@@ -221,8 +315,9 @@ mod tests {
 
     #[test]
     fn doctor_report_summary_counts() {
-        let ok = DiagnosticCheck::new("ok", CheckStatus::Pass { details: None });
+        let ok = DiagnosticCheck::new("test.ok", "ok", CheckStatus::Pass { details: None });
         let bad = DiagnosticCheck::new(
+            "test.bad",
             "bad",
             CheckStatus::Fail {
                 reason: "nope".to_string(),
@@ -253,6 +348,10 @@ mod tests {
             caut_git_sha: "deadbeef".to_string(),
             config_status: ok,
             providers: vec![provider_ok, provider_bad],
+            plan_limits: None,
+            canary: None,
+            file_permissions: None,
+            clock_skew: None,
             total_duration: Duration::from_secs(1),
         };
 
@@ -261,14 +360,25 @@ mod tests {
 
     #[test]
     fn doctor_report_serializes_to_json() {
-        let ok = DiagnosticCheck::new("ok", CheckStatus::Pass { details: None });
+        let check = |suffix: &str| {
+            DiagnosticCheck::new(
+                check_ids::for_provider(Provider::Codex, suffix),
+                suffix,
+                CheckStatus::Pass { details: None },
+            )
+        };
+        let ok = DiagnosticCheck::new(
+            check_ids::CONFIG,
+            "Config",
+            CheckStatus::Pass { details: None },
+        );
         let provider = ProviderHealth {
             provider: Provider::Codex,
-            cli_installed: ok.clone(),
+            cli_installed: check(check_ids::CLI_INSTALLED),
             cli_version: Some("1.0.0".to_string()),
-            authenticated: ok.clone(),
+            authenticated: check(check_ids::AUTH_STATUS),
             credential_health: None,
-            api_reachable: ok.clone(),
+            api_reachable: check(check_ids::API_REACHABLE),
         };
 
         let report = DoctorReport {
@@ -276,11 +386,29 @@ mod tests {
             caut_git_sha: "deadbeef".to_string(),
             config_status: ok,
             providers: vec![provider],
+            plan_limits: None,
+            canary: None,
+            file_permissions: None,
+            clock_skew: None,
             total_duration: Duration::from_secs(1),
         };
 
         let json = serde_json::to_string(&report).unwrap();
         assert!(json.contains("\"cautVersion\""));
         assert!(json.contains("\"providers\""));
+        assert!(json.contains("\"id\":\"codex.auth.status\""));
+
+        assert!(report.check("codex.api.reachable").is_some());
+        assert!(report.check("codex.auth.token_expiry").is_none());
+        let ids: Vec<_> = report.checks().map(|c| c.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "config.load",
+                "codex.cli.installed",
+                "codex.auth.status",
+                "codex.api.reachable"
+            ]
+        );
     }
 }
@@ -0,0 +1,200 @@
+//! Permission checks for files holding secrets.
+//!
+//! Provider credential files (`~/.claude/.credentials.json`,
+//! `~/.codex/auth.json`, ...) and caut's own config and state can carry
+//! tokens, API keys, and account details. Any of them that group or other
+//! users can read or write is flagged, and `doctor --fix` restricts it to the
+//! owner (mode 600, as caut writes them; see [`private_file`]). Missing files
+//! are ignored. On non-Unix platforms the check is skipped.
+
+use std::path::{Path, PathBuf};
+
+use super::{CheckStatus, DiagnosticCheck, check_ids};
+use crate::core::provider::Provider;
+use crate::providers::claude::installations;
+use crate::storage::config::Config;
+use crate::storage::{AppPaths, HistoryStore, private_file};
+
+/// Mode applied by `doctor --fix`.
+pub const SECURE_MODE: u32 = private_file::PRIVATE_MODE;
+
+/// A sensitive file that other users can access.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissiveFile {
+    pub path: PathBuf,
+    /// Permission bits (e.g. `0o644`).
+    pub mode: u32,
+}
+
+/// Credential and state files worth protecting, whether or not they exist.
+#[must_use]
+pub fn sensitive_files() -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = directories::BaseDirs::new()
+        .map(|dirs| {
+            let home = dirs.home_dir();
+            Provider::ALL
+                .iter()
+                .filter_map(|p| p.credentials_path())
+                .map(|path| home.join(path))
+                .chain(installations::credential_files(home))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let paths = AppPaths::new();
+    files.extend([
+        Config::config_path(),
+        paths.token_accounts_file(),
+        paths.budgets_file(),
+        paths.alerts_file(),
+        paths.alert_state_file(),
+    ]);
+    files.extend(HistoryStore::database_files(&paths.history_db_file()));
+    files
+}
+
+/// Files in `paths` that exist and are accessible to group or other users.
+#[must_use]
+pub fn find_permissive(paths: &[PathBuf]) -> Vec<PermissiveFile> {
+    paths
+        .iter()
+        .filter_map(|path| {
+            let mode = file_mode(path)?;
+            (mode & 0o077 != 0).then(|| PermissiveFile {
+                path: path.clone(),
+                mode,
+            })
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+fn file_mode(path: &Path) -> Option<u32> {
+    use std::os::unix::fs::PermissionsExt;
+    let metadata = std::fs::metadata(path).ok()?;
+    metadata
+        .is_file()
+        .then(|| metadata.permissions().mode() & 0o777)
+}
+
+#[cfg(not(unix))]
+fn file_mode(_path: &Path) -> Option<u32> {
+    None
+}
+
+/// Restrict each file to its owner.
+///
+/// # Errors
+/// Returns the first file that could not be changed, with the I/O error.
+pub fn fix(files: &[PermissiveFile]) -> Result<(), (PathBuf, std::io::Error)> {
+    for file in files {
+        private_file::restrict(&file.path).map_err(|e| (file.path.clone(), e))?;
+    }
+    Ok(())
+}
+
+/// Check sensitive file permissions, first tightening them when `apply_fix`
+/// is set.
+#[must_use]
+pub fn check_file_permissions(apply_fix: bool) -> DiagnosticCheck {
+    let start = std::time::Instant::now();
+    let status = permission_status(&sensitive_files(), apply_fix);
+    DiagnosticCheck::new(
+        check_ids::FILE_PERMISSIONS,
+        "Credential file permissions",
+        status,
+    )
+    .with_duration(start.elapsed())
+}
+
+fn permission_status(paths: &[PathBuf], apply_fix: bool) -> CheckStatus {
+    if cfg!(not(unix)) {
+        return CheckStatus::Skipped {
+            reason: "File modes are only checked on Unix".to_string(),
+        };
+    }
+
+    let permissive = find_permissive(paths);
+    if permissive.is_empty() {
+        return CheckStatus::Pass {
+            details: Some("Owner-only access".to_string()),
+        };
+    }
+
+    if apply_fix {
+        return match fix(&permissive) {
+            Ok(()) => CheckStatus::Pass {
+                details: Some(format!(
+                    "Set {} file{} to {SECURE_MODE:o}",
+                    permissive.len(),
+                    if permissive.len() == 1 { "" } else { "s" }
+                )),
+            },
+            Err((path, e)) => CheckStatus::Fail {
+                reason: format!("Could not chmod {}: {e}", path.display()),
+                suggestion: Some(format!("Run: chmod 600 {}", path.display())),
+            },
+        };
+    }
+
+    let listed = permissive
+        .iter()
+        .map(|file| format!("{} ({:o})", file.path.display(), file.mode))
+        .collect::<Vec<_>>()
+        .join(", ");
+    CheckStatus::Fail {
+        reason: format!("Readable by other users: {listed}"),
+        suggestion: Some("Run: caut doctor --fix".to_string()),
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use tempfile::TempDir;
+
+    fn write_with_mode(dir: &Path, name: &str, mode: u32) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, "{}").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode)).unwrap();
+        path
+    }
+
+    #[test]
+    fn flags_group_or_world_access() {
+        let dir = TempDir::new().unwrap();
+        let paths = vec![
+            write_with_mode(dir.path(), "auth.json", 0o644),
+            write_with_mode(dir.path(), "secure.json", 0o600),
+            write_with_mode(dir.path(), "group.json", 0o640),
+            dir.path().join("missing.json"),
+        ];
+
+        let permissive = find_permissive(&paths);
+        assert_eq!(permissive.len(), 2);
+        assert_eq!(permissive[0].mode, 0o644);
+        assert!(matches!(
+            permission_status(&paths, false),
+            CheckStatus::Fail { .. }
+        ));
+    }
+
+    #[test]
+    fn fix_restricts_files_to_owner() {
+        let dir = TempDir::new().unwrap();
+        let path = write_with_mode(dir.path(), ".credentials.json", 0o666);
+        let paths = vec![path.clone()];
+
+        let status = permission_status(&paths, true);
+        assert_eq!(
+            status,
+            CheckStatus::Pass {
+                details: Some("Set 1 file to 600".to_string())
+            }
+        );
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, SECURE_MODE);
+        assert!(find_permissive(&paths).is_empty());
+    }
+}
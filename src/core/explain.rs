@@ -0,0 +1,137 @@
+//! Plain-language derivations for usage numbers (`caut usage --explain`).
+//!
+//! When a percentage disagrees with the provider's own UI, the first question
+//! is where it came from. Each window is explained from what the provider
+//! actually returned: an allowance such as `12/50 premium requests` yields
+//! the formula behind the percentage, otherwise the percentage is the
+//! provider's own figure. Every explanation names the source and when it was
+//! fetched.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Local, Utc};
+
+use crate::core::models::{ProviderPayload, RateWindow};
+use crate::core::plan_limits::Allowance;
+
+/// Explanation lines for one provider's payload, in display order.
+#[must_use]
+pub fn explain_payload(payload: &ProviderPayload) -> Vec<String> {
+    let mut lines = vec![format!(
+        "source: {} response at {}",
+        payload.source,
+        clock_time(payload.usage.updated_at)
+    )];
+
+    let windows = [
+        ("Session", payload.usage.primary.as_ref()),
+        ("Weekly", payload.usage.secondary.as_ref()),
+        ("Opus/Sonnet", payload.usage.tertiary.as_ref()),
+    ];
+    lines.extend(
+        windows
+            .into_iter()
+            .filter_map(|(label, window)| Some(explain_window(label, window?))),
+    );
+
+    if let Some(credits) = &payload.credits {
+        lines.push(format!(
+            "Credits: {:.1} left, as reported at {}",
+            credits.remaining,
+            clock_time(credits.updated_at)
+        ));
+    }
+
+    lines
+}
+
+/// Explain one rate window.
+#[must_use]
+pub fn explain_window(label: &str, window: &RateWindow) -> String {
+    let used = window.used_percent;
+    let mut line = match window
+        .reset_description
+        .as_deref()
+        .and_then(Allowance::parse)
+    {
+        Some(allowance) if allowance.limit > 0.0 => format!(
+            "{label}: used {used:.0}% = {} / {} {}; left {:.0}% = 100 − {used:.0}",
+            format_amount(allowance.used),
+            format_amount(allowance.limit),
+            allowance.unit,
+            window.remaining_percent()
+        ),
+        _ => format!(
+            "{label}: used {used:.0}% as reported by the provider; left {:.0}% = 100 − {used:.0}",
+            window.remaining_percent()
+        ),
+    };
+
+    if let Some(minutes) = window.window_minutes {
+        let _ = write!(line, "; {} window", format_window_length(minutes));
+    }
+    if let Some(resets_at) = window.resets_at {
+        let _ = write!(
+            line,
+            "; resets {}",
+            resets_at.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        );
+    }
+    line
+}
+
+fn clock_time(at: DateTime<Utc>) -> String {
+    at.with_timezone(&Local).format("%H:%M:%S").to_string()
+}
+
+fn format_amount(value: f64) -> String {
+    if value.fract().abs() < f64::EPSILON {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+fn format_window_length(minutes: i32) -> String {
+    match minutes {
+        m if m % (24 * 60) == 0 => format!("{}d", m / (24 * 60)),
+        m if m % 60 == 0 => format!("{}h", m / 60),
+        m => format!("{m}m"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(used_percent: f64, description: Option<&str>) -> RateWindow {
+        RateWindow {
+            used_percent,
+            window_minutes: Some(300),
+            resets_at: None,
+            reset_description: description.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn allowance_windows_show_the_formula() {
+        let line = explain_window("Session", &window(24.0, Some("12/50 premium requests")));
+        assert_eq!(
+            line,
+            "Session: used 24% = 12 / 50 premium requests; left 76% = 100 − 24; 5h window"
+        );
+    }
+
+    #[test]
+    fn reported_percentages_are_attributed_to_the_provider() {
+        let line = explain_window("Weekly", &window(38.0, Some("resets Monday")));
+        assert!(line.starts_with("Weekly: used 38% as reported by the provider; left 62%"));
+    }
+
+    #[test]
+    fn window_lengths_use_the_largest_unit() {
+        assert_eq!(format_window_length(7 * 24 * 60), "7d");
+        assert_eq!(format_window_length(300), "5h");
+        assert_eq!(format_window_length(90), "90m");
+    }
+}
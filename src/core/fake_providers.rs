@@ -0,0 +1,236 @@
+//! Deterministic fake providers for demos and offline tests.
+//!
+//! When `CAUT_FAKE_PROVIDERS=1` is set, the fetch pipeline and cost scanner
+//! return fixture data instead of contacting real providers. Values are
+//! derived from the provider alone, and every timestamp is measured from a
+//! fixed clock ([`FAKE_EPOCH`], or `CAUT_FAKE_NOW` as RFC 3339), so the
+//! same fixtures come out on every run.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+use super::fetch_plan::{FetchAttempt, FetchKind, FetchOutcome};
+use super::models::{
+    CostDailyEntry, CostPayload, CostTotals, ProviderIdentity, RateWindow, UsageSnapshot,
+};
+use super::provider::Provider;
+
+/// Environment variable that enables fake providers.
+pub const ENV_FAKE_PROVIDERS: &str = "CAUT_FAKE_PROVIDERS";
+
+/// Environment variable overriding the fixture clock (RFC 3339).
+pub const ENV_FAKE_NOW: &str = "CAUT_FAKE_NOW";
+
+/// The fixture clock when `CAUT_FAKE_NOW` is not set.
+pub const FAKE_EPOCH: &str = "2026-01-15T09:00:00Z";
+
+/// Source label reported for fake data.
+pub const FAKE_SOURCE: &str = "fake";
+
+/// Strategy ID recorded for fake fetch attempts.
+pub const FAKE_STRATEGY_ID: &str = "fake-fixture";
+
+/// Number of daily cost entries produced for fake cost payloads.
+const FAKE_COST_DAYS: i64 = 7;
+
+/// Check whether fake provider mode is enabled.
+#[must_use]
+pub fn is_enabled() -> bool {
+    std::env::var(ENV_FAKE_PROVIDERS).is_ok_and(|v| parse_flag(&v))
+}
+
+fn parse_flag(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
+/// The time fixtures are built at: `CAUT_FAKE_NOW` if it parses, otherwise
+/// [`FAKE_EPOCH`].
+#[must_use]
+pub fn fixture_now() -> DateTime<Utc> {
+    std::env::var(ENV_FAKE_NOW)
+        .ok()
+        .and_then(|value| DateTime::parse_from_rfc3339(value.trim()).ok())
+        .or_else(|| DateTime::parse_from_rfc3339(FAKE_EPOCH).ok())
+        .map_or(DateTime::UNIX_EPOCH, |at| at.with_timezone(&Utc))
+}
+
+/// Stable per-provider seed (position in `Provider::ALL`).
+fn seed(provider: Provider) -> u32 {
+    let index = Provider::ALL
+        .iter()
+        .position(|p| *p == provider)
+        .unwrap_or_default();
+    u32::try_from(index).unwrap_or_default()
+}
+
+/// Build a fixture usage snapshot for a provider.
+#[must_use]
+pub fn usage_snapshot(provider: Provider) -> UsageSnapshot {
+    let seed = seed(provider);
+    let now = fixture_now();
+
+    let primary = RateWindow {
+        used_percent: f64::from((seed * 37 + 18) % 100),
+        window_minutes: Some(300),
+        resets_at: Some(now + Duration::minutes(i64::from(30 + seed * 11 % 240))),
+        reset_description: None,
+    };
+    let secondary = RateWindow {
+        used_percent: f64::from((seed * 23 + 41) % 100),
+        window_minutes: Some(10_080),
+        resets_at: Some(now + Duration::hours(i64::from(12 + seed * 7 % 150))),
+        reset_description: None,
+    };
+
+    let mut snapshot = UsageSnapshot::new(primary);
+    snapshot.secondary = Some(secondary);
+    if provider == Provider::Claude {
+        snapshot.tertiary = Some(RateWindow {
+            used_percent: 92.0,
+            window_minutes: Some(10_080),
+            resets_at: Some(now + Duration::hours(36)),
+            reset_description: None,
+        });
+    }
+    snapshot.updated_at = now;
+    snapshot.identity = Some(ProviderIdentity {
+        account_email: Some(format!("demo@{}.example", provider.cli_name())),
+        account_organization: Some("Demo Org".to_string()),
+        login_method: Some(FAKE_SOURCE.to_string()),
+    });
+    snapshot
+}
+
+/// Build a successful fetch outcome backed by fixture data.
+#[must_use]
+pub fn fetch_outcome(provider: Provider) -> FetchOutcome {
+    let attempt = FetchAttempt {
+        strategy_id: FAKE_STRATEGY_ID.to_string(),
+        kind: FetchKind::LocalProbe,
+        started_at: fixture_now(),
+        duration_ms: 0,
+        success: true,
+        error: None,
+    };
+    FetchOutcome::success(
+        provider,
+        usage_snapshot(provider),
+        FAKE_SOURCE,
+        vec![attempt],
+    )
+}
+
+/// Build a fixture cost payload for a provider.
+#[must_use]
+pub fn cost_payload(provider: Provider) -> CostPayload {
+    let seed = i64::from(seed(provider));
+    let now = fixture_now();
+    let today = now.date_naive();
+
+    let daily: Vec<CostDailyEntry> = (0..FAKE_COST_DAYS)
+        .rev()
+        .map(|offset| daily_entry(today - Duration::days(offset), seed + offset))
+        .collect();
+
+    let totals = daily.iter().fold(CostTotals::default(), |mut acc, day| {
+        acc.input_tokens = Some(acc.input_tokens.unwrap_or(0) + day.input_tokens.unwrap_or(0));
+        acc.output_tokens = Some(acc.output_tokens.unwrap_or(0) + day.output_tokens.unwrap_or(0));
+        acc.total_tokens = Some(acc.total_tokens.unwrap_or(0) + day.total_tokens.unwrap_or(0));
+        acc.total_cost = Some(acc.total_cost.unwrap_or(0.0) + day.total_cost.unwrap_or(0.0));
+        acc
+    });
+
+    let session = daily.last();
+    CostPayload {
+        provider: provider.cli_name().to_string(),
+        source: FAKE_SOURCE.to_string(),
+        updated_at: now,
+        session_tokens: session.and_then(|d| d.total_tokens),
+        session_cost_usd: session.and_then(|d| d.total_cost),
+        last_30_days_tokens: totals.total_tokens,
+        last_30_days_cost_usd: totals.total_cost,
+        daily,
+        totals: Some(totals),
+    }
+}
+
+#[allow(clippy::cast_precision_loss)] // fixture token counts are far below 2^52
+fn daily_entry(date: NaiveDate, seed: i64) -> CostDailyEntry {
+    let input = 120_000 + (seed * 48_271) % 400_000;
+    let output = 30_000 + (seed * 16_807) % 90_000;
+    let total = input + output;
+    let cost = (input as f64).mul_add(3.0e-6, output as f64 * 15.0e-6);
+
+    CostDailyEntry {
+        date: date.format("%Y-%m-%d").to_string(),
+        input_tokens: Some(input),
+        output_tokens: Some(output),
+        cache_read_tokens: None,
+        cache_creation_tokens: None,
+        total_tokens: Some(total),
+        total_cost: Some((cost * 100.0).round() / 100.0),
+        models_used: Some(vec!["demo-model".to_string()]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_flag_accepts_truthy_values() {
+        assert!(parse_flag("1"));
+        assert!(parse_flag("true"));
+        assert!(parse_flag(" YES "));
+        assert!(!parse_flag("0"));
+        assert!(!parse_flag(""));
+    }
+
+    #[test]
+    fn usage_snapshot_is_deterministic() {
+        for provider in Provider::ALL {
+            let a = usage_snapshot(*provider);
+            let b = usage_snapshot(*provider);
+            assert_eq!(
+                a.primary.as_ref().map(|w| w.used_percent),
+                b.primary.as_ref().map(|w| w.used_percent)
+            );
+            assert_eq!(
+                a.secondary.as_ref().map(|w| w.used_percent),
+                b.secondary.as_ref().map(|w| w.used_percent)
+            );
+            assert!(a.primary.unwrap().used_percent < 100.0);
+        }
+    }
+
+    #[test]
+    fn fixtures_serialize_identically_across_runs() {
+        let render = || {
+            let usage: Vec<_> = Provider::ALL.iter().map(|p| usage_snapshot(*p)).collect();
+            let costs: Vec<_> = Provider::ALL.iter().map(|p| cost_payload(*p)).collect();
+            serde_json::to_string(&(usage, costs)).unwrap()
+        };
+        let first = render();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        assert_eq!(first, render());
+    }
+
+    #[test]
+    fn fetch_outcome_is_successful_and_labelled() {
+        let outcome = fetch_outcome(Provider::Codex);
+        assert!(outcome.result.is_ok());
+        assert_eq!(outcome.source_label, FAKE_SOURCE);
+        assert_eq!(outcome.attempts.len(), 1);
+        assert_eq!(outcome.attempts[0].strategy_id, FAKE_STRATEGY_ID);
+    }
+
+    #[test]
+    fn cost_payload_totals_match_daily() {
+        let payload = cost_payload(Provider::Claude);
+        assert_eq!(payload.daily.len(), 7);
+        let sum: i64 = payload.daily.iter().filter_map(|d| d.total_tokens).sum();
+        assert_eq!(payload.totals.unwrap().total_tokens, Some(sum));
+    }
+}
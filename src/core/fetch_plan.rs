@@ -5,6 +5,7 @@
 
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use chrono::{DateTime, Utc};
 
@@ -197,6 +198,11 @@ impl FetchPlan {
     #[must_use]
     pub fn for_mode(&self, mode: SourceMode) -> Vec<&FetchStrategy> {
         match mode {
+            SourceMode::Auto if SKIP_WEB.load(Ordering::Relaxed) => self
+                .strategies
+                .iter()
+                .filter(|s| !matches!(s.kind, FetchKind::Web | FetchKind::WebDashboard))
+                .collect(),
             SourceMode::Auto => self.strategies.iter().collect(),
             SourceMode::Web => self
                 .strategies
@@ -217,6 +223,16 @@ impl FetchPlan {
     }
 }
 
+/// Whether automatic source selection skips web strategies.
+static SKIP_WEB: AtomicBool = AtomicBool::new(false);
+
+/// Skip web and web-dashboard strategies in [`SourceMode::Auto`] for the rest
+/// of the run, or stop skipping them. An explicit `--source web` still uses
+/// them.
+pub fn set_skip_web(skip: bool) {
+    SKIP_WEB.store(skip, Ordering::Relaxed);
+}
+
 /// Type alias for async fetch function.
 pub type FetchFn =
     Box<dyn Fn() -> Pin<Box<dyn Future<Output = Result<UsageSnapshot>> + Send>> + Send + Sync>;
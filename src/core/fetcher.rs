@@ -0,0 +1,66 @@
+//! Provider fetcher trait.
+//!
+//! Each implemented provider registers a [`ProviderFetcher`] in the
+//! [`ProviderRegistry`](super::provider::ProviderRegistry), which the fetch
+//! pipeline, cost scanning, and auth checks use instead of matching on the
+//! provider. Additional fetchers can be registered at runtime with
+//! [`ProviderRegistry::register`](super::provider::ProviderRegistry::register).
+//!
+//! Details a provider reports beyond its rate windows, such as a credit
+//! balance or locally recorded spend, are added by the fetcher's
+//! [`enrich`](ProviderFetcher::enrich) once usage has been fetched.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use super::cost_scanner::CostScanner;
+use super::credential_health::{AuthHealthAggregator, ProviderAuthHealth};
+use super::fetch_plan::FetchPlan;
+use super::models::{CostPayload, ProviderPayload, UsageSnapshot};
+use super::provider::Provider;
+use crate::error::{CautError, Result};
+
+/// Boxed future returned by fetcher methods, so fetchers can be trait objects.
+pub type FetchFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// Boxed future returned by [`ProviderFetcher::enrich`].
+pub type EnrichFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+/// Usage, cost, and auth access for one provider.
+pub trait ProviderFetcher: Send + Sync {
+    /// Provider this fetcher serves.
+    fn provider(&self) -> Provider;
+
+    /// Ordered strategies for fetching usage.
+    fn fetch_plan(&self) -> FetchPlan;
+
+    /// Run one strategy from [`fetch_plan`](Self::fetch_plan).
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot>;
+
+    /// Scan local cost data. Defaults to the shared [`CostScanner`].
+    fn fetch_cost(&self) -> FetchFuture<'_, CostPayload> {
+        let provider = self.provider();
+        Box::pin(async move { CostScanner::new().scan(provider, false).await })
+    }
+
+    /// Check the health of the provider's stored credentials.
+    fn auth_status(&self) -> ProviderAuthHealth {
+        AuthHealthAggregator::new().check_provider(self.provider())
+    }
+
+    /// Add provider-specific details to the payload of a successful live
+    /// fetch. A detail that cannot be read is logged and left unset. Adds
+    /// nothing by default.
+    fn enrich<'a>(&'a self, _payload: &'a mut ProviderPayload) -> EnrichFuture<'a> {
+        Box::pin(std::future::ready(()))
+    }
+}
+
+/// Error for a strategy ID the fetcher does not know.
+#[must_use]
+pub fn unknown_strategy(provider: Provider, strategy_id: &str) -> CautError {
+    CautError::FetchFailed {
+        provider: provider.cli_name().to_string(),
+        reason: format!("Unknown strategy: {strategy_id}"),
+    }
+}
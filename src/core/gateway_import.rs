@@ -0,0 +1,410 @@
+//! Usage imported from LLM gateways.
+//!
+//! Teams that route requests through Helicone, or a gateway exposing the
+//! OpenAI usage API, already have every request logged with its model,
+//! tokens, and cost. Importing those logs into the history store lets
+//! `caut history show` chart gateway spend next to provider snapshots.
+//!
+//! Requests are rolled up per day and model. Re-importing a day replaces its
+//! rows (see [`crate::storage::HistoryStore::record_gateway_usage`]), so an
+//! import can be re-run over an overlapping range. Requests the gateway did
+//! not price are priced with [`PricingTable`] at API rates.
+//!
+//! Sources:
+//! - Helicone request API (`POST /v1/request/query`), key from
+//!   `HELICONE_API_KEY`, base URL from `HELICONE_API_URL`
+//! - OpenAI usage API (`GET /v1/organization/usage/completions`), key from
+//!   `GATEWAY_API_KEY` or `OPENAI_ADMIN_KEY`
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::clock_skew;
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::core::pricing::PricingTable;
+use crate::error::{CautError, Result};
+
+/// Environment variable holding the Helicone API key.
+pub const HELICONE_KEY_ENV: &str = "HELICONE_API_KEY";
+
+/// Environment variable overriding the Helicone API base URL.
+pub const HELICONE_URL_ENV: &str = "HELICONE_API_URL";
+
+/// Environment variable holding the key for an OpenAI-compatible gateway.
+pub const GATEWAY_KEY_ENV: &str = "GATEWAY_API_KEY";
+
+/// Fallback key for the OpenAI usage API itself.
+pub const OPENAI_ADMIN_KEY_ENV: &str = "OPENAI_ADMIN_KEY";
+
+const HELICONE_DEFAULT_URL: &str = "https://api.helicone.ai";
+const OPENAI_DEFAULT_URL: &str = "https://api.openai.com";
+
+/// Requests fetched per Helicone page.
+const HELICONE_PAGE_SIZE: usize = 1000;
+
+/// Upper bound on pages read in one import.
+const MAX_PAGES: usize = 200;
+
+/// One day of a gateway's requests to one model.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayUsage {
+    /// Import source, e.g. `helicone` or `gateway`.
+    pub gateway: String,
+    pub day: NaiveDate,
+    pub model: String,
+    pub requests: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cost_usd: f64,
+}
+
+/// Logged requests to one model, before rollup.
+#[derive(Debug, Clone)]
+struct LoggedRequests {
+    day: NaiveDate,
+    model: String,
+    requests: i64,
+    input_tokens: i64,
+    cached_tokens: i64,
+    output_tokens: i64,
+    /// Cost reported by the gateway, if it priced the request.
+    cost_usd: Option<f64>,
+}
+
+/// Sum logged requests per day and model, pricing unpriced ones locally.
+fn rollup(gateway: &str, logged: Vec<LoggedRequests>) -> Vec<GatewayUsage> {
+    let pricing = PricingTable::current();
+    let mut rows: BTreeMap<(NaiveDate, String), GatewayUsage> = BTreeMap::new();
+
+    for entry in logged {
+        let cost = entry.cost_usd.unwrap_or_else(|| {
+            pricing.resolve(&entry.model).map_or(0.0, |p| {
+                p.calculate_cost(
+                    entry.input_tokens - entry.cached_tokens,
+                    entry.output_tokens,
+                    entry.cached_tokens,
+                    0,
+                )
+                .total_cost_usd
+            })
+        });
+        let row = rows
+            .entry((entry.day, entry.model.clone()))
+            .or_insert_with(|| GatewayUsage {
+                gateway: gateway.to_string(),
+                day: entry.day,
+                model: entry.model,
+                requests: 0,
+                input_tokens: 0,
+                output_tokens: 0,
+                cost_usd: 0.0,
+            });
+        row.requests += entry.requests;
+        row.input_tokens += entry.input_tokens;
+        row.output_tokens += entry.output_tokens;
+        row.cost_usd += cost;
+    }
+
+    rows.into_values().collect()
+}
+
+fn status_error(gateway: &str, status: reqwest::StatusCode) -> CautError {
+    let reason = if matches!(status.as_u16(), 401 | 403) {
+        "API key was rejected".to_string()
+    } else {
+        format!("request returned {status}")
+    };
+    CautError::FetchFailed {
+        provider: gateway.to_string(),
+        reason,
+    }
+}
+
+fn network_error(e: &reqwest::Error) -> CautError {
+    if e.is_timeout() {
+        CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+    } else {
+        CautError::Network(e.to_string())
+    }
+}
+
+// =============================================================================
+// Helicone
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct HeliconeResponse {
+    #[serde(default)]
+    data: Vec<HeliconeRequest>,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HeliconeRequest {
+    request_created_at: DateTime<Utc>,
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    request_model: Option<String>,
+    #[serde(default)]
+    prompt_tokens: Option<i64>,
+    #[serde(default)]
+    prompt_cache_read_tokens: Option<i64>,
+    #[serde(default)]
+    completion_tokens: Option<i64>,
+    #[serde(default)]
+    cost: Option<f64>,
+    #[serde(default, rename = "costUSD")]
+    cost_usd: Option<f64>,
+}
+
+impl HeliconeRequest {
+    fn into_logged(self) -> LoggedRequests {
+        LoggedRequests {
+            day: self.request_created_at.with_timezone(&Local).date_naive(),
+            model: self
+                .model
+                .or(self.request_model)
+                .filter(|m| !m.is_empty())
+                .unwrap_or_else(|| "unknown".to_string()),
+            requests: 1,
+            input_tokens: self.prompt_tokens.unwrap_or(0),
+            cached_tokens: self.prompt_cache_read_tokens.unwrap_or(0),
+            output_tokens: self.completion_tokens.unwrap_or(0),
+            cost_usd: self.cost.or(self.cost_usd),
+        }
+    }
+}
+
+/// Import Helicone-logged requests created at or after `since`.
+///
+/// # Errors
+/// Returns an error if no API key is set, a request fails or is rejected, or
+/// a response does not decode.
+pub async fn fetch_helicone(url: Option<&str>, since: DateTime<Utc>) -> Result<Vec<GatewayUsage>> {
+    let key = std::env::var(HELICONE_KEY_ENV)
+        .ok()
+        .filter(|k| !k.trim().is_empty())
+        .ok_or_else(|| {
+            CautError::Config(format!("Set {HELICONE_KEY_ENV} to import from Helicone"))
+        })?;
+    let base = url
+        .map(str::to_string)
+        .or_else(|| std::env::var(HELICONE_URL_ENV).ok())
+        .unwrap_or_else(|| HELICONE_DEFAULT_URL.to_string());
+    let endpoint = format!("{}/v1/request/query", base.trim_end_matches('/'));
+
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let mut logged = Vec::new();
+    for page in 0..MAX_PAGES {
+        let response = client
+            .post(&endpoint)
+            .bearer_auth(&key)
+            .json(&serde_json::json!({
+                "filter": { "request": { "created_at": { "gte": since.to_rfc3339() } } },
+                "sort": { "created_at": "asc" },
+                "offset": page * HELICONE_PAGE_SIZE,
+                "limit": HELICONE_PAGE_SIZE,
+                "isCached": false,
+            }))
+            .send()
+            .await
+            .map_err(|e| network_error(&e))?;
+        clock_skew::observe(&response);
+        if !response.status().is_success() {
+            return Err(status_error("helicone", response.status()));
+        }
+
+        let body: HeliconeResponse = response
+            .json()
+            .await
+            .map_err(|e| CautError::ParseResponse(format!("Invalid Helicone response: {e}")))?;
+        if let Some(error) = body.error.filter(|e| !e.is_null()) {
+            return Err(CautError::FetchFailed {
+                provider: "helicone".to_string(),
+                reason: error.to_string(),
+            });
+        }
+        let count = body.data.len();
+        logged.extend(body.data.into_iter().map(HeliconeRequest::into_logged));
+        if count < HELICONE_PAGE_SIZE {
+            break;
+        }
+    }
+
+    Ok(rollup("helicone", logged))
+}
+
+// =============================================================================
+// OpenAI usage API
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct UsagePage {
+    #[serde(default)]
+    data: Vec<UsageBucket>,
+    #[serde(default)]
+    has_more: bool,
+    #[serde(default)]
+    next_page: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UsageBucket {
+    start_time: i64,
+    #[serde(default)]
+    results: Vec<UsageResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UsageResult {
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    input_tokens: i64,
+    #[serde(default)]
+    input_cached_tokens: i64,
+    #[serde(default)]
+    output_tokens: i64,
+    #[serde(default)]
+    num_model_requests: i64,
+}
+
+fn usage_page_logged(page: UsagePage) -> Vec<LoggedRequests> {
+    let mut logged = Vec::new();
+    for bucket in page.data {
+        // Buckets start at UTC midnight.
+        let Some(day) = DateTime::<Utc>::from_timestamp(bucket.start_time, 0) else {
+            continue;
+        };
+        logged.extend(bucket.results.into_iter().map(|result| LoggedRequests {
+            day: day.date_naive(),
+            model: result.model.unwrap_or_else(|| "unknown".to_string()),
+            requests: result.num_model_requests,
+            input_tokens: result.input_tokens,
+            cached_tokens: result.input_cached_tokens,
+            output_tokens: result.output_tokens,
+            cost_usd: None,
+        }));
+    }
+    logged
+}
+
+/// Import daily completions usage from a gateway serving the OpenAI usage
+/// API, starting at `since`.
+///
+/// # Errors
+/// Returns an error if no key is set, a request fails or is rejected, or a
+/// response does not decode.
+pub async fn fetch_openai_usage(
+    url: Option<&str>,
+    since: DateTime<Utc>,
+) -> Result<Vec<GatewayUsage>> {
+    let key = [GATEWAY_KEY_ENV, OPENAI_ADMIN_KEY_ENV]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|k| !k.trim().is_empty()))
+        .ok_or_else(|| {
+            CautError::Config(format!(
+                "Set {GATEWAY_KEY_ENV} (or {OPENAI_ADMIN_KEY_ENV}) to import gateway usage"
+            ))
+        })?;
+    let base = url.unwrap_or(OPENAI_DEFAULT_URL).trim_end_matches('/');
+    let endpoint = format!(
+        "{base}/v1/organization/usage/completions?start_time={}&bucket_width=1d&group_by=model&limit=31",
+        since.timestamp()
+    );
+
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let mut logged = Vec::new();
+    let mut next_page: Option<String> = None;
+    for _ in 0..MAX_PAGES {
+        let page_url = match &next_page {
+            Some(page) => format!("{endpoint}&page={page}"),
+            None => endpoint.clone(),
+        };
+        let response = client
+            .get(&page_url)
+            .bearer_auth(&key)
+            .send()
+            .await
+            .map_err(|e| network_error(&e))?;
+        clock_skew::observe(&response);
+        if !response.status().is_success() {
+            return Err(status_error("gateway", response.status()));
+        }
+
+        let page: UsagePage = response
+            .json()
+            .await
+            .map_err(|e| CautError::ParseResponse(format!("Invalid usage API response: {e}")))?;
+        next_page = page.next_page.clone().filter(|_| page.has_more);
+        logged.extend(usage_page_logged(page));
+        if next_page.is_none() {
+            break;
+        }
+    }
+
+    Ok(rollup("gateway", logged))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn helicone_requests_roll_up_per_day_and_model() {
+        let body: HeliconeResponse = serde_json::from_str(
+            r#"{"data": [
+                {"request_created_at": "2026-10-14T12:00:00Z", "model": "gpt-4o",
+                 "prompt_tokens": 1000, "completion_tokens": 200, "cost": 0.01},
+                {"request_created_at": "2026-10-14T12:30:00Z", "request_model": "gpt-4o",
+                 "prompt_tokens": 3000, "completion_tokens": 100, "costUSD": 0.02},
+                {"request_created_at": "2026-10-14T13:00:00Z", "model": "my-finetune",
+                 "prompt_tokens": 50}
+            ], "error": null}"#,
+        )
+        .unwrap();
+        let logged = body
+            .data
+            .into_iter()
+            .map(HeliconeRequest::into_logged)
+            .collect();
+
+        let rows = rollup("helicone", logged);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].model, "gpt-4o");
+        assert_eq!(rows[0].requests, 2);
+        assert_eq!(rows[0].input_tokens, 4000);
+        assert!((rows[0].cost_usd - 0.03).abs() < 1e-9);
+        // Unknown models without a gateway price count as free.
+        assert_eq!(rows[1].model, "my-finetune");
+        assert!(rows[1].cost_usd.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn usage_api_buckets_are_priced_locally() {
+        let page: UsagePage = serde_json::from_str(
+            r#"{"data": [{"start_time": 1791936000, "results": [
+                {"model": "gpt-4o", "input_tokens": 2000000, "input_cached_tokens": 0,
+                 "output_tokens": 100000, "num_model_requests": 40}
+            ]}], "has_more": false, "next_page": null}"#,
+        )
+        .unwrap();
+
+        let rows = rollup("gateway", usage_page_logged(page));
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].day, NaiveDate::from_ymd_opt(2026, 10, 14).unwrap());
+        assert_eq!(rows[0].requests, 40);
+        let expected = PricingTable::current()
+            .resolve("gpt-4o")
+            .unwrap()
+            .calculate_cost(2_000_000, 100_000, 0, 0)
+            .total_cost_usd;
+        assert!(expected > 0.0);
+        assert!((rows[0].cost_usd - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,321 @@
+//! Git-correlated cost attribution.
+//!
+//! Estimates agent cost per commit and per merged PR by matching session logs
+//! against `git log`. Sessions are selected by working directory, then each
+//! session's cost is split evenly across the commits made while it was active
+//! or shortly after it ended. This is a heuristic ROI measure, not an exact
+//! accounting.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::Serialize;
+
+use crate::core::cli_runner::{CLI_TIMEOUT, run_command};
+use crate::error::{CautError, Result};
+
+/// Minutes after a session ends during which a commit is still credited to it.
+pub const COMMIT_GRACE_MINUTES: i64 = 120;
+
+/// Field separator used in the `git log` format string.
+const FIELD_SEP: char = '\u{1f}';
+
+/// A commit read from `git log`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitCommit {
+    pub sha: String,
+    pub committed_at: DateTime<Utc>,
+    pub subject: String,
+    /// PR number parsed from a squash `(#123)` or merge-commit subject.
+    pub pr_number: Option<u32>,
+}
+
+/// Time span and cost of one agent session in the repository.
+#[derive(Debug, Clone)]
+pub struct SessionSpan {
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub cost_usd: f64,
+}
+
+/// Estimated cost attributed to a commit.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitCost {
+    pub sha: String,
+    pub committed_at: DateTime<Utc>,
+    pub subject: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pr_number: Option<u32>,
+    pub cost_usd: f64,
+    /// Number of sessions that contributed to this commit.
+    pub session_count: usize,
+}
+
+/// Estimated cost attributed to a merged PR.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestCost {
+    pub number: u32,
+    pub commit_count: usize,
+    pub cost_usd: f64,
+}
+
+/// Result of correlating session costs with git history.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitCostReport {
+    pub repo: String,
+    pub since: DateTime<Utc>,
+    /// Commits newest first.
+    pub commits: Vec<CommitCost>,
+    /// PRs newest first.
+    pub pull_requests: Vec<PullRequestCost>,
+    pub session_count: usize,
+    pub attributed_cost_usd: f64,
+    /// Cost of sessions with no commit during or shortly after them.
+    pub unattributed_cost_usd: f64,
+}
+
+/// Resolve the top-level directory of the repository containing `path`.
+///
+/// # Errors
+/// Returns an error if git is not installed or `path` is not inside a
+/// repository.
+pub async fn repo_root(path: &Path) -> Result<PathBuf> {
+    let dir = path.to_string_lossy();
+    let output = run_command(
+        "git",
+        &["-C", &dir, "rev-parse", "--show-toplevel"],
+        CLI_TIMEOUT,
+    )
+    .await?;
+
+    if !output.success() {
+        return Err(CautError::Config(format!(
+            "{} is not inside a git repository",
+            path.display()
+        )));
+    }
+    Ok(PathBuf::from(output.stdout.trim()))
+}
+
+/// Read commits reachable from HEAD made at or after `since`.
+///
+/// # Errors
+/// Returns an error if `git log` cannot be run or fails.
+pub async fn read_commits(repo: &Path, since: DateTime<Utc>) -> Result<Vec<GitCommit>> {
+    let dir = repo.to_string_lossy();
+    let since_arg = format!("--since={}", since.to_rfc3339());
+    let output = run_command(
+        "git",
+        &[
+            "-C",
+            &dir,
+            "log",
+            "--no-color",
+            &since_arg,
+            "--format=%H%x1f%ct%x1f%s",
+        ],
+        CLI_TIMEOUT,
+    )
+    .await?;
+
+    if !output.success() {
+        return Err(CautError::Config(format!(
+            "git log failed in {}: {}",
+            repo.display(),
+            output.stderr.trim()
+        )));
+    }
+    Ok(parse_git_log(&output.stdout))
+}
+
+/// Parse `git log --format=%H%x1f%ct%x1f%s` output, skipping malformed lines.
+#[must_use]
+pub fn parse_git_log(output: &str) -> Vec<GitCommit> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, FIELD_SEP);
+            let sha = fields.next()?.trim();
+            let timestamp: i64 = fields.next()?.trim().parse().ok()?;
+            let subject = fields.next().unwrap_or_default().trim();
+            if sha.is_empty() {
+                return None;
+            }
+            Some(GitCommit {
+                sha: sha.to_string(),
+                committed_at: Utc.timestamp_opt(timestamp, 0).single()?,
+                subject: subject.to_string(),
+                pr_number: pr_number_from_subject(subject),
+            })
+        })
+        .collect()
+}
+
+/// Extract a PR number from `Merge pull request #12 ...` or `Subject (#12)`.
+fn pr_number_from_subject(subject: &str) -> Option<u32> {
+    if let Some(rest) = subject.strip_prefix("Merge pull request #") {
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        return digits.parse().ok();
+    }
+
+    let inner = subject.strip_suffix(')')?;
+    let start = inner.rfind("(#")?;
+    inner[start + 2..].parse().ok()
+}
+
+/// Split session costs across the commits each session plausibly produced.
+///
+/// A session is credited to every commit made between its start and
+/// [`COMMIT_GRACE_MINUTES`] after its end, split evenly.
+#[must_use]
+pub fn attribute_costs(
+    repo: &Path,
+    since: DateTime<Utc>,
+    commits: &[GitCommit],
+    sessions: &[SessionSpan],
+) -> GitCostReport {
+    let grace = Duration::minutes(COMMIT_GRACE_MINUTES);
+    let mut costs: Vec<CommitCost> = commits
+        .iter()
+        .map(|c| CommitCost {
+            sha: c.sha.clone(),
+            committed_at: c.committed_at,
+            subject: c.subject.clone(),
+            pr_number: c.pr_number,
+            cost_usd: 0.0,
+            session_count: 0,
+        })
+        .collect();
+    costs.sort_by_key(|c| std::cmp::Reverse(c.committed_at));
+
+    let mut attributed = 0.0;
+    let mut unattributed = 0.0;
+    for session in sessions {
+        let matches: Vec<usize> = costs
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| {
+                c.committed_at >= session.started_at && c.committed_at <= session.ended_at + grace
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        if matches.is_empty() {
+            unattributed += session.cost_usd;
+            continue;
+        }
+
+        #[allow(clippy::cast_precision_loss)] // commit counts are small
+        let share = session.cost_usd / matches.len() as f64;
+        for i in matches {
+            costs[i].cost_usd += share;
+            costs[i].session_count += 1;
+        }
+        attributed += session.cost_usd;
+    }
+
+    let mut prs: BTreeMap<u32, PullRequestCost> = BTreeMap::new();
+    for commit in &costs {
+        if let Some(number) = commit.pr_number {
+            let pr = prs.entry(number).or_insert(PullRequestCost {
+                number,
+                commit_count: 0,
+                cost_usd: 0.0,
+            });
+            pr.commit_count += 1;
+            pr.cost_usd += commit.cost_usd;
+        }
+    }
+
+    GitCostReport {
+        repo: repo.display().to_string(),
+        since,
+        commits: costs,
+        pull_requests: prs.into_values().rev().collect(),
+        session_count: sessions.len(),
+        attributed_cost_usd: attributed,
+        unattributed_cost_usd: unattributed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 15, hour, 0, 0).unwrap()
+    }
+
+    fn commit(sha: &str, hour: u32, subject: &str) -> GitCommit {
+        GitCommit {
+            sha: sha.to_string(),
+            committed_at: at(hour),
+            subject: subject.to_string(),
+            pr_number: pr_number_from_subject(subject),
+        }
+    }
+
+    fn session(start: u32, end: u32, cost_usd: f64) -> SessionSpan {
+        SessionSpan {
+            started_at: at(start),
+            ended_at: at(end),
+            cost_usd,
+        }
+    }
+
+    #[test]
+    fn parse_git_log_reads_fields() {
+        let output = format!(
+            "abc123{FIELD_SEP}1768478400{FIELD_SEP}Add parser (#42)\nbroken line\n\
+             def456{FIELD_SEP}1768474800{FIELD_SEP}Fix typo\n"
+        );
+        let commits = parse_git_log(&output);
+
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].sha, "abc123");
+        assert_eq!(commits[0].committed_at, at(12));
+        assert_eq!(commits[0].pr_number, Some(42));
+        assert_eq!(commits[1].subject, "Fix typo");
+        assert_eq!(commits[1].pr_number, None);
+    }
+
+    #[test]
+    fn pr_numbers_from_merge_and_squash_subjects() {
+        assert_eq!(
+            pr_number_from_subject("Merge pull request #17 from dev/feature"),
+            Some(17)
+        );
+        assert_eq!(pr_number_from_subject("Add thing (#305)"), Some(305));
+        assert_eq!(pr_number_from_subject("Refer to (#abc)"), None);
+        assert_eq!(pr_number_from_subject("Plain commit"), None);
+    }
+
+    #[test]
+    fn session_cost_splits_across_commits_in_window() {
+        let commits = vec![
+            commit("a", 10, "First (#1)"),
+            commit("b", 11, "Second (#1)"),
+            commit("c", 20, "Later (#2)"),
+        ];
+        let sessions = vec![session(9, 10, 4.0), session(15, 16, 1.0)];
+
+        let report = attribute_costs(Path::new("/repo"), at(0), &commits, &sessions);
+
+        // Newest first.
+        assert_eq!(report.commits[0].sha, "c");
+        assert!((report.commits[0].cost_usd).abs() < f64::EPSILON);
+        assert!((report.commits[1].cost_usd - 2.0).abs() < f64::EPSILON);
+        assert!((report.commits[2].cost_usd - 2.0).abs() < f64::EPSILON);
+        assert!((report.attributed_cost_usd - 4.0).abs() < f64::EPSILON);
+        assert!((report.unattributed_cost_usd - 1.0).abs() < f64::EPSILON);
+
+        assert_eq!(report.pull_requests.len(), 2);
+        assert_eq!(report.pull_requests[0].number, 2);
+        assert_eq!(report.pull_requests[1].commit_count, 2);
+        assert!((report.pull_requests[1].cost_usd - 4.0).abs() < f64::EPSILON);
+    }
+}
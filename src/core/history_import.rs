@@ -0,0 +1,294 @@
+//! Cost history imported from ccusage and `CodexBar`.
+//!
+//! People switching to caut often have months of Claude and Codex cost
+//! history in one of these tools. Both compute it from the same local session
+//! logs and report daily totals with a per-model breakdown:
+//! - `ccusage daily --json` (Claude only)
+//! - `codexbar cost --format json` (Claude and Codex, one payload each)
+//!
+//! Days are stored per provider and model (see
+//! [`crate::storage::HistoryStore::record_imported_costs`]). Importing a day
+//! that is already stored replaces it, whichever tool it came from, so
+//! importing from both tools, or re-running an import, never counts a day
+//! twice.
+
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::core::cli_runner::run_command;
+use crate::error::{CautError, Result};
+
+/// How long the other tool may take; a full log rescan can be slow.
+const IMPORT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// One day of a provider's cost for one model.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedCost {
+    pub provider: String,
+    pub day: NaiveDate,
+    /// Empty when the source had no model breakdown for the day.
+    pub model: String,
+    pub input_tokens: Option<i64>,
+    pub output_tokens: Option<i64>,
+    pub cache_read_tokens: Option<i64>,
+    pub cache_creation_tokens: Option<i64>,
+    pub cost_usd: f64,
+    /// Tool the row came from, `ccusage` or `codexbar`.
+    pub source: String,
+}
+
+/// A daily entry as both tools write it.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DailyEntry {
+    date: String,
+    input_tokens: Option<i64>,
+    output_tokens: Option<i64>,
+    cache_read_tokens: Option<i64>,
+    cache_creation_tokens: Option<i64>,
+    #[serde(alias = "costUSD")]
+    total_cost: Option<f64>,
+    #[serde(default)]
+    model_breakdowns: Vec<ModelBreakdown>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ModelBreakdown {
+    model_name: String,
+    input_tokens: Option<i64>,
+    output_tokens: Option<i64>,
+    cache_read_tokens: Option<i64>,
+    cache_creation_tokens: Option<i64>,
+    #[serde(alias = "costUSD")]
+    cost: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CcusageDaily {
+    daily: Vec<DailyEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CodexbarPayload {
+    provider: String,
+    #[serde(default)]
+    daily: Vec<DailyEntry>,
+}
+
+/// `codexbar cost` prints one payload per provider, or a single object.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CodexbarOutput {
+    Many(Vec<CodexbarPayload>),
+    One(CodexbarPayload),
+}
+
+/// Parse `ccusage daily --json` output, keeping days from `since` onwards.
+///
+/// # Errors
+/// Returns an error if the JSON is not ccusage daily output or a date is
+/// malformed.
+pub fn parse_ccusage(json: &str, since: NaiveDate) -> Result<Vec<ImportedCost>> {
+    let report: CcusageDaily = serde_json::from_str(json)
+        .map_err(|e| CautError::ParseResponse(format!("ccusage daily report: {e}")))?;
+    to_rows("ccusage", "claude", report.daily, since)
+}
+
+/// Parse `codexbar cost --format json` output, keeping days from `since`
+/// onwards.
+///
+/// # Errors
+/// Returns an error if the JSON is not `CodexBar` cost output or a date is
+/// malformed.
+pub fn parse_codexbar(json: &str, since: NaiveDate) -> Result<Vec<ImportedCost>> {
+    let payloads = match serde_json::from_str(json)
+        .map_err(|e| CautError::ParseResponse(format!("CodexBar cost report: {e}")))?
+    {
+        CodexbarOutput::Many(payloads) => payloads,
+        CodexbarOutput::One(payload) => vec![payload],
+    };
+
+    let mut rows = Vec::new();
+    for payload in payloads {
+        let provider = payload.provider.to_lowercase();
+        rows.extend(to_rows("codexbar", &provider, payload.daily, since)?);
+    }
+    Ok(rows)
+}
+
+/// Run `ccusage daily --json` and parse its report.
+///
+/// # Errors
+/// Returns an error if ccusage is not installed, fails, or prints something
+/// other than a daily report.
+pub async fn fetch_ccusage(since: NaiveDate) -> Result<Vec<ImportedCost>> {
+    let since_arg = since.format("%Y%m%d").to_string();
+    let stdout = run_tool(
+        "ccusage",
+        &["daily", "--json", "--breakdown", "--since", &since_arg],
+    )
+    .await?;
+    parse_ccusage(&stdout, since)
+}
+
+/// Run `codexbar cost --format json` and parse its report.
+///
+/// # Errors
+/// Returns an error if `CodexBar` is not installed, fails, or prints
+/// something other than a cost report.
+pub async fn fetch_codexbar(since: NaiveDate) -> Result<Vec<ImportedCost>> {
+    let stdout = run_tool(
+        "codexbar",
+        &["cost", "--format", "json", "--provider", "both"],
+    )
+    .await?;
+    parse_codexbar(&stdout, since)
+}
+
+async fn run_tool(program: &str, args: &[&str]) -> Result<String> {
+    let output = run_command(program, args, IMPORT_TIMEOUT).await?;
+    if !output.success() {
+        return Err(CautError::FetchFailed {
+            provider: program.to_string(),
+            reason: format!(
+                "exited with status {}: {}",
+                output.exit_code,
+                output.stderr.trim()
+            ),
+        });
+    }
+    Ok(output.stdout)
+}
+
+fn to_rows(
+    source: &str,
+    provider: &str,
+    daily: Vec<DailyEntry>,
+    since: NaiveDate,
+) -> Result<Vec<ImportedCost>> {
+    let mut rows = Vec::new();
+    for entry in daily {
+        // Some versions write full timestamps; the calendar day is enough.
+        let date = entry.date.get(..10).unwrap_or(&entry.date);
+        let day = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|e| {
+            CautError::ParseResponse(format!("{source} date {:?}: {e}", entry.date))
+        })?;
+        if day < since {
+            continue;
+        }
+
+        let row = |model: String, tokens: [Option<i64>; 4], cost_usd: f64| ImportedCost {
+            provider: provider.to_string(),
+            day,
+            model,
+            input_tokens: tokens[0],
+            output_tokens: tokens[1],
+            cache_read_tokens: tokens[2],
+            cache_creation_tokens: tokens[3],
+            cost_usd,
+            source: source.to_string(),
+        };
+        if entry.model_breakdowns.is_empty() {
+            rows.push(row(
+                String::new(),
+                [
+                    entry.input_tokens,
+                    entry.output_tokens,
+                    entry.cache_read_tokens,
+                    entry.cache_creation_tokens,
+                ],
+                entry.total_cost.unwrap_or(0.0),
+            ));
+            continue;
+        }
+        for model in entry.model_breakdowns {
+            rows.push(row(
+                model.model_name,
+                [
+                    model.input_tokens,
+                    model.output_tokens,
+                    model.cache_read_tokens,
+                    model.cache_creation_tokens,
+                ],
+                model.cost.unwrap_or(0.0),
+            ));
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, d).unwrap()
+    }
+
+    #[test]
+    fn parses_ccusage_daily_with_model_breakdowns() {
+        let json = r#"{
+            "daily": [
+                {"date": "2026-09-01", "inputTokens": 10, "outputTokens": 5, "totalCost": 9.0,
+                 "modelBreakdowns": []},
+                {"date": "2026-09-02", "inputTokens": 1500, "outputTokens": 300,
+                 "cacheCreationTokens": 20, "cacheReadTokens": 9000, "totalTokens": 10820,
+                 "totalCost": 4.5, "modelsUsed": ["claude-opus-4", "claude-sonnet-4"],
+                 "modelBreakdowns": [
+                    {"modelName": "claude-opus-4", "inputTokens": 1000, "outputTokens": 200,
+                     "cacheCreationTokens": 20, "cacheReadTokens": 8000, "cost": 4.0},
+                    {"modelName": "claude-sonnet-4", "inputTokens": 500, "outputTokens": 100,
+                     "cacheCreationTokens": 0, "cacheReadTokens": 1000, "cost": 0.5}
+                 ]}
+            ],
+            "totals": {"totalCost": 13.5}
+        }"#;
+
+        let rows = parse_ccusage(json, day(2)).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0],
+            ImportedCost {
+                provider: "claude".to_string(),
+                day: day(2),
+                model: "claude-opus-4".to_string(),
+                input_tokens: Some(1000),
+                output_tokens: Some(200),
+                cache_read_tokens: Some(8000),
+                cache_creation_tokens: Some(20),
+                cost_usd: 4.0,
+                source: "ccusage".to_string(),
+            }
+        );
+        assert!(parse_ccusage("[]", day(1)).is_err());
+    }
+
+    #[test]
+    fn parses_codexbar_payloads_per_provider() {
+        let json = r#"[
+            {"provider": "claude", "source": "local", "updatedAt": "2026-09-03T10:00:00Z",
+             "daily": [{"date": "2026-09-03", "inputTokens": 100, "outputTokens": 20,
+                        "costUSD": 1.25, "modelBreakdowns": [
+                            {"modelName": "claude-sonnet-4", "cost": 1.25}]}]},
+            {"provider": "Codex", "source": "local", "updatedAt": "2026-09-03T10:00:00Z",
+             "daily": [{"date": "2026-09-03", "inputTokens": 400, "outputTokens": 80,
+                        "totalCost": 0.75}]}
+        ]"#;
+
+        let rows = parse_codexbar(json, day(1)).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].model, "claude-sonnet-4");
+        assert_eq!(rows[0].input_tokens, None);
+        assert_eq!(rows[1].provider, "codex");
+        assert_eq!(rows[1].model, "");
+        assert_eq!(rows[1].input_tokens, Some(400));
+        assert!((rows[1].cost_usd - 0.75).abs() < f64::EPSILON);
+
+        let single = r#"{"provider": "codex", "daily": []}"#;
+        assert!(parse_codexbar(single, day(1)).unwrap().is_empty());
+    }
+}
@@ -6,6 +6,7 @@ use std::time::Duration;
 
 use reqwest::{Client, ClientBuilder};
 
+use crate::core::clock_skew;
 use crate::error::{CautError, Result};
 
 /// Default timeout for HTTP requests.
@@ -48,6 +49,7 @@ pub async fn fetch_json<T: serde::de::DeserializeOwned>(client: &Client, url: &s
             CautError::Network(e.to_string())
         }
     })?;
+    clock_skew::observe(&response);
 
     if !response.status().is_success() {
         return Err(CautError::Network(format!(
@@ -0,0 +1,195 @@
+//! Estimated cost of self-hosted models.
+//!
+//! A local runtime has no bill, but the machine it runs on costs something:
+//! a rented GPU by the hour, or the power an owned one draws. With a
+//! `[local_cost]` rate configured, the time a model was loaded becomes an
+//! estimated cost that `caut cost` and budgets report next to API spend.
+//!
+//! Load time comes from the throughput samples that Ollama fetches record to
+//! history, one per fetch while a model is loaded. Samples less than
+//! [`SESSION_GAP_MINUTES`] apart belong to one session, which runs from its
+//! first sample to its last plus [`SAMPLE_MINUTES`], the time one sample
+//! stands for.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Duration, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::models::CostDailyEntry;
+use crate::error::{CautError, Result};
+
+/// Longest gap between samples of one session.
+pub const SESSION_GAP_MINUTES: i64 = 15;
+
+/// Time each session is credited beyond its last sample: the `caut daemon`
+/// polling interval.
+pub const SAMPLE_MINUTES: i64 = 5;
+
+/// `[local_cost]` config section. Unset, local usage has no cost.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LocalCostConfig {
+    /// Hourly rate of the machine, e.g. a rented GPU, in USD.
+    pub hourly_usd: Option<f64>,
+    /// Power the machine draws while a model is loaded, in watts.
+    pub power_watts: Option<f64>,
+    /// Electricity price, in USD per kWh.
+    pub kwh_usd: Option<f64>,
+}
+
+impl LocalCostConfig {
+    /// Cost of one hour with a model loaded: `hourly_usd` when set (a rental
+    /// rate includes power), otherwise the power draw at the electricity
+    /// price.
+    #[must_use]
+    pub fn hourly_rate(&self) -> Option<f64> {
+        self.hourly_usd.or_else(|| {
+            self.power_watts
+                .zip(self.kwh_usd)
+                .map(|(watts, price)| watts / 1000.0 * price)
+        })
+    }
+
+    /// Check that rates are not negative and power comes with a price.
+    ///
+    /// # Errors
+    /// Returns a config error naming the first invalid setting.
+    pub fn validate(&self) -> Result<()> {
+        for (name, value) in [
+            ("hourly_usd", self.hourly_usd),
+            ("power_watts", self.power_watts),
+            ("kwh_usd", self.kwh_usd),
+        ] {
+            if value.is_some_and(|v| !v.is_finite() || v < 0.0) {
+                return Err(CautError::Config(format!(
+                    "local_cost.{name} must be zero or more"
+                )));
+            }
+        }
+        if self.power_watts.is_some() != self.kwh_usd.is_some() {
+            return Err(CautError::Config(
+                "local_cost.power_watts and local_cost.kwh_usd must be set together".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A stretch of time with a model loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalSession {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub models: BTreeSet<String>,
+}
+
+impl LocalSession {
+    /// Length of the session, in hours.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // session lengths are far below 2^52 seconds
+    pub fn hours(&self) -> f64 {
+        (self.end - self.start).num_seconds() as f64 / 3600.0
+    }
+}
+
+/// Group `(time, model)` samples, oldest first, into sessions.
+pub fn sessions<'a>(
+    samples: impl IntoIterator<Item = (DateTime<Utc>, &'a str)>,
+) -> Vec<LocalSession> {
+    let gap = Duration::minutes(SESSION_GAP_MINUTES);
+    let credit = Duration::minutes(SAMPLE_MINUTES);
+    let mut sessions: Vec<LocalSession> = Vec::new();
+    for (at, model) in samples {
+        match sessions.last_mut() {
+            Some(session) if at - (session.end - credit) <= gap => {
+                session.end = session.end.max(at + credit);
+                session.models.insert(model.to_string());
+            }
+            _ => sessions.push(LocalSession {
+                start: at,
+                end: at + credit,
+                models: BTreeSet::from([model.to_string()]),
+            }),
+        }
+    }
+    sessions
+}
+
+/// Daily cost entries for `sessions` at `hourly_rate`, by local start date.
+#[must_use]
+pub fn daily_costs(sessions: &[LocalSession], hourly_rate: f64) -> Vec<CostDailyEntry> {
+    let mut days: BTreeMap<String, (f64, BTreeSet<String>)> = BTreeMap::new();
+    for session in sessions {
+        let date = session
+            .start
+            .with_timezone(&Local)
+            .format("%Y-%m-%d")
+            .to_string();
+        let day = days.entry(date).or_default();
+        day.0 += session.hours();
+        day.1.extend(session.models.iter().cloned());
+    }
+    days.into_iter()
+        .map(|(date, (hours, models))| CostDailyEntry {
+            date,
+            input_tokens: None,
+            output_tokens: None,
+            cache_read_tokens: None,
+            cache_creation_tokens: None,
+            total_tokens: None,
+            total_cost: Some(hours * hourly_rate),
+            models_used: Some(models.into_iter().collect()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_prefers_hourly_over_power() {
+        let power = LocalCostConfig {
+            hourly_usd: None,
+            power_watts: Some(450.0),
+            kwh_usd: Some(0.20),
+        };
+        assert!((power.hourly_rate().unwrap() - 0.09).abs() < 1e-9);
+        let rented = LocalCostConfig {
+            hourly_usd: Some(1.5),
+            ..power.clone()
+        };
+        assert_eq!(rented.hourly_rate(), Some(1.5));
+        assert_eq!(LocalCostConfig::default().hourly_rate(), None);
+
+        let half = LocalCostConfig {
+            kwh_usd: None,
+            ..power
+        };
+        assert!(half.validate().is_err());
+    }
+
+    #[test]
+    fn samples_group_into_sessions() {
+        let t0 = Utc::now() - Duration::days(1);
+        let at = |minutes| t0 + Duration::minutes(minutes);
+        let found = sessions([
+            (at(0), "llama3.2"),
+            (at(5), "llama3.2"),
+            (at(10), "qwen2.5-coder"),
+            // More than the gap after the last sample: a new session.
+            (at(120), "llama3.2"),
+        ]);
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].start, at(0));
+        assert_eq!(found[0].end, at(15));
+        assert_eq!(found[0].models.len(), 2);
+        assert!((found[1].hours() - 5.0 / 60.0).abs() < 1e-9);
+
+        let daily = daily_costs(&found, 2.0);
+        let total: f64 = daily.iter().filter_map(|d| d.total_cost).sum();
+        assert!((total - 20.0 / 60.0 * 2.0).abs() < 1e-9);
+    }
+}
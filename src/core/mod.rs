@@ -1,50 +1,128 @@
 //! Core data models and provider infrastructure.
 
+#[cfg(feature = "native")]
+pub mod account_switch;
+#[cfg(feature = "native")]
+pub mod alerts;
 pub mod budgets;
+#[cfg(feature = "native")]
+pub mod bug_report;
+#[cfg(feature = "native")]
+pub mod canary;
+#[cfg(feature = "native")]
 pub mod cli_runner;
+pub mod clock_skew;
+#[cfg(feature = "native")]
+pub mod compare;
+pub mod cost_projection;
+#[cfg(feature = "native")]
 pub mod cost_scanner;
+#[cfg(feature = "native")]
 pub mod credential_hash;
+#[cfg(feature = "native")]
 pub mod credential_health;
+#[cfg(feature = "native")]
 pub mod credential_watcher;
+#[cfg(feature = "native")]
+pub mod divergence;
+#[cfg(feature = "native")]
 pub mod doctor;
+#[cfg(feature = "native")]
+pub mod explain;
+#[cfg(feature = "native")]
+pub mod fake_providers;
+#[cfg(feature = "native")]
 pub mod fetch_plan;
+#[cfg(feature = "native")]
+pub mod fetcher;
+#[cfg(feature = "native")]
+pub mod gateway_import;
+#[cfg(feature = "native")]
+pub mod git_cost;
+#[cfg(feature = "native")]
+pub mod history_import;
+#[cfg(feature = "native")]
 pub mod http;
+pub mod local_cost;
+#[cfg(feature = "native")]
 pub mod logging;
 pub mod models;
+#[cfg(feature = "native")]
+pub mod notify;
+#[cfg(feature = "native")]
+pub mod org_usage;
+#[cfg(feature = "native")]
 pub mod pipeline;
+#[cfg(feature = "native")]
+pub mod plan_limits;
+pub mod plan_value;
+pub mod policy;
+#[cfg(feature = "native")]
+pub mod power;
+#[cfg(feature = "native")]
 pub mod prediction;
 pub mod pricing;
 pub mod provider;
+pub mod rate_window;
+#[cfg(feature = "native")]
+pub mod report;
 pub mod session_logs;
+pub mod severity;
+#[cfg(feature = "native")]
 pub mod status;
+pub mod summary;
+pub mod token_estimate;
+#[cfg(feature = "native")]
+pub mod usage_filter;
+#[cfg(feature = "native")]
+pub mod usage_profile;
+#[cfg(feature = "native")]
+pub mod watchdog;
+#[cfg(feature = "native")]
+pub mod web_session;
 
 pub use budgets::{
-    BudgetConfig, BudgetFileConfig, BudgetLimits, BudgetPriority, BudgetSources, BudgetViolation,
-    CurrentUsage, ProviderBudgetConfig, ResolvedBudget, ViolationType, check_budget_violations,
-    resolve_budget,
+    BudgetConfig, BudgetFileConfig, BudgetLimits, BudgetPeriod, BudgetPriority, BudgetSources,
+    BudgetStatus, BudgetViolation, CurrentUsage, ProviderBudgetConfig, ResolvedBudget,
+    ViolationType, check_budget_violations, resolve_budget,
 };
+#[cfg(feature = "native")]
 pub use cost_scanner::CostScanner;
+#[cfg(feature = "native")]
 pub use credential_hash::{ChangeType, CredentialHasher, CredentialHashes, IdentityFields};
+#[cfg(feature = "native")]
 pub use credential_health::{
     AuthHealthAggregator, CredentialHealth, CredentialHealthReport, CredentialType, HealthSeverity,
     JwtHealth, JwtHealthChecker, OAuthHealth, OverallHealth, ProviderAuthHealth, SourceHealth,
     check_oauth_file, check_oauth_json, get_reauth_instructions,
 };
+#[cfg(feature = "native")]
 pub use credential_watcher::{CredentialWatcher, WatchEvent};
+#[cfg(feature = "native")]
 pub use doctor::{CheckStatus, DiagnosticCheck, DoctorReport, ProviderHealth};
+#[cfg(feature = "native")]
 pub use fetch_plan::{FetchAttempt, FetchOutcome, FetchStrategy};
+#[cfg(feature = "native")]
+pub use fetcher::ProviderFetcher;
 pub use models::{
     CostDailyEntry, CostPayload, CostTotals, CreditEvent, CreditsSnapshot, OpenAIDashboardSnapshot,
     ProviderIdentity, ProviderPayload, RateWindow, RobotOutput, StatusIndicator, StatusPayload,
     UsageSnapshot,
 };
-pub use prediction::{calculate_velocity, detect_reset, smoothed_velocity};
+pub use policy::{PolicyAction, PolicyConfig, PolicyRule, PolicyViolation};
+#[cfg(feature = "native")]
+pub use prediction::{
+    UsageWindow, calculate_velocity, detect_reset, smoothed_velocity, time_to_exhaustion,
+    window_velocity,
+};
 pub use pricing::{
     CostConfidence, ModelPricing, PricingTable, SessionCost, SessionCostCalculator,
     TokenCostBreakdown,
 };
 pub use provider::{Provider, ProviderDescriptor, ProviderRegistry, ProviderSelection};
 pub use session_logs::{
-    ClaudeSessionParser, CodexSessionParser, SessionLogFinder, SessionLogPath, SessionUsage,
+    ClaudeSessionParser, CodexSessionParser, SessionLogFinder, SessionLogPath, SessionTurn,
+    SessionUsage,
 };
+#[cfg(feature = "native")]
 pub use status::StatusFetcher;
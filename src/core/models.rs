@@ -3,9 +3,15 @@
 //! These types represent the canonical usage data structures.
 //! See `EXISTING_CODEXBAR_STRUCTURE.md` section 5 for field semantics.
 
+use std::sync::RwLock;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::core::budgets::BudgetStatus;
+use crate::core::policy::PolicyViolation;
+use crate::core::summary::UsageSummary;
+
 // =============================================================================
 // Rate Window
 // =============================================================================
@@ -39,6 +45,24 @@ impl RateWindow {
         (100.0 - self.used_percent).max(0.0)
     }
 
+    /// Reset wording for display.
+    ///
+    /// When the reset time is known it is humanized (`resets tonight at
+    /// 11pm`) and follows the description, unless the description is itself
+    /// a countdown the humanized phrase supersedes. Otherwise the description
+    /// is shown as-is.
+    #[must_use]
+    pub fn reset_text(&self) -> Option<String> {
+        let Some(resets_at) = self.resets_at else {
+            return self.reset_description.clone();
+        };
+        let phrase = crate::util::time::humanize_reset(resets_at);
+        match self.reset_description.as_deref() {
+            Some(desc) if !is_countdown(desc) => Some(format!("{desc} · {phrase}")),
+            _ => Some(phrase),
+        }
+    }
+
     /// Create a new rate window with the given usage percentage.
     #[must_use]
     pub const fn new(used_percent: f64) -> Self {
@@ -51,6 +75,13 @@ impl RateWindow {
     }
 }
 
+/// Whether a reset description only says when the window resets
+/// (`resets in 2h`, `in 45m`, `resets Fri 9am`).
+fn is_countdown(description: &str) -> bool {
+    let lower = description.trim().to_lowercase();
+    lower.is_empty() || lower == "now" || lower.starts_with("resets") || lower.starts_with("in ")
+}
+
 // =============================================================================
 // Provider Identity
 // =============================================================================
@@ -194,6 +225,162 @@ pub struct OpenAIDashboardSnapshot {
     pub updated_at: DateTime<Utc>,
 }
 
+// =============================================================================
+// Copilot Premium Requests
+// =============================================================================
+
+/// Where a Copilot seat's premium-request consumption is heading this month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PremiumRequestStatus {
+    /// Projected to stay within the monthly allowance.
+    OnTrack,
+    /// Within the allowance now, but projected to exceed it before reset.
+    AtRisk,
+    /// Allowance used up; further requests are billed as overage.
+    Overage,
+    /// Allowance used up and policy blocks overage billing.
+    Blocked,
+}
+
+impl PremiumRequestStatus {
+    /// Stable label, the same one JSON output uses.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::OnTrack => "on_track",
+            Self::AtRisk => "at_risk",
+            Self::Overage => "overage",
+            Self::Blocked => "blocked",
+        }
+    }
+}
+
+/// Premium-request projection combining seat policy with consumption.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopilotPremiumProjection {
+    /// Copilot plan (e.g. `business`, `enterprise`, `pro`).
+    pub plan: String,
+
+    /// Organization that assigns the seat, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub org: Option<String>,
+
+    /// Premium requests used this month.
+    pub used_requests: f64,
+
+    /// Included premium requests per month for the plan.
+    pub allowance: f64,
+
+    /// Requests expected by month end at the current rate.
+    pub projected_requests: f64,
+
+    /// Whether policy lets usage continue past the allowance as paid overage.
+    pub overage_allowed: bool,
+
+    /// Premium-request budget set by the org, in USD.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_usd: Option<f64>,
+
+    /// Projected overage cost by month end (capped by the budget).
+    pub projected_overage_usd: f64,
+
+    /// Day the allowance runs out at the current rate, if before reset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exhausted_on: Option<chrono::NaiveDate>,
+
+    /// When the allowance resets (start of next month, UTC).
+    pub resets_at: DateTime<Utc>,
+
+    pub status: PremiumRequestStatus,
+}
+
+// =============================================================================
+// Task Spend
+// =============================================================================
+
+/// API spend an agent records locally per task, for agents billed through
+/// the user's own API keys (Cline, Roo Code).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskSpend {
+    /// Spend on tasks started today (local time), in USD.
+    pub today_usd: f64,
+    pub today_tasks: i64,
+    /// Spend on tasks started in the last 30 days, in USD.
+    pub last_30_days_usd: f64,
+    pub last_30_days_tasks: i64,
+}
+
+/// Generation throughput measured on a local model server (Ollama).
+///
+/// Local models cost nothing per token, so their usage is tracked as
+/// throughput on the loaded model rather than as spend.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalModelStats {
+    /// Model the measurement ran on.
+    pub model: String,
+    /// Models loaded in memory.
+    pub loaded_models: Vec<String>,
+    pub prompt_tokens: i64,
+    pub eval_tokens: i64,
+    /// Prompt processing speed, in tokens per second.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_tokens_per_sec: Option<f64>,
+    /// Generation speed, in tokens per second.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eval_tokens_per_sec: Option<f64>,
+}
+
+// =============================================================================
+// Source Divergence
+// =============================================================================
+
+/// A window that two sources reported materially differently
+/// (`caut usage --verify`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceDivergence {
+    /// `primary`, `secondary`, or `tertiary`.
+    pub window: String,
+    /// Source the payload was built from.
+    pub preferred_source: String,
+    pub preferred_percent: f64,
+    /// Source it was checked against.
+    pub other_source: String,
+    pub other_percent: f64,
+}
+
+impl SourceDivergence {
+    /// Absolute difference in used percentage points.
+    #[must_use]
+    pub fn delta(&self) -> f64 {
+        (self.preferred_percent - self.other_percent).abs()
+    }
+
+    /// One-line description, e.g.
+    /// `Session: oauth reports 38% used, web reports 52% (14 pts apart)`.
+    #[must_use]
+    pub fn message(&self) -> String {
+        let label = match self.window.as_str() {
+            "primary" => "Session",
+            "secondary" => "Weekly",
+            "tertiary" => "Opus/Sonnet",
+            other => other,
+        };
+        format!(
+            "{label}: {} reports {:.0}% used, {} reports {:.0}% ({:.0} pts apart)",
+            self.preferred_source,
+            self.preferred_percent,
+            self.other_source,
+            self.other_percent,
+            self.delta()
+        )
+    }
+}
+
 // =============================================================================
 // Status
 // =============================================================================
@@ -288,6 +475,22 @@ pub struct ProviderPayload {
     #[serde(rename = "openaiDashboard")]
     pub openai_dashboard: Option<OpenAIDashboardSnapshot>,
 
+    /// Copilot premium-request projection (Copilot only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copilot_premium: Option<CopilotPremiumProjection>,
+
+    /// Locally recorded task spend (Cline, and DeepSeek via Aider logs).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_spend: Option<TaskSpend>,
+
+    /// Generation throughput on a local model server (Ollama only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_model: Option<LocalModelStats>,
+
+    /// Windows another source disagreed on (`--verify` only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub divergences: Vec<SourceDivergence>,
+
     /// Authentication health warning message (if credentials need attention).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auth_warning: Option<String>,
@@ -422,6 +625,27 @@ pub struct ProviderErrorReport {
     pub suggestions: Vec<FixSuggestionReport>,
 }
 
+/// Warnings raised before the command ran, such as unknown config keys,
+/// which every robot envelope printed afterwards carries.
+static RUN_WARNINGS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Record a warning for the `warnings` array of this run's robot output,
+/// where it can't corrupt the JSON the way a line on stderr mixed into the
+/// stream could.
+///
+/// # Panics
+/// Panics if the warnings lock is poisoned.
+pub fn add_run_warning(warning: impl Into<String>) {
+    RUN_WARNINGS.write().unwrap().push(warning.into());
+}
+
+fn run_warnings() -> Vec<String> {
+    RUN_WARNINGS
+        .read()
+        .map(|warnings| warnings.to_vec())
+        .unwrap_or_default()
+}
+
 /// Top-level JSON envelope for robot mode output.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -437,6 +661,26 @@ pub struct RobotOutput<T> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_details: Option<Vec<ProviderErrorReport>>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_violations: Option<Vec<PolicyViolation>>,
+
+    /// Spend against configured budgets (see `caut budget`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budgets: Option<Vec<BudgetStatus>>,
+
+    /// Run-level warnings, such as a skewed system clock.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+
+    /// Totals across all providers, in multi-provider usage output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<UsageSummary>,
+
+    /// Why `data` is empty without anything having failed, e.g.
+    /// `no_providers_configured` on a machine with no provider set up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+
     pub meta: RobotMeta,
 }
 
@@ -459,6 +703,11 @@ impl<T> RobotOutput<T> {
             data,
             errors: Vec::new(),
             error_details: None,
+            policy_violations: None,
+            budgets: None,
+            warnings: run_warnings(),
+            summary: None,
+            notice: None,
             meta: RobotMeta {
                 format: "json".to_string(),
                 flags: Vec::new(),
@@ -476,6 +725,11 @@ impl<T> RobotOutput<T> {
             data,
             errors,
             error_details: None,
+            policy_violations: None,
+            budgets: None,
+            warnings: run_warnings(),
+            summary: None,
+            notice: None,
             meta: RobotMeta {
                 format: "json".to_string(),
                 flags: Vec::new(),
@@ -484,6 +738,42 @@ impl<T> RobotOutput<T> {
         }
     }
 
+    /// Attach policy violations (omitted from JSON when empty).
+    #[must_use]
+    pub fn with_policy_violations(mut self, violations: Vec<PolicyViolation>) -> Self {
+        self.policy_violations = (!violations.is_empty()).then_some(violations);
+        self
+    }
+
+    /// Attach budget status (omitted from JSON when empty).
+    #[must_use]
+    pub fn with_budgets(mut self, budgets: Vec<BudgetStatus>) -> Self {
+        self.budgets = (!budgets.is_empty()).then_some(budgets);
+        self
+    }
+
+    /// Attach run-level warnings after any recorded with
+    /// [`add_run_warning`] (omitted from JSON when empty).
+    #[must_use]
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings.extend(warnings);
+        self
+    }
+
+    /// Attach the all-providers summary.
+    #[must_use]
+    pub fn with_summary(mut self, summary: Option<UsageSummary>) -> Self {
+        self.summary = summary;
+        self
+    }
+
+    /// Attach a notice explaining empty output.
+    #[must_use]
+    pub fn with_notice(mut self, notice: impl Into<String>) -> Self {
+        self.notice = Some(notice.into());
+        self
+    }
+
     /// Create with errors and structured error details.
     pub fn with_errors_and_details(
         command: impl Into<String>,
@@ -498,6 +788,11 @@ impl<T> RobotOutput<T> {
             data,
             errors,
             error_details,
+            policy_violations: None,
+            budgets: None,
+            warnings: run_warnings(),
+            summary: None,
+            notice: None,
             meta: RobotMeta {
                 format: "json".to_string(),
                 flags: Vec::new(),
@@ -564,6 +859,26 @@ mod tests {
         assert!(window.reset_description.is_some());
     }
 
+    #[test]
+    fn reset_text_replaces_countdowns_and_keeps_allowances() {
+        let mut window = make_test_rate_window(28.0);
+        let phrase = crate::util::time::humanize_reset(window.resets_at.unwrap());
+        assert_eq!(window.reset_text(), Some(phrase.clone()));
+
+        window.reset_description = Some("12/50 premium requests".to_string());
+        assert_eq!(
+            window.reset_text(),
+            Some(format!("12/50 premium requests · {phrase}"))
+        );
+
+        window.resets_at = None;
+        assert_eq!(
+            window.reset_text().as_deref(),
+            Some("12/50 premium requests")
+        );
+        assert_eq!(RateWindow::new(10.0).reset_text(), None);
+    }
+
     #[test]
     fn usage_snapshot_from_test_utils() {
         let snapshot = make_test_usage_snapshot();
@@ -0,0 +1,146 @@
+//! One-shot status notifications.
+//!
+//! `caut notify`, usually run from cron, renders the current usage as a
+//! short plain-text status and sends it to every channel in the `[notify]`
+//! config section. Channels take the same forms as alert channels (see
+//! [`AlertChannel`]):
+//!
+//! ```toml
+//! [notify]
+//! channels = [
+//!     "webhook:https://hooks.slack.com/services/...",
+//!     "email:me@example.com",
+//! ]
+//! ```
+
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::alerts::AlertChannel;
+use crate::core::models::ProviderPayload;
+use crate::core::summary::UsageSummary;
+use crate::util::format::format_cost;
+use crate::util::time::format_countdown;
+
+/// `[notify]` config section.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NotifyConfig {
+    /// Where `caut notify` sends the status.
+    pub channels: Vec<AlertChannel>,
+}
+
+/// The status sent by `caut notify`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusMessage {
+    /// One line: overall status and the tightest window.
+    pub subject: String,
+    /// The subject, then one line per provider, spend, and failures.
+    pub text: String,
+}
+
+impl StatusMessage {
+    /// Build the status for a usage run. `errors` are the providers that
+    /// failed, as reported by the fetch.
+    #[must_use]
+    pub fn new(payloads: &[ProviderPayload], summary: &UsageSummary, errors: &[String]) -> Self {
+        let mut subject = format!("caut usage: {}", summary.status.label());
+        if let Some(window) = &summary.most_constrained {
+            let left = (100.0 - window.used_percent).max(0.0);
+            write!(
+                subject,
+                " ({} {} {left:.0}% left)",
+                window.provider, window.window
+            )
+            .ok();
+        }
+
+        let mut text = format!("{subject}\n\n");
+        let width = payloads.iter().map(|p| p.provider.len()).max().unwrap_or(0);
+        for payload in payloads {
+            let usage = &payload.usage;
+            let mut parts: Vec<String> = [
+                ("session", usage.primary.as_ref()),
+                ("weekly", usage.secondary.as_ref()),
+            ]
+            .into_iter()
+            .filter_map(|(label, window)| window.map(|w| format!("{label} {:.0}%", w.used_percent)))
+            .collect();
+            if let Some(resets_at) = usage.primary.as_ref().and_then(|w| w.resets_at) {
+                parts.push(format!("resets {}", format_countdown(resets_at)));
+            }
+            if parts.is_empty() {
+                parts.push("no rate windows".to_string());
+            }
+            writeln!(text, "{:<width$}  {}", payload.provider, parts.join(" · ")).ok();
+        }
+
+        let spend = match (summary.cost_today_usd, summary.cost_month_usd) {
+            (Some(today), Some(month)) => Some(format!(
+                "{} today, {} this month",
+                format_cost(today),
+                format_cost(month)
+            )),
+            (Some(today), None) => Some(format!("{} today", format_cost(today))),
+            (None, Some(month)) => Some(format!("{} this month", format_cost(month))),
+            (None, None) => None,
+        };
+        if let Some(spend) = spend {
+            writeln!(text, "\nSpend: {spend}").ok();
+        }
+        for error in errors {
+            writeln!(text, "Failed: {error}").ok();
+        }
+
+        Self { subject, text }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::RateWindow;
+    use crate::test_utils::make_test_provider_payload;
+    use chrono::Utc;
+
+    #[test]
+    fn status_lists_providers_spend_and_failures() {
+        let mut claude = make_test_provider_payload("claude", "oauth");
+        claude.usage.primary = Some(RateWindow::new(88.0));
+        claude.usage.secondary = Some(RateWindow::new(40.0));
+        let mut codex = make_test_provider_payload("codex", "cli");
+        codex.usage.primary = Some(RateWindow::new(10.0));
+        codex.usage.secondary = None;
+        let payloads = [claude, codex];
+        let mut summary = UsageSummary::build(&payloads, &[], 1, Utc::now());
+        summary.cost_today_usd = Some(4.2);
+        summary.cost_month_usd = None;
+
+        let message = StatusMessage::new(&payloads, &summary, &["gemini: timed out".to_string()]);
+
+        assert_eq!(
+            message.subject,
+            "caut usage: warning (claude session 12% left)"
+        );
+        assert_eq!(
+            message.text,
+            "caut usage: warning (claude session 12% left)\n\n\
+             claude  session 88% · weekly 40%\n\
+             codex   session 10%\n\
+             \nSpend: $4.20 today\n\
+             Failed: gemini: timed out\n"
+        );
+
+        let config: NotifyConfig =
+            toml::from_str(r#"channels = ["desktop", "email:me@example.com"]"#).unwrap();
+        assert_eq!(
+            config.channels,
+            [
+                AlertChannel::Desktop,
+                AlertChannel::Email("me@example.com".to_string())
+            ]
+        );
+    }
+}
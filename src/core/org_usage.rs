@@ -0,0 +1,193 @@
+//! Organization usage reported by provider admin APIs.
+//!
+//! Team and Enterprise plans expose per-member consumption to admins through
+//! a separate admin key. Each provider's admin client turns its report into an
+//! [`OrgUsageReport`] with one [`SeatUsage`] per member, which `caut org usage`
+//! sorts, renders, and exports. This is independent of the personal usage
+//! windows the rest of caut tracks.
+
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::util::format::csv_escape;
+
+/// Consumption of one seat (member or API key) over the report period.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeatUsage {
+    /// Member email, or API key name for programmatic use.
+    pub member: String,
+    /// Sessions (Claude) or model requests (OpenAI).
+    pub sessions: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cache_read_tokens: i64,
+    pub cache_creation_tokens: i64,
+    /// Estimated cost in USD, as reported by the provider or priced from
+    /// tokens when it has no per-member cost.
+    pub cost_usd: f64,
+    pub models: BTreeSet<String>,
+}
+
+impl SeatUsage {
+    /// All tokens, including cache reads and writes.
+    #[must_use]
+    pub const fn total_tokens(&self) -> i64 {
+        self.input_tokens + self.output_tokens + self.cache_read_tokens + self.cache_creation_tokens
+    }
+
+    /// Fold another record for the same member into this one.
+    pub fn absorb(&mut self, other: Self) {
+        self.sessions += other.sessions;
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_read_tokens += other.cache_read_tokens;
+        self.cache_creation_tokens += other.cache_creation_tokens;
+        self.cost_usd += other.cost_usd;
+        self.models.extend(other.models);
+    }
+}
+
+/// Per-seat usage for an organization over a date range.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrgUsageReport {
+    /// Admin API the report came from (`claude` or `openai`).
+    pub provider: String,
+    /// First day covered (UTC).
+    pub start: NaiveDate,
+    /// Last day covered (UTC).
+    pub end: NaiveDate,
+    pub seats: Vec<SeatUsage>,
+}
+
+impl OrgUsageReport {
+    /// Build a report, merging records that belong to the same member.
+    #[must_use]
+    pub fn new(
+        provider: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+        records: impl IntoIterator<Item = SeatUsage>,
+    ) -> Self {
+        let mut seats: Vec<SeatUsage> = Vec::new();
+        for record in records {
+            match seats.iter_mut().find(|s| s.member == record.member) {
+                Some(seat) => seat.absorb(record),
+                None => seats.push(record),
+            }
+        }
+        Self {
+            provider: provider.to_string(),
+            start,
+            end,
+            seats,
+        }
+    }
+
+    /// Display name of the report's provider.
+    #[must_use]
+    pub fn provider_name(&self) -> &str {
+        match self.provider.as_str() {
+            "claude" => "Claude",
+            "openai" => "OpenAI",
+            other => other,
+        }
+    }
+
+    /// Total estimated cost across seats.
+    #[must_use]
+    pub fn total_cost(&self) -> f64 {
+        self.seats.iter().map(|s| s.cost_usd).sum()
+    }
+
+    /// Total tokens across seats.
+    #[must_use]
+    pub fn total_tokens(&self) -> i64 {
+        self.seats.iter().map(SeatUsage::total_tokens).sum()
+    }
+
+    /// Seats as CSV, one row per seat in the current order.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(
+            "member,sessions,input_tokens,output_tokens,cache_read_tokens,cache_creation_tokens,total_tokens,cost_usd,models\n",
+        );
+        for seat in &self.seats {
+            let models = seat.models.iter().cloned().collect::<Vec<_>>().join(";");
+            out.push_str(&format!(
+                "{},{},{},{},{},{},{},{:.4},{}\n",
+                csv_escape(&seat.member),
+                seat.sessions,
+                seat.input_tokens,
+                seat.output_tokens,
+                seat.cache_read_tokens,
+                seat.cache_creation_tokens,
+                seat.total_tokens(),
+                seat.cost_usd,
+                csv_escape(&models)
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seat(member: &str, cost: f64, model: &str) -> SeatUsage {
+        SeatUsage {
+            member: member.to_string(),
+            sessions: 1,
+            input_tokens: 100,
+            output_tokens: 50,
+            cost_usd: cost,
+            models: BTreeSet::from([model.to_string()]),
+            ..SeatUsage::default()
+        }
+    }
+
+    #[test]
+    fn merges_records_for_the_same_member() {
+        let day = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let report = OrgUsageReport::new(
+            "claude",
+            day,
+            day,
+            [
+                seat("ana@example.com", 1.5, "claude-sonnet-4"),
+                seat("bo@example.com", 0.5, "claude-sonnet-4"),
+                seat("ana@example.com", 2.0, "claude-opus-4"),
+            ],
+        );
+
+        assert_eq!(report.seats.len(), 2);
+        assert_eq!(report.seats[0].sessions, 2);
+        assert_eq!(report.seats[0].models.len(), 2);
+        assert!((report.total_cost() - 4.0).abs() < 1e-9);
+        assert_eq!(report.total_tokens(), 450);
+    }
+
+    #[test]
+    fn csv_has_a_row_per_seat() {
+        let day = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let report = OrgUsageReport::new(
+            "claude",
+            day,
+            day,
+            [seat("Ops, shared key", 0.25, "claude-haiku-4")],
+        );
+        let csv = report.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("member,sessions,"));
+        assert_eq!(
+            lines[1],
+            "\"Ops, shared key\",1,100,50,0,0,150,0.2500,claude-haiku-4"
+        );
+    }
+}
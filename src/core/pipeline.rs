@@ -1,22 +1,36 @@
 //! Provider fetch pipeline executor.
 //!
-//! Orchestrates the execution of fetch strategies for providers.
+//! Orchestrates the execution of fetch strategies for providers, and turns a
+//! whole usage run into one [`ProviderResult`] per provider so every consumer
+//! (renderers, history, notifications, the TUI) shares the same fetch logic.
 
 use std::time::Instant;
 
 use chrono::Utc;
 use tokio::time::{Duration, timeout};
 
-use super::fetch_plan::{FetchAttempt, FetchKind, FetchOutcome, SourceMode};
-use super::models::UsageSnapshot;
-use super::provider::Provider;
+use super::credential_health::AuthHealthAggregator;
+use super::divergence;
+use super::fake_providers;
+use super::fetch_plan::{FetchAttempt, FetchKind, FetchOutcome, FetchPlan, SourceMode};
+use super::fetcher::ProviderFetcher;
+use super::models::{ProviderPayload, SourceDivergence, UsageSnapshot};
+use super::provider::{Provider, ProviderRegistry};
+use super::rate_window;
+use super::status::StatusFetcher;
 use crate::error::{CautError, Result};
-use crate::providers::{claude, codex};
+use crate::providers::claude;
+use crate::storage::{AppPaths, HistoryStore, SnapshotTrigger};
 
 /// Execute the fetch pipeline for a provider.
 ///
 /// Tries strategies in order until one succeeds or all fail.
 pub async fn fetch_provider(provider: Provider, mode: SourceMode) -> FetchOutcome {
+    if fake_providers::is_enabled() {
+        return fake_providers::fetch_outcome(provider);
+    }
+
+    let fetcher = ProviderRegistry::new().fetcher(provider);
     let plan = get_fetch_plan(provider);
     let strategies = plan.for_mode(mode);
 
@@ -54,7 +68,10 @@ pub async fn fetch_provider(provider: Provider, mode: SourceMode) -> FetchOutcom
         let start = Instant::now();
 
         // Execute the fetch
-        let result = execute_strategy(provider, strategy.id, strategy.kind).await;
+        let result = match &fetcher {
+            Some(fetcher) => execute_strategy(fetcher.as_ref(), strategy.id).await,
+            None => Err(super::fetcher::unknown_strategy(provider, strategy.id)),
+        };
         #[allow(clippy::cast_possible_truncation)] // fetch durations won't exceed u64::MAX ms
         let duration_ms = start.elapsed().as_millis() as u64;
 
@@ -70,6 +87,7 @@ pub async fn fetch_provider(provider: Provider, mode: SourceMode) -> FetchOutcom
 
         match result {
             Ok(snapshot) => {
+                let snapshot = rate_window::normalize_snapshot(provider, snapshot);
                 // Differentiate "fetched rate-limit data" from "fetched identity
                 // only" so users understand why `primary`/`secondary` may be null
                 // on platforms where the CLI doesn't expose quota (see #7).
@@ -128,48 +146,63 @@ pub async fn fetch_provider(provider: Provider, mode: SourceMode) -> FetchOutcom
 }
 
 /// Get the fetch plan for a provider.
-fn get_fetch_plan(provider: Provider) -> super::fetch_plan::FetchPlan {
-    match provider {
-        Provider::Codex => codex::fetch_plan(),
-        Provider::Claude => claude::fetch_plan(),
-        // Add other providers as they're implemented
-        _ => super::fetch_plan::FetchPlan::new(provider, vec![]),
-    }
+///
+/// Providers without a registered fetcher get an empty plan.
+fn get_fetch_plan(provider: Provider) -> FetchPlan {
+    ProviderRegistry::new()
+        .fetcher(provider)
+        .map_or_else(|| FetchPlan::new(provider, vec![]), |f| f.fetch_plan())
 }
 
 /// Execute a specific fetch strategy.
 async fn execute_strategy(
-    provider: Provider,
+    fetcher: &dyn ProviderFetcher,
     strategy_id: &str,
-    _kind: FetchKind,
 ) -> Result<UsageSnapshot> {
-    match (provider, strategy_id) {
-        // Codex strategies
-        (Provider::Codex, "codex-web-dashboard") => codex::fetch_web_dashboard().await,
-        (Provider::Codex, "codex-cli-rpc") => codex::fetch_cli().await,
-
-        // Claude strategies
-        (Provider::Claude, "claude-oauth") => {
-            // Same fallback chain as the strategy's availability check:
-            // keyring -> Claude Code's .credentials.json -> macOS Keychain.
-            let token = claude::get_oauth_token().ok_or_else(|| {
-                CautError::Config(
-                    "No Claude OAuth token found (checked keyring, \
-                     <claude_dir>/.credentials.json, and macOS Keychain)"
-                        .to_string(),
-                )
-            })?;
-            claude::fetch_oauth(&token).await
-        }
-        (Provider::Claude, "claude-web") => claude::fetch_web().await,
-        (Provider::Claude, "claude-cli-pty") => claude::fetch_cli().await,
+    fetcher.fetch_usage(strategy_id).await
+}
 
-        // Unknown strategy
-        _ => Err(CautError::FetchFailed {
-            provider: provider.cli_name().to_string(),
-            reason: format!("Unknown strategy: {strategy_id}"),
-        }),
+/// Run every available strategy for `provider`, not just the first that
+/// works, and report each attempt. Used by the [canary](super::canary)
+/// self-test; nothing is recorded to history.
+pub async fn probe_strategies(provider: Provider, timeout_duration: Duration) -> Vec<FetchAttempt> {
+    if fake_providers::is_enabled() {
+        return Vec::new();
     }
+    let Some(fetcher) = ProviderRegistry::new().fetcher(provider) else {
+        return Vec::new();
+    };
+
+    let mut attempts = Vec::new();
+    for strategy in fetcher.fetch_plan().strategies {
+        if !(strategy.is_available)() {
+            continue;
+        }
+        let started_at = Utc::now();
+        let start = Instant::now();
+        let result = timeout(
+            timeout_duration,
+            execute_strategy(fetcher.as_ref(), strategy.id),
+        )
+        .await
+        .unwrap_or_else(|_| {
+            Err(CautError::TimeoutWithProvider {
+                provider: provider.cli_name().to_string(),
+                seconds: timeout_duration.as_secs(),
+            })
+        });
+        #[allow(clippy::cast_possible_truncation)] // fetch durations won't exceed u64::MAX ms
+        let duration_ms = start.elapsed().as_millis() as u64;
+        attempts.push(FetchAttempt {
+            strategy_id: strategy.id.to_string(),
+            kind: strategy.kind,
+            started_at,
+            duration_ms,
+            success: result.is_ok(),
+            error: result.err().map(|e| e.to_string()),
+        });
+    }
+    attempts
 }
 
 /// Fetch multiple providers in parallel.
@@ -194,7 +227,8 @@ pub async fn fetch_providers_with_timeout(
     futures::future::join_all(futures).await
 }
 
-async fn fetch_provider_with_timeout(
+/// Fetch one provider, failing with a timeout error after `timeout_duration`.
+pub async fn fetch_provider_with_timeout(
     provider: Provider,
     mode: SourceMode,
     timeout_duration: Duration,
@@ -213,21 +247,473 @@ async fn fetch_provider_with_timeout(
         })
 }
 
+// =============================================================================
+// Usage Run
+// =============================================================================
+
+/// What to fetch in a usage run.
+#[derive(Debug, Clone)]
+pub struct UsageRequest {
+    pub providers: Vec<Provider>,
+    pub mode: SourceMode,
+    /// Per-provider timeout; `None` uses each provider's default.
+    pub timeout_override: Option<Duration>,
+    /// Fetch provider status pages.
+    pub include_status: bool,
+    /// Record successful snapshots, and failed fetches of providers that
+    /// are set up, to the history database.
+    pub record_history: bool,
+    /// Also fetch every other available source and report windows that
+    /// disagree with the preferred one.
+    pub verify: bool,
+}
+
+/// Result of one provider in a usage run.
+#[derive(Debug)]
+pub struct ProviderResult {
+    pub provider: Provider,
+    /// The rendered payload, or why the provider could not be fetched.
+    pub outcome: Result<ProviderPayload>,
+    /// Strategies tried, in order.
+    pub attempts: Vec<FetchAttempt>,
+    /// Wall-clock time for the provider, including enrichment.
+    pub duration_ms: u64,
+}
+
+impl ProviderResult {
+    /// The payload, if the fetch succeeded.
+    #[must_use]
+    pub fn payload(&self) -> Option<&ProviderPayload> {
+        self.outcome.as_ref().ok()
+    }
+
+    /// The error, if the fetch failed.
+    #[must_use]
+    pub fn error(&self) -> Option<&CautError> {
+        self.outcome.as_ref().err()
+    }
+
+    /// Whether the provider is not set up on this machine: its CLI is
+    /// missing, or none of its fetch strategies was available to try.
+    #[must_use]
+    pub fn is_not_configured(&self) -> bool {
+        match self.error() {
+            Some(CautError::NoAvailableStrategy(_)) => self.attempts.is_empty(),
+            Some(
+                CautError::ProviderNotFound(_)
+                | CautError::CliNotFound { .. }
+                | CautError::AuthNotConfigured { .. },
+            ) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Fetch every requested provider in parallel and build their payloads.
+///
+/// Successful snapshots are enriched with status, auth warnings, credits,
+/// and provider-specific projections, and recorded to history when
+/// requested. Results keep the order of `request.providers`.
+pub async fn run_usage(request: &UsageRequest) -> Vec<ProviderResult> {
+    let status_fetcher = request.include_status.then(StatusFetcher::new);
+    let auth_checker = AuthHealthAggregator::new();
+
+    let futures: Vec<_> = request
+        .providers
+        .iter()
+        .map(|&provider| {
+            let timeout = request
+                .timeout_override
+                .unwrap_or_else(|| provider.default_timeout());
+            let status_fetcher = status_fetcher.as_ref();
+            let auth_checker = &auth_checker;
+            async move {
+                let start = Instant::now();
+                let outcome = fetch_provider_with_timeout(provider, request.mode, timeout).await;
+                let payload = match outcome.result {
+                    Ok(snapshot) => {
+                        let divergences = if request.verify {
+                            let preferred = outcome
+                                .attempts
+                                .iter()
+                                .rfind(|a| a.success)
+                                .map(|a| a.strategy_id.as_str());
+                            verify_sources(
+                                provider,
+                                preferred,
+                                &outcome.source_label,
+                                &snapshot,
+                                timeout,
+                            )
+                            .await
+                        } else {
+                            Vec::new()
+                        };
+                        let mut payload = build_payload(
+                            provider,
+                            snapshot,
+                            outcome.source_label,
+                            status_fetcher,
+                            auth_checker,
+                        )
+                        .await;
+                        payload.divergences = divergences;
+                        Ok(payload)
+                    }
+                    Err(e) => Err(e),
+                };
+                #[allow(clippy::cast_possible_truncation)]
+                // fetch durations won't exceed u64::MAX ms
+                let duration_ms = start.elapsed().as_millis() as u64;
+                ProviderResult {
+                    provider,
+                    outcome: payload,
+                    attempts: outcome.attempts,
+                    duration_ms,
+                }
+            }
+        })
+        .collect();
+
+    let mut results = futures::future::join_all(futures).await;
+
+    // Preview/nightly Claude Code installs are listed right after Claude
+    if let Some(at) = results.iter().position(|r| r.provider == Provider::Claude)
+        && matches!(request.mode, SourceMode::Auto | SourceMode::OAuth)
+        && !fake_providers::is_enabled()
+    {
+        let extra =
+            claude_installation_results(request, status_fetcher.as_ref(), &auth_checker).await;
+        results.splice(at + 1..at + 1, extra);
+    }
+
+    if request.record_history
+        && let Ok(store) = HistoryStore::open(&AppPaths::new().history_db_file())
+    {
+        record_results(&store, &results, SnapshotTrigger::Periodic, None);
+    }
+
+    results
+}
+
+/// Record a usage run to history: a snapshot (with plan limits and local
+/// model throughput) for each success, and a failure for each provider that
+/// is set up but could not be fetched.
+///
+/// Storage errors are logged rather than returned, so one bad row does not
+/// lose the rest. Returns the number of snapshots recorded.
+pub fn record_results(
+    store: &HistoryStore,
+    results: &[ProviderResult],
+    trigger: SnapshotTrigger,
+    note: Option<&str>,
+) -> usize {
+    let mut recorded = 0;
+    for result in results {
+        let payload = match &result.outcome {
+            Ok(payload) => payload,
+            Err(error) => {
+                if !result.is_not_configured()
+                    && let Err(e) =
+                        store.record_fetch_failure(&result.provider, error.error_code(), Utc::now())
+                {
+                    tracing::warn!("Failed to record fetch failure: {}", e);
+                }
+                continue;
+            }
+        };
+        match store.record_snapshot_as(&payload.usage, &result.provider, trigger, note) {
+            Ok(_) => recorded += 1,
+            Err(e) => tracing::warn!("Failed to record snapshot: {}", e),
+        }
+        match store.record_plan_limits(&payload.usage, &result.provider) {
+            Ok(changes) => {
+                for change in changes {
+                    tracing::info!("{}", change.message());
+                }
+            }
+            Err(e) => tracing::warn!("Failed to record plan limits: {}", e),
+        }
+        if let Some(stats) = &payload.local_model
+            && let Err(e) =
+                store.record_local_model_stats(stats, &result.provider, payload.usage.updated_at)
+        {
+            tracing::warn!("Failed to record local model stats: {}", e);
+        }
+    }
+    recorded
+}
+
+/// Fetch each Claude Code installation found next to the stable one, as
+/// Claude results whose source is labelled with the install's channel.
+async fn claude_installation_results(
+    request: &UsageRequest,
+    status_fetcher: Option<&StatusFetcher>,
+    auth_checker: &AuthHealthAggregator,
+) -> Vec<ProviderResult> {
+    let timeout_duration = request
+        .timeout_override
+        .unwrap_or_else(|| Provider::Claude.default_timeout());
+    let mut results = Vec::new();
+
+    for install in claude::installations::extra() {
+        let started_at = Utc::now();
+        let start = Instant::now();
+        let fetched = timeout(timeout_duration, claude::installations::fetch(&install))
+            .await
+            .unwrap_or_else(|_| {
+                Err(CautError::TimeoutWithProvider {
+                    provider: install.label("claude"),
+                    seconds: timeout_duration.as_secs(),
+                })
+            });
+        #[allow(clippy::cast_possible_truncation)] // fetch durations won't exceed u64::MAX ms
+        let duration_ms = start.elapsed().as_millis() as u64;
+        let attempts = vec![FetchAttempt {
+            strategy_id: "claude-oauth".to_string(),
+            kind: FetchKind::OAuth,
+            started_at,
+            duration_ms,
+            success: fetched.is_ok(),
+            error: fetched.as_ref().err().map(ToString::to_string),
+        }];
+
+        let outcome = match fetched {
+            Ok(snapshot) => Ok(build_payload(
+                Provider::Claude,
+                snapshot,
+                install.label(FetchKind::OAuth.source_label()),
+                status_fetcher,
+                auth_checker,
+            )
+            .await),
+            Err(e) => Err(e),
+        };
+        results.push(ProviderResult {
+            provider: Provider::Claude,
+            outcome,
+            attempts,
+            duration_ms,
+        });
+    }
+    results
+}
+
+/// Fetch every other available strategy for `provider` and compare it with
+/// the snapshot from the preferred strategy.
+///
+/// Sources that fail or time out are skipped; verification never turns a
+/// successful fetch into an error.
+async fn verify_sources(
+    provider: Provider,
+    preferred_strategy: Option<&str>,
+    preferred_source: &str,
+    preferred: &UsageSnapshot,
+    timeout_duration: Duration,
+) -> Vec<SourceDivergence> {
+    if fake_providers::is_enabled() {
+        return Vec::new();
+    }
+    let Some(fetcher) = ProviderRegistry::new().fetcher(provider) else {
+        return Vec::new();
+    };
+
+    let mut divergences = Vec::new();
+    for strategy in fetcher.fetch_plan().strategies {
+        if Some(strategy.id) == preferred_strategy || !(strategy.is_available)() {
+            continue;
+        }
+        let source = strategy.kind.source_label();
+        match timeout(
+            timeout_duration,
+            execute_strategy(fetcher.as_ref(), strategy.id),
+        )
+        .await
+        {
+            Ok(Ok(snapshot)) => divergences.extend(divergence::compare(
+                preferred_source,
+                preferred,
+                source,
+                &snapshot,
+            )),
+            Ok(Err(e)) => tracing::debug!(
+                provider = %provider.cli_name(),
+                strategy = strategy.id,
+                error = %e,
+                "Verification source failed"
+            ),
+            Err(_) => tracing::debug!(
+                provider = %provider.cli_name(),
+                strategy = strategy.id,
+                "Verification source timed out"
+            ),
+        }
+    }
+
+    for divergence in &divergences {
+        tracing::warn!(
+            provider = %provider.cli_name(),
+            "Sources disagree: {}",
+            divergence.message()
+        );
+    }
+    divergences
+}
+
+/// Build the payload for a successful fetch.
+async fn build_payload(
+    provider: Provider,
+    snapshot: UsageSnapshot,
+    source: String,
+    status_fetcher: Option<&StatusFetcher>,
+    auth_checker: &AuthHealthAggregator,
+) -> ProviderPayload {
+    let status = match (status_fetcher, provider.status_page_url()) {
+        (Some(fetcher), Some(url)) => fetcher.fetch(url).await.ok(),
+        _ => None,
+    };
+
+    let auth_warning = auth_checker.check_provider(provider).warning_message();
+
+    let mut payload = ProviderPayload {
+        provider: provider.cli_name().to_string(),
+        account: snapshot
+            .identity
+            .as_ref()
+            .and_then(|i| i.account_email.clone()),
+        version: None, // TODO: Get from CLI version
+        source,
+        status,
+        usage: snapshot,
+        credits: None,
+        antigravity_plan_info: None,
+        openai_dashboard: None,
+        copilot_premium: None,
+        task_spend: None,
+        local_model: None,
+        divergences: Vec::new(),
+        auth_warning,
+    };
+
+    // Provider-specific details (credits, projections, local stats) come from
+    // live services, which fake providers must not touch.
+    if !fake_providers::is_enabled()
+        && let Some(fetcher) = ProviderRegistry::new().fetcher(provider)
+    {
+        fetcher.enrich(&mut payload).await;
+    }
+    payload
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_get_fetch_plan_codex() {
-        let plan = get_fetch_plan(Provider::Codex);
-        assert_eq!(plan.provider, Provider::Codex);
-        assert!(!plan.strategies.is_empty());
+    fn test_get_fetch_plan_strategies_per_source() {
+        // Web-backed providers are checked with an explicit source so the
+        // result doesn't depend on whether auto mode is skipping web.
+        let cases: &[(Provider, SourceMode, &[&str])] = &[
+            (Provider::Codex, SourceMode::Web, &["codex-web-dashboard"]),
+            (Provider::Codex, SourceMode::Cli, &["codex-cli-rpc"]),
+            (Provider::Codex, SourceMode::OAuth, &[]),
+            (Provider::Claude, SourceMode::OAuth, &["claude-oauth"]),
+            (Provider::Claude, SourceMode::Web, &["claude-web"]),
+            (Provider::Claude, SourceMode::Cli, &["claude-cli-pty"]),
+            (Provider::Cursor, SourceMode::Web, &["cursor-web"]),
+            (Provider::Copilot, SourceMode::Auto, &["copilot-api"]),
+            (
+                Provider::JetBrainsAI,
+                SourceMode::Auto,
+                &["jetbrains-local"],
+            ),
+            (Provider::Perplexity, SourceMode::Auto, &["perplexity-api"]),
+            (Provider::Groq, SourceMode::Auto, &["groq-api"]),
+            (Provider::Together, SourceMode::Auto, &["together-api"]),
+            (Provider::Cohere, SourceMode::Auto, &["cohere-api"]),
+            (Provider::Fireworks, SourceMode::Auto, &["fireworks-api"]),
+            (Provider::Windsurf, SourceMode::Auto, &["windsurf-api"]),
+            (Provider::Aider, SourceMode::Auto, &["aider-local"]),
+            (Provider::Cline, SourceMode::Auto, &["cline-local"]),
+            (Provider::OpenRouter, SourceMode::Auto, &["openrouter-api"]),
+            (Provider::AmazonQ, SourceMode::Auto, &["amazonq-api"]),
+            (Provider::Cody, SourceMode::Auto, &["cody-api"]),
+            (Provider::DeepSeek, SourceMode::Auto, &["deepseek-api"]),
+            (Provider::Xai, SourceMode::Auto, &["xai-api"]),
+            (Provider::Continue, SourceMode::Auto, &["continue-local"]),
+            (Provider::Ollama, SourceMode::Auto, &["ollama-api"]),
+            (Provider::Qwen, SourceMode::Auto, &["qwen-local"]),
+            (Provider::Goose, SourceMode::Auto, &["goose-local"]),
+            (
+                Provider::AzureOpenAI,
+                SourceMode::Auto,
+                &["azure-openai-api"],
+            ),
+            (Provider::Bedrock, SourceMode::Auto, &["bedrock-cloudwatch"]),
+            (Provider::LiteLLM, SourceMode::Auto, &["litellm-api"]),
+        ];
+
+        for &(provider, source, expected) in cases {
+            let plan = get_fetch_plan(provider);
+            assert_eq!(plan.provider, provider);
+            let ids: Vec<_> = plan.for_mode(source).iter().map(|s| s.id).collect();
+            assert_eq!(ids, expected, "{provider:?} with {source:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn run_usage_reports_unsupported_provider_as_error() {
+        let request = UsageRequest {
+            providers: vec![Provider::Kiro],
+            mode: SourceMode::Auto,
+            timeout_override: Some(Duration::from_secs(5)),
+            include_status: false,
+            record_history: false,
+            verify: false,
+        };
+
+        let results = run_usage(&request).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].provider, Provider::Kiro);
+        assert!(results[0].payload().is_none());
+        assert!(matches!(
+            results[0].error(),
+            Some(CautError::UnsupportedSource { .. })
+        ));
+        assert!(results[0].attempts.is_empty());
     }
 
     #[test]
-    fn test_get_fetch_plan_claude() {
-        let plan = get_fetch_plan(Provider::Claude);
-        assert_eq!(plan.provider, Provider::Claude);
-        assert!(!plan.strategies.is_empty());
+    fn provider_without_usable_strategy_is_not_configured() {
+        let result = |error, attempts| ProviderResult {
+            provider: Provider::Codex,
+            outcome: Err(error),
+            attempts,
+            duration_ms: 0,
+        };
+        let attempt = FetchAttempt {
+            strategy_id: "codex-cli".to_string(),
+            kind: FetchKind::Cli,
+            started_at: Utc::now(),
+            duration_ms: 1,
+            success: false,
+            error: Some("exit status 1".to_string()),
+        };
+
+        assert!(
+            result(CautError::NoAvailableStrategy("codex".to_string()), vec![]).is_not_configured()
+        );
+        assert!(
+            result(CautError::ProviderNotFound("codex".to_string()), vec![]).is_not_configured()
+        );
+        // A strategy ran and failed: set up, but broken.
+        assert!(
+            !result(
+                CautError::NoAvailableStrategy("codex".to_string()),
+                vec![attempt]
+            )
+            .is_not_configured()
+        );
+        assert!(!result(CautError::Network("down".to_string()), vec![]).is_not_configured());
     }
 }
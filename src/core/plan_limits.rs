@@ -0,0 +1,289 @@
+//! Provider plan limit change detection.
+//!
+//! Providers adjust allowances and window lengths on their side without
+//! notice. Each recorded snapshot's window definitions (length, and the
+//! allowance when the window description has the form `used/limit unit`)
+//! are compared with the last ones seen, and differences are stored in the
+//! history database as [`PlanLimitChange`]s for `caut history changes` and
+//! `caut doctor`.
+
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Local, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::core::models::{RateWindow, UsageSnapshot};
+use crate::core::provider::Provider;
+
+/// Relative change in window length treated as noise. Calendar-month
+/// windows vary between 28 and 31 days.
+const WINDOW_TOLERANCE: f64 = 0.15;
+
+/// `used/limit unit`, e.g. `125/500 premium requests`.
+static ALLOWANCE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*([0-9][0-9,]*(?:\.[0-9]+)?)\s*/\s*([0-9][0-9,]*(?:\.[0-9]+)?)\s+(.+?)\s*$")
+        .expect("valid allowance regex")
+});
+
+/// Usage against an allowance, from a window description of the form
+/// `used/limit unit`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Allowance {
+    pub used: f64,
+    pub limit: f64,
+    pub unit: String,
+}
+
+impl Allowance {
+    /// Parse a window description; `None` unless it has the allowance form.
+    #[must_use]
+    pub fn parse(description: &str) -> Option<Self> {
+        let caps = ALLOWANCE_RE.captures(description)?;
+        let number = |i: usize| caps[i].replace(',', "").parse::<f64>().ok();
+        Some(Self {
+            used: number(1)?,
+            limit: number(2)?,
+            unit: caps[3].to_string(),
+        })
+    }
+}
+
+/// Observed definition of one usage window.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanLimit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_minutes: Option<i32>,
+    /// Allowance for the window, when the provider reports one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<f64>,
+    /// Unit of `limit`, e.g. `premium requests`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+impl PlanLimit {
+    /// Definition of a rate window; `None` when it carries no limit details.
+    #[must_use]
+    pub fn from_window(window: &RateWindow) -> Option<Self> {
+        let (limit, unit) = window
+            .reset_description
+            .as_deref()
+            .and_then(Allowance::parse)
+            .map_or((None, None), |allowance| {
+                (Some(allowance.limit), Some(allowance.unit))
+            });
+        let limit = Self {
+            window_minutes: window.window_minutes,
+            limit,
+            unit,
+        };
+        (limit != Self::default()).then_some(limit)
+    }
+
+    /// Whether `other` is a different plan, ignoring details either side lacks.
+    #[must_use]
+    pub fn differs_from(&self, other: &Self) -> bool {
+        let window_changed = match (self.window_minutes, other.window_minutes) {
+            (Some(a), Some(b)) if a > 0 && b > 0 => {
+                f64::from((a - b).abs()) / f64::from(a.max(b)) > WINDOW_TOLERANCE
+            }
+            _ => false,
+        };
+        let limit_changed = match (self.limit, other.limit) {
+            (Some(a), Some(b)) => self.unit == other.unit && (a - b).abs() > f64::EPSILON,
+            _ => false,
+        };
+        window_changed || limit_changed
+    }
+
+    /// This definition with details missing from it filled in from `previous`.
+    #[must_use]
+    pub fn merged_over(&self, previous: &Self) -> Self {
+        let same_unit = self.unit.is_none() || self.unit == previous.unit;
+        Self {
+            window_minutes: self.window_minutes.or(previous.window_minutes),
+            limit: self.limit.or(previous.limit.filter(|_| same_unit)),
+            unit: self.unit.clone().or_else(|| previous.unit.clone()),
+        }
+    }
+}
+
+/// Window definitions in a snapshot, keyed by slot name.
+#[must_use]
+pub fn snapshot_limits(snapshot: &UsageSnapshot) -> Vec<(&'static str, PlanLimit)> {
+    [
+        ("primary", snapshot.primary.as_ref()),
+        ("secondary", snapshot.secondary.as_ref()),
+        ("tertiary", snapshot.tertiary.as_ref()),
+    ]
+    .into_iter()
+    .filter_map(|(slot, window)| Some((slot, PlanLimit::from_window(window?)?)))
+    .collect()
+}
+
+/// A detected change in a provider window's definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanLimitChange {
+    pub provider: Provider,
+    /// Window slot (`primary`, `secondary`, `tertiary`).
+    pub window: String,
+    pub detected_at: DateTime<Utc>,
+    pub old: PlanLimit,
+    pub new: PlanLimit,
+}
+
+impl PlanLimitChange {
+    /// One-line description, e.g. "Claude weekly limit appears to have
+    /// changed on Jan 12 (window 7d -> 5d)".
+    #[must_use]
+    pub fn message(&self) -> String {
+        let label = self
+            .new
+            .window_minutes
+            .or(self.old.window_minutes)
+            .and_then(window_label)
+            .unwrap_or(self.window.as_str());
+
+        let mut details = Vec::new();
+        if let (Some(old), Some(new)) = (self.old.limit, self.new.limit)
+            && (old - new).abs() > f64::EPSILON
+        {
+            let unit = self.new.unit.as_deref().unwrap_or_default();
+            details.push(format!("{old} -> {new} {unit}").trim_end().to_string());
+        }
+        if let (Some(old), Some(new)) = (self.old.window_minutes, self.new.window_minutes)
+            && old != new
+        {
+            details.push(format!(
+                "window {} -> {}",
+                format_window(old),
+                format_window(new)
+            ));
+        }
+
+        let mut message = format!(
+            "{} {label} limit appears to have changed on {}",
+            self.provider.display_name(),
+            self.detected_at.with_timezone(&Local).format("%b %-d")
+        );
+        if !details.is_empty() {
+            message.push_str(&format!(" ({})", details.join(", ")));
+        }
+        message
+    }
+}
+
+/// Common name for a window length.
+fn window_label(minutes: i32) -> Option<&'static str> {
+    match minutes {
+        300 => Some("5-hour"),
+        1440 => Some("daily"),
+        10_080 => Some("weekly"),
+        40_320..=44_640 => Some("monthly"),
+        _ => None,
+    }
+}
+
+fn format_window(minutes: i32) -> String {
+    if minutes % 1440 == 0 {
+        format!("{}d", minutes / 1440)
+    } else if minutes % 60 == 0 {
+        format!("{}h", minutes / 60)
+    } else {
+        format!("{minutes}m")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn window(minutes: Option<i32>, description: Option<&str>) -> RateWindow {
+        RateWindow {
+            used_percent: 10.0,
+            window_minutes: minutes,
+            resets_at: None,
+            reset_description: description.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn parses_allowance_from_description() {
+        let limit =
+            PlanLimit::from_window(&window(None, Some("125/1,500 premium requests"))).unwrap();
+        assert_eq!(limit.limit, Some(1500.0));
+        assert_eq!(limit.unit.as_deref(), Some("premium requests"));
+
+        let countdown = PlanLimit::from_window(&window(Some(300), Some("in 2h 15m"))).unwrap();
+        assert_eq!(countdown.window_minutes, Some(300));
+        assert!(countdown.limit.is_none());
+
+        assert!(PlanLimit::from_window(&window(None, Some("in 2h"))).is_none());
+    }
+
+    #[test]
+    fn month_length_is_not_a_change() {
+        let february = PlanLimit {
+            window_minutes: Some(28 * 1440),
+            ..PlanLimit::default()
+        };
+        let march = PlanLimit {
+            window_minutes: Some(31 * 1440),
+            ..PlanLimit::default()
+        };
+        assert!(!february.differs_from(&march));
+
+        let weekly = PlanLimit {
+            window_minutes: Some(10_080),
+            ..PlanLimit::default()
+        };
+        assert!(weekly.differs_from(&february));
+    }
+
+    #[test]
+    fn missing_details_are_not_a_change() {
+        let full = PlanLimit {
+            window_minutes: Some(10_080),
+            limit: Some(500.0),
+            unit: Some("requests".to_string()),
+        };
+        let partial = PlanLimit {
+            window_minutes: Some(10_080),
+            ..PlanLimit::default()
+        };
+        assert!(!full.differs_from(&partial));
+        assert_eq!(partial.merged_over(&full), full);
+
+        let lowered = PlanLimit {
+            limit: Some(400.0),
+            ..full.clone()
+        };
+        assert!(full.differs_from(&lowered));
+    }
+
+    #[test]
+    fn message_names_window_and_change() {
+        let change = PlanLimitChange {
+            provider: Provider::Claude,
+            window: "secondary".to_string(),
+            detected_at: Utc.with_ymd_and_hms(2026, 1, 12, 12, 0, 0).unwrap(),
+            old: PlanLimit {
+                window_minutes: Some(10_080),
+                limit: Some(500.0),
+                unit: Some("requests".to_string()),
+            },
+            new: PlanLimit {
+                window_minutes: Some(10_080),
+                limit: Some(400.0),
+                unit: Some("requests".to_string()),
+            },
+        };
+        let message = change.message();
+        assert!(message.starts_with("Claude weekly limit appears to have changed on Jan 1"));
+        assert!(message.ends_with("(500 -> 400 requests)"));
+    }
+}
@@ -0,0 +1,239 @@
+//! Subscription value: token use priced at API rates vs. the plan price.
+//!
+//! A subscription pays off when the tokens an agent burns through it would
+//! have cost more at pay-as-you-go API prices. Each billing cycle's session
+//! spend (priced with [`crate::core::pricing`]) is compared with the plan's
+//! monthly price. Cycles start on the billing day of each month, clamped to
+//! the month's length.
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Months, NaiveDate};
+use serde::Serialize;
+
+use crate::core::provider::Provider;
+
+/// A subscription plan and its monthly list price in USD.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plan {
+    pub provider: Provider,
+    /// Name used with `--plan` and detected from local credentials.
+    pub name: &'static str,
+    pub label: &'static str,
+    pub monthly_usd: f64,
+}
+
+/// Individual plans with a flat monthly price (Team is the monthly
+/// per-seat price).
+pub const PLANS: [Plan; 8] = [
+    plan(Provider::Claude, "pro", "Pro", 20.0),
+    plan(Provider::Claude, "max-5x", "Max 5x", 100.0),
+    plan(Provider::Claude, "max-20x", "Max 20x", 200.0),
+    plan(Provider::Claude, "team", "Team", 30.0),
+    plan(Provider::Codex, "plus", "Plus", 20.0),
+    plan(Provider::Codex, "pro", "Pro", 200.0),
+    plan(Provider::Codex, "team", "Team", 30.0),
+    plan(Provider::Codex, "business", "Business", 30.0),
+];
+
+const fn plan(
+    provider: Provider,
+    name: &'static str,
+    label: &'static str,
+    monthly_usd: f64,
+) -> Plan {
+    Plan {
+        provider,
+        name,
+        label,
+        monthly_usd,
+    }
+}
+
+/// Look up a provider's plan by name (case-insensitive).
+#[must_use]
+pub fn find_plan(provider: Provider, name: &str) -> Option<&'static Plan> {
+    PLANS
+        .iter()
+        .find(|plan| plan.provider == provider && plan.name.eq_ignore_ascii_case(name.trim()))
+}
+
+/// Plan names known for `provider`, for error messages.
+#[must_use]
+pub fn plan_names(provider: Provider) -> Vec<&'static str> {
+    PLANS
+        .iter()
+        .filter(|plan| plan.provider == provider)
+        .map(|plan| plan.name)
+        .collect()
+}
+
+/// One billing cycle, `start` to `end` inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BillingCycle {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+/// Day `billing_day` of the month containing `date`, clamped to the month.
+fn cycle_day(date: NaiveDate, billing_day: u32) -> NaiveDate {
+    (1..=billing_day.clamp(1, 31))
+        .rev()
+        .find_map(|day| date.with_day(day))
+        .unwrap_or(date)
+}
+
+/// The `count` most recent billing cycles as of `today`, oldest first; the
+/// last one is the cycle in progress.
+#[must_use]
+pub fn billing_cycles(today: NaiveDate, billing_day: u32, count: u32) -> Vec<BillingCycle> {
+    let this_month = cycle_day(today, billing_day);
+    let current_start = if this_month <= today {
+        this_month
+    } else {
+        cycle_day(today - Months::new(1), billing_day)
+    };
+
+    (0..count)
+        .rev()
+        .filter_map(|back| {
+            let start = cycle_day(
+                current_start.checked_sub_months(Months::new(back))?,
+                billing_day,
+            );
+            let next = cycle_day(start.checked_add_months(Months::new(1))?, billing_day);
+            Some(BillingCycle {
+                start,
+                end: next.pred_opt()?,
+            })
+        })
+        .collect()
+}
+
+/// Value of the plan in one billing cycle.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleValue {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// The cycle has not ended yet; spend is to date.
+    pub in_progress: bool,
+    /// Session token use priced at API rates.
+    pub api_value_usd: f64,
+    /// API value minus the plan price; negative when the plan cost more.
+    pub savings_usd: f64,
+}
+
+/// Value of a provider's plan over recent billing cycles.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanValue {
+    pub provider: String,
+    /// Plan label, e.g. `Max 20x`, or `custom` for a price given directly.
+    pub plan: String,
+    pub plan_price_usd: f64,
+    /// Oldest first; the last cycle is the one in progress.
+    pub cycles: Vec<CycleValue>,
+    /// Savings summed over completed cycles.
+    pub total_savings_usd: f64,
+}
+
+impl PlanValue {
+    /// The most recent completed cycle, if any.
+    #[must_use]
+    pub fn last_completed(&self) -> Option<&CycleValue> {
+        self.cycles.iter().rev().find(|cycle| !cycle.in_progress)
+    }
+}
+
+/// Compare `daily` API-rate spend with `plan_price_usd` over `cycles`.
+#[must_use]
+pub fn evaluate(
+    provider: &str,
+    plan: &str,
+    plan_price_usd: f64,
+    daily: &BTreeMap<NaiveDate, f64>,
+    cycles: &[BillingCycle],
+    today: NaiveDate,
+) -> PlanValue {
+    let cycles: Vec<CycleValue> = cycles
+        .iter()
+        .map(|cycle| {
+            let api_value_usd: f64 = daily.range(cycle.start..=cycle.end).map(|(_, c)| c).sum();
+            CycleValue {
+                start: cycle.start,
+                end: cycle.end,
+                in_progress: cycle.end >= today,
+                api_value_usd,
+                savings_usd: api_value_usd - plan_price_usd,
+            }
+        })
+        .collect();
+    let total_savings_usd = cycles
+        .iter()
+        .filter(|cycle| !cycle.in_progress)
+        .map(|cycle| cycle.savings_usd)
+        .sum();
+
+    PlanValue {
+        provider: provider.to_string(),
+        plan: plan.to_string(),
+        plan_price_usd,
+        cycles,
+        total_savings_usd,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn cycles_follow_the_billing_day() {
+        let cycle = |start: NaiveDate, end: NaiveDate| BillingCycle { start, end };
+
+        // Billing on the 31st falls back to the last day of shorter months.
+        let cycles = billing_cycles(date(2026, 3, 10), 31, 3);
+        assert_eq!(
+            cycles,
+            [
+                cycle(date(2025, 12, 31), date(2026, 1, 30)),
+                cycle(date(2026, 1, 31), date(2026, 2, 27)),
+                cycle(date(2026, 2, 28), date(2026, 3, 30)),
+            ]
+        );
+
+        let monthly = billing_cycles(date(2026, 10, 16), 1, 2);
+        assert_eq!(
+            monthly,
+            [
+                cycle(date(2026, 9, 1), date(2026, 9, 30)),
+                cycle(date(2026, 10, 1), date(2026, 10, 31)),
+            ]
+        );
+    }
+
+    #[test]
+    fn savings_count_completed_cycles_only() {
+        let today = date(2026, 10, 16);
+        let daily = BTreeMap::from([
+            (date(2026, 9, 2), 300.0),
+            (date(2026, 9, 20), 212.4),
+            (date(2026, 10, 3), 50.0),
+        ]);
+        let cycles = billing_cycles(today, 1, 2);
+
+        let value = evaluate("claude", "Max 20x", 200.0, &daily, &cycles, today);
+        let september = value.last_completed().unwrap();
+        assert!((september.api_value_usd - 512.4).abs() < 1e-9);
+        assert!((september.savings_usd - 312.4).abs() < 1e-9);
+        assert!(value.cycles[1].in_progress);
+        assert!((value.total_savings_usd - 312.4).abs() < 1e-9);
+        let plan = find_plan(Provider::Claude, "MAX-20X").unwrap();
+        assert!((plan.monthly_usd - 200.0).abs() < f64::EPSILON);
+    }
+}
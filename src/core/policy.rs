@@ -0,0 +1,485 @@
+//! Team policy rules for providers and models.
+//!
+//! Rules live under `[[policy.rules]]` in the config file, so a remote team
+//! config can set them, and flag use of specific providers or models,
+//! optionally only at certain times:
+//!
+//! ```toml
+//! [[policy.rules]]
+//! name = "no-opus-business-hours"
+//! action = "forbid"
+//! provider = "claude"
+//! model = "*opus*"
+//! days = ["mon", "tue", "wed", "thu", "fri"]
+//! hours = "09:00-18:00"
+//!
+//! [[policy.rules]]
+//! action = "warn"
+//! model = "gpt-4.5*"
+//! ```
+//!
+//! Rules are checked against session logs (models and start time) in `cost`
+//! output, and against live usage in `usage --watch`, where new violations
+//! raise a notification. Live usage carries no model, so only rules without
+//! a `model` apply to it. Times are local.
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Datelike, Local, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::core::models::ProviderPayload;
+use crate::core::provider::Provider;
+use crate::core::session_logs::{SessionLogFinder, SessionUsage};
+use crate::error::{CautError, Result};
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+/// What a matching rule means.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PolicyAction {
+    /// Usage is allowed but flagged.
+    #[default]
+    Warn,
+    /// Usage is against policy.
+    Forbid,
+}
+
+impl PolicyAction {
+    /// Lowercase label used in output.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Warn => "warn",
+            Self::Forbid => "forbid",
+        }
+    }
+}
+
+/// `[policy]` config section.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PolicyConfig {
+    pub rules: Vec<PolicyRule>,
+}
+
+/// A single policy rule. Unset fields match anything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PolicyRule {
+    /// Name shown in violations (defaults to a description of the rule).
+    pub name: Option<String>,
+    pub action: PolicyAction,
+    /// Provider CLI name (e.g. "claude").
+    pub provider: Option<String>,
+    /// Model name pattern; `*` matches any run of characters.
+    pub model: Option<String>,
+    /// Days the rule applies ("mon" .. "sun").
+    pub days: Vec<String>,
+    /// Local time range the rule applies, e.g. "09:00-18:00".
+    pub hours: Option<String>,
+}
+
+impl PolicyRule {
+    /// Name shown in violations.
+    #[must_use]
+    pub fn label(&self) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+        let mut parts = vec![self.action.label().to_string()];
+        parts.extend(self.provider.clone());
+        parts.extend(self.model.clone());
+        if let Some(hours) = &self.hours {
+            parts.push(hours.clone());
+        }
+        parts.join(" ")
+    }
+
+    /// Check that the provider, days, and hours are well-formed.
+    ///
+    /// # Errors
+    /// Returns an error describing the first invalid field.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |what: String| {
+            CautError::Config(format!("Invalid policy rule \"{}\": {what}", self.label()))
+        };
+        if let Some(provider) = &self.provider {
+            Provider::from_cli_name(provider)
+                .map_err(|_| invalid(format!("unknown provider \"{provider}\"")))?;
+        }
+        for day in &self.days {
+            day.parse::<Weekday>()
+                .map_err(|_| invalid(format!("unknown day \"{day}\"")))?;
+        }
+        if let Some(hours) = &self.hours {
+            parse_hours(hours).ok_or_else(|| {
+                invalid(format!("hours \"{hours}\" must look like \"09:00-18:00\""))
+            })?;
+        }
+        Ok(())
+    }
+
+    fn matches_provider(&self, provider: Provider) -> bool {
+        self.provider
+            .as_deref()
+            .is_none_or(|name| name.eq_ignore_ascii_case(provider.cli_name()))
+    }
+
+    fn matches_model(&self, model: &str) -> bool {
+        self.model.as_deref().is_none_or(|pattern| {
+            glob_match(&pattern.to_ascii_lowercase(), &model.to_ascii_lowercase())
+        })
+    }
+
+    /// Whether the rule applies at `at`. Rules without days or hours always
+    /// apply.
+    fn matches_time(&self, at: DateTime<Local>) -> bool {
+        let day_ok = self.days.is_empty()
+            || self
+                .days
+                .iter()
+                .filter_map(|day| day.parse::<Weekday>().ok())
+                .any(|day| day == at.weekday());
+        let hours_ok = self.hours.as_deref().is_none_or(|hours| {
+            parse_hours(hours).is_some_and(|(start, end)| {
+                let time = at.time();
+                if start <= end {
+                    start <= time && time < end
+                } else {
+                    // Overnight range, e.g. 22:00-06:00.
+                    time >= start || time < end
+                }
+            })
+        });
+        day_ok && hours_ok
+    }
+
+    const fn is_timed(&self) -> bool {
+        !self.days.is_empty() || self.hours.is_some()
+    }
+
+    fn violation(&self, provider: Provider) -> PolicyViolation {
+        PolicyViolation {
+            rule: self.label(),
+            action: self.action,
+            provider: provider.cli_name().to_string(),
+            model: None,
+            session_id: None,
+            at: None,
+        }
+    }
+}
+
+fn parse_hours(hours: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let (start, end) = hours.split_once('-')?;
+    let time = |s: &str| NaiveTime::parse_from_str(s.trim(), "%H:%M").ok();
+    Some((time(start)?, time(end)?))
+}
+
+/// Match `text` against a pattern where `*` matches any run of characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<_> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+// =============================================================================
+// Violations
+// =============================================================================
+
+/// Usage that matched a policy rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyViolation {
+    pub rule: String,
+    pub action: PolicyAction,
+    pub provider: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// When the session started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at: Option<DateTime<Utc>>,
+}
+
+impl PolicyViolation {
+    /// One-line description for human output and notifications.
+    #[must_use]
+    pub fn message(&self) -> String {
+        let subject = self.model.as_ref().map_or_else(
+            || self.provider.clone(),
+            |model| format!("{} {model}", self.provider),
+        );
+        let when = self
+            .at
+            .map(|at| format!(" at {}", at.with_timezone(&Local).format("%Y-%m-%d %H:%M")))
+            .unwrap_or_default();
+        format!("[{}] {}: {subject}{when}", self.action.label(), self.rule)
+    }
+}
+
+/// Check one parsed session against the rules.
+///
+/// Each rule is reported at most once per model. Timed rules need the
+/// session's start time and are skipped without one.
+#[must_use]
+pub fn check_session(
+    rules: &[PolicyRule],
+    provider: Provider,
+    usage: &SessionUsage,
+) -> Vec<PolicyViolation> {
+    let started = usage.started_at.map(|at| at.with_timezone(&Local));
+    let models: BTreeSet<&str> = usage.models_used.iter().map(String::as_str).collect();
+    let mut violations = Vec::new();
+
+    for rule in rules {
+        if !rule.matches_provider(provider) {
+            continue;
+        }
+        match started {
+            Some(at) if !rule.matches_time(at) => continue,
+            None if rule.is_timed() => continue,
+            _ => {}
+        }
+        let session = |model: Option<&str>| PolicyViolation {
+            model: model.map(str::to_string),
+            session_id: Some(usage.session_id.clone()),
+            at: usage.started_at,
+            ..rule.violation(provider)
+        };
+        if rule.model.is_none() {
+            violations.push(session(None));
+            continue;
+        }
+        violations.extend(
+            models
+                .iter()
+                .copied()
+                .filter(|model| rule.matches_model(model))
+                .map(|model| session(Some(model))),
+        );
+    }
+    violations
+}
+
+/// Check session logs modified since `since` against the rules.
+///
+/// # Errors
+/// Returns an error if the home directory cannot be determined.
+pub fn scan_sessions(
+    rules: &[PolicyRule],
+    providers: &[Provider],
+    since: DateTime<Utc>,
+) -> Result<Vec<PolicyViolation>> {
+    if rules.is_empty() {
+        return Ok(Vec::new());
+    }
+    let finder = SessionLogFinder::new()?;
+    let mut violations = Vec::new();
+
+    for provider in providers {
+        for log in finder.find_sessions(*provider, Some(since), None) {
+            match log.parse() {
+                Ok(usage) => violations.extend(check_session(rules, *provider, &usage)),
+                Err(e) => {
+                    tracing::debug!(path = %log.path.display(), error = %e, "Skipping session log");
+                }
+            }
+        }
+    }
+
+    violations.sort_by(|a, b| b.at.cmp(&a.at));
+    Ok(violations)
+}
+
+/// Check live usage against rules that do not name a model.
+///
+/// A provider counts as in use when any of its windows shows usage.
+#[must_use]
+pub fn check_live(
+    rules: &[PolicyRule],
+    payloads: &[ProviderPayload],
+    now: DateTime<Local>,
+) -> Vec<PolicyViolation> {
+    let mut violations = Vec::new();
+    for payload in payloads {
+        let Ok(provider) = Provider::from_cli_name(&payload.provider) else {
+            continue;
+        };
+        let usage = &payload.usage;
+        let in_use = [&usage.primary, &usage.secondary, &usage.tertiary]
+            .into_iter()
+            .flatten()
+            .any(|window| window.used_percent > 0.0);
+        if !in_use {
+            continue;
+        }
+        violations.extend(
+            rules
+                .iter()
+                .filter(|rule| {
+                    rule.model.is_none()
+                        && rule.matches_provider(provider)
+                        && rule.matches_time(now)
+                })
+                .map(|rule| rule.violation(provider)),
+        );
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::collections::HashSet;
+
+    use crate::core::models::RateWindow;
+    use crate::test_utils::make_test_provider_payload;
+
+    fn opus_rule() -> PolicyRule {
+        PolicyRule {
+            name: Some("no-opus-business-hours".to_string()),
+            action: PolicyAction::Forbid,
+            provider: Some("claude".to_string()),
+            model: Some("*opus*".to_string()),
+            days: ["mon", "tue", "wed", "thu", "fri"]
+                .map(str::to_string)
+                .to_vec(),
+            hours: Some("09:00-18:00".to_string()),
+        }
+    }
+
+    fn session(models: &[&str], started: DateTime<Local>) -> SessionUsage {
+        SessionUsage {
+            session_id: "abc".to_string(),
+            started_at: Some(started.with_timezone(&Utc)),
+            models_used: models
+                .iter()
+                .map(|m| (*m).to_string())
+                .collect::<HashSet<_>>(),
+            ..SessionUsage::default()
+        }
+    }
+
+    fn local(y: i32, m: u32, d: u32, h: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn glob_patterns() {
+        assert!(glob_match("*opus*", "claude-opus-4-1"));
+        assert!(glob_match("gpt-4.5*", "gpt-4.5-preview"));
+        assert!(glob_match("o3", "o3"));
+        assert!(!glob_match("o3", "o3-mini"));
+        assert!(!glob_match("gpt-4.5*", "gpt-4o"));
+        assert!(glob_match("claude-*-4*", "claude-sonnet-4-5"));
+    }
+
+    #[test]
+    fn flags_model_during_business_hours() {
+        let rules = [opus_rule()];
+        // 2026-10-14 is a Wednesday.
+        let usage = session(
+            &["claude-opus-4-1", "claude-sonnet-4-5"],
+            local(2026, 10, 14, 10),
+        );
+
+        let violations = check_session(&rules, Provider::Claude, &usage);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].model.as_deref(), Some("claude-opus-4-1"));
+        assert_eq!(violations[0].action, PolicyAction::Forbid);
+        assert!(
+            violations[0]
+                .message()
+                .starts_with("[forbid] no-opus-business-hours")
+        );
+    }
+
+    #[test]
+    fn ignores_sessions_outside_rule_times() {
+        let rules = [opus_rule()];
+        let evening = session(&["claude-opus-4-1"], local(2026, 10, 14, 20));
+        let saturday = session(&["claude-opus-4-1"], local(2026, 10, 17, 10));
+
+        assert!(check_session(&rules, Provider::Claude, &evening).is_empty());
+        assert!(check_session(&rules, Provider::Claude, &saturday).is_empty());
+        assert!(check_session(&rules, Provider::Codex, &saturday).is_empty());
+    }
+
+    #[test]
+    fn overnight_hours_wrap() {
+        let rule = PolicyRule {
+            hours: Some("22:00-06:00".to_string()),
+            ..PolicyRule::default()
+        };
+        assert!(rule.matches_time(local(2026, 10, 14, 23)));
+        assert!(rule.matches_time(local(2026, 10, 14, 3)));
+        assert!(!rule.matches_time(local(2026, 10, 14, 12)));
+    }
+
+    #[test]
+    fn live_usage_checks_provider_rules() {
+        let rules = [
+            PolicyRule {
+                provider: Some("codex".to_string()),
+                ..PolicyRule::default()
+            },
+            opus_rule(),
+        ];
+        let mut payload = make_test_provider_payload("codex", "cli");
+        payload.usage.primary = Some(RateWindow::new(12.0));
+
+        let violations = check_live(&rules, &[payload], local(2026, 10, 14, 10));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule, "warn codex");
+    }
+
+    #[test]
+    fn validate_rejects_bad_fields() {
+        assert!(opus_rule().validate().is_ok());
+        let bad_day = PolicyRule {
+            days: vec!["funday".to_string()],
+            ..PolicyRule::default()
+        };
+        assert!(bad_day.validate().is_err());
+        let bad_hours = PolicyRule {
+            hours: Some("9am".to_string()),
+            ..PolicyRule::default()
+        };
+        assert!(bad_hours.validate().is_err());
+    }
+
+    #[test]
+    fn parses_from_toml() {
+        let config: PolicyConfig = toml::from_str(
+            r#"
+[[rules]]
+action = "forbid"
+model = "*opus*"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.rules.len(), 1);
+        assert_eq!(config.rules[0].action, PolicyAction::Forbid);
+        assert_eq!(config.rules[0].label(), "forbid *opus*");
+    }
+}
@@ -0,0 +1,233 @@
+//! Battery and metered-network awareness for long-running modes.
+//!
+//! `caut serve` and `caut usage --watch` poll providers indefinitely. On
+//! battery, or on a metered connection, each refresh is stretched to
+//! [`CONSTRAINED_INTERVAL_FACTOR`] times the configured interval, and
+//! automatic source selection skips web strategies, which scrape dashboards
+//! with browser cookies and are the heaviest fetches.
+//!
+//! Detection:
+//! - battery: `pmset -g batt` on macOS, `/sys/class/power_supply` on Linux
+//! - metered: `NetworkManager`'s `GENERAL.METERED` on Linux (macOS exposes no
+//!   equivalent)
+//!
+//! Set `power_aware = false` under `[general]` to always poll at the
+//! configured interval.
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::core::cli_runner::run_command;
+use crate::core::fetch_plan;
+
+/// How much longer the poll interval gets while constrained.
+pub const CONSTRAINED_INTERVAL_FACTOR: u32 = 4;
+
+/// Timeout for the detection commands.
+const DETECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Where Linux exposes batteries and AC adapters.
+const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+
+/// Whether the machine is running on battery or a metered connection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PowerState {
+    pub on_battery: bool,
+    pub metered: bool,
+}
+
+impl PowerState {
+    /// Whether polling should be scaled back.
+    #[must_use]
+    pub const fn is_constrained(self) -> bool {
+        self.on_battery || self.metered
+    }
+
+    /// Short description, e.g. "on battery".
+    #[must_use]
+    pub const fn describe(self) -> &'static str {
+        match (self.on_battery, self.metered) {
+            (true, true) => "on battery and a metered connection",
+            (true, false) => "on battery",
+            (false, true) => "on a metered connection",
+            (false, false) => "on AC power and an unmetered connection",
+        }
+    }
+}
+
+/// Detect the current power and network state. Anything that cannot be
+/// detected counts as unconstrained.
+pub async fn detect() -> PowerState {
+    PowerState {
+        on_battery: on_battery().await,
+        metered: metered().await,
+    }
+}
+
+async fn on_battery() -> bool {
+    if cfg!(target_os = "macos") {
+        return run_command("pmset", &["-g", "batt"], DETECT_TIMEOUT)
+            .await
+            .is_ok_and(|out| out.success() && pmset_on_battery(&out.stdout));
+    }
+    if cfg!(target_os = "linux") {
+        return linux_on_battery(Path::new(POWER_SUPPLY_DIR));
+    }
+    false
+}
+
+async fn metered() -> bool {
+    if !cfg!(target_os = "linux") {
+        return false;
+    }
+    run_command(
+        "nmcli",
+        &["-t", "-f", "GENERAL.METERED", "device", "show"],
+        DETECT_TIMEOUT,
+    )
+    .await
+    .is_ok_and(|out| out.success() && nmcli_metered(&out.stdout))
+}
+
+/// `pmset -g batt` starts with "Now drawing from 'Battery Power'" on battery.
+fn pmset_on_battery(stdout: &str) -> bool {
+    stdout
+        .lines()
+        .next()
+        .is_some_and(|line| line.contains("'Battery Power'"))
+}
+
+/// On battery when a battery is discharging and no AC adapter is online.
+fn linux_on_battery(dir: &Path) -> bool {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return false;
+    };
+    let read = |path: &Path, name: &str| {
+        std::fs::read_to_string(path.join(name))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut discharging = false;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match read(&path, "type").as_str() {
+            "Mains" | "USB" if read(&path, "online") == "1" => return false,
+            "Battery" if read(&path, "status") == "Discharging" => discharging = true,
+            _ => {}
+        }
+    }
+    discharging
+}
+
+/// `nmcli -t -f GENERAL.METERED device show` prints one
+/// `GENERAL.METERED:<value>` line per device; values are `yes`, `no`,
+/// `unknown`, or either of the first two followed by ` (guessed)`.
+fn nmcli_metered(stdout: &str) -> bool {
+    stdout.lines().any(|line| {
+        line.strip_prefix("GENERAL.METERED:")
+            .is_some_and(|value| value.starts_with("yes"))
+    })
+}
+
+/// Tracks power state between refreshes of a long-running mode.
+#[derive(Debug)]
+pub struct PowerScheduler {
+    enabled: bool,
+    state: PowerState,
+}
+
+impl PowerScheduler {
+    /// A scheduler that does nothing unless `enabled` (`general.power_aware`).
+    #[must_use]
+    pub const fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            state: PowerState {
+                on_battery: false,
+                metered: false,
+            },
+        }
+    }
+
+    /// Re-detect the power state and apply it to source selection. Returns a
+    /// message to show when the state changed.
+    pub async fn update(&mut self) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+        let state = detect().await;
+        if state == self.state {
+            return None;
+        }
+        self.state = state;
+        fetch_plan::set_skip_web(state.is_constrained());
+        let message = if state.is_constrained() {
+            format!(
+                "Power: {}; polling {CONSTRAINED_INTERVAL_FACTOR}x less often and skipping web sources",
+                state.describe()
+            )
+        } else {
+            format!(
+                "Power: {}; polling at the normal interval",
+                state.describe()
+            )
+        };
+        tracing::info!("{message}");
+        Some(message)
+    }
+
+    /// The poll interval for the current state.
+    #[must_use]
+    pub fn interval(&self, base: Duration) -> Duration {
+        if self.state.is_constrained() {
+            base * CONSTRAINED_INTERVAL_FACTOR
+        } else {
+            base
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parses_pmset_and_nmcli_output() {
+        assert!(pmset_on_battery(
+            "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=1)\t82%; discharging;"
+        ));
+        assert!(!pmset_on_battery(
+            "Now drawing from 'AC Power'\n -InternalBattery-0 (id=1)\t100%; charged;"
+        ));
+
+        assert!(nmcli_metered(
+            "GENERAL.METERED:unknown\n\nGENERAL.METERED:yes (guessed)\n"
+        ));
+        assert!(!nmcli_metered(
+            "GENERAL.METERED:no\nGENERAL.METERED:unknown\n"
+        ));
+        assert!(!nmcli_metered(""));
+    }
+
+    #[test]
+    fn linux_battery_requires_no_online_adapter() {
+        let dir = TempDir::new().unwrap();
+        let supply = |name: &str, files: &[(&str, &str)]| {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(&path).unwrap();
+            for (file, value) in files {
+                std::fs::write(path.join(file), format!("{value}\n")).unwrap();
+            }
+        };
+
+        supply("BAT0", &[("type", "Battery"), ("status", "Discharging")]);
+        assert!(linux_on_battery(dir.path()));
+
+        supply("AC", &[("type", "Mains"), ("online", "1")]);
+        assert!(!linux_on_battery(dir.path()));
+
+        assert!(!linux_on_battery(&dir.path().join("missing")));
+    }
+}
@@ -1,36 +1,123 @@
 //! Prediction utilities for usage trends.
 //!
 //! Provides velocity calculations over recent history snapshots. Velocity is
-//! measured as percentage points per hour.
+//! measured as percentage points per hour, and
+//! [`time_to_exhaustion`] turns it into the moment a window would hit 100%.
 
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 
 use crate::storage::StoredSnapshot;
 
+/// Which rate window of a snapshot to read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageWindow {
+    Primary,
+    Secondary,
+    Tertiary,
+}
+
+impl UsageWindow {
+    /// All windows, in display order.
+    pub const ALL: [Self; 3] = [Self::Primary, Self::Secondary, Self::Tertiary];
+
+    /// Label used in output.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Primary => "session",
+            Self::Secondary => "weekly",
+            Self::Tertiary => "tertiary",
+        }
+    }
+
+    /// Percent used in this window.
+    #[must_use]
+    pub const fn used_pct(self, snapshot: &StoredSnapshot) -> Option<f64> {
+        match self {
+            Self::Primary => snapshot.primary_used_pct,
+            Self::Secondary => snapshot.secondary_used_pct,
+            Self::Tertiary => snapshot.tertiary_used_pct,
+        }
+    }
+
+    /// When this window resets.
+    #[must_use]
+    pub const fn resets_at(self, snapshot: &StoredSnapshot) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Primary => snapshot.primary_resets_at,
+            Self::Secondary => snapshot.secondary_resets_at,
+            Self::Tertiary => snapshot.tertiary_resets_at,
+        }
+    }
+
+    /// Length of this window in minutes.
+    #[must_use]
+    pub const fn window_minutes(self, snapshot: &StoredSnapshot) -> Option<i32> {
+        match self {
+            Self::Primary => snapshot.primary_window_minutes,
+            Self::Secondary => snapshot.secondary_window_minutes,
+            Self::Tertiary => snapshot.tertiary_window_minutes,
+        }
+    }
+}
+
 /// Calculate usage velocity over a time window.
 ///
 /// Returns percent-per-hour (can be negative). Returns None when there is
 /// insufficient data or the window is invalid.
 #[must_use]
 pub fn calculate_velocity(history: &[StoredSnapshot], window: Duration) -> Option<f64> {
+    window_velocity(history, window, UsageWindow::Primary)
+}
+
+/// Calculate the velocity of one rate window over a time window.
+///
+/// Like [`calculate_velocity`], but for any of a snapshot's windows.
+#[must_use]
+pub fn window_velocity(
+    history: &[StoredSnapshot],
+    window: Duration,
+    kind: UsageWindow,
+) -> Option<f64> {
     if history.len() < 2 || window <= Duration::zero() {
         return None;
     }
 
-    let recent = recent_points(history, window);
+    let recent = recent_points(history, window, kind);
     if recent.len() < 2 {
         return None;
     }
 
-    let segment = strip_resets(&recent);
+    let segment = strip_resets(&recent, kind);
     if segment.len() < 2 {
         return None;
     }
 
-    let slope_per_second = linear_regression_slope(&segment)?;
+    let slope_per_second = linear_regression_slope(&segment, kind)?;
     Some(slope_per_second * 3600.0)
 }
 
+/// When a window at `used_pct` reaches 100% at `velocity` percent per hour.
+///
+/// Returns `now` for a window that is already full, and None when usage is
+/// not rising.
+#[must_use]
+pub fn time_to_exhaustion(
+    used_pct: f64,
+    velocity: f64,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    if used_pct >= 100.0 {
+        return Some(now);
+    }
+    if !velocity.is_finite() || velocity <= 0.0 {
+        return None;
+    }
+    #[allow(clippy::cast_possible_truncation)] // bounded by 100% at a positive rate
+    let seconds = ((100.0 - used_pct) / velocity * 3600.0).min(f64::from(i32::MAX)) as i64;
+    Some(now + Duration::seconds(seconds))
+}
+
 /// Compute a smoothed velocity using an exponential moving average.
 ///
 /// `alpha` is the smoothing factor (0.0 < alpha <= 1.0).
@@ -40,12 +127,12 @@ pub fn smoothed_velocity(history: &[StoredSnapshot], window: Duration, alpha: f6
         return None;
     }
 
-    let recent = recent_points(history, window);
+    let recent = recent_points(history, window, UsageWindow::Primary);
     if recent.len() < 2 {
         return None;
     }
 
-    let segment = strip_resets(&recent);
+    let segment = strip_resets(&recent, UsageWindow::Primary);
     if segment.len() < 2 {
         return None;
     }
@@ -66,27 +153,38 @@ pub fn smoothed_velocity(history: &[StoredSnapshot], window: Duration, alpha: f6
 /// Detect a likely usage reset between two snapshots.
 #[must_use]
 pub fn detect_reset(prev: &StoredSnapshot, curr: &StoredSnapshot) -> bool {
-    let prev_pct = prev.primary_used_pct.unwrap_or(0.0);
-    let curr_pct = curr.primary_used_pct.unwrap_or(0.0);
+    reset_between(prev, curr, UsageWindow::Primary)
+}
+
+fn reset_between(prev: &StoredSnapshot, curr: &StoredSnapshot, kind: UsageWindow) -> bool {
+    let prev_pct = kind.used_pct(prev).unwrap_or(0.0);
+    let curr_pct = kind.used_pct(curr).unwrap_or(0.0);
 
     prev_pct > 50.0 && curr_pct < 10.0 && (prev_pct - curr_pct) > 40.0
 }
 
-fn recent_points(history: &[StoredSnapshot], window: Duration) -> Vec<&StoredSnapshot> {
+fn recent_points(
+    history: &[StoredSnapshot],
+    window: Duration,
+    kind: UsageWindow,
+) -> Vec<&StoredSnapshot> {
     let cutoff = Utc::now() - window;
     let mut points: Vec<&StoredSnapshot> = history
         .iter()
-        .filter(|s| s.fetched_at >= cutoff && s.primary_used_pct.is_some())
+        .filter(|s| s.fetched_at >= cutoff && kind.used_pct(s).is_some())
         .collect();
     points.sort_by_key(|a| a.fetched_at);
     points
 }
 
-fn strip_resets<'a>(points: &'a [&'a StoredSnapshot]) -> Vec<&'a StoredSnapshot> {
+fn strip_resets<'a>(
+    points: &'a [&'a StoredSnapshot],
+    kind: UsageWindow,
+) -> Vec<&'a StoredSnapshot> {
     let mut segment: Vec<&StoredSnapshot> = Vec::new();
     for point in points {
         if let Some(prev) = segment.last().copied()
-            && detect_reset(prev, point)
+            && reset_between(prev, point, kind)
         {
             segment.clear();
         }
@@ -96,7 +194,7 @@ fn strip_resets<'a>(points: &'a [&'a StoredSnapshot]) -> Vec<&'a StoredSnapshot>
 }
 
 #[allow(clippy::similar_names)]
-fn linear_regression_slope(points: &[&StoredSnapshot]) -> Option<f64> {
+fn linear_regression_slope(points: &[&StoredSnapshot], kind: UsageWindow) -> Option<f64> {
     #[allow(clippy::cast_precision_loss)] // point count will never exceed f64 precision
     let n = points.len() as f64;
     if n < 2.0 {
@@ -114,7 +212,7 @@ fn linear_regression_slope(points: &[&StoredSnapshot]) -> Option<f64> {
     for point in points {
         #[allow(clippy::cast_precision_loss)] // timestamp fits within f64 precision for current era
         let x = point.fetched_at.timestamp() as f64 - base_time;
-        let y = point.primary_used_pct?;
+        let y = kind.used_pct(point)?;
 
         sum_x += x;
         sum_y += y;
@@ -192,6 +290,7 @@ mod tests {
             account_org: None,
             fetch_duration_ms: None,
             created_at: None,
+            note: None,
         }
     }
 
@@ -246,6 +345,25 @@ mod tests {
         assert!(detect_reset(&prev, &curr));
     }
 
+    #[test]
+    fn window_velocity_reads_the_requested_window() {
+        let now = Utc::now();
+        let mut earlier = make_snapshot_at(now - Duration::hours(2), 10.0);
+        earlier.secondary_used_pct = Some(40.0);
+        let mut later = make_snapshot_at(now, 10.0);
+        later.secondary_used_pct = Some(50.0);
+        let history = vec![earlier, later];
+
+        let weekly = window_velocity(&history, Duration::hours(4), UsageWindow::Secondary);
+        assert_float_eq!(weekly.unwrap(), 5.0, 0.01);
+        assert!(window_velocity(&history, Duration::hours(4), UsageWindow::Tertiary).is_none());
+
+        let full_at = time_to_exhaustion(50.0, 5.0, now).unwrap();
+        assert_eq!(full_at - now, Duration::hours(10));
+        assert!(time_to_exhaustion(50.0, 0.0, now).is_none());
+        assert_eq!(time_to_exhaustion(100.0, 0.0, now), Some(now));
+    }
+
     #[test]
     fn smoothed_velocity_returns_none_for_invalid_alpha() {
         let now = Utc::now();
@@ -228,6 +228,16 @@ impl PricingTable {
         Self::add_model(&mut models, "o3-mini-2025-01-31", 1.1, 4.4, 0.55, 1.1);
 
         // Google Gemini models
+        Self::add_model(&mut models, "gemini-2.5-pro", 1.25, 10.0, 0.31, 1.25);
+        Self::add_model(&mut models, "gemini-2.5-flash", 0.30, 2.50, 0.075, 0.30);
+        Self::add_model(
+            &mut models,
+            "gemini-2.5-flash-lite",
+            0.10,
+            0.40,
+            0.025,
+            0.10,
+        );
         Self::add_model(&mut models, "gemini-2.0-flash", 0.10, 0.40, 0.025, 0.10);
         Self::add_model(&mut models, "gemini-1.5-pro", 1.25, 5.0, 0.3125, 1.25);
         Self::add_model(&mut models, "gemini-1.5-flash", 0.075, 0.30, 0.01875, 0.075);
@@ -260,6 +270,20 @@ impl PricingTable {
         self.models.get(&model.to_lowercase())
     }
 
+    /// Look up pricing by exact name, or by the shortest known model name
+    /// that starts with `name` (so `claude-sonnet` finds `claude-sonnet-4`).
+    #[must_use]
+    pub fn resolve(&self, name: &str) -> Option<&ModelPricing> {
+        let name = name.to_lowercase();
+        self.models.get(&name).or_else(|| {
+            self.models
+                .iter()
+                .filter(|(model, _)| model.starts_with(&name))
+                .min_by(|a, b| a.0.len().cmp(&b.0.len()).then_with(|| a.0.cmp(b.0)))
+                .map(|(_, pricing)| pricing)
+        })
+    }
+
     /// Get pricing for a model, falling back to a conservative estimate.
     ///
     /// If the model is unknown, returns mid-tier pricing as a fallback.
@@ -374,8 +398,9 @@ impl SessionCostCalculator {
         // Determine confidence level
         let confidence = self.calculate_confidence(usage, model_known);
 
+        // Prefer the agent's own figure when it logs one (Aider does)
         SessionCost {
-            total_usd: breakdown.total_cost_usd,
+            total_usd: usage.reported_cost_usd.unwrap_or(breakdown.total_cost_usd),
             breakdown,
             model: primary_model,
             confidence,
@@ -460,6 +485,7 @@ mod tests {
         SessionUsage {
             session_id: "test".to_string(),
             project_path: None,
+            cwd: None,
             started_at: Some(Utc::now()),
             ended_at: Some(Utc::now()),
             input_tokens: input,
@@ -468,6 +494,7 @@ mod tests {
             cache_creation_tokens: 0,
             models_used: models.iter().map(|s| (*s).to_string()).collect(),
             message_count: 1,
+            reported_cost_usd: None,
         }
     }
 
@@ -494,6 +521,17 @@ mod tests {
         assert!(table.get("GPT-4O").is_some());
     }
 
+    #[test]
+    fn resolve_accepts_model_prefixes() {
+        let table = PricingTable::current();
+        assert_eq!(
+            table.resolve("claude-sonnet").unwrap().model,
+            "claude-sonnet-4"
+        );
+        assert_eq!(table.resolve("GPT-4o").unwrap().model, "gpt-4o");
+        assert!(table.resolve("llama").is_none());
+    }
+
     #[test]
     fn unknown_model_returns_estimate() {
         let table = PricingTable::current();
@@ -583,6 +621,7 @@ mod tests {
         let usage = SessionUsage {
             session_id: "test".to_string(),
             project_path: None,
+            cwd: None,
             started_at: Some(Utc::now()),
             ended_at: Some(Utc::now()),
             input_tokens: 100_000,
@@ -591,6 +630,7 @@ mod tests {
             cache_creation_tokens: 0,
             models_used: HashSet::new(),
             message_count: 1,
+            reported_cost_usd: None,
         };
         let cost = calc.calculate(&usage);
 
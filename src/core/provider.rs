@@ -5,8 +5,12 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+#[cfg(feature = "native")]
+use std::sync::Arc;
 use std::time::Duration;
 
+#[cfg(feature = "native")]
+use super::fetcher::ProviderFetcher;
 use crate::error::{CautError, Result};
 
 // =============================================================================
@@ -33,6 +37,26 @@ pub enum Provider {
     VertexAI,
     JetBrainsAI,
     Amp,
+    Perplexity,
+    Groq,
+    Together,
+    Cohere,
+    Fireworks,
+    Windsurf,
+    Aider,
+    Cline,
+    OpenRouter,
+    AmazonQ,
+    Cody,
+    DeepSeek,
+    Xai,
+    Continue,
+    Ollama,
+    Qwen,
+    Goose,
+    AzureOpenAI,
+    Bedrock,
+    LiteLLM,
 }
 
 impl Provider {
@@ -54,6 +78,26 @@ impl Provider {
         Self::VertexAI,
         Self::JetBrainsAI,
         Self::Amp,
+        Self::Perplexity,
+        Self::Groq,
+        Self::Together,
+        Self::Cohere,
+        Self::Fireworks,
+        Self::Windsurf,
+        Self::Aider,
+        Self::Cline,
+        Self::OpenRouter,
+        Self::AmazonQ,
+        Self::Cody,
+        Self::DeepSeek,
+        Self::Xai,
+        Self::Continue,
+        Self::Ollama,
+        Self::Qwen,
+        Self::Goose,
+        Self::AzureOpenAI,
+        Self::Bedrock,
+        Self::LiteLLM,
     ];
 
     /// Primary providers (Codex + Claude).
@@ -79,6 +123,26 @@ impl Provider {
             Self::VertexAI => "vertexai",
             Self::JetBrainsAI => "jetbrains",
             Self::Amp => "amp",
+            Self::Perplexity => "perplexity",
+            Self::Groq => "groq",
+            Self::Together => "together",
+            Self::Cohere => "cohere",
+            Self::Fireworks => "fireworks",
+            Self::Windsurf => "windsurf",
+            Self::Aider => "aider",
+            Self::Cline => "cline",
+            Self::OpenRouter => "openrouter",
+            Self::AmazonQ => "amazonq",
+            Self::Cody => "cody",
+            Self::DeepSeek => "deepseek",
+            Self::Xai => "xai",
+            Self::Continue => "continue",
+            Self::Ollama => "ollama",
+            Self::Qwen => "qwen",
+            Self::Goose => "goose",
+            Self::AzureOpenAI => "azure-openai",
+            Self::Bedrock => "bedrock",
+            Self::LiteLLM => "litellm",
         }
     }
 
@@ -102,9 +166,35 @@ impl Provider {
             Self::VertexAI => "Vertex AI",
             Self::JetBrainsAI => "JetBrains AI",
             Self::Amp => "Amp",
+            Self::Perplexity => "Perplexity",
+            Self::Groq => "Groq",
+            Self::Together => "Together AI",
+            Self::Cohere => "Cohere",
+            Self::Fireworks => "Fireworks",
+            Self::Windsurf => "Windsurf",
+            Self::Aider => "Aider",
+            Self::Cline => "Cline",
+            Self::OpenRouter => "OpenRouter",
+            Self::AmazonQ => "Amazon Q Developer",
+            Self::Cody => "Sourcegraph Cody",
+            Self::DeepSeek => "DeepSeek",
+            Self::Xai => "xAI Grok",
+            Self::Continue => "Continue",
+            Self::Ollama => "Ollama",
+            Self::Qwen => "Qwen Code",
+            Self::Goose => "Goose",
+            Self::AzureOpenAI => "Azure OpenAI",
+            Self::Bedrock => "AWS Bedrock",
+            Self::LiteLLM => "LiteLLM",
         }
     }
 
+    /// Web page where the account's usage and limits can be reviewed.
+    #[must_use]
+    pub fn dashboard_url(self) -> Option<&'static str> {
+        ProviderRegistry::new().get(self)?.metadata.dashboard_url
+    }
+
     /// Parse from CLI argument.
     ///
     /// # Errors
@@ -127,7 +217,10 @@ impl Provider {
     /// Whether this provider supports credits.
     #[must_use]
     pub const fn supports_credits(self) -> bool {
-        matches!(self, Self::Codex)
+        matches!(
+            self,
+            Self::Codex | Self::Perplexity | Self::Windsurf | Self::OpenRouter | Self::DeepSeek
+        )
     }
 
     /// Whether this provider supports token accounts.
@@ -147,7 +240,25 @@ impl Provider {
     /// Whether this provider supports local cost scanning.
     #[must_use]
     pub const fn supports_cost_scan(self) -> bool {
-        matches!(self, Self::Codex | Self::Claude)
+        matches!(
+            self,
+            Self::Codex
+                | Self::Claude
+                | Self::Gemini
+                | Self::Aider
+                | Self::Cline
+                | Self::Continue
+                | Self::Goose
+                | Self::Bedrock
+                | Self::LiteLLM
+                | Self::Ollama
+        )
+    }
+
+    /// Whether per-session token usage can be read from local session logs.
+    #[must_use]
+    pub const fn supports_session_logs(self) -> bool {
+        matches!(self, Self::Codex | Self::Claude | Self::Aider)
     }
 
     /// Default timeout for provider fetch operations.
@@ -202,6 +313,26 @@ impl Provider {
             Self::Antigravity => 14,
             Self::OpenCode => 15,
             Self::Factory => 16,
+            Self::Perplexity => 17,
+            Self::Groq => 18,
+            Self::Together => 19,
+            Self::Cohere => 20,
+            Self::Fireworks => 21,
+            Self::Windsurf => 22,
+            Self::Aider => 23,
+            Self::Cline => 24,
+            Self::OpenRouter => 25,
+            Self::AmazonQ => 26,
+            Self::Cody => 27,
+            Self::DeepSeek => 28,
+            Self::Xai => 29,
+            Self::Continue => 30,
+            Self::Ollama => 31,
+            Self::Qwen => 32,
+            Self::Goose => 33,
+            Self::AzureOpenAI => 34,
+            Self::Bedrock => 35,
+            Self::LiteLLM => 36,
         }
     }
 
@@ -214,10 +345,32 @@ impl Provider {
             Self::Gemini | Self::VertexAI => Some("https://status.cloud.google.com"),
             Self::Cursor => Some("https://status.cursor.com"),
             Self::Copilot => Some("https://www.githubstatus.com"),
+            Self::Perplexity => Some("https://status.perplexity.com"),
+            Self::Groq => Some("https://groqstatus.com"),
+            Self::Together => Some("https://status.together.ai"),
+            Self::Cohere => Some("https://status.cohere.com"),
+            Self::Fireworks => Some("https://status.fireworks.ai"),
+            Self::Windsurf => Some("https://status.codeium.com"),
+            Self::OpenRouter => Some("https://status.openrouter.ai"),
+            Self::AmazonQ => Some("https://health.aws.amazon.com/health/status"),
+            Self::Cody => Some("https://sourcegraphstatus.com"),
+            Self::DeepSeek => Some("https://status.deepseek.com"),
+            Self::Xai => Some("https://status.x.ai"),
             _ => None,
         }
     }
 
+    /// Name of the executable the doctor looks for on `PATH`.
+    #[must_use]
+    pub const fn executable(self) -> &'static str {
+        match self {
+            Self::AmazonQ => "q",
+            Self::AzureOpenAI => "az",
+            Self::Bedrock => "aws",
+            _ => self.cli_name(),
+        }
+    }
+
     /// Get installation suggestion for this provider's CLI.
     #[must_use]
     pub const fn install_suggestion(self) -> &'static str {
@@ -229,6 +382,22 @@ impl Provider {
             Self::Copilot => "Install GitHub Copilot extension in your editor",
             Self::VertexAI => "Install with: gcloud components install vertex-ai",
             Self::JetBrainsAI => "Enable JetBrains AI Assistant in your IDE",
+            Self::Windsurf => "Install Windsurf from: https://windsurf.com/download",
+            Self::Aider => "Install with: python -m pip install aider-install && aider-install",
+            Self::Cline => "Install the Cline or Roo Code extension in VS Code",
+            Self::Continue => "Install the Continue extension in VS Code or JetBrains",
+            Self::Ollama => "Install Ollama from: https://ollama.com/download",
+            Self::Qwen => "Install with: npm install -g @qwen-code/qwen-code",
+            Self::Goose => {
+                "Install Goose from: https://block.github.io/goose/docs/getting-started/installation"
+            }
+            Self::AmazonQ => {
+                "Install the Amazon Q Developer CLI from: https://aws.amazon.com/q/developer/"
+            }
+            Self::AzureOpenAI => {
+                "Install the Azure CLI from: https://learn.microsoft.com/cli/azure/install-azure-cli"
+            }
+            Self::Bedrock => "Install the AWS CLI from: https://aws.amazon.com/cli/",
             _ => "Check provider documentation for installation instructions",
         }
     }
@@ -238,12 +407,36 @@ impl Provider {
     pub const fn auth_suggestion(self) -> &'static str {
         match self {
             Self::Codex => "Run: codex auth login",
-            Self::Claude => "Run: claude auth login",
+            Self::Claude => "Run: claude auth login (or caut login claude)",
             Self::Gemini => "Run: gemini auth login",
             Self::Cursor => "Open Cursor and sign in",
             Self::Copilot => "Sign in with your GitHub account",
             Self::VertexAI => "Run: gcloud auth application-default login",
             Self::JetBrainsAI => "Configure in IDE Settings > AI Assistant",
+            Self::Perplexity => "Set PERPLEXITY_API_KEY to an API key",
+            Self::Groq => "Set GROQ_API_KEY to an API key",
+            Self::Together => "Set TOGETHER_API_KEY to an API key",
+            Self::Cohere => "Set COHERE_API_KEY to an API key",
+            Self::Fireworks => "Set FIREWORKS_API_KEY to an API key",
+            Self::Windsurf => "Open Windsurf and sign in",
+            Self::Aider => "Aider needs no sign-in; caut reads its chat history files",
+            Self::Cline => {
+                "Cline needs no sign-in; caut reads its task history from editor storage"
+            }
+            Self::OpenRouter => "Set OPENROUTER_API_KEY to an API key",
+            Self::AmazonQ => "Run: q login",
+            Self::Cody => "Set SRC_ACCESS_TOKEN or run: src login",
+            Self::DeepSeek => "Set DEEPSEEK_API_KEY to an API key",
+            Self::Xai => "Set XAI_API_KEY to an API key",
+            Self::Continue => "Continue needs no sign-in; caut reads its local dev data",
+            Self::Ollama => "Start the Ollama server: ollama serve",
+            Self::Qwen => "Run: qwen, then /auth to sign in with Qwen OAuth",
+            Self::Goose => "Run: goose configure",
+            Self::AzureOpenAI => {
+                "Run: az login (or set AZURE_TENANT_ID, AZURE_CLIENT_ID, AZURE_CLIENT_SECRET)"
+            }
+            Self::Bedrock => "Run: aws configure (or aws sso login)",
+            Self::LiteLLM => "Set LITELLM_PROXY_URL and LITELLM_PROXY_API_KEY",
             _ => "Check provider documentation for authentication",
         }
     }
@@ -356,9 +549,13 @@ pub struct ProviderDescriptor {
 // Provider Registry
 // =============================================================================
 
-/// Registry of all provider descriptors.
+/// Registry of all provider descriptors and their fetchers.
+///
+/// Without the `native` feature only descriptors are available.
 pub struct ProviderRegistry {
     descriptors: HashMap<Provider, ProviderDescriptor>,
+    #[cfg(feature = "native")]
+    fetchers: HashMap<Provider, Arc<dyn ProviderFetcher>>,
 }
 
 impl ProviderRegistry {
@@ -380,7 +577,7 @@ impl ProviderRegistry {
                     opus_label: None,
                     supports_credits: true,
                     status_page_url: Some("https://status.openai.com"),
-                    dashboard_url: Some("https://platform.openai.com/usage"),
+                    dashboard_url: Some("https://chatgpt.com/codex/settings/usage"),
                 },
                 branding: ProviderBranding {
                     primary_color: "#10A37F",
@@ -448,6 +645,26 @@ impl ProviderRegistry {
             Provider::VertexAI,
             Provider::JetBrainsAI,
             Provider::Amp,
+            Provider::Perplexity,
+            Provider::Groq,
+            Provider::Together,
+            Provider::Cohere,
+            Provider::Fireworks,
+            Provider::Windsurf,
+            Provider::Aider,
+            Provider::Cline,
+            Provider::OpenRouter,
+            Provider::AmazonQ,
+            Provider::Cody,
+            Provider::DeepSeek,
+            Provider::Xai,
+            Provider::Continue,
+            Provider::Ollama,
+            Provider::Qwen,
+            Provider::Goose,
+            Provider::AzureOpenAI,
+            Provider::Bedrock,
+            Provider::LiteLLM,
         ] {
             descriptors.insert(
                 provider,
@@ -459,9 +676,9 @@ impl ProviderRegistry {
                         weekly_label: "Weekly",
                         supports_opus: false,
                         opus_label: None,
-                        supports_credits: false,
+                        supports_credits: provider.supports_credits(),
                         status_page_url: None,
-                        dashboard_url: None,
+                        dashboard_url: default_dashboard_url(provider),
                     },
                     branding: ProviderBranding {
                         primary_color: "#888888",
@@ -471,7 +688,14 @@ impl ProviderRegistry {
             );
         }
 
-        Self { descriptors }
+        Self {
+            descriptors,
+            #[cfg(feature = "native")]
+            fetchers: crate::providers::builtin_fetchers()
+                .into_iter()
+                .map(|fetcher| (fetcher.provider(), fetcher))
+                .collect(),
+        }
     }
 
     /// Get descriptor for a provider.
@@ -484,6 +708,25 @@ impl ProviderRegistry {
     pub fn iter(&self) -> impl Iterator<Item = &ProviderDescriptor> {
         self.descriptors.values()
     }
+
+    /// Get the fetcher for a provider, if it is implemented.
+    #[cfg(feature = "native")]
+    #[must_use]
+    pub fn fetcher(&self, provider: Provider) -> Option<Arc<dyn ProviderFetcher>> {
+        self.fetchers.get(&provider).cloned()
+    }
+
+    /// Iterate registered fetchers.
+    #[cfg(feature = "native")]
+    pub fn fetchers(&self) -> impl Iterator<Item = &Arc<dyn ProviderFetcher>> {
+        self.fetchers.values()
+    }
+
+    /// Register a fetcher, replacing any existing one for its provider.
+    #[cfg(feature = "native")]
+    pub fn register(&mut self, fetcher: Arc<dyn ProviderFetcher>) {
+        self.fetchers.insert(fetcher.provider(), fetcher);
+    }
 }
 
 impl Default for ProviderRegistry {
@@ -492,6 +735,30 @@ impl Default for ProviderRegistry {
     }
 }
 
+/// Usage dashboard for providers registered with default metadata.
+const fn default_dashboard_url(provider: Provider) -> Option<&'static str> {
+    match provider {
+        Provider::Cursor => Some("https://cursor.com/dashboard"),
+        Provider::Copilot => Some("https://github.com/settings/copilot"),
+        Provider::Amp => Some("https://ampcode.com/settings"),
+        Provider::Perplexity => Some("https://www.perplexity.ai/account/api/billing"),
+        Provider::Groq => Some("https://console.groq.com/settings/limits"),
+        Provider::Together => Some("https://api.together.ai/settings/billing"),
+        Provider::Cohere => Some("https://dashboard.cohere.com/billing"),
+        Provider::Fireworks => Some("https://fireworks.ai/account/usage"),
+        Provider::Windsurf => Some("https://windsurf.com/subscription/usage"),
+        Provider::OpenRouter => Some("https://openrouter.ai/settings/credits"),
+        Provider::Cody => Some("https://sourcegraph.com/cody/manage"),
+        Provider::DeepSeek => Some("https://platform.deepseek.com/usage"),
+        Provider::Xai => Some("https://console.x.ai"),
+        Provider::Continue => Some("https://hub.continue.dev"),
+        Provider::Qwen => Some("https://chat.qwen.ai"),
+        Provider::AzureOpenAI => Some("https://ai.azure.com/"),
+        Provider::Bedrock => Some("https://console.aws.amazon.com/bedrock/"),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -524,6 +791,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn registry_has_fetchers_for_implemented_providers() {
+        let registry = ProviderRegistry::new();
+        for provider in [Provider::Claude, Provider::Codex, Provider::Copilot] {
+            let fetcher = registry.fetcher(provider).unwrap();
+            assert_eq!(fetcher.provider(), provider);
+            assert_eq!(fetcher.fetch_plan().provider, provider);
+        }
+        assert!(registry.fetcher(Provider::Kiro).is_none());
+    }
+
+    #[test]
+    fn dashboard_urls_cover_main_providers() {
+        assert!(Provider::Claude.dashboard_url().is_some());
+        assert!(Provider::Codex.dashboard_url().is_some());
+        assert_eq!(
+            Provider::Cursor.dashboard_url(),
+            Some("https://cursor.com/dashboard")
+        );
+        assert_eq!(Provider::Kiro.dashboard_url(), None);
+    }
+
     #[test]
     fn provider_default_timeout_values() {
         assert_eq!(Provider::Claude.default_timeout().as_secs(), 30);
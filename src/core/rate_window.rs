@@ -0,0 +1,460 @@
+//! Rate-window normalization.
+//!
+//! Providers report their limits in different shapes: percent used or
+//! percent remaining, a fraction, or counts of requests, tokens, or dollars
+//! against a limit. Resets come as RFC 3339 timestamps, epoch seconds or
+//! milliseconds, or seconds from now, and window lengths as minutes,
+//! seconds, or named periods. [`RawWindow`] describes what a provider sent
+//! and [`RawWindow::normalize`] turns it into the one form renderers and
+//! history assume:
+//!
+//! - `used_percent` is finite and within 0 to 100; overage shows as full.
+//! - `resets_at` is an absolute UTC time.
+//! - `window_minutes` is positive when present.
+//!
+//! [`normalize_snapshot`] holds every fetched snapshot to the same rules and
+//! applies per-provider [`Quirks`], such as window lengths a source leaves
+//! out.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::core::models::{RateWindow, UsageSnapshot};
+use crate::core::provider::Provider;
+
+/// Epoch values above this are milliseconds; as seconds they would be
+/// thousands of years away.
+const EPOCH_MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+/// How a provider reports consumption of one window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RawUsage {
+    /// Percent of the window used, 0 to 100.
+    UsedPercent(f64),
+    /// Percent of the window left, 0 to 100.
+    RemainingPercent(f64),
+    /// Share of the window used, 0.0 to 1.0.
+    UsedFraction(f64),
+    /// Share of the window left, 0.0 to 1.0.
+    RemainingFraction(f64),
+    /// Amount used out of a limit, in any unit.
+    Used { used: f64, limit: f64 },
+    /// Amount left out of a limit, in any unit.
+    Remaining { remaining: f64, limit: f64 },
+}
+
+impl RawUsage {
+    /// Percent used, clamped to 0 to 100. `None` for values that are not
+    /// numbers; a limit of zero or less counts as nothing used.
+    #[must_use]
+    pub fn used_percent(self) -> Option<f64> {
+        let percent = match self {
+            Self::UsedPercent(p) => p,
+            Self::RemainingPercent(p) => 100.0 - p,
+            Self::UsedFraction(f) => f * 100.0,
+            Self::RemainingFraction(f) => (1.0 - f) * 100.0,
+            Self::Used { used, limit } => ratio_percent(used, limit)?,
+            Self::Remaining { remaining, limit } => ratio_percent(limit - remaining, limit)?,
+        };
+        percent.is_finite().then(|| percent.clamp(0.0, 100.0))
+    }
+}
+
+fn ratio_percent(used: f64, limit: f64) -> Option<f64> {
+    if !used.is_finite() || !limit.is_finite() {
+        return None;
+    }
+    Some(if limit > 0.0 {
+        used / limit * 100.0
+    } else {
+        0.0
+    })
+}
+
+/// How a provider reports when a window resets.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RawReset {
+    /// Already an absolute time.
+    At(DateTime<Utc>),
+    /// RFC 3339, or epoch seconds or milliseconds written as a string.
+    Timestamp(String),
+    /// Epoch seconds, or milliseconds when too large to be seconds.
+    Epoch(i64),
+    /// Seconds from the time of the fetch.
+    AfterSeconds(f64),
+}
+
+impl RawReset {
+    /// The reset as an absolute time, given when the data was fetched.
+    #[must_use]
+    pub fn resolve(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::At(at) => Some(*at),
+            Self::Timestamp(value) => {
+                let value = value.trim();
+                DateTime::parse_from_rfc3339(value)
+                    .map(|at| at.with_timezone(&Utc))
+                    .ok()
+                    .or_else(|| value.parse().ok().and_then(epoch))
+            }
+            Self::Epoch(value) => epoch(*value),
+            Self::AfterSeconds(secs) => {
+                #[allow(clippy::cast_possible_truncation)] // guarded by the range check
+                let millis = (secs * 1000.0).round() as i64;
+                (secs.is_finite() && *secs >= 0.0 && *secs < 1e12)
+                    .then(|| now + Duration::milliseconds(millis))
+            }
+        }
+    }
+}
+
+fn epoch(value: i64) -> Option<DateTime<Utc>> {
+    if value <= 0 {
+        return None;
+    }
+    if value > EPOCH_MILLIS_THRESHOLD {
+        Utc.timestamp_millis_opt(value).single()
+    } else {
+        Utc.timestamp_opt(value, 0).single()
+    }
+}
+
+/// How a provider reports the length of a window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RawLength {
+    Minutes(i64),
+    Seconds(i64),
+    /// A period name: "hourly", "daily", "weekly", or "monthly" (30 days).
+    Named(String),
+}
+
+impl RawLength {
+    /// Length in whole minutes; `None` when unknown or not positive.
+    #[must_use]
+    pub fn minutes(&self) -> Option<i32> {
+        let minutes = match self {
+            Self::Minutes(m) => *m,
+            Self::Seconds(s) => *s / 60,
+            Self::Named(name) => match name.trim().to_ascii_lowercase().as_str() {
+                "hour" | "hourly" => 60,
+                "day" | "daily" => 24 * 60,
+                "week" | "weekly" => 7 * 24 * 60,
+                "month" | "monthly" => 30 * 24 * 60,
+                _ => return None,
+            },
+        };
+        i32::try_from(minutes).ok().filter(|m| *m > 0)
+    }
+}
+
+/// One window as a provider reported it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawWindow {
+    pub usage: RawUsage,
+    pub length: Option<RawLength>,
+    pub reset: Option<RawReset>,
+    pub description: Option<String>,
+}
+
+impl RawWindow {
+    /// A window with only its usage known.
+    #[must_use]
+    pub const fn new(usage: RawUsage) -> Self {
+        Self {
+            usage,
+            length: None,
+            reset: None,
+            description: None,
+        }
+    }
+
+    /// Set the window length.
+    #[must_use]
+    pub fn with_length(mut self, length: RawLength) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Set when the window resets.
+    #[must_use]
+    pub fn with_reset(mut self, reset: RawReset) -> Self {
+        self.reset = Some(reset);
+        self
+    }
+
+    /// Set the description shown with the window.
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The canonical window, or `None` when the usage is not a number.
+    /// An unreadable reset or length is dropped rather than failing the
+    /// window.
+    #[must_use]
+    pub fn normalize(&self, now: DateTime<Utc>) -> Option<RateWindow> {
+        Some(RateWindow {
+            used_percent: self.usage.used_percent()?,
+            window_minutes: self.length.as_ref().and_then(RawLength::minutes),
+            resets_at: self.reset.as_ref().and_then(|reset| reset.resolve(now)),
+            reset_description: self.description.clone(),
+        })
+    }
+}
+
+/// What caut knows about a provider's windows beyond what it reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Quirks {
+    /// Lengths of the primary, secondary, and tertiary windows, filled in
+    /// when a source leaves them out.
+    pub window_minutes: [Option<i32>; 3],
+}
+
+/// Known quirks of `provider`.
+#[must_use]
+pub const fn quirks(provider: Provider) -> Quirks {
+    const FIVE_HOURS: Option<i32> = Some(5 * 60);
+    const SEVEN_DAYS: Option<i32> = Some(7 * 24 * 60);
+    match provider {
+        // Session, weekly, and weekly per-model windows.
+        Provider::Claude => Quirks {
+            window_minutes: [FIVE_HOURS, SEVEN_DAYS, SEVEN_DAYS],
+        },
+        // The rate-limit endpoint gives the weekly length but not the
+        // session one.
+        Provider::Codex => Quirks {
+            window_minutes: [FIVE_HOURS, SEVEN_DAYS, None],
+        },
+        _ => Quirks {
+            window_minutes: [None; 3],
+        },
+    }
+}
+
+/// Hold a fetched snapshot to the canonical form: windows whose usage is
+/// not a number are dropped, usage is clamped to 0 to 100, lengths that are
+/// not positive are cleared, and missing lengths come from [`quirks`].
+#[must_use]
+pub fn normalize_snapshot(provider: Provider, mut snapshot: UsageSnapshot) -> UsageSnapshot {
+    let quirks = quirks(provider);
+    for (window, known_minutes) in [
+        &mut snapshot.primary,
+        &mut snapshot.secondary,
+        &mut snapshot.tertiary,
+    ]
+    .into_iter()
+    .zip(quirks.window_minutes)
+    {
+        *window = window
+            .take()
+            .and_then(|window| normalize_window(window, known_minutes));
+    }
+    snapshot
+}
+
+fn normalize_window(mut window: RateWindow, known_minutes: Option<i32>) -> Option<RateWindow> {
+    window.used_percent = RawUsage::UsedPercent(window.used_percent).used_percent()?;
+    window.window_minutes = window.window_minutes.filter(|m| *m > 0).or(known_minutes);
+    Some(window)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 4, 12, 0, 0).unwrap()
+    }
+
+    fn assert_percent(usage: RawUsage, expected: f64) {
+        let actual = usage.used_percent().expect("a percentage");
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{usage:?}: expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn every_usage_shape_becomes_percent_used() {
+        assert_percent(RawUsage::UsedPercent(42.0), 42.0);
+        assert_percent(RawUsage::RemainingPercent(58.0), 42.0);
+        assert_percent(RawUsage::UsedFraction(0.42), 42.0);
+        assert_percent(RawUsage::RemainingFraction(0.58), 42.0);
+        assert_percent(
+            RawUsage::Used {
+                used: 42.0,
+                limit: 100.0,
+            },
+            42.0,
+        );
+        assert_percent(
+            RawUsage::Remaining {
+                remaining: 5_800.0,
+                limit: 10_000.0,
+            },
+            42.0,
+        );
+    }
+
+    #[test]
+    fn usage_is_clamped_and_rejects_non_numbers() {
+        assert_percent(RawUsage::UsedPercent(130.0), 100.0);
+        assert_percent(RawUsage::RemainingPercent(120.0), 0.0);
+        assert_percent(RawUsage::UsedFraction(-0.1), 0.0);
+        assert_percent(
+            RawUsage::Used {
+                used: 12.0,
+                limit: 10.0,
+            },
+            100.0,
+        );
+        assert_percent(
+            RawUsage::Used {
+                used: 5.0,
+                limit: 0.0,
+            },
+            0.0,
+        );
+        assert_eq!(RawUsage::UsedPercent(f64::NAN).used_percent(), None);
+        assert_eq!(
+            RawUsage::RemainingFraction(f64::INFINITY).used_percent(),
+            None
+        );
+        assert_eq!(
+            RawUsage::Remaining {
+                remaining: f64::NAN,
+                limit: 10.0
+            }
+            .used_percent(),
+            None
+        );
+    }
+
+    #[test]
+    fn every_reset_shape_becomes_an_absolute_time() {
+        let expected = Utc.with_ymd_and_hms(2026, 3, 4, 17, 0, 0).unwrap();
+        let shapes = [
+            RawReset::At(expected),
+            RawReset::Timestamp("2026-03-04T17:00:00Z".to_string()),
+            RawReset::Timestamp("2026-03-04T12:00:00-05:00".to_string()),
+            RawReset::Timestamp(expected.timestamp().to_string()),
+            RawReset::Timestamp(expected.timestamp_millis().to_string()),
+            RawReset::Epoch(expected.timestamp()),
+            RawReset::Epoch(expected.timestamp_millis()),
+            RawReset::AfterSeconds(5.0 * 3600.0),
+        ];
+        for shape in shapes {
+            assert_eq!(shape.resolve(now()), Some(expected), "{shape:?}");
+        }
+    }
+
+    #[test]
+    fn unreadable_resets_are_dropped() {
+        for shape in [
+            RawReset::Timestamp("tomorrow".to_string()),
+            RawReset::Timestamp(String::new()),
+            RawReset::Epoch(0),
+            RawReset::Epoch(-5),
+            RawReset::AfterSeconds(-1.0),
+            RawReset::AfterSeconds(f64::NAN),
+        ] {
+            assert_eq!(shape.resolve(now()), None, "{shape:?}");
+        }
+    }
+
+    #[test]
+    fn lengths_become_minutes() {
+        assert_eq!(RawLength::Minutes(300).minutes(), Some(300));
+        assert_eq!(RawLength::Seconds(18_000).minutes(), Some(300));
+        assert_eq!(RawLength::Named("daily".to_string()).minutes(), Some(1440));
+        assert_eq!(
+            RawLength::Named("Weekly".to_string()).minutes(),
+            Some(10_080)
+        );
+        assert_eq!(
+            RawLength::Named("monthly".to_string()).minutes(),
+            Some(43_200)
+        );
+        assert_eq!(RawLength::Named("hourly".to_string()).minutes(), Some(60));
+        assert_eq!(RawLength::Named("never".to_string()).minutes(), None);
+        assert_eq!(RawLength::Minutes(0).minutes(), None);
+        assert_eq!(RawLength::Seconds(30).minutes(), None);
+        assert_eq!(RawLength::Minutes(i64::MAX).minutes(), None);
+    }
+
+    #[test]
+    fn raw_window_normalizes_all_fields() {
+        let window = RawWindow::new(RawUsage::Remaining {
+            remaining: 250.0,
+            limit: 1000.0,
+        })
+        .with_length(RawLength::Named("daily".to_string()))
+        .with_reset(RawReset::AfterSeconds(90.0))
+        .with_description("1000 req/day")
+        .normalize(now())
+        .expect("a window");
+
+        assert!((window.used_percent - 75.0).abs() < 1e-9);
+        assert_eq!(window.window_minutes, Some(1440));
+        assert_eq!(window.resets_at, Some(now() + Duration::seconds(90)));
+        assert_eq!(window.reset_description.as_deref(), Some("1000 req/day"));
+
+        let unreadable = RawWindow::new(RawUsage::UsedPercent(10.0))
+            .with_length(RawLength::Named("fortnightly".to_string()))
+            .with_reset(RawReset::Timestamp("soon".to_string()))
+            .normalize(now())
+            .expect("usage alone is enough");
+        assert_eq!(unreadable.window_minutes, None);
+        assert_eq!(unreadable.resets_at, None);
+        assert!(
+            RawWindow::new(RawUsage::UsedFraction(f64::NAN))
+                .normalize(now())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn snapshots_are_clamped_and_filled_from_quirks() {
+        let mut snapshot = UsageSnapshot::new(RateWindow {
+            used_percent: 104.0,
+            window_minutes: None,
+            resets_at: None,
+            reset_description: None,
+        });
+        snapshot.secondary = Some(RateWindow {
+            used_percent: -3.0,
+            window_minutes: Some(0),
+            resets_at: None,
+            reset_description: None,
+        });
+        snapshot.tertiary = Some(RateWindow::new(f64::NAN));
+
+        let codex = normalize_snapshot(Provider::Codex, snapshot.clone());
+        let primary = codex.primary.expect("primary kept");
+        assert!((primary.used_percent - 100.0).abs() < f64::EPSILON);
+        assert_eq!(primary.window_minutes, Some(300));
+        let secondary = codex.secondary.expect("secondary kept");
+        assert!(secondary.used_percent.abs() < f64::EPSILON);
+        assert_eq!(secondary.window_minutes, Some(10_080));
+        assert!(codex.tertiary.is_none(), "a NaN window is dropped");
+
+        let other = normalize_snapshot(Provider::Groq, snapshot);
+        assert_eq!(other.primary.expect("primary").window_minutes, None);
+        assert_eq!(other.secondary.expect("secondary").window_minutes, None);
+    }
+
+    #[test]
+    fn reported_lengths_win_over_quirks() {
+        let snapshot = UsageSnapshot::new(RateWindow {
+            used_percent: 20.0,
+            window_minutes: Some(60),
+            resets_at: None,
+            reset_description: None,
+        });
+        let normalized = normalize_snapshot(Provider::Claude, snapshot);
+        assert_eq!(
+            normalized.primary.expect("primary").window_minutes,
+            Some(60)
+        );
+    }
+}
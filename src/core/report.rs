@@ -0,0 +1,370 @@
+//! Weekly and monthly usage reports.
+//!
+//! A report summarizes recorded history and local cost data for a period:
+//! spend per provider, the busiest days, and the windows that came close to
+//! their limit, plus a few sentences saying the same in words. Gathering
+//! the data and rendering it are left to `caut report`.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::core::models::CostPayload;
+use crate::storage::StoredSnapshot;
+use crate::util::format::format_cost;
+
+/// Usage at or above this percentage of a window counts as a near-miss.
+pub const NEAR_MISS_PERCENT: f64 = 90.0;
+
+/// Busiest days listed in a report.
+const BUSIEST_DAYS: usize = 3;
+
+/// A usage report for one period.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageReport {
+    /// "week" or "month".
+    pub period: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub total_cost_usd: f64,
+    /// Providers by cost, then by peak usage.
+    pub providers: Vec<ProviderReport>,
+    pub busiest_days: Vec<BusyDay>,
+    pub near_misses: Vec<NearMiss>,
+    /// The report in a few sentences.
+    pub narrative: Vec<String>,
+}
+
+/// One provider's share of a report.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderReport {
+    pub provider: String,
+    pub cost_usd: f64,
+    /// Snapshots recorded in the period.
+    pub snapshots: usize,
+    /// Highest usage seen in any window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_used_percent: Option<f64>,
+}
+
+/// A day's spend across providers.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BusyDay {
+    pub date: NaiveDate,
+    pub cost_usd: f64,
+}
+
+/// A rate window that reached [`NEAR_MISS_PERCENT`], once per reset.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NearMiss {
+    pub provider: String,
+    /// "primary", "secondary", or "tertiary".
+    pub window: String,
+    pub used_percent: f64,
+    pub at: DateTime<Utc>,
+}
+
+impl UsageReport {
+    /// Build the report for `from..=to` (local dates) from the snapshots
+    /// recorded in the period and cost payloads covering it.
+    #[must_use]
+    pub fn build(
+        period: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+        snapshots: &[StoredSnapshot],
+        costs: &[CostPayload],
+    ) -> Self {
+        let mut providers: BTreeMap<String, ProviderReport> = BTreeMap::new();
+        let mut days: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+
+        for payload in costs {
+            let report = provider_entry(&mut providers, &payload.provider);
+            for entry in &payload.daily {
+                let (Ok(date), Some(cost)) = (
+                    NaiveDate::parse_from_str(&entry.date, "%Y-%m-%d"),
+                    entry.total_cost,
+                ) else {
+                    continue;
+                };
+                if date < from || date > to {
+                    continue;
+                }
+                report.cost_usd += cost;
+                *days.entry(date).or_default() += cost;
+            }
+        }
+
+        let mut misses: HashMap<(String, &str, String), NearMiss> = HashMap::new();
+        for snapshot in snapshots {
+            let name = snapshot.provider.cli_name();
+            let report = provider_entry(&mut providers, name);
+            report.snapshots += 1;
+            for (window, used, resets_at) in [
+                (
+                    "primary",
+                    snapshot.primary_used_pct,
+                    snapshot.primary_resets_at,
+                ),
+                (
+                    "secondary",
+                    snapshot.secondary_used_pct,
+                    snapshot.secondary_resets_at,
+                ),
+                (
+                    "tertiary",
+                    snapshot.tertiary_used_pct,
+                    snapshot.tertiary_resets_at,
+                ),
+            ] {
+                let Some(used) = used else {
+                    continue;
+                };
+                report.peak_used_percent =
+                    Some(report.peak_used_percent.map_or(used, |peak| peak.max(used)));
+                if used < NEAR_MISS_PERCENT {
+                    continue;
+                }
+                // One near-miss per window reset; windows without a known
+                // reset count once per day.
+                let reset = resets_at.map_or_else(
+                    || snapshot.fetched_at.date_naive().to_string(),
+                    |at| at.to_rfc3339(),
+                );
+                let miss = misses
+                    .entry((name.to_string(), window, reset))
+                    .or_insert_with(|| NearMiss {
+                        provider: name.to_string(),
+                        window: window.to_string(),
+                        used_percent: used,
+                        at: snapshot.fetched_at,
+                    });
+                if used > miss.used_percent {
+                    miss.used_percent = used;
+                    miss.at = snapshot.fetched_at;
+                }
+            }
+        }
+
+        let mut providers: Vec<ProviderReport> = providers.into_values().collect();
+        providers.sort_by(|a, b| {
+            b.cost_usd.total_cmp(&a.cost_usd).then_with(|| {
+                b.peak_used_percent
+                    .unwrap_or(-1.0)
+                    .total_cmp(&a.peak_used_percent.unwrap_or(-1.0))
+            })
+        });
+
+        let mut busiest_days: Vec<BusyDay> = days
+            .into_iter()
+            .filter(|(_, cost)| *cost > 0.0)
+            .map(|(date, cost_usd)| BusyDay { date, cost_usd })
+            .collect();
+        busiest_days.sort_by(|a, b| b.cost_usd.total_cmp(&a.cost_usd));
+        busiest_days.truncate(BUSIEST_DAYS);
+
+        let mut near_misses: Vec<NearMiss> = misses.into_values().collect();
+        near_misses.sort_by_key(|miss| miss.at);
+
+        let mut report = Self {
+            period: period.to_string(),
+            from,
+            to,
+            total_cost_usd: providers.iter().map(|p| p.cost_usd).sum(),
+            providers,
+            busiest_days,
+            near_misses,
+            narrative: Vec::new(),
+        };
+        report.narrative = report.narrate();
+        report
+    }
+
+    /// The report in a few sentences.
+    fn narrate(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let when = format!("in the last {}", self.period);
+
+        let spenders: Vec<&ProviderReport> =
+            self.providers.iter().filter(|p| p.cost_usd > 0.0).collect();
+        match spenders.first() {
+            Some(top) => lines.push(format!(
+                "You spent {} across {} provider(s) {when}, most on {} ({}, {:.0}%).",
+                format_cost(self.total_cost_usd),
+                spenders.len(),
+                top.provider,
+                format_cost(top.cost_usd),
+                top.cost_usd / self.total_cost_usd * 100.0
+            )),
+            None => lines.push(format!("No local cost data was found {when}.")),
+        }
+
+        if let Some(day) = self.busiest_days.first() {
+            lines.push(format!(
+                "Your busiest day was {} ({}).",
+                day.date.format("%A, %b %-d"),
+                format_cost(day.cost_usd)
+            ));
+        }
+
+        if self.near_misses.is_empty() {
+            lines.push(format!(
+                "No limit came within {:.0}% of running out {when}.",
+                100.0 - NEAR_MISS_PERCENT
+            ));
+        } else {
+            let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+            for miss in &self.near_misses {
+                *counts.entry(miss.provider.as_str()).or_default() += 1;
+            }
+            for (provider, count) in counts {
+                lines.push(format!(
+                    "{provider} came within {:.0}% of a limit {} time(s).",
+                    100.0 - NEAR_MISS_PERCENT,
+                    count
+                ));
+            }
+        }
+
+        if self.providers.iter().all(|p| p.snapshots == 0) {
+            lines.push(
+                "No usage history was recorded; run `caut usage` or `caut daemon` regularly to fill it."
+                    .to_string(),
+            );
+        }
+        lines
+    }
+}
+
+fn provider_entry<'a>(
+    providers: &'a mut BTreeMap<String, ProviderReport>,
+    name: &str,
+) -> &'a mut ProviderReport {
+    providers
+        .entry(name.to_string())
+        .or_insert_with(|| ProviderReport {
+            provider: name.to_string(),
+            cost_usd: 0.0,
+            snapshots: 0,
+            peak_used_percent: None,
+        })
+}
+
+/// The local date range of a report ending today: the last `days` days.
+#[must_use]
+pub fn period_range(days: i64) -> (NaiveDate, NaiveDate) {
+    let to = Local::now().date_naive();
+    (to - chrono::Duration::days(days - 1), to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::CostDailyEntry;
+    use crate::core::provider::Provider;
+    use chrono::{Duration, TimeZone};
+
+    fn snapshot(provider: Provider, used: f64, at: DateTime<Utc>) -> StoredSnapshot {
+        StoredSnapshot {
+            id: 0,
+            provider,
+            fetched_at: at,
+            source: "oauth".to_string(),
+            primary_used_pct: Some(used),
+            primary_window_minutes: Some(300),
+            primary_resets_at: Some(at.date_naive().and_hms_opt(23, 0, 0).unwrap().and_utc()),
+            secondary_used_pct: None,
+            secondary_window_minutes: None,
+            secondary_resets_at: None,
+            tertiary_used_pct: None,
+            tertiary_window_minutes: None,
+            tertiary_resets_at: None,
+            cost_today_usd: None,
+            cost_mtd_usd: None,
+            credits_remaining: None,
+            account_email: None,
+            account_org: None,
+            fetch_duration_ms: None,
+            created_at: Some(at),
+            note: None,
+        }
+    }
+
+    fn cost(provider: &str, daily: &[(&str, f64)]) -> CostPayload {
+        CostPayload {
+            provider: provider.to_string(),
+            source: "local".to_string(),
+            updated_at: Utc::now(),
+            session_tokens: None,
+            session_cost_usd: None,
+            last_30_days_tokens: None,
+            last_30_days_cost_usd: None,
+            daily: daily
+                .iter()
+                .map(|(date, total)| CostDailyEntry {
+                    date: (*date).to_string(),
+                    input_tokens: None,
+                    output_tokens: None,
+                    cache_read_tokens: None,
+                    cache_creation_tokens: None,
+                    total_tokens: None,
+                    total_cost: Some(*total),
+                    models_used: None,
+                })
+                .collect(),
+            totals: None,
+        }
+    }
+
+    #[test]
+    fn report_ranks_spend_days_and_near_misses() {
+        let from = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let to = NaiveDate::from_ymd_opt(2026, 3, 8).unwrap();
+        let at = Utc.with_ymd_and_hms(2026, 3, 4, 10, 0, 0).unwrap();
+        let snapshots = [
+            snapshot(Provider::Claude, 91.0, at),
+            snapshot(Provider::Claude, 97.0, at + Duration::hours(2)),
+            snapshot(Provider::Codex, 40.0, at),
+        ];
+        let costs = [
+            cost(
+                "claude",
+                &[
+                    ("2026-03-03", 10.0),
+                    ("2026-03-04", 20.0),
+                    ("2026-02-20", 99.0),
+                ],
+            ),
+            cost("codex", &[("2026-03-04", 10.0)]),
+        ];
+
+        let report = UsageReport::build("week", from, to, &snapshots, &costs);
+
+        assert!(
+            (report.total_cost_usd - 40.0).abs() < 1e-9,
+            "outside the period is left out"
+        );
+        assert_eq!(report.providers[0].provider, "claude");
+        assert_eq!(report.providers[0].peak_used_percent, Some(97.0));
+        assert_eq!(
+            report.busiest_days[0].date,
+            NaiveDate::from_ymd_opt(2026, 3, 4).unwrap()
+        );
+        assert!((report.busiest_days[0].cost_usd - 30.0).abs() < 1e-9);
+        assert_eq!(report.near_misses.len(), 1, "one per window reset");
+        assert!((report.near_misses[0].used_percent - 97.0).abs() < 1e-9);
+        assert_eq!(
+            report.narrative[0],
+            "You spent $40.00 across 2 provider(s) in the last week, most on claude ($30.00, 75%)."
+        );
+        assert_eq!(
+            report.narrative[1],
+            "Your busiest day was Wednesday, Mar 4 ($30.00)."
+        );
+    }
+}
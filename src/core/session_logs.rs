@@ -1,18 +1,78 @@
 //! Session log discovery and parsing.
 //!
 //! Provides discovery of provider session logs and parsers for extracting
-//! per-session usage totals from JSONL files.
+//! per-session usage totals, or per-turn usage, from JSONL files.
+//!
+//! Aider keeps no central log directory; it writes `.aider.chat.history.md`
+//! into each repository it runs in (and, when `--analytics-log` is set, a
+//! JSONL event log). Repositories are found by walking the roots in
+//! `CAUT_AIDER_ROOTS` (default: home) a few levels deep.
+//!
+//! Claude and Codex logs written inside a devcontainer or on a remote host
+//! live under other mount paths. Extra roots from `--log-root` and the
+//! config's `[sessions] log_roots` are searched like a home directory (or
+//! used directly when they are a `.claude`/`.codex` directory), and a
+//! session reachable through more than one root is counted once.
 
+use crate::core::models::TaskSpend;
 use crate::core::provider::Provider;
 use crate::error::{CautError, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Local, NaiveDateTime, TimeZone, Utc};
+use regex::Regex;
 use serde_json::Value;
 use std::collections::HashSet;
 use std::fs::{self, File};
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, OnceLock};
 use std::time::SystemTime;
 
+/// Environment variable listing directories to search for Aider chat
+/// histories (platform path-list syntax).
+pub const AIDER_ROOTS_ENV: &str = "CAUT_AIDER_ROOTS";
+
+/// Environment variable naming Aider's `--analytics-log` file. When set, it
+/// is read instead of the per-repository chat histories.
+pub const AIDER_ANALYTICS_LOG_ENV: &str = "CAUT_AIDER_ANALYTICS_LOG";
+
+/// Extra session log roots from `--log-root`, set once at startup.
+static LOG_ROOTS: OnceLock<Vec<PathBuf>> = OnceLock::new();
+
+/// Also search these directories for session logs (`--log-root`).
+///
+/// Searched before the config's `[sessions] log_roots`. Only the first call
+/// has an effect.
+pub fn set_log_roots(roots: Vec<PathBuf>) {
+    let _ = LOG_ROOTS.set(roots);
+}
+
+/// Chat transcript Aider writes in the repository root.
+const AIDER_CHAT_HISTORY: &str = ".aider.chat.history.md";
+
+/// Directory levels below each root searched for chat histories.
+const AIDER_SCAN_DEPTH: usize = 4;
+
+/// Directories never worth descending into when looking for repositories.
+const AIDER_SKIP_DIRS: &[&str] = &["node_modules", "target", "vendor", "venv", "Library"];
+
+/// `# aider chat started at 2026-01-12 09:30:00`
+static AIDER_SESSION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^# aider chat started at (\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2})")
+        .expect("valid aider session regex")
+});
+
+/// `> Main model: claude-sonnet-4 with diff edit format` (`> Model:` in
+/// older releases).
+static AIDER_MODEL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^>\s*(?:Main model|Model):\s*(\S+)").expect("valid aider model regex")
+});
+
+/// `> Tokens: 2.4k sent, 1.2k cache hit, 312 received. Cost: $0.01 message, ...`
+static AIDER_TOKENS_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^>\s*Tokens:\s*(.+?)\.\s*Cost:\s*\$([0-9.,]+)\s+message")
+        .expect("valid aider tokens regex")
+});
+
 /// Discovered session log file with metadata.
 #[derive(Debug, Clone)]
 pub struct SessionLogPath {
@@ -21,6 +81,9 @@ pub struct SessionLogPath {
     pub project_path: Option<PathBuf>,
     pub session_id: String,
     pub modified_at: Option<DateTime<Utc>>,
+    /// Channel of a side-by-side install (e.g. `preview`); `None` for the
+    /// default one.
+    pub installation: Option<String>,
 }
 
 impl SessionLogPath {
@@ -33,6 +96,43 @@ impl SessionLogPath {
             project_path,
             session_id,
             modified_at,
+            installation: None,
+        }
+    }
+
+    /// Parse this log with the provider's session parser.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or the provider has no
+    /// session log parser.
+    pub fn parse(&self) -> Result<SessionUsage> {
+        match self.provider {
+            Provider::Claude => ClaudeSessionParser.parse(&self.path),
+            Provider::Codex => CodexSessionParser.parse(&self.path),
+            Provider::Aider => AiderSessionParser.parse(&self.path),
+            _ => Err(CautError::Config(format!(
+                "Session logs not supported for provider: {}",
+                self.provider.cli_name()
+            ))),
+        }
+    }
+
+    /// Parse this log into one entry per model response.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or the provider does not
+    /// write JSONL session logs.
+    pub fn turns(&self) -> Result<Vec<SessionTurn>> {
+        match self.provider {
+            Provider::Claude | Provider::Codex => {
+                let file = File::open(&self.path)
+                    .map_err(|e| CautError::Config(format!("Failed to open session log: {e}")))?;
+                Ok(parse_session_turns(BufReader::new(file)))
+            }
+            _ => Err(CautError::Config(format!(
+                "Per-turn breakdown not supported for provider: {}",
+                self.provider.cli_name()
+            ))),
         }
     }
 }
@@ -40,7 +140,13 @@ impl SessionLogPath {
 /// Session log discovery for supported providers.
 pub struct SessionLogFinder {
     claude_base: PathBuf,
+    /// Config directories of preview/nightly Claude Code installs, by channel.
+    claude_installations: Vec<(String, PathBuf)>,
     codex_base: PathBuf,
+    /// Extra roots, e.g. a devcontainer home, searched for Claude and Codex logs.
+    log_roots: Vec<PathBuf>,
+    aider_roots: Vec<PathBuf>,
+    aider_analytics_log: Option<PathBuf>,
 }
 
 impl SessionLogFinder {
@@ -51,9 +157,41 @@ impl SessionLogFinder {
     pub fn new() -> Result<Self> {
         let home = dirs::home_dir()
             .ok_or_else(|| CautError::Config("Cannot determine home directory".to_string()))?;
+        let aider_roots = std::env::var_os(AIDER_ROOTS_ENV)
+            .map(|roots| std::env::split_paths(&roots).collect::<Vec<_>>())
+            .filter(|roots| !roots.is_empty())
+            .unwrap_or_else(|| vec![home.clone()]);
+        let aider_analytics_log = std::env::var_os(AIDER_ANALYTICS_LOG_ENV)
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from);
+        #[cfg(feature = "native")]
+        let claude_installations = crate::providers::claude::installations::session_bases();
+        #[cfg(not(feature = "native"))]
+        let claude_installations = Vec::new();
+        #[cfg(feature = "native")]
+        let configured_roots = crate::storage::Config::load()
+            .map(|config| config.sessions.log_roots)
+            .unwrap_or_default();
+        #[cfg(not(feature = "native"))]
+        let configured_roots = Vec::new();
+        let log_roots = LOG_ROOTS
+            .get()
+            .into_iter()
+            .flatten()
+            .cloned()
+            .chain(configured_roots)
+            .map(|root| match root.strip_prefix("~") {
+                Ok(rest) => home.join(rest),
+                Err(_) => root,
+            })
+            .collect();
         Ok(Self {
             claude_base: home.join(".claude"),
+            claude_installations,
             codex_base: home.join(".codex"),
+            log_roots,
+            aider_roots,
+            aider_analytics_log,
         })
     }
 
@@ -62,10 +200,36 @@ impl SessionLogFinder {
     pub const fn with_paths(claude_base: PathBuf, codex_base: PathBuf) -> Self {
         Self {
             claude_base,
+            claude_installations: Vec::new(),
             codex_base,
+            log_roots: Vec::new(),
+            aider_roots: Vec::new(),
+            aider_analytics_log: None,
         }
     }
 
+    /// Also discover Claude sessions in these `(channel, config dir)`
+    /// installs, labelled with their channel.
+    #[must_use]
+    pub fn with_claude_installations(mut self, installations: Vec<(String, PathBuf)>) -> Self {
+        self.claude_installations = installations;
+        self
+    }
+
+    /// Also search these roots for Claude and Codex session logs.
+    #[must_use]
+    pub fn with_log_roots(mut self, roots: Vec<PathBuf>) -> Self {
+        self.log_roots = roots;
+        self
+    }
+
+    /// Search these directories for Aider chat histories.
+    #[must_use]
+    pub fn with_aider_roots(mut self, roots: Vec<PathBuf>) -> Self {
+        self.aider_roots = roots;
+        self
+    }
+
     /// Find session logs for a provider, optionally filtering by modified time.
     #[must_use]
     pub fn find_sessions(
@@ -77,6 +241,7 @@ impl SessionLogFinder {
         match provider {
             Provider::Claude => self.find_claude_sessions(since, until),
             Provider::Codex => self.find_codex_sessions(since, until),
+            Provider::Aider => self.find_aider_sessions(since, until),
             _ => Vec::new(),
         }
     }
@@ -86,59 +251,82 @@ impl SessionLogFinder {
         since: Option<DateTime<Utc>>,
         until: Option<DateTime<Utc>>,
     ) -> Vec<SessionLogPath> {
-        let mut results = Vec::new();
-        let projects_dir = self.claude_base.join("projects");
-        if !projects_dir.exists() {
-            return results;
+        let bases = std::iter::once((None, self.claude_base.clone()))
+            .chain(
+                self.claude_installations
+                    .iter()
+                    .map(|(channel, base)| (Some(channel.as_str()), base.clone())),
+            )
+            .chain(
+                self.log_roots
+                    .iter()
+                    .map(|root| (None, provider_dir_in(root, ".claude", "projects"))),
+            );
+
+        let mut found = Vec::new();
+        for (installation, base) in bases {
+            let mut logs = Vec::new();
+            find_claude_sessions_in(&base, installation, since, until, &mut logs);
+            found.extend(logs.into_iter().map(|log| (root_key(&log, &base), log)));
         }
+        let mut results = dedupe_across_roots(found);
+        sort_logs_by_mtime(&mut results);
+        results
+    }
 
-        if let Ok(projects) = fs::read_dir(&projects_dir) {
-            for project_entry in projects.flatten() {
-                let project_path = project_entry.path();
-                if !project_path.is_dir() {
-                    continue;
-                }
-
-                let conversations_dir = project_path.join("conversations");
-                if !conversations_dir.exists() {
-                    continue;
-                }
+    fn find_codex_sessions(
+        &self,
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+    ) -> Vec<SessionLogPath> {
+        let bases = std::iter::once(self.codex_base.clone()).chain(
+            self.log_roots
+                .iter()
+                .map(|root| provider_dir_in(root, ".codex", "sessions")),
+        );
 
-                if let Ok(files) = fs::read_dir(&conversations_dir) {
-                    for file_entry in files.flatten() {
-                        let path = file_entry.path();
-                        if !is_jsonl_file(&path) {
-                            continue;
-                        }
-
-                        let log =
-                            SessionLogPath::new(Provider::Claude, path, Some(project_path.clone()));
-                        if within_range(log.modified_at, since, until) {
-                            results.push(log);
-                        }
-                    }
+        let mut found = Vec::new();
+        for base in bases {
+            let sessions_dir = base.join("sessions");
+            if !sessions_dir.exists() {
+                continue;
+            }
+            for path in collect_jsonl_files(&sessions_dir) {
+                let log = SessionLogPath::new(Provider::Codex, path, None);
+                if within_range(log.modified_at, since, until) {
+                    found.push((root_key(&log, &base), log));
                 }
             }
         }
-
+        let mut results = dedupe_across_roots(found);
         sort_logs_by_mtime(&mut results);
         results
     }
 
-    fn find_codex_sessions(
+    fn find_aider_sessions(
         &self,
         since: Option<DateTime<Utc>>,
         until: Option<DateTime<Utc>>,
     ) -> Vec<SessionLogPath> {
-        let mut results = Vec::new();
-        let sessions_dir = self.codex_base.join("sessions");
-        if !sessions_dir.exists() {
-            return results;
-        }
+        let files = match &self.aider_analytics_log {
+            Some(log) if log.exists() => vec![log.clone()],
+            Some(_) => Vec::new(),
+            None => self
+                .aider_roots
+                .iter()
+                .flat_map(|root| collect_aider_histories(root))
+                .collect(),
+        };
 
-        let files = collect_jsonl_files(&sessions_dir);
+        let mut results = Vec::new();
         for path in files {
-            let log = SessionLogPath::new(Provider::Codex, path, None);
+            let project_path = (!is_jsonl_file(&path))
+                .then(|| path.parent().map(Path::to_path_buf))
+                .flatten();
+            let mut log = SessionLogPath::new(Provider::Aider, path, project_path);
+            if let Some(name) = log.project_path.as_deref().and_then(project_name) {
+                log.session_id = name;
+            }
             if within_range(log.modified_at, since, until) {
                 results.push(log);
             }
@@ -154,6 +342,8 @@ impl SessionLogFinder {
 pub struct SessionUsage {
     pub session_id: String,
     pub project_path: Option<PathBuf>,
+    /// Working directory the agent ran in, when the log records one.
+    pub cwd: Option<PathBuf>,
     pub started_at: Option<DateTime<Utc>>,
     pub ended_at: Option<DateTime<Utc>>,
     pub input_tokens: i64,
@@ -162,6 +352,8 @@ pub struct SessionUsage {
     pub cache_creation_tokens: i64,
     pub models_used: HashSet<String>,
     pub message_count: i64,
+    /// Cost reported by the agent itself, when its logs record one.
+    pub reported_cost_usd: Option<f64>,
 }
 
 impl SessionUsage {
@@ -169,6 +361,60 @@ impl SessionUsage {
         self.started_at = Some(self.started_at.map_or(ts, |cur| cur.min(ts)));
         self.ended_at = Some(self.ended_at.map_or(ts, |cur| cur.max(ts)));
     }
+
+    fn add_reported_cost(&mut self, cost: f64) {
+        self.reported_cost_usd = Some(self.reported_cost_usd.unwrap_or(0.0) + cost);
+    }
+
+    /// Fold another session's totals into this one.
+    fn absorb(&mut self, other: Self) {
+        if let Some(ts) = other.started_at {
+            self.record_timestamp(ts);
+        }
+        if let Some(ts) = other.ended_at {
+            self.record_timestamp(ts);
+        }
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_read_tokens += other.cache_read_tokens;
+        self.cache_creation_tokens += other.cache_creation_tokens;
+        self.models_used.extend(other.models_used);
+        self.message_count += other.message_count;
+        if let Some(cost) = other.reported_cost_usd {
+            self.add_reported_cost(cost);
+        }
+    }
+}
+
+/// Sum session costs for today and the last 30 days, by local start time.
+///
+/// Each session counts as one task; sessions without a start time are
+/// skipped.
+#[must_use]
+pub fn spend_from_sessions(sessions: &[SessionUsage], now: DateTime<Local>) -> TaskSpend {
+    let today = now.date_naive();
+    let cutoff = now - Duration::days(30);
+    let mut spend = TaskSpend {
+        today_usd: 0.0,
+        today_tasks: 0,
+        last_30_days_usd: 0.0,
+        last_30_days_tasks: 0,
+    };
+    for session in sessions {
+        let Some(started_at) = session.started_at.map(|t| t.with_timezone(&Local)) else {
+            continue;
+        };
+        let cost = session.reported_cost_usd.unwrap_or(0.0);
+        if started_at >= cutoff {
+            spend.last_30_days_usd += cost;
+            spend.last_30_days_tasks += 1;
+        }
+        if started_at.date_naive() == today {
+            spend.today_usd += cost;
+            spend.today_tasks += 1;
+        }
+    }
+    spend
 }
 
 /// Parser for Claude Code session logs.
@@ -201,14 +447,205 @@ impl CodexSessionParser {
     }
 }
 
+/// Parser for Aider chat histories and analytics logs.
+pub struct AiderSessionParser;
+
+impl AiderSessionParser {
+    /// Parse an Aider log into a single usage total covering every chat in it.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be opened or read.
+    pub fn parse(&self, path: &Path) -> Result<SessionUsage> {
+        let sessions = self.parse_sessions(path)?;
+        let mut usage = SessionUsage {
+            session_id: path
+                .parent()
+                .and_then(project_name)
+                .unwrap_or_else(|| session_id_from_path(path)),
+            project_path: sessions.first().and_then(|s| s.project_path.clone()),
+            cwd: sessions.first().and_then(|s| s.cwd.clone()),
+            ..SessionUsage::default()
+        };
+        for session in sessions {
+            usage.absorb(session);
+        }
+        Ok(usage)
+    }
+
+    /// Parse an Aider log into one usage total per chat session.
+    ///
+    /// Chat histories (`.aider.chat.history.md`) start a session at each
+    /// `# aider chat started at` header and report tokens and cost after every
+    /// reply. Analytics logs (`.jsonl`) start one at each `launched` event and
+    /// report tokens and cost in `message_send` events.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be opened or read.
+    pub fn parse_sessions(&self, path: &Path) -> Result<Vec<SessionUsage>> {
+        let file = File::open(path)
+            .map_err(|e| CautError::Config(format!("Failed to open Aider log: {e}")))?;
+        let reader = BufReader::new(file);
+        if is_jsonl_file(path) {
+            Ok(parse_aider_analytics(reader))
+        } else {
+            Ok(parse_aider_chat_history(reader, path.parent()))
+        }
+    }
+}
+
+fn parse_aider_chat_history(reader: impl BufRead, project: Option<&Path>) -> Vec<SessionUsage> {
+    let project_label = project.and_then(project_name);
+    let mut sessions: Vec<SessionUsage> = Vec::new();
+
+    for line in reader.lines() {
+        let Ok(line) = line else { continue };
+
+        if let Some(caps) = AIDER_SESSION_RE.captures(&line) {
+            let started_at = NaiveDateTime::parse_from_str(&caps[1], "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+                .map(|local| local.with_timezone(&Utc));
+            let mut session = SessionUsage {
+                session_id: format!(
+                    "{}-{}",
+                    project_label.as_deref().unwrap_or("aider"),
+                    caps[1].replace([' ', ':'], "-")
+                ),
+                project_path: project.map(Path::to_path_buf),
+                cwd: project.map(Path::to_path_buf),
+                ..SessionUsage::default()
+            };
+            if let Some(ts) = started_at {
+                session.record_timestamp(ts);
+            }
+            sessions.push(session);
+            continue;
+        }
+
+        let Some(session) = sessions.last_mut() else {
+            continue;
+        };
+
+        if let Some(caps) = AIDER_MODEL_RE.captures(&line) {
+            session.models_used.insert(caps[1].to_string());
+        } else if let Some(caps) = AIDER_TOKENS_RE.captures(&line) {
+            session.message_count += 1;
+            for part in caps[1].split(',') {
+                let Some((count, kind)) = part.trim().split_once(' ') else {
+                    continue;
+                };
+                let Some(count) = parse_aider_token_count(count) else {
+                    continue;
+                };
+                match kind.trim() {
+                    "sent" => session.input_tokens += count,
+                    "received" => session.output_tokens += count,
+                    "cache hit" => session.cache_read_tokens += count,
+                    "cache write" => session.cache_creation_tokens += count,
+                    _ => {}
+                }
+            }
+            if let Ok(cost) = caps[2].replace(',', "").parse::<f64>() {
+                session.add_reported_cost(cost);
+            }
+        }
+    }
+
+    sessions
+}
+
+fn parse_aider_analytics(reader: impl BufRead) -> Vec<SessionUsage> {
+    let mut sessions: Vec<SessionUsage> = Vec::new();
+
+    for line in reader.lines() {
+        let Ok(line) = line else { continue };
+        let Ok(value) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        let timestamp = value
+            .get("time")
+            .and_then(Value::as_i64)
+            .and_then(|secs| DateTime::<Utc>::from_timestamp(secs, 0));
+
+        match value.get("event").and_then(Value::as_str) {
+            Some("launched") => {
+                let mut session = SessionUsage {
+                    session_id: timestamp.map_or_else(
+                        || format!("aider-{}", sessions.len() + 1),
+                        |ts| format!("aider-{}", ts.format("%Y-%m-%d-%H-%M-%S")),
+                    ),
+                    ..SessionUsage::default()
+                };
+                if let Some(ts) = timestamp {
+                    session.record_timestamp(ts);
+                }
+                sessions.push(session);
+            }
+            Some("message_send") => {
+                if sessions.is_empty() {
+                    sessions.push(SessionUsage {
+                        session_id: "aider-1".to_string(),
+                        ..SessionUsage::default()
+                    });
+                }
+                let Some(session) = sessions.last_mut() else {
+                    continue;
+                };
+                let Some(props) = value.get("properties") else {
+                    continue;
+                };
+                session.message_count += 1;
+                if let Some(ts) = timestamp {
+                    session.record_timestamp(ts);
+                }
+                if let Some(model) = props.get("main_model").and_then(Value::as_str)
+                    && !model.is_empty()
+                {
+                    session.models_used.insert(model.to_string());
+                }
+                session.input_tokens += first_i64(props, &["prompt_tokens"]).unwrap_or(0);
+                session.output_tokens += first_i64(props, &["completion_tokens"]).unwrap_or(0);
+                if let Some(cost) = props.get("cost").and_then(Value::as_f64) {
+                    session.add_reported_cost(cost);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    sessions
+}
+
+/// Parse Aider's abbreviated token counts (`312`, `2.4k`, `1.1M`).
+fn parse_aider_token_count(raw: &str) -> Option<i64> {
+    let raw = raw.trim().replace(',', "");
+    let (number, scale) = match raw.chars().last()? {
+        'k' | 'K' => (&raw[..raw.len() - 1], 1_000.0),
+        'm' | 'M' => (&raw[..raw.len() - 1], 1_000_000.0),
+        _ => (raw.as_str(), 1.0),
+    };
+    let value = number.parse::<f64>().ok()? * scale;
+    #[allow(clippy::cast_possible_truncation)] // token counts fit comfortably
+    Some(value.round() as i64)
+}
+
 fn parse_session_log(path: &Path) -> Result<SessionUsage> {
     let file = File::open(path)
         .map_err(|e| CautError::Config(format!("Failed to open session log: {e}")))?;
-    let reader = BufReader::new(file);
+    Ok(parse_session_jsonl(
+        BufReader::new(file),
+        session_id_from_path(path),
+    ))
+}
 
+/// Total the tokens, models, and timestamps in a Claude or Codex session log
+/// read from `reader`. Lines that are not JSON are skipped.
+#[must_use]
+pub fn parse_session_jsonl(reader: impl BufRead, session_id: String) -> SessionUsage {
     let mut usage = SessionUsage {
-        session_id: session_id_from_path(path),
+        session_id,
         project_path: None,
+        cwd: None,
         started_at: None,
         ended_at: None,
         input_tokens: 0,
@@ -217,6 +654,7 @@ fn parse_session_log(path: &Path) -> Result<SessionUsage> {
         cache_creation_tokens: 0,
         models_used: HashSet::new(),
         message_count: 0,
+        reported_cost_usd: None,
     };
 
     for line in reader.lines() {
@@ -236,6 +674,10 @@ fn parse_session_log(path: &Path) -> Result<SessionUsage> {
             usage.record_timestamp(ts);
         }
 
+        if usage.cwd.is_none() {
+            usage.cwd = extract_cwd(&value).map(PathBuf::from);
+        }
+
         if let Some(model) = extract_model(&value)
             && !model.is_empty()
         {
@@ -252,7 +694,61 @@ fn parse_session_log(path: &Path) -> Result<SessionUsage> {
         }
     }
 
-    Ok(usage)
+    usage
+}
+
+/// Token usage of one model response in a session log.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionTurn {
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Model named on the line, or the last model seen before it.
+    pub model: Option<String>,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cache_read_tokens: i64,
+    pub cache_creation_tokens: i64,
+}
+
+/// Split a Claude or Codex session log into turns: every line that reports
+/// token usage. Lines that are not JSON, or carry no tokens, are skipped.
+#[must_use]
+pub fn parse_session_turns(reader: impl BufRead) -> Vec<SessionTurn> {
+    let mut turns = Vec::new();
+    let mut model: Option<String> = None;
+
+    for line in reader.lines() {
+        let Ok(line) = line else { continue };
+        let Ok(value) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        if let Some(name) = extract_model(&value).filter(|name| !name.is_empty()) {
+            model = Some(name.to_string());
+        }
+
+        let mut turn = SessionTurn {
+            timestamp: extract_timestamp(&value),
+            model: model.clone(),
+            ..SessionTurn::default()
+        };
+        for usage_value in extract_usage_candidates(&value) {
+            if let Some(counts) = extract_token_counts(usage_value) {
+                turn.input_tokens += counts.input;
+                turn.output_tokens += counts.output;
+                turn.cache_read_tokens += counts.cache_read;
+                turn.cache_creation_tokens += counts.cache_creation;
+            }
+        }
+        if turn.input_tokens
+            + turn.output_tokens
+            + turn.cache_read_tokens
+            + turn.cache_creation_tokens
+            > 0
+        {
+            turns.push(turn);
+        }
+    }
+
+    turns
 }
 
 #[derive(Default)]
@@ -328,6 +824,14 @@ fn extract_model(value: &Value) -> Option<&str> {
     None
 }
 
+fn extract_cwd(value: &Value) -> Option<&str> {
+    value
+        .get("cwd")
+        .or_else(|| value.get("payload").and_then(|p| p.get("cwd")))
+        .and_then(Value::as_str)
+        .filter(|cwd| !cwd.is_empty())
+}
+
 fn extract_timestamp(value: &Value) -> Option<DateTime<Utc>> {
     let ts_keys = ["timestamp", "created_at", "createdAt", "ts", "time"];
 
@@ -384,6 +888,78 @@ fn first_i64(value: &Value, keys: &[&str]) -> Option<i64> {
     None
 }
 
+/// Collect Claude conversation logs under `<base>/projects` into `results`.
+fn find_claude_sessions_in(
+    base: &Path,
+    installation: Option<&str>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    results: &mut Vec<SessionLogPath>,
+) {
+    let projects_dir = base.join("projects");
+    if let Ok(projects) = fs::read_dir(&projects_dir) {
+        for project_entry in projects.flatten() {
+            let project_path = project_entry.path();
+            if !project_path.is_dir() {
+                continue;
+            }
+
+            let conversations_dir = project_path.join("conversations");
+            if !conversations_dir.exists() {
+                continue;
+            }
+
+            if let Ok(files) = fs::read_dir(&conversations_dir) {
+                for file_entry in files.flatten() {
+                    let path = file_entry.path();
+                    if !is_jsonl_file(&path) {
+                        continue;
+                    }
+
+                    let mut log =
+                        SessionLogPath::new(Provider::Claude, path, Some(project_path.clone()));
+                    log.installation = installation.map(str::to_string);
+                    if within_range(log.modified_at, since, until) {
+                        results.push(log);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A provider's config directory (`dir_name`) under an extra root, or the
+/// root itself when it already is one (contains `marker`).
+fn provider_dir_in(root: &Path, dir_name: &str, marker: &str) -> PathBuf {
+    if root.join(marker).is_dir() {
+        root.to_path_buf()
+    } else {
+        root.join(dir_name)
+    }
+}
+
+/// Identity of a log across roots: its install channel and path below the
+/// base it was found under.
+fn root_key(log: &SessionLogPath, base: &Path) -> (Option<String>, PathBuf) {
+    let relative = log.path.strip_prefix(base).unwrap_or(&log.path);
+    (log.installation.clone(), relative.to_path_buf())
+}
+
+/// Keep one copy of each session reachable through several roots (a host
+/// `~/.claude` bind-mounted into a devcontainer shows up twice), preferring
+/// the most recently modified.
+fn dedupe_across_roots(
+    mut found: Vec<((Option<String>, PathBuf), SessionLogPath)>,
+) -> Vec<SessionLogPath> {
+    found.sort_by(|a, b| b.1.modified_at.cmp(&a.1.modified_at));
+    let mut seen = HashSet::new();
+    found
+        .into_iter()
+        .filter(|(key, _)| seen.insert(key.clone()))
+        .map(|(_, log)| log)
+        .collect()
+}
+
 fn is_jsonl_file(path: &Path) -> bool {
     path.extension().is_some_and(|ext| ext == "jsonl")
 }
@@ -410,6 +986,42 @@ fn collect_jsonl_files(root: &Path) -> Vec<PathBuf> {
     results
 }
 
+/// Find Aider chat histories at most [`AIDER_SCAN_DEPTH`] levels below `root`.
+fn collect_aider_histories(root: &Path) -> Vec<PathBuf> {
+    let mut results = Vec::new();
+    let mut stack = vec![(root.to_path_buf(), 0)];
+
+    while let Some((dir, depth)) = stack.pop() {
+        let history = dir.join(AIDER_CHAT_HISTORY);
+        if history.is_file() {
+            results.push(history);
+        }
+        if depth >= AIDER_SCAN_DEPTH {
+            continue;
+        }
+
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if file_type.is_dir() && !name.starts_with('.') && !AIDER_SKIP_DIRS.contains(&&*name) {
+                stack.push((entry.path(), depth + 1));
+            }
+        }
+    }
+
+    results
+}
+
+fn project_name(dir: &Path) -> Option<String> {
+    dir.file_name().and_then(|s| s.to_str()).map(str::to_string)
+}
+
 fn session_id_from_path(path: &Path) -> String {
     path.file_stem()
         .and_then(|s| s.to_str())
@@ -500,6 +1112,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn find_claude_sessions_labels_installations() {
+        let temp = TempDir::new().unwrap();
+        for (base, session) in [(".claude", "stable_1"), (".claude-preview", "preview_1")] {
+            let conversations = temp.path().join(base).join("projects/proj1/conversations");
+            std::fs::create_dir_all(&conversations).unwrap();
+            std::fs::write(conversations.join(format!("{session}.jsonl")), "{}\n").unwrap();
+        }
+
+        let finder =
+            SessionLogFinder::with_paths(temp.path().join(".claude"), temp.path().join(".codex"))
+                .with_claude_installations(vec![(
+                    "preview".to_string(),
+                    temp.path().join(".claude-preview"),
+                )]);
+        let mut logs: Vec<(String, Option<String>)> = finder
+            .find_sessions(Provider::Claude, None, None)
+            .into_iter()
+            .map(|log| (log.session_id, log.installation))
+            .collect();
+        logs.sort();
+
+        assert_eq!(
+            logs,
+            [
+                ("preview_1".to_string(), Some("preview".to_string())),
+                ("stable_1".to_string(), None),
+            ]
+        );
+    }
+
     #[test]
     fn find_codex_sessions_recurses_directories() {
         let temp = TempDir::new().unwrap();
@@ -520,6 +1163,35 @@ mod tests {
         assert_eq!(logs[0].session_id, "session_xyz");
     }
 
+    #[test]
+    fn log_roots_add_sessions_and_skip_duplicates() {
+        let temp = TempDir::new().unwrap();
+        let write = |dir: &Path, name: &str| {
+            std::fs::create_dir_all(dir).unwrap();
+            std::fs::write(dir.join(format!("{name}.jsonl")), "{}\n").unwrap();
+        };
+        let home = temp.path().join("home");
+        write(&home.join(".codex/sessions/2026/10"), "shared");
+        // A devcontainer home with the host's ~/.codex mounted into it
+        let container = temp.path().join("devcontainer");
+        write(&container.join(".codex/sessions/2026/10"), "shared");
+        write(&container.join(".codex/sessions/2026/10"), "container_only");
+        // A root that is itself a Codex home
+        let remote = temp.path().join("remote-codex");
+        write(&remote.join("sessions"), "remote_only");
+
+        let finder = SessionLogFinder::with_paths(home.join(".claude"), home.join(".codex"))
+            .with_log_roots(vec![container, remote]);
+        let mut ids: Vec<String> = finder
+            .find_sessions(Provider::Codex, None, None)
+            .into_iter()
+            .map(|log| log.session_id)
+            .collect();
+        ids.sort();
+
+        assert_eq!(ids, ["container_only", "remote_only", "shared"]);
+    }
+
     #[test]
     fn parse_claude_session_log_extracts_tokens_and_models() {
         let temp = TempDir::new().unwrap();
@@ -563,6 +1235,19 @@ malformed-line
         assert_eq!(usage.message_count, 2);
     }
 
+    #[test]
+    fn parse_session_log_records_first_cwd() {
+        let temp = TempDir::new().unwrap();
+        let log_path = temp.path().join("cwd.jsonl");
+        let content = r#"{"type":"session_meta","payload":{"cwd":"/work/repo"},"timestamp":"2026-01-18T10:00:00Z"}
+{"cwd":"/work/repo/sub","timestamp":"2026-01-18T10:01:00Z"}
+"#;
+        std::fs::write(&log_path, content).unwrap();
+
+        let usage = CodexSessionParser.parse(&log_path).unwrap();
+        assert_eq!(usage.cwd, Some(PathBuf::from("/work/repo")));
+    }
+
     #[test]
     fn parse_session_log_handles_empty_file() {
         let temp = TempDir::new().unwrap();
@@ -575,4 +1260,164 @@ malformed-line
         assert_eq!(usage.message_count, 0);
         assert_eq!(usage.input_tokens, 0);
     }
+
+    const AIDER_HISTORY: &str = "# aider chat started at 2026-01-12 09:30:00
+
+> /usr/local/bin/aider --model sonnet
+> Aider v0.86.1
+> Main model: anthropic/claude-sonnet-4-20250514 with diff edit format, infinite output
+> Weak model: anthropic/claude-3-5-haiku-20241022
+
+#### add a --verbose flag
+
+Here is the change.
+
+> Tokens: 2.4k sent, 1.2k cache hit, 312 received. Cost: $0.01 message, $0.01 session.
+
+#### now document it
+
+> Tokens: 3.1k sent, 2.0k cache write, 1.1M cache hit, 95 received. Cost: $0.03 message, $0.04 session.
+
+# aider chat started at 2026-01-13 14:00:00
+
+> Model: gpt-4o with diff edit format
+
+> Tokens: 800 sent, 40 received. Cost: $0.0024 message, $0.0024 session.
+";
+
+    #[test]
+    fn parse_aider_chat_history_splits_sessions() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("webapp");
+        std::fs::create_dir_all(&repo).unwrap();
+        let log_path = repo.join(".aider.chat.history.md");
+        std::fs::write(&log_path, AIDER_HISTORY).unwrap();
+
+        let sessions = AiderSessionParser.parse_sessions(&log_path).unwrap();
+        assert_eq!(sessions.len(), 2);
+
+        let first = &sessions[0];
+        assert_eq!(first.message_count, 2);
+        assert_eq!(first.input_tokens, 5_500);
+        assert_eq!(first.output_tokens, 407);
+        assert_eq!(first.cache_read_tokens, 1_101_200);
+        assert_eq!(first.cache_creation_tokens, 2_000);
+        assert!(
+            first
+                .models_used
+                .contains("anthropic/claude-sonnet-4-20250514")
+        );
+        assert!((first.reported_cost_usd.unwrap() - 0.04).abs() < 1e-9);
+        assert_eq!(first.cwd.as_deref(), Some(repo.as_path()));
+        assert!(first.started_at.is_some());
+
+        let total = AiderSessionParser.parse(&log_path).unwrap();
+        assert_eq!(total.session_id, "webapp");
+        assert_eq!(total.message_count, 3);
+        assert!(total.models_used.contains("gpt-4o"));
+        assert!((total.reported_cost_usd.unwrap() - 0.0424).abs() < 1e-9);
+    }
+
+    #[test]
+    fn parse_aider_analytics_log() {
+        let temp = TempDir::new().unwrap();
+        let log_path = temp.path().join("aider-analytics.jsonl");
+        let content = r#"{"event":"launched","properties":{},"time":1768210200}
+{"event":"message_send","properties":{"main_model":"gpt-4o","prompt_tokens":1200,"completion_tokens":300,"total_tokens":1500,"cost":0.006,"total_cost":0.006},"time":1768210260}
+{"event":"command_add","properties":{},"time":1768210300}
+{"event":"message_send","properties":{"main_model":"gpt-4o","prompt_tokens":800,"completion_tokens":100,"cost":0.003},"time":1768210400}
+{"event":"launched","properties":{},"time":1768300000}
+"#;
+        std::fs::write(&log_path, content).unwrap();
+
+        let sessions = AiderSessionParser.parse_sessions(&log_path).unwrap();
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].message_count, 2);
+        assert_eq!(sessions[0].input_tokens, 2_000);
+        assert_eq!(sessions[0].output_tokens, 400);
+        assert!((sessions[0].reported_cost_usd.unwrap() - 0.009).abs() < 1e-9);
+        assert_eq!(sessions[1].message_count, 0);
+    }
+
+    #[test]
+    fn find_aider_sessions_skips_hidden_and_deep_directories() {
+        let temp = TempDir::new().unwrap();
+        let write = |dir: &Path| {
+            std::fs::create_dir_all(dir).unwrap();
+            std::fs::write(dir.join(".aider.chat.history.md"), "").unwrap();
+        };
+        write(&temp.path().join("code").join("api"));
+        write(&temp.path().join(".cache").join("repo"));
+        write(
+            &temp
+                .path()
+                .join("code")
+                .join("web")
+                .join("node_modules")
+                .join("pkg"),
+        );
+        write(
+            &temp
+                .path()
+                .join("a")
+                .join("b")
+                .join("c")
+                .join("d")
+                .join("e"),
+        );
+
+        let finder =
+            SessionLogFinder::with_paths(temp.path().join(".claude"), temp.path().join(".codex"))
+                .with_aider_roots(vec![temp.path().to_path_buf()]);
+        let logs = finder.find_sessions(Provider::Aider, None, None);
+
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].session_id, "api");
+    }
+
+    #[test]
+    fn spend_splits_today_from_last_30_days() {
+        let now = Local.with_ymd_and_hms(2026, 10, 16, 18, 0, 0).unwrap();
+        let session = |hours_ago: i64, cost: f64| SessionUsage {
+            started_at: Some((now - Duration::hours(hours_ago)).with_timezone(&Utc)),
+            reported_cost_usd: Some(cost),
+            ..SessionUsage::default()
+        };
+        let sessions = [
+            session(1, 0.5),
+            session(2, 0.25),
+            session(72, 2.0),
+            session(24 * 40, 9.0),
+        ];
+
+        let spend = spend_from_sessions(&sessions, now);
+        assert_eq!(spend.today_tasks, 2);
+        assert!((spend.today_usd - 0.75).abs() < 1e-9);
+        assert_eq!(spend.last_30_days_tasks, 3);
+        assert!((spend.last_30_days_usd - 2.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn session_turns_carry_model_forward() {
+        let log = concat!(
+            r#"{"type":"turn_context","payload":{"model":"gpt-5"}}"#,
+            "\n",
+            r#"{"timestamp":"2026-01-10T10:00:00Z","payload":{"usage":{"input_tokens":100,"output_tokens":20}}}"#,
+            "\n",
+            "not json\n",
+            r#"{"timestamp":"2026-01-10T10:01:00Z","message":{"model":"claude-sonnet-4","usage":{"input_tokens":5,"output_tokens":7,"cache_read_input_tokens":300}}}"#,
+            "\n",
+            r#"{"timestamp":"2026-01-10T10:02:00Z","type":"user"}"#,
+            "\n",
+        );
+
+        let turns = parse_session_turns(log.as_bytes());
+
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[0].model.as_deref(), Some("gpt-5"));
+        assert_eq!((turns[0].input_tokens, turns[0].output_tokens), (100, 20));
+        assert_eq!(turns[1].model.as_deref(), Some("claude-sonnet-4"));
+        assert_eq!(turns[1].cache_read_tokens, 300);
+        assert!(turns[0].timestamp < turns[1].timestamp);
+    }
 }
@@ -0,0 +1,128 @@
+//! Severity of rate windows by how much of them is left.
+//!
+//! Shared by `caut simulate`, which reports the severity of each synthetic
+//! window, and watch mode, which notifies when a provider's worst window
+//! gets more severe.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::core::models::UsageSnapshot;
+
+/// Severity of a usage window, based on remaining percentage.
+///
+/// Thresholds match the colors used by the human renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// At least 25% remaining.
+    Ok,
+    /// Between 10% and 25% remaining.
+    Warning,
+    /// Less than 10% remaining.
+    Critical,
+    /// Nothing remaining.
+    Exhausted,
+}
+
+impl Severity {
+    /// Classify a remaining percentage.
+    #[must_use]
+    pub fn from_remaining(remaining_percent: f64) -> Self {
+        if remaining_percent <= 0.0 {
+            Self::Exhausted
+        } else if remaining_percent < 10.0 {
+            Self::Critical
+        } else if remaining_percent < 25.0 {
+            Self::Warning
+        } else {
+            Self::Ok
+        }
+    }
+
+    /// Whether this severity would trigger an alert.
+    #[must_use]
+    pub const fn is_alert(self) -> bool {
+        !matches!(self, Self::Ok)
+    }
+
+    /// Short label for display.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+            Self::Exhausted => "exhausted",
+        }
+    }
+}
+
+/// Severity evaluation for a single window.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowSeverity {
+    /// Window label (session, weekly, tertiary).
+    pub window: &'static str,
+    /// Usage percentage.
+    pub used_percent: f64,
+    /// Remaining percentage.
+    pub remaining_percent: f64,
+    /// When the window resets, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resets_at: Option<DateTime<Utc>>,
+    /// Resulting severity.
+    pub severity: Severity,
+}
+
+/// Classify every window present in `usage`, session first.
+#[must_use]
+pub fn evaluate_windows(usage: &UsageSnapshot) -> Vec<WindowSeverity> {
+    [
+        ("session", usage.primary.as_ref()),
+        ("weekly", usage.secondary.as_ref()),
+        ("tertiary", usage.tertiary.as_ref()),
+    ]
+    .into_iter()
+    .filter_map(|(label, window)| {
+        window.map(|w| {
+            let remaining = w.remaining_percent();
+            WindowSeverity {
+                window: label,
+                used_percent: w.used_percent,
+                remaining_percent: remaining,
+                resets_at: w.resets_at,
+                severity: Severity::from_remaining(remaining),
+            }
+        })
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::RateWindow;
+
+    #[test]
+    fn severity_thresholds() {
+        assert_eq!(Severity::from_remaining(50.0), Severity::Ok);
+        assert_eq!(Severity::from_remaining(25.0), Severity::Ok);
+        assert_eq!(Severity::from_remaining(15.0), Severity::Warning);
+        assert_eq!(Severity::from_remaining(5.0), Severity::Critical);
+        assert_eq!(Severity::from_remaining(0.0), Severity::Exhausted);
+    }
+
+    #[test]
+    fn evaluates_present_windows_in_order() {
+        let mut usage = UsageSnapshot::new(RateWindow::new(40.0));
+        usage.tertiary = Some(RateWindow::new(95.0));
+
+        let windows = evaluate_windows(&usage);
+        let labels: Vec<_> = windows.iter().map(|w| (w.window, w.severity)).collect();
+        assert_eq!(
+            labels,
+            [("session", Severity::Ok), ("tertiary", Severity::Critical)]
+        );
+    }
+}
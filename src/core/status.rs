@@ -8,6 +8,7 @@ use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::Deserialize;
 
+use super::clock_skew;
 use super::models::{StatusIndicator, StatusPayload};
 use crate::error::{CautError, Result};
 
@@ -67,6 +68,7 @@ impl StatusFetcher {
                 CautError::Network(e.to_string())
             }
         })?;
+        clock_skew::observe(&response);
 
         if !response.status().is_success() {
             return Ok(StatusPayload {
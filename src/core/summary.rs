@@ -0,0 +1,327 @@
+//! Aggregated "all providers" summary for multi-provider usage output.
+//!
+//! Rolls every provider's payload up into one block: spend today and this
+//! month, the window closest to its limit, the next reset, and an overall
+//! status. Spend comes from local cost scans where the provider supports
+//! them, otherwise from the task spend a provider reports itself.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::models::{CostPayload, ProviderPayload, RateWindow, StatusIndicator};
+
+/// Used percentage at which a window turns the summary to `warning`.
+pub const WARNING_PERCENT: f64 = 70.0;
+
+/// Used percentage at which a window turns the summary to `critical`.
+pub const CRITICAL_PERCENT: f64 = 90.0;
+
+/// Overall state across all providers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SummaryStatus {
+    Ok,
+    /// A window is past [`WARNING_PERCENT`], a provider failed, or a
+    /// provider reports a minor incident.
+    Warning,
+    /// A window is past [`CRITICAL_PERCENT`] or a provider reports a major
+    /// outage.
+    Critical,
+}
+
+impl SummaryStatus {
+    /// Short label for human and Markdown output.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+        }
+    }
+
+    const fn for_percent(used_percent: f64) -> Self {
+        if used_percent >= CRITICAL_PERCENT {
+            Self::Critical
+        } else if used_percent >= WARNING_PERCENT {
+            Self::Warning
+        } else {
+            Self::Ok
+        }
+    }
+
+    const fn for_indicator(indicator: StatusIndicator) -> Self {
+        match indicator {
+            StatusIndicator::Major | StatusIndicator::Critical => Self::Critical,
+            StatusIndicator::Minor | StatusIndicator::Maintenance => Self::Warning,
+            StatusIndicator::None | StatusIndicator::Unknown => Self::Ok,
+        }
+    }
+}
+
+/// The rate window closest to its limit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstrainedWindow {
+    pub provider: String,
+    /// `session`, `weekly`, or `opus/sonnet`, as labelled in usage output.
+    pub window: String,
+    pub used_percent: f64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resets_at: Option<DateTime<Utc>>,
+}
+
+/// The soonest upcoming window reset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NextReset {
+    pub provider: String,
+    pub window: String,
+    pub resets_at: DateTime<Utc>,
+}
+
+/// Totals across every provider in a usage run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageSummary {
+    /// Providers that returned usage.
+    pub providers: usize,
+    /// Providers that failed to fetch.
+    pub failed: usize,
+
+    /// Spend today, when any provider reports spend.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_today_usd: Option<f64>,
+
+    /// Spend since the first of the month (UTC), when any provider has a
+    /// daily cost breakdown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_month_usd: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub most_constrained: Option<ConstrainedWindow>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_reset: Option<NextReset>,
+
+    pub status: SummaryStatus,
+}
+
+impl UsageSummary {
+    /// Summarize `payloads`, with `costs` from local cost scans and `failed`
+    /// providers that returned an error.
+    #[must_use]
+    pub fn build(
+        payloads: &[ProviderPayload],
+        costs: &[CostPayload],
+        failed: usize,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let windows: Vec<(&str, &str, &RateWindow)> = payloads
+            .iter()
+            .flat_map(|payload| {
+                [
+                    ("session", payload.usage.primary.as_ref()),
+                    ("weekly", payload.usage.secondary.as_ref()),
+                    ("opus/sonnet", payload.usage.tertiary.as_ref()),
+                ]
+                .into_iter()
+                .filter_map(move |(label, window)| {
+                    window.map(|window| (payload.provider.as_str(), label, window))
+                })
+            })
+            .collect();
+
+        let most_constrained = windows
+            .iter()
+            .max_by(|a, b| a.2.used_percent.total_cmp(&b.2.used_percent))
+            .map(|(provider, label, window)| ConstrainedWindow {
+                provider: (*provider).to_string(),
+                window: (*label).to_string(),
+                used_percent: window.used_percent,
+                resets_at: window.resets_at,
+            });
+
+        let next_reset = windows
+            .iter()
+            .filter_map(|(provider, label, window)| {
+                let resets_at = window.resets_at.filter(|at| *at > now)?;
+                Some(NextReset {
+                    provider: (*provider).to_string(),
+                    window: (*label).to_string(),
+                    resets_at,
+                })
+            })
+            .min_by_key(|reset| reset.resets_at);
+
+        let window_status = most_constrained
+            .as_ref()
+            .map_or(SummaryStatus::Ok, |window| {
+                SummaryStatus::for_percent(window.used_percent)
+            });
+        let incident_status = payloads
+            .iter()
+            .filter_map(|payload| payload.status.as_ref())
+            .map(|status| SummaryStatus::for_indicator(status.indicator))
+            .max()
+            .unwrap_or(SummaryStatus::Ok);
+        let failure_status = if failed > 0 {
+            SummaryStatus::Warning
+        } else {
+            SummaryStatus::Ok
+        };
+
+        let (cost_today_usd, cost_month_usd) = spend(payloads, costs, now);
+
+        Self {
+            providers: payloads.len(),
+            failed,
+            cost_today_usd,
+            cost_month_usd,
+            most_constrained,
+            next_reset,
+            status: window_status.max(incident_status).max(failure_status),
+        }
+    }
+}
+
+/// Spend today and month to date, summed over scanned costs and, for
+/// providers without a scan, their reported task spend.
+fn spend(
+    payloads: &[ProviderPayload],
+    costs: &[CostPayload],
+    now: DateTime<Utc>,
+) -> (Option<f64>, Option<f64>) {
+    let month = now.format("%Y-%m-").to_string();
+
+    let today = payloads
+        .iter()
+        .filter_map(|payload| cost_today_usd(payload, costs))
+        .reduce(|a, b| a + b);
+    let month_to_date = costs
+        .iter()
+        .flat_map(|cost| &cost.daily)
+        .filter(|entry| entry.date.starts_with(&month))
+        .filter_map(|entry| entry.total_cost)
+        .reduce(|a, b| a + b);
+
+    (today, month_to_date)
+}
+
+/// A provider's spend today: its local cost scan if there is one, otherwise
+/// the task spend it reports itself.
+#[must_use]
+pub fn cost_today_usd(payload: &ProviderPayload, costs: &[CostPayload]) -> Option<f64> {
+    match costs.iter().find(|cost| cost.provider == payload.provider) {
+        Some(cost) => cost.session_cost_usd,
+        None => payload.task_spend.as_ref().map(|spend| spend.today_usd),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::{CostDailyEntry, StatusPayload, TaskSpend};
+    use crate::test_utils::make_test_provider_payload_minimal;
+    use chrono::Duration;
+
+    fn cost(provider: &str, today: f64, daily: &[(&str, f64)]) -> CostPayload {
+        CostPayload {
+            provider: provider.to_string(),
+            source: "local".to_string(),
+            updated_at: Utc::now(),
+            session_tokens: None,
+            session_cost_usd: Some(today),
+            last_30_days_tokens: None,
+            last_30_days_cost_usd: None,
+            daily: daily
+                .iter()
+                .map(|(date, usd)| CostDailyEntry {
+                    date: (*date).to_string(),
+                    input_tokens: None,
+                    output_tokens: None,
+                    cache_read_tokens: None,
+                    cache_creation_tokens: None,
+                    total_tokens: None,
+                    total_cost: Some(*usd),
+                    models_used: None,
+                })
+                .collect(),
+            totals: None,
+        }
+    }
+
+    #[test]
+    fn picks_tightest_window_and_soonest_reset() {
+        let now: DateTime<Utc> = "2026-10-16T12:00:00Z".parse().unwrap();
+        let mut codex = make_test_provider_payload_minimal("codex", "cli");
+        codex.usage.primary = Some(RateWindow {
+            resets_at: Some(now + Duration::hours(1)),
+            ..RateWindow::new(40.0)
+        });
+        let mut claude = make_test_provider_payload_minimal("claude", "oauth");
+        claude.usage.primary = Some(RateWindow::new(10.0));
+        claude.usage.secondary = Some(RateWindow {
+            resets_at: Some(now + Duration::days(3)),
+            ..RateWindow::new(75.0)
+        });
+
+        let summary = UsageSummary::build(&[codex, claude], &[], 0, now);
+
+        let tightest = summary.most_constrained.unwrap();
+        assert_eq!(tightest.provider, "claude");
+        assert_eq!(tightest.window, "weekly");
+        let next = summary.next_reset.unwrap();
+        assert_eq!(next.provider, "codex");
+        assert_eq!(next.window, "session");
+        assert_eq!(summary.status, SummaryStatus::Warning);
+        assert!(summary.cost_today_usd.is_none());
+    }
+
+    #[test]
+    fn sums_spend_without_double_counting_scanned_providers() {
+        let now: DateTime<Utc> = "2026-10-16T12:00:00Z".parse().unwrap();
+        let spend = TaskSpend {
+            today_usd: 2.0,
+            today_tasks: 1,
+            last_30_days_usd: 9.0,
+            last_30_days_tasks: 4,
+        };
+        let mut cline = make_test_provider_payload_minimal("cline", "local");
+        cline.task_spend = Some(spend.clone());
+        let mut roo = make_test_provider_payload_minimal("roo", "local");
+        roo.task_spend = Some(spend);
+        let costs = [cost(
+            "cline",
+            1.5,
+            &[
+                ("2026-09-30", 4.0),
+                ("2026-10-01", 3.0),
+                ("2026-10-16", 1.5),
+            ],
+        )];
+
+        let summary = UsageSummary::build(&[cline, roo], &costs, 1, now);
+
+        assert!((summary.cost_today_usd.unwrap() - 3.5).abs() < f64::EPSILON);
+        assert!((summary.cost_month_usd.unwrap() - 4.5).abs() < f64::EPSILON);
+        assert_eq!(summary.failed, 1);
+        assert!(summary.status >= SummaryStatus::Warning);
+    }
+
+    #[test]
+    fn major_outage_is_critical() {
+        let now = Utc::now();
+        let mut payload = make_test_provider_payload_minimal("codex", "cli");
+        payload.status = Some(StatusPayload {
+            indicator: StatusIndicator::Major,
+            description: None,
+            updated_at: None,
+            url: String::new(),
+        });
+
+        let summary = UsageSummary::build(&[payload], &[], 0, now);
+        assert_eq!(summary.status, SummaryStatus::Critical);
+    }
+}
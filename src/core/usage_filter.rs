@@ -0,0 +1,298 @@
+//! Sorting and filtering of usage results (`caut usage --sort/--filter`).
+//!
+//! Each provider is reduced to three numbers: `used` (its fullest window, in
+//! percent), `cost` (spend today, in USD), and `reset` (minutes until its
+//! next window reset). Filters such as `used>50` keep the providers whose
+//! number satisfies every comparison; providers without that number are
+//! dropped. Sorting puts what matters first: the fullest windows, the
+//! biggest spend, or the soonest reset, with providers lacking the number
+//! last.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+
+use crate::core::models::{CostPayload, ProviderPayload};
+use crate::core::summary::cost_today_usd;
+use crate::error::{CautError, Result};
+use crate::util::time::parse_duration_secs;
+
+/// A number usage results can be sorted or filtered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageField {
+    /// Highest used percentage across the provider's windows.
+    Used,
+    /// Spend today in USD.
+    Cost,
+    /// Minutes until the provider's next window reset.
+    Reset,
+}
+
+impl UsageField {
+    /// Order two values so the one that matters more comes first.
+    fn rank(self, a: f64, b: f64) -> Ordering {
+        match self {
+            Self::Used | Self::Cost => b.total_cmp(&a),
+            Self::Reset => a.total_cmp(&b),
+        }
+    }
+}
+
+/// Comparison operator in a filter expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Comparison {
+    /// Operators, longest first so `>=` is not read as `>`.
+    const OPERATORS: [(&'static str, Self); 7] = [
+        (">=", Self::GreaterOrEqual),
+        ("<=", Self::LessOrEqual),
+        ("!=", Self::NotEqual),
+        ("==", Self::Equal),
+        (">", Self::Greater),
+        ("<", Self::Less),
+        ("=", Self::Equal),
+    ];
+
+    const fn holds(self, actual: f64, expected: f64) -> bool {
+        match self {
+            Self::Greater => actual > expected,
+            Self::GreaterOrEqual => actual >= expected,
+            Self::Less => actual < expected,
+            Self::LessOrEqual => actual <= expected,
+            Self::Equal => (actual - expected).abs() < f64::EPSILON,
+            Self::NotEqual => (actual - expected).abs() >= f64::EPSILON,
+        }
+    }
+}
+
+/// A `--filter` expression such as `used>50`, `cost>=1.50`, or `reset<2h`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageFilter {
+    pub field: UsageField,
+    pub comparison: Comparison,
+    /// Percent for `used`, USD for `cost`, minutes for `reset`.
+    pub value: f64,
+}
+
+impl UsageFilter {
+    /// Whether `metrics` satisfies this filter. A missing number never does.
+    #[must_use]
+    pub fn matches(&self, metrics: &UsageMetrics) -> bool {
+        metrics
+            .get(self.field)
+            .is_some_and(|actual| self.comparison.holds(actual, self.value))
+    }
+}
+
+impl FromStr for UsageFilter {
+    type Err = CautError;
+
+    #[allow(clippy::cast_precision_loss)] // reset durations are far below 2^52 seconds
+    fn from_str(input: &str) -> Result<Self> {
+        let invalid = || {
+            CautError::Config(format!(
+                "Invalid filter '{input}' (expected e.g. used>50, cost>=1.50, reset<2h)"
+            ))
+        };
+
+        let (at, symbol, comparison) = Comparison::OPERATORS
+            .iter()
+            .filter_map(|(symbol, comparison)| {
+                input.find(symbol).map(|at| (at, *symbol, *comparison))
+            })
+            .min_by_key(|(at, symbol, _)| (*at, std::cmp::Reverse(symbol.len())))
+            .ok_or_else(invalid)?;
+        let field = match input[..at].trim().to_lowercase().as_str() {
+            "used" => UsageField::Used,
+            "cost" => UsageField::Cost,
+            "reset" => UsageField::Reset,
+            _ => return Err(invalid()),
+        };
+
+        let raw = input[at + symbol.len()..].trim();
+        let value: Option<f64> = match field {
+            UsageField::Used => raw.trim_end_matches('%').trim().parse().ok(),
+            UsageField::Cost => raw.trim_start_matches('$').trim().parse().ok(),
+            // Bare numbers are minutes; `90m`, `2h`, `1d` also work.
+            UsageField::Reset => raw
+                .parse()
+                .ok()
+                .or_else(|| parse_duration_secs(raw).ok().map(|secs| secs as f64 / 60.0)),
+        };
+        let value = value
+            .filter(|value| value.is_finite())
+            .ok_or_else(invalid)?;
+
+        Ok(Self {
+            field,
+            comparison,
+            value,
+        })
+    }
+}
+
+/// The numbers a provider is sorted and filtered by.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UsageMetrics {
+    pub used_percent: Option<f64>,
+    pub cost_today_usd: Option<f64>,
+    pub reset_minutes: Option<f64>,
+}
+
+impl UsageMetrics {
+    /// Metrics for `payload`, with spend from `costs` where scanned.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // seconds until a reset are far below 2^52
+    pub fn of(payload: &ProviderPayload, costs: &[CostPayload], now: DateTime<Utc>) -> Self {
+        let windows = [
+            payload.usage.primary.as_ref(),
+            payload.usage.secondary.as_ref(),
+            payload.usage.tertiary.as_ref(),
+        ];
+        let windows = windows.iter().flatten();
+
+        Self {
+            used_percent: windows
+                .clone()
+                .map(|window| window.used_percent)
+                .max_by(f64::total_cmp),
+            cost_today_usd: cost_today_usd(payload, costs),
+            reset_minutes: windows
+                .filter_map(|window| window.resets_at)
+                .filter(|at| *at > now)
+                .min()
+                .map(|at| (at - now).num_seconds() as f64 / 60.0),
+        }
+    }
+
+    /// The value of `field`, if known.
+    #[must_use]
+    pub const fn get(&self, field: UsageField) -> Option<f64> {
+        match field {
+            UsageField::Used => self.used_percent,
+            UsageField::Cost => self.cost_today_usd,
+            UsageField::Reset => self.reset_minutes,
+        }
+    }
+}
+
+/// Drop payloads failing any of `filters`, then order them by `sort`.
+///
+/// The sort is stable, so providers with equal or missing values keep their
+/// original order.
+pub fn apply(
+    payloads: &mut Vec<ProviderPayload>,
+    sort: Option<UsageField>,
+    filters: &[UsageFilter],
+    costs: &[CostPayload],
+    now: DateTime<Utc>,
+) {
+    if sort.is_none() && filters.is_empty() {
+        return;
+    }
+
+    let mut ranked: Vec<(UsageMetrics, ProviderPayload)> = payloads
+        .drain(..)
+        .map(|payload| (UsageMetrics::of(&payload, costs, now), payload))
+        .filter(|(metrics, _)| filters.iter().all(|filter| filter.matches(metrics)))
+        .collect();
+
+    if let Some(field) = sort {
+        ranked.sort_by(|(a, _), (b, _)| match (a.get(field), b.get(field)) {
+            (Some(a), Some(b)) => field.rank(a, b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+    }
+
+    payloads.extend(ranked.into_iter().map(|(_, payload)| payload));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::{RateWindow, TaskSpend};
+    use crate::test_utils::make_test_provider_payload_minimal;
+    use chrono::Duration;
+
+    fn payload(provider: &str, used: f64, resets_in_hours: Option<i64>) -> ProviderPayload {
+        let now: DateTime<Utc> = "2026-10-16T12:00:00Z".parse().unwrap();
+        let mut payload = make_test_provider_payload_minimal(provider, "cli");
+        payload.usage.primary = Some(RateWindow {
+            resets_at: resets_in_hours.map(|hours| now + Duration::hours(hours)),
+            ..RateWindow::new(used)
+        });
+        payload
+    }
+
+    #[test]
+    fn parses_filter_expressions() {
+        let filter: UsageFilter = "used>50".parse().unwrap();
+        assert_eq!(filter.field, UsageField::Used);
+        assert_eq!(filter.comparison, Comparison::Greater);
+        assert!((filter.value - 50.0).abs() < f64::EPSILON);
+
+        let filter: UsageFilter = " cost >= $1.50 ".parse().unwrap();
+        assert_eq!(filter.comparison, Comparison::GreaterOrEqual);
+        assert!((filter.value - 1.5).abs() < f64::EPSILON);
+
+        let filter: UsageFilter = "reset<2h".parse().unwrap();
+        assert!((filter.value - 120.0).abs() < f64::EPSILON);
+        let filter: UsageFilter = "reset!=30".parse().unwrap();
+        assert_eq!(filter.comparison, Comparison::NotEqual);
+        assert!((filter.value - 30.0).abs() < f64::EPSILON);
+
+        for bad in ["used", "tokens>5", "used>lots", ">50"] {
+            assert!(bad.parse::<UsageFilter>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn filters_then_sorts_with_missing_values_last() {
+        let now: DateTime<Utc> = "2026-10-16T12:00:00Z".parse().unwrap();
+        let mut payloads = vec![
+            payload("codex", 40.0, Some(5)),
+            payload("claude", 80.0, Some(48)),
+            payload("gemini", 20.0, None),
+            payload("cursor", 95.0, Some(1)),
+        ];
+
+        let filters = ["used>30".parse().unwrap()];
+        apply(&mut payloads, Some(UsageField::Used), &filters, &[], now);
+        let order: Vec<&str> = payloads.iter().map(|p| p.provider.as_str()).collect();
+        assert_eq!(order, ["cursor", "claude", "codex"]);
+
+        payloads.push(payload("gemini", 20.0, None));
+        apply(&mut payloads, Some(UsageField::Reset), &[], &[], now);
+        let order: Vec<&str> = payloads.iter().map(|p| p.provider.as_str()).collect();
+        assert_eq!(order, ["cursor", "codex", "claude", "gemini"]);
+    }
+
+    #[test]
+    fn cost_comes_from_task_spend_without_a_scan() {
+        let now = Utc::now();
+        let mut cline = payload("cline", 0.0, None);
+        cline.task_spend = Some(TaskSpend {
+            today_usd: 3.25,
+            today_tasks: 2,
+            last_30_days_usd: 10.0,
+            last_30_days_tasks: 6,
+        });
+        let mut payloads = vec![payload("codex", 10.0, None), cline];
+
+        let filters = ["cost>1".parse().unwrap()];
+        apply(&mut payloads, None, &filters, &[], now);
+        assert_eq!(payloads.len(), 1);
+        assert_eq!(payloads[0].provider, "cline");
+    }
+}
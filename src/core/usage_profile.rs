@@ -0,0 +1,244 @@
+//! Typical usage by weekday and hour.
+//!
+//! Recorded snapshots are turned into the percentage points of a rate window
+//! consumed in each local hour, then averaged per weekday. Comparing today
+//! against that curve shows whether the day is running hot, and which of the
+//! remaining hours are usually quiet enough for heavy agent work.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike};
+use serde::Serialize;
+
+use crate::core::prediction::UsageWindow;
+use crate::storage::StoredSnapshot;
+
+/// Consecutive snapshots further apart than this are not attributed to an
+/// hour; the usage between them could have happened at any point.
+const MAX_SAMPLE_GAP: Duration = Duration::hours(3);
+
+const WEEKDAY_LABELS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Usage per hour for one weekday, or for today.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeekdayProfile {
+    pub weekday: &'static str,
+    /// Days of history averaged into this profile.
+    pub days: usize,
+    /// Percentage points consumed in each local hour (0-23). None where no
+    /// data covers the hour.
+    pub hours: Vec<Option<f64>>,
+}
+
+impl WeekdayProfile {
+    /// Total consumed in hours `0..=hour`.
+    #[must_use]
+    pub fn through(&self, hour: u32) -> f64 {
+        self.hours.iter().take(hour as usize + 1).flatten().sum()
+    }
+
+    /// Up to `count` hours after `after`, least used first.
+    #[must_use]
+    pub fn quietest_after(&self, after: u32, count: usize) -> Vec<u32> {
+        let mut hours: Vec<(u32, f64)> = (after + 1..24)
+            .filter_map(|h| self.hours[h as usize].map(|v| (h, v)))
+            .collect();
+        hours.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        hours.into_iter().take(count).map(|(h, _)| h).collect()
+    }
+}
+
+/// A provider's typical week next to today's actual usage.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageProfile {
+    pub provider: String,
+    pub window: &'static str,
+    /// Typical usage, Monday first.
+    pub typical: Vec<WeekdayProfile>,
+    /// Today's usage through the current hour.
+    pub today: WeekdayProfile,
+    /// The current local hour.
+    pub hour: u32,
+}
+
+impl UsageProfile {
+    /// The typical profile for today's weekday.
+    #[must_use]
+    pub fn typical_today(&self) -> &WeekdayProfile {
+        let index = WEEKDAY_LABELS
+            .iter()
+            .position(|label| *label == self.today.weekday)
+            .unwrap_or(0);
+        &self.typical[index]
+    }
+}
+
+/// Build a usage profile from `history`, bucketed in `now`'s time zone.
+///
+/// Past days count toward the typical week only if they have at least one
+/// snapshot, so days the machine was off do not drag the averages to zero.
+#[must_use]
+pub fn build_profile<Tz: TimeZone>(
+    provider: &str,
+    history: &[StoredSnapshot],
+    kind: UsageWindow,
+    now: &DateTime<Tz>,
+) -> UsageProfile {
+    let tz = now.timezone();
+    let today = now.date_naive();
+    let consumed = hourly_consumption(history, kind, &tz);
+    let observed: BTreeSet<NaiveDate> = history
+        .iter()
+        .filter(|s| kind.used_pct(s).is_some())
+        .map(|s| s.fetched_at.with_timezone(&tz).date_naive())
+        .collect();
+
+    let typical = (0..7)
+        .map(|weekday| {
+            let days: Vec<NaiveDate> = observed
+                .iter()
+                .filter(|d| **d < today && d.weekday().num_days_from_monday() == weekday)
+                .copied()
+                .collect();
+            #[allow(clippy::cast_precision_loss)] // a handful of days
+            let hours = (0..24)
+                .map(|hour| {
+                    (!days.is_empty()).then(|| {
+                        days.iter()
+                            .filter_map(|d| consumed.get(&(*d, hour)))
+                            .sum::<f64>()
+                            / days.len() as f64
+                    })
+                })
+                .collect();
+            WeekdayProfile {
+                weekday: WEEKDAY_LABELS[weekday as usize],
+                days: days.len(),
+                hours,
+            }
+        })
+        .collect();
+
+    let seen_today = observed.contains(&today);
+    let today_profile = WeekdayProfile {
+        weekday: WEEKDAY_LABELS[today.weekday().num_days_from_monday() as usize],
+        days: usize::from(seen_today),
+        hours: (0..24)
+            .map(|hour| {
+                (seen_today && hour <= now.hour())
+                    .then(|| consumed.get(&(today, hour)).copied().unwrap_or(0.0))
+            })
+            .collect(),
+    };
+
+    UsageProfile {
+        provider: provider.to_string(),
+        window: kind.label(),
+        typical,
+        today: today_profile,
+        hour: now.hour(),
+    }
+}
+
+/// Percentage points consumed per local (day, hour).
+///
+/// Each rise between consecutive snapshots is credited to the hour of the
+/// later one. After a reset, the new reading is what was used since.
+fn hourly_consumption<Tz: TimeZone>(
+    history: &[StoredSnapshot],
+    kind: UsageWindow,
+    tz: &Tz,
+) -> BTreeMap<(NaiveDate, u32), f64> {
+    let mut points: Vec<(DateTime<chrono::Utc>, f64)> = history
+        .iter()
+        .filter_map(|s| Some((s.fetched_at, kind.used_pct(s)?)))
+        .collect();
+    points.sort_by_key(|(at, _)| *at);
+
+    let mut consumed = BTreeMap::new();
+    for pair in points.windows(2) {
+        let ((prev_at, prev), (at, used)) = (pair[0], pair[1]);
+        if at - prev_at > MAX_SAMPLE_GAP {
+            continue;
+        }
+        let delta = if used < prev { used } else { used - prev };
+        let local = at.with_timezone(tz);
+        *consumed
+            .entry((local.date_naive(), local.hour()))
+            .or_insert(0.0) += delta;
+    }
+    consumed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::provider::Provider;
+    use chrono::Utc;
+
+    fn snapshot(at: DateTime<Utc>, primary: f64) -> StoredSnapshot {
+        StoredSnapshot {
+            id: 0,
+            provider: Provider::Claude,
+            fetched_at: at,
+            source: "test".to_string(),
+            primary_used_pct: Some(primary),
+            primary_window_minutes: Some(300),
+            primary_resets_at: None,
+            secondary_used_pct: None,
+            secondary_window_minutes: None,
+            secondary_resets_at: None,
+            tertiary_used_pct: None,
+            tertiary_window_minutes: None,
+            tertiary_resets_at: None,
+            cost_today_usd: None,
+            cost_mtd_usd: None,
+            credits_remaining: None,
+            account_email: None,
+            account_org: None,
+            fetch_duration_ms: None,
+            created_at: None,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn averages_past_weekdays_and_tracks_today() {
+        // Thursday 2026-10-15 15:30 UTC; the two Thursdays before it have data.
+        let now = Utc.with_ymd_and_hms(2026, 10, 15, 15, 30, 0).unwrap();
+        let at = |day: u32, hour: u32, min: u32| {
+            Utc.with_ymd_and_hms(2026, 10, day, hour, min, 0).unwrap()
+        };
+        let history = vec![
+            // Oct 1: 20 points during the 10:00 hour.
+            snapshot(at(1, 9, 50), 10.0),
+            snapshot(at(1, 10, 30), 30.0),
+            // Oct 8: 10 points at 10:00, then 5 after a reset at 11:00.
+            snapshot(at(8, 9, 50), 50.0),
+            snapshot(at(8, 10, 30), 60.0),
+            snapshot(at(8, 11, 10), 5.0),
+            snapshot(at(8, 14, 40), 5.0),
+            // Today: 12 points at 9:00.
+            snapshot(at(15, 8, 55), 0.0),
+            snapshot(at(15, 9, 20), 12.0),
+        ];
+
+        let profile = build_profile("claude", &history, UsageWindow::Primary, &now);
+        let thursday = profile.typical_today();
+
+        assert_eq!(thursday.weekday, "Thu");
+        assert_eq!(thursday.days, 2);
+        assert_eq!(thursday.hours[10], Some(15.0));
+        assert_eq!(thursday.hours[11], Some(2.5));
+        // The 14:40 reading follows a gap longer than MAX_SAMPLE_GAP.
+        assert_eq!(thursday.hours[14], Some(0.0));
+        assert_eq!(profile.typical[0].hours[10], None);
+        assert_eq!(profile.today.hours[9], Some(12.0));
+        assert_eq!(profile.today.hours[15], Some(0.0));
+        assert_eq!(profile.today.hours[16], None);
+        assert!((profile.today.through(15) - 12.0).abs() < f64::EPSILON);
+        assert_eq!(thursday.quietest_after(15, 2), vec![16, 17]);
+    }
+}
@@ -0,0 +1,375 @@
+//! Watchdog for runaway agent sessions.
+//!
+//! In `usage --watch` and `caut serve`, session logs written to recently
+//! are checked on every refresh against limits from the `[watchdog]` config
+//! section:
+//!
+//! ```toml
+//! [watchdog]
+//! max_tokens = 5_000_000
+//! max_cost_usd = 25.0
+//! max_duration_minutes = 240
+//! hook = "pkill -STOP -x claude"
+//! ```
+//!
+//! A session crossing a limit raises a notification once per limit, and runs
+//! `hook` through the shell if one is set, so the agent can be paused or
+//! killed. The hook sees the session in `CAUT_*` environment variables (see
+//! [`run_hook`]).
+//!
+//! Tokens count input, output, and cache writes; cache reads are cheap and
+//! would trip a token limit on any long session.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::pricing::SessionCostCalculator;
+use crate::core::provider::Provider;
+use crate::core::session_logs::{SessionLogFinder, SessionUsage};
+use crate::error::{CautError, Result};
+use crate::util::format::{format_cost, format_tokens};
+
+/// Providers whose session logs the watchdog reads.
+const WATCHED_PROVIDERS: [Provider; 2] = [Provider::Claude, Provider::Codex];
+
+/// Sessions whose log changed this recently count as running.
+const ACTIVE_WINDOW_MINUTES: i64 = 15;
+
+/// `[watchdog]` config section. Unset limits are not checked.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WatchdogConfig {
+    /// Tokens one session may use.
+    pub max_tokens: Option<i64>,
+    /// Estimated cost one session may reach, in USD.
+    pub max_cost_usd: Option<f64>,
+    /// Minutes from a session's first to its latest message.
+    pub max_duration_minutes: Option<i64>,
+    /// Shell command run when a session crosses a limit.
+    pub hook: Option<String>,
+}
+
+impl WatchdogConfig {
+    /// Whether any limit is set.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.max_tokens.is_some()
+            || self.max_cost_usd.is_some()
+            || self.max_duration_minutes.is_some()
+    }
+
+    /// Check that every limit is positive.
+    ///
+    /// # Errors
+    /// Returns a config error naming the first invalid limit.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |name: &str| {
+            Err(CautError::Config(format!(
+                "watchdog.{name} must be greater than zero"
+            )))
+        };
+        if self.max_tokens.is_some_and(|n| n <= 0) {
+            return invalid("max_tokens");
+        }
+        if self.max_cost_usd.is_some_and(|c| c.is_nan() || c <= 0.0) {
+            return invalid("max_cost_usd");
+        }
+        if self.max_duration_minutes.is_some_and(|m| m <= 0) {
+            return invalid("max_duration_minutes");
+        }
+        Ok(())
+    }
+}
+
+/// A watchdog limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Limit {
+    Tokens,
+    Cost,
+    Duration,
+}
+
+impl Limit {
+    /// Lowercase label used in output and `CAUT_WATCHDOG_LIMITS`.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Tokens => "tokens",
+            Self::Cost => "cost",
+            Self::Duration => "duration",
+        }
+    }
+}
+
+/// A running session over one or more limits.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunawaySession {
+    pub provider: Provider,
+    pub session_id: String,
+    pub log_path: PathBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<PathBuf>,
+    pub tokens: i64,
+    pub cost_usd: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_minutes: Option<i64>,
+    /// Limits crossed, including ones flagged on an earlier check.
+    pub exceeded: Vec<Limit>,
+}
+
+impl RunawaySession {
+    /// One-line description for notifications and logs.
+    #[must_use]
+    pub fn message(&self) -> String {
+        let short_id: String = self.session_id.chars().take(8).collect();
+        let mut facts = vec![
+            format!("{} tokens", format_tokens(self.tokens)),
+            format_cost(self.cost_usd),
+        ];
+        if let Some(minutes) = self.duration_minutes {
+            facts.push(format!("{}h{:02}m", minutes / 60, minutes % 60));
+        }
+        let limits: Vec<&str> = self.exceeded.iter().map(|l| l.label()).collect();
+        format!(
+            "caut: runaway {} session {short_id} over {} limit ({})",
+            self.provider.cli_name(),
+            limits.join("/"),
+            facts.join(", ")
+        )
+    }
+}
+
+/// Limits `usage` is over, given its estimated cost.
+#[must_use]
+pub fn exceeded_limits(config: &WatchdogConfig, usage: &SessionUsage, cost_usd: f64) -> Vec<Limit> {
+    let mut exceeded = Vec::new();
+    if config
+        .max_tokens
+        .is_some_and(|max| session_tokens(usage) > max)
+    {
+        exceeded.push(Limit::Tokens);
+    }
+    if config.max_cost_usd.is_some_and(|max| cost_usd > max) {
+        exceeded.push(Limit::Cost);
+    }
+    if config
+        .max_duration_minutes
+        .zip(session_minutes(usage))
+        .is_some_and(|(max, minutes)| minutes > max)
+    {
+        exceeded.push(Limit::Duration);
+    }
+    exceeded
+}
+
+const fn session_tokens(usage: &SessionUsage) -> i64 {
+    usage.input_tokens + usage.output_tokens + usage.cache_creation_tokens
+}
+
+fn session_minutes(usage: &SessionUsage) -> Option<i64> {
+    Some((usage.ended_at? - usage.started_at?).num_minutes())
+}
+
+/// Checks running sessions on each refresh, reporting each limit a session
+/// crosses once.
+pub struct Watchdog {
+    config: WatchdogConfig,
+    finder: SessionLogFinder,
+    calculator: SessionCostCalculator,
+    /// Provider, session, and limit already reported.
+    flagged: HashSet<(Provider, String, Limit)>,
+}
+
+impl Watchdog {
+    /// A watchdog for `config`, or `None` when no limit is set or session
+    /// logs cannot be located.
+    #[must_use]
+    pub fn from_config(config: WatchdogConfig) -> Option<Self> {
+        if !config.is_enabled() {
+            return None;
+        }
+        let finder = SessionLogFinder::new()
+            .map_err(|e| tracing::warn!(error = %e, "Watchdog disabled: no session logs"))
+            .ok()?;
+        Some(Self::with_finder(config, finder))
+    }
+
+    fn with_finder(config: WatchdogConfig, finder: SessionLogFinder) -> Self {
+        Self {
+            config,
+            finder,
+            calculator: SessionCostCalculator::new(),
+            flagged: HashSet::new(),
+        }
+    }
+
+    /// Sessions active in the last few minutes that crossed a limit not
+    /// reported for them before.
+    pub fn check(&mut self, now: DateTime<Utc>) -> Vec<RunawaySession> {
+        let since = now - Duration::minutes(ACTIVE_WINDOW_MINUTES);
+        let mut runaway = Vec::new();
+
+        for provider in WATCHED_PROVIDERS {
+            for log in self.finder.find_sessions(provider, Some(since), None) {
+                let usage = match log.parse() {
+                    Ok(usage) => usage,
+                    Err(e) => {
+                        tracing::debug!(path = %log.path.display(), error = %e, "Skipping session log");
+                        continue;
+                    }
+                };
+                let cost_usd = self.calculator.calculate(&usage).total_usd;
+                let exceeded = exceeded_limits(&self.config, &usage, cost_usd);
+                let mut new = false;
+                for limit in &exceeded {
+                    new |= self
+                        .flagged
+                        .insert((provider, usage.session_id.clone(), *limit));
+                }
+                if !new {
+                    continue;
+                }
+                runaway.push(RunawaySession {
+                    provider,
+                    tokens: session_tokens(&usage),
+                    duration_minutes: session_minutes(&usage),
+                    session_id: usage.session_id,
+                    log_path: log.path,
+                    cwd: usage.cwd.or(usage.project_path),
+                    cost_usd,
+                    exceeded,
+                });
+            }
+        }
+        runaway
+    }
+
+    /// Run the configured hook for `session`, if any.
+    pub fn trip(&self, session: &RunawaySession) {
+        if let Some(hook) = &self.config.hook {
+            run_hook(hook, session);
+        }
+    }
+}
+
+/// Start `hook` through the shell without waiting for it.
+///
+/// The hook gets `CAUT_PROVIDER`, `CAUT_SESSION_ID`, `CAUT_SESSION_LOG`,
+/// `CAUT_SESSION_CWD` (when known), `CAUT_SESSION_TOKENS`,
+/// `CAUT_SESSION_COST_USD`, and `CAUT_WATCHDOG_LIMITS` (comma-separated).
+/// Failures to start it are logged, not returned: a broken hook must not stop
+/// the refresh loop.
+pub fn run_hook(hook: &str, session: &RunawaySession) {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let limits: Vec<&str> = session.exceeded.iter().map(|l| l.label()).collect();
+    let mut command = tokio::process::Command::new(shell);
+    command
+        .args([flag, hook])
+        .env("CAUT_PROVIDER", session.provider.cli_name())
+        .env("CAUT_SESSION_ID", &session.session_id)
+        .env("CAUT_SESSION_LOG", &session.log_path)
+        .env("CAUT_SESSION_TOKENS", session.tokens.to_string())
+        .env("CAUT_SESSION_COST_USD", format!("{:.4}", session.cost_usd))
+        .env("CAUT_WATCHDOG_LIMITS", limits.join(","))
+        .stdin(std::process::Stdio::null());
+    if let Some(cwd) = &session.cwd {
+        command.env("CAUT_SESSION_CWD", cwd);
+    }
+    if let Err(e) = command.spawn() {
+        tracing::warn!(hook, error = %e, "Watchdog hook failed to start");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(tokens: i64, minutes: i64) -> SessionUsage {
+        let started = Utc::now() - Duration::minutes(minutes);
+        SessionUsage {
+            session_id: "0f3c9a7e-runaway".to_string(),
+            started_at: Some(started),
+            ended_at: Some(started + Duration::minutes(minutes)),
+            input_tokens: tokens / 2,
+            output_tokens: tokens / 2,
+            cache_read_tokens: 50_000_000,
+            ..SessionUsage::default()
+        }
+    }
+
+    #[test]
+    fn flags_each_limit_crossed() {
+        let config = WatchdogConfig {
+            max_tokens: Some(1_000_000),
+            max_cost_usd: Some(20.0),
+            max_duration_minutes: Some(120),
+            hook: None,
+        };
+        assert!(exceeded_limits(&config, &usage(900_000, 30), 5.0).is_empty());
+        assert_eq!(
+            exceeded_limits(&config, &usage(2_000_000, 150), 25.0),
+            [Limit::Tokens, Limit::Cost, Limit::Duration]
+        );
+        assert!(
+            exceeded_limits(&WatchdogConfig::default(), &usage(2_000_000, 150), 25.0).is_empty()
+        );
+
+        assert!(config.validate().is_ok());
+        let bad = WatchdogConfig {
+            max_cost_usd: Some(0.0),
+            ..config
+        };
+        assert!(bad.validate().is_err());
+    }
+
+    #[test]
+    fn reports_a_running_session_once_per_limit() {
+        let home = tempfile::tempdir().unwrap();
+        let claude = home.path().join(".claude");
+        let project = claude.join("projects/-work-app/conversations");
+        std::fs::create_dir_all(&project).unwrap();
+        let now = Utc::now();
+        let line = |tokens: i64| {
+            format!(
+                r#"{{"type":"assistant","timestamp":"{}","message":{{"model":"claude-sonnet-4-5","usage":{{"input_tokens":{tokens},"output_tokens":1000}}}}}}"#,
+                now.to_rfc3339()
+            )
+        };
+        let log = project.join("abc123.jsonl");
+        std::fs::write(&log, line(600_000) + "\n").unwrap();
+
+        let finder = SessionLogFinder::with_paths(claude, home.path().join(".codex"));
+        let mut watchdog = Watchdog::with_finder(
+            WatchdogConfig {
+                max_tokens: Some(1_000_000),
+                ..WatchdogConfig::default()
+            },
+            finder,
+        );
+        assert!(watchdog.check(now).is_empty());
+
+        std::fs::write(&log, line(600_000) + "\n" + &line(600_000) + "\n").unwrap();
+        let runaway = watchdog.check(now);
+        assert_eq!(runaway.len(), 1);
+        assert_eq!(runaway[0].exceeded, [Limit::Tokens]);
+        assert_eq!(
+            runaway[0].message(),
+            format!(
+                "caut: runaway claude session abc123 over tokens limit (1.2M tokens, {}, 0h00m)",
+                format_cost(runaway[0].cost_usd)
+            )
+        );
+
+        // Still over the same limit: not reported again.
+        assert!(watchdog.check(now).is_empty());
+    }
+}
@@ -0,0 +1,386 @@
+//! Browser session cookies for web fetch strategies.
+//!
+//! Web strategies sign in with the session cookie a provider's site keeps in
+//! the browser. Chromium-based browsers (Chrome, Arc, Brave, Edge, Chromium)
+//! encrypt their cookie store with a key derived from a "<Browser> Safe
+//! Storage" password in the macOS Keychain, so reading it brings up a
+//! Keychain access prompt. To ask once rather than on every fetch, the one
+//! cookie a provider needs is imported into a `caut` keyring entry and reused
+//! until it expires or the site rejects it; [`refresh`] then imports it
+//! again.
+//!
+//! Cookies are read from a read-only, immutable view of the browser's
+//! database, so a running browser is neither locked out nor disturbed.
+
+use std::path::{Path, PathBuf};
+
+use aes::cipher::block_padding::Pkcs7;
+use aes::cipher::{BlockDecryptMut, KeyIvInit};
+use chrono::{DateTime, Utc};
+use directories::BaseDirs;
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CautError, Result};
+
+const KEYRING_SERVICE: &str = "caut";
+
+/// Salt and rounds Chromium derives its cookie key with on macOS.
+const KEY_SALT: &[u8] = b"saltysalt";
+const KEY_ROUNDS: u32 = 1003;
+
+/// Prefix of values encrypted with the Keychain-derived key.
+const ENCRYPTED_PREFIX: &[u8] = b"v10";
+
+/// From this cookie database version on, decrypted values start with a
+/// SHA-256 of the cookie's host.
+const HOST_DIGEST_DB_VERSION: i64 = 24;
+
+/// Seconds between 1601-01-01 (Chromium's epoch) and 1970-01-01.
+const CHROMIUM_EPOCH_OFFSET_SECS: i64 = 11_644_473_600;
+
+/// The cookie a provider's web strategy signs in with.
+#[derive(Debug, Clone, Copy)]
+pub struct CookieSpec {
+    /// Provider CLI name, used in errors.
+    pub provider: &'static str,
+    /// Site the cookie belongs to, e.g. "claude.ai".
+    pub domain: &'static str,
+    /// Cookie name, e.g. "sessionKey".
+    pub name: &'static str,
+    /// `caut` keyring account the imported cookie is kept in.
+    pub keyring_account: &'static str,
+}
+
+/// An imported session cookie.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebSession {
+    pub value: String,
+    /// `None` for a cookie that lasts as long as the browser session.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Browser the cookie was imported from.
+    pub browser: String,
+    pub imported_at: DateTime<Utc>,
+}
+
+impl WebSession {
+    /// Whether the cookie has expired at `now`.
+    #[must_use]
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+    }
+
+    /// The `Cookie` header value for `spec`.
+    #[must_use]
+    pub fn cookie_header(&self, spec: &CookieSpec) -> String {
+        format!("{}={}", spec.name, self.value)
+    }
+}
+
+/// A Chromium-based browser and where it keeps its profiles.
+struct Browser {
+    name: &'static str,
+    /// Profile root, relative to `~/Library/Application Support`.
+    dir: &'static str,
+    keychain_service: &'static str,
+    keychain_account: &'static str,
+}
+
+const BROWSERS: &[Browser] = &[
+    Browser {
+        name: "Chrome",
+        dir: "Google/Chrome",
+        keychain_service: "Chrome Safe Storage",
+        keychain_account: "Chrome",
+    },
+    Browser {
+        name: "Arc",
+        dir: "Arc/User Data",
+        keychain_service: "Arc Safe Storage",
+        keychain_account: "Arc",
+    },
+    Browser {
+        name: "Brave",
+        dir: "BraveSoftware/Brave-Browser",
+        keychain_service: "Brave Safe Storage",
+        keychain_account: "Brave",
+    },
+    Browser {
+        name: "Edge",
+        dir: "Microsoft Edge",
+        keychain_service: "Microsoft Edge Safe Storage",
+        keychain_account: "Microsoft Edge",
+    },
+    Browser {
+        name: "Chromium",
+        dir: "Chromium",
+        keychain_service: "Chromium Safe Storage",
+        keychain_account: "Chromium",
+    },
+];
+
+/// The stored session for `spec`, importing it from the browser when there
+/// is none or it has expired.
+///
+/// # Errors
+/// Returns an error if no stored session is usable and [`refresh`] fails.
+pub fn session(spec: &CookieSpec) -> Result<WebSession> {
+    match load(spec) {
+        Some(session) if !session.is_expired(Utc::now()) => Ok(session),
+        _ => refresh(spec),
+    }
+}
+
+/// Import the cookie for `spec` from the browser and store it, replacing any
+/// stored session. Call this when the site rejects the stored one.
+///
+/// # Errors
+/// Returns an error on platforms other than macOS, if no browser has a
+/// current cookie for the site, if Keychain access is denied, or if the
+/// keyring cannot be written.
+pub fn refresh(spec: &CookieSpec) -> Result<WebSession> {
+    let session = import(spec)?;
+    let json = serde_json::to_string(&session)?;
+    keyring_entry(spec)?
+        .set_password(&json)
+        .map_err(|e| CautError::Config(format!("Failed to store web session: {e}")))?;
+    tracing::debug!(
+        provider = spec.provider,
+        browser = %session.browser,
+        "Imported web session cookie"
+    );
+    Ok(session)
+}
+
+/// Remove the stored session for `spec`. Returns whether there was one.
+///
+/// # Errors
+/// Returns an error if the keyring cannot be accessed.
+pub fn forget(spec: &CookieSpec) -> Result<bool> {
+    match keyring_entry(spec)?.delete_credential() {
+        Ok(()) => Ok(true),
+        Err(keyring::Error::NoEntry) => Ok(false),
+        Err(e) => Err(CautError::Config(format!(
+            "Failed to delete web session: {e}"
+        ))),
+    }
+}
+
+fn load(spec: &CookieSpec) -> Option<WebSession> {
+    let json = keyring_entry(spec).ok()?.get_password().ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+fn keyring_entry(spec: &CookieSpec) -> Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, spec.keyring_account)
+        .map_err(|e| CautError::Config(format!("Keyring error: {e}")))
+}
+
+/// A cookie row found in a browser profile, still encrypted.
+struct FoundCookie {
+    browser: &'static Browser,
+    encrypted: Vec<u8>,
+    plain: String,
+    expires_at: Option<DateTime<Utc>>,
+    db_version: i64,
+}
+
+/// Find the longest-lived current cookie for `spec` across every profile of
+/// every installed browser, then decrypt it. Only the browser it came from
+/// is asked for its Keychain password.
+fn import(spec: &CookieSpec) -> Result<WebSession> {
+    if !cfg!(target_os = "macos") {
+        return Err(CautError::UnsupportedSource {
+            provider: spec.provider.to_string(),
+            source_type: "web".to_string(),
+        });
+    }
+    let support = BaseDirs::new()
+        .map(|dirs| dirs.data_dir().to_path_buf())
+        .ok_or_else(|| CautError::Config("Could not determine the home directory".to_string()))?;
+
+    let now = Utc::now();
+    let best = BROWSERS
+        .iter()
+        .flat_map(|browser| {
+            cookie_databases(&support.join(browser.dir))
+                .into_iter()
+                .filter_map(move |db| find_cookie(&db, spec, browser).ok().flatten())
+        })
+        .filter(|found| found.expires_at.is_none_or(|at| at > now))
+        .max_by_key(|found| found.expires_at.unwrap_or(DateTime::<Utc>::MIN_UTC))
+        .ok_or_else(|| CautError::AuthNotConfigured {
+            provider: spec.provider.to_string(),
+        })?;
+
+    let value = if best.encrypted.is_empty() {
+        best.plain
+    } else {
+        let password =
+            keyring::Entry::new(best.browser.keychain_service, best.browser.keychain_account)
+                .and_then(|entry| entry.get_password())
+                .map_err(|e| CautError::AuthInvalid {
+                    provider: spec.provider.to_string(),
+                    reason: format!(
+                        "Keychain access to {} was not granted: {e}",
+                        best.browser.keychain_service
+                    ),
+                })?;
+        decrypt(&best.encrypted, &password, best.db_version).ok_or_else(|| {
+            CautError::AuthInvalid {
+                provider: spec.provider.to_string(),
+                reason: format!("Could not decrypt the {} cookie", best.browser.name),
+            }
+        })?
+    };
+
+    Ok(WebSession {
+        value,
+        expires_at: best.expires_at,
+        browser: best.browser.name.to_string(),
+        imported_at: now,
+    })
+}
+
+/// Cookie databases of every profile under a browser's profile root.
+fn cookie_databases(root: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name == "Default" || name.starts_with("Profile ")
+        })
+        .flat_map(|entry| {
+            let profile = entry.path();
+            [profile.join("Network/Cookies"), profile.join("Cookies")]
+        })
+        .filter(|path| path.is_file())
+        .collect()
+}
+
+fn find_cookie(
+    db: &Path,
+    spec: &CookieSpec,
+    browser: &'static Browser,
+) -> rusqlite::Result<Option<FoundCookie>> {
+    let conn = Connection::open_with_flags(
+        sqlite_uri(db),
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_URI,
+    )?;
+    let db_version: i64 = conn
+        .query_row("SELECT value FROM meta WHERE key = 'version'", [], |row| {
+            row.get::<_, String>(0)
+        })
+        .optional()?
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_default();
+    conn.query_row(
+        "SELECT encrypted_value, value, expires_utc FROM cookies \
+         WHERE host_key IN (?1, ?2) AND name = ?3 \
+         ORDER BY expires_utc DESC LIMIT 1",
+        rusqlite::params![spec.domain, format!(".{}", spec.domain), spec.name],
+        |row| {
+            Ok(FoundCookie {
+                browser,
+                encrypted: row.get(0)?,
+                plain: row.get(1)?,
+                expires_at: from_chromium_time(row.get(2)?),
+                db_version,
+            })
+        },
+    )
+    .optional()
+}
+
+/// An immutable, read-only `SQLite` URI for `path`.
+fn sqlite_uri(path: &Path) -> String {
+    let path = path
+        .to_string_lossy()
+        .replace('%', "%25")
+        .replace(' ', "%20")
+        .replace('?', "%3F")
+        .replace('#', "%23");
+    format!("file:{path}?immutable=1")
+}
+
+/// Chromium timestamps count microseconds since 1601; 0 marks a session
+/// cookie.
+fn from_chromium_time(micros: i64) -> Option<DateTime<Utc>> {
+    (micros > 0)
+        .then(|| DateTime::from_timestamp(micros / 1_000_000 - CHROMIUM_EPOCH_OFFSET_SECS, 0))
+        .flatten()
+}
+
+/// Decrypt a `v10` cookie value with the browser's Keychain password.
+fn decrypt(encrypted: &[u8], password: &str, db_version: i64) -> Option<String> {
+    let data = encrypted.strip_prefix(ENCRYPTED_PREFIX)?;
+    let mut key = [0u8; 16];
+    pbkdf2::pbkdf2_hmac::<sha1::Sha1>(password.as_bytes(), KEY_SALT, KEY_ROUNDS, &mut key);
+    let iv = [b' '; 16];
+    let mut buf = data.to_vec();
+    let plain = cbc::Decryptor::<aes::Aes128>::new(&key.into(), &iv.into())
+        .decrypt_padded_mut::<Pkcs7>(&mut buf)
+        .ok()?;
+    let plain = if db_version >= HOST_DIGEST_DB_VERSION {
+        plain.get(32..)?
+    } else {
+        plain
+    };
+    String::from_utf8(plain.to_vec()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::cipher::BlockEncryptMut;
+
+    fn encrypt(plain: &[u8], password: &str) -> Vec<u8> {
+        let mut key = [0u8; 16];
+        pbkdf2::pbkdf2_hmac::<sha1::Sha1>(password.as_bytes(), KEY_SALT, KEY_ROUNDS, &mut key);
+        let mut buf = [0u8; 128];
+        let encrypted = cbc::Encryptor::<aes::Aes128>::new(&key.into(), &[b' '; 16].into())
+            .encrypt_padded_mut::<Pkcs7>(&mut buf[..plain.len() + 16], plain.len())
+            .unwrap();
+        [ENCRYPTED_PREFIX, encrypted].concat()
+    }
+
+    #[test]
+    fn decrypts_chromium_cookie_values() {
+        let encrypted = encrypt(b"sk-ant-sid01-abc", "peanuts");
+        assert_eq!(
+            decrypt(&encrypted, "peanuts", 20).as_deref(),
+            Some("sk-ant-sid01-abc")
+        );
+        assert_eq!(decrypt(&encrypted, "wrong", 20), None);
+
+        // Newer databases prefix the value with a digest of the host.
+        let with_digest = encrypt(
+            &[[0u8; 32].as_slice(), b"sk-ant-sid01-abc"].concat(),
+            "peanuts",
+        );
+        assert_eq!(
+            decrypt(&with_digest, "peanuts", HOST_DIGEST_DB_VERSION).as_deref(),
+            Some("sk-ant-sid01-abc")
+        );
+    }
+
+    #[test]
+    fn chromium_times_convert_to_utc() {
+        assert_eq!(from_chromium_time(0), None);
+        // 2026-01-01T00:00:00Z
+        let micros = (1_767_225_600 + CHROMIUM_EPOCH_OFFSET_SECS) * 1_000_000;
+        assert_eq!(
+            from_chromium_time(micros).unwrap().to_rfc3339(),
+            "2026-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            sqlite_uri(Path::new("/Users/me/Library/Application Support/Cookies")),
+            "file:/Users/me/Library/Application%20Support/Cookies?immutable=1"
+        );
+    }
+}
@@ -216,6 +216,11 @@ pub enum CautError {
     #[error("environment variable not set: {name}")]
     EnvVarMissing { name: String },
 
+    /// None of the providers checked is installed, signed in, or given an
+    /// API key.
+    #[error("no providers configured: none of the {checked} provider(s) checked is set up")]
+    NoProvidersConfigured { checked: usize },
+
     // ==========================================================================
     // Legacy errors (maintained for backward compatibility)
     // ==========================================================================
@@ -323,7 +328,9 @@ impl CautError {
     pub const fn exit_code(&self) -> ExitCode {
         match self {
             // Environment errors -> Binary not found
-            Self::ProviderNotFound(_) | Self::CliNotFound { .. } => ExitCode::BinaryNotFound,
+            Self::ProviderNotFound(_)
+            | Self::CliNotFound { .. }
+            | Self::NoProvidersConfigured { .. } => ExitCode::BinaryNotFound,
 
             // Configuration and parse errors -> Parse error
             Self::Config(_)
@@ -409,7 +416,8 @@ impl CautError {
             Self::ProviderNotFound(_)
             | Self::CliNotFound { .. }
             | Self::PermissionDenied { .. }
-            | Self::EnvVarMissing { .. } => ErrorCategory::Environment,
+            | Self::EnvVarMissing { .. }
+            | Self::NoProvidersConfigured { .. } => ErrorCategory::Environment,
 
             // Internal errors
             Self::Io(_) | Self::Json(_) | Self::Other(_) => ErrorCategory::Internal,
@@ -469,6 +477,7 @@ impl CautError {
             Self::ProviderNotFound(_) => "CAUT-E002",
             Self::PermissionDenied { .. } => "CAUT-E003",
             Self::EnvVarMissing { .. } => "CAUT-E004",
+            Self::NoProvidersConfigured { .. } => "CAUT-E005",
 
             // Internal errors (X001-X099)
             Self::Io(_) => "CAUT-X001",
@@ -670,6 +679,7 @@ impl CautError {
             }
             Self::PermissionDenied { path } => suggestions::permission_denied_suggestions(path),
             Self::EnvVarMissing { name } => suggestions::env_var_missing_suggestions(name),
+            Self::NoProvidersConfigured { .. } => suggestions::no_providers_suggestions(),
 
             // Internal errors - generic suggestions
             Self::Io(err) => {
@@ -1198,6 +1208,7 @@ mod tests {
             CautError::EnvVarMissing {
                 name: "CLAUDE_API_KEY".to_string(),
             },
+            CautError::NoProvidersConfigured { checked: 2 },
         ];
 
         for err in errors {
@@ -462,6 +462,32 @@ pub fn env_var_missing_suggestions(name: &str) -> Vec<FixSuggestion> {
     )]
 }
 
+/// Generates the first-run guide shown when no provider is set up.
+#[must_use]
+pub fn no_providers_suggestions() -> Vec<FixSuggestion> {
+    vec![
+        FixSuggestion::new(
+            vec![
+                "npm install -g @anthropic-ai/claude-code && claude auth login".to_string(),
+                "npm install -g @openai/codex && codex auth login".to_string(),
+                "caut login claude".to_string(),
+            ],
+            "caut reads usage from the coding agents you already use, and found none \
+             set up on this machine. Install and sign in to Claude Code or Codex, or \
+             sign caut in to Claude directly.",
+        ),
+        FixSuggestion::new(
+            vec![
+                "export GROQ_API_KEY=\"...\"".to_string(),
+                "caut usage --provider all".to_string(),
+                "caut doctor".to_string(),
+            ],
+            "API-key providers are picked up from their environment variables. \
+             `caut doctor` shows what caut can see for each provider.",
+        ),
+    ]
+}
+
 /// Generates fix suggestions for invalid provider errors.
 #[must_use]
 pub fn invalid_provider_suggestions(name: &str) -> Vec<FixSuggestion> {
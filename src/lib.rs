@@ -10,22 +10,30 @@
 // Allow async functions without await - stub functions will use async when implemented
 #![allow(clippy::unused_async)]
 
+#[cfg(feature = "native")]
 pub mod cli;
 pub mod core;
 pub mod error;
+#[cfg(feature = "native")]
 pub mod providers;
+#[cfg(feature = "native")]
 pub mod render;
+#[cfg(feature = "native")]
 pub mod rich;
+#[cfg(feature = "native")]
 pub mod storage;
+#[cfg(feature = "native")]
 pub mod tui;
 pub mod util;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 /// Test utilities module - included in test builds or when test-utils feature is enabled.
-#[cfg(any(test, feature = "test-utils"))]
+#[cfg(all(feature = "native", any(test, feature = "test-utils")))]
 pub mod test_utils;
 
 pub use error::{CautError, ExitCode, Result};
 
 // Re-export test utilities for external test crates
-#[cfg(any(test, feature = "test-utils"))]
+#[cfg(all(feature = "native", any(test, feature = "test-utils")))]
 pub use test_utils::*;
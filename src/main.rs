@@ -6,10 +6,10 @@
 #![warn(clippy::pedantic, clippy::nursery)]
 #![allow(clippy::module_name_repetitions)]
 
-use clap::Parser;
 use std::process::ExitCode;
 
-use caut::cli::{Cli, Commands};
+use caut::cli::args::{args_request_json, is_informational, usage_error};
+use caut::cli::{Cli, Commands, OutputFormat};
 use caut::core::logging;
 use caut::error::CautError;
 
@@ -29,7 +29,31 @@ mod build_info {
 
 #[tokio::main]
 async fn main() -> ExitCode {
-    let cli = Cli::parse();
+    let args: Vec<std::ffi::OsString> = std::env::args_os().collect();
+    let raw: Vec<String> = args
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    let (cli, command) = match Cli::try_parse_with_path(&args) {
+        Ok(parsed) => parsed,
+        // Argument errors are reported as JSON too when JSON was asked for
+        Err(e) if !is_informational(&e) && args_request_json(&raw) => {
+            let error = usage_error(&e);
+            let pretty = raw.iter().any(|arg| arg == "--pretty");
+            eprintln!(
+                "{}",
+                caut::render::error::render_command_error(
+                    &error,
+                    None,
+                    OutputFormat::Json,
+                    true,
+                    pretty
+                )
+            );
+            return ExitCode::from(error.exit_code() as u8);
+        }
+        Err(e) => e.exit(),
+    };
 
     // Initialize logging
     let log_level = cli
@@ -57,6 +81,39 @@ async fn main() -> ExitCode {
         return ExitCode::SUCCESS;
     }
 
+    if let Some(location) = &cli.config {
+        caut::storage::config::set_config_location(location.clone());
+    }
+    if !cli.log_root.is_empty() {
+        caut::core::session_logs::set_log_roots(cli.log_root.clone());
+    }
+    // Prompt, statusline, summary, completions, and `warm --background` (run
+    // from shell init) must stay fast; they use the cached remote config.
+    let fast_path = match &cli.command {
+        Some(
+            Commands::Prompt(_)
+            | Commands::Statusline(_)
+            | Commands::Summary(_)
+            | Commands::Completions(_),
+        ) => true,
+        Some(Commands::Warm(args)) => args.background,
+        _ => false,
+    };
+    if !fast_path {
+        caut::storage::remote_config::refresh_configured().await;
+    }
+    // `caut config validate` reports unknown keys itself. JSON output
+    // carries them in its `warnings` array instead of on stderr.
+    if !fast_path && !matches!(cli.command, Some(Commands::Config(_))) {
+        for key in caut::storage::Config::unknown_keys().unwrap_or_default() {
+            if format == OutputFormat::Json {
+                caut::core::models::add_run_warning(key.to_string());
+            } else {
+                eprintln!("Warning: {key}");
+            }
+        }
+    }
+
     // Execute command
     let result = run(cli).await;
 
@@ -65,19 +122,40 @@ async fn main() -> ExitCode {
         Err(e) => {
             tracing::error!("{}", e);
             // Use rich error rendering (respects format, no_color, TTY, and pretty)
-            let error_output = caut::render::error::render_error_full(&e, format, no_color, pretty);
+            let error_output = caut::render::error::render_command_error(
+                &e,
+                Some(&command),
+                format,
+                no_color,
+                pretty,
+            );
             eprintln!("{error_output}");
+            // Internal errors are bugs; keep a redacted record to report.
+            if let Some(record) = caut::core::bug_report::CrashRecord::capture(&e, &raw) {
+                let saved = record.save(&caut::storage::AppPaths::new().last_error_file());
+                if saved.is_ok() && format == OutputFormat::Human {
+                    eprintln!(
+                        "\nThis looks like a bug in caut. Run `caut report-bug` to draft a GitHub issue."
+                    );
+                }
+            }
             ExitCode::from(e.exit_code() as u8)
         }
     }
 }
 
 async fn run(cli: Cli) -> caut::Result<()> {
+    caut::cli::output::OutputFile::begin_active(cli.output.as_deref(), cli.command.as_ref())?;
+    let result = run_command(cli).await;
+    caut::cli::output::finish(result)
+}
+
+async fn run_command(mut cli: Cli) -> caut::Result<()> {
     let format = cli.effective_format();
     let pretty = cli.pretty;
     let no_color = cli.no_color || !caut::util::env::should_use_color(cli.no_color);
 
-    match cli.command {
+    match cli.command.take() {
         // Default to usage command
         None => {
             print_quickstart();
@@ -94,18 +172,32 @@ async fn run(cli: Cli) -> caut::Result<()> {
 
         Some(Commands::TokenAccounts(cmd)) => handle_token_accounts(cmd),
 
+        Some(Commands::Accounts(cmd)) => caut::cli::accounts::execute(&cmd, format, pretty),
+
         Some(Commands::Doctor(args)) => {
             caut::cli::doctor::execute(&args, format, pretty, no_color).await
         }
 
         Some(Commands::History(cmd)) => caut::cli::history::execute(&cmd, format, pretty, no_color),
 
+        Some(Commands::Prune(args)) => caut::cli::prune::execute(&args, format, pretty),
+
+        Some(Commands::Snapshot(args)) => caut::cli::snapshot::execute(&args, format, pretty).await,
+
         Some(Commands::Prompt(args)) => caut::cli::prompt::execute(&args),
 
+        Some(Commands::Summary(args)) => caut::cli::summary::execute(&args, format),
+
+        Some(Commands::Statusline(cmd)) => caut::cli::statusline::execute(&cmd, pretty),
+
         Some(Commands::Session(args)) => {
             caut::cli::session::execute(&args, format, pretty, no_color).await
         }
 
+        Some(Commands::Sessions(cmd)) => caut::cli::sessions::execute(&cmd, format, pretty),
+
+        Some(Commands::Top(args)) => caut::cli::top::execute(&args, format, pretty).await,
+
         Some(Commands::Dashboard(args)) => {
             let usage_args = args.to_usage_args();
             caut::tui::run_dashboard(&usage_args, args.interval).await
@@ -114,9 +206,68 @@ async fn run(cli: Cli) -> caut::Result<()> {
         Some(Commands::Serve(args)) => caut::cli::serve::execute(&args).await,
 
         Some(Commands::Query(args)) => caut::cli::query::execute(&args, pretty).await,
+
+        Some(Commands::Simulate(args)) => {
+            caut::cli::simulate::execute(&args, format, pretty, no_color)
+        }
+
+        Some(Commands::Estimate(args)) => caut::cli::estimate::execute(&args, format, pretty),
+
+        Some(Commands::Project(args)) => caut::cli::project::execute(&args, format, pretty),
+
+        Some(Commands::Forecast(args)) => caut::cli::forecast::execute(&args, format, pretty),
+
+        Some(Commands::Org(cmd)) => caut::cli::org::execute(&cmd, format, pretty).await,
+
+        Some(Commands::Repl(args)) => caut::cli::repl::execute(&args, dispatch).await,
+
+        Some(Commands::Render(args)) => caut::cli::render::execute(&args, format, pretty, no_color),
+
+        Some(Commands::Merge(args)) => caut::cli::merge::execute(&args, format, pretty, no_color),
+
+        Some(Commands::Value(args)) => caut::cli::value::execute(&args, format, pretty),
+
+        Some(Commands::Import(args)) => caut::cli::import::execute(&args, format, pretty).await,
+
+        Some(Commands::Export(args)) => caut::cli::export::execute(&args),
+
+        Some(Commands::Budget(cmd)) => {
+            caut::cli::budget::execute(&cmd, format, pretty, no_color).await
+        }
+
+        Some(Commands::Alert(cmd)) => caut::cli::alert::execute(&cmd, format, pretty),
+
+        Some(Commands::Notify(args)) => caut::cli::notify::execute(&args, format, pretty).await,
+
+        Some(Commands::ReportBug(args)) => caut::cli::report_bug::execute(&args, format, pretty),
+
+        Some(Commands::Login(args)) => caut::cli::login::execute_login(&args, format, pretty).await,
+
+        Some(Commands::Logout(args)) => caut::cli::login::execute_logout(&args, format, pretty),
+
+        Some(Commands::Config(cmd)) => caut::cli::config::execute(&cmd, &cli, format, pretty),
+
+        Some(Commands::Warm(args)) => caut::cli::warm::execute(&args).await,
+
+        Some(Commands::Daemon(args)) => caut::cli::daemon::execute(&args).await,
+
+        Some(Commands::Report(args)) => caut::cli::report::execute(&args, format, pretty).await,
+
+        Some(Commands::Compare(args)) => caut::cli::compare::execute(&args, format, pretty).await,
+
+        Some(Commands::Diff(args)) => caut::cli::diff::execute(&args, format, pretty),
+
+        Some(Commands::Troubleshoot(args)) => caut::cli::troubleshoot::execute(&args).await,
+
+        Some(Commands::Completions(args)) => caut::cli::completions::execute(&args),
     }
 }
 
+/// Command dispatch for lines entered in `caut repl`.
+fn dispatch(cli: Cli) -> futures::future::LocalBoxFuture<'static, caut::Result<()>> {
+    Box::pin(run(cli))
+}
+
 #[allow(clippy::too_many_lines)]
 fn handle_token_accounts(cmd: caut::cli::args::TokenAccountsCommand) -> caut::Result<()> {
     use caut::cli::args::TokenAccountsCommand;
@@ -127,7 +278,7 @@ fn handle_token_accounts(cmd: caut::cli::args::TokenAccountsCommand) -> caut::Re
     let paths = AppPaths::new();
 
     match cmd {
-        TokenAccountsCommand::List { provider } => {
+        TokenAccountsCommand::List { provider, full } => {
             let store = TokenAccountStore::load(&paths.token_accounts_file())?;
 
             // If a provider is specified, list only that provider's accounts
@@ -139,12 +290,7 @@ fn handle_token_accounts(cmd: caut::cli::args::TokenAccountsCommand) -> caut::Re
                     println!("No accounts configured for provider: {provider_name}");
                 } else {
                     println!("Accounts for {}:", provider.display_name());
-                    println!("{:<20} {:<40} Added", "Label", "ID");
-                    println!("{:-<20} {:-<40} {:-<20}", "", "", "");
-                    for account in accounts {
-                        let added = account.added_at.format("%Y-%m-%d %H:%M");
-                        println!("{:<20} {:<40} {}", account.label, account.id, added);
-                    }
+                    print_accounts_table(&accounts, full);
                 }
             } else {
                 // List accounts for all providers
@@ -154,12 +300,7 @@ fn handle_token_accounts(cmd: caut::cli::args::TokenAccountsCommand) -> caut::Re
                     if !accounts.is_empty() {
                         found_any = true;
                         println!("\n{}:", provider.display_name());
-                        println!("{:<20} {:<40} Added", "Label", "ID");
-                        println!("{:-<20} {:-<40} {:-<20}", "", "", "");
-                        for account in accounts {
-                            let added = account.added_at.format("%Y-%m-%d %H:%M");
-                            println!("{:<20} {:<40} {}", account.label, account.id, added);
-                        }
+                        print_accounts_table(&accounts, full);
                     }
                 }
                 if !found_any {
@@ -243,6 +384,44 @@ fn handle_token_accounts(cmd: caut::cli::args::TokenAccountsCommand) -> caut::Re
     }
 }
 
+/// Column widths for the token accounts table.
+const ACCOUNT_LABEL_WIDTH: usize = 20;
+const ACCOUNT_ID_WIDTH: usize = 40;
+
+/// Print a token accounts table, eliding the middle of long values unless `full`.
+fn print_accounts_table(accounts: &[&caut::storage::token_accounts::TokenAccount], full: bool) {
+    use caut::util::format::{pad_to_width, truncate_middle};
+
+    let cell = |value: &str, width: usize| {
+        let value = if full {
+            value.to_string()
+        } else {
+            truncate_middle(value, width)
+        };
+        pad_to_width(&value, width)
+    };
+
+    println!(
+        "{} {} Added",
+        pad_to_width("Label", ACCOUNT_LABEL_WIDTH),
+        pad_to_width("ID", ACCOUNT_ID_WIDTH)
+    );
+    println!(
+        "{} {} {}",
+        "-".repeat(ACCOUNT_LABEL_WIDTH),
+        "-".repeat(ACCOUNT_ID_WIDTH),
+        "-".repeat(20)
+    );
+    for account in accounts {
+        let added = account.added_at.format("%Y-%m-%d %H:%M");
+        println!(
+            "{} {} {added}",
+            cell(&account.label, ACCOUNT_LABEL_WIDTH),
+            cell(&account.id, ACCOUNT_ID_WIDTH)
+        );
+    }
+}
+
 /// Print quickstart help when no command is given.
 fn print_quickstart() {
     println!(
@@ -264,6 +443,9 @@ COMMANDS:
     token-accounts  Manage token accounts
     doctor          Diagnose caut setup and provider health
     prompt          Output usage for shell prompt integration
+    statusline      Output usage for editor and launcher statuslines
+    simulate        Render synthetic usage to test thresholds and budgets
+    repl            Interactive shell with history and tab-completion
 
 QUICK START:
     caut usage                    # Show usage for primary providers
@@ -274,16 +456,21 @@ QUICK START:
     caut session                  # Show last session cost attribution
     caut session --list           # List recent sessions with costs
     caut doctor                   # Check setup and provider health
+    caut simulate --primary 95    # Preview alerts at 95% session usage
 
 SHELL PROMPT INTEGRATION:
     caut prompt                   # Output for shell prompt (fast, cached)
     caut prompt --install bash    # Generate bash integration snippet
+    caut statusline lsp-json      # JSON text + severity for editor statusbars
+    caut statusline raycast       # Raycast list items with quick actions
 
 BACKGROUND SERVER (for plugins and scripts):
     caut serve                    # Start HTTP server on localhost:19485
     caut serve --port 8080        # Custom port
     caut query usage              # Query cached usage data (JSON)
     caut query cost               # Query cost data (JSON)
+    caut query doctor             # Run health checks (JSON)
+    caut query history            # Recorded usage snapshots (JSON)
     caut query health             # Check server health
 
 ROBOT MODE (for AI agents):
@@ -0,0 +1,101 @@
+//! Aider provider implementation.
+//!
+//! Supports:
+//! - Local chat history probe
+//!
+//! Source label: `local`
+//!
+//! Aider talks to whichever model API it is configured with and has no usage
+//! service of its own, so there are no windows to report. It does print the
+//! tokens and cost of every reply into `.aider.chat.history.md` in the
+//! repository it runs in (and into its `--analytics-log`, when enabled);
+//! `caut cost --provider aider` totals those through
+//! [`AiderSessionParser`](crate::core::session_logs::AiderSessionParser).
+//! The usage fetch only confirms that logs exist and when they last changed.
+//!
+//! Set `CAUT_AIDER_ROOTS` to the directories holding your repositories, or
+//! `CAUT_AIDER_ANALYTICS_LOG` to read the analytics log instead.
+
+use chrono::Utc;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::models::UsageSnapshot;
+use crate::core::provider::Provider;
+use crate::core::session_logs::{SessionLogFinder, SessionLogPath};
+use crate::error::{CautError, Result};
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Aider.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Aider,
+        vec![FetchStrategy {
+            id: "aider-local",
+            kind: FetchKind::LocalProbe,
+            is_available: || newest_log().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+/// Most recently written Aider log.
+fn newest_log() -> Option<SessionLogPath> {
+    SessionLogFinder::new()
+        .ok()?
+        .find_sessions(Provider::Aider, None, None)
+        .into_iter()
+        .next()
+}
+
+/// Confirm Aider logs exist and report when they were last written.
+///
+/// # Errors
+/// Returns an error if no chat history or analytics log can be found.
+pub async fn fetch_local() -> Result<UsageSnapshot> {
+    let log = newest_log().ok_or_else(|| {
+        CautError::Config(
+            "No Aider chat history found; set CAUT_AIDER_ROOTS to the directories holding your repositories"
+                .to_string(),
+        )
+    })?;
+    tracing::debug!(path = ?log.path, "Found Aider log");
+
+    Ok(UsageSnapshot {
+        primary: None,
+        secondary: None,
+        tertiary: None,
+        updated_at: log.modified_at.unwrap_or_else(Utc::now),
+        identity: None,
+    })
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Aider.
+pub struct AiderFetcher;
+
+impl ProviderFetcher for AiderFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Aider
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "aider-local" => fetch_local().await,
+                _ => Err(unknown_strategy(Provider::Aider, strategy_id)),
+            }
+        })
+    }
+}
@@ -0,0 +1,414 @@
+//! Amazon Q Developer provider implementation.
+//!
+//! Supports:
+//! - Q Developer usage limits API (monthly agentic request allowance)
+//!
+//! Source label: `api`
+//!
+//! The `q` CLI signs in with AWS Builder ID or IAM Identity Center and keeps
+//! the SSO access token in its data database (`amazon-q/data.sqlite3` under
+//! the platform data directory, table `auth_kv`). The IDE plugins and the AWS
+//! CLI write the same kind of token to `~/.aws/sso/cache/*.json`, which is
+//! used when the `q` database has none. caut sends the token to the usage
+//! limits endpoint the CLI uses; the monthly agentic request allowance is the
+//! primary window.
+//!
+//! Set `CAUT_AMAZON_Q_DATA_DB` to read a different `q` database. Tokens are
+//! not refreshed; run `q login` when the sign-in expires.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, TimeZone, Utc};
+use rusqlite::{Connection, OpenFlags};
+use serde::Deserialize;
+
+use crate::core::clock_skew;
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::core::models::{ProviderIdentity, RateWindow, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+
+/// Environment variable overriding the `q` data database path.
+pub const DATA_DB_ENV: &str = "CAUT_AMAZON_Q_DATA_DB";
+
+/// Key of the SSO token in the `q` database's `auth_kv` table.
+const TOKEN_KEY: &str = "codewhisperer:odic:token";
+
+/// Start URL of AWS Builder ID sign-ins.
+const BUILDER_ID_START_URL: &str = "https://view.awsapps.com/start";
+
+/// Region used when a token does not name one.
+const DEFAULT_REGION: &str = "us-east-1";
+
+/// Usage resource counted against the monthly allowance.
+const AGENTIC_REQUEST: &str = "AGENTIC_REQUEST";
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Amazon Q Developer.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::AmazonQ,
+        vec![FetchStrategy {
+            id: "amazonq-api",
+            kind: FetchKind::ApiToken,
+            is_available: || find_token().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// Local Credentials
+// =============================================================================
+
+/// Cached SSO access token.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SsoToken {
+    #[serde(alias = "accessToken")]
+    pub access_token: String,
+    #[serde(default, alias = "expiresAt")]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub region: Option<String>,
+    #[serde(default, alias = "startUrl")]
+    pub start_url: Option<String>,
+}
+
+impl SsoToken {
+    /// Whether the token has passed its expiry time.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|at| at <= Utc::now())
+    }
+
+    /// `builder-id` or `identity-center`, from the sign-in start URL.
+    #[must_use]
+    pub fn login_method(&self) -> &'static str {
+        match self.start_url.as_deref() {
+            Some(url) if url.trim_end_matches('/') != BUILDER_ID_START_URL => "identity-center",
+            _ => "builder-id",
+        }
+    }
+
+    fn region(&self) -> &str {
+        self.region.as_deref().unwrap_or(DEFAULT_REGION)
+    }
+}
+
+fn data_db_path() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var(DATA_DB_ENV)
+        && !path.trim().is_empty()
+    {
+        return Some(PathBuf::from(path.trim()));
+    }
+    directories::BaseDirs::new().map(|d| d.data_local_dir().join("amazon-q/data.sqlite3"))
+}
+
+fn read_data_db(path: &Path) -> Option<SsoToken> {
+    let conn = Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .ok()?;
+    let raw: String = conn
+        .query_row(
+            "SELECT CAST(value AS TEXT) FROM auth_kv WHERE key = ?1",
+            [TOKEN_KEY],
+            |row| row.get(0),
+        )
+        .ok()?;
+    serde_json::from_str(&raw)
+        .map_err(|e| tracing::debug!(path = %path.display(), "Invalid Amazon Q token: {}", e))
+        .ok()
+}
+
+/// Newest token in the AWS SSO cache that carries an access token.
+fn read_sso_cache(dir: &Path) -> Option<SsoToken> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| {
+            let content = std::fs::read_to_string(entry.path()).ok()?;
+            serde_json::from_str::<SsoToken>(&content).ok()
+        })
+        .max_by_key(|token| token.expires_at)
+}
+
+/// The signed-in SSO token, from the `q` database or the AWS SSO cache.
+#[must_use]
+pub fn find_token() -> Option<SsoToken> {
+    data_db_path()
+        .filter(|path| path.exists())
+        .and_then(|path| read_data_db(&path))
+        .or_else(|| {
+            let home = directories::BaseDirs::new()?.home_dir().to_path_buf();
+            read_sso_cache(&home.join(".aws/sso/cache"))
+        })
+}
+
+fn require_token() -> Result<SsoToken> {
+    let token = find_token()
+        .ok_or_else(|| CautError::Config("Not signed in to Amazon Q (run: q login)".to_string()))?;
+    if token.is_expired() {
+        return Err(CautError::Config(
+            "Amazon Q sign-in has expired (run: q login)".to_string(),
+        ));
+    }
+    Ok(token)
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UsageLimitsResponse {
+    #[serde(default, alias = "usageBreakdownList")]
+    limits: Vec<UsageLimit>,
+    /// Epoch seconds.
+    #[serde(default)]
+    next_date_reset: Option<f64>,
+    #[serde(default)]
+    subscription_info: Option<SubscriptionInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UsageLimit {
+    #[serde(alias = "resourceType", rename = "type")]
+    kind: String,
+    #[serde(default)]
+    current_usage: i64,
+    #[serde(default, alias = "usageLimit")]
+    total_usage_limit: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SubscriptionInfo {
+    #[serde(default)]
+    subscription_title: Option<String>,
+}
+
+/// Fetch usage from the Q Developer usage limits API.
+///
+/// # Errors
+/// Returns an error if there is no valid sign-in, the request fails, or the
+/// response cannot be parsed.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    let token = require_token()?;
+    let mut url = reqwest::Url::parse(&format!(
+        "https://q.{}.amazonaws.com/getUsageLimits",
+        token.region()
+    ))
+    .map_err(|e| CautError::Config(format!("Invalid Amazon Q region: {e}")))?;
+    url.query_pairs_mut()
+        .append_pair("origin", "CLI")
+        .append_pair("resourceType", AGENTIC_REQUEST);
+
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let response = client
+        .get(url)
+        .bearer_auth(&token.access_token)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+            } else {
+                CautError::Network(e.to_string())
+            }
+        })?;
+    clock_skew::observe(&response);
+
+    let status = response.status();
+    if !status.is_success() {
+        let reason = if matches!(status.as_u16(), 401 | 403) {
+            "sign-in rejected; run: q login".to_string()
+        } else {
+            format!("usage limits request returned {status}")
+        };
+        return Err(CautError::FetchFailed {
+            provider: Provider::AmazonQ.cli_name().to_string(),
+            reason,
+        });
+    }
+
+    let body: UsageLimitsResponse = response
+        .json()
+        .await
+        .map_err(|e| CautError::ParseResponse(format!("Invalid Amazon Q usage limits: {e}")))?;
+    Ok(usage_from_limits(body, &token))
+}
+
+#[allow(clippy::cast_precision_loss)] // request counts are small
+#[allow(clippy::cast_possible_truncation)] // epoch seconds fit in i64
+fn usage_from_limits(body: UsageLimitsResponse, token: &SsoToken) -> UsageSnapshot {
+    let resets_at = body
+        .next_date_reset
+        .and_then(|secs| Utc.timestamp_opt(secs as i64, 0).single());
+    let primary = body
+        .limits
+        .iter()
+        .find(|limit| limit.kind == AGENTIC_REQUEST && limit.total_usage_limit > 0)
+        .map(|limit| RateWindow {
+            used_percent: (limit.current_usage as f64 / limit.total_usage_limit as f64 * 100.0)
+                .clamp(0.0, 100.0),
+            window_minutes: None,
+            resets_at,
+            reset_description: Some(format!(
+                "{}/{} requests this month",
+                limit.current_usage, limit.total_usage_limit
+            )),
+        });
+
+    UsageSnapshot {
+        primary,
+        secondary: None,
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: Some(ProviderIdentity {
+            account_email: None,
+            account_organization: body.subscription_info.and_then(|s| s.subscription_title),
+            login_method: Some(token.login_method().to_string()),
+        }),
+    }
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Amazon Q Developer.
+pub struct AmazonQFetcher;
+
+impl ProviderFetcher for AmazonQFetcher {
+    fn provider(&self) -> Provider {
+        Provider::AmazonQ
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "amazonq-api" => fetch_api().await,
+                _ => Err(unknown_strategy(Provider::AmazonQ, strategy_id)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn token(start_url: &str) -> SsoToken {
+        SsoToken {
+            access_token: "aoa-token".to_string(),
+            expires_at: None,
+            region: None,
+            start_url: Some(start_url.to_string()),
+        }
+    }
+
+    #[test]
+    fn maps_agentic_request_allowance() {
+        let body: UsageLimitsResponse = serde_json::from_str(
+            r#"{
+                "limits": [
+                    {"type": "CODE_COMPLETIONS", "currentUsage": 900, "totalUsageLimit": 0},
+                    {"type": "AGENTIC_REQUEST", "currentUsage": 12, "totalUsageLimit": 50}
+                ],
+                "nextDateReset": 1793491200.0,
+                "subscriptionInfo": {"subscriptionTitle": "Amazon Q Developer Free"}
+            }"#,
+        )
+        .unwrap();
+
+        let usage = usage_from_limits(body, &token(BUILDER_ID_START_URL));
+        let window = usage.primary.unwrap();
+        assert!((window.used_percent - 24.0).abs() < f64::EPSILON);
+        assert_eq!(window.resets_at.unwrap().timestamp(), 1_793_491_200);
+        assert_eq!(
+            window.reset_description.as_deref(),
+            Some("12/50 requests this month")
+        );
+        let identity = usage.identity.unwrap();
+        assert_eq!(identity.login_method.as_deref(), Some("builder-id"));
+        assert_eq!(
+            identity.account_organization.as_deref(),
+            Some("Amazon Q Developer Free")
+        );
+    }
+
+    #[test]
+    fn accepts_usage_breakdown_list() {
+        let body: UsageLimitsResponse = serde_json::from_str(
+            r#"{"usageBreakdownList": [
+                {"resourceType": "AGENTIC_REQUEST", "currentUsage": 100, "usageLimit": 1000}
+            ]}"#,
+        )
+        .unwrap();
+
+        let usage = usage_from_limits(body, &token("https://example.awsapps.com/start"));
+        assert!((usage.primary.unwrap().used_percent - 10.0).abs() < f64::EPSILON);
+        assert_eq!(
+            usage.identity.unwrap().login_method.as_deref(),
+            Some("identity-center")
+        );
+    }
+
+    #[test]
+    fn reads_token_from_q_database_and_sso_cache() {
+        let dir = TempDir::new().unwrap();
+        let db = dir.path().join("data.sqlite3");
+        let conn = Connection::open(&db).unwrap();
+        conn.execute(
+            "CREATE TABLE auth_kv (key TEXT PRIMARY KEY, value TEXT)",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO auth_kv (key, value) VALUES (?1, ?2)",
+            [
+                TOKEN_KEY,
+                r#"{"access_token": "from-q", "expires_at": "2020-01-01T00:00:00Z", "region": "eu-central-1"}"#,
+            ],
+        )
+        .unwrap();
+        let from_db = read_data_db(&db).unwrap();
+        assert_eq!(from_db.access_token, "from-q");
+        assert_eq!(from_db.region(), "eu-central-1");
+        assert!(from_db.is_expired());
+
+        let cache = dir.path().join("sso");
+        std::fs::create_dir(&cache).unwrap();
+        std::fs::write(
+            cache.join("a.json"),
+            r#"{"accessToken": "older", "expiresAt": "2026-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            cache.join("b.json"),
+            r#"{"accessToken": "newer", "expiresAt": "2099-01-01T00:00:00Z", "startUrl": "https://view.awsapps.com/start/"}"#,
+        )
+        .unwrap();
+        std::fs::write(cache.join("client.json"), r#"{"clientId": "x"}"#).unwrap();
+        let from_cache = read_sso_cache(&cache).unwrap();
+        assert_eq!(from_cache.access_token, "newer");
+        assert_eq!(from_cache.login_method(), "builder-id");
+        assert!(!from_cache.is_expired());
+    }
+}
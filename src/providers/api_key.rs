@@ -0,0 +1,459 @@
+//! Generic API-key provider.
+//!
+//! Long-tail inference providers differ only in where the key comes from,
+//! how it is sent, which endpoint to probe, and which headers carry the
+//! limits. Describe those with the const builder on [`ApiKeyProvider`] and
+//! key lookup, authentication, rate-limit parsing, and JSON decoding are
+//! shared:
+//!
+//! ```ignore
+//! const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::Groq)
+//!     .env("GROQ_API_KEY")
+//!     .probe("https://api.groq.com/openai/v1/models")
+//!     .primary(TOKENS_PER_MINUTE)
+//!     .secondary(REQUESTS_PER_DAY);
+//! ```
+//!
+//! Keys are resolved in order: `--api-key <provider>=<key>`, the provider's
+//! environment variable, the system keyring (`caut` / `<provider>-api-key`),
+//! then `api_key` in the provider's config section, or the secret store its
+//! `api_key_ref` points at (see [`crate::storage::secrets`]).
+//!
+//! Such a provider module only needs a `fetch_plan` with its one API
+//! strategy and an async `fetch_api`; [`ApiKeyFetcher`] turns the pair into
+//! its registry entry, optionally with an `enrich` for details beyond the
+//! rate windows.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{LazyLock, RwLock};
+
+use chrono::Utc;
+use reqwest::header::HeaderMap;
+use serde::de::DeserializeOwned;
+
+use crate::core::clock_skew;
+use crate::core::fetch_plan::FetchPlan;
+use crate::core::fetcher::{EnrichFuture, FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::core::models::{ProviderPayload, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::providers::rate_limits::{HeaderLimit, window_from_headers};
+use crate::storage::config::Config;
+use crate::storage::secrets;
+
+/// Keyring service holding caut's stored API keys.
+const KEYRING_SERVICE: &str = "caut";
+
+/// Keys passed on the command line for this run.
+static FLAG_KEYS: LazyLock<RwLock<HashMap<Provider, String>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Where an API key was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeySource {
+    Flag,
+    Env,
+    Keyring,
+    Config,
+}
+
+impl KeySource {
+    /// Short description for diagnostics (e.g. "environment").
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Flag => "--api-key",
+            Self::Env => "environment",
+            Self::Keyring => "keyring",
+            Self::Config => "config",
+        }
+    }
+}
+
+/// How the API key is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthStyle {
+    /// `Authorization: Bearer <key>`.
+    Bearer,
+    /// The key as the value of a named header (e.g. `x-api-key`).
+    Header(&'static str),
+}
+
+/// Description of a provider authenticated with an API key.
+#[derive(Debug, Clone, Copy)]
+pub struct ApiKeyProvider {
+    pub provider: Provider,
+    /// Environment variable holding the API key.
+    pub env_var: Option<&'static str>,
+    pub auth: AuthStyle,
+    /// Cheap authenticated endpoint whose response carries the limit headers.
+    pub probe_url: Option<&'static str>,
+    /// Limit mapped to the primary window.
+    pub primary: Option<HeaderLimit>,
+    /// Limit mapped to the secondary window.
+    pub secondary: Option<HeaderLimit>,
+}
+
+impl ApiKeyProvider {
+    /// Start describing a provider (bearer auth, no env var or limits).
+    #[must_use]
+    pub const fn new(provider: Provider) -> Self {
+        Self {
+            provider,
+            env_var: None,
+            auth: AuthStyle::Bearer,
+            probe_url: None,
+            primary: None,
+            secondary: None,
+        }
+    }
+
+    /// Read the key from this environment variable.
+    #[must_use]
+    pub const fn env(mut self, var: &'static str) -> Self {
+        self.env_var = Some(var);
+        self
+    }
+
+    /// Send the key in this header instead of as a bearer token.
+    #[must_use]
+    pub const fn header_auth(mut self, header: &'static str) -> Self {
+        self.auth = AuthStyle::Header(header);
+        self
+    }
+
+    /// Endpoint to request when reading rate-limit headers.
+    #[must_use]
+    pub const fn probe(mut self, url: &'static str) -> Self {
+        self.probe_url = Some(url);
+        self
+    }
+
+    /// Limit shown as the primary window.
+    #[must_use]
+    pub const fn primary(mut self, limit: HeaderLimit) -> Self {
+        self.primary = Some(limit);
+        self
+    }
+
+    /// Limit shown as the secondary window.
+    #[must_use]
+    pub const fn secondary(mut self, limit: HeaderLimit) -> Self {
+        self.secondary = Some(limit);
+        self
+    }
+
+    /// Keyring account name for this provider's key.
+    fn keyring_account(&self) -> String {
+        format!("{}-api-key", self.provider.cli_name())
+    }
+
+    /// Resolve the API key and where it came from.
+    #[must_use]
+    pub fn resolve_key(&self) -> Option<(String, KeySource)> {
+        let tagged = |key: Option<String>, source| key.map(|k| (k, source));
+
+        tagged(flag_key(self.provider), KeySource::Flag)
+            .or_else(|| tagged(self.env_var.and_then(env_key), KeySource::Env))
+            .or_else(|| tagged(self.keyring_key(), KeySource::Keyring))
+            .or_else(|| tagged(self.config_key(), KeySource::Config))
+    }
+
+    /// Resolve the API key, if one is configured.
+    #[must_use]
+    pub fn api_key(&self) -> Option<String> {
+        self.resolve_key().map(|(key, _)| key)
+    }
+
+    fn keyring_key(&self) -> Option<String> {
+        let entry = keyring::Entry::new(KEYRING_SERVICE, &self.keyring_account()).ok()?;
+        non_empty(entry.get_password().ok()?)
+    }
+
+    fn config_key(&self) -> Option<String> {
+        let config = Config::load().ok()?;
+        let settings = config.providers.get_settings(self.provider.cli_name());
+        if let Some(key) = settings.api_key.and_then(non_empty) {
+            return Some(key);
+        }
+        let reference = settings.api_key_ref?;
+        secrets::resolve(&reference)
+            .map_err(|e| tracing::warn!(provider = %self.provider.cli_name(), "{}", e))
+            .ok()
+    }
+
+    fn require_key(&self) -> Result<String> {
+        self.api_key().ok_or_else(|| {
+            let name = self.provider.display_name();
+            CautError::Config(match self.env_var {
+                Some(var) => format!("No {name} API key found (set {var} or use --api-key)"),
+                None => format!("No {name} API key found (use --api-key)"),
+            })
+        })
+    }
+
+    /// Send an authenticated GET, failing on a non-success status.
+    async fn get(&self, url: &str) -> Result<reqwest::Response> {
+        let key = self.require_key()?;
+        let client = build_client(DEFAULT_TIMEOUT)?;
+        let request = client.get(url).header("Accept", "application/json");
+        let request = match self.auth {
+            AuthStyle::Bearer => request.bearer_auth(&key),
+            AuthStyle::Header(name) => request.header(name, &key),
+        };
+
+        let response = request.send().await.map_err(|e| {
+            if e.is_timeout() {
+                CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+            } else {
+                CautError::Network(e.to_string())
+            }
+        })?;
+        clock_skew::observe(&response);
+
+        let status = response.status();
+        if !status.is_success() {
+            let reason = if status.as_u16() == 401 {
+                "API key was rejected".to_string()
+            } else {
+                format!("request returned {status}")
+            };
+            return Err(CautError::FetchFailed {
+                provider: self.provider.cli_name().to_string(),
+                reason,
+            });
+        }
+        Ok(response)
+    }
+
+    /// Fetch and decode a JSON endpoint with the provider's key.
+    ///
+    /// # Errors
+    /// Returns an error if no API key is set, the request fails, or the body
+    /// does not decode as `T`.
+    pub async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let name = self.provider.display_name();
+        self.get(url)
+            .await?
+            .json()
+            .await
+            .map_err(|e| CautError::ParseResponse(format!("Invalid {name} response: {e}")))
+    }
+
+    /// Probe the API and map its rate-limit headers into a usage snapshot.
+    ///
+    /// # Errors
+    /// Returns an error if no API key is set, the request fails, or the
+    /// response carries none of the configured headers.
+    pub async fn fetch_usage(&self) -> Result<UsageSnapshot> {
+        let name = self.provider.display_name();
+        let url = self.probe_url.ok_or_else(|| CautError::FetchFailed {
+            provider: self.provider.cli_name().to_string(),
+            reason: "no probe endpoint configured".to_string(),
+        })?;
+        let headers = self.get(url).await?.headers().clone();
+
+        self.usage_from_headers(&headers).ok_or_else(|| {
+            CautError::ParseResponse(format!("{name} response had no rate-limit headers"))
+        })
+    }
+
+    /// Map configured rate-limit headers into a usage snapshot.
+    ///
+    /// Returns `None` when none of the configured limits are present.
+    #[must_use]
+    pub fn usage_from_headers(&self, headers: &HeaderMap) -> Option<UsageSnapshot> {
+        // Relative reset headers count from the server's clock.
+        let now = clock_skew::now();
+        let window = |limit: Option<HeaderLimit>| {
+            limit.and_then(|spec| window_from_headers(headers, &spec, now))
+        };
+        let primary = window(self.primary);
+        let secondary = window(self.secondary);
+        if primary.is_none() && secondary.is_none() {
+            return None;
+        }
+
+        Some(UsageSnapshot {
+            primary,
+            secondary,
+            tertiary: None,
+            updated_at: Utc::now(),
+            identity: None,
+        })
+    }
+}
+
+/// Registry entry for a provider fetched through its API alone: every
+/// strategy in `plan` runs `fetch`.
+pub struct ApiKeyFetcher<F> {
+    provider: Provider,
+    plan: fn() -> FetchPlan,
+    fetch: fn() -> F,
+    enrich: Option<fn(&mut ProviderPayload) -> EnrichFuture<'_>>,
+}
+
+impl<F> ApiKeyFetcher<F> {
+    /// Pair a provider's fetch plan with its API fetch.
+    #[must_use]
+    pub fn new(plan: fn() -> FetchPlan, fetch: fn() -> F) -> Self {
+        Self {
+            provider: plan().provider,
+            plan,
+            fetch,
+            enrich: None,
+        }
+    }
+
+    /// Use `enrich` as the fetcher's [`ProviderFetcher::enrich`].
+    #[must_use]
+    pub fn with_enrichment(mut self, enrich: fn(&mut ProviderPayload) -> EnrichFuture<'_>) -> Self {
+        self.enrich = Some(enrich);
+        self
+    }
+}
+
+impl<F> ProviderFetcher for ApiKeyFetcher<F>
+where
+    F: Future<Output = Result<UsageSnapshot>> + Send + 'static,
+{
+    fn provider(&self) -> Provider {
+        self.provider
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        (self.plan)()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        let known = self
+            .fetch_plan()
+            .strategies
+            .iter()
+            .any(|strategy| strategy.id == strategy_id);
+        Box::pin(async move {
+            if known {
+                (self.fetch)().await
+            } else {
+                Err(unknown_strategy(self.provider, strategy_id))
+            }
+        })
+    }
+
+    fn enrich<'a>(&'a self, payload: &'a mut ProviderPayload) -> EnrichFuture<'a> {
+        match self.enrich {
+            Some(enrich) => enrich(payload),
+            None => Box::pin(std::future::ready(())),
+        }
+    }
+}
+
+/// Record keys passed with `--api-key` for the rest of the run.
+///
+/// # Panics
+/// Panics if the key table lock is poisoned.
+pub fn set_flag_keys(keys: impl IntoIterator<Item = (Provider, String)>) {
+    let mut table = FLAG_KEYS.write().expect("api key table lock");
+    table.extend(keys);
+}
+
+fn flag_key(provider: Provider) -> Option<String> {
+    FLAG_KEYS.read().ok()?.get(&provider).cloned()
+}
+
+/// Read a non-empty API key from an environment variable.
+fn env_key(var: &str) -> Option<String> {
+    non_empty(std::env::var(var).ok()?)
+}
+
+fn non_empty(key: String) -> Option<String> {
+    let key = key.trim();
+    (!key.is_empty()).then(|| key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::RateWindow;
+    use reqwest::header::HeaderValue;
+
+    const REQUESTS: HeaderLimit = HeaderLimit {
+        limit: "x-ratelimit-limit-requests",
+        remaining: "x-ratelimit-remaining-requests",
+        reset: "x-ratelimit-reset-requests",
+        window_minutes: Some(1),
+        unit: "req/min",
+    };
+
+    const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::Fireworks)
+        .header_auth("x-api-key")
+        .probe("https://example.invalid/models")
+        .primary(REQUESTS);
+
+    #[test]
+    fn builder_sets_fields() {
+        assert_eq!(SPEC.auth, AuthStyle::Header("x-api-key"));
+        assert_eq!(SPEC.probe_url, Some("https://example.invalid/models"));
+        assert!(SPEC.env_var.is_none());
+        assert!(SPEC.secondary.is_none());
+    }
+
+    #[test]
+    fn flag_key_takes_precedence() {
+        set_flag_keys([(Provider::Fireworks, "from-flag".to_string())]);
+        assert_eq!(
+            SPEC.resolve_key(),
+            Some(("from-flag".to_string(), KeySource::Flag))
+        );
+    }
+
+    #[test]
+    fn maps_configured_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUESTS.limit, HeaderValue::from_static("600"));
+        headers.insert(REQUESTS.remaining, HeaderValue::from_static("450"));
+
+        let usage = SPEC.usage_from_headers(&headers).unwrap();
+        assert!((usage.primary.unwrap().used_percent - 25.0).abs() < f64::EPSILON);
+        assert!(usage.secondary.is_none());
+        assert!(SPEC.usage_from_headers(&HeaderMap::new()).is_none());
+    }
+
+    #[tokio::test]
+    async fn fetcher_rejects_strategies_outside_its_plan() {
+        let fetcher = ApiKeyFetcher::new(crate::providers::groq::fetch_plan, || async {
+            Ok::<_, CautError>(UsageSnapshot::new(RateWindow::new(10.0)))
+        });
+        assert_eq!(fetcher.provider(), Provider::Groq);
+        assert!(fetcher.fetch_usage("groq-api").await.is_ok());
+        assert!(fetcher.fetch_usage("groq-web").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn fetcher_enriches_only_when_given_enrichment() {
+        fn add_credits(payload: &mut ProviderPayload) -> EnrichFuture<'_> {
+            payload.credits = Some(crate::test_utils::make_test_credits_snapshot(5.0));
+            Box::pin(std::future::ready(()))
+        }
+        let fetch = || async { Ok::<_, CautError>(UsageSnapshot::new(RateWindow::new(10.0))) };
+        let mut payload = crate::test_utils::make_test_provider_payload("groq", "api");
+        payload.credits = None;
+
+        let plain = ApiKeyFetcher::new(crate::providers::groq::fetch_plan, fetch);
+        plain.enrich(&mut payload).await;
+        assert!(payload.credits.is_none());
+
+        let enriching = ApiKeyFetcher::new(crate::providers::groq::fetch_plan, fetch)
+            .with_enrichment(add_credits);
+        enriching.enrich(&mut payload).await;
+        assert!(payload.credits.is_some());
+    }
+
+    #[test]
+    fn blank_keys_are_ignored() {
+        assert_eq!(non_empty("  ".to_string()), None);
+        assert_eq!(non_empty(" k ".to_string()), Some("k".to_string()));
+    }
+}
@@ -0,0 +1,505 @@
+//! Azure OpenAI provider implementation.
+//!
+//! Supports:
+//! - Azure Resource Manager API (deployment quota per region)
+//!
+//! Source label: `api`
+//!
+//! Azure OpenAI has no per-user usage endpoint; capacity is a per-region
+//! tokens-per-minute quota for each model, shared by the subscription's
+//! deployments. caut lists the subscription's OpenAI resources, reads the
+//! quota usage of every region they live in, and reports the model quota
+//! closest to its limit as the primary window, e.g. `gpt-4o 450K/500K TPM
+//! (Standard, eastus)`, followed by how many other model quotas are in use.
+//!
+//! Credentials, first found wins:
+//! - `AZURE_ACCESS_TOKEN`: an Azure Resource Manager bearer token
+//! - `AZURE_TENANT_ID`, `AZURE_CLIENT_ID`, `AZURE_CLIENT_SECRET`: a service
+//!   principal, exchanged for a token with the client credentials flow
+//! - the Azure CLI (`az account get-access-token`)
+//!
+//! The subscription is `AZURE_SUBSCRIPTION_ID`, or the Azure CLI's current
+//! subscription.
+
+use std::collections::BTreeSet;
+
+use chrono::Utc;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::core::cli_runner::{CLI_TIMEOUT, run_json_command};
+use crate::core::clock_skew;
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::core::models::{ProviderIdentity, RateWindow, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+
+/// Environment variable holding a ready-made Resource Manager token.
+pub const ACCESS_TOKEN_ENV: &str = "AZURE_ACCESS_TOKEN";
+
+/// Environment variable naming the subscription to report on.
+pub const SUBSCRIPTION_ENV: &str = "AZURE_SUBSCRIPTION_ID";
+
+/// Service principal environment variables.
+const TENANT_ENV: &str = "AZURE_TENANT_ID";
+const CLIENT_ID_ENV: &str = "AZURE_CLIENT_ID";
+const CLIENT_SECRET_ENV: &str = "AZURE_CLIENT_SECRET";
+
+/// Azure CLI binary name.
+const AZ_CLI: &str = "az";
+
+const MANAGEMENT_URL: &str = "https://management.azure.com";
+const MANAGEMENT_SCOPE: &str = "https://management.azure.com/.default";
+const API_VERSION: &str = "2024-10-01";
+
+/// Account kinds that host Azure OpenAI deployments.
+const OPENAI_KINDS: [&str; 2] = ["OpenAI", "AIServices"];
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Azure OpenAI.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::AzureOpenAI,
+        vec![FetchStrategy {
+            id: "azure-openai-api",
+            kind: FetchKind::ApiToken,
+            is_available: has_credentials,
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+fn has_credentials() -> bool {
+    env_var(ACCESS_TOKEN_ENV).is_some()
+        || service_principal().is_some()
+        || which::which(AZ_CLI).is_ok()
+}
+
+// =============================================================================
+// Credentials
+// =============================================================================
+
+/// A Resource Manager token and where it came from.
+#[derive(Debug, Clone)]
+struct AzureToken {
+    access_token: String,
+    /// Subscription the Azure CLI is set to, when the token came from it.
+    subscription: Option<String>,
+    /// `token`, `service-principal`, or `az-cli`.
+    method: &'static str,
+}
+
+/// Output of `az account get-access-token`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CliToken {
+    access_token: String,
+    #[serde(default)]
+    subscription: Option<String>,
+}
+
+/// Response of the Entra ID token endpoint.
+#[derive(Debug, Deserialize)]
+struct ClientCredentialsToken {
+    access_token: String,
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Tenant, client ID, and secret, when all three are set.
+fn service_principal() -> Option<(String, String, String)> {
+    Some((
+        env_var(TENANT_ENV)?,
+        env_var(CLIENT_ID_ENV)?,
+        env_var(CLIENT_SECRET_ENV)?,
+    ))
+}
+
+async fn azure_token() -> Result<AzureToken> {
+    if let Some(access_token) = env_var(ACCESS_TOKEN_ENV) {
+        return Ok(AzureToken {
+            access_token,
+            subscription: None,
+            method: "token",
+        });
+    }
+
+    if let Some((tenant, client_id, secret)) = service_principal() {
+        let client = build_client(DEFAULT_TIMEOUT)?;
+        let response = client
+            .post(format!(
+                "https://login.microsoftonline.com/{tenant}/oauth2/v2.0/token"
+            ))
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(form_body(&[
+                ("grant_type", "client_credentials"),
+                ("client_id", &client_id),
+                ("client_secret", &secret),
+                ("scope", MANAGEMENT_SCOPE),
+            ]))
+            .send()
+            .await
+            .map_err(request_error)?;
+        if !response.status().is_success() {
+            return Err(CautError::FetchFailed {
+                provider: Provider::AzureOpenAI.cli_name().to_string(),
+                reason: format!(
+                    "service principal sign-in returned {}; check {TENANT_ENV}, {CLIENT_ID_ENV}, and {CLIENT_SECRET_ENV}",
+                    response.status()
+                ),
+            });
+        }
+        let token: ClientCredentialsToken = response.json().await.map_err(|e| {
+            CautError::ParseResponse(format!("Invalid Entra ID token response: {e}"))
+        })?;
+        return Ok(AzureToken {
+            access_token: token.access_token,
+            subscription: None,
+            method: "service-principal",
+        });
+    }
+
+    let token: CliToken = run_json_command(
+        AZ_CLI,
+        &[
+            "account",
+            "get-access-token",
+            "--resource",
+            MANAGEMENT_URL,
+            "--output",
+            "json",
+        ],
+        CLI_TIMEOUT,
+    )
+    .await
+    .map_err(|e| match e {
+        CautError::FetchFailed { .. } => {
+            CautError::Config("Not signed in to Azure (run: az login)".to_string())
+        }
+        other => other,
+    })?;
+    Ok(AzureToken {
+        access_token: token.access_token,
+        subscription: token.subscription,
+        method: "az-cli",
+    })
+}
+
+/// `application/x-www-form-urlencoded` body for `pairs`.
+fn form_body(pairs: &[(&str, &str)]) -> String {
+    let mut url = reqwest::Url::parse("https://localhost/").expect("static URL parses");
+    url.query_pairs_mut().extend_pairs(pairs);
+    url.query().unwrap_or_default().to_string()
+}
+
+fn request_error(e: reqwest::Error) -> CautError {
+    if e.is_timeout() {
+        CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+    } else {
+        CautError::Network(e.to_string())
+    }
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct ListResponse<T> {
+    #[serde(default)]
+    value: Vec<T>,
+}
+
+/// A Cognitive Services account (an Azure OpenAI resource).
+#[derive(Debug, Deserialize)]
+struct Account {
+    name: String,
+    #[serde(default)]
+    kind: String,
+    #[serde(default)]
+    location: String,
+}
+
+/// Quota usage of one model in one region.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QuotaUsage {
+    name: QuotaName,
+    #[serde(default)]
+    current_value: f64,
+    #[serde(default)]
+    limit: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct QuotaName {
+    /// e.g. `OpenAI.Standard.gpt-4o`.
+    value: String,
+}
+
+/// A model quota with the region it applies to.
+#[derive(Debug, Clone, PartialEq)]
+struct RegionQuota {
+    region: String,
+    /// Model name, e.g. `gpt-4o`.
+    model: String,
+    /// Deployment SKU, e.g. `Standard` or `GlobalStandard`.
+    sku: String,
+    /// Tokens per minute, in thousands, assigned to deployments.
+    used: f64,
+    limit: f64,
+}
+
+impl RegionQuota {
+    fn from_usage(region: &str, usage: &QuotaUsage) -> Option<Self> {
+        let mut parts = usage.name.value.splitn(3, '.');
+        if parts.next() != Some("OpenAI") || usage.limit <= 0.0 {
+            return None;
+        }
+        let sku = parts.next()?.to_string();
+        let model = parts.next()?.to_string();
+        Some(Self {
+            region: region.to_string(),
+            model,
+            sku,
+            used: usage.current_value,
+            limit: usage.limit,
+        })
+    }
+
+    fn used_percent(&self) -> f64 {
+        (self.used / self.limit * 100.0).clamp(0.0, 100.0)
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{} {:.0}K/{:.0}K TPM ({}, {})",
+            self.model, self.used, self.limit, self.sku, self.region
+        )
+    }
+}
+
+async fn get_json<T: DeserializeOwned>(token: &AzureToken, url: &str) -> Result<T> {
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let response = client
+        .get(url)
+        .bearer_auth(&token.access_token)
+        .send()
+        .await
+        .map_err(request_error)?;
+    clock_skew::observe(&response);
+
+    let status = response.status();
+    if !status.is_success() {
+        let reason = if matches!(status.as_u16(), 401 | 403) {
+            "Azure rejected the credentials; run: az login, or grant the Reader role on the subscription".to_string()
+        } else {
+            format!("Resource Manager request returned {status}")
+        };
+        return Err(CautError::FetchFailed {
+            provider: Provider::AzureOpenAI.cli_name().to_string(),
+            reason,
+        });
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| CautError::ParseResponse(format!("Invalid Azure response: {e}")))
+}
+
+// =============================================================================
+// Fetching
+// =============================================================================
+
+/// Fetch deployment quota usage across the subscription's regions.
+///
+/// # Errors
+/// Returns an error if there are no Azure credentials, no subscription is
+/// known, the subscription has no Azure OpenAI resources, or a request
+/// fails.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    let token = azure_token().await?;
+    let subscription = env_var(SUBSCRIPTION_ENV)
+        .or_else(|| token.subscription.clone())
+        .ok_or_else(|| {
+            CautError::Config(format!(
+                "No Azure subscription selected (set {SUBSCRIPTION_ENV} or run: az account set)"
+            ))
+        })?;
+
+    let accounts: ListResponse<Account> = get_json(
+        &token,
+        &format!(
+            "{MANAGEMENT_URL}/subscriptions/{subscription}/providers/Microsoft.CognitiveServices/accounts?api-version={API_VERSION}"
+        ),
+    )
+    .await?;
+    let accounts: Vec<Account> = accounts
+        .value
+        .into_iter()
+        .filter(|account| OPENAI_KINDS.contains(&account.kind.as_str()))
+        .collect();
+    if accounts.is_empty() {
+        return Err(CautError::Config(format!(
+            "No Azure OpenAI resources in subscription {subscription}"
+        )));
+    }
+
+    let regions: BTreeSet<&str> = accounts
+        .iter()
+        .map(|account| account.location.as_str())
+        .filter(|location| !location.is_empty())
+        .collect();
+    let mut quotas = Vec::new();
+    for region in regions {
+        let usages: ListResponse<QuotaUsage> = get_json(
+            &token,
+            &format!(
+                "{MANAGEMENT_URL}/subscriptions/{subscription}/providers/Microsoft.CognitiveServices/locations/{region}/usages?api-version={API_VERSION}"
+            ),
+        )
+        .await?;
+        quotas.extend(
+            usages
+                .value
+                .iter()
+                .filter_map(|usage| RegionQuota::from_usage(region, usage)),
+        );
+    }
+
+    let names: Vec<&str> = accounts
+        .iter()
+        .map(|account| account.name.as_str())
+        .collect();
+    Ok(usage_from_quotas(&quotas, &names, token.method))
+}
+
+fn usage_from_quotas(quotas: &[RegionQuota], accounts: &[&str], method: &str) -> UsageSnapshot {
+    let mut ranked: Vec<&RegionQuota> = quotas.iter().filter(|quota| quota.used > 0.0).collect();
+    ranked.sort_by(|a, b| b.used_percent().total_cmp(&a.used_percent()));
+
+    let primary = ranked.first().map(|tightest| {
+        let others = ranked.len() - 1;
+        let description = if others == 0 {
+            tightest.describe()
+        } else {
+            format!(
+                "{} · {others} more model quota{} in use",
+                tightest.describe(),
+                if others == 1 { "" } else { "s" }
+            )
+        };
+        RateWindow {
+            used_percent: tightest.used_percent(),
+            window_minutes: Some(1),
+            resets_at: None,
+            reset_description: Some(description),
+        }
+    });
+
+    UsageSnapshot {
+        primary,
+        secondary: None,
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: Some(ProviderIdentity {
+            account_email: None,
+            account_organization: Some(accounts.join(", ")).filter(|names| !names.is_empty()),
+            login_method: Some(method.to_string()),
+        }),
+    }
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Azure OpenAI.
+pub struct AzureOpenAIFetcher;
+
+impl ProviderFetcher for AzureOpenAIFetcher {
+    fn provider(&self) -> Provider {
+        Provider::AzureOpenAI
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "azure-openai-api" => fetch_api().await,
+                _ => Err(unknown_strategy(Provider::AzureOpenAI, strategy_id)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_tightest_model_quota() {
+        let usages: ListResponse<QuotaUsage> = serde_json::from_str(
+            r#"{"value": [
+                {"name": {"value": "OpenAI.Standard.gpt-4o", "localizedValue": "Tokens Per Minute (thousands) - GPT-4o"},
+                 "currentValue": 450, "limit": 500, "unit": "Count"},
+                {"name": {"value": "OpenAI.GlobalStandard.gpt-4o-mini"}, "currentValue": 100, "limit": 2000},
+                {"name": {"value": "OpenAI.Standard.text-embedding-3-small"}, "currentValue": 0, "limit": 350},
+                {"name": {"value": "AccountCount"}, "currentValue": 3, "limit": 30}
+            ]}"#,
+        )
+        .unwrap();
+        let quotas: Vec<RegionQuota> = usages
+            .value
+            .iter()
+            .filter_map(|usage| RegionQuota::from_usage("eastus", usage))
+            .collect();
+        assert_eq!(quotas.len(), 3);
+
+        let usage = usage_from_quotas(&quotas, &["team-aoai"], "az-cli");
+        let primary = usage.primary.unwrap();
+        assert!((primary.used_percent - 90.0).abs() < f64::EPSILON);
+        assert_eq!(
+            primary.reset_description.as_deref(),
+            Some("gpt-4o 450K/500K TPM (Standard, eastus) · 1 more model quota in use")
+        );
+        let identity = usage.identity.unwrap();
+        assert_eq!(identity.account_organization.as_deref(), Some("team-aoai"));
+        assert_eq!(identity.login_method.as_deref(), Some("az-cli"));
+    }
+
+    #[test]
+    fn parses_cli_token_and_form_body() {
+        let token: CliToken = serde_json::from_str(
+            r#"{"accessToken": "eyJ0", "expiresOn": "2026-10-16 13:00:00.000000",
+                "subscription": "0b1f6471-1bf0-4dda-aec3-111122223333",
+                "tenant": "72f988bf-0000-0000-0000-000000000000", "tokenType": "Bearer"}"#,
+        )
+        .unwrap();
+        assert_eq!(token.access_token, "eyJ0");
+        assert_eq!(
+            token.subscription.as_deref(),
+            Some("0b1f6471-1bf0-4dda-aec3-111122223333")
+        );
+
+        assert_eq!(
+            form_body(&[("client_secret", "a+b/c=d"), ("scope", MANAGEMENT_SCOPE)]),
+            "client_secret=a%2Bb%2Fc%3Dd&scope=https%3A%2F%2Fmanagement.azure.com%2F.default"
+        );
+    }
+}
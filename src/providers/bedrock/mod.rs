@@ -0,0 +1,482 @@
+//! AWS Bedrock provider implementation.
+//!
+//! Supports:
+//! - CloudWatch token metrics (via the AWS CLI)
+//!
+//! Source label: `api`
+//!
+//! Bedrock is billed per token with no subscription quota, so there is no
+//! usage window to report. Bedrock publishes token counts per model to
+//! CloudWatch (`AWS/Bedrock` namespace, `ModelId` dimension) for every
+//! `InvokeModel` and `Converse` call. caut reads the daily sums for the last
+//! 30 days with `aws cloudwatch get-metric-data` and prices them with the
+//! pricing table for `caut cost --provider bedrock`. Models the table does
+//! not know are estimated at Sonnet-tier prices.
+//!
+//! Credentials and region are whatever the AWS CLI uses (`AWS_PROFILE`,
+//! `AWS_REGION`, SSO, instance roles). Set `CAUT_BEDROCK_REGIONS` to a
+//! comma-separated list to read several regions.
+
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{DateTime, Duration, Local, NaiveTime, SecondsFormat, Utc};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::core::cli_runner::{CLI_TIMEOUT, run_command, run_json_command};
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::models::{ProviderIdentity, UsageSnapshot};
+use crate::core::pricing::PricingTable;
+use crate::core::provider::Provider;
+use crate::core::session_logs::SessionUsage;
+use crate::error::{CautError, Result};
+
+/// Environment variable listing the regions to read, comma-separated.
+pub const REGIONS_ENV: &str = "CAUT_BEDROCK_REGIONS";
+
+/// AWS CLI binary name.
+const AWS_CLI: &str = "aws";
+
+/// CloudWatch namespace Bedrock publishes runtime metrics to.
+const NAMESPACE: &str = "AWS/Bedrock";
+
+/// Days of history read from CloudWatch.
+const HISTORY_DAYS: i64 = 30;
+
+/// Geographic prefixes of cross-region inference profile IDs.
+const PROFILE_PREFIXES: [&str; 8] = ["us", "eu", "apac", "global", "us-gov", "jp", "au", "ca"];
+
+/// A CloudWatch token metric and the token kind it counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenMetric {
+    Input,
+    Output,
+    CacheRead,
+    CacheWrite,
+}
+
+impl TokenMetric {
+    const ALL: [Self; 4] = [Self::Input, Self::Output, Self::CacheRead, Self::CacheWrite];
+
+    const fn metric_name(self) -> &'static str {
+        match self {
+            Self::Input => "InputTokenCount",
+            Self::Output => "OutputTokenCount",
+            Self::CacheRead => "CacheReadInputTokenCount",
+            Self::CacheWrite => "CacheWriteInputTokenCount",
+        }
+    }
+
+    const fn query_prefix(self) -> &'static str {
+        match self {
+            Self::Input => "in",
+            Self::Output => "out",
+            Self::CacheRead => "cr",
+            Self::CacheWrite => "cw",
+        }
+    }
+
+    fn from_query_id(id: &str) -> Option<(Self, usize)> {
+        Self::ALL.into_iter().find_map(|metric| {
+            let index = id.strip_prefix(metric.query_prefix())?.strip_prefix('_')?;
+            Some((metric, index.parse().ok()?))
+        })
+    }
+}
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for AWS Bedrock.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Bedrock,
+        vec![FetchStrategy {
+            id: "bedrock-cloudwatch",
+            kind: FetchKind::ApiToken,
+            is_available: || which::which(AWS_CLI).is_ok(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// CloudWatch Types
+// =============================================================================
+
+/// Output of `aws cloudwatch list-metrics`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListMetrics {
+    #[serde(default)]
+    metrics: Vec<Metric>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Metric {
+    #[serde(default)]
+    dimensions: Vec<Dimension>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Dimension {
+    name: String,
+    value: String,
+}
+
+/// Output of `aws cloudwatch get-metric-data`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct MetricData {
+    #[serde(default)]
+    metric_data_results: Vec<MetricDataResult>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct MetricDataResult {
+    id: String,
+    #[serde(default)]
+    timestamps: Vec<DateTime<Utc>>,
+    #[serde(default)]
+    values: Vec<f64>,
+}
+
+// =============================================================================
+// Model IDs
+// =============================================================================
+
+/// Pricing-table name for a Bedrock model ID.
+///
+/// Strips the ARN path, the cross-region profile prefix (`us.`), the vendor
+/// (`anthropic.`), and the version suffix (`-v2:0`), so
+/// `us.anthropic.claude-sonnet-4-20250514-v1:0` becomes
+/// `claude-sonnet-4-20250514`. Names the pricing table knows by prefix are
+/// replaced by the full table entry.
+fn model_name(model_id: &str, pricing: &PricingTable) -> String {
+    let id = model_id.rsplit('/').next().unwrap_or(model_id);
+    let mut segments: Vec<&str> = id.split('.').collect();
+    if segments.len() > 2 && PROFILE_PREFIXES.contains(&segments[0]) {
+        segments.remove(0);
+    }
+    if segments.len() > 1 {
+        segments.remove(0);
+    }
+    let mut name = segments.join(".");
+    if let Some(at) = name.rfind("-v")
+        && name[at + 2..].starts_with(|c: char| c.is_ascii_digit())
+    {
+        name.truncate(at);
+    }
+
+    pricing
+        .resolve(&name)
+        .map_or(name, |model| model.model.clone())
+}
+
+// =============================================================================
+// Fetching
+// =============================================================================
+
+/// Regions to read: `CAUT_BEDROCK_REGIONS`, or the AWS CLI's default.
+fn regions() -> Vec<Option<String>> {
+    let regions: Vec<Option<String>> = std::env::var(REGIONS_ENV)
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|region| !region.is_empty())
+        .map(|region| Some(region.to_string()))
+        .collect();
+    if regions.is_empty() {
+        vec![None]
+    } else {
+        regions
+    }
+}
+
+async fn aws_json<T: serde::de::DeserializeOwned>(
+    args: &[&str],
+    region: Option<&str>,
+) -> Result<T> {
+    let mut args = args.to_vec();
+    if let Some(region) = region {
+        args.extend(["--region", region]);
+    }
+    args.extend(["--output", "json"]);
+    run_json_command(AWS_CLI, &args, CLI_TIMEOUT)
+        .await
+        .map_err(|e| match e {
+            CautError::FetchFailed { reason, .. } => CautError::FetchFailed {
+                provider: Provider::Bedrock.cli_name().to_string(),
+                reason: format!("{reason} (check: aws sts get-caller-identity)"),
+            },
+            other => other,
+        })
+}
+
+/// Bedrock model IDs with token metrics in `region`.
+async fn model_ids(region: Option<&str>) -> Result<Vec<String>> {
+    let listed: ListMetrics = aws_json(
+        &[
+            "cloudwatch",
+            "list-metrics",
+            "--namespace",
+            NAMESPACE,
+            "--metric-name",
+            TokenMetric::Input.metric_name(),
+        ],
+        region,
+    )
+    .await?;
+
+    let mut ids: Vec<String> = listed
+        .metrics
+        .into_iter()
+        .flat_map(|metric| metric.dimensions)
+        .filter(|dimension| dimension.name == "ModelId")
+        .map(|dimension| dimension.value)
+        .collect();
+    ids.sort();
+    ids.dedup();
+    Ok(ids)
+}
+
+/// Daily token totals per model, one [`SessionUsage`] per model and local
+/// day, starting at that day's midnight, over the last 30 days.
+///
+/// Returns nothing when the AWS CLI is not installed.
+///
+/// # Errors
+/// Returns an error if the AWS CLI fails (usually missing credentials) or
+/// its output cannot be parsed.
+pub async fn daily_usage() -> Result<Vec<SessionUsage>> {
+    if which::which(AWS_CLI).is_err() {
+        return Ok(Vec::new());
+    }
+
+    // Daily buckets aligned to local midnight, so days match other providers.
+    let today = Local::now()
+        .date_naive()
+        .and_time(NaiveTime::MIN)
+        .and_local_timezone(Local)
+        .earliest()
+        .map_or_else(Utc::now, |midnight| midnight.with_timezone(&Utc));
+    let start =
+        (today - Duration::days(HISTORY_DAYS - 1)).to_rfc3339_opts(SecondsFormat::Secs, true);
+    let end = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let pricing = PricingTable::current();
+    let mut usage = Vec::new();
+    for region in regions() {
+        let region = region.as_deref();
+        let ids = model_ids(region).await?;
+        if ids.is_empty() {
+            continue;
+        }
+
+        let queries: Vec<serde_json::Value> = ids
+            .iter()
+            .enumerate()
+            .flat_map(|(index, id)| {
+                TokenMetric::ALL.into_iter().map(move |metric| {
+                    json!({
+                        "Id": format!("{}_{index}", metric.query_prefix()),
+                        "MetricStat": {
+                            "Metric": {
+                                "Namespace": NAMESPACE,
+                                "MetricName": metric.metric_name(),
+                                "Dimensions": [{"Name": "ModelId", "Value": id}],
+                            },
+                            "Period": 86_400,
+                            "Stat": "Sum",
+                        },
+                        "ReturnData": true,
+                    })
+                })
+            })
+            .collect();
+        let queries = serde_json::to_string(&queries)?;
+
+        let data: MetricData = aws_json(
+            &[
+                "cloudwatch",
+                "get-metric-data",
+                "--start-time",
+                &start,
+                "--end-time",
+                &end,
+                "--metric-data-queries",
+                &queries,
+            ],
+            region,
+        )
+        .await?;
+        let names: Vec<String> = ids.iter().map(|id| model_name(id, &pricing)).collect();
+        usage.extend(usage_from_metrics(&data, &names));
+    }
+    Ok(usage)
+}
+
+/// Fold metric results into one usage entry per model and day.
+/// `names[i]` is the model queried with index `i`.
+#[allow(clippy::cast_possible_truncation)] // token sums are whole numbers far below i64::MAX
+fn usage_from_metrics(data: &MetricData, names: &[String]) -> Vec<SessionUsage> {
+    let mut days: BTreeMap<(String, DateTime<Utc>), SessionUsage> = BTreeMap::new();
+    for result in &data.metric_data_results {
+        let Some((metric, index)) = TokenMetric::from_query_id(&result.id) else {
+            continue;
+        };
+        let Some(name) = names.get(index) else {
+            continue;
+        };
+        for (day, value) in result.timestamps.iter().zip(&result.values) {
+            let tokens = value.round() as i64;
+            let entry = days
+                .entry((name.clone(), *day))
+                .or_insert_with(|| SessionUsage {
+                    session_id: format!("bedrock:{name}:{}", day.format("%Y-%m-%d")),
+                    started_at: Some(*day),
+                    ended_at: Some(*day),
+                    models_used: HashSet::from([name.clone()]),
+                    ..SessionUsage::default()
+                });
+            match metric {
+                TokenMetric::Input => entry.input_tokens += tokens,
+                TokenMetric::Output => entry.output_tokens += tokens,
+                TokenMetric::CacheRead => entry.cache_read_tokens += tokens,
+                TokenMetric::CacheWrite => entry.cache_creation_tokens += tokens,
+            }
+        }
+    }
+    days.into_values().collect()
+}
+
+/// Confirm the AWS CLI can read Bedrock metrics and report the account.
+///
+/// # Errors
+/// Returns an error if the AWS CLI has no working credentials.
+pub async fn fetch_cloudwatch() -> Result<UsageSnapshot> {
+    let output = run_command(
+        AWS_CLI,
+        &[
+            "sts",
+            "get-caller-identity",
+            "--query",
+            "Account",
+            "--output",
+            "text",
+        ],
+        CLI_TIMEOUT,
+    )
+    .await?;
+    if !output.success() {
+        return Err(CautError::Config(
+            "AWS CLI has no working credentials (run: aws configure or aws sso login)".to_string(),
+        ));
+    }
+    let account = output.stdout.trim().to_string();
+
+    let regions: Vec<String> = regions().into_iter().flatten().collect();
+    Ok(UsageSnapshot {
+        primary: None,
+        secondary: None,
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: Some(ProviderIdentity {
+            account_email: None,
+            account_organization: Some(account).filter(|account| !account.is_empty()),
+            login_method: Some(if regions.is_empty() {
+                "aws-cli".to_string()
+            } else {
+                format!("aws-cli ({})", regions.join(", "))
+            }),
+        }),
+    })
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for AWS Bedrock.
+pub struct BedrockFetcher;
+
+impl ProviderFetcher for BedrockFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Bedrock
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "bedrock-cloudwatch" => fetch_cloudwatch().await,
+                _ => Err(unknown_strategy(Provider::Bedrock, strategy_id)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_model_ids_to_pricing_names() {
+        let pricing = PricingTable::current();
+        assert_eq!(
+            model_name("us.anthropic.claude-sonnet-4-20250514-v1:0", &pricing),
+            "claude-sonnet-4-20250514"
+        );
+        assert_eq!(
+            model_name(
+                "arn:aws:bedrock:us-east-1:123456789012:inference-profile/eu.anthropic.claude-3-haiku-20240307-v1:0",
+                &pricing
+            ),
+            "claude-3-haiku-20240307"
+        );
+        assert_eq!(model_name("amazon.nova-pro-v1:0", &pricing), "nova-pro");
+        assert_eq!(
+            model_name("meta.llama3-1-70b-instruct-v1:0", &pricing),
+            "llama3-1-70b-instruct"
+        );
+    }
+
+    #[test]
+    fn folds_metric_results_per_model_and_day() {
+        let data: MetricData = serde_json::from_str(
+            r#"{"MetricDataResults": [
+                {"Id": "in_0", "Label": "InputTokenCount", "StatusCode": "Complete",
+                 "Timestamps": ["2026-10-15T00:00:00Z", "2026-10-14T00:00:00Z"], "Values": [1200.0, 800.0]},
+                {"Id": "out_0", "Timestamps": ["2026-10-15T00:00:00Z"], "Values": [300.0]},
+                {"Id": "cr_0", "Timestamps": ["2026-10-15T00:00:00Z"], "Values": [5000.0]},
+                {"Id": "in_1", "Timestamps": ["2026-10-15T00:00:00Z"], "Values": [50.0]},
+                {"Id": "in_9", "Timestamps": ["2026-10-15T00:00:00Z"], "Values": [1.0]}
+            ], "Messages": []}"#,
+        )
+        .unwrap();
+        let names = [
+            "claude-sonnet-4-20250514".to_string(),
+            "nova-pro".to_string(),
+        ];
+
+        let usage = usage_from_metrics(&data, &names);
+        assert_eq!(usage.len(), 3);
+        let sonnet_day = usage
+            .iter()
+            .find(|u| u.session_id == "bedrock:claude-sonnet-4-20250514:2026-10-15")
+            .unwrap();
+        assert_eq!(sonnet_day.input_tokens, 1200);
+        assert_eq!(sonnet_day.output_tokens, 300);
+        assert_eq!(sonnet_day.cache_read_tokens, 5000);
+        assert!(sonnet_day.models_used.contains("claude-sonnet-4-20250514"));
+    }
+}
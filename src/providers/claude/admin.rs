@@ -0,0 +1,258 @@
+//! Claude organization usage via the Anthropic Admin API.
+//!
+//! The Claude Code analytics report returns one record per member (or API
+//! key) per UTC day, with session counts and per-model token counts and
+//! estimated cost in cents. It needs an admin key (`sk-ant-admin...`) from
+//! the Claude Console, read from `ANTHROPIC_ADMIN_KEY`.
+
+use chrono::{Duration, NaiveDate};
+use serde::Deserialize;
+
+use super::API_BASE;
+use crate::core::clock_skew;
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::core::org_usage::{OrgUsageReport, SeatUsage};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+
+/// Environment variable holding the Admin API key.
+pub const ADMIN_KEY_ENV: &str = "ANTHROPIC_ADMIN_KEY";
+
+/// Anthropic API version header value.
+const API_VERSION: &str = "2023-06-01";
+
+/// Records requested per page (the API maximum).
+const PAGE_LIMIT: u32 = 1000;
+
+#[derive(Debug, Deserialize)]
+struct ReportPage {
+    #[serde(default)]
+    data: Vec<ReportRecord>,
+    #[serde(default)]
+    has_more: bool,
+    #[serde(default)]
+    next_page: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReportRecord {
+    actor: Actor,
+    #[serde(default)]
+    core_metrics: Option<CoreMetrics>,
+    #[serde(default)]
+    model_breakdown: Vec<ModelBreakdown>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Actor {
+    UserActor { email_address: String },
+    ApiActor { api_key_name: String },
+}
+
+#[derive(Debug, Deserialize)]
+struct CoreMetrics {
+    #[serde(default)]
+    num_sessions: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelBreakdown {
+    model: String,
+    #[serde(default)]
+    tokens: ModelTokens,
+    #[serde(default)]
+    estimated_cost: Option<EstimatedCost>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ModelTokens {
+    #[serde(default)]
+    input: i64,
+    #[serde(default)]
+    output: i64,
+    #[serde(default)]
+    cache_read: i64,
+    #[serde(default)]
+    cache_creation: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct EstimatedCost {
+    /// Amount in cents.
+    amount: f64,
+}
+
+fn admin_key() -> Result<String> {
+    std::env::var(ADMIN_KEY_ENV)
+        .ok()
+        .map(|key| key.trim().to_string())
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| {
+            CautError::Config(format!(
+                "Set {ADMIN_KEY_ENV} to an Admin API key (Claude Console > Settings > Admin keys)"
+            ))
+        })
+}
+
+fn seat_from_record(record: ReportRecord) -> SeatUsage {
+    let member = match record.actor {
+        Actor::UserActor { email_address } => email_address,
+        Actor::ApiActor { api_key_name } => format!("api key: {api_key_name}"),
+    };
+    let mut seat = SeatUsage {
+        member,
+        sessions: record.core_metrics.map_or(0, |m| m.num_sessions),
+        ..SeatUsage::default()
+    };
+    for breakdown in record.model_breakdown {
+        seat.input_tokens += breakdown.tokens.input;
+        seat.output_tokens += breakdown.tokens.output;
+        seat.cache_read_tokens += breakdown.tokens.cache_read;
+        seat.cache_creation_tokens += breakdown.tokens.cache_creation;
+        seat.cost_usd += breakdown.estimated_cost.map_or(0.0, |c| c.amount / 100.0);
+        seat.models.insert(breakdown.model);
+    }
+    seat
+}
+
+async fn fetch_day(client: &reqwest::Client, key: &str, day: NaiveDate) -> Result<Vec<SeatUsage>> {
+    let mut seats = Vec::new();
+    let mut page: Option<String> = None;
+
+    loop {
+        let mut url = reqwest::Url::parse(&format!(
+            "{API_BASE}/v1/organizations/usage_report/claude_code"
+        ))
+        .map_err(|e| CautError::Config(format!("Invalid usage report URL: {e}")))?;
+        url.query_pairs_mut()
+            .append_pair("starting_at", &day.to_string())
+            .append_pair("limit", &PAGE_LIMIT.to_string());
+        if let Some(cursor) = &page {
+            url.query_pairs_mut().append_pair("page", cursor);
+        }
+
+        let response = client
+            .get(url)
+            .header("x-api-key", key)
+            .header("anthropic-version", API_VERSION)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+                } else {
+                    CautError::Network(e.to_string())
+                }
+            })?;
+        clock_skew::observe(&response);
+
+        let status = response.status();
+        if !status.is_success() {
+            let reason = if matches!(status.as_u16(), 401 | 403) {
+                format!("{ADMIN_KEY_ENV} was rejected; it must be an Admin API key")
+            } else {
+                format!("usage report request returned {status}")
+            };
+            return Err(CautError::FetchFailed {
+                provider: Provider::Claude.cli_name().to_string(),
+                reason,
+            });
+        }
+
+        let body: ReportPage = response
+            .json()
+            .await
+            .map_err(|e| CautError::ParseResponse(format!("Invalid Claude usage report: {e}")))?;
+        seats.extend(body.data.into_iter().map(seat_from_record));
+
+        match body.next_page {
+            Some(next) if body.has_more => page = Some(next),
+            _ => break,
+        }
+    }
+
+    Ok(seats)
+}
+
+/// Fetch per-seat Claude Code usage for each UTC day from `start` to `end`.
+///
+/// # Errors
+/// Returns an error if no admin key is configured, a request fails or is
+/// rejected, or a response cannot be parsed.
+pub async fn fetch_org_usage(start: NaiveDate, end: NaiveDate) -> Result<OrgUsageReport> {
+    let key = admin_key()?;
+    let client = build_client(DEFAULT_TIMEOUT)?;
+
+    let mut records = Vec::new();
+    let mut day = start;
+    while day <= end {
+        tracing::debug!(%day, "Fetching Claude usage report");
+        records.extend(fetch_day(&client, &key, day).await?);
+        day += Duration::days(1);
+    }
+
+    Ok(OrgUsageReport::new("claude", start, end, records))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"{
+        "data": [
+            {
+                "date": "2026-10-01T00:00:00Z",
+                "actor": {"type": "user_actor", "email_address": "ana@example.com"},
+                "organization_id": "org-1",
+                "customer_type": "subscription",
+                "terminal_type": "vscode",
+                "core_metrics": {
+                    "num_sessions": 4,
+                    "lines_of_code": {"added": 120, "removed": 30},
+                    "commits_by_claude_code": 2,
+                    "pull_requests_by_claude_code": 1
+                },
+                "model_breakdown": [
+                    {
+                        "model": "claude-sonnet-4-20250514",
+                        "tokens": {"input": 1000, "output": 400, "cache_read": 5000, "cache_creation": 200},
+                        "estimated_cost": {"currency": "USD", "amount": 125}
+                    },
+                    {
+                        "model": "claude-3-5-haiku-20241022",
+                        "tokens": {"input": 300, "output": 100, "cache_read": 0, "cache_creation": 0},
+                        "estimated_cost": {"currency": "USD", "amount": 2.5}
+                    }
+                ]
+            },
+            {
+                "date": "2026-10-01T00:00:00Z",
+                "actor": {"type": "api_actor", "api_key_name": "ci-bot"},
+                "core_metrics": {"num_sessions": 1},
+                "model_breakdown": []
+            }
+        ],
+        "has_more": false,
+        "next_page": null
+    }"#;
+
+    #[test]
+    fn parses_report_records_into_seats() {
+        let page: ReportPage = serde_json::from_str(PAGE).unwrap();
+        assert!(!page.has_more);
+        let seats: Vec<SeatUsage> = page.data.into_iter().map(seat_from_record).collect();
+
+        assert_eq!(seats.len(), 2);
+        let ana = &seats[0];
+        assert_eq!(ana.member, "ana@example.com");
+        assert_eq!(ana.sessions, 4);
+        assert_eq!(ana.input_tokens, 1300);
+        assert_eq!(ana.cache_read_tokens, 5000);
+        assert!((ana.cost_usd - 1.275).abs() < 1e-9);
+        assert_eq!(ana.models.len(), 2);
+
+        assert_eq!(seats[1].member, "api key: ci-bot");
+        assert_eq!(seats[1].total_tokens(), 0);
+    }
+}
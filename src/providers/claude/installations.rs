@@ -0,0 +1,168 @@
+//! Side-by-side Claude Code installations.
+//!
+//! Preview and nightly builds of Claude Code ship as separate binaries
+//! (`claude-preview`, `claude-nightly`) that keep their own config directory
+//! (`~/.claude-preview`, `~/.claude-nightly`), with their own sign-in and
+//! session logs. Each one found next to the stable install is tracked as a
+//! distinct source under the `claude` provider, labelled with its channel:
+//! usage as `oauth (preview)`, session logs and costs as `local (preview)`.
+//!
+//! An installation counts when its config directory exists and either its
+//! binary is on `PATH` or the directory already holds credentials or
+//! session logs.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::models::{ProviderIdentity, UsageSnapshot};
+use crate::error::{CautError, Result};
+
+/// Release channels installed alongside stable: channel, binary, and config
+/// directory under home.
+const CHANNELS: [(&str, &str, &str); 2] = [
+    ("preview", "claude-preview", ".claude-preview"),
+    ("nightly", "claude-nightly", ".claude-nightly"),
+];
+
+/// A non-stable Claude Code installation with its own config directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Installation {
+    /// Release channel, e.g. `preview`.
+    pub channel: &'static str,
+    pub binary: &'static str,
+    pub config_dir: PathBuf,
+}
+
+impl Installation {
+    /// `source` labelled with this installation's channel, e.g.
+    /// `oauth (preview)`.
+    #[must_use]
+    pub fn label(&self, source: &str) -> String {
+        format!("{source} ({})", self.channel)
+    }
+
+    fn fetch_error(&self, reason: impl std::fmt::Display) -> CautError {
+        CautError::FetchFailed {
+            provider: "claude".to_string(),
+            reason: format!("{} install: {reason}", self.channel),
+        }
+    }
+}
+
+/// Where each non-stable channel keeps its OAuth credentials under `home`,
+/// whether or not it is installed.
+#[must_use]
+pub fn credential_files(home: &Path) -> Vec<PathBuf> {
+    CHANNELS
+        .iter()
+        .map(|&(_, _, dir_name)| home.join(dir_name).join(".credentials.json"))
+        .collect()
+}
+
+/// Installations found next to the stable one, in channel order.
+#[must_use]
+pub fn extra() -> Vec<Installation> {
+    let Some(home) = directories::BaseDirs::new().map(|d| d.home_dir().to_path_buf()) else {
+        return Vec::new();
+    };
+    let stable = super::get_claude_dir();
+    detect(&home, stable.as_deref(), |binary| {
+        which::which(binary).is_ok()
+    })
+}
+
+fn detect(
+    home: &Path,
+    stable_dir: Option<&Path>,
+    on_path: impl Fn(&str) -> bool,
+) -> Vec<Installation> {
+    CHANNELS
+        .iter()
+        .filter_map(|&(channel, binary, dir_name)| {
+            let config_dir = home.join(dir_name);
+            // CLAUDE_CONFIG_DIR may already point the stable fetch here.
+            if !config_dir.is_dir() || stable_dir == Some(config_dir.as_path()) {
+                return None;
+            }
+            let has_state = config_dir.join(".credentials.json").is_file()
+                || config_dir.join("projects").is_dir();
+            (on_path(binary) || has_state).then_some(Installation {
+                channel,
+                binary,
+                config_dir,
+            })
+        })
+        .collect()
+}
+
+/// Fetch usage for an installation with the OAuth token in its own config
+/// directory.
+///
+/// # Errors
+/// Returns an error if the installation has no valid OAuth token or the
+/// usage request fails.
+pub async fn fetch(install: &Installation) -> Result<UsageSnapshot> {
+    let content =
+        fs::read_to_string(install.config_dir.join(".credentials.json")).map_err(|_| {
+            install.fetch_error(format!("not signed in (run: {} login)", install.binary))
+        })?;
+    let token = super::token_from_credentials_json(&content)
+        .ok_or_else(|| install.fetch_error("OAuth token is missing or expired"))?;
+
+    let mut snapshot = super::fetch_oauth(&token)
+        .await
+        .map_err(|e| install.fetch_error(e))?;
+    let account = super::read_oauth_account_in(&install.config_dir);
+    snapshot.identity = Some(ProviderIdentity {
+        account_email: account.as_ref().and_then(|a| a.email_address.clone()),
+        account_organization: account.and_then(|a| a.organization_name),
+        login_method: Some("oauth".to_string()),
+    });
+    Ok(snapshot)
+}
+
+/// Config directories of the extra installations with their channel, for
+/// session log discovery.
+#[must_use]
+pub fn session_bases() -> Vec<(String, PathBuf)> {
+    extra()
+        .into_iter()
+        .map(|install| (install.channel.to_string(), install.config_dir))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_channels_with_their_own_config_dir() {
+        let home = tempfile::tempdir().unwrap();
+        fs::create_dir_all(home.path().join(".claude-preview/projects")).unwrap();
+        // Nightly's directory exists but it was never used or installed.
+        fs::create_dir_all(home.path().join(".claude-nightly")).unwrap();
+
+        let found = detect(home.path(), None, |_| false);
+        assert_eq!(
+            found,
+            [Installation {
+                channel: "preview",
+                binary: "claude-preview",
+                config_dir: home.path().join(".claude-preview"),
+            }]
+        );
+        assert_eq!(found[0].label("oauth"), "oauth (preview)");
+
+        let found = detect(home.path(), None, |binary| binary == "claude-nightly");
+        assert_eq!(found.len(), 2);
+    }
+
+    #[test]
+    fn skips_the_directory_stable_already_uses() {
+        let home = tempfile::tempdir().unwrap();
+        let preview = home.path().join(".claude-preview");
+        fs::create_dir_all(preview.join("projects")).unwrap();
+
+        assert!(detect(home.path(), Some(&preview), |_| true).is_empty());
+    }
+}
@@ -0,0 +1,305 @@
+//! Claude sign-in for `caut login claude`.
+//!
+//! Runs the OAuth authorization-code flow with PKCE against the public client
+//! Claude Code uses: the user approves access on claude.ai, which shows a
+//! code to paste back into caut. The access token goes in the `caut` /
+//! `claude-oauth-token` keyring entry that [`super::get_oauth_token`] reads
+//! first; the refresh token and expiry go in `caut` / `claude-oauth-refresh`
+//! so an expired sign-in is renewed before the next OAuth fetch.
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::error::{CautError, Result};
+
+/// Claude Code's public OAuth client.
+const CLIENT_ID: &str = "9d1c250a-e61b-44d9-88ed-5944d1962f5e";
+
+const AUTHORIZE_URL: &str = "https://claude.ai/oauth/authorize";
+
+const TOKEN_URL: &str = "https://console.anthropic.com/v1/oauth/token";
+
+/// Shows the authorization code to the user instead of redirecting to a
+/// local server.
+const REDIRECT_URI: &str = "https://console.anthropic.com/oauth/code/callback";
+
+const SCOPES: &str = "org:create_api_key user:profile user:inference";
+
+const KEYRING_SERVICE: &str = "caut";
+
+/// Keyring account holding the access token.
+const TOKEN_ACCOUNT: &str = "claude-oauth-token";
+
+/// Keyring account holding the [`StoredSession`] JSON.
+const SESSION_ACCOUNT: &str = "claude-oauth-refresh";
+
+/// Refresh this long before the access token expires.
+const REFRESH_MARGIN_MINUTES: i64 = 5;
+
+/// A sign-in waiting for the user to approve it in the browser.
+#[derive(Debug)]
+pub struct LoginRequest {
+    /// Page where the user approves access.
+    pub url: String,
+    verifier: String,
+    state: String,
+}
+
+impl LoginRequest {
+    /// Start a sign-in with a fresh PKCE verifier and state.
+    ///
+    /// # Errors
+    /// Returns an error if the system random source fails or the
+    /// authorization URL cannot be built.
+    pub fn new() -> Result<Self> {
+        let verifier = URL_SAFE_NO_PAD.encode(random_bytes()?);
+        let state = URL_SAFE_NO_PAD.encode(random_bytes()?);
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+        let url = reqwest::Url::parse_with_params(
+            AUTHORIZE_URL,
+            [
+                ("code", "true"),
+                ("client_id", CLIENT_ID),
+                ("response_type", "code"),
+                ("redirect_uri", REDIRECT_URI),
+                ("scope", SCOPES),
+                ("code_challenge", challenge.as_str()),
+                ("code_challenge_method", "S256"),
+                ("state", state.as_str()),
+            ],
+        )
+        .map_err(|e| CautError::Config(format!("Invalid authorization URL: {e}")))?;
+        Ok(Self {
+            url: url.to_string(),
+            verifier,
+            state,
+        })
+    }
+
+    /// Exchange the code the user pasted for tokens and store them in the
+    /// keyring.
+    ///
+    /// # Errors
+    /// Returns an error if the code is malformed or from another sign-in, the
+    /// token request fails, or the keyring cannot be written.
+    pub async fn complete(&self, pasted: &str) -> Result<StoredSession> {
+        let code = parse_pasted_code(pasted, &self.state)?;
+        let tokens = request_tokens(&serde_json::json!({
+            "grant_type": "authorization_code",
+            "code": code,
+            "state": self.state,
+            "client_id": CLIENT_ID,
+            "redirect_uri": REDIRECT_URI,
+            "code_verifier": self.verifier,
+        }))
+        .await?;
+        store(tokens, None)
+    }
+}
+
+/// What caut keeps next to the access token to renew it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredSession {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl StoredSession {
+    /// Whether the access token has expired or is about to.
+    #[must_use]
+    pub fn needs_refresh(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at
+            .is_some_and(|at| at <= now + Duration::minutes(REFRESH_MARGIN_MINUTES))
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    /// Seconds until the access token expires.
+    #[serde(default)]
+    expires_in: Option<i64>,
+}
+
+/// Renew the stored access token if `caut login` saved one that has expired.
+///
+/// Returns whether a new token was stored. Sign-ins from Claude Code itself
+/// are left alone; Claude Code renews those.
+///
+/// # Errors
+/// Returns an error if the refresh request fails or the keyring cannot be
+/// written.
+pub async fn refresh_if_expired() -> Result<bool> {
+    let Some(session) = load_session() else {
+        return Ok(false);
+    };
+    let Some(refresh_token) = session.refresh_token.as_deref() else {
+        return Ok(false);
+    };
+    if !session.needs_refresh(Utc::now()) {
+        return Ok(false);
+    }
+    let tokens = request_tokens(&serde_json::json!({
+        "grant_type": "refresh_token",
+        "refresh_token": refresh_token,
+        "client_id": CLIENT_ID,
+    }))
+    .await?;
+    store(tokens, session.refresh_token)?;
+    tracing::debug!("Refreshed Claude OAuth token");
+    Ok(true)
+}
+
+/// Remove the tokens `caut login claude` stored. Returns whether there were
+/// any.
+///
+/// # Errors
+/// Returns an error if the keyring cannot be accessed.
+pub fn logout() -> Result<bool> {
+    let token = delete_entry(TOKEN_ACCOUNT)?;
+    let session = delete_entry(SESSION_ACCOUNT)?;
+    Ok(token || session)
+}
+
+/// The pasted value is `<code>#<state>`; a bare code is accepted too.
+fn parse_pasted_code<'a>(pasted: &'a str, expected_state: &str) -> Result<&'a str> {
+    let pasted = pasted.trim();
+    let (code, state) = pasted.split_once('#').unwrap_or((pasted, expected_state));
+    if code.is_empty() {
+        return Err(CautError::Config(
+            "No authorization code entered".to_string(),
+        ));
+    }
+    if state != expected_state {
+        return Err(CautError::Config(
+            "Authorization code is from a different sign-in; run `caut login claude` again"
+                .to_string(),
+        ));
+    }
+    Ok(code)
+}
+
+async fn request_tokens(body: &serde_json::Value) -> Result<TokenResponse> {
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let response = client
+        .post(TOKEN_URL)
+        .json(body)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+            } else {
+                CautError::Network(e.to_string())
+            }
+        })?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let detail = response.text().await.unwrap_or_default();
+        return Err(CautError::FetchFailed {
+            provider: "claude".to_string(),
+            reason: format!(
+                "OAuth token request returned HTTP {status}: {}",
+                detail.trim()
+            ),
+        });
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| CautError::ParseResponse(format!("Invalid OAuth token response: {e}")))
+}
+
+/// Save the tokens. A refresh response may omit the refresh token, in which
+/// case `previous_refresh` is kept.
+fn store(tokens: TokenResponse, previous_refresh: Option<String>) -> Result<StoredSession> {
+    super::store_oauth_token(&tokens.access_token)?;
+    let session = StoredSession {
+        refresh_token: tokens.refresh_token.or(previous_refresh),
+        expires_at: tokens
+            .expires_in
+            .map(|secs| Utc::now() + Duration::seconds(secs)),
+    };
+    let json = serde_json::to_string(&session)?;
+    keyring_entry(SESSION_ACCOUNT)?
+        .set_password(&json)
+        .map_err(|e| CautError::Config(format!("Failed to store token: {e}")))?;
+    Ok(session)
+}
+
+fn load_session() -> Option<StoredSession> {
+    let json = keyring_entry(SESSION_ACCOUNT).ok()?.get_password().ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+fn delete_entry(account: &str) -> Result<bool> {
+    match keyring_entry(account)?.delete_credential() {
+        Ok(()) => Ok(true),
+        Err(keyring::Error::NoEntry) => Ok(false),
+        Err(e) => Err(CautError::Config(format!("Failed to delete token: {e}"))),
+    }
+}
+
+fn keyring_entry(account: &str) -> Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, account)
+        .map_err(|e| CautError::Config(format!("Keyring error: {e}")))
+}
+
+/// 32 bytes from the operating system's random source for the PKCE
+/// verifier and state.
+fn random_bytes() -> Result<[u8; 32]> {
+    let mut bytes = [0u8; 32];
+    getrandom::fill(&mut bytes)
+        .map_err(|e| CautError::Config(format!("Cannot read system randomness: {e}")))?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn login_url_carries_pkce_challenge_and_state() {
+        let request = LoginRequest::new().unwrap();
+        let url = reqwest::Url::parse(&request.url).unwrap();
+        let params: std::collections::HashMap<_, _> = url.query_pairs().into_owned().collect();
+        assert_eq!(params["client_id"], CLIENT_ID);
+        assert_eq!(params["code_challenge_method"], "S256");
+        assert_eq!(params["state"], request.state);
+        assert_eq!(
+            params["code_challenge"],
+            URL_SAFE_NO_PAD.encode(Sha256::digest(request.verifier.as_bytes()))
+        );
+        assert_ne!(request.verifier, LoginRequest::new().unwrap().verifier);
+    }
+
+    #[test]
+    fn pasted_code_must_match_state() {
+        assert_eq!(parse_pasted_code(" abc#s1 \n", "s1").unwrap(), "abc");
+        assert_eq!(parse_pasted_code("abc", "s1").unwrap(), "abc");
+        assert!(parse_pasted_code("abc#other", "s1").is_err());
+        assert!(parse_pasted_code("  ", "s1").is_err());
+    }
+
+    #[test]
+    fn refreshes_only_sessions_about_to_expire() {
+        let now = Utc::now();
+        let session = |expires_at| StoredSession {
+            refresh_token: Some("r".to_string()),
+            expires_at,
+        };
+        assert!(session(Some(now + Duration::minutes(1))).needs_refresh(now));
+        assert!(!session(Some(now + Duration::hours(1))).needs_refresh(now));
+        assert!(!session(None).needs_refresh(now));
+    }
+}
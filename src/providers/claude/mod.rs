@@ -3,23 +3,34 @@
 //! Supports:
 //! - OAuth API (token from the keyring, Claude Code's `.credentials.json`,
 //!   or the macOS Keychain)
-//! - Web scraping (macOS only)
+//! - claude.ai web API with the browser's session cookie (macOS only)
 //! - CLI local config reading
 //! - CLI PTY
 //!
 //! Source labels: `oauth`, `web`, `claude`, `cli-local`
+//!
+//! Organization usage for admins lives in [`admin`]; preview and nightly
+//! installs next to the stable one are tracked by [`installations`];
+//! `caut login claude` is implemented in [`login`].
+
+pub mod admin;
+pub mod installations;
+pub mod login;
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use chrono::Utc;
 use serde::Deserialize;
 
 use crate::core::cli_runner::{CLI_TIMEOUT, run_command, run_json_command};
+use crate::core::clock_skew;
 use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
 use crate::core::http::{DEFAULT_TIMEOUT, build_client};
 use crate::core::models::{ProviderIdentity, RateWindow, UsageSnapshot};
 use crate::core::provider::Provider;
+use crate::core::web_session::{self, CookieSpec, WebSession};
 use crate::error::{CautError, Result};
 
 /// Source label for OAuth.
@@ -37,6 +48,18 @@ const CLI_NAME: &str = "claude";
 /// Anthropic API base URL.
 const API_BASE: &str = "https://api.anthropic.com";
 
+/// claude.ai, whose web API the web strategy reads.
+const WEB_BASE: &str = "https://claude.ai";
+
+/// The claude.ai session cookie, kept in the `caut` / `claude-web-session`
+/// keyring entry once imported.
+pub const WEB_SESSION: CookieSpec = CookieSpec {
+    provider: "claude",
+    domain: "claude.ai",
+    name: "sessionKey",
+    keyring_account: "claude-web-session",
+};
+
 // =============================================================================
 // Fetch Plan
 // =============================================================================
@@ -61,7 +84,7 @@ pub fn fetch_plan() -> FetchPlan {
                 id: "claude-web",
                 kind: FetchKind::Web,
                 is_available: || {
-                    // Web requires macOS with cookies
+                    // Web reads browser cookies, which needs macOS
                     cfg!(target_os = "macos")
                 },
                 should_fallback: |_| true,
@@ -164,6 +187,11 @@ fn get_claude_dir() -> Option<PathBuf> {
     directories::BaseDirs::new().map(|d| d.home_dir().join(".claude"))
 }
 
+/// Claude Code's credentials file, `<claude_dir>/.credentials.json`.
+pub(crate) fn credentials_file() -> Option<PathBuf> {
+    get_claude_dir().map(|d| d.join(".credentials.json"))
+}
+
 /// Check if Claude is configured locally.
 ///
 /// Considers:
@@ -222,6 +250,12 @@ struct ClaudeOauthCredentials {
     /// Token expiry as epoch milliseconds.
     #[serde(default)]
     expires_at: Option<i64>,
+    /// e.g. `pro` or `max`.
+    #[serde(default)]
+    subscription_type: Option<String>,
+    /// e.g. `default_claude_max_20x`.
+    #[serde(default)]
+    rate_limit_tier: Option<String>,
 }
 
 /// Extract a non-expired access token from a credentials JSON payload
@@ -239,6 +273,32 @@ fn token_from_credentials_json(content: &str) -> Option<String> {
     Some(token)
 }
 
+/// The subscription Claude Code is signed in with, from `.credentials.json`:
+/// `pro`, `max-5x`, `max-20x`, or the raw subscription type (plain `max`
+/// when the tier is not recorded).
+#[must_use]
+pub fn local_plan() -> Option<String> {
+    let content = fs::read_to_string(get_claude_dir()?.join(".credentials.json")).ok()?;
+    plan_from_credentials_json(&content)
+}
+
+fn plan_from_credentials_json(content: &str) -> Option<String> {
+    let oauth = serde_json::from_str::<ClaudeCredentialsFile>(content)
+        .ok()?
+        .claude_ai_oauth?;
+    let tier = oauth.rate_limit_tier.unwrap_or_default();
+    if tier.contains("max_20x") {
+        return Some("max-20x".to_string());
+    }
+    if tier.contains("max_5x") {
+        return Some("max-5x".to_string());
+    }
+    oauth
+        .subscription_type
+        .map(|plan| plan.to_lowercase())
+        .filter(|plan| !plan.is_empty())
+}
+
 /// Shape of Claude Code's main config (`~/.claude.json`): account identity
 /// lives under the top-level `oauthAccount` object.
 #[derive(Debug, Deserialize)]
@@ -271,21 +331,19 @@ fn oauth_account_from_json(content: &str) -> Option<ClaudeOauthAccount> {
 /// `CLAUDE_CONFIG_DIR` relocates the config), then the documented default
 /// `~/.claude.json`.
 fn read_oauth_account() -> Option<ClaudeOauthAccount> {
-    let mut candidates = Vec::new();
-    if let Some(dir) = get_claude_dir() {
-        candidates.push(dir.join(".claude.json"));
-    }
-    if let Some(base) = directories::BaseDirs::new() {
-        candidates.push(base.home_dir().join(".claude.json"));
-    }
-    for path in candidates {
-        if let Ok(content) = fs::read_to_string(&path)
-            && let Some(account) = oauth_account_from_json(&content)
-        {
-            return Some(account);
-        }
-    }
-    None
+    get_claude_dir()
+        .and_then(|dir| read_oauth_account_in(&dir))
+        .or_else(|| {
+            let base = directories::BaseDirs::new()?;
+            let content = fs::read_to_string(base.home_dir().join(".claude.json")).ok()?;
+            oauth_account_from_json(&content)
+        })
+}
+
+/// Read the `oauthAccount` identity from `<dir>/.claude.json`.
+fn read_oauth_account_in(dir: &Path) -> Option<ClaudeOauthAccount> {
+    let content = fs::read_to_string(dir.join(".claude.json")).ok()?;
+    oauth_account_from_json(&content)
 }
 
 /// Build a [`ProviderIdentity`] from the local `oauthAccount` config with the
@@ -329,6 +387,12 @@ struct ClaudeOauthUsageResponse {
     seven_day_sonnet: Option<ClaudeUsageWindow>,
 }
 
+/// An organization the claude.ai session belongs to.
+#[derive(Debug, Deserialize)]
+struct ClaudeWebOrganization {
+    uuid: String,
+}
+
 /// A single usage window from the OAuth usage endpoint.
 ///
 /// `utilization` is already percent-scale (e.g. `18.0` means 18% used);
@@ -374,6 +438,7 @@ pub async fn fetch_oauth(token: &str) -> Result<UsageSnapshot> {
                 CautError::Network(e.to_string())
             }
         })?;
+    clock_skew::observe(&response);
 
     if !response.status().is_success() {
         return Err(CautError::FetchFailed {
@@ -390,34 +455,87 @@ pub async fn fetch_oauth(token: &str) -> Result<UsageSnapshot> {
     Ok(parse_oauth_usage_response(&data))
 }
 
-/// Fetch usage via web scraping.
+/// Fetch usage via the claude.ai web API.
 ///
-/// This requires macOS with browser cookies available.
+/// Signs in with the browser's `sessionKey` cookie, imported once into the
+/// keyring (see [`crate::core::web_session`]), and reads the same usage
+/// windows the claude.ai settings page shows. A rejected cookie is imported
+/// again from the browser and the fetch retried once.
 ///
 /// # Errors
-/// Returns an error if web scraping is not supported on the current platform
-/// or if the scraping operation fails.
+/// Returns an error off macOS, if no browser is signed in to claude.ai, or
+/// if the request or response parsing fails.
 pub async fn fetch_web() -> Result<UsageSnapshot> {
-    #[cfg(not(target_os = "macos"))]
-    {
-        Err(CautError::UnsupportedSource {
-            provider: "claude".to_string(),
-            source_type: "web".to_string(),
-        })
+    let session = web_session::session(&WEB_SESSION)?;
+    match fetch_web_with(&session).await {
+        Err(CautError::AuthExpired { .. }) => {
+            fetch_web_with(&web_session::refresh(&WEB_SESSION)?).await
+        }
+        result => result,
     }
+}
 
-    #[cfg(target_os = "macos")]
-    {
-        // TODO: Implement actual web scraping
-        // This would involve:
-        // 1. Reading browser cookies for claude.ai
-        // 2. Making authenticated request to the web dashboard
-        // 3. Parsing the response
-        Err(CautError::FetchFailed {
+async fn fetch_web_with(session: &WebSession) -> Result<UsageSnapshot> {
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let cookie = session.cookie_header(&WEB_SESSION);
+
+    let organizations: Vec<ClaudeWebOrganization> =
+        web_get(&client, &format!("{WEB_BASE}/api/organizations"), &cookie).await?;
+    let organization = organizations
+        .first()
+        .ok_or_else(|| CautError::FetchFailed {
             provider: "claude".to_string(),
-            reason: "Web scraping not yet implemented".to_string(),
-        })
+            reason: "No organization for this claude.ai session".to_string(),
+        })?;
+    let usage: ClaudeOauthUsageResponse = web_get(
+        &client,
+        &format!("{WEB_BASE}/api/organizations/{}/usage", organization.uuid),
+        &cookie,
+    )
+    .await?;
+
+    let mut snapshot = parse_oauth_usage_response(&usage);
+    snapshot.identity = Some(local_identity_with_method(SOURCE_WEB));
+    Ok(snapshot)
+}
+
+/// GET a claude.ai API path as the signed-in user. A rejected session is
+/// reported as expired so the caller can import a fresh one.
+async fn web_get<T: serde::de::DeserializeOwned>(
+    client: &reqwest::Client,
+    url: &str,
+    cookie: &str,
+) -> Result<T> {
+    let response = client
+        .get(url)
+        .header("Cookie", cookie)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+            } else {
+                CautError::Network(e.to_string())
+            }
+        })?;
+    clock_skew::observe(&response);
+
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+        return Err(CautError::AuthExpired {
+            provider: "claude".to_string(),
+        });
     }
+    if !status.is_success() {
+        return Err(CautError::FetchFailed {
+            provider: "claude".to_string(),
+            reason: format!("HTTP {status}"),
+        });
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| CautError::ParseResponse(e.to_string()))
 }
 
 /// Fetch usage via CLI PTY.
@@ -676,6 +794,48 @@ pub fn delete_oauth_token() -> Result<()> {
         .map_err(|e| CautError::Config(format!("Failed to delete token: {e}")))
 }
 
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Claude.
+pub struct ClaudeFetcher;
+
+impl ProviderFetcher for ClaudeFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Claude
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "claude-oauth" => {
+                    if let Err(e) = login::refresh_if_expired().await {
+                        tracing::debug!(error = %e, "Claude OAuth token refresh failed");
+                    }
+                    // Same fallback chain as the strategy's availability check:
+                    // keyring -> Claude Code's .credentials.json -> macOS Keychain.
+                    let token = get_oauth_token().ok_or_else(|| {
+                        CautError::Config(
+                            "No Claude OAuth token found (checked keyring, \
+                             <claude_dir>/.credentials.json, and macOS Keychain)"
+                                .to_string(),
+                        )
+                    })?;
+                    fetch_oauth(&token).await
+                }
+                "claude-web" => fetch_web().await,
+                "claude-cli-pty" => fetch_cli().await,
+                _ => Err(unknown_strategy(Provider::Claude, strategy_id)),
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -919,6 +1079,20 @@ mod tests {
         assert!(token_from_credentials_json("not json").is_none());
     }
 
+    #[test]
+    fn plan_from_credentials_json_prefers_rate_limit_tier() {
+        let max_20x = r#"{"claudeAiOauth":{"accessToken":"t","subscriptionType":"max","rateLimitTier":"default_claude_max_20x"}}"#;
+        assert_eq!(
+            plan_from_credentials_json(max_20x).as_deref(),
+            Some("max-20x")
+        );
+        let max = r#"{"claudeAiOauth":{"accessToken":"t","subscriptionType":"max"}}"#;
+        assert_eq!(plan_from_credentials_json(max).as_deref(), Some("max"));
+        let pro = r#"{"claudeAiOauth":{"subscriptionType":"Pro"}}"#;
+        assert_eq!(plan_from_credentials_json(pro).as_deref(), Some("pro"));
+        assert!(plan_from_credentials_json(r#"{"claudeAiOauth":{}}"#).is_none());
+    }
+
     // =========================================================================
     // Main Config (oauthAccount) Identity Tests
     // =========================================================================
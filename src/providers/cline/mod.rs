@@ -0,0 +1,344 @@
+//! Cline and Roo Code provider implementation.
+//!
+//! Supports:
+//! - Local extension storage probe
+//!
+//! Source label: `local`
+//!
+//! Cline and its fork Roo Code call model APIs with the user's own keys, so
+//! there is no quota to report. Both record every task's token counts and API
+//! cost in the editor's extension storage: `state/taskHistory.json` (Cline),
+//! `tasks/<id>/history_item.json` (Roo Code), or a `taskHistory` list in the
+//! editor's global state database (older releases). Tasks from every VS Code
+//! based editor are merged by task ID; `caut cost` reports them per day and
+//! `caut usage` shows today's and the last 30 days' spend.
+//!
+//! Set `CAUT_CLINE_STORAGE_DIRS` to a path list of extension storage
+//! directories to read only those.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{EnrichFuture, FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::models::{ProviderPayload, TaskSpend, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::core::session_logs::{SessionUsage, spend_from_sessions};
+use crate::error::{CautError, Result};
+use crate::providers::vscode_state::StateDb;
+
+/// Environment variable listing extension storage directories to read.
+pub const STORAGE_DIRS_ENV: &str = "CAUT_CLINE_STORAGE_DIRS";
+
+/// Extension IDs as used in the editor's global state database.
+const EXTENSION_IDS: &[&str] = &["saoudrizwan.claude-dev", "RooVeterinaryInc.roo-cline"];
+
+/// VS Code based editors, by application directory name.
+const EDITORS: &[&str] = &["Code", "Code - Insiders", "VSCodium", "Cursor", "Windsurf"];
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Cline.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Cline,
+        vec![FetchStrategy {
+            id: "cline-local",
+            kind: FetchKind::LocalProbe,
+            is_available: || !storage_dirs().is_empty() || !state_dbs().is_empty(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// Task History
+// =============================================================================
+
+/// One task from an extension's task history (`HistoryItem`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HistoryItem {
+    id: String,
+    /// Task start, milliseconds since the epoch.
+    ts: i64,
+    #[serde(default)]
+    tokens_in: i64,
+    #[serde(default)]
+    tokens_out: i64,
+    #[serde(default)]
+    cache_writes: Option<i64>,
+    #[serde(default)]
+    cache_reads: Option<i64>,
+    #[serde(default)]
+    total_cost: f64,
+    /// Directory the task started in (Cline).
+    #[serde(default)]
+    cwd_on_task_initialization: Option<String>,
+    /// Workspace the task ran in (Roo Code).
+    #[serde(default)]
+    workspace: Option<String>,
+}
+
+impl HistoryItem {
+    fn into_session(self) -> SessionUsage {
+        let started_at = DateTime::<Utc>::from_timestamp_millis(self.ts);
+        let cwd = self
+            .cwd_on_task_initialization
+            .or(self.workspace)
+            .map(PathBuf::from);
+        SessionUsage {
+            session_id: self.id,
+            project_path: cwd.clone(),
+            cwd,
+            started_at,
+            ended_at: started_at,
+            input_tokens: self.tokens_in,
+            output_tokens: self.tokens_out,
+            cache_read_tokens: self.cache_reads.unwrap_or(0),
+            cache_creation_tokens: self.cache_writes.unwrap_or(0),
+            message_count: 1,
+            reported_cost_usd: Some(self.total_cost),
+            ..SessionUsage::default()
+        }
+    }
+}
+
+/// Extension storage directories that exist on this machine.
+fn storage_dirs() -> Vec<PathBuf> {
+    if let Some(dirs) = std::env::var_os(STORAGE_DIRS_ENV)
+        && !dirs.is_empty()
+    {
+        return std::env::split_paths(&dirs)
+            .filter(|d| d.is_dir())
+            .collect();
+    }
+    let Some(config) = directories::BaseDirs::new().map(|d| d.config_dir().to_path_buf()) else {
+        return Vec::new();
+    };
+    EDITORS
+        .iter()
+        .flat_map(|editor| {
+            let global = config.join(editor).join("User/globalStorage");
+            EXTENSION_IDS
+                .iter()
+                .map(move |id| global.join(id.to_lowercase()))
+        })
+        .filter(|dir| dir.is_dir())
+        .collect()
+}
+
+/// Editor global state databases, unless storage directories are overridden.
+fn state_dbs() -> Vec<PathBuf> {
+    if std::env::var_os(STORAGE_DIRS_ENV).is_some_and(|dirs| !dirs.is_empty()) {
+        return Vec::new();
+    }
+    let Some(config) = directories::BaseDirs::new().map(|d| d.config_dir().to_path_buf()) else {
+        return Vec::new();
+    };
+    EDITORS
+        .iter()
+        .map(|editor| config.join(editor).join("User/globalStorage/state.vscdb"))
+        .filter(|path| path.is_file())
+        .collect()
+}
+
+/// Tasks recorded in one extension storage directory.
+fn read_storage_dir(dir: &Path) -> Vec<HistoryItem> {
+    let mut items: Vec<HistoryItem> = fs::read_to_string(dir.join("state/taskHistory.json"))
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default();
+
+    if let Ok(tasks) = fs::read_dir(dir.join("tasks")) {
+        for task in tasks.flatten() {
+            let path = task.path().join("history_item.json");
+            if let Some(item) = fs::read_to_string(&path)
+                .ok()
+                .and_then(|raw| serde_json::from_str::<HistoryItem>(&raw).ok())
+            {
+                items.push(item);
+            }
+        }
+    }
+
+    items
+}
+
+/// Tasks in the `taskHistory` lists an editor keeps in its global state.
+fn read_state_db(path: &Path) -> Vec<HistoryItem> {
+    let db = match StateDb::open(path, "editor") {
+        Ok(db) => db,
+        Err(e) => {
+            tracing::debug!(?path, error = %e, "Skipping editor state database");
+            return Vec::new();
+        }
+    };
+    EXTENSION_IDS
+        .iter()
+        .filter_map(|id| db.get(id))
+        .flat_map(|raw| parse_global_state(&raw))
+        .collect()
+}
+
+fn parse_global_state(raw: &str) -> Vec<HistoryItem> {
+    serde_json::from_str::<Value>(raw)
+        .ok()
+        .and_then(|state| state.get("taskHistory").cloned())
+        .and_then(|history| serde_json::from_value(history).ok())
+        .unwrap_or_default()
+}
+
+/// Drop repeated task IDs, keeping the first record of each.
+fn dedup_tasks(items: Vec<HistoryItem>) -> Vec<HistoryItem> {
+    let mut seen = HashSet::new();
+    items
+        .into_iter()
+        .filter(|item| seen.insert(item.id.clone()))
+        .collect()
+}
+
+/// Every recorded Cline and Roo Code task, as one session per task with the
+/// extension's own cost.
+#[must_use]
+pub fn task_sessions() -> Vec<SessionUsage> {
+    let mut items: Vec<HistoryItem> = storage_dirs()
+        .iter()
+        .flat_map(|dir| read_storage_dir(dir))
+        .collect();
+    items.extend(state_dbs().iter().flat_map(|path| read_state_db(path)));
+    dedup_tasks(items)
+        .into_iter()
+        .map(HistoryItem::into_session)
+        .collect()
+}
+
+/// Spend recorded in Cline and Roo Code task histories.
+///
+/// # Errors
+/// Returns an error if no task history can be found.
+pub fn task_spend() -> Result<TaskSpend> {
+    let sessions = task_sessions();
+    if sessions.is_empty() {
+        return Err(CautError::Config(
+            "No Cline or Roo Code task history found".to_string(),
+        ));
+    }
+    Ok(spend_from_sessions(&sessions, Local::now()))
+}
+
+/// Confirm task history exists and report when the latest task started.
+///
+/// # Errors
+/// Returns an error if no task history can be found.
+pub async fn fetch_local() -> Result<UsageSnapshot> {
+    let latest = task_sessions()
+        .into_iter()
+        .filter_map(|session| session.started_at)
+        .max()
+        .ok_or_else(|| {
+            CautError::Config(
+                "No Cline or Roo Code task history found; run a task in the extension first"
+                    .to_string(),
+            )
+        })?;
+
+    Ok(UsageSnapshot {
+        primary: None,
+        secondary: None,
+        tertiary: None,
+        updated_at: latest,
+        identity: None,
+    })
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Cline.
+pub struct ClineFetcher;
+
+impl ProviderFetcher for ClineFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Cline
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "cline-local" => fetch_local().await,
+                _ => Err(unknown_strategy(Provider::Cline, strategy_id)),
+            }
+        })
+    }
+
+    /// Attach the spend recorded in Cline's local task history.
+    fn enrich<'a>(&'a self, payload: &'a mut ProviderPayload) -> EnrichFuture<'a> {
+        payload.task_spend = task_spend()
+            .map_err(|e| tracing::warn!("Failed to read Cline task spend: {}", e))
+            .ok();
+        Box::pin(std::future::ready(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const TASK_HISTORY: &str = r#"[
+        {"id": "1760600000000", "ts": 1760600000000, "task": "Fix the build", "tokensIn": 1200, "tokensOut": 340, "cacheWrites": 5000, "cacheReads": 42000, "totalCost": 0.0871, "size": 1024, "cwdOnTaskInitialization": "/work/api"},
+        {"id": "1760610000000", "ts": 1760610000000, "task": "Add tests", "tokensIn": 800, "tokensOut": 120, "totalCost": 0.012}
+    ]"#;
+
+    #[test]
+    fn reads_cline_and_roo_task_files() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("state")).unwrap();
+        fs::write(dir.path().join("state/taskHistory.json"), TASK_HISTORY).unwrap();
+        let task = dir.path().join("tasks/roo-1");
+        fs::create_dir_all(&task).unwrap();
+        fs::write(
+            task.join("history_item.json"),
+            r#"{"id": "roo-1", "number": 3, "ts": 1760620000000, "task": "Refactor", "tokensIn": 10, "tokensOut": 5, "totalCost": 0.5, "workspace": "/work/web", "mode": "code"}"#,
+        )
+        .unwrap();
+
+        let items = read_storage_dir(dir.path());
+        assert_eq!(items.len(), 3);
+
+        let session = items[0].clone().into_session();
+        assert_eq!(session.input_tokens, 1200);
+        assert_eq!(session.cache_read_tokens, 42_000);
+        assert_eq!(session.cwd, Some(PathBuf::from("/work/api")));
+        assert_eq!(session.reported_cost_usd, Some(0.0871));
+        assert_eq!(
+            items[2].clone().into_session().cwd,
+            Some(PathBuf::from("/work/web"))
+        );
+    }
+
+    #[test]
+    fn parses_global_state_and_dedups_tasks() {
+        let raw = format!(r#"{{"taskHistory": {TASK_HISTORY}, "mode": "act"}}"#);
+        let mut items = parse_global_state(&raw);
+        assert_eq!(items.len(), 2);
+
+        items.extend(parse_global_state(&raw));
+        assert_eq!(dedup_tasks(items).len(), 2);
+        assert!(parse_global_state("not json").is_empty());
+    }
+}
@@ -0,0 +1,333 @@
+//! `OpenAI` organization usage via the Administration API.
+//!
+//! The completions usage report is read in daily buckets grouped by user, API
+//! key, and model, and user IDs are resolved to emails through the
+//! organization's user list. `OpenAI`'s costs endpoint cannot be grouped by
+//! user, so each seat's cost is estimated from its tokens with caut's pricing
+//! table. It needs an admin key (`sk-admin-...`) from the `OpenAI` platform
+//! settings, read from `OPENAI_ADMIN_KEY`.
+
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{Duration, NaiveDate, NaiveTime};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::core::clock_skew;
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::core::org_usage::{OrgUsageReport, SeatUsage};
+use crate::core::pricing::PricingTable;
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+
+/// Environment variable holding the admin key.
+pub const ADMIN_KEY_ENV: &str = "OPENAI_ADMIN_KEY";
+
+/// `OpenAI` API base URL.
+const API_BASE: &str = "https://api.openai.com/v1";
+
+/// Daily buckets requested per page (the API maximum for `1d` buckets).
+const BUCKET_LIMIT: u32 = 31;
+
+/// Users requested per page (the API maximum).
+const USERS_LIMIT: u32 = 100;
+
+#[derive(Debug, Deserialize)]
+struct UsagePage {
+    #[serde(default)]
+    data: Vec<UsageBucket>,
+    #[serde(default)]
+    has_more: bool,
+    #[serde(default)]
+    next_page: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UsageBucket {
+    #[serde(default)]
+    results: Vec<UsageResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UsageResult {
+    /// All input tokens, including cached ones.
+    #[serde(default)]
+    input_tokens: i64,
+    #[serde(default)]
+    output_tokens: i64,
+    #[serde(default)]
+    input_cached_tokens: i64,
+    #[serde(default)]
+    num_model_requests: i64,
+    #[serde(default)]
+    user_id: Option<String>,
+    #[serde(default)]
+    api_key_id: Option<String>,
+    #[serde(default)]
+    model: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UsersPage {
+    #[serde(default)]
+    data: Vec<OrgUser>,
+    #[serde(default)]
+    has_more: bool,
+    #[serde(default)]
+    last_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OrgUser {
+    id: String,
+    #[serde(default)]
+    email: Option<String>,
+}
+
+fn admin_key() -> Result<String> {
+    std::env::var(ADMIN_KEY_ENV)
+        .ok()
+        .map(|key| key.trim().to_string())
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| {
+            CautError::Config(format!(
+                "Set {ADMIN_KEY_ENV} to an admin key (OpenAI platform > Organization > Admin keys)"
+            ))
+        })
+}
+
+fn seat_from_result(
+    result: UsageResult,
+    emails: &HashMap<String, String>,
+    pricing: &PricingTable,
+) -> SeatUsage {
+    let member = match (result.user_id, result.api_key_id) {
+        (Some(user_id), _) => emails.get(&user_id).cloned().unwrap_or(user_id),
+        (None, Some(key_id)) => format!("api key: {key_id}"),
+        (None, None) => "unattributed".to_string(),
+    };
+    let uncached_input = (result.input_tokens - result.input_cached_tokens).max(0);
+    let mut seat = SeatUsage {
+        member,
+        sessions: result.num_model_requests,
+        input_tokens: uncached_input,
+        output_tokens: result.output_tokens,
+        cache_read_tokens: result.input_cached_tokens,
+        ..SeatUsage::default()
+    };
+    if let Some(model) = result.model {
+        let (model_pricing, _) = pricing.get_or_estimate(&model);
+        seat.cost_usd = model_pricing
+            .calculate_cost(
+                uncached_input,
+                result.output_tokens,
+                result.input_cached_tokens,
+                0,
+            )
+            .total_cost_usd;
+        seat.models = BTreeSet::from([model]);
+    }
+    seat
+}
+
+async fn get_json<T: DeserializeOwned>(
+    client: &reqwest::Client,
+    key: &str,
+    url: reqwest::Url,
+) -> Result<T> {
+    let response = client.get(url).bearer_auth(key).send().await.map_err(|e| {
+        if e.is_timeout() {
+            CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+        } else {
+            CautError::Network(e.to_string())
+        }
+    })?;
+    clock_skew::observe(&response);
+
+    let status = response.status();
+    if !status.is_success() {
+        let reason = if matches!(status.as_u16(), 401 | 403) {
+            format!("{ADMIN_KEY_ENV} was rejected; it must be an admin key")
+        } else {
+            format!("organization API request returned {status}")
+        };
+        return Err(CautError::FetchFailed {
+            provider: Provider::Codex.cli_name().to_string(),
+            reason,
+        });
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| CautError::ParseResponse(format!("Invalid OpenAI organization response: {e}")))
+}
+
+fn api_url(path: &str) -> Result<reqwest::Url> {
+    reqwest::Url::parse(&format!("{API_BASE}{path}"))
+        .map_err(|e| CautError::Config(format!("Invalid OpenAI admin URL: {e}")))
+}
+
+/// Map of user ID to email for every member of the organization.
+async fn fetch_emails(client: &reqwest::Client, key: &str) -> Result<HashMap<String, String>> {
+    let mut emails = HashMap::new();
+    let mut after: Option<String> = None;
+
+    loop {
+        let mut url = api_url("/organization/users")?;
+        url.query_pairs_mut()
+            .append_pair("limit", &USERS_LIMIT.to_string());
+        if let Some(cursor) = &after {
+            url.query_pairs_mut().append_pair("after", cursor);
+        }
+
+        let page: UsersPage = get_json(client, key, url).await?;
+        emails.extend(
+            page.data
+                .into_iter()
+                .filter_map(|user| user.email.map(|email| (user.id, email))),
+        );
+
+        match page.last_id {
+            Some(last) if page.has_more => after = Some(last),
+            _ => break,
+        }
+    }
+
+    Ok(emails)
+}
+
+/// Fetch per-seat `OpenAI` usage for the UTC days from `start` to `end`.
+///
+/// # Errors
+/// Returns an error if no admin key is configured, a request fails or is
+/// rejected, or a response cannot be parsed.
+pub async fn fetch_org_usage(start: NaiveDate, end: NaiveDate) -> Result<OrgUsageReport> {
+    let key = admin_key()?;
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let emails = fetch_emails(&client, &key).await?;
+    let pricing = PricingTable::current();
+
+    let start_time = start.and_time(NaiveTime::MIN).and_utc().timestamp();
+    let end_time = (end + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc()
+        .timestamp();
+
+    let mut records = Vec::new();
+    let mut page: Option<String> = None;
+    loop {
+        let mut url = api_url("/organization/usage/completions")?;
+        url.query_pairs_mut()
+            .append_pair("start_time", &start_time.to_string())
+            .append_pair("end_time", &end_time.to_string())
+            .append_pair("bucket_width", "1d")
+            .append_pair("group_by", "user_id")
+            .append_pair("group_by", "api_key_id")
+            .append_pair("group_by", "model")
+            .append_pair("limit", &BUCKET_LIMIT.to_string());
+        if let Some(cursor) = &page {
+            url.query_pairs_mut().append_pair("page", cursor);
+        }
+
+        tracing::debug!(%start, %end, "Fetching OpenAI completions usage");
+        let body: UsagePage = get_json(&client, &key, url).await?;
+        records.extend(
+            body.data
+                .into_iter()
+                .flat_map(|bucket| bucket.results)
+                .map(|result| seat_from_result(result, &emails, &pricing)),
+        );
+
+        match body.next_page {
+            Some(next) if body.has_more => page = Some(next),
+            _ => break,
+        }
+    }
+
+    Ok(OrgUsageReport::new("openai", start, end, records))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"{
+        "object": "page",
+        "data": [
+            {
+                "object": "bucket",
+                "start_time": 1790812800,
+                "end_time": 1790899200,
+                "results": [
+                    {
+                        "object": "organization.usage.completions.result",
+                        "input_tokens": 12000,
+                        "output_tokens": 3000,
+                        "input_cached_tokens": 2000,
+                        "num_model_requests": 7,
+                        "user_id": "user-ana",
+                        "api_key_id": "key_abc",
+                        "model": "gpt-5"
+                    },
+                    {
+                        "object": "organization.usage.completions.result",
+                        "input_tokens": 500,
+                        "output_tokens": 100,
+                        "input_cached_tokens": 0,
+                        "num_model_requests": 2,
+                        "user_id": null,
+                        "api_key_id": "key_ci",
+                        "model": "gpt-5-mini"
+                    }
+                ]
+            }
+        ],
+        "has_more": false,
+        "next_page": null
+    }"#;
+
+    #[test]
+    fn parses_usage_results_into_seats() {
+        let page: UsagePage = serde_json::from_str(PAGE).unwrap();
+        assert!(!page.has_more);
+        let emails = HashMap::from([("user-ana".to_string(), "ana@example.com".to_string())]);
+        let pricing = PricingTable::current();
+        let seats: Vec<SeatUsage> = page
+            .data
+            .into_iter()
+            .flat_map(|bucket| bucket.results)
+            .map(|result| seat_from_result(result, &emails, &pricing))
+            .collect();
+
+        assert_eq!(seats.len(), 2);
+        let ana = &seats[0];
+        assert_eq!(ana.member, "ana@example.com");
+        assert_eq!(ana.sessions, 7);
+        assert_eq!(ana.input_tokens, 10_000);
+        assert_eq!(ana.cache_read_tokens, 2000);
+        assert_eq!(ana.total_tokens(), 15_000);
+        assert!(ana.cost_usd > 0.0);
+        assert!(ana.models.contains("gpt-5"));
+
+        assert_eq!(seats[1].member, "api key: key_ci");
+    }
+
+    #[test]
+    fn unknown_users_keep_their_id() {
+        let result = UsageResult {
+            input_tokens: 10,
+            output_tokens: 5,
+            input_cached_tokens: 0,
+            num_model_requests: 1,
+            user_id: Some("user-gone".to_string()),
+            api_key_id: None,
+            model: None,
+        };
+        let seat = seat_from_result(result, &HashMap::new(), &PricingTable::current());
+
+        assert_eq!(seat.member, "user-gone");
+        assert!(seat.cost_usd.abs() < f64::EPSILON);
+        assert!(seat.models.is_empty());
+    }
+}
@@ -10,6 +10,10 @@
 //! The CLI local config reads identity and subscription info from
 //! `~/.codex/auth.json`, which contains JWT tokens with embedded claims
 //! about the user's plan type and subscription status.
+//!
+//! Organization usage for `OpenAI` admins lives in [`admin`].
+
+pub mod admin;
 
 use std::fs;
 use std::path::PathBuf;
@@ -19,8 +23,10 @@ use serde::Deserialize;
 
 use crate::core::cli_runner::{CLI_TIMEOUT, run_command, run_json_command};
 use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
-use crate::core::models::{CreditsSnapshot, ProviderIdentity, RateWindow, UsageSnapshot};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::models::{CreditsSnapshot, ProviderIdentity, UsageSnapshot};
 use crate::core::provider::Provider;
+use crate::core::rate_window::{RawLength, RawReset, RawUsage, RawWindow};
 use crate::error::{CautError, Result};
 
 /// Source label for web dashboard.
@@ -89,6 +95,11 @@ fn get_codex_dir() -> Option<PathBuf> {
     directories::BaseDirs::new().map(|d| d.home_dir().join(".codex"))
 }
 
+/// Codex's credentials file, `<codex_dir>/auth.json`.
+pub(crate) fn auth_file() -> Option<PathBuf> {
+    get_codex_dir().map(|d| d.join("auth.json"))
+}
+
 /// Auth.json structure from ~/.codex/auth.json
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -187,6 +198,17 @@ fn read_local_auth() -> Option<CodexAuthJson> {
     serde_json::from_str(&content).ok()
 }
 
+/// The `ChatGPT` plan Codex is signed in with (`plus`, `pro`, `team`, ...),
+/// from the ID token in `auth.json`.
+#[must_use]
+pub fn local_plan() -> Option<String> {
+    let (_, subscription) = get_local_identity()?;
+    subscription?
+        .plan_type
+        .map(|plan| plan.to_lowercase())
+        .filter(|plan| !plan.is_empty())
+}
+
 /// Check if user is authenticated with Codex
 fn is_authenticated() -> bool {
     read_local_auth().is_some_and(|auth| auth.openai_api_key.is_some() || auth.tokens.is_some())
@@ -523,28 +545,25 @@ fn parse_rate_limit_response(
 ) -> UsageSnapshot {
     let now = Utc::now();
 
-    let primary = response.rate_limit.as_ref().and_then(|rl| {
-        rl.remaining_percent.map(|pct| {
-            let used = 100.0 - pct;
-            RateWindow {
-                used_percent: used,
-                window_minutes: None,
-                resets_at: rl.resets_at.as_ref().and_then(|s| s.parse().ok()),
-                reset_description: None,
-            }
-        })
-    });
-
+    // Codex reports what is left; the session window's length is not sent.
+    let window = |remaining: Option<f64>, resets_at: Option<&str>, minutes: i64| {
+        let mut raw = RawWindow::new(RawUsage::RemainingPercent(remaining?))
+            .with_length(RawLength::Minutes(minutes));
+        if let Some(at) = resets_at {
+            raw = raw.with_reset(RawReset::Timestamp(at.to_string()));
+        }
+        raw.normalize(now)
+    };
+    let primary = response
+        .rate_limit
+        .as_ref()
+        .and_then(|rl| window(rl.remaining_percent, rl.resets_at.as_deref(), 5 * 60));
     let secondary = response.rate_limit.as_ref().and_then(|rl| {
-        rl.weekly_remaining_percent.map(|pct| {
-            let used = 100.0 - pct;
-            RateWindow {
-                used_percent: used,
-                window_minutes: Some(10080), // 7 days in minutes
-                resets_at: rl.weekly_resets_at.as_ref().and_then(|s| s.parse().ok()),
-                reset_description: None,
-            }
-        })
+        window(
+            rl.weekly_remaining_percent,
+            rl.weekly_resets_at.as_deref(),
+            7 * 24 * 60,
+        )
     });
 
     let identity = Some(ProviderIdentity {
@@ -606,6 +625,33 @@ pub async fn fetch_credits() -> Result<CreditsSnapshot> {
     })
 }
 
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Codex.
+pub struct CodexFetcher;
+
+impl ProviderFetcher for CodexFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Codex
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "codex-web-dashboard" => fetch_web_dashboard().await,
+                "codex-cli-rpc" => fetch_cli().await,
+                _ => Err(unknown_strategy(Provider::Codex, strategy_id)),
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
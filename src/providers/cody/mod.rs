@@ -0,0 +1,351 @@
+//! Sourcegraph Cody provider implementation.
+//!
+//! Supports:
+//! - Sourcegraph GraphQL API (Cody chat and autocomplete quota)
+//!
+//! Source label: `api`
+//!
+//! Cody Free has monthly caps on chat messages and autocomplete suggestions;
+//! Pro lifts them. The current user's usage and caps for the billing period
+//! are read from the instance's GraphQL API. Window mapping: primary = chat
+//! messages, secondary = autocompletions. A cap of zero or less means
+//! unlimited, and that window is left empty.
+//!
+//! Token resolution: `--api-key`, `SRC_ACCESS_TOKEN`, keyring, config (see
+//! [`crate::providers::api_key`]), then the `src` CLI config file
+//! (`SRC_CONFIG`, default `~/.src-config.json`). The instance comes from
+//! `SRC_ENDPOINT`, the `src` config, or defaults to sourcegraph.com.
+
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::core::clock_skew;
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::core::models::{ProviderIdentity, RateWindow, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::providers::api_key::ApiKeyProvider;
+
+/// Environment variable holding the Sourcegraph access token.
+pub const TOKEN_ENV: &str = "SRC_ACCESS_TOKEN";
+
+/// Environment variable naming the Sourcegraph instance.
+pub const ENDPOINT_ENV: &str = "SRC_ENDPOINT";
+
+/// Environment variable pointing at the `src` CLI config file.
+pub const SRC_CONFIG_ENV: &str = "SRC_CONFIG";
+
+/// Instance used when none is configured.
+const DEFAULT_ENDPOINT: &str = "https://sourcegraph.com";
+
+const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::Cody).env(TOKEN_ENV);
+
+const QUOTA_QUERY: &str = "query CautCodyQuota {
+  currentUser {
+    username
+    primaryEmail { email }
+    codySubscription { plan }
+    codyCurrentPeriodChatUsage
+    codyCurrentPeriodChatLimit
+    codyCurrentPeriodCodeUsage
+    codyCurrentPeriodCodeLimit
+    codyCurrentPeriodEndDate
+  }
+}";
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Sourcegraph Cody.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Cody,
+        vec![FetchStrategy {
+            id: "cody-api",
+            kind: FetchKind::ApiToken,
+            is_available: || credentials().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// Local Credentials
+// =============================================================================
+
+/// `src` CLI config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SrcConfig {
+    #[serde(default)]
+    endpoint: Option<String>,
+    #[serde(default)]
+    access_token: Option<String>,
+}
+
+fn src_config_path() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var(SRC_CONFIG_ENV)
+        && !path.trim().is_empty()
+    {
+        return Some(PathBuf::from(path.trim()));
+    }
+    directories::BaseDirs::new().map(|d| d.home_dir().join(".src-config.json"))
+}
+
+fn read_src_config() -> SrcConfig {
+    src_config_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Instance URL and access token, if a token is configured.
+fn credentials() -> Option<(String, String)> {
+    let config = read_src_config();
+    let token = SPEC.api_key().or_else(|| {
+        config
+            .access_token
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+    })?;
+    let endpoint = std::env::var(ENDPOINT_ENV)
+        .ok()
+        .or(config.endpoint)
+        .map(|e| e.trim().trim_end_matches('/').to_string())
+        .filter(|e| !e.is_empty())
+        .unwrap_or_else(|| DEFAULT_ENDPOINT.to_string());
+    Some((endpoint, token))
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse {
+    #[serde(default)]
+    data: Option<QuotaData>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QuotaData {
+    current_user: Option<CurrentUser>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CurrentUser {
+    username: String,
+    #[serde(default)]
+    primary_email: Option<PrimaryEmail>,
+    #[serde(default)]
+    cody_subscription: Option<CodySubscription>,
+    #[serde(default)]
+    cody_current_period_chat_usage: i64,
+    #[serde(default)]
+    cody_current_period_chat_limit: i64,
+    #[serde(default)]
+    cody_current_period_code_usage: i64,
+    #[serde(default)]
+    cody_current_period_code_limit: i64,
+    #[serde(default)]
+    cody_current_period_end_date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PrimaryEmail {
+    email: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CodySubscription {
+    plan: String,
+}
+
+/// Fetch Cody quota from the Sourcegraph GraphQL API.
+///
+/// # Errors
+/// Returns an error if no access token is configured, the request fails or
+/// is rejected, or the response has no current user.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    let (endpoint, token) = credentials().ok_or_else(|| {
+        CautError::Config(format!(
+            "No Sourcegraph access token found (set {TOKEN_ENV} or run: src login)"
+        ))
+    })?;
+
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let response = client
+        .post(format!("{endpoint}/.api/graphql"))
+        .header("Authorization", format!("token {token}"))
+        .json(&serde_json::json!({ "query": QUOTA_QUERY }))
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+            } else {
+                CautError::Network(e.to_string())
+            }
+        })?;
+    clock_skew::observe(&response);
+
+    let status = response.status();
+    if !status.is_success() {
+        let reason = if matches!(status.as_u16(), 401 | 403) {
+            "access token was rejected".to_string()
+        } else {
+            format!("GraphQL request returned {status}")
+        };
+        return Err(CautError::FetchFailed {
+            provider: Provider::Cody.cli_name().to_string(),
+            reason,
+        });
+    }
+
+    let body: GraphQlResponse = response
+        .json()
+        .await
+        .map_err(|e| CautError::ParseResponse(format!("Invalid Sourcegraph response: {e}")))?;
+    usage_from_response(body)
+}
+
+fn usage_from_response(body: GraphQlResponse) -> Result<UsageSnapshot> {
+    if let Some(error) = body.errors.first() {
+        return Err(CautError::FetchFailed {
+            provider: Provider::Cody.cli_name().to_string(),
+            reason: error.message.clone(),
+        });
+    }
+    let user = body
+        .data
+        .and_then(|data| data.current_user)
+        .ok_or_else(|| {
+            CautError::ParseResponse("Sourcegraph returned no current user".to_string())
+        })?;
+
+    let resets_at = user.cody_current_period_end_date;
+    Ok(UsageSnapshot {
+        primary: quota_window(
+            user.cody_current_period_chat_usage,
+            user.cody_current_period_chat_limit,
+            "chat messages",
+            resets_at,
+        ),
+        secondary: quota_window(
+            user.cody_current_period_code_usage,
+            user.cody_current_period_code_limit,
+            "autocompletions",
+            resets_at,
+        ),
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: Some(ProviderIdentity {
+            account_email: Some(
+                user.primary_email
+                    .map_or(user.username, |primary| primary.email),
+            ),
+            account_organization: None,
+            login_method: user.cody_subscription.map(|s| s.plan.to_lowercase()),
+        }),
+    })
+}
+
+#[allow(clippy::cast_precision_loss)] // quota counts are small
+fn quota_window(
+    used: i64,
+    limit: i64,
+    label: &str,
+    resets_at: Option<DateTime<Utc>>,
+) -> Option<RateWindow> {
+    if limit <= 0 {
+        return None;
+    }
+    Some(RateWindow {
+        used_percent: (used as f64 / limit as f64 * 100.0).clamp(0.0, 100.0),
+        window_minutes: None,
+        resets_at,
+        reset_description: Some(format!("{used}/{limit} {label}")),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(json: &str) -> GraphQlResponse {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn free_plan_maps_chat_and_autocomplete() {
+        let usage = usage_from_response(response(
+            r#"{"data": {"currentUser": {
+                "username": "dev",
+                "primaryEmail": {"email": "dev@example.com"},
+                "codySubscription": {"plan": "FREE"},
+                "codyCurrentPeriodChatUsage": 50,
+                "codyCurrentPeriodChatLimit": 200,
+                "codyCurrentPeriodCodeUsage": 1000,
+                "codyCurrentPeriodCodeLimit": 2000,
+                "codyCurrentPeriodEndDate": "2026-11-01T00:00:00Z"
+            }}}"#,
+        ))
+        .unwrap();
+
+        let chat = usage.primary.unwrap();
+        assert!((chat.used_percent - 25.0).abs() < f64::EPSILON);
+        assert_eq!(
+            chat.reset_description.as_deref(),
+            Some("50/200 chat messages")
+        );
+        assert!(chat.resets_at.is_some());
+        assert!((usage.secondary.unwrap().used_percent - 50.0).abs() < f64::EPSILON);
+        let identity = usage.identity.unwrap();
+        assert_eq!(identity.account_email.as_deref(), Some("dev@example.com"));
+        assert_eq!(identity.login_method.as_deref(), Some("free"));
+    }
+
+    #[test]
+    fn pro_plan_has_no_capped_windows() {
+        let usage = usage_from_response(response(
+            r#"{"data": {"currentUser": {
+                "username": "dev",
+                "codySubscription": {"plan": "PRO"},
+                "codyCurrentPeriodChatUsage": 900,
+                "codyCurrentPeriodChatLimit": -1,
+                "codyCurrentPeriodCodeUsage": 5000,
+                "codyCurrentPeriodCodeLimit": 0
+            }}}"#,
+        ))
+        .unwrap();
+
+        assert!(usage.primary.is_none());
+        assert!(usage.secondary.is_none());
+        assert_eq!(
+            usage.identity.unwrap().account_email.as_deref(),
+            Some("dev")
+        );
+    }
+
+    #[test]
+    fn graphql_errors_fail_the_fetch() {
+        let result = usage_from_response(response(
+            r#"{"data": null, "errors": [{"message": "Cannot query field"}]}"#,
+        ));
+        assert!(matches!(result, Err(CautError::FetchFailed { .. })));
+    }
+}
@@ -0,0 +1,68 @@
+//! Cohere provider implementation.
+//!
+//! Supports:
+//! - Cohere API (rate-limit headers)
+//!
+//! Source label: `api`
+//!
+//! Trial keys are limited per minute and per month, and Cohere reports both
+//! on every response. Window mapping: primary = calls per minute,
+//! secondary = calls per month. Production keys carry no such headers.
+//!
+//! Key resolution: `--api-key`, `COHERE_API_KEY`, keyring, then config (see
+//! [`crate::providers::api_key`]).
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::models::UsageSnapshot;
+use crate::core::provider::Provider;
+use crate::error::Result;
+use crate::providers::api_key::ApiKeyProvider;
+use crate::providers::rate_limits::HeaderLimit;
+
+/// Environment variable holding the Cohere API key.
+pub const API_KEY_ENV: &str = "COHERE_API_KEY";
+
+const CALLS_PER_MINUTE: HeaderLimit = HeaderLimit {
+    limit: "x-trial-endpoint-call-limit",
+    remaining: "x-trial-endpoint-call-remaining",
+    reset: "x-trial-endpoint-call-reset",
+    window_minutes: Some(1),
+    unit: "calls/min",
+};
+
+const CALLS_PER_MONTH: HeaderLimit = HeaderLimit {
+    limit: "x-endpoint-monthly-call-limit",
+    remaining: "x-endpoint-monthly-call-remaining",
+    reset: "x-endpoint-monthly-call-reset",
+    window_minutes: Some(30 * 24 * 60),
+    unit: "calls/month",
+};
+
+const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::Cohere)
+    .env(API_KEY_ENV)
+    .probe("https://api.cohere.com/v1/models")
+    .primary(CALLS_PER_MINUTE)
+    .secondary(CALLS_PER_MONTH);
+
+/// Create fetch plan for Cohere.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Cohere,
+        vec![FetchStrategy {
+            id: "cohere-api",
+            kind: FetchKind::ApiToken,
+            is_available: || SPEC.api_key().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+/// Fetch rate-limit usage from the Cohere API.
+///
+/// # Errors
+/// Returns an error if no API key is set, the request fails, or the response
+/// carries no rate-limit headers.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    SPEC.fetch_usage().await
+}
@@ -0,0 +1,275 @@
+//! Continue provider implementation.
+//!
+//! Supports:
+//! - Local dev data probe
+//!
+//! Source label: `local`
+//!
+//! Continue calls models with the user's own keys or a local runtime, so
+//! there is no quota to report. It logs one `tokensGenerated` event per model
+//! call to `~/.continue/dev_data/<schema>/tokensGenerated.jsonl` with the
+//! model name and prompt and generated token counts; each event is
+//! read as a session and priced by model for `caut cost`. Schema 0.1.0
+//! events carry no timestamp, so the chat session index
+//! (`sessions/sessions.json`) also dates the latest activity.
+//!
+//! Set `CONTINUE_GLOBAL_DIR` to read a different Continue directory, as
+//! Continue itself does.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::models::UsageSnapshot;
+use crate::core::provider::Provider;
+use crate::core::session_logs::SessionUsage;
+use crate::error::{CautError, Result};
+
+/// Environment variable overriding the Continue directory.
+pub const GLOBAL_DIR_ENV: &str = "CONTINUE_GLOBAL_DIR";
+
+/// Dev data file holding one event per model call.
+const TOKENS_FILE: &str = "tokensGenerated.jsonl";
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Continue.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Continue,
+        vec![FetchStrategy {
+            id: "continue-local",
+            kind: FetchKind::LocalProbe,
+            is_available: || {
+                global_dir().is_some_and(|dir| {
+                    !token_files(&dir).is_empty() || dir.join("sessions/sessions.json").is_file()
+                })
+            },
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// Dev Data
+// =============================================================================
+
+/// One `tokensGenerated` dev data event.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TokenEvent {
+    #[serde(default)]
+    model: String,
+    #[serde(default, alias = "prompt_tokens")]
+    prompt_tokens: i64,
+    #[serde(default, alias = "generated_tokens")]
+    generated_tokens: i64,
+    #[serde(default)]
+    timestamp: Option<DateTime<Utc>>,
+}
+
+impl TokenEvent {
+    fn into_session(self, session_id: String) -> SessionUsage {
+        let models_used = Some(self.model)
+            .filter(|model| !model.is_empty())
+            .into_iter()
+            .collect::<HashSet<_>>();
+        SessionUsage {
+            session_id,
+            started_at: self.timestamp,
+            ended_at: self.timestamp,
+            input_tokens: self.prompt_tokens,
+            output_tokens: self.generated_tokens,
+            models_used,
+            message_count: 1,
+            ..SessionUsage::default()
+        }
+    }
+}
+
+/// The Continue directory, if it exists.
+fn global_dir() -> Option<PathBuf> {
+    let dir = match std::env::var_os(GLOBAL_DIR_ENV) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => directories::BaseDirs::new()?.home_dir().join(".continue"),
+    };
+    dir.is_dir().then_some(dir)
+}
+
+/// `tokensGenerated.jsonl` files for every dev data schema version.
+fn token_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![dir.join("dev_data").join(TOKENS_FILE)];
+    if let Ok(versions) = fs::read_dir(dir.join("dev_data")) {
+        files.extend(versions.flatten().map(|v| v.path().join(TOKENS_FILE)));
+    }
+    files.retain(|path| path.is_file());
+    files.sort();
+    files
+}
+
+/// Events in one dev data file, skipping lines that do not parse.
+fn read_token_file(path: &Path) -> Vec<TokenEvent> {
+    let Ok(file) = fs::File::open(path) else {
+        return Vec::new();
+    };
+    BufReader::new(file)
+        .lines()
+        .map_while(std::result::Result::ok)
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect()
+}
+
+/// Latest `dateCreated` in the chat session index.
+///
+/// Continue has written the field as epoch milliseconds, both as a number
+/// and as a string, so the index is read loosely.
+fn latest_session(dir: &Path) -> Option<DateTime<Utc>> {
+    let raw = fs::read_to_string(dir.join("sessions/sessions.json")).ok()?;
+    let sessions: Vec<Value> = serde_json::from_str(&raw).ok()?;
+    sessions
+        .iter()
+        .filter_map(|session| match session.get("dateCreated")? {
+            Value::Number(ms) => ms.as_i64(),
+            Value::String(ms) => ms.parse().ok(),
+            _ => None,
+        })
+        .filter_map(DateTime::<Utc>::from_timestamp_millis)
+        .max()
+}
+
+/// Every model call in Continue's dev data, as one session per call.
+#[must_use]
+pub fn token_sessions() -> Vec<SessionUsage> {
+    let Some(dir) = global_dir() else {
+        return Vec::new();
+    };
+    sessions_in(&dir)
+}
+
+fn sessions_in(dir: &Path) -> Vec<SessionUsage> {
+    token_files(dir)
+        .iter()
+        .flat_map(|path| {
+            let stem = path
+                .parent()
+                .and_then(Path::file_name)
+                .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+            read_token_file(path)
+                .into_iter()
+                .enumerate()
+                .map(move |(i, event)| event.into_session(format!("{stem}:{i}")))
+        })
+        .collect()
+}
+
+/// Confirm Continue has recorded activity and report when it last did.
+///
+/// # Errors
+/// Returns an error if no dev data or chat sessions can be found.
+pub async fn fetch_local() -> Result<UsageSnapshot> {
+    let not_found = || {
+        CautError::Config(
+            "No Continue dev data found; chat with Continue in your editor first".to_string(),
+        )
+    };
+    let dir = global_dir().ok_or_else(not_found)?;
+    let latest = sessions_in(&dir)
+        .into_iter()
+        .filter_map(|session| session.started_at)
+        .chain(latest_session(&dir))
+        .max()
+        .ok_or_else(not_found)?;
+
+    Ok(UsageSnapshot {
+        primary: None,
+        secondary: None,
+        tertiary: None,
+        updated_at: latest,
+        identity: None,
+    })
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Continue.
+pub struct ContinueFetcher;
+
+impl ProviderFetcher for ContinueFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Continue
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "continue-local" => fetch_local().await,
+                _ => Err(unknown_strategy(Provider::Continue, strategy_id)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const EVENTS: &str = r#"{"eventName":"tokensGenerated","schema":"0.2.0","timestamp":"2026-10-15T09:30:00.000Z","model":"claude-sonnet-4-5","provider":"anthropic","promptTokens":1800,"generatedTokens":240}
+not json
+{"model":"qwen2.5-coder:7b","provider":"ollama","prompt_tokens":300,"generated_tokens":90}
+"#;
+
+    #[test]
+    fn reads_token_events_from_every_schema() {
+        let dir = TempDir::new().unwrap();
+        let v2 = dir.path().join("dev_data/0.2.0");
+        fs::create_dir_all(&v2).unwrap();
+        fs::write(v2.join(TOKENS_FILE), EVENTS).unwrap();
+        fs::write(dir.path().join("dev_data").join(TOKENS_FILE), "").unwrap();
+
+        let sessions = sessions_in(dir.path());
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].session_id, "0.2.0:0");
+        assert_eq!(sessions[0].input_tokens, 1800);
+        assert_eq!(sessions[0].output_tokens, 240);
+        assert!(sessions[0].models_used.contains("claude-sonnet-4-5"));
+        assert!(sessions[0].started_at.is_some());
+        assert_eq!(sessions[1].input_tokens, 300);
+        assert!(sessions[1].started_at.is_none());
+    }
+
+    #[test]
+    fn session_index_dates_latest_activity() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("sessions")).unwrap();
+        fs::write(
+            dir.path().join("sessions/sessions.json"),
+            r#"[{"sessionId":"a","title":"Fix","dateCreated":"1760600000000","workspaceDirectory":"/work"},
+                {"sessionId":"b","title":"Test","dateCreated":1760610000000}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            latest_session(dir.path()),
+            DateTime::<Utc>::from_timestamp_millis(1_760_610_000_000)
+        );
+        assert!(latest_session(&dir.path().join("missing")).is_none());
+    }
+}
@@ -0,0 +1,632 @@
+//! GitHub Copilot provider implementation.
+//!
+//! Supports:
+//! - GitHub REST API (enhanced billing premium-request usage)
+//!
+//! Source label: `api`
+//!
+//! Premium requests are counted per calendar month (UTC) against the plan's
+//! allowance. For Copilot Business/Enterprise seats, set `CAUT_COPILOT_ORG`
+//! to the organization that assigns the seat so its plan and premium-request
+//! budget are used to project overage before it is billed. Personal plans can
+//! be set with `CAUT_COPILOT_PLAN` (`free`, `pro`, `pro_plus`).
+//!
+//! Token resolution: `GITHUB_TOKEN`, `GH_TOKEN`, then `gh auth token`.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use serde::Deserialize;
+
+use crate::core::cli_runner::{CLI_TIMEOUT, run_command};
+use crate::core::clock_skew;
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{EnrichFuture, FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::core::models::{
+    CopilotPremiumProjection, PremiumRequestStatus, ProviderIdentity, ProviderPayload, RateWindow,
+    UsageSnapshot,
+};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+
+/// GitHub REST API base URL.
+const API_BASE: &str = "https://api.github.com";
+
+/// GitHub REST API version header value.
+const API_VERSION: &str = "2022-11-28";
+
+/// Environment variable naming the org that assigns the Copilot seat.
+pub const ORG_ENV: &str = "CAUT_COPILOT_ORG";
+
+/// Environment variable overriding the personal Copilot plan.
+pub const PLAN_ENV: &str = "CAUT_COPILOT_PLAN";
+
+/// List price of one premium request beyond the allowance (USD).
+pub const PREMIUM_REQUEST_PRICE_USD: f64 = 0.04;
+
+/// `gh` CLI binary name.
+const GH_CLI: &str = "gh";
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Copilot.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Copilot,
+        vec![FetchStrategy {
+            id: "copilot-api",
+            kind: FetchKind::ApiToken,
+            is_available: has_token_source,
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+/// Check whether a GitHub token can be resolved.
+fn has_token_source() -> bool {
+    env_token().is_some() || which::which(GH_CLI).is_ok()
+}
+
+fn env_token() -> Option<String> {
+    ["GITHUB_TOKEN", "GH_TOKEN"]
+        .iter()
+        .filter_map(|key| std::env::var(key).ok())
+        .map(|token| token.trim().to_string())
+        .find(|token| !token.is_empty())
+}
+
+async fn github_token() -> Result<String> {
+    if let Some(token) = env_token() {
+        return Ok(token);
+    }
+    let output = run_command(GH_CLI, &["auth", "token"], CLI_TIMEOUT).await?;
+    let token = output.stdout.trim();
+    if !output.success() || token.is_empty() {
+        return Err(CautError::Config(
+            "No GitHub token found (set GITHUB_TOKEN or run `gh auth login`)".to_string(),
+        ));
+    }
+    Ok(token.to_string())
+}
+
+// =============================================================================
+// Plans and Policy
+// =============================================================================
+
+/// Copilot plan, which determines the monthly premium-request allowance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopilotPlan {
+    Free,
+    Pro,
+    ProPlus,
+    Business,
+    Enterprise,
+}
+
+impl CopilotPlan {
+    /// Parse a plan name from the API or `CAUT_COPILOT_PLAN`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name
+            .trim()
+            .to_lowercase()
+            .replace(['-', ' ', '+'], "_")
+            .as_str()
+        {
+            "free" => Some(Self::Free),
+            "pro" | "individual" => Some(Self::Pro),
+            "pro_plus" | "pro_" => Some(Self::ProPlus),
+            "business" => Some(Self::Business),
+            "enterprise" => Some(Self::Enterprise),
+            _ => None,
+        }
+    }
+
+    /// Plan name used in output.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Free => "free",
+            Self::Pro => "pro",
+            Self::ProPlus => "pro_plus",
+            Self::Business => "business",
+            Self::Enterprise => "enterprise",
+        }
+    }
+
+    /// Included premium requests per month.
+    #[must_use]
+    pub const fn monthly_allowance(self) -> f64 {
+        match self {
+            Self::Free => 50.0,
+            Self::Pro | Self::Business => 300.0,
+            Self::Enterprise => 1000.0,
+            Self::ProPlus => 1500.0,
+        }
+    }
+}
+
+/// Seat policy that decides what happens past the allowance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatPolicy {
+    pub plan: CopilotPlan,
+    pub org: Option<String>,
+    /// Whether paid overage is allowed once the allowance is used.
+    pub overage_allowed: bool,
+    /// Premium-request budget in USD, if one is configured.
+    pub budget_usd: Option<f64>,
+}
+
+impl SeatPolicy {
+    /// Policy for a personal plan, where overage follows the account's budget
+    /// settings, which aren't exposed via the API; assume it is allowed.
+    #[must_use]
+    pub const fn personal(plan: CopilotPlan) -> Self {
+        Self {
+            plan,
+            org: None,
+            overage_allowed: !matches!(plan, CopilotPlan::Free),
+            budget_usd: None,
+        }
+    }
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct GitHubUser {
+    login: String,
+    #[serde(default)]
+    email: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PremiumRequestUsage {
+    #[serde(default)]
+    usage_items: Vec<PremiumRequestUsageItem>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PremiumRequestUsageItem {
+    #[serde(default)]
+    product: String,
+    #[serde(default)]
+    gross_quantity: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct OrgCopilotBilling {
+    #[serde(default)]
+    plan_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BudgetList {
+    #[serde(default)]
+    budgets: Vec<Budget>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Budget {
+    #[serde(default)]
+    budget_amount: f64,
+    #[serde(default)]
+    prevent_further_usage: bool,
+    #[serde(default)]
+    budget_product_sku: Option<String>,
+}
+
+/// Total premium requests across Copilot usage items.
+fn premium_requests_used(usage: &PremiumRequestUsage) -> f64 {
+    usage
+        .usage_items
+        .iter()
+        .filter(|item| item.product.eq_ignore_ascii_case("copilot"))
+        .map(|item| item.gross_quantity)
+        .sum()
+}
+
+/// Build the seat policy from the org plan and its premium-request budget.
+///
+/// Without a premium-request budget the org's paid-usage policy governs
+/// overage; caut treats that as allowed and uncapped.
+fn org_policy(org: &str, billing: &OrgCopilotBilling, budgets: &BudgetList) -> SeatPolicy {
+    let plan = billing
+        .plan_type
+        .as_deref()
+        .and_then(CopilotPlan::from_name)
+        .unwrap_or(CopilotPlan::Business);
+    let budget = budgets.budgets.iter().find(|b| {
+        b.budget_product_sku
+            .as_deref()
+            .is_some_and(|sku| sku.contains("premium_request"))
+    });
+
+    SeatPolicy {
+        plan,
+        org: Some(org.to_string()),
+        overage_allowed: budget.is_none_or(|b| !b.prevent_further_usage || b.budget_amount > 0.0),
+        budget_usd: budget.map(|b| b.budget_amount),
+    }
+}
+
+// =============================================================================
+// Projection
+// =============================================================================
+
+/// Start of the month containing `now` and of the following month (UTC).
+fn month_bounds(now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let start = NaiveDate::from_ymd_opt(now.year(), now.month(), 1).unwrap_or_default();
+    let next = if now.month() == 12 {
+        NaiveDate::from_ymd_opt(now.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(now.year(), now.month() + 1, 1)
+    }
+    .unwrap_or_default();
+    (
+        Utc.from_utc_datetime(&start.and_time(chrono::NaiveTime::MIN)),
+        Utc.from_utc_datetime(&next.and_time(chrono::NaiveTime::MIN)),
+    )
+}
+
+/// Project month-end premium-request usage at the current rate.
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // second counts within a month are small
+pub fn project_premium(
+    used: f64,
+    policy: &SeatPolicy,
+    now: DateTime<Utc>,
+) -> CopilotPremiumProjection {
+    let (start, resets_at) = month_bounds(now);
+    let month_secs = (resets_at - start).num_seconds() as f64;
+    // Floor at one hour so a burst right after reset doesn't explode the rate.
+    let elapsed_secs = ((now - start).num_seconds() as f64).max(3600.0);
+    let rate_per_sec = used / elapsed_secs;
+    let projected = (rate_per_sec * month_secs).max(used);
+    let allowance = policy.plan.monthly_allowance();
+
+    let exhausted_on = if used < allowance && rate_per_sec > 0.0 {
+        #[allow(clippy::cast_possible_truncation)] // bounded by the month length
+        let at = start + Duration::seconds((allowance / rate_per_sec) as i64);
+        (at < resets_at).then(|| at.date_naive())
+    } else {
+        None
+    };
+
+    let overage_requests = if policy.overage_allowed {
+        (projected - allowance).max(0.0)
+    } else {
+        0.0
+    };
+    let overage_usd = overage_requests * PREMIUM_REQUEST_PRICE_USD;
+    let projected_overage_usd = policy
+        .budget_usd
+        .map_or(overage_usd, |budget| overage_usd.min(budget));
+
+    let status = if used >= allowance {
+        if policy.overage_allowed {
+            PremiumRequestStatus::Overage
+        } else {
+            PremiumRequestStatus::Blocked
+        }
+    } else if projected > allowance {
+        PremiumRequestStatus::AtRisk
+    } else {
+        PremiumRequestStatus::OnTrack
+    };
+
+    CopilotPremiumProjection {
+        plan: policy.plan.name().to_string(),
+        org: policy.org.clone(),
+        used_requests: used,
+        allowance,
+        projected_requests: projected,
+        overage_allowed: policy.overage_allowed,
+        budget_usd: policy.budget_usd,
+        projected_overage_usd,
+        exhausted_on,
+        resets_at,
+        status,
+    }
+}
+
+/// Usage snapshot with the premium-request allowance as the primary window.
+fn projection_snapshot(
+    projection: &CopilotPremiumProjection,
+    user: &GitHubUser,
+    now: DateTime<Utc>,
+) -> UsageSnapshot {
+    let (start, resets_at) = month_bounds(now);
+    let used_percent = if projection.allowance > 0.0 {
+        (projection.used_requests / projection.allowance * 100.0).min(100.0)
+    } else {
+        0.0
+    };
+
+    let mut snapshot = UsageSnapshot::new(RateWindow {
+        used_percent,
+        window_minutes: i32::try_from((resets_at - start).num_minutes()).ok(),
+        resets_at: Some(resets_at),
+        reset_description: Some(format!(
+            "{:.0}/{:.0} premium requests",
+            projection.used_requests, projection.allowance
+        )),
+    });
+    snapshot.identity = Some(ProviderIdentity {
+        account_email: user.email.clone().or_else(|| Some(user.login.clone())),
+        account_organization: projection.org.clone(),
+        login_method: Some("github-token".to_string()),
+    });
+    snapshot
+}
+
+// =============================================================================
+// Fetch
+// =============================================================================
+
+async fn get_json<T: serde::de::DeserializeOwned>(
+    client: &reqwest::Client,
+    token: &str,
+    path: &str,
+) -> Result<T> {
+    let url = format!("{API_BASE}{path}");
+    let response = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {token}"))
+        .header("Accept", "application/vnd.github+json")
+        .header("X-GitHub-Api-Version", API_VERSION)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+            } else {
+                CautError::Network(e.to_string())
+            }
+        })?;
+    clock_skew::observe(&response);
+
+    if !response.status().is_success() {
+        return Err(CautError::FetchFailed {
+            provider: "copilot".to_string(),
+            reason: format!("HTTP {} from {path}", response.status()),
+        });
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| CautError::ParseResponse(e.to_string()))
+}
+
+async fn fetch_policy(client: &reqwest::Client, token: &str) -> Result<SeatPolicy> {
+    if let Ok(org) = std::env::var(ORG_ENV)
+        && !org.trim().is_empty()
+    {
+        let org = org.trim();
+        let billing: OrgCopilotBilling =
+            get_json(client, token, &format!("/orgs/{org}/copilot/billing")).await?;
+        // Budgets need billing-manager access; fall back to "no budget".
+        let budgets: BudgetList = get_json(
+            client,
+            token,
+            &format!("/organizations/{org}/settings/billing/budgets"),
+        )
+        .await
+        .unwrap_or(BudgetList {
+            budgets: Vec::new(),
+        });
+        return Ok(org_policy(org, &billing, &budgets));
+    }
+
+    let plan = std::env::var(PLAN_ENV)
+        .ok()
+        .and_then(|name| CopilotPlan::from_name(&name))
+        .unwrap_or(CopilotPlan::Pro);
+    Ok(SeatPolicy::personal(plan))
+}
+
+async fn fetch_premium(
+    client: &reqwest::Client,
+    token: &str,
+) -> Result<(GitHubUser, CopilotPremiumProjection)> {
+    let now = Utc::now();
+    let user: GitHubUser = get_json(client, token, "/user").await?;
+    let policy = fetch_policy(client, token).await?;
+
+    let period = format!("year={}&month={}", now.year(), now.month());
+    let path = match &policy.org {
+        Some(org) => format!(
+            "/organizations/{org}/settings/billing/premium_request/usage?user={}&{period}",
+            user.login
+        ),
+        None => format!(
+            "/users/{}/settings/billing/premium_request/usage?{period}",
+            user.login
+        ),
+    };
+    let usage: PremiumRequestUsage = get_json(client, token, &path).await?;
+
+    let projection = project_premium(premium_requests_used(&usage), &policy, now);
+    Ok((user, projection))
+}
+
+/// Fetch premium-request usage via the GitHub API.
+///
+/// # Errors
+/// Returns an error if no GitHub token is available, or a request fails or
+/// returns an unexpected response.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    let token = github_token().await?;
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let (user, projection) = fetch_premium(&client, &token).await?;
+    Ok(projection_snapshot(&projection, &user, Utc::now()))
+}
+
+/// Fetch the premium-request projection for the dedicated usage section.
+///
+/// # Errors
+/// Returns an error if no GitHub token is available, or a request fails or
+/// returns an unexpected response.
+pub async fn fetch_projection() -> Result<CopilotPremiumProjection> {
+    let token = github_token().await?;
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    fetch_premium(&client, &token)
+        .await
+        .map(|(_, projection)| projection)
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Copilot.
+pub struct CopilotFetcher;
+
+impl ProviderFetcher for CopilotFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Copilot
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "copilot-api" => fetch_api().await,
+                _ => Err(unknown_strategy(Provider::Copilot, strategy_id)),
+            }
+        })
+    }
+
+    /// Project premium requests against the seat policy.
+    fn enrich<'a>(&'a self, payload: &'a mut ProviderPayload) -> EnrichFuture<'a> {
+        Box::pin(async move {
+            payload.copilot_premium = fetch_projection()
+                .await
+                .map_err(|e| tracing::warn!("Failed to project Copilot premium requests: {}", e))
+                .ok();
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jan(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, day, hour, 0, 0).unwrap()
+    }
+
+    fn business(overage_allowed: bool, budget_usd: Option<f64>) -> SeatPolicy {
+        SeatPolicy {
+            plan: CopilotPlan::Business,
+            org: Some("acme".to_string()),
+            overage_allowed,
+            budget_usd,
+        }
+    }
+
+    #[test]
+    fn plan_names_and_allowances() {
+        assert_eq!(
+            CopilotPlan::from_name("Business"),
+            Some(CopilotPlan::Business)
+        );
+        assert_eq!(CopilotPlan::from_name("pro+"), Some(CopilotPlan::ProPlus));
+        assert_eq!(CopilotPlan::from_name("nope"), None);
+        assert!((CopilotPlan::Enterprise.monthly_allowance() - 1000.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn projection_on_track_at_low_rate() {
+        // 50 requests by mid-month projects to ~100 of 300.
+        let projection = project_premium(50.0, &business(true, None), jan(16, 0));
+        assert_eq!(projection.status, PremiumRequestStatus::OnTrack);
+        assert!(projection.projected_requests < 300.0);
+        assert!(projection.exhausted_on.is_none());
+        assert!(projection.projected_overage_usd.abs() < f64::EPSILON);
+        assert_eq!(
+            projection.resets_at,
+            Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn projection_warns_before_overage() {
+        // 200 requests in 10 days projects to 620: 320 over at $0.04.
+        let projection = project_premium(200.0, &business(true, None), jan(11, 0));
+        assert_eq!(projection.status, PremiumRequestStatus::AtRisk);
+        assert_eq!(
+            projection.exhausted_on,
+            NaiveDate::from_ymd_opt(2026, 1, 16)
+        );
+        assert!((projection.projected_overage_usd - 12.8).abs() < 0.01);
+    }
+
+    #[test]
+    fn projection_caps_overage_at_budget() {
+        let projection = project_premium(200.0, &business(true, Some(5.0)), jan(11, 0));
+        assert!((projection.projected_overage_usd - 5.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn projection_blocked_when_overage_disallowed() {
+        let projection = project_premium(300.0, &business(false, Some(0.0)), jan(20, 0));
+        assert_eq!(projection.status, PremiumRequestStatus::Blocked);
+        assert!(projection.projected_overage_usd.abs() < f64::EPSILON);
+
+        let projection = project_premium(310.0, &business(true, None), jan(20, 0));
+        assert_eq!(projection.status, PremiumRequestStatus::Overage);
+    }
+
+    #[test]
+    fn org_policy_reads_premium_request_budget() {
+        let billing = OrgCopilotBilling {
+            plan_type: Some("enterprise".to_string()),
+        };
+        let budgets: BudgetList = serde_json::from_str(
+            r#"{"budgets":[
+                {"budget_amount":100,"prevent_further_usage":true,"budget_product_sku":"actions"},
+                {"budget_amount":0,"prevent_further_usage":true,"budget_product_sku":"copilot_premium_request"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let policy = org_policy("acme", &billing, &budgets);
+        assert_eq!(policy.plan, CopilotPlan::Enterprise);
+        assert!(!policy.overage_allowed);
+        assert_eq!(policy.budget_usd, Some(0.0));
+
+        let open = org_policy(
+            "acme",
+            &billing,
+            &BudgetList {
+                budgets: Vec::new(),
+            },
+        );
+        assert!(open.overage_allowed);
+        assert_eq!(open.budget_usd, None);
+    }
+
+    #[test]
+    fn premium_usage_sums_copilot_items() {
+        let usage: PremiumRequestUsage = serde_json::from_str(
+            r#"{"usageItems":[
+                {"product":"Copilot","model":"Claude Sonnet 4","grossQuantity":120},
+                {"product":"Copilot","model":"GPT-5","grossQuantity":30.5},
+                {"product":"Actions","grossQuantity":999}
+            ]}"#,
+        )
+        .unwrap();
+        assert!((premium_requests_used(&usage) - 150.5).abs() < f64::EPSILON);
+    }
+}
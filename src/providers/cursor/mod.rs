@@ -0,0 +1,349 @@
+//! Cursor provider implementation.
+//!
+//! Supports:
+//! - Cursor dashboard API (premium request usage)
+//!
+//! Source label: `web`
+//!
+//! The editor keeps its session in a VS Code style SQLite store
+//! (`Cursor/User/globalStorage/state.vscdb` under the platform config
+//! directory). caut reads the access token from there and calls the same
+//! usage endpoint as the cursor.com dashboard, authenticated with the
+//! session cookie the dashboard uses. Premium (fast) requests are counted
+//! per billing month; on team plans the count is the seat's usage against the
+//! team's per-seat allowance, and the month starts at the team's billing date.
+//!
+//! Set `CAUT_CURSOR_STATE_DB` to read a different state database.
+
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Months, Utc};
+use serde::Deserialize;
+
+use crate::core::clock_skew;
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::core::models::{ProviderIdentity, RateWindow, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::providers::vscode_state::{self, StateDb};
+
+/// Cursor web API base URL.
+const API_BASE: &str = "https://cursor.com";
+
+/// Environment variable overriding the state database path.
+pub const STATE_DB_ENV: &str = "CAUT_CURSOR_STATE_DB";
+
+/// Application directory under the platform config directory.
+const APP_DIR: &str = "Cursor";
+
+/// State keys written by the Cursor editor on sign-in.
+const ACCESS_TOKEN_KEY: &str = "cursorAuth/accessToken";
+const EMAIL_KEY: &str = "cursorAuth/cachedEmail";
+
+/// Session cookie accepted by the dashboard API.
+const SESSION_COOKIE: &str = "WorkosCursorSessionToken";
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Cursor.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Cursor,
+        vec![FetchStrategy {
+            id: "cursor-web",
+            kind: FetchKind::Web,
+            is_available: || state_db_path().is_some_and(|path| path.exists()),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// Local Session
+// =============================================================================
+
+/// Signed-in Cursor session read from the state database.
+#[derive(Debug)]
+struct Session {
+    user_id: String,
+    access_token: String,
+    email: Option<String>,
+}
+
+/// Claims needed from the access token.
+#[derive(Debug, Deserialize)]
+struct TokenClaims {
+    /// Subject, e.g. `auth0|user_01H...`.
+    sub: String,
+}
+
+fn state_db_path() -> Option<PathBuf> {
+    vscode_state::state_db_path(STATE_DB_ENV, APP_DIR)
+}
+
+fn read_session(path: &Path) -> Result<Session> {
+    let db = StateDb::open(path, "Cursor")?;
+
+    let access_token = db.get(ACCESS_TOKEN_KEY).ok_or_else(|| {
+        CautError::Config("Not signed in to Cursor (open Cursor and sign in)".to_string())
+    })?;
+    let user_id = user_id_from_token(&access_token).ok_or_else(|| {
+        CautError::ParseResponse("Cursor access token has no user ID".to_string())
+    })?;
+
+    Ok(Session {
+        user_id,
+        access_token,
+        email: db.get(EMAIL_KEY),
+    })
+}
+
+/// User ID from the token subject (the part after the auth provider prefix).
+fn user_id_from_token(token: &str) -> Option<String> {
+    let payload = token.split('.').nth(1)?;
+    let decoded = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+    let claims: TokenClaims = serde_json::from_slice(&decoded).ok()?;
+    let id = claims.sub.rsplit('|').next()?;
+    (!id.is_empty()).then(|| id.to_string())
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+/// `/api/usage` response, keyed by model bucket.
+#[derive(Debug, Deserialize)]
+struct UsageResponse {
+    /// Premium (fast) requests; the bucket keeps its historical name.
+    #[serde(rename = "gpt-4", default)]
+    premium: Option<BucketUsage>,
+    /// Start of the current billing month.
+    #[serde(rename = "startOfMonth", default)]
+    start_of_month: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BucketUsage {
+    #[serde(default)]
+    num_requests: u64,
+    /// Included requests; `None` on plans without a request cap.
+    #[serde(default)]
+    max_request_usage: Option<u64>,
+}
+
+/// Fetch usage from the Cursor dashboard API.
+///
+/// # Errors
+/// Returns an error if Cursor is not signed in, the request fails, or the
+/// response cannot be parsed.
+pub async fn fetch_web() -> Result<UsageSnapshot> {
+    let path = state_db_path().ok_or_else(|| {
+        CautError::Config("Cannot determine the Cursor config directory".to_string())
+    })?;
+    let session = read_session(&path)?;
+
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let response = client
+        .get(format!("{API_BASE}/api/usage?user={}", session.user_id))
+        .header("Accept", "application/json")
+        .header(
+            "Cookie",
+            format!(
+                "{SESSION_COOKIE}={}%3A%3A{}",
+                session.user_id, session.access_token
+            ),
+        )
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+            } else {
+                CautError::Network(e.to_string())
+            }
+        })?;
+    clock_skew::observe(&response);
+
+    let status = response.status();
+    if !status.is_success() {
+        let reason = if matches!(status.as_u16(), 401 | 403) {
+            "session expired; open Cursor to sign in again".to_string()
+        } else {
+            format!("usage request returned {status}")
+        };
+        return Err(CautError::FetchFailed {
+            provider: Provider::Cursor.cli_name().to_string(),
+            reason,
+        });
+    }
+
+    let usage: UsageResponse = response
+        .json()
+        .await
+        .map_err(|e| CautError::ParseResponse(format!("Invalid Cursor usage response: {e}")))?;
+
+    let mut snapshot = usage_from_response(&usage);
+    snapshot.identity = Some(ProviderIdentity {
+        account_email: session.email,
+        account_organization: None,
+        login_method: None,
+    });
+    Ok(snapshot)
+}
+
+fn usage_from_response(usage: &UsageResponse) -> UsageSnapshot {
+    let primary = usage.premium.as_ref().map(|bucket| {
+        let resets_at = usage
+            .start_of_month
+            .and_then(|start| start.checked_add_months(Months::new(1)));
+        #[allow(clippy::cast_precision_loss)] // request counts are small
+        let (used_percent, description) = match bucket.max_request_usage.filter(|max| *max > 0) {
+            Some(max) => (
+                (bucket.num_requests as f64 / max as f64 * 100.0).clamp(0.0, 100.0),
+                format!("{}/{max} premium requests", bucket.num_requests),
+            ),
+            None => (
+                0.0,
+                format!("{} premium requests (no cap)", bucket.num_requests),
+            ),
+        };
+        RateWindow {
+            used_percent,
+            window_minutes: None,
+            resets_at,
+            reset_description: Some(description),
+        }
+    });
+
+    UsageSnapshot {
+        primary,
+        secondary: None,
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: None,
+    }
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Cursor.
+pub struct CursorFetcher;
+
+impl ProviderFetcher for CursorFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Cursor
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "cursor-web" => fetch_web().await,
+                _ => Err(unknown_strategy(Provider::Cursor, strategy_id)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rusqlite::Connection;
+
+    fn token(sub: &str) -> String {
+        let payload = URL_SAFE_NO_PAD.encode(format!(r#"{{"sub":"{sub}","exp":0}}"#));
+        format!("eyJhbGciOiJIUzI1NiJ9.{payload}.sig")
+    }
+
+    #[test]
+    fn extracts_user_id_from_token() {
+        assert_eq!(
+            user_id_from_token(&token("auth0|user_01ABC")).as_deref(),
+            Some("user_01ABC")
+        );
+        assert_eq!(user_id_from_token("not-a-jwt"), None);
+    }
+
+    #[test]
+    fn reads_session_from_state_db() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.vscdb");
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch("CREATE TABLE ItemTable (key TEXT UNIQUE, value BLOB);")
+            .unwrap();
+        conn.execute(
+            "INSERT INTO ItemTable VALUES (?1, ?2), (?3, ?4)",
+            [
+                ACCESS_TOKEN_KEY,
+                token("github|user_42").as_str(),
+                EMAIL_KEY,
+                "dev@example.com",
+            ],
+        )
+        .unwrap();
+        drop(conn);
+
+        let session = read_session(&path).unwrap();
+        assert_eq!(session.user_id, "user_42");
+        assert_eq!(session.email.as_deref(), Some("dev@example.com"));
+    }
+
+    #[test]
+    fn missing_token_is_not_signed_in() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.vscdb");
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch("CREATE TABLE ItemTable (key TEXT UNIQUE, value BLOB);")
+            .unwrap();
+
+        let err = read_session(&path).unwrap_err();
+        assert!(err.to_string().contains("Not signed in"));
+    }
+
+    #[test]
+    fn maps_premium_requests() {
+        let usage: UsageResponse = serde_json::from_str(
+            r#"{
+                "gpt-4": {"numRequests": 125, "numTokens": 900000, "maxRequestUsage": 500},
+                "gpt-3.5-turbo": {"numRequests": 40, "maxRequestUsage": null},
+                "startOfMonth": "2026-10-03T12:00:00.000Z"
+            }"#,
+        )
+        .unwrap();
+
+        let window = usage_from_response(&usage).primary.unwrap();
+        assert!((window.used_percent - 25.0).abs() < f64::EPSILON);
+        assert_eq!(
+            window.resets_at,
+            Some(Utc.with_ymd_and_hms(2026, 11, 3, 12, 0, 0).unwrap())
+        );
+        assert_eq!(
+            window.reset_description.as_deref(),
+            Some("125/500 premium requests")
+        );
+    }
+
+    #[test]
+    fn uncapped_plan_reports_count_only() {
+        let usage: UsageResponse =
+            serde_json::from_str(r#"{"gpt-4": {"numRequests": 7}}"#).unwrap();
+        let window = usage_from_response(&usage).primary.unwrap();
+        assert!(window.used_percent.abs() < f64::EPSILON);
+        assert!(window.resets_at.is_none());
+    }
+}
@@ -0,0 +1,249 @@
+//! `DeepSeek` provider implementation.
+//!
+//! Supports:
+//! - `DeepSeek` API (account balance)
+//!
+//! Source label: `api`
+//!
+//! The API is prepaid with no rate windows, so `/user/balance` supplies the
+//! remaining balance as credits. Accounts hold a balance per currency (CNY
+//! or USD); USD is reported when present, otherwise the first one listed.
+//! The currency, and whether the balance is exhausted, are shown as the
+//! login method.
+//!
+//! Spend comes from local session logs when available: Aider records the
+//! model and cost of every reply, and sessions that used a `deepseek` model
+//! are totalled for today and the last 30 days.
+//!
+//! Key resolution: `--api-key`, `DEEPSEEK_API_KEY`, keyring, then config
+//! (see [`crate::providers::api_key`]).
+
+use chrono::{Duration, Local, Utc};
+use serde::Deserialize;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::EnrichFuture;
+use crate::core::models::{
+    CreditsSnapshot, ProviderIdentity, ProviderPayload, TaskSpend, UsageSnapshot,
+};
+use crate::core::provider::Provider;
+use crate::core::session_logs::{
+    AiderSessionParser, SessionLogFinder, SessionUsage, spend_from_sessions,
+};
+use crate::error::{CautError, Result};
+use crate::providers::api_key::ApiKeyProvider;
+
+/// Environment variable holding the `DeepSeek` API key.
+pub const API_KEY_ENV: &str = "DEEPSEEK_API_KEY";
+
+/// Account balance endpoint.
+const BALANCE_URL: &str = "https://api.deepseek.com/user/balance";
+
+const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::DeepSeek).env(API_KEY_ENV);
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for `DeepSeek`.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::DeepSeek,
+        vec![FetchStrategy {
+            id: "deepseek-api",
+            kind: FetchKind::ApiToken,
+            is_available: || SPEC.api_key().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct BalanceResponse {
+    /// Whether the balance covers further API calls.
+    #[serde(default)]
+    is_available: bool,
+    #[serde(default)]
+    balance_infos: Vec<BalanceInfo>,
+}
+
+/// Balance in one currency. Amounts are decimal strings.
+#[derive(Debug, Deserialize)]
+struct BalanceInfo {
+    currency: String,
+    total_balance: String,
+}
+
+impl BalanceResponse {
+    /// Balance to report: USD when the account has one, else the first.
+    fn preferred(&self) -> Option<&BalanceInfo> {
+        self.balance_infos
+            .iter()
+            .find(|b| b.currency.eq_ignore_ascii_case("USD"))
+            .or_else(|| self.balance_infos.first())
+    }
+
+    fn login_method(&self) -> String {
+        let currency = self
+            .preferred()
+            .map_or_else(String::new, |b| format!(", {}", b.currency));
+        let exhausted = if self.is_available { "" } else { ", exhausted" };
+        format!("api-key{currency}{exhausted}")
+    }
+}
+
+async fn fetch_balance() -> Result<BalanceResponse> {
+    SPEC.get_json(BALANCE_URL).await
+}
+
+/// Fetch account details from the `DeepSeek` balance endpoint.
+///
+/// # Errors
+/// Returns an error if no API key is set or the balance request fails.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    Ok(usage_from_balance(&fetch_balance().await?))
+}
+
+/// Fetch the account's remaining balance.
+///
+/// # Errors
+/// Returns an error if no API key is set, the request fails, or the account
+/// reports no balance.
+pub async fn fetch_credits() -> Result<CreditsSnapshot> {
+    credits_from_balance(&fetch_balance().await?)
+}
+
+/// Attach the account balance, and the spend Aider logged against
+/// `DeepSeek` models when there is any, to a fetched payload.
+pub fn enrich(payload: &mut ProviderPayload) -> EnrichFuture<'_> {
+    payload.task_spend = local_spend()
+        .map_err(|e| tracing::debug!("No local DeepSeek spend: {}", e))
+        .ok();
+    Box::pin(async move {
+        payload.credits = fetch_credits()
+            .await
+            .map_err(|e| tracing::warn!("Failed to fetch DeepSeek credits: {}", e))
+            .ok();
+    })
+}
+
+fn usage_from_balance(balance: &BalanceResponse) -> UsageSnapshot {
+    UsageSnapshot {
+        primary: None,
+        secondary: None,
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: Some(ProviderIdentity {
+            account_email: None,
+            account_organization: None,
+            login_method: Some(balance.login_method()),
+        }),
+    }
+}
+
+fn credits_from_balance(balance: &BalanceResponse) -> Result<CreditsSnapshot> {
+    let info = balance
+        .preferred()
+        .ok_or_else(|| CautError::ParseResponse("DeepSeek returned no balance".to_string()))?;
+    let remaining = info.total_balance.trim().parse::<f64>().map_err(|e| {
+        CautError::ParseResponse(format!(
+            "Invalid DeepSeek balance '{}': {e}",
+            info.total_balance
+        ))
+    })?;
+    Ok(CreditsSnapshot {
+        remaining,
+        events: Vec::new(),
+        updated_at: Utc::now(),
+    })
+}
+
+// =============================================================================
+// Local Spend
+// =============================================================================
+
+fn uses_deepseek(session: &SessionUsage) -> bool {
+    session
+        .models_used
+        .iter()
+        .any(|model| model.to_lowercase().contains("deepseek"))
+}
+
+/// Spend Aider recorded against `DeepSeek` models.
+///
+/// # Errors
+/// Returns an error if no local session used a `DeepSeek` model in the last
+/// 30 days.
+pub fn local_spend() -> Result<TaskSpend> {
+    let cutoff = Utc::now() - Duration::days(30);
+    let sessions: Vec<SessionUsage> = SessionLogFinder::new()?
+        .find_sessions(Provider::Aider, Some(cutoff), None)
+        .iter()
+        .filter_map(|log| AiderSessionParser.parse_sessions(&log.path).ok())
+        .flatten()
+        .filter(uses_deepseek)
+        .collect();
+    if sessions.is_empty() {
+        return Err(CautError::Config(
+            "No local sessions using DeepSeek models found".to_string(),
+        ));
+    }
+    Ok(spend_from_sessions(&sessions, Local::now()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> BalanceResponse {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn prefers_usd_balance() {
+        let balance = parse(
+            r#"{"is_available": true, "balance_infos": [
+                {"currency": "CNY", "total_balance": "110.00", "granted_balance": "10.00", "topped_up_balance": "100.00"},
+                {"currency": "USD", "total_balance": "4.25", "granted_balance": "0.00", "topped_up_balance": "4.25"}
+            ]}"#,
+        );
+
+        let credits = credits_from_balance(&balance).unwrap();
+        assert!((credits.remaining - 4.25).abs() < f64::EPSILON);
+        let usage = usage_from_balance(&balance);
+        assert!(usage.primary.is_none());
+        assert_eq!(
+            usage.identity.unwrap().login_method.as_deref(),
+            Some("api-key, USD")
+        );
+    }
+
+    #[test]
+    fn exhausted_balance_is_flagged() {
+        let balance = parse(
+            r#"{"is_available": false, "balance_infos": [
+                {"currency": "CNY", "total_balance": "0.00"}
+            ]}"#,
+        );
+
+        assert_eq!(balance.login_method(), "api-key, CNY, exhausted");
+        assert!(credits_from_balance(&balance).unwrap().remaining.abs() < f64::EPSILON);
+        assert!(credits_from_balance(&parse(r#"{"balance_infos": []}"#)).is_err());
+    }
+
+    #[test]
+    fn only_deepseek_sessions_count_toward_spend() {
+        let session = |model: &str| SessionUsage {
+            models_used: [model.to_string()].into_iter().collect(),
+            ..SessionUsage::default()
+        };
+        assert!(uses_deepseek(&session("deepseek/deepseek-chat")));
+        assert!(uses_deepseek(&session("openrouter/deepseek/deepseek-r1")));
+        assert!(!uses_deepseek(&session("gpt-4.1")));
+    }
+}
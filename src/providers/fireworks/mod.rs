@@ -0,0 +1,68 @@
+//! Fireworks provider implementation.
+//!
+//! Supports:
+//! - Fireworks API (rate-limit headers)
+//!
+//! Source label: `api`
+//!
+//! Fireworks reports the account's serverless limits on every response.
+//! Window mapping: primary = requests per minute, secondary = prompt tokens
+//! per minute.
+//!
+//! Key resolution: `--api-key`, `FIREWORKS_API_KEY`, keyring, then config (see
+//! [`crate::providers::api_key`]).
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::models::UsageSnapshot;
+use crate::core::provider::Provider;
+use crate::error::Result;
+use crate::providers::api_key::ApiKeyProvider;
+use crate::providers::rate_limits::HeaderLimit;
+
+/// Environment variable holding the Fireworks API key.
+pub const API_KEY_ENV: &str = "FIREWORKS_API_KEY";
+
+const REQUESTS_PER_MINUTE: HeaderLimit = HeaderLimit {
+    limit: "x-ratelimit-limit-requests",
+    remaining: "x-ratelimit-remaining-requests",
+    reset: "x-ratelimit-reset-requests",
+    window_minutes: Some(1),
+    unit: "req/min",
+};
+
+const PROMPT_TOKENS_PER_MINUTE: HeaderLimit = HeaderLimit {
+    limit: "x-ratelimit-limit-tokens-prompt",
+    remaining: "x-ratelimit-remaining-tokens-prompt",
+    reset: "x-ratelimit-reset-tokens-prompt",
+    window_minutes: Some(1),
+    unit: "prompt tokens/min",
+};
+
+const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::Fireworks)
+    .env(API_KEY_ENV)
+    .probe("https://api.fireworks.ai/inference/v1/models")
+    .primary(REQUESTS_PER_MINUTE)
+    .secondary(PROMPT_TOKENS_PER_MINUTE);
+
+/// Create fetch plan for Fireworks.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Fireworks,
+        vec![FetchStrategy {
+            id: "fireworks-api",
+            kind: FetchKind::ApiToken,
+            is_available: || SPEC.api_key().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+/// Fetch rate-limit usage from the Fireworks API.
+///
+/// # Errors
+/// Returns an error if no API key is set, the request fails, or the response
+/// carries no rate-limit headers.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    SPEC.fetch_usage().await
+}
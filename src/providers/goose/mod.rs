@@ -0,0 +1,429 @@
+//! Goose provider implementation.
+//!
+//! Supports:
+//! - Local session store probe
+//!
+//! Source label: `local`
+//!
+//! Goose (by Block) calls whichever model backend it is configured with, so
+//! there is no Goose quota to report. It keeps per-session token totals:
+//! older releases in the first (metadata) line of
+//! `sessions/<id>.jsonl`, newer ones in the `sessions` table of
+//! `sessions/sessions.db`, both under Goose's data directory. Each session is
+//! priced for `caut cost` by the model it recorded, or else the
+//! `GOOSE_MODEL` in `config.yaml`. Sessions on a local backend (Ollama) cost
+//! nothing.
+//!
+//! Directories follow Goose: `GOOSE_PATH_ROOT` when set, otherwise
+//! `~/.local/share/goose` and `~/.config/goose` (XDG variables honoured), or
+//! `%APPDATA%\Block\goose` on Windows.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, OpenFlags, Row};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::models::{ProviderIdentity, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::core::session_logs::SessionUsage;
+use crate::error::{CautError, Result};
+
+/// Environment variable Goose uses to relocate all of its directories.
+pub const PATH_ROOT_ENV: &str = "GOOSE_PATH_ROOT";
+
+/// Backends that run models locally, at no cost.
+const LOCAL_BACKENDS: &[&str] = &["ollama"];
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Goose.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Goose,
+        vec![FetchStrategy {
+            id: "goose-local",
+            kind: FetchKind::LocalProbe,
+            is_available: || data_dir().is_some_and(|dir| dir.join("sessions").is_dir()),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// Paths and Config
+// =============================================================================
+
+fn goose_dir(kind: &str, xdg_var: &str, xdg_default: &str) -> Option<PathBuf> {
+    if let Some(root) = std::env::var_os(PATH_ROOT_ENV).filter(|root| !root.is_empty()) {
+        return Some(PathBuf::from(root).join(kind));
+    }
+    if cfg!(windows) {
+        return Some(
+            directories::BaseDirs::new()?
+                .data_dir()
+                .join("Block/goose")
+                .join(kind),
+        );
+    }
+    let base = match std::env::var_os(xdg_var) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => directories::BaseDirs::new()?.home_dir().join(xdg_default),
+    };
+    Some(base.join("goose"))
+}
+
+/// Goose's data directory, holding `sessions/`.
+fn data_dir() -> Option<PathBuf> {
+    goose_dir("data", "XDG_DATA_HOME", ".local/share").filter(|dir| dir.is_dir())
+}
+
+/// Model backend and default model from `config.yaml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct GooseConfig {
+    provider: Option<String>,
+    model: Option<String>,
+}
+
+impl GooseConfig {
+    fn load() -> Self {
+        goose_dir("config", "XDG_CONFIG_HOME", ".config")
+            .and_then(|dir| fs::read_to_string(dir.join("config.yaml")).ok())
+            .map(|raw| Self::parse(&raw))
+            .unwrap_or_default()
+    }
+
+    /// Read the two top-level scalar keys caut needs; the rest of the YAML
+    /// (extensions and the like) is ignored.
+    fn parse(raw: &str) -> Self {
+        let mut config = Self::default();
+        for line in raw.lines().filter(|line| !line.starts_with([' ', '\t'])) {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value
+                .split(" #")
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .trim_matches(['"', '\''])
+                .to_string();
+            if value.is_empty() {
+                continue;
+            }
+            match key.trim() {
+                "GOOSE_PROVIDER" => config.provider = Some(value),
+                "GOOSE_MODEL" => config.model = Some(value),
+                _ => {}
+            }
+        }
+        config
+    }
+
+    fn is_local(&self) -> bool {
+        self.provider
+            .as_deref()
+            .is_some_and(|provider| LOCAL_BACKENDS.contains(&provider))
+    }
+}
+
+// =============================================================================
+// Sessions
+// =============================================================================
+
+/// Token totals of one session. `accumulated_*` cover the whole session;
+/// the plain fields only the latest turn, in releases without them.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct SessionTotals {
+    #[serde(default)]
+    working_dir: Option<PathBuf>,
+    #[serde(default)]
+    message_count: Option<i64>,
+    #[serde(default)]
+    input_tokens: Option<i64>,
+    #[serde(default)]
+    output_tokens: Option<i64>,
+    #[serde(default)]
+    accumulated_input_tokens: Option<i64>,
+    #[serde(default)]
+    accumulated_output_tokens: Option<i64>,
+}
+
+impl SessionTotals {
+    fn into_session(
+        self,
+        session_id: String,
+        model: Option<String>,
+        span: (Option<DateTime<Utc>>, Option<DateTime<Utc>>),
+        config: &GooseConfig,
+    ) -> SessionUsage {
+        let models_used = model
+            .or_else(|| config.model.clone())
+            .into_iter()
+            .collect::<HashSet<_>>();
+        SessionUsage {
+            session_id,
+            cwd: self.working_dir,
+            started_at: span.0,
+            ended_at: span.1,
+            input_tokens: self
+                .accumulated_input_tokens
+                .or(self.input_tokens)
+                .unwrap_or(0),
+            output_tokens: self
+                .accumulated_output_tokens
+                .or(self.output_tokens)
+                .unwrap_or(0),
+            models_used,
+            message_count: self.message_count.unwrap_or(0),
+            reported_cost_usd: config.is_local().then_some(0.0),
+            ..SessionUsage::default()
+        }
+    }
+}
+
+/// A session from a JSONL session file: metadata line, then one message per
+/// line with `created` in epoch seconds.
+fn read_jsonl_session(path: &Path, config: &GooseConfig) -> Option<SessionUsage> {
+    let file = fs::File::open(path).ok()?;
+    let mut lines = BufReader::new(file)
+        .lines()
+        .map_while(std::result::Result::ok);
+    let totals: SessionTotals = serde_json::from_str(&lines.next()?).ok()?;
+
+    let times: Vec<DateTime<Utc>> = lines
+        .filter_map(|line| serde_json::from_str::<Value>(&line).ok())
+        .filter_map(|message| message.get("created")?.as_i64())
+        .filter_map(|secs| DateTime::<Utc>::from_timestamp(secs, 0))
+        .collect();
+    let span = (times.iter().min().copied(), times.iter().max().copied());
+    let id = path.file_stem()?.to_string_lossy().into_owned();
+    Some(totals.into_session(id, None, span, config))
+}
+
+fn parse_db_time(raw: &str) -> Option<DateTime<Utc>> {
+    raw.parse::<DateTime<Utc>>().ok().or_else(|| {
+        NaiveDateTime::parse_from_str(raw, "%Y-%m-%d %H:%M:%S%.f")
+            .ok()
+            .map(|naive| naive.and_utc())
+    })
+}
+
+/// A `sessions` row, read by column name so columns added or missing across
+/// Goose releases do not matter.
+fn read_db_row(row: &Row<'_>, columns: &[String], config: &GooseConfig) -> SessionUsage {
+    let text = |name: &str| {
+        let index = columns.iter().position(|column| column == name)?;
+        match row.get_ref(index).ok()? {
+            ValueRef::Text(text) => Some(String::from_utf8_lossy(text).into_owned()),
+            ValueRef::Integer(n) => Some(n.to_string()),
+            _ => None,
+        }
+    };
+    let number = |name: &str| text(name)?.parse::<i64>().ok();
+
+    let totals = SessionTotals {
+        working_dir: text("working_dir").map(PathBuf::from),
+        message_count: number("message_count"),
+        input_tokens: number("input_tokens"),
+        output_tokens: number("output_tokens"),
+        accumulated_input_tokens: number("accumulated_input_tokens"),
+        accumulated_output_tokens: number("accumulated_output_tokens"),
+    };
+    let model = text("model_config_json")
+        .and_then(|raw| serde_json::from_str::<Value>(&raw).ok())
+        .and_then(|model| Some(model.get("model_name")?.as_str()?.to_string()));
+    let span = (
+        text("created_at").as_deref().and_then(parse_db_time),
+        text("updated_at").as_deref().and_then(parse_db_time),
+    );
+    totals.into_session(text("id").unwrap_or_default(), model, span, config)
+}
+
+fn read_db_sessions(path: &Path, config: &GooseConfig) -> Vec<SessionUsage> {
+    let read = || -> rusqlite::Result<Vec<SessionUsage>> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        let mut stmt = conn.prepare("SELECT * FROM sessions")?;
+        let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+        stmt.query_map([], |row| Ok(read_db_row(row, &columns, config)))?
+            .collect()
+    };
+    read()
+        .map_err(|e| tracing::debug!(path = %path.display(), "Unreadable Goose sessions: {}", e))
+        .unwrap_or_default()
+}
+
+fn sessions_in(dir: &Path, config: &GooseConfig) -> Vec<SessionUsage> {
+    let sessions_dir = dir.join("sessions");
+    let mut sessions = read_db_sessions(&sessions_dir.join("sessions.db"), config);
+    let imported: HashSet<String> = sessions.iter().map(|s| s.session_id.clone()).collect();
+
+    // Sessions migrated into the database keep their file IDs; count each once.
+    let mut files: Vec<PathBuf> = fs::read_dir(&sessions_dir)
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default();
+    files.retain(|path| path.extension().is_some_and(|ext| ext == "jsonl"));
+    files.sort();
+    sessions.extend(
+        files
+            .iter()
+            .filter_map(|path| read_jsonl_session(path, config))
+            .filter(|session| !imported.contains(&session.session_id)),
+    );
+    sessions
+}
+
+/// Every Goose session, with token totals and the model it ran on.
+#[must_use]
+pub fn sessions() -> Vec<SessionUsage> {
+    let Some(dir) = data_dir() else {
+        return Vec::new();
+    };
+    sessions_in(&dir, &GooseConfig::load())
+}
+
+/// Confirm Goose has recorded sessions and report when it was last used.
+///
+/// # Errors
+/// Returns an error if no Goose sessions can be found.
+pub async fn fetch_local() -> Result<UsageSnapshot> {
+    let not_found =
+        || CautError::Config("No Goose sessions found; run a session with goose first".to_string());
+    let dir = data_dir().ok_or_else(not_found)?;
+    let config = GooseConfig::load();
+    let sessions = sessions_in(&dir, &config);
+    let latest = sessions
+        .iter()
+        .filter_map(|session| session.ended_at.or(session.started_at))
+        .max()
+        .ok_or_else(not_found)?;
+
+    let backend = match (&config.provider, &config.model) {
+        (Some(provider), Some(model)) => Some(format!("{provider}, {model}")),
+        (provider, model) => provider.clone().or_else(|| model.clone()),
+    };
+    Ok(UsageSnapshot {
+        primary: None,
+        secondary: None,
+        tertiary: None,
+        updated_at: latest,
+        identity: Some(ProviderIdentity {
+            account_email: None,
+            account_organization: None,
+            login_method: backend,
+        }),
+    })
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Goose.
+pub struct GooseFetcher;
+
+impl ProviderFetcher for GooseFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Goose
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "goose-local" => fetch_local().await,
+                _ => Err(unknown_strategy(Provider::Goose, strategy_id)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const CONFIG: &str = "GOOSE_PROVIDER: anthropic\nGOOSE_MODEL: \"claude-sonnet-4\"  # default\nextensions:\n  developer:\n    enabled: true\n";
+
+    #[test]
+    fn reads_database_and_jsonl_sessions_once() {
+        let dir = TempDir::new().unwrap();
+        let sessions_dir = dir.path().join("sessions");
+        fs::create_dir_all(&sessions_dir).unwrap();
+        let conn = Connection::open(sessions_dir.join("sessions.db")).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE sessions (id TEXT PRIMARY KEY, working_dir TEXT,
+                created_at TIMESTAMP, updated_at TIMESTAMP, input_tokens INTEGER,
+                output_tokens INTEGER, accumulated_input_tokens INTEGER,
+                accumulated_output_tokens INTEGER, model_config_json TEXT);
+             INSERT INTO sessions VALUES ('20261015_1', '/work', '2026-10-15 09:00:00',
+                '2026-10-15 09:30:00', 500, 80, 12000, 900,
+                '{\"model_name\": \"gpt-4o\", \"context_limit\": 128000}');",
+        )
+        .unwrap();
+        fs::write(
+            sessions_dir.join("20261015_1.jsonl"),
+            "{\"working_dir\":\"/work\",\"accumulated_input_tokens\":12000}\n",
+        )
+        .unwrap();
+        fs::write(
+            sessions_dir.join("20261014_2.jsonl"),
+            r#"{"working_dir":"/other","description":"fix","message_count":2,"input_tokens":300,"output_tokens":40}
+{"role":"user","created":1760432400,"content":[{"type":"text","text":"hi"}]}
+{"role":"assistant","created":1760432460,"content":[{"type":"text","text":"hello"}]}
+"#,
+        )
+        .unwrap();
+
+        let config = GooseConfig::parse(CONFIG);
+        let sessions = sessions_in(dir.path(), &config);
+        assert_eq!(sessions.len(), 2);
+
+        let db = &sessions[0];
+        assert_eq!(db.input_tokens, 12000);
+        assert_eq!(db.output_tokens, 900);
+        assert!(db.models_used.contains("gpt-4o"));
+        assert_eq!(
+            db.started_at.unwrap().to_rfc3339(),
+            "2026-10-15T09:00:00+00:00"
+        );
+        assert_eq!(db.reported_cost_usd, None);
+
+        let file = &sessions[1];
+        assert_eq!(file.session_id, "20261014_2");
+        assert_eq!(file.input_tokens, 300);
+        assert!(file.models_used.contains("claude-sonnet-4"));
+        assert_eq!(file.cwd.as_deref(), Some(Path::new("/other")));
+        assert_eq!(file.ended_at.unwrap().timestamp(), 1_760_432_460);
+    }
+
+    #[test]
+    fn local_backends_cost_nothing() {
+        let config = GooseConfig::parse("GOOSE_PROVIDER: ollama\nGOOSE_MODEL: qwen2.5-coder\n");
+        assert!(config.is_local());
+        let session = SessionTotals {
+            input_tokens: Some(10),
+            ..SessionTotals::default()
+        }
+        .into_session("s".to_string(), None, (None, None), &config);
+        assert_eq!(session.reported_cost_usd, Some(0.0));
+        assert!(session.models_used.contains("qwen2.5-coder"));
+        assert_eq!(GooseConfig::parse(""), GooseConfig::default());
+    }
+}
@@ -0,0 +1,67 @@
+//! Groq provider implementation.
+//!
+//! Supports:
+//! - Groq API (rate-limit headers)
+//!
+//! Source label: `api`
+//!
+//! Groq reports the key's limits on every response. Window mapping:
+//! primary = tokens per minute, secondary = requests per day.
+//!
+//! Key resolution: `--api-key`, `GROQ_API_KEY`, keyring, then config (see
+//! [`crate::providers::api_key`]).
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::models::UsageSnapshot;
+use crate::core::provider::Provider;
+use crate::error::Result;
+use crate::providers::api_key::ApiKeyProvider;
+use crate::providers::rate_limits::HeaderLimit;
+
+/// Environment variable holding the Groq API key.
+pub const API_KEY_ENV: &str = "GROQ_API_KEY";
+
+const TOKENS_PER_MINUTE: HeaderLimit = HeaderLimit {
+    limit: "x-ratelimit-limit-tokens",
+    remaining: "x-ratelimit-remaining-tokens",
+    reset: "x-ratelimit-reset-tokens",
+    window_minutes: Some(1),
+    unit: "tokens/min",
+};
+
+const REQUESTS_PER_DAY: HeaderLimit = HeaderLimit {
+    limit: "x-ratelimit-limit-requests",
+    remaining: "x-ratelimit-remaining-requests",
+    reset: "x-ratelimit-reset-requests",
+    window_minutes: Some(24 * 60),
+    unit: "req/day",
+};
+
+const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::Groq)
+    .env(API_KEY_ENV)
+    .probe("https://api.groq.com/openai/v1/models")
+    .primary(TOKENS_PER_MINUTE)
+    .secondary(REQUESTS_PER_DAY);
+
+/// Create fetch plan for Groq.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Groq,
+        vec![FetchStrategy {
+            id: "groq-api",
+            kind: FetchKind::ApiToken,
+            is_available: || SPEC.api_key().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+/// Fetch rate-limit usage from the Groq API.
+///
+/// # Errors
+/// Returns an error if no API key is set, the request fails, or the response
+/// carries no rate-limit headers.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    SPEC.fetch_usage().await
+}
@@ -0,0 +1,254 @@
+//! JetBrains AI Assistant provider implementation.
+//!
+//! Supports:
+//! - Local IDE settings probe
+//!
+//! Source label: `local`
+//!
+//! AI Assistant fetches the account's monthly credit quota from JetBrains'
+//! account service and caches it in each IDE's config directory, in
+//! `options/AIAssistantQuotaManager2.xml`. The quota endpoint needs the IDE's
+//! own session token, so caut reads the cached copy from the most recently
+//! updated IDE instead of calling the service directly.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::models::{RateWindow, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+
+/// Quota file written by AI Assistant, relative to an IDE config directory.
+const QUOTA_FILE: &str = "options/AIAssistantQuotaManager2.xml";
+
+/// Environment variable overriding the JetBrains config root.
+pub const CONFIG_DIR_ENV: &str = "CAUT_JETBRAINS_CONFIG_DIR";
+
+/// Monthly quota window in minutes (30 days).
+const QUOTA_WINDOW_MINUTES: i32 = 30 * 24 * 60;
+
+/// Matches `<option name="..." value="..." />` entries.
+static OPTION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"<option\s+name="([^"]+)"\s+value="([^"]*)""#).expect("valid regex")
+});
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for JetBrains AI.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::JetBrainsAI,
+        vec![FetchStrategy {
+            id: "jetbrains-local",
+            kind: FetchKind::LocalProbe,
+            is_available: || find_quota_file().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// Quota File
+// =============================================================================
+
+/// Quota state cached by AI Assistant (`quotaInfo` option).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QuotaInfo {
+    /// Credits used in the current period (decimal string).
+    #[serde(default)]
+    current: Option<String>,
+    /// Credits included in the period (decimal string).
+    #[serde(default)]
+    maximum: Option<String>,
+    /// End of the current period.
+    #[serde(default)]
+    until: Option<DateTime<Utc>>,
+}
+
+/// Next refill (`nextRefill` option).
+#[derive(Debug, Deserialize)]
+struct NextRefill {
+    #[serde(default)]
+    next: Option<DateTime<Utc>>,
+}
+
+/// Root directory holding per-IDE config directories.
+fn config_root() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var(CONFIG_DIR_ENV)
+        && !dir.trim().is_empty()
+    {
+        return Some(PathBuf::from(dir.trim()));
+    }
+    directories::BaseDirs::new().map(|d| d.config_dir().join("JetBrains"))
+}
+
+/// Find the most recently modified quota file across installed IDEs.
+fn find_quota_file() -> Option<PathBuf> {
+    newest_quota_file(&config_root()?)
+}
+
+fn newest_quota_file(root: &Path) -> Option<PathBuf> {
+    fs::read_dir(root)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path().join(QUOTA_FILE))
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((modified, path))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+/// Undo XML attribute escaping.
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#10;", "\n")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+fn option_value(xml: &str, name: &str) -> Option<String> {
+    OPTION_RE
+        .captures_iter(xml)
+        .find(|caps| &caps[1] == name)
+        .map(|caps| unescape_xml(&caps[2]))
+}
+
+/// Parse the quota file into a usage snapshot.
+fn parse_quota_xml(xml: &str) -> Result<UsageSnapshot> {
+    let raw = option_value(xml, "quotaInfo").ok_or_else(|| {
+        CautError::ParseResponse("JetBrains AI quota file has no quotaInfo".to_string())
+    })?;
+    let quota: QuotaInfo = serde_json::from_str(&raw)
+        .map_err(|e| CautError::ParseResponse(format!("Invalid JetBrains AI quota: {e}")))?;
+
+    let parse = |v: &Option<String>| v.as_deref().and_then(|s| s.trim().parse::<f64>().ok());
+    let (Some(current), Some(maximum)) = (parse(&quota.current), parse(&quota.maximum)) else {
+        return Err(CautError::ParseResponse(
+            "JetBrains AI quota is missing current/maximum".to_string(),
+        ));
+    };
+
+    let refill = option_value(xml, "nextRefill")
+        .and_then(|raw| serde_json::from_str::<NextRefill>(&raw).ok())
+        .and_then(|r| r.next);
+    let used_percent = if maximum > 0.0 {
+        (current / maximum * 100.0).clamp(0.0, 100.0)
+    } else {
+        0.0
+    };
+
+    Ok(UsageSnapshot::new(RateWindow {
+        used_percent,
+        window_minutes: Some(QUOTA_WINDOW_MINUTES),
+        resets_at: refill.or(quota.until),
+        reset_description: None,
+    }))
+}
+
+/// Read the monthly AI credit quota cached by the IDE.
+///
+/// # Errors
+/// Returns an error if no IDE has cached a quota, or the file cannot be read
+/// or parsed.
+pub async fn fetch_local() -> Result<UsageSnapshot> {
+    let path = find_quota_file().ok_or_else(|| {
+        CautError::Config(
+            "No JetBrains AI quota found; open AI Assistant in a JetBrains IDE first".to_string(),
+        )
+    })?;
+    tracing::debug!(?path, "Reading JetBrains AI quota");
+
+    let xml = fs::read_to_string(&path)?;
+    parse_quota_xml(&xml)
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for JetBrains AI.
+pub struct JetBrainsFetcher;
+
+impl ProviderFetcher for JetBrainsFetcher {
+    fn provider(&self) -> Provider {
+        Provider::JetBrainsAI
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "jetbrains-local" => fetch_local().await,
+                _ => Err(unknown_strategy(Provider::JetBrainsAI, strategy_id)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const QUOTA_XML: &str = r#"<application>
+  <component name="AIAssistantQuotaManager2">
+    <option name="nextRefill" value="{&quot;type&quot;:&quot;Known&quot;,&quot;next&quot;:&quot;2026-02-01T00:00:00Z&quot;}" />
+    <option name="quotaInfo" value="{&quot;type&quot;:&quot;Available&quot;,&quot;current&quot;:&quot;250000.5&quot;,&quot;maximum&quot;:&quot;1000000&quot;,&quot;until&quot;:&quot;2026-01-31T23:59:59Z&quot;}" />
+  </component>
+</application>"#;
+
+    #[test]
+    fn parses_quota_and_refill() {
+        let snapshot = parse_quota_xml(QUOTA_XML).unwrap();
+        let primary = snapshot.primary.unwrap();
+
+        assert!((primary.used_percent - 25.00005).abs() < 1e-6);
+        assert_eq!(primary.window_minutes, Some(QUOTA_WINDOW_MINUTES));
+        assert_eq!(
+            primary.resets_at.unwrap().to_rfc3339(),
+            "2026-02-01T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn missing_quota_info_is_an_error() {
+        let err = parse_quota_xml("<application />").unwrap_err();
+        assert!(matches!(err, CautError::ParseResponse(_)));
+    }
+
+    #[test]
+    fn unescapes_xml_entities() {
+        assert_eq!(unescape_xml("&quot;a&amp;b&lt;&gt;&quot;"), "\"a&b<>\"");
+    }
+
+    #[test]
+    fn picks_quota_file_from_ide_directories() {
+        let root = TempDir::new().unwrap();
+        let ide = root.path().join("IntelliJIdea2025.3").join("options");
+        fs::create_dir_all(&ide).unwrap();
+        fs::create_dir_all(root.path().join("PyCharm2025.3")).unwrap();
+        fs::write(ide.join("AIAssistantQuotaManager2.xml"), QUOTA_XML).unwrap();
+
+        let found = newest_quota_file(root.path()).unwrap();
+        assert!(found.ends_with("IntelliJIdea2025.3/options/AIAssistantQuotaManager2.xml"));
+    }
+}
@@ -0,0 +1,336 @@
+//! LiteLLM proxy provider implementation.
+//!
+//! Supports:
+//! - LiteLLM proxy API (key info and spend log endpoints)
+//!
+//! Source label: `api`
+//!
+//! Teams often route agents through a self-hosted LiteLLM proxy that hands
+//! out virtual keys with their own budgets. `/key/info` reports what the
+//! calling key has spent and its `max_budget`; the spent share of that
+//! budget is the primary window, resetting at `budget_reset_at`. The key's
+//! TPM/RPM limits are shown as the login method. `/spend/logs` feeds
+//! `caut cost --provider litellm` with the key's daily spend per model.
+//!
+//! The proxy is found at `LITELLM_PROXY_URL`, or `api_base` in the
+//! `[providers.litellm]` config section. Key resolution:
+//! `--api-key`, `LITELLM_PROXY_API_KEY`, keyring, then config (see
+//! [`crate::providers::api_key`]).
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Duration, Local, Utc};
+use serde::Deserialize;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::models::{CostDailyEntry, ProviderIdentity, RateWindow, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::providers::api_key::ApiKeyProvider;
+use crate::storage::config::Config;
+use crate::util::time::parse_duration_secs;
+
+/// Environment variable holding the proxy's base URL.
+pub const PROXY_URL_ENV: &str = "LITELLM_PROXY_URL";
+
+/// Environment variable holding the LiteLLM virtual key.
+pub const API_KEY_ENV: &str = "LITELLM_PROXY_API_KEY";
+
+const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::LiteLLM).env(API_KEY_ENV);
+
+/// Days of spend history read for `caut cost`.
+const HISTORY_DAYS: i64 = 30;
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for LiteLLM.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::LiteLLM,
+        vec![FetchStrategy {
+            id: "litellm-api",
+            kind: FetchKind::ApiToken,
+            is_available: || base_url().is_some() && SPEC.api_key().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+/// The proxy's base URL, without a trailing slash.
+fn base_url() -> Option<String> {
+    std::env::var(PROXY_URL_ENV)
+        .ok()
+        .or_else(|| {
+            Config::load()
+                .ok()?
+                .providers
+                .get_settings(Provider::LiteLLM.cli_name())
+                .api_base
+        })
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty())
+}
+
+fn require_base_url() -> Result<String> {
+    base_url().ok_or_else(|| {
+        CautError::Config(format!(
+            "No LiteLLM proxy configured (set {PROXY_URL_ENV} or api_base under [providers.litellm])"
+        ))
+    })
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct KeyInfoResponse {
+    info: KeyInfo,
+}
+
+/// Virtual key details. Amounts are in USD.
+#[derive(Debug, Deserialize)]
+struct KeyInfo {
+    #[serde(default)]
+    spend: f64,
+    #[serde(default)]
+    max_budget: Option<f64>,
+    /// e.g. `30d`, `1h`, `1mo`.
+    #[serde(default)]
+    budget_duration: Option<String>,
+    #[serde(default)]
+    budget_reset_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    key_alias: Option<String>,
+    #[serde(default)]
+    team_id: Option<String>,
+    #[serde(default)]
+    user_id: Option<String>,
+    #[serde(default)]
+    tpm_limit: Option<i64>,
+    #[serde(default)]
+    rpm_limit: Option<i64>,
+}
+
+/// A spend log row: either one request, or a day's total when the proxy
+/// aggregates by date.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SpendLog {
+    #[serde(default)]
+    start_time: Option<String>,
+    #[serde(default)]
+    spend: f64,
+    #[serde(default)]
+    model: Option<String>,
+    /// Spend per model, on aggregated rows.
+    #[serde(default)]
+    models: BTreeMap<String, f64>,
+    #[serde(default)]
+    total_tokens: Option<i64>,
+    #[serde(default)]
+    prompt_tokens: Option<i64>,
+    #[serde(default)]
+    completion_tokens: Option<i64>,
+}
+
+/// Minutes in a LiteLLM budget duration such as `30d` or `1mo`.
+fn budget_minutes(duration: &str) -> Option<i32> {
+    let secs = match duration.strip_suffix("mo") {
+        Some(months) => months.trim().parse::<u64>().ok()? * 30 * 24 * 3600,
+        None => parse_duration_secs(duration).ok()?,
+    };
+    i32::try_from(secs / 60).ok()
+}
+
+impl KeyInfo {
+    fn budget_window(&self) -> Option<RateWindow> {
+        let budget = self.max_budget.filter(|b| *b > 0.0)?;
+        Some(RateWindow {
+            used_percent: (self.spend / budget * 100.0).clamp(0.0, 100.0),
+            window_minutes: self.budget_duration.as_deref().and_then(budget_minutes),
+            resets_at: self.budget_reset_at,
+            reset_description: Some(format!("${:.2}/${budget:.2} key budget", self.spend)),
+        })
+    }
+
+    fn login_method(&self) -> String {
+        let limits: Vec<String> = [(self.tpm_limit, "TPM"), (self.rpm_limit, "RPM")]
+            .into_iter()
+            .filter_map(|(limit, unit)| limit.map(|limit| format!("{limit} {unit}")))
+            .collect();
+        if limits.is_empty() {
+            "virtual key".to_string()
+        } else {
+            format!("virtual key ({})", limits.join(", "))
+        }
+    }
+}
+
+fn usage_from_key(key: &KeyInfo) -> UsageSnapshot {
+    // Keys without a budget still report spend, which is worth showing.
+    let primary = key.budget_window().unwrap_or_else(|| RateWindow {
+        used_percent: 0.0,
+        window_minutes: None,
+        resets_at: None,
+        reset_description: Some(format!("${:.2} spent, no key budget", key.spend)),
+    });
+
+    UsageSnapshot {
+        primary: Some(primary),
+        secondary: None,
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: Some(ProviderIdentity {
+            account_email: key.user_id.clone(),
+            account_organization: key.team_id.clone().or_else(|| key.key_alias.clone()),
+            login_method: Some(key.login_method()),
+        }),
+    }
+}
+
+// =============================================================================
+// Fetching
+// =============================================================================
+
+/// Fetch the calling key's budget and spend from the proxy.
+///
+/// # Errors
+/// Returns an error if no proxy URL or key is configured, or the request
+/// fails.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    let base = require_base_url()?;
+    let response: KeyInfoResponse = SPEC.get_json(&format!("{base}/key/info")).await?;
+    Ok(usage_from_key(&response.info))
+}
+
+/// Daily spend per model for the calling key over the last 30 days, newest
+/// first.
+///
+/// # Errors
+/// Returns an error if no proxy URL or key is configured, or the request
+/// fails (spend logs can be restricted to admin keys).
+pub async fn daily_spend() -> Result<Vec<CostDailyEntry>> {
+    let base = require_base_url()?;
+    let today = Local::now().date_naive();
+    let start = today - Duration::days(HISTORY_DAYS - 1);
+    let logs: Vec<SpendLog> = SPEC
+        .get_json(&format!(
+            "{base}/spend/logs?start_date={}&end_date={}",
+            start.format("%Y-%m-%d"),
+            (today + Duration::days(1)).format("%Y-%m-%d"),
+        ))
+        .await?;
+    Ok(daily_entries(&logs))
+}
+
+fn daily_entries(logs: &[SpendLog]) -> Vec<CostDailyEntry> {
+    #[derive(Default)]
+    struct Day {
+        cost: f64,
+        input: Option<i64>,
+        output: Option<i64>,
+        total: Option<i64>,
+        models: BTreeSet<String>,
+    }
+    let add = |acc: Option<i64>, value: Option<i64>| match (acc, value) {
+        (acc, None) => acc,
+        (acc, Some(value)) => Some(acc.unwrap_or(0) + value),
+    };
+
+    let mut days: BTreeMap<String, Day> = BTreeMap::new();
+    for log in logs {
+        let Some(date) = log.start_time.as_deref().and_then(|at| at.get(..10)) else {
+            continue;
+        };
+        let day = days.entry(date.to_string()).or_default();
+        day.cost += log.spend;
+        day.input = add(day.input, log.prompt_tokens);
+        day.output = add(day.output, log.completion_tokens);
+        day.total = add(day.total, log.total_tokens);
+        day.models
+            .extend(log.model.iter().filter(|m| !m.is_empty()).cloned());
+        day.models.extend(log.models.keys().cloned());
+    }
+
+    days.into_iter()
+        .rev()
+        .map(|(date, day)| CostDailyEntry {
+            date,
+            input_tokens: day.input,
+            output_tokens: day.output,
+            cache_read_tokens: None,
+            cache_creation_tokens: None,
+            total_tokens: day.total,
+            total_cost: Some(day.cost),
+            models_used: Some(day.models.into_iter().collect()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_key_budget_to_primary_window() {
+        let response: KeyInfoResponse = serde_json::from_str(
+            r#"{"key": "sk-...abcd", "info": {
+                "key_name": "sk-...abcd", "key_alias": "ci-agents", "spend": 37.5,
+                "max_budget": 50.0, "budget_duration": "30d",
+                "budget_reset_at": "2026-11-01T00:00:00Z", "team_id": "platform",
+                "user_id": "dev@example.com", "tpm_limit": 100000, "rpm_limit": null,
+                "models": ["gpt-4o", "claude-sonnet-4"]
+            }}"#,
+        )
+        .unwrap();
+
+        let usage = usage_from_key(&response.info);
+        let primary = usage.primary.unwrap();
+        assert!((primary.used_percent - 75.0).abs() < f64::EPSILON);
+        assert_eq!(primary.window_minutes, Some(30 * 24 * 60));
+        assert!(primary.resets_at.is_some());
+        assert_eq!(
+            primary.reset_description.as_deref(),
+            Some("$37.50/$50.00 key budget")
+        );
+        let identity = usage.identity.unwrap();
+        assert_eq!(identity.account_email.as_deref(), Some("dev@example.com"));
+        assert_eq!(identity.account_organization.as_deref(), Some("platform"));
+        assert_eq!(
+            identity.login_method.as_deref(),
+            Some("virtual key (100000 TPM)")
+        );
+
+        assert_eq!(budget_minutes("1mo"), Some(30 * 24 * 60));
+    }
+
+    #[test]
+    fn sums_spend_logs_per_day() {
+        let logs: Vec<SpendLog> = serde_json::from_str(
+            r#"[
+                {"request_id": "a", "startTime": "2026-10-16T09:00:00Z", "spend": 0.25,
+                 "model": "gpt-4o", "prompt_tokens": 1000, "completion_tokens": 200, "total_tokens": 1200},
+                {"request_id": "b", "startTime": "2026-10-16T10:00:00Z", "spend": 0.5,
+                 "model": "claude-sonnet-4", "prompt_tokens": 3000, "completion_tokens": 100, "total_tokens": 3100},
+                {"startTime": "2026-10-15", "spend": 2.0, "models": {"gpt-4o": 2.0}, "users": {}}
+            ]"#,
+        )
+        .unwrap();
+
+        let days = daily_entries(&logs);
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date, "2026-10-16");
+        assert!((days[0].total_cost.unwrap() - 0.75).abs() < f64::EPSILON);
+        assert_eq!(days[0].input_tokens, Some(4000));
+        assert_eq!(days[0].total_tokens, Some(4300));
+        assert_eq!(
+            days[0].models_used.as_deref(),
+            Some(&["claude-sonnet-4".to_string(), "gpt-4o".to_string()][..])
+        );
+        assert_eq!(days[1].input_tokens, None);
+    }
+}
@@ -1,10 +1,91 @@
 //! Provider-specific fetchers.
 //!
-//! Each provider has its own submodule implementing fetch strategies.
+//! Each provider has its own submodule implementing fetch strategies and a
+//! [`ProviderFetcher`] registered in the provider registry. Providers read
+//! only through their API register an [`api_key::ApiKeyFetcher`] instead of
+//! their own.
 
+use std::sync::Arc;
+
+pub mod aider;
+pub mod amazon_q;
+pub mod api_key;
+pub mod azure_openai;
+pub mod bedrock;
 pub mod claude;
+pub mod cline;
 pub mod codex;
+pub mod cody;
+pub mod cohere;
+pub mod continue_dev;
+pub mod copilot;
+pub mod cursor;
+pub mod deepseek;
+pub mod fireworks;
+pub mod goose;
+pub mod groq;
+pub mod jetbrains;
+pub mod litellm;
+pub mod ollama;
+pub mod openrouter;
+pub mod perplexity;
+pub mod qwen;
+pub mod rate_limits;
+pub mod together;
+pub mod vscode_state;
+pub mod windsurf;
+pub mod xai;
+
+use api_key::ApiKeyFetcher;
 
 // Re-export common types
 pub use crate::core::fetch_plan::{FetchKind, FetchOutcome, FetchPlan, FetchStrategy, SourceMode};
+pub use crate::core::fetcher::ProviderFetcher;
 pub use crate::core::provider::Provider;
+
+/// Fetchers for every implemented provider.
+#[must_use]
+pub fn builtin_fetchers() -> Vec<Arc<dyn ProviderFetcher>> {
+    vec![
+        Arc::new(claude::ClaudeFetcher),
+        Arc::new(codex::CodexFetcher),
+        Arc::new(copilot::CopilotFetcher),
+        Arc::new(jetbrains::JetBrainsFetcher),
+        Arc::new(
+            ApiKeyFetcher::new(perplexity::fetch_plan, perplexity::fetch_api)
+                .with_enrichment(perplexity::enrich),
+        ),
+        Arc::new(ApiKeyFetcher::new(groq::fetch_plan, groq::fetch_api)),
+        Arc::new(ApiKeyFetcher::new(
+            together::fetch_plan,
+            together::fetch_api,
+        )),
+        Arc::new(ApiKeyFetcher::new(cohere::fetch_plan, cohere::fetch_api)),
+        Arc::new(ApiKeyFetcher::new(
+            fireworks::fetch_plan,
+            fireworks::fetch_api,
+        )),
+        Arc::new(cursor::CursorFetcher),
+        Arc::new(windsurf::WindsurfFetcher),
+        Arc::new(aider::AiderFetcher),
+        Arc::new(cline::ClineFetcher),
+        Arc::new(
+            ApiKeyFetcher::new(openrouter::fetch_plan, openrouter::fetch_api)
+                .with_enrichment(openrouter::enrich),
+        ),
+        Arc::new(amazon_q::AmazonQFetcher),
+        Arc::new(ApiKeyFetcher::new(cody::fetch_plan, cody::fetch_api)),
+        Arc::new(
+            ApiKeyFetcher::new(deepseek::fetch_plan, deepseek::fetch_api)
+                .with_enrichment(deepseek::enrich),
+        ),
+        Arc::new(ApiKeyFetcher::new(xai::fetch_plan, xai::fetch_api)),
+        Arc::new(continue_dev::ContinueFetcher),
+        Arc::new(ollama::OllamaFetcher),
+        Arc::new(qwen::QwenFetcher),
+        Arc::new(goose::GooseFetcher),
+        Arc::new(azure_openai::AzureOpenAIFetcher),
+        Arc::new(bedrock::BedrockFetcher),
+        Arc::new(ApiKeyFetcher::new(litellm::fetch_plan, litellm::fetch_api)),
+    ]
+}
@@ -0,0 +1,313 @@
+//! Ollama provider implementation.
+//!
+//! Supports:
+//! - Local Ollama server API
+//!
+//! Source label: `api`
+//!
+//! Ollama runs models locally, so there is no quota or bill; cost is only
+//! estimated from load time when a `[local_cost]` rate is configured (see
+//! [`crate::core::local_cost`]). Usage is tracked as generation throughput
+//! instead. `/api/ps` lists the models loaded in memory; a short generation
+//! on the first one listed returns Ollama's eval counts and durations, from
+//! which prompt and generation speed are derived and recorded to history on
+//! every fetch.
+//! Models that are not loaded are never loaded just to measure them, and the
+//! probe keeps the model's remaining keep-alive time.
+//!
+//! The server is found at `OLLAMA_HOST`, as for the `ollama` CLI, or
+//! `http://127.0.0.1:11434`.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{EnrichFuture, FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::http::{DEFAULT_TIMEOUT, build_client, fetch_json};
+use crate::core::models::{LocalModelStats, ProviderIdentity, ProviderPayload, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+
+/// Environment variable holding the Ollama server address.
+pub const HOST_ENV: &str = "OLLAMA_HOST";
+
+const DEFAULT_PORT: u16 = 11434;
+
+/// Tokens generated when measuring throughput.
+const PROBE_TOKENS: u32 = 16;
+
+const PROBE_PROMPT: &str = "Count from one to twenty.";
+
+/// Remaining keep-alive beyond which a model is treated as never unloading.
+const KEEP_ALIVE_FOREVER_SECS: i64 = 365 * 24 * 3600;
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Ollama.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Ollama,
+        vec![FetchStrategy {
+            id: "ollama-api",
+            kind: FetchKind::ApiToken,
+            is_available: || std::env::var_os(HOST_ENV).is_some() || which::which("ollama").is_ok(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+/// Base URL of the Ollama server.
+#[must_use]
+pub fn base_url() -> String {
+    normalize_host(std::env::var(HOST_ENV).ok().as_deref().unwrap_or_default())
+}
+
+/// Turn an `OLLAMA_HOST` value into a base URL, filling in the scheme and
+/// port the way Ollama does. A bind-all address is reached on loopback.
+fn normalize_host(host: &str) -> String {
+    let host = host.trim().trim_end_matches('/');
+    let (scheme, rest) = host.split_once("://").unwrap_or(("http", host));
+    let rest = match rest {
+        "" => "127.0.0.1",
+        _ => rest,
+    };
+    let (name, port) = match rest.rsplit_once(':') {
+        Some((name, port)) if !name.ends_with(':') && port.parse::<u16>().is_ok() => {
+            (name, port.to_string())
+        }
+        _ => (rest, DEFAULT_PORT.to_string()),
+    };
+    let name = match name {
+        "" | "0.0.0.0" => "127.0.0.1",
+        "[::]" => "[::1]",
+        _ => name,
+    };
+    format!("{scheme}://{name}:{port}")
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct VersionResponse {
+    version: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RunningModels {
+    #[serde(default)]
+    models: Vec<RunningModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RunningModel {
+    name: String,
+    /// When the model will be unloaded.
+    #[serde(default)]
+    expires_at: Option<DateTime<Utc>>,
+}
+
+/// Timing fields of a non-streamed `/api/generate` response. Durations are
+/// in nanoseconds.
+#[derive(Debug, Deserialize)]
+struct GenerateStats {
+    #[serde(default)]
+    prompt_eval_count: i64,
+    #[serde(default)]
+    prompt_eval_duration: u64,
+    #[serde(default)]
+    eval_count: i64,
+    #[serde(default)]
+    eval_duration: u64,
+}
+
+impl GenerateStats {
+    fn into_stats(self, model: String, loaded_models: Vec<String>) -> LocalModelStats {
+        LocalModelStats {
+            model,
+            loaded_models,
+            prompt_tokens: self.prompt_eval_count,
+            eval_tokens: self.eval_count,
+            prompt_tokens_per_sec: tokens_per_sec(
+                self.prompt_eval_count,
+                self.prompt_eval_duration,
+            ),
+            eval_tokens_per_sec: tokens_per_sec(self.eval_count, self.eval_duration),
+        }
+    }
+}
+
+#[allow(clippy::cast_precision_loss)] // token counts and durations are far below 2^52
+fn tokens_per_sec(tokens: i64, duration_ns: u64) -> Option<f64> {
+    (tokens > 0 && duration_ns > 0).then(|| tokens as f64 / (duration_ns as f64 / 1e9))
+}
+
+fn unreachable_error(e: CautError) -> CautError {
+    match e {
+        CautError::Network(reason) => CautError::FetchFailed {
+            provider: Provider::Ollama.cli_name().to_string(),
+            reason: format!("Ollama server not reachable at {} ({reason})", base_url()),
+        },
+        other => other,
+    }
+}
+
+// =============================================================================
+// Fetching
+// =============================================================================
+
+/// Confirm the Ollama server is running and report its version.
+///
+/// # Errors
+/// Returns an error if the server does not answer.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let version: VersionResponse = fetch_json(&client, &format!("{}/api/version", base_url()))
+        .await
+        .map_err(unreachable_error)?;
+
+    Ok(UsageSnapshot {
+        primary: None,
+        secondary: None,
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: Some(ProviderIdentity {
+            account_email: None,
+            account_organization: None,
+            login_method: Some(format!("local, ollama {}", version.version)),
+        }),
+    })
+}
+
+/// Measure generation throughput on the first loaded model.
+///
+/// # Errors
+/// Returns an error if the server does not answer, no model is loaded, or
+/// the model cannot generate (e.g. an embedding model).
+pub async fn fetch_stats() -> Result<LocalModelStats> {
+    let base = base_url();
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let running: RunningModels = fetch_json(&client, &format!("{base}/api/ps"))
+        .await
+        .map_err(unreachable_error)?;
+    let Some(target) = running.models.first() else {
+        return Err(CautError::Config(
+            "No Ollama model is loaded; throughput is measured on a loaded model".to_string(),
+        ));
+    };
+
+    let mut request = json!({
+        "model": target.name,
+        "prompt": PROBE_PROMPT,
+        "stream": false,
+        "options": { "num_predict": PROBE_TOKENS },
+    });
+    // Leave the model loaded for as long as it already was; models kept
+    // loaded indefinitely report an expiry centuries away.
+    if let Some(expires) = target.expires_at {
+        let secs = expires.signed_duration_since(Utc::now()).num_seconds();
+        request["keep_alive"] = json!(if secs > KEEP_ALIVE_FOREVER_SECS {
+            -1
+        } else {
+            secs.max(1)
+        });
+    }
+    let response = client
+        .post(format!("{base}/api/generate"))
+        .json(&request)
+        .send()
+        .await
+        .map_err(|e| CautError::Network(e.to_string()))?;
+    if !response.status().is_success() {
+        return Err(CautError::FetchFailed {
+            provider: Provider::Ollama.cli_name().to_string(),
+            reason: format!(
+                "generation on {} returned {}",
+                target.name,
+                response.status()
+            ),
+        });
+    }
+    let stats: GenerateStats = response
+        .json()
+        .await
+        .map_err(|e| CautError::ParseResponse(format!("Invalid Ollama response: {e}")))?;
+
+    let loaded = running.models.iter().map(|m| m.name.clone()).collect();
+    Ok(stats.into_stats(target.name.clone(), loaded))
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Ollama.
+pub struct OllamaFetcher;
+
+impl ProviderFetcher for OllamaFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Ollama
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "ollama-api" => fetch_api().await,
+                _ => Err(unknown_strategy(Provider::Ollama, strategy_id)),
+            }
+        })
+    }
+
+    /// Attach throughput on the loaded model, when one is loaded.
+    fn enrich<'a>(&'a self, payload: &'a mut ProviderPayload) -> EnrichFuture<'a> {
+        Box::pin(async move {
+            payload.local_model = fetch_stats()
+                .await
+                .map_err(|e| tracing::debug!("No Ollama throughput measured: {}", e))
+                .ok();
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_values_become_base_urls() {
+        assert_eq!(normalize_host(""), "http://127.0.0.1:11434");
+        assert_eq!(normalize_host("0.0.0.0"), "http://127.0.0.1:11434");
+        assert_eq!(normalize_host("0.0.0.0:8080"), "http://127.0.0.1:8080");
+        assert_eq!(
+            normalize_host("https://ollama.lan/"),
+            "https://ollama.lan:11434"
+        );
+        assert_eq!(normalize_host("gpu-box:11500"), "http://gpu-box:11500");
+    }
+
+    #[test]
+    fn generate_timings_give_throughput() {
+        let stats: GenerateStats = serde_json::from_str(
+            r#"{"model": "llama3.2", "response": "1, 2, 3", "done": true,
+                "total_duration": 600000000, "load_duration": 1000000,
+                "prompt_eval_count": 30, "prompt_eval_duration": 100000000,
+                "eval_count": 16, "eval_duration": 400000000}"#,
+        )
+        .unwrap();
+        let stats = stats.into_stats("llama3.2".to_string(), vec!["llama3.2".to_string()]);
+
+        assert_eq!(stats.eval_tokens, 16);
+        assert!((stats.eval_tokens_per_sec.unwrap() - 40.0).abs() < 1e-9);
+        assert!((stats.prompt_tokens_per_sec.unwrap() - 300.0).abs() < 1e-9);
+        assert_eq!(tokens_per_sec(0, 100), None);
+    }
+}
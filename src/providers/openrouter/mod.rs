@@ -0,0 +1,254 @@
+//! `OpenRouter` provider implementation.
+//!
+//! Supports:
+//! - `OpenRouter` API (key usage endpoint)
+//!
+//! Source label: `api`
+//!
+//! `/api/v1/key` reports what the key has spent and, when the key has a credit
+//! limit, how much of it remains and how often it resets. The spent share of
+//! that limit is the primary window. Keys without a limit draw on the account
+//! balance, so the remaining credit then comes from `/api/v1/credits`. The
+//! key's request rate limit and free-tier status are shown as the login
+//! method.
+//!
+//! Key resolution: `--api-key`, `OPENROUTER_API_KEY`, keyring, then config
+//! (see [`crate::providers::api_key`]).
+
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::EnrichFuture;
+use crate::core::models::{
+    CreditsSnapshot, ProviderIdentity, ProviderPayload, RateWindow, UsageSnapshot,
+};
+use crate::core::provider::Provider;
+use crate::error::Result;
+use crate::providers::api_key::ApiKeyProvider;
+
+/// Environment variable holding the `OpenRouter` API key.
+pub const API_KEY_ENV: &str = "OPENROUTER_API_KEY";
+
+/// Current key endpoint.
+const KEY_URL: &str = "https://openrouter.ai/api/v1/key";
+
+/// Account credits endpoint.
+const CREDITS_URL: &str = "https://openrouter.ai/api/v1/credits";
+
+const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::OpenRouter).env(API_KEY_ENV);
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for `OpenRouter`.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::OpenRouter,
+        vec![FetchStrategy {
+            id: "openrouter-api",
+            kind: FetchKind::ApiToken,
+            is_available: || SPEC.api_key().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct Envelope<T> {
+    data: T,
+}
+
+/// Key details. Amounts are in USD.
+#[derive(Debug, Deserialize)]
+struct KeyInfo {
+    /// Spent on this key since it was created.
+    #[serde(default)]
+    usage: f64,
+    /// Credit limit, or `None` for unlimited keys.
+    #[serde(default)]
+    limit: Option<f64>,
+    #[serde(default)]
+    limit_remaining: Option<f64>,
+    /// `daily`, `weekly`, or `monthly` when the limit resets.
+    #[serde(default)]
+    limit_reset: Option<String>,
+    #[serde(default)]
+    is_free_tier: bool,
+    #[serde(default)]
+    rate_limit: Option<KeyRateLimit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KeyRateLimit {
+    requests: i64,
+    interval: String,
+}
+
+/// Account credit totals. Amounts are in USD.
+#[derive(Debug, Deserialize)]
+struct AccountCredits {
+    total_credits: f64,
+    total_usage: f64,
+}
+
+impl KeyInfo {
+    fn remaining(&self) -> Option<f64> {
+        self.limit_remaining
+            .or_else(|| self.limit.map(|limit| limit - self.usage))
+            .map(|remaining| remaining.max(0.0))
+    }
+
+    fn limit_window(&self) -> Option<RateWindow> {
+        let limit = self.limit.filter(|l| *l > 0.0)?;
+        let remaining = self.remaining().unwrap_or(0.0);
+        let used = limit - remaining;
+        let window_minutes = match self.limit_reset.as_deref() {
+            Some("daily") => Some(24 * 60),
+            Some("weekly") => Some(7 * 24 * 60),
+            _ => None,
+        };
+        let reset = self
+            .limit_reset
+            .as_deref()
+            .map_or_else(String::new, |period| format!(", resets {period}"));
+
+        Some(RateWindow {
+            used_percent: (used / limit * 100.0).clamp(0.0, 100.0),
+            window_minutes,
+            resets_at: None,
+            reset_description: Some(format!("${used:.2}/${limit:.2} key limit{reset}")),
+        })
+    }
+
+    fn login_method(&self) -> String {
+        let tier = if self.is_free_tier {
+            "free tier"
+        } else {
+            "api-key"
+        };
+        self.rate_limit.as_ref().map_or_else(
+            || tier.to_string(),
+            |rate| format!("{tier} ({} req/{})", rate.requests, rate.interval),
+        )
+    }
+}
+
+async fn fetch_key() -> Result<KeyInfo> {
+    let envelope: Envelope<KeyInfo> = SPEC.get_json(KEY_URL).await?;
+    Ok(envelope.data)
+}
+
+/// Fetch usage from the `OpenRouter` key endpoint.
+///
+/// # Errors
+/// Returns an error if no API key is set or the key request fails.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    Ok(usage_from_key(&fetch_key().await?))
+}
+
+/// Fetch the credit left to spend with this key.
+///
+/// Uses the key's own limit when it has one, and the account balance
+/// otherwise.
+///
+/// # Errors
+/// Returns an error if no API key is set or a request fails.
+pub async fn fetch_credits() -> Result<CreditsSnapshot> {
+    let key = fetch_key().await?;
+    let remaining = match key.remaining() {
+        Some(remaining) => remaining,
+        None => {
+            let account: Envelope<AccountCredits> = SPEC.get_json(CREDITS_URL).await?;
+            (account.data.total_credits - account.data.total_usage).max(0.0)
+        }
+    };
+    Ok(CreditsSnapshot {
+        remaining,
+        events: Vec::new(),
+        updated_at: Utc::now(),
+    })
+}
+
+/// Attach the key limit or account balance left to a fetched payload.
+pub fn enrich(payload: &mut ProviderPayload) -> EnrichFuture<'_> {
+    Box::pin(async move {
+        payload.credits = fetch_credits()
+            .await
+            .map_err(|e| tracing::warn!("Failed to fetch OpenRouter credits: {}", e))
+            .ok();
+    })
+}
+
+fn usage_from_key(key: &KeyInfo) -> UsageSnapshot {
+    UsageSnapshot {
+        primary: key.limit_window(),
+        secondary: None,
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: Some(ProviderIdentity {
+            account_email: None,
+            account_organization: None,
+            login_method: Some(key.login_method()),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> KeyInfo {
+        serde_json::from_str::<Envelope<KeyInfo>>(json)
+            .unwrap()
+            .data
+    }
+
+    #[test]
+    fn limited_key_maps_to_window() {
+        let key = parse(
+            r#"{"data": {
+                "label": "sk-or-v1-abc...",
+                "usage": 7.5,
+                "limit": 30,
+                "limit_remaining": 22.5,
+                "limit_reset": "weekly",
+                "is_free_tier": false,
+                "rate_limit": {"requests": 200, "interval": "10s"}
+            }}"#,
+        );
+
+        let usage = usage_from_key(&key);
+        let window = usage.primary.unwrap();
+        assert!((window.used_percent - 25.0).abs() < f64::EPSILON);
+        assert_eq!(window.window_minutes, Some(7 * 24 * 60));
+        assert_eq!(
+            window.reset_description.as_deref(),
+            Some("$7.50/$30.00 key limit, resets weekly")
+        );
+        assert_eq!(
+            usage.identity.unwrap().login_method.as_deref(),
+            Some("api-key (200 req/10s)")
+        );
+        assert!((key.remaining().unwrap() - 22.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn unlimited_key_has_no_window() {
+        let key = parse(r#"{"data": {"usage": 1.25, "limit": null, "is_free_tier": true}}"#);
+
+        assert!(key.remaining().is_none());
+        let usage = usage_from_key(&key);
+        assert!(usage.primary.is_none());
+        assert_eq!(
+            usage.identity.unwrap().login_method.as_deref(),
+            Some("free tier")
+        );
+    }
+}
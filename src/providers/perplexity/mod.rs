@@ -0,0 +1,141 @@
+//! Perplexity provider implementation.
+//!
+//! Supports:
+//! - Perplexity API (account credit balance)
+//!
+//! Source label: `api`
+//!
+//! The API is prepaid, so there are no rate windows to report; the account's
+//! remaining credit balance is attached to the payload instead. When the
+//! response also includes the total credits granted, the share spent is shown
+//! as the primary window.
+//!
+//! Key resolution: `--api-key`, `PERPLEXITY_API_KEY`, keyring, then config
+//! (see [`crate::providers::api_key`]).
+
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::EnrichFuture;
+use crate::core::models::{CreditsSnapshot, ProviderPayload, RateWindow, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::Result;
+use crate::providers::api_key::ApiKeyProvider;
+
+/// Environment variable holding the Perplexity API key.
+pub const API_KEY_ENV: &str = "PERPLEXITY_API_KEY";
+
+/// Account credits endpoint.
+const CREDITS_URL: &str = "https://api.perplexity.ai/v1/credits";
+
+const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::Perplexity).env(API_KEY_ENV);
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Perplexity.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Perplexity,
+        vec![FetchStrategy {
+            id: "perplexity-api",
+            kind: FetchKind::ApiToken,
+            is_available: || SPEC.api_key().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+/// Credit balance response. Amounts are in USD.
+#[derive(Debug, Deserialize)]
+struct CreditsResponse {
+    #[serde(alias = "balance", alias = "remaining_credits", alias = "credits")]
+    remaining: f64,
+    #[serde(default, alias = "total_credits", alias = "granted")]
+    total: Option<f64>,
+}
+
+impl CreditsResponse {
+    fn used_percent(&self) -> Option<f64> {
+        let total = self.total.filter(|t| *t > 0.0)?;
+        Some(((total - self.remaining) / total * 100.0).clamp(0.0, 100.0))
+    }
+}
+
+async fn fetch_balance() -> Result<CreditsResponse> {
+    SPEC.get_json(CREDITS_URL).await
+}
+
+/// Fetch usage from the Perplexity API.
+///
+/// # Errors
+/// Returns an error if no API key is set or the credits request fails.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    Ok(usage_from_balance(&fetch_balance().await?))
+}
+
+/// Fetch the account's remaining credit balance.
+///
+/// # Errors
+/// Returns an error if no API key is set or the credits request fails.
+pub async fn fetch_credits() -> Result<CreditsSnapshot> {
+    Ok(credits_from_balance(&fetch_balance().await?))
+}
+
+/// Attach the prepaid credit balance to a fetched payload.
+pub fn enrich(payload: &mut ProviderPayload) -> EnrichFuture<'_> {
+    Box::pin(async move {
+        payload.credits = fetch_credits()
+            .await
+            .map_err(|e| tracing::warn!("Failed to fetch Perplexity credits: {}", e))
+            .ok();
+    })
+}
+
+fn usage_from_balance(balance: &CreditsResponse) -> UsageSnapshot {
+    UsageSnapshot {
+        primary: balance.used_percent().map(RateWindow::new),
+        secondary: None,
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: None,
+    }
+}
+
+fn credits_from_balance(balance: &CreditsResponse) -> CreditsSnapshot {
+    CreditsSnapshot {
+        remaining: balance.remaining,
+        events: Vec::new(),
+        updated_at: Utc::now(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_balance_aliases() {
+        let balance: CreditsResponse =
+            serde_json::from_str(r#"{"balance": 12.5, "total_credits": 50}"#).unwrap();
+        assert!((balance.remaining - 12.5).abs() < f64::EPSILON);
+        assert!((balance.used_percent().unwrap() - 75.0).abs() < f64::EPSILON);
+
+        let credits = credits_from_balance(&balance);
+        assert!((credits.remaining - 12.5).abs() < f64::EPSILON);
+        assert!(credits.events.is_empty());
+    }
+
+    #[test]
+    fn balance_without_total_has_no_window() {
+        let balance: CreditsResponse = serde_json::from_str(r#"{"remaining": 3.0}"#).unwrap();
+        assert!(usage_from_balance(&balance).primary.is_none());
+    }
+}
@@ -0,0 +1,332 @@
+//! Qwen Code provider implementation.
+//!
+//! Supports:
+//! - Local OAuth credentials and chat recording probe
+//!
+//! Source label: `local`
+//!
+//! Qwen Code signs in with Qwen OAuth and keeps the token in
+//! `~/.qwen/oauth_creds.json`, as Gemini CLI does under `~/.gemini`. The free
+//! tier allows 2,000 model requests a day and has no endpoint reporting how
+//! many remain, so today's requests are counted from Qwen Code's chat
+//! recordings: one per assistant response in
+//! `projects/<project>/chats/*.jsonl`, or per model message in the older
+//! `tmp/<project>/chats/session-*.json` files. The day is counted in UTC.
+//! Responses that failed before being recorded are not counted, so the
+//! figure can run slightly under Qwen's own.
+//!
+//! Sign-ins with an API key instead of Qwen OAuth have no daily quota and are
+//! not reported. Set `CAUT_QWEN_DIR` to read a different Qwen directory.
+
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::models::{ProviderIdentity, RateWindow, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+
+/// Environment variable overriding the Qwen directory.
+pub const QWEN_DIR_ENV: &str = "CAUT_QWEN_DIR";
+
+/// Model requests per day on the Qwen OAuth free tier.
+pub const FREE_TIER_DAILY_REQUESTS: u32 = 2_000;
+
+const CREDS_FILE: &str = "oauth_creds.json";
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Qwen Code.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Qwen,
+        vec![FetchStrategy {
+            id: "qwen-local",
+            kind: FetchKind::LocalProbe,
+            is_available: || qwen_dir().is_some_and(|dir| dir.join(CREDS_FILE).is_file()),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// Local Credentials
+// =============================================================================
+
+/// Qwen OAuth credentials as written by Qwen Code.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QwenCredentials {
+    pub access_token: String,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    /// API host the token is valid for (e.g. `portal.qwen.ai`).
+    #[serde(default)]
+    pub resource_url: Option<String>,
+    /// Milliseconds since the epoch.
+    #[serde(default)]
+    pub expiry_date: Option<i64>,
+}
+
+impl QwenCredentials {
+    /// Whether the sign-in is unusable: the access token has expired and
+    /// there is no refresh token for Qwen Code to renew it with.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        let refreshable = self
+            .refresh_token
+            .as_deref()
+            .is_some_and(|token| !token.is_empty());
+        !refreshable
+            && self
+                .expiry_date
+                .and_then(DateTime::<Utc>::from_timestamp_millis)
+                .is_some_and(|at| at <= Utc::now())
+    }
+}
+
+/// The Qwen directory, if it exists.
+fn qwen_dir() -> Option<PathBuf> {
+    let dir = match std::env::var_os(QWEN_DIR_ENV) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => directories::BaseDirs::new()?.home_dir().join(".qwen"),
+    };
+    dir.is_dir().then_some(dir)
+}
+
+fn read_credentials(dir: &Path) -> Result<QwenCredentials> {
+    let path = dir.join(CREDS_FILE);
+    let raw = fs::read_to_string(&path).map_err(|_| {
+        CautError::Config("Not signed in to Qwen Code (run: qwen, then /auth)".to_string())
+    })?;
+    serde_json::from_str(&raw).map_err(|e| {
+        CautError::ParseResponse(format!("Invalid Qwen credentials {}: {e}", path.display()))
+    })
+}
+
+// =============================================================================
+// Chat Recordings
+// =============================================================================
+
+/// One record in a JSONL chat recording.
+#[derive(Debug, Deserialize)]
+struct ChatRecord {
+    #[serde(rename = "type")]
+    kind: String,
+    timestamp: DateTime<Utc>,
+}
+
+/// Chat recording files in every project, both layouts.
+fn chat_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for root in ["projects", "tmp"] {
+        let Ok(projects) = fs::read_dir(dir.join(root)) else {
+            continue;
+        };
+        for project in projects.flatten() {
+            if let Ok(chats) = fs::read_dir(project.path().join("chats")) {
+                files.extend(chats.flatten().map(|chat| chat.path()).filter(|path| {
+                    path.extension()
+                        .is_some_and(|ext| ext == "jsonl" || ext == "json")
+                }));
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// When each model response in a chat recording was made.
+fn response_times(path: &Path) -> Vec<DateTime<Utc>> {
+    let Ok(file) = fs::File::open(path) else {
+        return Vec::new();
+    };
+    if path.extension().is_some_and(|ext| ext == "jsonl") {
+        return BufReader::new(file)
+            .lines()
+            .map_while(std::result::Result::ok)
+            .filter_map(|line| serde_json::from_str::<ChatRecord>(&line).ok())
+            .filter(|record| record.kind == "assistant")
+            .map(|record| record.timestamp)
+            .collect();
+    }
+
+    // Older recordings: one JSON document with a `messages` array, where
+    // model turns are typed after the model family.
+    let Ok(doc) = serde_json::from_reader::<_, Value>(BufReader::new(file)) else {
+        return Vec::new();
+    };
+    doc.get("messages")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|message| {
+            matches!(
+                message.get("type").and_then(Value::as_str),
+                Some("qwen" | "gemini" | "model")
+            )
+        })
+        .filter_map(|message| message.get("timestamp")?.as_str()?.parse().ok())
+        .collect()
+}
+
+/// Model requests recorded since `since`.
+fn requests_since(dir: &Path, since: DateTime<Utc>) -> u32 {
+    let count = chat_files(dir)
+        .iter()
+        .flat_map(|path| response_times(path))
+        .filter(|at| *at >= since)
+        .count();
+    u32::try_from(count).unwrap_or(u32::MAX)
+}
+
+fn daily_window(requests: u32, now: DateTime<Utc>) -> RateWindow {
+    let today = now.date_naive().and_hms_opt(0, 0, 0).unwrap_or_default();
+    RateWindow {
+        used_percent: (f64::from(requests) / f64::from(FREE_TIER_DAILY_REQUESTS) * 100.0)
+            .clamp(0.0, 100.0),
+        window_minutes: Some(24 * 60),
+        resets_at: Some(today.and_utc() + Duration::days(1)),
+        reset_description: Some(format!(
+            "{requests}/{FREE_TIER_DAILY_REQUESTS} requests today"
+        )),
+    }
+}
+
+// =============================================================================
+// Fetching
+// =============================================================================
+
+/// Report today's share of the free-tier request quota.
+///
+/// # Errors
+/// Returns an error if there is no Qwen OAuth sign-in or it has expired.
+pub async fn fetch_local() -> Result<UsageSnapshot> {
+    let dir = qwen_dir().ok_or_else(|| {
+        CautError::Config(
+            "Qwen Code not found (install: npm install -g @qwen-code/qwen-code)".to_string(),
+        )
+    })?;
+    let creds = read_credentials(&dir)?;
+    if creds.is_expired() {
+        return Err(CautError::Config(
+            "Qwen sign-in has expired (run: qwen, then /auth)".to_string(),
+        ));
+    }
+
+    let now = Utc::now();
+    let today = now
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .unwrap_or_default()
+        .and_utc();
+    Ok(UsageSnapshot {
+        primary: Some(daily_window(requests_since(&dir, today), now)),
+        secondary: None,
+        tertiary: None,
+        updated_at: now,
+        identity: Some(ProviderIdentity {
+            account_email: None,
+            account_organization: creds.resource_url,
+            login_method: Some("qwen-oauth".to_string()),
+        }),
+    })
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Qwen Code.
+pub struct QwenFetcher;
+
+impl ProviderFetcher for QwenFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Qwen
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "qwen-local" => fetch_local().await,
+                _ => Err(unknown_strategy(Provider::Qwen, strategy_id)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn counts_todays_responses_in_both_layouts() {
+        let dir = TempDir::new().unwrap();
+        let jsonl = dir.path().join("projects/-work-app/chats");
+        fs::create_dir_all(&jsonl).unwrap();
+        fs::write(
+            jsonl.join("a1.jsonl"),
+            r#"{"uuid":"1","type":"user","timestamp":"2026-10-16T08:00:00Z"}
+{"uuid":"2","type":"assistant","timestamp":"2026-10-16T08:00:05Z","model":"coder-model"}
+{"uuid":"3","type":"assistant","timestamp":"2026-10-15T23:59:00Z"}
+not json
+"#,
+        )
+        .unwrap();
+        let legacy = dir.path().join("tmp/3f2a/chats");
+        fs::create_dir_all(&legacy).unwrap();
+        fs::write(
+            legacy.join("session-1.json"),
+            r#"{"sessionId":"s","messages":[
+                {"type":"user","timestamp":"2026-10-16T09:00:00Z"},
+                {"type":"qwen","timestamp":"2026-10-16T09:00:03Z"},
+                {"type":"gemini","timestamp":"2026-10-16T09:01:00Z"}]}"#,
+        )
+        .unwrap();
+
+        let since = "2026-10-16T00:00:00Z".parse().unwrap();
+        assert_eq!(requests_since(dir.path(), since), 3);
+
+        let now = "2026-10-16T12:00:00Z".parse().unwrap();
+        let window = daily_window(500, now);
+        assert!((window.used_percent - 25.0).abs() < f64::EPSILON);
+        assert_eq!(
+            window.resets_at.unwrap().to_rfc3339(),
+            "2026-10-17T00:00:00+00:00"
+        );
+        assert_eq!(
+            window.reset_description.as_deref(),
+            Some("500/2000 requests today")
+        );
+    }
+
+    #[test]
+    fn refresh_token_keeps_sign_in_valid() {
+        let creds: QwenCredentials = serde_json::from_str(
+            r#"{"access_token":"a","refresh_token":"r","token_type":"Bearer",
+                "resource_url":"portal.qwen.ai","expiry_date":1600000000000}"#,
+        )
+        .unwrap();
+        assert!(!creds.is_expired());
+        assert_eq!(creds.resource_url.as_deref(), Some("portal.qwen.ai"));
+
+        let creds = QwenCredentials {
+            refresh_token: None,
+            ..creds
+        };
+        assert!(creds.is_expired());
+    }
+}
@@ -0,0 +1,149 @@
+//! Rate-limit header parsing shared by API-key providers.
+//!
+//! OpenAI-compatible inference APIs report the key's limits on every
+//! response as `x-ratelimit-*` style headers. A cheap authenticated request
+//! (usually listing models) is enough to read them, so providers only need
+//! to name their headers and window lengths.
+
+use chrono::{DateTime, Duration, Utc};
+use reqwest::header::HeaderMap;
+
+use crate::core::models::RateWindow;
+use crate::core::rate_window::{RawLength, RawReset, RawUsage, RawWindow};
+
+/// Header names describing one limit.
+#[derive(Debug, Clone, Copy)]
+pub struct HeaderLimit {
+    /// Header carrying the limit.
+    pub limit: &'static str,
+    /// Header carrying what is left of the limit.
+    pub remaining: &'static str,
+    /// Header carrying time until the limit resets.
+    pub reset: &'static str,
+    /// Window length, if the limit is measured over whole minutes.
+    pub window_minutes: Option<i32>,
+    /// Unit shown after the limit (e.g. "req/day").
+    pub unit: &'static str,
+}
+
+/// Build a rate window from the headers of one limit.
+///
+/// Returns `None` when the limit or remaining header is missing.
+#[must_use]
+pub fn window_from_headers(
+    headers: &HeaderMap,
+    spec: &HeaderLimit,
+    now: DateTime<Utc>,
+) -> Option<RateWindow> {
+    let number = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse::<f64>().ok();
+    let limit = number(spec.limit)?;
+    let remaining = number(spec.remaining)?;
+
+    let mut window = RawWindow::new(RawUsage::Remaining { remaining, limit })
+        .with_description(format!("{limit:.0} {}", spec.unit));
+    if let Some(minutes) = spec.window_minutes {
+        window = window.with_length(RawLength::Minutes(minutes.into()));
+    }
+    if let Some(after) = headers
+        .get(spec.reset)
+        .and_then(|v| v.to_str().ok())
+        .and_then(parse_reset)
+    {
+        window = window.with_reset(RawReset::At(now + after));
+    }
+    window.normalize(now)
+}
+
+/// Parse a reset value: plain seconds (`"12"`, `"0.5"`) or a Go-style
+/// duration (`"2m59.56s"`, `"1h30m"`, `"250ms"`).
+#[must_use]
+pub fn parse_reset(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<f64>() {
+        return seconds(secs);
+    }
+
+    let mut total = 0.0;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let amount: f64 = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        total += match &rest[..unit_len] {
+            "h" => amount * 3600.0,
+            "m" => amount * 60.0,
+            "s" => amount,
+            "ms" => amount / 1000.0,
+            _ => return None,
+        };
+        rest = &rest[unit_len..];
+    }
+    seconds(total)
+}
+
+fn seconds(secs: f64) -> Option<Duration> {
+    if !secs.is_finite() || secs < 0.0 {
+        return None;
+    }
+    #[allow(clippy::cast_possible_truncation)] // reset times are short
+    Some(Duration::milliseconds((secs * 1000.0).round() as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    const TOKENS: HeaderLimit = HeaderLimit {
+        limit: "x-ratelimit-limit-tokens",
+        remaining: "x-ratelimit-remaining-tokens",
+        reset: "x-ratelimit-reset-tokens",
+        window_minutes: Some(1),
+        unit: "tokens/min",
+    };
+
+    #[test]
+    fn parses_reset_formats() {
+        assert_eq!(parse_reset("12"), Some(Duration::seconds(12)));
+        assert_eq!(
+            parse_reset("2m59.5s"),
+            Some(Duration::milliseconds(179_500))
+        );
+        assert_eq!(parse_reset("1h30m"), Some(Duration::minutes(90)));
+        assert_eq!(parse_reset("250ms"), Some(Duration::milliseconds(250)));
+        assert_eq!(parse_reset("soon"), None);
+        assert_eq!(parse_reset("-1"), None);
+    }
+
+    #[test]
+    fn builds_window_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(TOKENS.limit, HeaderValue::from_static("6000"));
+        headers.insert(TOKENS.remaining, HeaderValue::from_static("1500"));
+        headers.insert(TOKENS.reset, HeaderValue::from_static("7.5s"));
+        let now = Utc::now();
+
+        let window = window_from_headers(&headers, &TOKENS, now).unwrap();
+        assert!((window.used_percent - 75.0).abs() < f64::EPSILON);
+        assert_eq!(window.window_minutes, Some(1));
+        assert_eq!(window.resets_at, Some(now + Duration::milliseconds(7500)));
+        assert!(
+            window
+                .reset_description
+                .unwrap()
+                .starts_with("6000 tokens/min")
+        );
+    }
+
+    #[test]
+    fn missing_headers_yield_no_window() {
+        let mut headers = HeaderMap::new();
+        headers.insert(TOKENS.limit, HeaderValue::from_static("6000"));
+        assert!(window_from_headers(&headers, &TOKENS, Utc::now()).is_none());
+    }
+}
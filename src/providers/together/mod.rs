@@ -0,0 +1,67 @@
+//! Together AI provider implementation.
+//!
+//! Supports:
+//! - Together AI API (rate-limit headers)
+//!
+//! Source label: `api`
+//!
+//! Together reports the key's limits on every response. Window mapping:
+//! primary = tokens per minute, secondary = requests per second.
+//!
+//! Key resolution: `--api-key`, `TOGETHER_API_KEY`, keyring, then config (see
+//! [`crate::providers::api_key`]).
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::models::UsageSnapshot;
+use crate::core::provider::Provider;
+use crate::error::Result;
+use crate::providers::api_key::ApiKeyProvider;
+use crate::providers::rate_limits::HeaderLimit;
+
+/// Environment variable holding the Together AI API key.
+pub const API_KEY_ENV: &str = "TOGETHER_API_KEY";
+
+const TOKENS_PER_MINUTE: HeaderLimit = HeaderLimit {
+    limit: "x-tokenlimit-limit",
+    remaining: "x-tokenlimit-remaining",
+    reset: "x-ratelimit-reset",
+    window_minutes: Some(1),
+    unit: "tokens/min",
+};
+
+const REQUESTS_PER_SECOND: HeaderLimit = HeaderLimit {
+    limit: "x-ratelimit-limit",
+    remaining: "x-ratelimit-remaining",
+    reset: "x-ratelimit-reset",
+    window_minutes: None,
+    unit: "req/s",
+};
+
+const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::Together)
+    .env(API_KEY_ENV)
+    .probe("https://api.together.xyz/v1/models")
+    .primary(TOKENS_PER_MINUTE)
+    .secondary(REQUESTS_PER_SECOND);
+
+/// Create fetch plan for Together AI.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Together,
+        vec![FetchStrategy {
+            id: "together-api",
+            kind: FetchKind::ApiToken,
+            is_available: || SPEC.api_key().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+/// Fetch rate-limit usage from the Together AI API.
+///
+/// # Errors
+/// Returns an error if no API key is set, the request fails, or the response
+/// carries no rate-limit headers.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    SPEC.fetch_usage().await
+}
@@ -0,0 +1,91 @@
+//! Editor state databases for VS Code based editors.
+//!
+//! Cursor and Windsurf keep their sign-in state in VS Code's SQLite key/value
+//! store, `<App>/User/globalStorage/state.vscdb` under the platform config
+//! directory. Values are opened read-only so a running editor is unaffected.
+
+use std::path::{Path, PathBuf};
+
+use rusqlite::{Connection, OpenFlags};
+
+use crate::error::{CautError, Result};
+
+/// State database for `app` (e.g. `Cursor`), honoring an override variable.
+#[must_use]
+pub fn state_db_path(env_var: &str, app: &str) -> Option<PathBuf> {
+    if let Ok(path) = std::env::var(env_var)
+        && !path.trim().is_empty()
+    {
+        return Some(PathBuf::from(path.trim()));
+    }
+    directories::BaseDirs::new().map(|d| {
+        d.config_dir()
+            .join(app)
+            .join("User/globalStorage/state.vscdb")
+    })
+}
+
+/// Read-only handle on an editor state database.
+pub struct StateDb {
+    conn: Connection,
+}
+
+impl StateDb {
+    /// Open the database at `path`; `editor` names it in errors.
+    ///
+    /// # Errors
+    /// Returns an error if the database cannot be opened.
+    pub fn open(path: &Path, editor: &str) -> Result<Self> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .map_err(|e| CautError::Config(format!("Cannot open {editor} state database: {e}")))?;
+        Ok(Self { conn })
+    }
+
+    /// Value stored under `key`, without surrounding quotes; `None` if unset
+    /// or empty.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.conn
+            .query_row(
+                "SELECT CAST(value AS TEXT) FROM ItemTable WHERE key = ?1",
+                [key],
+                |row| row.get::<_, String>(0),
+            )
+            .ok()
+            .map(|value| value.trim().trim_matches('"').to_string())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_trimmed_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.vscdb");
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE ItemTable (key TEXT UNIQUE, value BLOB);
+             INSERT INTO ItemTable VALUES ('quoted', '\"abc\"'), ('empty', '');",
+        )
+        .unwrap();
+        drop(conn);
+
+        let db = StateDb::open(&path, "Test").unwrap();
+        assert_eq!(db.get("quoted").as_deref(), Some("abc"));
+        assert_eq!(db.get("empty"), None);
+        assert_eq!(db.get("missing"), None);
+    }
+
+    #[test]
+    fn missing_database_is_a_config_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = StateDb::open(&dir.path().join("missing/state.vscdb"), "Test").unwrap_err();
+        assert!(err.to_string().contains("Cannot open Test state database"));
+    }
+}
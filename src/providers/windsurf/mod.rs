@@ -0,0 +1,408 @@
+//! Windsurf (Codeium) provider implementation.
+//!
+//! Supports:
+//! - Codeium seat management API (prompt and flow action credits)
+//!
+//! Source label: `api`
+//!
+//! Windsurf stores the signed-in account's API key in its VS Code style state
+//! database (`Windsurf/User/globalStorage/state.vscdb` under the platform
+//! config directory, key `windsurfAuthStatus`). caut sends it to the same
+//! user status endpoint the editor polls. Plans grant a monthly allowance of
+//! prompt credits (one per user message) and flow action credits (one per
+//! tool call); both are shown as windows that reset at the end of the plan
+//! period. Remaining prompt credits plus any purchased flex credits are
+//! reported as the credit balance.
+//!
+//! Set `CAUT_WINDSURF_STATE_DB` to read a different state database.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer};
+
+use crate::core::clock_skew;
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::fetcher::{EnrichFuture, FetchFuture, ProviderFetcher, unknown_strategy};
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::core::models::{
+    CreditsSnapshot, ProviderIdentity, ProviderPayload, RateWindow, UsageSnapshot,
+};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::providers::vscode_state::{self, StateDb};
+
+/// User status endpoint (Connect protocol, JSON encoding).
+const USER_STATUS_URL: &str =
+    "https://server.codeium.com/exa.seat_management_pb.SeatManagementService/GetUserStatus";
+
+/// Environment variable overriding the state database path.
+pub const STATE_DB_ENV: &str = "CAUT_WINDSURF_STATE_DB";
+
+/// Application directory under the platform config directory.
+const APP_DIR: &str = "Windsurf";
+
+/// State key holding the signed-in account, as JSON.
+const AUTH_STATUS_KEY: &str = "windsurfAuthStatus";
+
+/// The API reports credits in hundredths.
+const CREDIT_SCALE: f64 = 100.0;
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for Windsurf.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Windsurf,
+        vec![FetchStrategy {
+            id: "windsurf-api",
+            kind: FetchKind::ApiToken,
+            is_available: || state_db_path().is_some_and(|path| path.exists()),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// Local Credentials
+// =============================================================================
+
+/// Sign-in state written by the editor.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AuthStatus {
+    api_key: String,
+}
+
+fn state_db_path() -> Option<PathBuf> {
+    vscode_state::state_db_path(STATE_DB_ENV, APP_DIR)
+}
+
+fn read_api_key(path: &Path) -> Result<String> {
+    let db = StateDb::open(path, "Windsurf")?;
+    let not_signed_in =
+        || CautError::Config("Not signed in to Windsurf (open Windsurf and sign in)".to_string());
+    let raw = db.get(AUTH_STATUS_KEY).ok_or_else(not_signed_in)?;
+    let status: AuthStatus = serde_json::from_str(&raw)
+        .map_err(|e| CautError::ParseResponse(format!("Invalid Windsurf auth status: {e}")))?;
+    let key = status.api_key.trim();
+    if key.is_empty() {
+        return Err(not_signed_in());
+    }
+    Ok(key.to_string())
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserStatusResponse {
+    user_status: UserStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserStatus {
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    plan_status: Option<PlanStatus>,
+}
+
+/// Credit counts are hundredths of a credit; proto3 JSON may send them as
+/// numbers or strings.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PlanStatus {
+    #[serde(default)]
+    plan_end: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "credit_count")]
+    available_prompt_credits: i64,
+    #[serde(default, deserialize_with = "credit_count")]
+    used_prompt_credits: i64,
+    #[serde(default, deserialize_with = "credit_count")]
+    available_flow_credits: i64,
+    #[serde(default, deserialize_with = "credit_count")]
+    used_flow_credits: i64,
+    #[serde(default, deserialize_with = "credit_count")]
+    available_flex_credits: i64,
+    #[serde(default, deserialize_with = "credit_count")]
+    used_flex_credits: i64,
+}
+
+fn credit_count<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<i64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Count {
+        Number(i64),
+        Text(String),
+    }
+    match Count::deserialize(deserializer)? {
+        Count::Number(n) => Ok(n),
+        Count::Text(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+async fn fetch_status() -> Result<UserStatus> {
+    let path = state_db_path().ok_or_else(|| {
+        CautError::Config("Cannot determine the Windsurf config directory".to_string())
+    })?;
+    let api_key = read_api_key(&path)?;
+
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let response = client
+        .post(USER_STATUS_URL)
+        .json(&serde_json::json!({
+            "metadata": {
+                "apiKey": api_key,
+                "ideName": "windsurf",
+                "extensionName": "windsurf",
+                "locale": "en",
+            }
+        }))
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+            } else {
+                CautError::Network(e.to_string())
+            }
+        })?;
+    clock_skew::observe(&response);
+
+    let status = response.status();
+    if !status.is_success() {
+        let reason = if matches!(status.as_u16(), 401 | 403) {
+            "API key rejected; open Windsurf to sign in again".to_string()
+        } else {
+            format!("user status request returned {status}")
+        };
+        return Err(CautError::FetchFailed {
+            provider: Provider::Windsurf.cli_name().to_string(),
+            reason,
+        });
+    }
+
+    let body: UserStatusResponse = response
+        .json()
+        .await
+        .map_err(|e| CautError::ParseResponse(format!("Invalid Windsurf user status: {e}")))?;
+    Ok(body.user_status)
+}
+
+/// Fetch usage from the Codeium user status API.
+///
+/// # Errors
+/// Returns an error if Windsurf is not signed in, the request fails, or the
+/// response cannot be parsed.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    Ok(usage_from_status(fetch_status().await?))
+}
+
+/// Fetch remaining prompt and flex credits.
+///
+/// # Errors
+/// Returns an error if Windsurf is not signed in, the request fails, or the
+/// response cannot be parsed.
+pub async fn fetch_credits() -> Result<CreditsSnapshot> {
+    Ok(credits_from_status(&fetch_status().await?))
+}
+
+#[allow(clippy::cast_precision_loss)] // credit counts are small
+fn credit_window(
+    used: i64,
+    available: i64,
+    label: &str,
+    resets_at: Option<DateTime<Utc>>,
+) -> Option<RateWindow> {
+    if available <= 0 {
+        return None;
+    }
+    Some(RateWindow {
+        used_percent: (used as f64 / available as f64 * 100.0).clamp(0.0, 100.0),
+        window_minutes: None,
+        resets_at,
+        reset_description: Some(format!(
+            "{}/{} {label}",
+            used as f64 / CREDIT_SCALE,
+            available as f64 / CREDIT_SCALE
+        )),
+    })
+}
+
+fn usage_from_status(status: UserStatus) -> UsageSnapshot {
+    let plan = status.plan_status.unwrap_or_default();
+    UsageSnapshot {
+        primary: credit_window(
+            plan.used_prompt_credits,
+            plan.available_prompt_credits,
+            "prompt credits",
+            plan.plan_end,
+        ),
+        secondary: credit_window(
+            plan.used_flow_credits,
+            plan.available_flow_credits,
+            "flow action credits",
+            plan.plan_end,
+        ),
+        tertiary: None,
+        updated_at: Utc::now(),
+        identity: Some(ProviderIdentity {
+            account_email: status.email,
+            account_organization: None,
+            login_method: None,
+        }),
+    }
+}
+
+#[allow(clippy::cast_precision_loss)] // credit counts are small
+fn credits_from_status(status: &UserStatus) -> CreditsSnapshot {
+    let remaining = status.plan_status.as_ref().map_or(0, |plan| {
+        (plan.available_prompt_credits - plan.used_prompt_credits).max(0)
+            + (plan.available_flex_credits - plan.used_flex_credits).max(0)
+    });
+    CreditsSnapshot {
+        remaining: remaining as f64 / CREDIT_SCALE,
+        events: Vec::new(),
+        updated_at: Utc::now(),
+    }
+}
+
+// =============================================================================
+// Fetcher
+// =============================================================================
+
+/// Registry entry for Windsurf.
+pub struct WindsurfFetcher;
+
+impl ProviderFetcher for WindsurfFetcher {
+    fn provider(&self) -> Provider {
+        Provider::Windsurf
+    }
+
+    fn fetch_plan(&self) -> FetchPlan {
+        fetch_plan()
+    }
+
+    fn fetch_usage<'a>(&'a self, strategy_id: &'a str) -> FetchFuture<'a, UsageSnapshot> {
+        Box::pin(async move {
+            match strategy_id {
+                "windsurf-api" => fetch_api().await,
+                _ => Err(unknown_strategy(Provider::Windsurf, strategy_id)),
+            }
+        })
+    }
+
+    /// Attach the remaining prompt credits.
+    fn enrich<'a>(&'a self, payload: &'a mut ProviderPayload) -> EnrichFuture<'a> {
+        Box::pin(async move {
+            payload.credits = fetch_credits()
+                .await
+                .map_err(|e| tracing::warn!("Failed to fetch Windsurf credits: {}", e))
+                .ok();
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rusqlite::Connection;
+
+    fn status() -> UserStatus {
+        let body: UserStatusResponse = serde_json::from_str(
+            r#"{
+                "userStatus": {
+                    "email": "dev@example.com",
+                    "planStatus": {
+                        "planInfo": {"planName": "Pro"},
+                        "planStart": "2026-10-01T00:00:00Z",
+                        "planEnd": "2026-11-01T00:00:00Z",
+                        "availablePromptCredits": 50000,
+                        "usedPromptCredits": "12500",
+                        "availableFlowCredits": 150000,
+                        "usedFlowCredits": 3000,
+                        "availableFlexCredits": "2000"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        body.user_status
+    }
+
+    #[test]
+    fn maps_prompt_and_flow_credits() {
+        let usage = usage_from_status(status());
+
+        let primary = usage.primary.unwrap();
+        assert!((primary.used_percent - 25.0).abs() < f64::EPSILON);
+        assert_eq!(
+            primary.resets_at,
+            Some(Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            primary.reset_description.as_deref(),
+            Some("125/500 prompt credits")
+        );
+        let secondary = usage.secondary.unwrap();
+        assert!((secondary.used_percent - 2.0).abs() < f64::EPSILON);
+        assert_eq!(
+            usage.identity.unwrap().account_email.as_deref(),
+            Some("dev@example.com")
+        );
+    }
+
+    #[test]
+    fn remaining_credits_include_flex() {
+        let credits = credits_from_status(&status());
+        assert!((credits.remaining - 395.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn plan_without_allowance_has_no_windows() {
+        let usage = usage_from_status(UserStatus {
+            email: None,
+            plan_status: None,
+        });
+        assert!(usage.primary.is_none());
+        assert!(usage.secondary.is_none());
+    }
+
+    #[test]
+    fn reads_api_key_from_state_db() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.vscdb");
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch("CREATE TABLE ItemTable (key TEXT UNIQUE, value BLOB);")
+            .unwrap();
+        conn.execute(
+            "INSERT INTO ItemTable VALUES (?1, ?2)",
+            [AUTH_STATUS_KEY, r#"{"name":"Dev","apiKey":"sk-ws-01abc"}"#],
+        )
+        .unwrap();
+        drop(conn);
+
+        assert_eq!(read_api_key(&path).unwrap(), "sk-ws-01abc");
+    }
+
+    #[test]
+    fn missing_auth_status_is_not_signed_in() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.vscdb");
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch("CREATE TABLE ItemTable (key TEXT UNIQUE, value BLOB);")
+            .unwrap();
+
+        let err = read_api_key(&path).unwrap_err();
+        assert!(err.to_string().contains("Not signed in"));
+    }
+}
@@ -0,0 +1,190 @@
+//! xAI Grok provider implementation.
+//!
+//! Supports:
+//! - xAI API (rate-limit headers, API key details)
+//!
+//! Source label: `api`
+//!
+//! The xAI API reports the key's limits as OpenAI-style headers on every
+//! response. Window mapping: primary = tokens per minute, secondary =
+//! requests per minute. `/v1/api-key` describes the key itself: its name is
+//! shown as the login method, and a key that xAI has blocked or disabled (or
+//! whose team is blocked) fails the fetch rather than reporting idle limits.
+//!
+//! Key resolution: `--api-key`, `XAI_API_KEY`, keyring, then config (see
+//! [`crate::providers::api_key`]).
+
+use serde::Deserialize;
+
+use crate::core::fetch_plan::{FetchKind, FetchPlan, FetchStrategy};
+use crate::core::models::{ProviderIdentity, UsageSnapshot};
+use crate::core::provider::Provider;
+use crate::error::{CautError, Result};
+use crate::providers::api_key::{ApiKeyProvider, KeySource};
+use crate::providers::rate_limits::HeaderLimit;
+
+/// Environment variable holding the xAI API key.
+pub const API_KEY_ENV: &str = "XAI_API_KEY";
+
+/// Details of the key making the request.
+const API_KEY_URL: &str = "https://api.x.ai/v1/api-key";
+
+const TOKENS_PER_MINUTE: HeaderLimit = HeaderLimit {
+    limit: "x-ratelimit-limit-tokens",
+    remaining: "x-ratelimit-remaining-tokens",
+    reset: "x-ratelimit-reset-tokens",
+    window_minutes: Some(1),
+    unit: "tokens/min",
+};
+
+const REQUESTS_PER_MINUTE: HeaderLimit = HeaderLimit {
+    limit: "x-ratelimit-limit-requests",
+    remaining: "x-ratelimit-remaining-requests",
+    reset: "x-ratelimit-reset-requests",
+    window_minutes: Some(1),
+    unit: "req/min",
+};
+
+const SPEC: ApiKeyProvider = ApiKeyProvider::new(Provider::Xai)
+    .env(API_KEY_ENV)
+    .probe("https://api.x.ai/v1/models")
+    .primary(TOKENS_PER_MINUTE)
+    .secondary(REQUESTS_PER_MINUTE);
+
+/// Where the xAI API key would be read from, if one is configured.
+#[must_use]
+pub fn key_source() -> Option<KeySource> {
+    SPEC.resolve_key().map(|(_, source)| source)
+}
+
+// =============================================================================
+// Fetch Plan
+// =============================================================================
+
+/// Create fetch plan for xAI Grok.
+#[must_use]
+pub fn fetch_plan() -> FetchPlan {
+    FetchPlan::new(
+        Provider::Xai,
+        vec![FetchStrategy {
+            id: "xai-api",
+            kind: FetchKind::ApiToken,
+            is_available: || SPEC.api_key().is_some(),
+            should_fallback: |_| false,
+        }],
+    )
+}
+
+// =============================================================================
+// API Types
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+struct ApiKeyInfo {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    api_key_blocked: bool,
+    #[serde(default)]
+    api_key_disabled: bool,
+    #[serde(default)]
+    team_blocked: bool,
+}
+
+impl ApiKeyInfo {
+    /// Why xAI will refuse requests with this key, if it will.
+    fn refusal(&self) -> Option<&'static str> {
+        if self.team_blocked {
+            Some("team is blocked")
+        } else if self.api_key_blocked {
+            Some("API key is blocked")
+        } else if self.api_key_disabled {
+            Some("API key is disabled")
+        } else {
+            None
+        }
+    }
+
+    fn login_method(&self) -> String {
+        self.name
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map_or_else(|| "api-key".to_string(), |name| format!("api-key, {name}"))
+    }
+}
+
+/// Fetch rate-limit usage and key details from the xAI API.
+///
+/// # Errors
+/// Returns an error if no API key is set, a request fails, the key or its
+/// team is blocked, or the response carries no rate-limit headers.
+pub async fn fetch_api() -> Result<UsageSnapshot> {
+    let info: ApiKeyInfo = SPEC.get_json(API_KEY_URL).await?;
+    if let Some(reason) = info.refusal() {
+        return Err(CautError::FetchFailed {
+            provider: Provider::Xai.cli_name().to_string(),
+            reason: reason.to_string(),
+        });
+    }
+
+    let mut usage = SPEC.fetch_usage().await?;
+    usage.identity = Some(ProviderIdentity {
+        account_email: None,
+        account_organization: None,
+        login_method: Some(info.login_method()),
+    });
+    Ok(usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    fn info(json: &str) -> ApiKeyInfo {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn maps_token_and_request_headers() {
+        let mut headers = HeaderMap::new();
+        for (name, value) in [
+            ("x-ratelimit-limit-tokens", "100000"),
+            ("x-ratelimit-remaining-tokens", "75000"),
+            ("x-ratelimit-limit-requests", "60"),
+            ("x-ratelimit-remaining-requests", "54"),
+        ] {
+            headers.insert(name, HeaderValue::from_static(value));
+        }
+
+        let usage = SPEC.usage_from_headers(&headers).unwrap();
+        let tokens = usage.primary.unwrap();
+        assert!((tokens.used_percent - 25.0).abs() < f64::EPSILON);
+        assert_eq!(tokens.window_minutes, Some(1));
+        assert!((usage.secondary.unwrap().used_percent - 10.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn key_details_name_the_login_method() {
+        let key = info(
+            r#"{"redacted_api_key": "xai-...abcd", "name": "laptop", "team_id": "t-1",
+                "api_key_blocked": false, "api_key_disabled": false, "team_blocked": false}"#,
+        );
+        assert!(key.refusal().is_none());
+        assert_eq!(key.login_method(), "api-key, laptop");
+        assert_eq!(info("{}").login_method(), "api-key");
+    }
+
+    #[test]
+    fn blocked_keys_and_teams_are_refused() {
+        assert_eq!(
+            info(r#"{"api_key_disabled": true}"#).refusal(),
+            Some("API key is disabled")
+        );
+        assert_eq!(
+            info(r#"{"api_key_blocked": true, "team_blocked": true}"#).refusal(),
+            Some("team is blocked")
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! Terminal recordings in asciinema's cast v2 format.
+//!
+//! A cast file is a JSON header line followed by one JSON array per output
+//! event: `[seconds_since_start, "o", text]`. Recordings written here play
+//! in `asciinema play` and the asciinema web player, and `caut usage
+//! --replay` reads them back.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::time::Instant;
+
+use serde_json::json;
+
+use crate::error::{CautError, Result};
+
+/// Clears the screen and homes the cursor before each recorded frame.
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+/// Writes frames to a cast file as they are rendered.
+pub struct CastWriter {
+    out: BufWriter<File>,
+    started: Instant,
+}
+
+impl CastWriter {
+    /// Create a cast file and write its header.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be created or written.
+    pub fn create(path: &Path, width: u16, height: u16, title: &str) -> Result<Self> {
+        let mut out = BufWriter::new(File::create(path)?);
+        let header = json!({
+            "version": 2,
+            "width": width,
+            "height": height,
+            "timestamp": chrono::Utc::now().timestamp(),
+            "title": title,
+        });
+        writeln!(out, "{header}")?;
+        out.flush()?;
+        Ok(Self {
+            out,
+            started: Instant::now(),
+        })
+    }
+
+    /// Append one full-screen frame.
+    ///
+    /// Line feeds become CRLF, as a terminal in raw mode would receive them.
+    ///
+    /// # Errors
+    /// Returns an error if the event cannot be written.
+    pub fn frame(&mut self, text: &str) -> Result<()> {
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let data = format!("{CLEAR_SCREEN}{}\r\n", text.replace('\n', "\r\n"));
+        writeln!(self.out, "{}", json!([elapsed, "o", data]))?;
+        // Flush per frame so an interrupted watch still leaves a usable file.
+        self.out.flush()?;
+        Ok(())
+    }
+}
+
+/// One output event from a cast file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CastEvent {
+    /// Seconds since the recording started.
+    pub time: f64,
+    /// Terminal output, escape sequences included.
+    pub data: String,
+}
+
+/// Read the output events of a cast v2 file, in order.
+///
+/// Input and marker events are skipped.
+///
+/// # Errors
+/// Returns an error if the file cannot be read, the header is not a cast v2
+/// header, or an event line is malformed.
+pub fn read_cast(path: &Path) -> Result<Vec<CastEvent>> {
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    let header: serde_json::Value = match lines.next() {
+        Some(line) => serde_json::from_str(&line?)?,
+        None => {
+            return Err(CautError::Config(format!(
+                "{} is empty, not a cast file",
+                path.display()
+            )));
+        }
+    };
+    if header.get("version").and_then(serde_json::Value::as_u64) != Some(2) {
+        return Err(CautError::Config(format!(
+            "{} is not an asciinema v2 cast file",
+            path.display()
+        )));
+    }
+
+    let mut events = Vec::new();
+    for (index, line) in lines.enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (time, kind, data): (f64, String, String) =
+            serde_json::from_str(&line).map_err(|e| {
+                CautError::Config(format!(
+                    "{}:{}: malformed cast event: {e}",
+                    path.display(),
+                    index + 2
+                ))
+            })?;
+        if kind == "o" {
+            events.push(CastEvent { time, data });
+        }
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_frames_read_back() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("session.cast");
+        let mut writer = CastWriter::create(&path, 100, 30, "caut usage").expect("create");
+        writer.frame("claude 42%\ncodex 7%").expect("frame 1");
+        writer.frame("claude 43%").expect("frame 2");
+        drop(writer);
+
+        let events = read_cast(&path).expect("read");
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].data, "\x1b[H\x1b[2Jclaude 42%\r\ncodex 7%\r\n");
+        assert!(events[0].time <= events[1].time);
+
+        std::fs::write(&path, "{\"version\":1}\n").expect("write");
+        assert!(read_cast(&path).is_err());
+    }
+}
@@ -100,6 +100,26 @@ fn render_installation(report: &DoctorReport, no_color: bool) -> String {
     output.push_str(&render_check_line(&report.config_status, "  ", no_color));
     output.push('\n');
 
+    if let Some(plan_limits) = &report.plan_limits {
+        output.push_str(&render_check_line(plan_limits, "  ", no_color));
+        output.push('\n');
+    }
+
+    if let Some(canary) = &report.canary {
+        output.push_str(&render_check_line(canary, "  ", no_color));
+        output.push('\n');
+    }
+
+    if let Some(file_permissions) = &report.file_permissions {
+        output.push_str(&render_check_line(file_permissions, "  ", no_color));
+        output.push('\n');
+    }
+
+    if let Some(clock_skew) = &report.clock_skew {
+        output.push_str(&render_check_line(clock_skew, "  ", no_color));
+        output.push('\n');
+    }
+
     output
 }
 
@@ -366,6 +386,30 @@ pub fn render_md(report: &DoctorReport) -> Result<String> {
         "- config: {}",
         format_check_status_md(&report.config_status)
     );
+    if let Some(plan_limits) = &report.plan_limits {
+        let _ = writeln!(
+            output,
+            "- plan limits: {}",
+            format_check_status_md(plan_limits)
+        );
+    }
+    if let Some(canary) = &report.canary {
+        let _ = writeln!(output, "- fetch canary: {}", format_check_status_md(canary));
+    }
+    if let Some(file_permissions) = &report.file_permissions {
+        let _ = writeln!(
+            output,
+            "- file permissions: {}",
+            format_check_status_md(file_permissions)
+        );
+    }
+    if let Some(clock_skew) = &report.clock_skew {
+        let _ = writeln!(
+            output,
+            "- system clock: {}",
+            format_check_status_md(clock_skew)
+        );
+    }
     output.push('\n');
 
     // Providers section
@@ -459,6 +503,7 @@ mod tests {
 
     fn make_test_report() -> DoctorReport {
         let ok_check = DiagnosticCheck::new(
+            "test.ok",
             "Test check",
             CheckStatus::Pass {
                 details: Some("all good".to_string()),
@@ -467,6 +512,7 @@ mod tests {
         .with_duration(Duration::from_millis(42));
 
         let fail_check = DiagnosticCheck::new(
+            "test.auth",
             "Auth check",
             CheckStatus::Fail {
                 reason: "No credentials".to_string(),
@@ -497,6 +543,10 @@ mod tests {
             caut_git_sha: "a999778deadbeef".to_string(),
             config_status: ok_check,
             providers: vec![provider_ok, provider_fail],
+            plan_limits: None,
+            canary: None,
+            file_permissions: None,
+            clock_skew: None,
             total_duration: Duration::from_millis(1234),
         }
     }
@@ -39,11 +39,26 @@ pub fn render_error_full(
     format: OutputFormat,
     no_color: bool,
     pretty: bool,
+) -> String {
+    render_command_error(error, None, format, no_color, pretty)
+}
+
+/// Render the error that ended `command` (e.g. `history export`).
+///
+/// Like [`render_error_full`], but the JSON object also names the command,
+/// so an agent running several commands can tell which one failed.
+#[must_use]
+pub fn render_command_error(
+    error: &CautError,
+    command: Option<&str>,
+    format: OutputFormat,
+    no_color: bool,
+    pretty: bool,
 ) -> String {
     // JSON/Md formats get structured JSON error output for AI agents
     match format {
-        OutputFormat::Json => return render_error_json(error, pretty),
-        OutputFormat::Md => return render_error_json(error, true), // Md always pretty
+        OutputFormat::Json => return error_json(error, command, pretty),
+        OutputFormat::Md => return error_json(error, command, true), // Md always pretty
         OutputFormat::Human => {}
     }
 
@@ -62,7 +77,12 @@ pub fn render_error_full(
 /// Render error as structured JSON for machine consumption.
 #[must_use]
 pub fn render_error_json(error: &CautError, pretty: bool) -> String {
-    let error_json = ErrorJson::from_error(error);
+    error_json(error, None, pretty)
+}
+
+fn error_json(error: &CautError, command: Option<&str>, pretty: bool) -> String {
+    let mut error_json = ErrorJson::from_error(error);
+    error_json.command = command.filter(|c| !c.is_empty()).map(String::from);
     if pretty {
         serde_json::to_string_pretty(&error_json).unwrap_or_else(|_| render_simple(error))
     } else {
@@ -257,9 +277,12 @@ fn render_simple(error: &CautError) -> String {
 /// JSON representation of an error for machine consumption.
 #[derive(serde::Serialize)]
 struct ErrorJson {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    command: Option<String>,
     error_code: String,
     category: String,
     message: String,
+    exit_code: u8,
     is_retryable: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     provider: Option<String>,
@@ -284,9 +307,11 @@ impl ErrorJson {
         let suggestions = error.fix_suggestions();
 
         Self {
+            command: None,
             error_code: error.error_code().to_string(),
             category: error.category().to_string(),
             message: error.to_string(),
+            exit_code: error.exit_code() as u8,
             is_retryable: error.is_retryable(),
             provider: error.provider().map(String::from),
             retry_after_seconds: error.retry_after().map(|d| d.as_secs()),
@@ -457,6 +482,27 @@ mod tests {
         assert!(parsed["suggestions"].is_array());
     }
 
+    #[test]
+    fn json_render_names_failed_command() {
+        let err = CautError::Config("Unknown source mode".to_string());
+        let output = render_command_error(
+            &err,
+            Some("history export"),
+            OutputFormat::Json,
+            false,
+            false,
+        );
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(parsed["command"], "history export");
+        assert_eq!(parsed["exit_code"], 3);
+        assert!(
+            serde_json::from_str::<serde_json::Value>(&render_error_json(&err, false)).unwrap()
+                ["command"]
+                .is_null()
+        );
+    }
+
     #[test]
     fn json_render_omits_null_fields() {
         let err = CautError::Timeout(30);
@@ -2,14 +2,56 @@
 //!
 //! Renders usage and cost data with styled panels, tables, and progress bars.
 
-use crate::core::models::{CostPayload, ProviderPayload, RateWindow, StatusIndicator};
+use crate::cli::args::UsageColumn;
+use crate::core::budgets::BudgetStatus;
+use crate::core::explain::explain_payload;
+use crate::core::models::{
+    CopilotPremiumProjection, CostPayload, LocalModelStats, PremiumRequestStatus, ProviderPayload,
+    RateWindow, StatusIndicator,
+};
+use crate::core::provider::Provider;
+use crate::core::summary::{SummaryStatus, UsageSummary};
 use crate::error::Result;
+use crate::util::format::{
+    display_width, format_cost, hyperlink, truncate_middle, truncate_to_width,
+};
+use crate::util::time::humanize_reset;
 use rich_rust::prelude::*;
 use rich_rust::{Color, ColorSystem, Segment, Style};
 use std::fmt::Write;
 use std::time::Instant;
 use tracing::Level;
 
+/// Total width of a usage panel, including borders.
+const USAGE_PANEL_WIDTH: usize = 60;
+
+/// Columns available for text inside a usage panel (borders plus padding).
+const USAGE_PANEL_CONTENT_WIDTH: usize = USAGE_PANEL_WIDTH - 4;
+
+/// Label shown before the account email in usage panels.
+const ACCOUNT_LABEL: &str = "Account: ";
+
+/// Label shown before the dashboard link in usage panels.
+const DASHBOARD_LABEL: &str = "Dashboard: ";
+
+/// Rendering options for usage panels.
+#[derive(Debug, Clone, Default)]
+pub struct UsageRenderOptions {
+    pub no_color: bool,
+    /// Show long values in full instead of truncating them.
+    pub full: bool,
+    /// Add the provider's dashboard URL to each panel.
+    pub links: bool,
+    /// Emit dashboard links as OSC 8 hyperlinks.
+    pub hyperlinks: bool,
+    /// Follow each panel with how its numbers were computed.
+    pub explain: bool,
+    /// Show a table of these columns instead of panels.
+    pub columns: Option<Vec<UsageColumn>>,
+    /// Print the table as CSV, whatever the output format.
+    pub csv: bool,
+}
+
 /// Convert segments to a styled string with ANSI codes.
 fn segments_to_string(segments: &[Segment], no_color: bool) -> String {
     let color_system = if no_color {
@@ -48,19 +90,130 @@ fn percentage_color(percent: f64) -> Color {
 /// # Errors
 /// Returns an error if rendering fails (infallible in practice).
 pub fn render_usage(results: &[ProviderPayload], no_color: bool) -> Result<String> {
+    render_usage_with_options(
+        results,
+        &UsageRenderOptions {
+            no_color,
+            ..UsageRenderOptions::default()
+        },
+    )
+}
+
+/// Render usage results with explicit options (truncation, dashboard links).
+///
+/// # Errors
+/// Returns an error if rendering fails (infallible in practice).
+pub fn render_usage_with_options(
+    results: &[ProviderPayload],
+    options: &UsageRenderOptions,
+) -> Result<String> {
     let _theme = crate::rich::get_theme();
     let mut output = String::new();
 
     for payload in results {
-        output.push_str(&render_provider_usage(payload, no_color));
+        output.push_str(&render_provider_usage(payload, options));
         output.push('\n');
+        if options.explain {
+            for line in explain_payload(payload) {
+                let _ = writeln!(output, "  {line}");
+            }
+            output.push('\n');
+        }
     }
 
     Ok(output)
 }
 
+/// Render the all-providers summary that closes multi-provider output.
+#[must_use]
+pub fn render_usage_summary(summary: &UsageSummary, no_color: bool) -> String {
+    let mut content_lines: Vec<Vec<Segment>> = Vec::new();
+
+    let spend = match (summary.cost_today_usd, summary.cost_month_usd) {
+        (Some(today), Some(month)) => Some(format!(
+            "{} today, {} this month",
+            format_cost(today),
+            format_cost(month)
+        )),
+        (Some(today), None) => Some(format!("{} today", format_cost(today))),
+        (None, Some(month)) => Some(format!("{} this month", format_cost(month))),
+        (None, None) => None,
+    };
+    if let Some(spend) = spend {
+        content_lines.push(vec![
+            Segment::styled("Spend: ", Style::new().bold()),
+            Segment::plain(spend),
+        ]);
+    }
+
+    if let Some(window) = &summary.most_constrained {
+        let remaining = (100.0 - window.used_percent).max(0.0);
+        let style = if no_color {
+            Style::new()
+        } else {
+            Style::new().color(percentage_color(remaining))
+        };
+        content_lines.push(vec![
+            Segment::styled("Tightest: ", Style::new().bold()),
+            Segment::plain(format!("{} {} ", window.provider, window.window)),
+            Segment::styled(format!("{remaining:.0}% left"), style),
+        ]);
+    }
+
+    if let Some(reset) = &summary.next_reset {
+        content_lines.push(vec![
+            Segment::styled("Next reset: ", Style::new().bold()),
+            Segment::plain(format!(
+                "{} {} {}",
+                reset.provider,
+                reset.window,
+                humanize_reset(reset.resets_at)
+            )),
+        ]);
+    }
+
+    let color = match summary.status {
+        SummaryStatus::Ok => "green",
+        SummaryStatus::Warning => "yellow",
+        SummaryStatus::Critical => "red",
+    };
+    let style = if no_color {
+        Style::new()
+    } else {
+        Style::new().color(Color::parse(color).unwrap())
+    };
+    let mut status_line = vec![
+        Segment::styled("Status: ", Style::new().bold()),
+        Segment::styled(summary.status.label(), style),
+    ];
+    if summary.failed > 0 {
+        status_line.push(Segment::plain(format!(
+            " ({} of {} providers failed)",
+            summary.failed,
+            summary.providers + summary.failed
+        )));
+    }
+    content_lines.push(status_line);
+
+    let title = if no_color {
+        Text::new("All providers")
+    } else {
+        Text::styled(
+            "All providers",
+            Style::new().bold().color(Color::parse("cyan").unwrap()),
+        )
+    };
+    let mut panel = Panel::new(content_lines).title(title).padding((0, 1));
+    if !no_color {
+        panel = panel.border_style(Style::new().color(Color::parse("blue").unwrap()));
+    }
+
+    segments_to_string(&panel.render(USAGE_PANEL_WIDTH), no_color)
+}
+
 /// Render a single provider's usage.
-fn render_provider_usage(payload: &ProviderPayload, no_color: bool) -> String {
+fn render_provider_usage(payload: &ProviderPayload, options: &UsageRenderOptions) -> String {
+    let no_color = options.no_color;
     let start = if tracing::enabled!(Level::DEBUG) {
         Some(Instant::now())
     } else {
@@ -116,11 +269,49 @@ fn render_provider_usage(payload: &ProviderPayload, no_color: bool) -> String {
         ))]);
     }
 
+    // Copilot premium-request projection
+    if let Some(premium) = &payload.copilot_premium {
+        content_lines.extend(format_copilot_premium_segments(premium, no_color));
+    }
+
+    // Locally recorded task spend
+    if let Some(spend) = &payload.task_spend {
+        content_lines.push(vec![Segment::plain(format!(
+            "Spend: {} today ({} tasks), {} last 30 days",
+            format_cost(spend.today_usd),
+            spend.today_tasks,
+            format_cost(spend.last_30_days_usd)
+        ))]);
+    }
+
+    // Local model throughput (cost is always zero)
+    if let Some(local) = &payload.local_model {
+        content_lines.push(vec![Segment::plain(format_local_model(local))]);
+    }
+
     // Identity
     if let Some(identity) = &payload.usage.identity
         && let Some(email) = &identity.account_email
     {
-        content_lines.push(vec![Segment::plain(format!("Account: {email}"))]);
+        let email = if options.full {
+            email.clone()
+        } else {
+            truncate_middle(
+                email,
+                USAGE_PANEL_CONTENT_WIDTH.saturating_sub(display_width(ACCOUNT_LABEL)),
+            )
+        };
+        content_lines.push(vec![Segment::plain(format!("{ACCOUNT_LABEL}{email}"))]);
+    }
+
+    // Dashboard link
+    let dashboard = if options.links {
+        dashboard_link(&payload.provider)
+    } else {
+        None
+    };
+    if let Some((_, label)) = &dashboard {
+        content_lines.push(vec![Segment::plain(format!("{DASHBOARD_LABEL}{label}"))]);
     }
 
     // Status
@@ -137,6 +328,14 @@ fn render_provider_usage(payload: &ProviderPayload, no_color: bool) -> String {
         content_lines.push(format_auth_warning_segments(warning, no_color));
     }
 
+    // Sources that disagreed under --verify
+    for divergence in &payload.divergences {
+        content_lines.push(format_auth_warning_segments(
+            &divergence.message(),
+            no_color,
+        ));
+    }
+
     // Fallback if no data
     if content_lines.is_empty() {
         let style = if no_color {
@@ -149,7 +348,10 @@ fn render_provider_usage(payload: &ProviderPayload, no_color: bool) -> String {
 
     // Build panel title with styling
     let version = payload.version.as_deref().unwrap_or("");
-    let title_text = format!("{} {} ({})", payload.provider, version, payload.source);
+    let title_text = truncate_to_width(
+        &format!("{} {} ({})", payload.provider, version, payload.source),
+        USAGE_PANEL_CONTENT_WIDTH,
+    );
     let title = if no_color {
         Text::new(&title_text)
     } else {
@@ -164,8 +366,21 @@ fn render_provider_usage(payload: &ProviderPayload, no_color: bool) -> String {
         panel = panel.border_style(Style::new().color(Color::parse("blue").unwrap()));
     }
 
-    let segments = panel.render(60);
-    let rendered = segments_to_string(&segments, no_color);
+    let segments = panel.render(USAGE_PANEL_WIDTH);
+    let mut rendered = segments_to_string(&segments, no_color);
+
+    // Wrap the link text after layout so the zero-width escapes don't skew
+    // the panel's width calculations.
+    if options.hyperlinks
+        && let Some((url, label)) = &dashboard
+    {
+        let target = format!("{DASHBOARD_LABEL}{label}");
+        rendered = rendered.replacen(
+            &target,
+            &format!("{DASHBOARD_LABEL}{}", hyperlink(url, label)),
+            1,
+        );
+    }
 
     if let Some(start) = start {
         tracing::debug!(
@@ -179,6 +394,17 @@ fn render_provider_usage(payload: &ProviderPayload, no_color: bool) -> String {
     rendered
 }
 
+/// Dashboard URL for a provider and the text shown for it in a panel.
+fn dashboard_link(provider: &str) -> Option<(&'static str, String)> {
+    let url = Provider::from_cli_name(provider).ok()?.dashboard_url()?;
+    let label = url.strip_prefix("https://").unwrap_or(url);
+    let label = truncate_to_width(
+        label,
+        USAGE_PANEL_CONTENT_WIDTH.saturating_sub(display_width(DASHBOARD_LABEL)),
+    );
+    Some((url, label))
+}
+
 /// Format rate window as styled segments with progress bar.
 fn format_rate_window_segments<'a>(
     label: &'a str,
@@ -187,9 +413,8 @@ fn format_rate_window_segments<'a>(
 ) -> Vec<Segment<'a>> {
     let remaining = window.remaining_percent();
     let reset = window
-        .reset_description
-        .as_deref()
-        .unwrap_or("unknown reset");
+        .reset_text()
+        .unwrap_or_else(|| "unknown reset".to_string());
 
     let mut segments = Vec::new();
 
@@ -285,6 +510,76 @@ fn format_auth_warning_segments(warning: &str, no_color: bool) -> Vec<Segment<'s
     segments
 }
 
+/// Throughput line for a local model server, e.g. "Throughput: 42.1 tok/s
+/// generation, 310.0 tok/s prompt (llama3.2) · $0.00 local".
+fn format_local_model(local: &LocalModelStats) -> String {
+    let rate = |label: &str, value: Option<f64>| value.map(|v| format!("{v:.1} tok/s {label}"));
+    let rates: Vec<String> = [
+        rate("generation", local.eval_tokens_per_sec),
+        rate("prompt", local.prompt_tokens_per_sec),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    let rates = if rates.is_empty() {
+        "not measured".to_string()
+    } else {
+        rates.join(", ")
+    };
+    format!(
+        "Throughput: {rates} ({}) · {} local",
+        local.model,
+        format_cost(0.0)
+    )
+}
+
+/// Format the Copilot premium-request section: usage line plus an overage
+/// warning when the projection crosses the allowance.
+fn format_copilot_premium_segments(
+    premium: &CopilotPremiumProjection,
+    no_color: bool,
+) -> Vec<Vec<Segment<'static>>> {
+    let reset = premium.resets_at.format("%b %-d");
+    let mut lines = vec![vec![Segment::plain(format!(
+        "Premium: {:.0}/{:.0} used · ~{:.0} by {reset}",
+        premium.used_requests, premium.allowance, premium.projected_requests
+    ))]];
+
+    let budget = premium
+        .budget_usd
+        .map(|b| format!(" (budget ${b:.2})"))
+        .unwrap_or_default();
+    let warning = match premium.status {
+        PremiumRequestStatus::OnTrack => None,
+        PremiumRequestStatus::AtRisk if premium.overage_allowed => Some(format!(
+            "Overage likely{}: ~${:.2}{budget}",
+            premium
+                .exhausted_on
+                .map(|d| format!(" from {}", d.format("%b %-d")))
+                .unwrap_or_default(),
+            premium.projected_overage_usd
+        )),
+        PremiumRequestStatus::AtRisk => Some(format!(
+            "Allowance likely to run out{}; overage is blocked by policy",
+            premium
+                .exhausted_on
+                .map(|d| format!(" on {}", d.format("%b %-d")))
+                .unwrap_or_default()
+        )),
+        PremiumRequestStatus::Overage => Some(format!(
+            "Over allowance: ~${:.2} overage by {reset}{budget}",
+            premium.projected_overage_usd
+        )),
+        PremiumRequestStatus::Blocked => Some(format!(
+            "Allowance used; policy blocks premium requests until {reset}"
+        )),
+    };
+    if let Some(warning) = warning {
+        lines.push(format_auth_warning_segments(&warning, no_color));
+    }
+    lines
+}
+
 /// Render cost results for human consumption.
 ///
 /// # Errors
@@ -469,6 +764,48 @@ pub fn render_history_chart(
     output
 }
 
+/// Width of the consumption bars in budget output.
+const BUDGET_BAR_WIDTH: usize = 20;
+
+/// Render spend against each budget as a consumption bar.
+#[must_use]
+pub fn render_budgets(statuses: &[BudgetStatus], no_color: bool) -> String {
+    let use_unicode = supports_unicode();
+    let mut output = String::new();
+    for status in statuses {
+        let label = format!(
+            "{:<10} {:<8}",
+            status.provider.cli_name(),
+            status.period.label()
+        );
+        let (Some(spent), Some(percent)) = (status.spent_usd, status.percent_used) else {
+            let _ = writeln!(
+                output,
+                "  {label} limit {} (no local cost data)",
+                format_cost(status.limit_usd)
+            );
+            continue;
+        };
+        let bar = render_bar(percent, BUDGET_BAR_WIDTH, no_color, use_unicode);
+        let over = if status.exceeded {
+            format!(
+                "  {}",
+                colorize_text("over budget", Color::parse("red").unwrap(), no_color)
+            )
+        } else {
+            String::new()
+        };
+        let _ = writeln!(
+            output,
+            "  {label} {bar} {} / {} {:>4.0}%{over}",
+            format_cost(spent),
+            format_cost(status.limit_usd),
+            percent
+        );
+    }
+    output
+}
+
 fn split_averages(values: &[f64]) -> (Option<f64>, Option<f64>) {
     if values.len() < 2 {
         return (None, None);
@@ -646,6 +983,13 @@ mod tests {
     };
     use crate::{assert_ansi_codes, assert_contains, assert_no_ansi_codes, assert_not_contains};
 
+    fn plain_options() -> UsageRenderOptions {
+        UsageRenderOptions {
+            no_color: true,
+            ..UsageRenderOptions::default()
+        }
+    }
+
     // =========================================================================
     // render_usage() Tests
     // =========================================================================
@@ -733,7 +1077,7 @@ mod tests {
         let mut payload = make_test_provider_payload("claude", "oauth");
         payload.usage = make_test_usage_snapshot_with_tertiary();
 
-        let result = render_provider_usage(&payload, false);
+        let result = render_provider_usage(&payload, &UsageRenderOptions::default());
 
         assert_contains!(&result, "Session");
         assert_contains!(&result, "Weekly");
@@ -747,7 +1091,7 @@ mod tests {
         payload.usage.secondary = None;
         payload.usage.tertiary = None;
 
-        let result = render_provider_usage(&payload, false);
+        let result = render_provider_usage(&payload, &UsageRenderOptions::default());
 
         assert_contains!(&result, "Session");
         assert_not_contains!(&result, "Weekly");
@@ -757,7 +1101,7 @@ mod tests {
     #[test]
     fn render_provider_usage_with_credits() {
         let payload = make_test_provider_payload("codex", "cli");
-        let result = render_provider_usage(&payload, false);
+        let result = render_provider_usage(&payload, &UsageRenderOptions::default());
 
         assert_contains!(&result, "Credits:");
     }
@@ -765,7 +1109,7 @@ mod tests {
     #[test]
     fn render_provider_usage_without_credits() {
         let payload = make_test_provider_payload("claude", "oauth");
-        let result = render_provider_usage(&payload, false);
+        let result = render_provider_usage(&payload, &UsageRenderOptions::default());
 
         assert_not_contains!(&result, "Credits:");
     }
@@ -773,12 +1117,46 @@ mod tests {
     #[test]
     fn render_provider_usage_with_account_identity() {
         let payload = make_test_provider_payload("claude", "oauth");
-        let result = render_provider_usage(&payload, false);
+        let result = render_provider_usage(&payload, &UsageRenderOptions::default());
 
         assert_contains!(&result, "Account:");
         assert_contains!(&result, "test@example.com");
     }
 
+    fn copilot_projection(status: PremiumRequestStatus) -> CopilotPremiumProjection {
+        CopilotPremiumProjection {
+            plan: "business".to_string(),
+            org: Some("acme".to_string()),
+            used_requests: 200.0,
+            allowance: 300.0,
+            projected_requests: 620.0,
+            overage_allowed: true,
+            budget_usd: Some(10.0),
+            projected_overage_usd: 10.0,
+            exhausted_on: chrono::NaiveDate::from_ymd_opt(2026, 1, 16),
+            resets_at: chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2026, 2, 1, 0, 0, 0)
+                .unwrap(),
+            status,
+        }
+    }
+
+    #[test]
+    fn render_provider_usage_copilot_premium_section() {
+        let mut payload = make_test_provider_payload("copilot", "api");
+        payload.copilot_premium = Some(copilot_projection(PremiumRequestStatus::AtRisk));
+
+        let result = render_provider_usage(&payload, &plain_options());
+        assert_contains!(&result, "Premium: 200/300 used · ~620 by Feb 1");
+        assert_contains!(
+            &result,
+            "Overage likely from Jan 16: ~$10.00 (budget $10.00)"
+        );
+
+        payload.copilot_premium = Some(copilot_projection(PremiumRequestStatus::OnTrack));
+        let result = render_provider_usage(&payload, &plain_options());
+        assert_not_contains!(&result, "Overage");
+    }
+
     #[test]
     fn render_provider_usage_empty_data() {
         let payload = make_test_provider_payload_minimal("empty", "test");
@@ -793,7 +1171,7 @@ mod tests {
             ..payload
         };
 
-        let result = render_provider_usage(&empty_payload, true);
+        let result = render_provider_usage(&empty_payload, &plain_options());
         assert_contains!(&result, "No usage data available");
     }
 
@@ -813,13 +1191,29 @@ mod tests {
     }
 
     #[test]
-    fn format_rate_window_shows_reset_description() {
+    fn format_rate_window_humanizes_reset_time() {
         let window = make_test_rate_window(30.0);
 
         let segments = format_rate_window_segments("Session", &window, true);
         let text: String = segments.iter().map(|s| s.text.clone()).collect();
 
-        assert_contains!(&text, "resets in");
+        // The countdown description gives way to the humanized reset time.
+        assert_contains!(
+            &text,
+            &crate::util::time::humanize_reset(window.resets_at.unwrap())
+        );
+        assert!(!text.contains("resets in 2h"));
+    }
+
+    #[test]
+    fn format_rate_window_keeps_allowance_beside_reset_time() {
+        let mut window = make_test_rate_window(30.0);
+        window.reset_description = Some("12/50 premium requests".to_string());
+
+        let segments = format_rate_window_segments("Session", &window, true);
+        let text: String = segments.iter().map(|s| s.text.clone()).collect();
+
+        assert_contains!(&text, "12/50 premium requests · resets ");
     }
 
     #[test]
@@ -1101,6 +1495,99 @@ mod tests {
     // No-Color Mode Tests
     // =========================================================================
 
+    // =========================================================================
+    // Wide Character Tests
+    // =========================================================================
+
+    #[test]
+    fn wide_account_email_is_truncated_to_panel() {
+        let mut payload = make_test_provider_payload("claude", "oauth");
+        let email = format!("{}@例え.jp", "田中".repeat(20));
+        payload.usage.identity = Some(crate::core::models::ProviderIdentity {
+            account_email: Some(email.clone()),
+            account_organization: None,
+            login_method: None,
+        });
+
+        let output = render_usage(&[payload], true).unwrap();
+        assert_not_contains!(&output, &email);
+        assert_contains!(&output, "…");
+        for line in output.lines() {
+            assert!(
+                crate::util::format::display_width(line) <= USAGE_PANEL_WIDTH,
+                "line exceeds panel width: {line}"
+            );
+        }
+    }
+
+    #[test]
+    fn long_account_uses_middle_ellipsis() {
+        let mut payload = make_test_provider_payload("claude", "oauth");
+        let email = format!("user_{}@example.com", "x".repeat(80));
+        payload.usage.identity = Some(crate::core::models::ProviderIdentity {
+            account_email: Some(email.clone()),
+            account_organization: None,
+            login_method: None,
+        });
+
+        let truncated = render_provider_usage(&payload, &plain_options());
+        assert_contains!(&truncated, "Account: user_");
+        assert_contains!(&truncated, "@example.com");
+        assert_not_contains!(&truncated, &email);
+
+        let run = "x".repeat(30);
+        assert_not_contains!(&truncated, &run);
+        let options = UsageRenderOptions {
+            full: true,
+            ..plain_options()
+        };
+        let full = render_usage_with_options(&[payload], &options).unwrap();
+        assert_contains!(&full, &run);
+    }
+
+    #[test]
+    fn links_add_dashboard_line() {
+        let payload = make_test_provider_payload("claude", "oauth");
+        let without = render_provider_usage(&payload, &plain_options());
+        assert_not_contains!(&without, "Dashboard:");
+
+        let options = UsageRenderOptions {
+            links: true,
+            ..plain_options()
+        };
+        let with_links = render_provider_usage(&payload, &options);
+        assert_contains!(&with_links, "Dashboard: claude.ai/settings/usage");
+        assert_not_contains!(&with_links, "\x1b]8;;");
+    }
+
+    #[test]
+    fn hyperlinks_wrap_dashboard_in_osc8() {
+        let payload = make_test_provider_payload("claude", "oauth");
+        let options = UsageRenderOptions {
+            links: true,
+            hyperlinks: true,
+            ..plain_options()
+        };
+        let output = render_provider_usage(&payload, &options);
+        assert_contains!(
+            &output,
+            "\x1b]8;;https://claude.ai/settings/usage\x1b\\claude.ai/settings/usage\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn mixed_width_content_keeps_short_values_intact() {
+        let mut payload = make_test_provider_payload("claude", "oauth");
+        payload.usage.identity = Some(crate::core::models::ProviderIdentity {
+            account_email: Some("🚀dev@例え.jp".to_string()),
+            account_organization: None,
+            login_method: None,
+        });
+
+        let output = render_usage(&[payload], true).unwrap();
+        assert_contains!(&output, "Account: 🚀dev@例え.jp");
+    }
+
     #[test]
     fn no_color_mode_preserves_content() {
         let payload = make_test_provider_payload("codex", "cli");
@@ -1140,4 +1627,20 @@ mod tests {
         assert_no_ansi_codes!(&result);
         assert_eq!(result, "bold text");
     }
+
+    #[test]
+    fn local_model_line_shows_rates_and_zero_cost() {
+        let local = LocalModelStats {
+            model: "llama3.2".to_string(),
+            loaded_models: vec!["llama3.2".to_string()],
+            prompt_tokens: 30,
+            eval_tokens: 16,
+            prompt_tokens_per_sec: Some(300.0),
+            eval_tokens_per_sec: Some(42.06),
+        };
+        assert_eq!(
+            format_local_model(&local),
+            "Throughput: 42.1 tok/s generation, 300.0 tok/s prompt (llama3.2) · $0.00 local"
+        );
+    }
 }
@@ -1,15 +1,17 @@
 //! Output rendering for human and robot modes.
 
+pub mod cast;
 pub mod doctor;
 pub mod error;
 pub mod human;
 pub mod robot;
+pub mod table;
 
 use crate::cli::args::OutputFormat;
 use crate::core::doctor::DoctorReport;
 use crate::core::models::{CostPayload, ProviderPayload};
 use crate::error::Result;
-pub use human::{HistoryDay, HistoryRenderOptions, render_history_chart};
+pub use human::{HistoryDay, HistoryRenderOptions, UsageRenderOptions, render_history_chart};
 
 /// Render usage results.
 ///
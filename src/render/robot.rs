@@ -3,6 +3,8 @@
 //! Provides stable, token-efficient output for AI agents.
 
 use crate::core::models::{CostPayload, ProviderPayload, RobotOutput};
+use crate::core::provider::Provider;
+use crate::core::summary::UsageSummary;
 use crate::error::Result;
 use std::fmt::Write;
 
@@ -22,6 +24,20 @@ pub fn render_json_pretty<T: serde::Serialize>(output: &T) -> Result<String> {
     Ok(serde_json::to_string_pretty(output)?)
 }
 
+/// Print any `RobotOutput` as JSON on stdout, pretty or compact.
+///
+/// # Errors
+/// Returns an error if JSON serialization fails.
+pub fn print_json<T: serde::Serialize>(output: &T, pretty: bool) -> Result<()> {
+    let json = if pretty {
+        render_json_pretty(output)?
+    } else {
+        render_json(output)?
+    };
+    println!("{json}");
+    Ok(())
+}
+
 /// Render usage as JSON (legacy).
 ///
 /// # Errors
@@ -49,6 +65,14 @@ pub fn render_markdown_usage(results: &[ProviderPayload]) -> Result<String> {
 /// # Errors
 /// Returns an error if formatting fails (infallible in practice).
 pub fn render_usage_md(results: &[ProviderPayload]) -> Result<String> {
+    render_usage_md_with_links(results, false)
+}
+
+/// Render usage as Markdown, optionally listing each provider's dashboard URL.
+///
+/// # Errors
+/// Returns an error if formatting fails (infallible in practice).
+pub fn render_usage_md_with_links(results: &[ProviderPayload], links: bool) -> Result<String> {
     let mut output = String::new();
 
     for payload in results {
@@ -84,12 +108,98 @@ pub fn render_usage_md(results: &[ProviderPayload]) -> Result<String> {
             let _ = writeln!(output, "- status: {:?}", status.indicator);
         }
 
+        if let Some(premium) = &payload.copilot_premium {
+            let _ = writeln!(
+                output,
+                "- premium_used: {:.0}/{:.0}",
+                premium.used_requests, premium.allowance
+            );
+            let _ = writeln!(
+                output,
+                "- premium_projected: {:.0}",
+                premium.projected_requests
+            );
+            let _ = writeln!(output, "- premium_status: {}", premium.status.as_str());
+            if premium.projected_overage_usd > 0.0 {
+                let _ = writeln!(
+                    output,
+                    "- premium_overage_usd: {:.2}",
+                    premium.projected_overage_usd
+                );
+            }
+        }
+
+        if let Some(spend) = &payload.task_spend {
+            let _ = writeln!(output, "- spend_today_usd: {:.2}", spend.today_usd);
+            let _ = writeln!(
+                output,
+                "- spend_30d_usd: {:.2} ({} tasks)",
+                spend.last_30_days_usd, spend.last_30_days_tasks
+            );
+        }
+
+        if let Some(local) = &payload.local_model {
+            let _ = writeln!(output, "- local_model: {}", local.model);
+            if let Some(rate) = local.eval_tokens_per_sec {
+                let _ = writeln!(output, "- eval_tokens_per_sec: {rate:.1}");
+            }
+            if let Some(rate) = local.prompt_tokens_per_sec {
+                let _ = writeln!(output, "- prompt_tokens_per_sec: {rate:.1}");
+            }
+        }
+
+        for divergence in &payload.divergences {
+            let _ = writeln!(output, "- divergence: {}", divergence.message());
+        }
+
+        if links
+            && let Some(url) = Provider::from_cli_name(&payload.provider)
+                .ok()
+                .and_then(Provider::dashboard_url)
+        {
+            let _ = writeln!(output, "- dashboard: <{url}>");
+        }
+
         output.push('\n');
     }
 
     Ok(output)
 }
 
+/// Render the all-providers summary as a Markdown section.
+#[must_use]
+pub fn render_usage_summary_md(summary: &UsageSummary) -> String {
+    let mut output = String::from("## Summary\n");
+
+    let _ = writeln!(output, "- providers: {}", summary.providers);
+    if summary.failed > 0 {
+        let _ = writeln!(output, "- failed: {}", summary.failed);
+    }
+    if let Some(today) = summary.cost_today_usd {
+        let _ = writeln!(output, "- spend_today_usd: {today:.2}");
+    }
+    if let Some(month) = summary.cost_month_usd {
+        let _ = writeln!(output, "- spend_month_usd: {month:.2}");
+    }
+    if let Some(window) = &summary.most_constrained {
+        let _ = writeln!(
+            output,
+            "- most_constrained: {} {} ({:.0}% used)",
+            window.provider, window.window, window.used_percent
+        );
+    }
+    if let Some(reset) = &summary.next_reset {
+        let _ = writeln!(
+            output,
+            "- next_reset: {} {} at {}",
+            reset.provider, reset.window, reset.resets_at
+        );
+    }
+    let _ = writeln!(output, "- status: {}", summary.status.label());
+
+    output
+}
+
 /// Render cost as JSON.
 ///
 /// # Errors
@@ -220,7 +330,7 @@ pub fn render_cost_md(results: &[CostPayload]) -> Result<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::models::RobotOutput;
+    use crate::core::models::{CopilotPremiumProjection, PremiumRequestStatus, RobotOutput};
     use crate::test_utils::{
         make_test_cost_payload, make_test_cost_payload_minimal, make_test_provider_payload,
         make_test_provider_payload_minimal, make_test_usage_snapshot_with_tertiary,
@@ -577,6 +687,62 @@ mod tests {
         assert_contains!(&md, "## claude (oauth)");
     }
 
+    #[test]
+    fn usage_md_links_list_dashboard() {
+        let payload = make_test_provider_payload("claude", "oauth");
+
+        let md = render_usage_md(std::slice::from_ref(&payload)).unwrap();
+        assert_not_contains!(&md, "dashboard:");
+
+        let md = render_usage_md_with_links(&[payload], true).unwrap();
+        assert_contains!(&md, "- dashboard: <https://claude.ai/settings/usage>");
+    }
+
+    #[test]
+    fn usage_md_premium_status_matches_json_label() {
+        let mut payload = make_test_provider_payload("copilot", "api");
+        payload.copilot_premium = Some(CopilotPremiumProjection {
+            plan: "business".to_string(),
+            org: None,
+            used_requests: 200.0,
+            allowance: 300.0,
+            projected_requests: 620.0,
+            overage_allowed: false,
+            budget_usd: None,
+            projected_overage_usd: 0.0,
+            exhausted_on: None,
+            resets_at: chrono::Utc::now(),
+            status: PremiumRequestStatus::AtRisk,
+        });
+
+        let md = render_usage_md(std::slice::from_ref(&payload)).unwrap();
+        assert_contains!(&md, "- premium_status: at_risk");
+
+        let json = render_usage_json(&[payload], false).unwrap();
+        assert_contains!(&json, r#""status":"at_risk""#);
+    }
+
+    #[test]
+    fn usage_md_summary_lists_totals() {
+        let payloads = vec![
+            make_test_provider_payload("codex", "cli"),
+            make_test_provider_payload("claude", "oauth"),
+        ];
+        let summary = UsageSummary::build(&payloads, &[], 1, chrono::Utc::now());
+
+        let md = render_usage_summary_md(&summary);
+        assert_contains!(&md, "## Summary");
+        assert_contains!(&md, "- providers: 2");
+        assert_contains!(&md, "- failed: 1");
+        assert_contains!(&md, "- most_constrained: ");
+        assert_contains!(&md, "- status: ");
+        assert_not_contains!(&md, "spend_today_usd");
+
+        let json =
+            render_json(&RobotOutput::usage(payloads, vec![]).with_summary(Some(summary))).unwrap();
+        assert_contains!(&json, r#""summary":{"providers":2,"failed":1"#);
+    }
+
     #[test]
     fn cost_md_format() {
         let payload = make_test_cost_payload("claude");
@@ -0,0 +1,274 @@
+//! Column-selected usage tables (`caut usage --columns`, `--csv`).
+//!
+//! One row per provider with only the chosen columns, for narrow terminals
+//! and focused reports. The same cells render as an aligned text table, a
+//! Markdown table, or CSV; CSV cells hold raw numbers and RFC 3339 times
+//! instead of display strings.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Utc};
+
+use crate::cli::args::UsageColumn;
+use crate::core::models::{CostPayload, ProviderPayload, RateWindow, StatusIndicator};
+use crate::core::summary::cost_today_usd;
+use crate::util::format::{csv_escape, display_width, format_cost, format_percent, pad_to_width};
+use crate::util::time::humanize_reset;
+
+/// Shown in text and Markdown cells with no value.
+const MISSING: &str = "—";
+
+/// How a usage table is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableStyle {
+    /// Aligned columns for the terminal.
+    Text,
+    /// GitHub-flavored Markdown table.
+    Markdown,
+    /// Comma-separated values with a header row.
+    Csv,
+}
+
+/// Render `payloads` as a table of `columns`, with spend from `costs`.
+#[must_use]
+pub fn render_usage_table(
+    payloads: &[ProviderPayload],
+    costs: &[CostPayload],
+    columns: &[UsageColumn],
+    style: TableStyle,
+) -> String {
+    let now = Utc::now();
+    let header: Vec<String> = columns
+        .iter()
+        .map(|column| header(*column, style).to_string())
+        .collect();
+    let rows: Vec<Vec<String>> = payloads
+        .iter()
+        .map(|payload| {
+            columns
+                .iter()
+                .map(|column| cell(*column, payload, costs, style, now))
+                .collect()
+        })
+        .collect();
+
+    match style {
+        TableStyle::Text => render_text(&header, &rows),
+        TableStyle::Markdown => render_markdown(&header, &rows),
+        TableStyle::Csv => render_csv(&header, &rows),
+    }
+}
+
+fn header(column: UsageColumn, style: TableStyle) -> &'static str {
+    let (label, csv) = match column {
+        UsageColumn::Provider => ("Provider", "provider"),
+        UsageColumn::Source => ("Source", "source"),
+        UsageColumn::Account => ("Account", "account"),
+        UsageColumn::Primary => ("Session", "session_used_percent"),
+        UsageColumn::Weekly => ("Weekly", "weekly_used_percent"),
+        UsageColumn::Tertiary => ("Opus/Sonnet", "opus_sonnet_used_percent"),
+        UsageColumn::Credits => ("Credits", "credits_remaining"),
+        UsageColumn::Cost => ("Today", "cost_today_usd"),
+        UsageColumn::Reset => ("Next reset", "next_reset"),
+        UsageColumn::Status => ("Status", "status"),
+    };
+    if style == TableStyle::Csv { csv } else { label }
+}
+
+fn cell(
+    column: UsageColumn,
+    payload: &ProviderPayload,
+    costs: &[CostPayload],
+    style: TableStyle,
+    now: DateTime<Utc>,
+) -> String {
+    let csv = style == TableStyle::Csv;
+    let window = |window: Option<&RateWindow>| {
+        window.map(|window| {
+            if csv {
+                format!("{:.1}", window.used_percent)
+            } else {
+                format_percent(window.used_percent)
+            }
+        })
+    };
+
+    let value = match column {
+        UsageColumn::Provider => Some(payload.provider.clone()),
+        UsageColumn::Source => Some(payload.source.clone()),
+        UsageColumn::Account => payload.account.clone().or_else(|| {
+            payload
+                .usage
+                .identity
+                .as_ref()
+                .and_then(|identity| identity.account_email.clone())
+        }),
+        UsageColumn::Primary => window(payload.usage.primary.as_ref()),
+        UsageColumn::Weekly => window(payload.usage.secondary.as_ref()),
+        UsageColumn::Tertiary => window(payload.usage.tertiary.as_ref()),
+        UsageColumn::Credits => payload
+            .credits
+            .as_ref()
+            .map(|credits| format!("{:.1}", credits.remaining)),
+        UsageColumn::Cost => cost_today_usd(payload, costs).map(|usd| {
+            if csv {
+                format!("{usd:.2}")
+            } else {
+                format_cost(usd)
+            }
+        }),
+        UsageColumn::Reset => [
+            payload.usage.primary.as_ref(),
+            payload.usage.secondary.as_ref(),
+            payload.usage.tertiary.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|window| window.resets_at)
+        .filter(|at| *at > now)
+        .min()
+        .map(|at| {
+            if csv {
+                at.to_rfc3339()
+            } else {
+                humanize_reset(at)
+            }
+        }),
+        UsageColumn::Status => payload
+            .status
+            .as_ref()
+            .map(|status| status_label(status.indicator).to_string()),
+    };
+
+    value.unwrap_or_else(|| {
+        if csv {
+            String::new()
+        } else {
+            MISSING.to_string()
+        }
+    })
+}
+
+const fn status_label(indicator: StatusIndicator) -> &'static str {
+    match indicator {
+        StatusIndicator::None => "operational",
+        StatusIndicator::Minor => "minor",
+        StatusIndicator::Major => "major",
+        StatusIndicator::Critical => "critical",
+        StatusIndicator::Maintenance => "maintenance",
+        StatusIndicator::Unknown => "unknown",
+    }
+}
+
+fn render_text(header: &[String], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| display_width(&row[i]))
+                .chain(std::iter::once(display_width(&header[i])))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let line = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| pad_to_width(cell, *width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(out, "{}", line(header));
+    let rule = widths.iter().sum::<usize>() + 2 * widths.len().saturating_sub(1);
+    let _ = writeln!(out, "{}", "─".repeat(rule));
+    for row in rows {
+        let _ = writeln!(out, "{}", line(row));
+    }
+    out
+}
+
+fn render_markdown(header: &[String], rows: &[Vec<String>]) -> String {
+    let line = |cells: &[String]| {
+        let cells: Vec<String> = cells.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(out, "{}", line(header));
+    let _ = writeln!(out, "|{}|", vec!["---"; header.len()].join("|"));
+    for row in rows {
+        let _ = writeln!(out, "{}", line(row));
+    }
+    out
+}
+
+fn render_csv(header: &[String], rows: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    for cells in std::iter::once(header).chain(rows.iter().map(Vec::as_slice)) {
+        let cells: Vec<String> = cells.iter().map(|cell| csv_escape(cell)).collect();
+        let _ = writeln!(out, "{}", cells.join(","));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::make_test_provider_payload;
+
+    const COLUMNS: [UsageColumn; 4] = [
+        UsageColumn::Provider,
+        UsageColumn::Primary,
+        UsageColumn::Weekly,
+        UsageColumn::Cost,
+    ];
+
+    #[test]
+    fn text_table_shows_only_chosen_columns() {
+        let payloads = [
+            make_test_provider_payload("codex", "cli"),
+            make_test_provider_payload("claude", "oauth"),
+        ];
+
+        let text = render_usage_table(&payloads, &[], &COLUMNS, TableStyle::Text);
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("Provider"));
+        assert!(lines[0].contains("Session") && lines[0].contains("Weekly"));
+        assert!(!lines[0].contains("Credits"));
+        assert!(lines[2].starts_with("codex "));
+        assert!(lines[3].starts_with("claude"));
+        assert!(lines[2].ends_with(MISSING), "no spend: {}", lines[2]);
+
+        let md = render_usage_table(&payloads, &[], &COLUMNS, TableStyle::Markdown);
+        assert!(md.starts_with("| Provider | Session | Weekly | Today |\n|---|---|---|---|\n"));
+    }
+
+    #[test]
+    fn csv_uses_raw_values_and_escapes() {
+        let mut payload = make_test_provider_payload("codex", "cli");
+        payload.account = Some("Team, Inc".to_string());
+        let primary = payload.usage.primary.as_ref().unwrap().used_percent;
+
+        let csv = render_usage_table(
+            &[payload],
+            &[],
+            &[
+                UsageColumn::Provider,
+                UsageColumn::Account,
+                UsageColumn::Primary,
+                UsageColumn::Cost,
+            ],
+            TableStyle::Csv,
+        );
+        assert_eq!(
+            csv,
+            format!(
+                "provider,account,session_used_percent,cost_today_usd\ncodex,\"Team, Inc\",{primary:.1},\n"
+            )
+        );
+    }
+}
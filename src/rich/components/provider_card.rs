@@ -80,7 +80,7 @@ impl<'a> ProviderCard<'a> {
         segments.extend(bar.render_segments(self.theme));
 
         // Reset info
-        if let Some(desc) = &window.reset_description {
+        if let Some(desc) = window.reset_text() {
             segments.push(Segment::styled(
                 format!(" ({desc})"),
                 self.theme.muted.clone(),
@@ -101,7 +101,7 @@ impl<'a> ProviderCard<'a> {
         let bar = UsageBar::new(window.used_percent).width(15);
         let bar_str = bar.render_plain();
 
-        let reset_info = window.reset_description.as_ref().map_or_else(
+        let reset_info = window.reset_text().map_or_else(
             || {
                 window.window_minutes.map_or_else(String::new, |minutes| {
                     format!(" ({})", format_duration_short(minutes))
@@ -229,7 +229,7 @@ impl Renderable for ProviderCard<'_> {
             lines.push(format!(
                 "  Session: {} {}",
                 format_percentage(primary.used_percent),
-                primary.reset_description.as_deref().unwrap_or("")
+                primary.reset_text().unwrap_or_default()
             ));
         }
 
@@ -237,7 +237,7 @@ impl Renderable for ProviderCard<'_> {
             lines.push(format!(
                 "  Weekly:  {} {}",
                 format_percentage(secondary.used_percent),
-                secondary.reset_description.as_deref().unwrap_or("")
+                secondary.reset_text().unwrap_or_default()
             ));
         }
 
@@ -209,6 +209,8 @@ pub struct ThemeConfig {
     pub provider_cursor: Style,
     /// GitHub Copilot brand color (blue).
     pub provider_copilot: Style,
+    /// xAI/Grok brand color (silver).
+    pub provider_xai: Style,
     /// Fallback for unknown providers.
     pub provider_other: Style,
 
@@ -273,6 +275,8 @@ impl ThemeConfig {
             &self.provider_cursor
         } else if name_lower.contains("copilot") || name_lower.contains("github") {
             &self.provider_copilot
+        } else if name_lower.contains("xai") || name_lower.contains("grok") {
+            &self.provider_xai
         } else {
             &self.provider_other
         }
@@ -324,6 +328,7 @@ pub fn create_default_theme() -> ThemeConfig {
         provider_google: Style::new().color(hex_or_named("#3B82F6", "blue")),
         provider_cursor: Style::new().color(hex_or_named("#8B5CF6", "magenta")),
         provider_copilot: Style::new().color(hex_or_named("#2563EB", "blue")),
+        provider_xai: Style::new().color(hex_or_named("#D4D4D8", "bright_white")),
         provider_other: Style::new().color(parse_color("white")),
 
         table_header: Style::new().bold().underline(),
@@ -369,6 +374,7 @@ pub fn create_minimal_theme() -> ThemeConfig {
         provider_google: Style::new(),
         provider_cursor: Style::new(),
         provider_copilot: Style::new(),
+        provider_xai: Style::new(),
         provider_other: Style::new(),
 
         table_header: Style::new().bold(),
@@ -414,6 +420,7 @@ pub fn create_high_contrast_theme() -> ThemeConfig {
         provider_google: Style::new().color(parse_color("blue")).bold(),
         provider_cursor: Style::new().color(parse_color("magenta")).bold(),
         provider_copilot: Style::new().color(parse_color("cyan")).bold(),
+        provider_xai: Style::new().color(parse_color("bright_white")).bold(),
         provider_other: Style::new().color(parse_color("white")).bold(),
 
         table_header: Style::new().color(parse_color("white")).bold().underline(),
@@ -696,6 +703,112 @@ pub fn should_use_rich_output(format: OutputFormat, no_color_flag: bool) -> bool
     true
 }
 
+// =============================================================================
+// Terminal Escape Capabilities
+// =============================================================================
+
+/// Environment variable forcing OSC 8 hyperlinks on (`1`) or off (`0`).
+const HYPERLINKS_ENV: &str = "CAUT_HYPERLINKS";
+
+/// Environment variable forcing OSC 9 notifications on (`1`) or off (`0`).
+const NOTIFY_ENV: &str = "CAUT_TERMINAL_NOTIFY";
+
+/// `TERM_PROGRAM` values for terminals that render OSC 8 hyperlinks.
+const HYPERLINK_TERM_PROGRAMS: &[&str] = &[
+    "iTerm.app",
+    "WezTerm",
+    "vscode",
+    "ghostty",
+    "Hyper",
+    "Tabby",
+    "rio",
+];
+
+/// `TERM_PROGRAM` values for terminals that show OSC 9 notifications.
+const NOTIFY_TERM_PROGRAMS: &[&str] = &["iTerm.app", "WezTerm", "ghostty"];
+
+/// Escape-sequence features beyond color that the terminal supports.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TerminalFeatures {
+    /// OSC 8 clickable hyperlinks.
+    pub hyperlinks: bool,
+    /// OSC 9 terminal-native desktop notifications.
+    pub notifications: bool,
+}
+
+impl TerminalFeatures {
+    /// Detect features for the current process.
+    ///
+    /// Both features require the rich output gate to pass, so robot mode,
+    /// pipes, CI, and `--no-color` never receive these escape sequences.
+    #[must_use]
+    pub fn detect(format: OutputFormat, no_color_flag: bool) -> Self {
+        if !should_use_rich_output(format, no_color_flag) {
+            return Self::default();
+        }
+        let features = Self::from_env(|key| std::env::var(key).ok());
+        tracing::debug!(
+            hyperlinks = features.hyperlinks,
+            notifications = features.notifications,
+            "Terminal features detected"
+        );
+        features
+    }
+
+    /// Detect features from environment lookups alone (no TTY checks).
+    fn from_env(env: impl Fn(&str) -> Option<String>) -> Self {
+        let term = env("TERM").unwrap_or_default();
+        let program = env("TERM_PROGRAM").unwrap_or_default();
+        // Multiplexers swallow or mangle these sequences unless configured.
+        let multiplexed = env("TMUX").is_some() || term.starts_with("screen");
+
+        let hyperlinks = env_override(&env, HYPERLINKS_ENV).unwrap_or_else(|| {
+            !multiplexed
+                && (HYPERLINK_TERM_PROGRAMS.contains(&program.as_str())
+                    || env("WT_SESSION").is_some()
+                    || env("KONSOLE_VERSION").is_some()
+                    || env("DOMTERM").is_some()
+                    || env("VTE_VERSION")
+                        .and_then(|v| v.parse::<u32>().ok())
+                        .is_some_and(|v| v >= 5000)
+                    || ["kitty", "alacritty", "foot", "ghostty"]
+                        .iter()
+                        .any(|name| term.contains(name)))
+        });
+
+        let notifications = env_override(&env, NOTIFY_ENV).unwrap_or_else(|| {
+            !multiplexed
+                && (NOTIFY_TERM_PROGRAMS.contains(&program.as_str())
+                    || env("ConEmuANSI").is_some_and(|v| v == "ON")
+                    || ["kitty", "ghostty"].iter().any(|name| term.contains(name)))
+        });
+
+        Self {
+            hyperlinks,
+            notifications,
+        }
+    }
+}
+
+/// Parse a `1`/`0` style override from the environment.
+fn env_override(env: &impl Fn(&str) -> Option<String>, key: &str) -> Option<bool> {
+    match env(key)?.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// Build an OSC 9 desktop notification sequence.
+///
+/// Control characters are stripped from `message` so it cannot terminate the
+/// sequence early.
+#[must_use]
+pub fn osc9_notification(message: &str) -> String {
+    let message: String = message.chars().filter(|c| !c.is_control()).collect();
+    format!("\x1b]9;{message}\x07")
+}
+
 /// Remove rich markup tags from text, preserving content.
 ///
 /// Handles all markup formats:
@@ -846,7 +959,11 @@ pub fn collect_rich_diagnostics(format: OutputFormat, no_color_flag: bool) -> St
     let log_file = env_or_unset("CAUT_LOG_FILE");
 
     let theme = env_or_unset(THEME_ENV);
+    let term_program = env_or_unset("TERM_PROGRAM");
+    let hyperlinks_env = env_or_unset(HYPERLINKS_ENV);
+    let notify_env = env_or_unset(NOTIFY_ENV);
     let rich_enabled = should_use_rich_output(format, no_color_flag);
+    let features = TerminalFeatures::detect(format, no_color_flag);
 
     let lines = [
         format!("stdout is TTY: {stdout_tty}"),
@@ -860,9 +977,14 @@ pub fn collect_rich_diagnostics(format: OutputFormat, no_color_flag: bool) -> St
         format!("CAUT_LOG_FORMAT: {log_format}"),
         format!("CAUT_LOG_FILE: {log_file}"),
         format!("CAUT_THEME: {theme}"),
+        format!("TERM_PROGRAM: {term_program}"),
+        format!("{HYPERLINKS_ENV}: {hyperlinks_env}"),
+        format!("{NOTIFY_ENV}: {notify_env}"),
         format!("output format: {format:?}"),
         format!("no_color flag: {no_color_flag}"),
         format!("rich output enabled: {rich_enabled}"),
+        format!("hyperlinks (OSC 8): {}", features.hyperlinks),
+        format!("notifications (OSC 9): {}", features.notifications),
     ];
 
     lines.join("\n")
@@ -1228,6 +1350,68 @@ mod tests {
         assert!(logs_contain("render_time_ms") || logs_contain("component"));
     }
 
+    // =========================================================================
+    // Terminal Feature Tests
+    // =========================================================================
+
+    fn features_for(vars: &[(&str, &str)]) -> TerminalFeatures {
+        TerminalFeatures::from_env(|key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| (*v).to_string())
+        })
+    }
+
+    #[test]
+    fn test_known_terminals_support_hyperlinks() {
+        assert!(features_for(&[("TERM_PROGRAM", "WezTerm")]).hyperlinks);
+        assert!(features_for(&[("TERM", "xterm-kitty")]).hyperlinks);
+        assert!(features_for(&[("WT_SESSION", "abc")]).hyperlinks);
+        assert!(features_for(&[("VTE_VERSION", "6003")]).hyperlinks);
+        assert!(!features_for(&[("VTE_VERSION", "4600")]).hyperlinks);
+        assert!(!features_for(&[("TERM", "xterm-256color")]).hyperlinks);
+    }
+
+    #[test]
+    fn test_notifications_limited_to_osc9_terminals() {
+        assert!(features_for(&[("TERM_PROGRAM", "iTerm.app")]).notifications);
+        assert!(features_for(&[("ConEmuANSI", "ON")]).notifications);
+        assert!(!features_for(&[("TERM_PROGRAM", "vscode")]).notifications);
+        assert!(!features_for(&[("WT_SESSION", "abc")]).notifications);
+    }
+
+    #[test]
+    fn test_multiplexer_disables_features_unless_forced() {
+        let tmux = features_for(&[("TERM_PROGRAM", "iTerm.app"), ("TMUX", "/tmp/tmux")]);
+        assert_eq!(tmux, TerminalFeatures::default());
+
+        let forced = features_for(&[
+            ("TMUX", "/tmp/tmux"),
+            (HYPERLINKS_ENV, "1"),
+            (NOTIFY_ENV, "yes"),
+        ]);
+        assert!(forced.hyperlinks);
+        assert!(forced.notifications);
+
+        let off = features_for(&[("TERM_PROGRAM", "WezTerm"), (HYPERLINKS_ENV, "0")]);
+        assert!(!off.hyperlinks);
+        assert!(off.notifications);
+    }
+
+    #[test]
+    fn test_robot_mode_disables_terminal_features() {
+        let features = TerminalFeatures::detect(OutputFormat::Json, false);
+        assert_eq!(features, TerminalFeatures::default());
+    }
+
+    #[test]
+    fn test_osc9_strips_control_characters() {
+        assert_eq!(
+            osc9_notification("claude\x07 at 95%\n"),
+            "\x1b]9;claude at 95%\x07"
+        );
+    }
+
     // =========================================================================
     // Diagnostics Tests
     // =========================================================================
@@ -1437,7 +1621,9 @@ mod tests {
     #[test]
     fn test_all_major_providers_have_colors() {
         let theme = create_default_theme();
-        let providers = ["Claude", "OpenAI", "Google", "Gemini", "Cursor", "Copilot"];
+        let providers = [
+            "Claude", "OpenAI", "Google", "Gemini", "Cursor", "Copilot", "Grok",
+        ];
         for p in providers {
             let style = theme.provider_style(p);
             // Should not be the "other" fallback
@@ -20,17 +20,37 @@
 //! - `CAUT_NO_COLOR` or `NO_COLOR`: Disable colors (1, true, yes)
 //! - `CAUT_VERBOSE`: Enable verbose output (1, true, yes)
 //! - `CAUT_PRETTY`: Pretty-print JSON output (1, true, yes)
-//! - `CAUT_CONFIG`: Override config file path
+//! - `CAUT_CONFIG`: Override config file path, or a remote config URL
+//!
+//! ## Remote Config
+//!
+//! `--config <URL>`, `CAUT_CONFIG=<URL>`, or `include_url` in the local file
+//! layers a team config under the local one (see [`super::remote_config`]).
+//!
+//! ## Unknown Keys
+//!
+//! Keys caut does not recognize are ignored, with a warning at startup;
+//! `caut config validate --strict` rejects them (see
+//! [`super::config_schema`]).
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
+use toml_edit::{DocumentMut, Item, TableLike};
 
 use super::AppPaths;
+use super::config_schema::{self, UnknownKey};
+use super::private_file;
+use super::remote_config::{self, RemoteSource};
 use crate::cli::args::{Cli, OutputFormat, UsageArgs};
+use crate::core::local_cost::LocalCostConfig;
+use crate::core::notify::NotifyConfig;
+use crate::core::policy::PolicyConfig;
 use crate::core::provider::Provider;
+use crate::core::watchdog::WatchdogConfig;
 use crate::error::Result;
 
 // =============================================================================
@@ -54,6 +74,24 @@ pub const ENV_PRETTY: &str = "CAUT_PRETTY";
 /// Environment variable to override config file path.
 pub const ENV_CONFIG: &str = "CAUT_CONFIG";
 
+/// Config location from `--config`, set once at startup.
+static CONFIG_LOCATION: OnceLock<String> = OnceLock::new();
+
+/// Load config from this path or URL instead of the default (`--config`).
+///
+/// Takes precedence over `CAUT_CONFIG`. Only the first call has an effect.
+pub fn set_config_location(location: String) {
+    let _ = CONFIG_LOCATION.set(location);
+}
+
+/// Config location from `--config`, then `CAUT_CONFIG`.
+fn config_location() -> Option<String> {
+    CONFIG_LOCATION
+        .get()
+        .cloned()
+        .or_else(|| std::env::var(ENV_CONFIG).ok())
+}
+
 // =============================================================================
 // Resolved Configuration
 // =============================================================================
@@ -137,7 +175,7 @@ impl ResolvedConfig {
     /// - The config file exists but is invalid
     /// - Any resolved value is invalid (e.g., unknown provider)
     pub fn resolve(cli: &Cli, usage_args: Option<&UsageArgs>) -> Result<Self> {
-        let config = Self::load_config()?;
+        let config = Config::load()?;
         config.validate()?;
 
         let mut sources = ConfigSources::default();
@@ -163,14 +201,6 @@ impl ResolvedConfig {
         })
     }
 
-    /// Load config file, respecting `CAUT_CONFIG` override.
-    fn load_config() -> Result<Config> {
-        std::env::var(ENV_CONFIG).map_or_else(
-            |_| Config::load(),
-            |path| Config::load_from(Path::new(&path)),
-        )
-    }
-
     /// Resolve providers setting.
     fn resolve_providers(
         usage_args: Option<&UsageArgs>,
@@ -407,20 +437,36 @@ impl ResolvedConfig {
 
 /// Application configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 #[derive(Default)]
 pub struct Config {
+    /// Remote team config merged under this file.
+    pub include_url: Option<String>,
+    /// Expected SHA-256 (hex) of the remote config.
+    pub include_sha256: Option<String>,
+    /// Ed25519 public key (base64) the remote config must be signed with.
+    pub include_public_key: Option<String>,
     /// General settings.
     pub general: GeneralConfig,
     /// Provider-specific settings.
     pub providers: ProvidersConfig,
     /// Output settings.
     pub output: OutputConfig,
+    /// Team policy rules for providers and models.
+    pub policy: PolicyConfig,
+    /// Session log discovery settings.
+    pub sessions: SessionsConfig,
+    /// Limits for runaway agent sessions in watch and serve mode.
+    pub watchdog: WatchdogConfig,
+    /// Rates for estimating the cost of self-hosted models.
+    pub local_cost: LocalCostConfig,
+    /// Channels `caut notify` sends the usage status to.
+    pub notify: NotifyConfig,
 }
 
 /// General application settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct GeneralConfig {
     /// Default timeout for network requests in seconds.
     pub timeout_seconds: u64,
@@ -428,6 +474,12 @@ pub struct GeneralConfig {
     pub include_status: bool,
     /// Default log level (error, warn, info, debug, trace).
     pub log_level: Option<String>,
+    /// Compute reset countdowns against provider server time when the
+    /// system clock is found to be off.
+    pub correct_clock_skew: bool,
+    /// Poll less often and skip web sources in `caut serve` and watch mode
+    /// while on battery or a metered connection.
+    pub power_aware: bool,
 }
 
 /// Provider-specific configuration.
@@ -443,7 +495,7 @@ pub struct ProvidersConfig {
 
 /// Settings for a specific provider.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct ProviderSettings {
     /// Whether this provider is enabled.
     pub enabled: bool,
@@ -455,6 +507,13 @@ pub struct ProviderSettings {
     pub strategies: Option<Vec<String>>,
     /// Custom API base URL (if different from default).
     pub api_base: Option<String>,
+    /// API key for API-key providers, used when no flag, environment
+    /// variable, or keyring entry provides one.
+    pub api_key: Option<String>,
+    /// Secret reference for the API key (`env:NAME`, `op://...`, or
+    /// `vault://path#field`), used when `api_key` is unset. Resolved only
+    /// when the key is needed (see [`super::secrets`]).
+    pub api_key_ref: Option<String>,
 }
 
 impl Default for ProviderSettings {
@@ -465,13 +524,24 @@ impl Default for ProviderSettings {
             timeout_seconds: None,
             strategies: None,
             api_base: None,
+            api_key: None,
+            api_key_ref: None,
         }
     }
 }
 
+/// Session log discovery settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SessionsConfig {
+    /// Extra directories searched for Claude and Codex session logs, such
+    /// as a devcontainer's home or a remote host's home mounted locally.
+    pub log_roots: Vec<PathBuf>,
+}
+
 /// Output formatting configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(default)]
+#[serde(default, deny_unknown_fields)]
 pub struct OutputConfig {
     /// Default output format (human, json, md).
     pub format: Option<String>,
@@ -479,6 +549,8 @@ pub struct OutputConfig {
     pub color: bool,
     /// Whether to pretty-print JSON output.
     pub pretty: bool,
+    /// Default `caut usage --columns`; when set, usage is shown as a table.
+    pub columns: Vec<String>,
 }
 
 impl Default for GeneralConfig {
@@ -487,6 +559,8 @@ impl Default for GeneralConfig {
             timeout_seconds: 30,
             include_status: false,
             log_level: None,
+            correct_clock_skew: false,
+            power_aware: true,
         }
     }
 }
@@ -531,21 +605,121 @@ impl Default for OutputConfig {
             format: None,
             color: true,
             pretty: false,
+            columns: Vec::new(),
         }
     }
 }
 
 impl Config {
-    /// Load configuration from the default config file path.
+    /// Load configuration from `--config`, `CAUT_CONFIG`, or the default
+    /// config file path.
     ///
+    /// When the location is a URL, the cached remote config is the base and
+    /// the default config file is merged over it.
     /// Returns default config if the file doesn't exist.
     /// Returns error only if the file exists but is invalid.
     ///
     /// # Errors
-    /// Returns an error if the config file exists but contains invalid TOML.
+    /// Returns an error if the config file exists but contains invalid TOML,
+    /// or a remote config is not cached or fails verification.
     pub fn load() -> Result<Self> {
-        let paths = AppPaths::new();
-        Self::load_from(&paths.config.join("config.toml"))
+        Self::parse(Self::location_table()?, false)
+    }
+
+    /// Load like [`Config::load`], but reject keys caut does not recognize
+    /// instead of ignoring them.
+    ///
+    /// # Errors
+    /// Returns an error if [`Config::load`] would, or the config has an
+    /// unknown key.
+    pub fn load_strict() -> Result<Self> {
+        Self::parse(Self::location_table()?, true)
+    }
+
+    /// Keys in the config that caut does not recognize, each with the
+    /// nearest known key when there is a close one.
+    ///
+    /// # Errors
+    /// Returns an error if the config file exists but is invalid TOML, or a
+    /// remote config is not cached or fails verification.
+    pub fn unknown_keys() -> Result<Vec<UnknownKey>> {
+        Ok(config_schema::unknown_keys(&Self::location_table()?))
+    }
+
+    /// Where config is loaded from, for display.
+    #[must_use]
+    pub fn location() -> String {
+        config_location().unwrap_or_else(|| Self::config_path().display().to_string())
+    }
+
+    /// The local config file: `--config` or `CAUT_CONFIG` when it is a path,
+    /// otherwise the default path (which a remote config is layered under).
+    #[must_use]
+    pub fn local_path() -> PathBuf {
+        match config_location() {
+            Some(path) if !remote_config::is_remote(&path) => PathBuf::from(path),
+            _ => Self::config_path(),
+        }
+    }
+
+    /// Value of a dotted key such as `general.timeout_seconds`, or `None`
+    /// when it is unset.
+    ///
+    /// # Errors
+    /// Returns an error if the key is unknown or the config cannot be
+    /// serialized.
+    pub fn get_value(&self, key: &str) -> Result<Option<toml::Value>> {
+        config_schema::check_key(key)?;
+        let mut value = toml::Value::try_from(self).map_err(|e| {
+            crate::error::CautError::Config(format!("Failed to serialize config: {e}"))
+        })?;
+        for part in key.split('.') {
+            match value {
+                toml::Value::Table(mut table) => match table.remove(part) {
+                    Some(next) => value = next,
+                    None => return Ok(None),
+                },
+                _ => return Ok(None),
+            }
+        }
+        Ok(Some(value))
+    }
+
+    /// Set a dotted key in the config file at `path`, keeping its other
+    /// settings, comments, and layout. The file is written, owner-only, only
+    /// if the result is a valid config.
+    ///
+    /// # Errors
+    /// Returns an error if the key is unknown, the file is not valid TOML, a
+    /// part of the key is not a table, the new value is invalid, or the file
+    /// cannot be written.
+    pub fn set_value(path: &Path, key: &str, value: &toml::Value) -> Result<()> {
+        use crate::error::CautError;
+
+        config_schema::check_key(key)?;
+        let mut doc = Self::read_document(path)?;
+        let (parents, leaf) = key
+            .rsplit_once('.')
+            .map_or((None, key), |(p, l)| (Some(p), l));
+        let mut table: &mut dyn TableLike = doc.as_table_mut();
+        for part in parents.into_iter().flat_map(|p| p.split('.')) {
+            table = table
+                .entry(part)
+                .or_insert_with(implicit_table)
+                .as_table_like_mut()
+                .ok_or_else(|| {
+                    CautError::Config(format!("Cannot set \"{key}\": \"{part}\" is not a table"))
+                })?;
+        }
+        set_item(table, leaf, value)?;
+
+        let content = doc.to_string();
+        let root: toml::Table = toml::from_str(&content)
+            .map_err(|e| CautError::Config(format!("Invalid config file: {e}")))?;
+        Self::parse(root, false)?.validate()?;
+        private_file::write(path, content)?;
+        tracing::debug!(?path, key, "Config value set");
+        Ok(())
     }
 
     /// Load configuration from a specific path.
@@ -554,19 +728,109 @@ impl Config {
     /// Returns error only if the file exists but is invalid.
     ///
     /// # Errors
-    /// Returns an error if the file exists but cannot be read or contains invalid TOML.
+    /// Returns an error if the file exists but cannot be read or contains invalid TOML,
+    /// or its `include_url` is not cached or fails verification.
     pub fn load_from(path: &Path) -> Result<Self> {
+        Self::parse(Self::layered_table(path, None)?, false)
+    }
+
+    /// The raw table for `--config`, `CAUT_CONFIG`, or the default path.
+    fn location_table() -> Result<toml::Table> {
+        match config_location() {
+            Some(url) if remote_config::is_remote(&url) => Self::layered_table(
+                &Self::config_path(),
+                Some(RemoteSource::from_location(&url)),
+            ),
+            Some(path) => Self::layered_table(Path::new(&path), None),
+            None => Self::layered_table(&Self::config_path(), None),
+        }
+    }
+
+    /// Parse a raw table. Unless `strict`, unknown keys are dropped first;
+    /// they are reported at startup (see [`config_schema`]).
+    fn parse(mut table: toml::Table, strict: bool) -> Result<Self> {
+        if !strict {
+            let unknown = config_schema::strip_unknown_keys(&mut table);
+            if !unknown.is_empty() {
+                tracing::debug!(count = unknown.len(), "Ignoring unknown config keys");
+            }
+        }
+        toml::Value::Table(table)
+            .try_into()
+            .map_err(|e| crate::error::CautError::Config(format!("Invalid config file: {e}")))
+    }
+
+    /// Read a config file over a remote base (given, or from `include_url`).
+    fn layered_table(path: &Path, remote: Option<RemoteSource>) -> Result<toml::Table> {
+        let local = Self::read_table(path)?.unwrap_or_default();
+        match remote.or_else(|| Self::include_source(&local)) {
+            Some(source) => {
+                tracing::debug!(url = %source.url, "Layering local config over remote config");
+                let mut base = remote_config::load_cached(&source)?;
+                remote_config::merge_tables(&mut base, local);
+                Ok(base)
+            }
+            None => Ok(local),
+        }
+    }
+
+    /// Read a config file as a raw TOML table, or `None` if it doesn't exist.
+    fn read_table(path: &Path) -> Result<Option<toml::Table>> {
         if !path.exists() {
             tracing::debug!(?path, "Config file not found, using defaults");
-            return Ok(Self::default());
+            return Ok(None);
         }
 
         tracing::debug!(?path, "Loading config file");
         let content = fs::read_to_string(path)?;
-        let config: Self = toml::from_str(&content)
-            .map_err(|e| crate::error::CautError::Config(format!("Invalid config file: {e}")))?;
+        toml::from_str(&content)
+            .map(Some)
+            .map_err(|e| crate::error::CautError::Config(format!("Invalid config file: {e}")))
+    }
+
+    /// Read a config file for editing in place, or an empty document if it
+    /// doesn't exist.
+    fn read_document(path: &Path) -> Result<DocumentMut> {
+        let content = if path.exists() {
+            fs::read_to_string(path)?
+        } else {
+            String::new()
+        };
+        content
+            .parse()
+            .map_err(|e| crate::error::CautError::Config(format!("Invalid config file: {e}")))
+    }
+
+    fn include_source(table: &toml::Table) -> Option<RemoteSource> {
+        let url = table.get("include_url")?.as_str()?;
+        Some(RemoteSource {
+            url: url.to_string(),
+            sha256: table
+                .get("include_sha256")
+                .and_then(toml::Value::as_str)
+                .map(str::to_string),
+            public_key: table
+                .get("include_public_key")
+                .and_then(toml::Value::as_str)
+                .map(str::to_string),
+        })
+    }
 
-        Ok(config)
+    /// Remote config to fetch at startup, if one is configured.
+    ///
+    /// # Errors
+    /// Returns an error if the local config file exists but is invalid TOML.
+    pub fn remote_source() -> Result<Option<RemoteSource>> {
+        let path: std::path::PathBuf = match config_location() {
+            Some(url) if remote_config::is_remote(&url) => {
+                return Ok(Some(RemoteSource::from_location(&url)));
+            }
+            Some(path) => path.into(),
+            None => Self::config_path(),
+        };
+        Ok(Self::read_table(&path)?
+            .as_ref()
+            .and_then(Self::include_source))
     }
 
     /// Save configuration to the default config file path.
@@ -578,22 +842,22 @@ impl Config {
         self.save_to(&paths.config.join("config.toml"))
     }
 
-    /// Save configuration to a specific path.
+    /// Save configuration to a specific path, owner-only.
+    ///
+    /// Comments and layout of an existing file are kept for the settings
+    /// that remain.
     ///
     /// # Errors
-    /// Returns an error if the parent directory cannot be created, serialization fails,
-    /// or the file cannot be written.
+    /// Returns an error if the existing file is not valid TOML, serialization
+    /// fails, or the file cannot be written.
     pub fn save_to(&self, path: &Path) -> Result<()> {
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        let content = toml::to_string_pretty(self).map_err(|e| {
+        let table = toml::Table::try_from(self).map_err(|e| {
             crate::error::CautError::Config(format!("Failed to serialize config: {e}"))
         })?;
+        let mut doc = Self::read_document(path)?;
+        sync_table(doc.as_table_mut(), &table)?;
 
-        fs::write(path, content)?;
+        private_file::write(path, doc.to_string())?;
         tracing::debug!(?path, "Config file saved");
         Ok(())
     }
@@ -669,6 +933,14 @@ impl Config {
             }
         }
 
+        // Validate policy rules
+        for rule in &self.policy.rules {
+            rule.validate()?;
+        }
+
+        self.watchdog.validate()?;
+        self.local_cost.validate()?;
+
         Ok(())
     }
 
@@ -719,6 +991,64 @@ impl Config {
     }
 }
 
+/// A table that gets a `[header]` only once it holds values of its own.
+fn implicit_table() -> Item {
+    let mut table = toml_edit::Table::new();
+    table.set_implicit(true);
+    Item::Table(table)
+}
+
+/// Make `table` hold exactly the entries of `new`, keeping the comments and
+/// layout of entries that stay.
+fn sync_table(table: &mut dyn TableLike, new: &toml::Table) -> Result<()> {
+    let stale: Vec<String> = table
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .filter(|key| !new.contains_key(key))
+        .collect();
+    for key in stale {
+        table.remove(&key);
+    }
+    for (key, value) in new {
+        set_item(table, key, value)?;
+    }
+    Ok(())
+}
+
+/// Set `key` in `table`. An unchanged value is left exactly as written, and
+/// a changed one keeps its surrounding comments.
+fn set_item(table: &mut dyn TableLike, key: &str, value: &toml::Value) -> Result<()> {
+    if let toml::Value::Table(new) = value {
+        let item = table.entry(key).or_insert_with(implicit_table);
+        if !item.is_table_like() {
+            *item = implicit_table();
+        }
+        if let Some(sub) = item.as_table_like_mut() {
+            sync_table(sub, new)?;
+        }
+        return Ok(());
+    }
+
+    let new: toml_edit::Value = value.to_string().parse().map_err(|e| {
+        crate::error::CautError::Config(format!("Invalid value for \"{key}\": {e}"))
+    })?;
+    match table.get_mut(key) {
+        Some(Item::Value(old)) => {
+            let decor = old.decor().clone();
+            old.decor_mut().clear();
+            if old.to_string().parse::<toml::Value>().ok().as_ref() != Some(value) {
+                *old = new;
+            }
+            *old.decor_mut() = decor;
+        }
+        Some(item) => *item = Item::Value(new),
+        None => {
+            table.insert(key, Item::Value(new));
+        }
+    }
+    Ok(())
+}
+
 /// Effective settings for a provider after merging config with defaults.
 #[derive(Debug, Clone)]
 pub struct EffectiveProviderSettings {
@@ -769,6 +1099,7 @@ include_status = true
 [output]
 color = false
 pretty = true
+columns = ["provider", "cost"]
  "
         )
         .unwrap();
@@ -778,6 +1109,95 @@ pretty = true
         assert!(config.general.include_status);
         assert!(!config.output.color);
         assert!(config.output.pretty);
+        assert_eq!(config.output.columns, ["provider", "cost"]);
+    }
+
+    #[test]
+    fn load_ignores_unknown_keys() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+proivders = ["claude"]
+
+[general]
+timeout_seconds = 60
+timout = 5
+
+[providers.claud]
+enabled = false
+"#
+        )
+        .unwrap();
+
+        let config = Config::load_from(file.path()).unwrap();
+        assert_eq!(config.general.timeout_seconds, 60);
+        assert!(!config.providers.settings.contains_key("claud"));
+        config.validate().unwrap();
+    }
+
+    #[test]
+    fn set_value_updates_file_and_get_value_reads_it() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "[output]\ncolor = false\n").unwrap();
+
+        Config::set_value(&path, "general.timeout_seconds", &toml::Value::Integer(90)).unwrap();
+        Config::set_value(
+            &path,
+            "providers.gemini.enabled",
+            &toml::Value::Boolean(false),
+        )
+        .unwrap();
+        let config = Config::load_from(&path).unwrap();
+        assert_eq!(config.general.timeout_seconds, 90);
+        assert!(!config.output.color);
+        assert!(!config.providers.is_enabled("gemini"));
+        assert_eq!(
+            config.get_value("general.timeout_seconds").unwrap(),
+            Some(toml::Value::Integer(90))
+        );
+        assert_eq!(config.get_value("general.log_level").unwrap(), None);
+
+        // Invalid values and unknown keys leave the file untouched.
+        let before = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            Config::set_value(&path, "general.timeout_seconds", &toml::Value::Integer(0)).is_err()
+        );
+        assert!(Config::set_value(&path, "general.timout", &toml::Value::Integer(5)).is_err());
+        assert!(config.get_value("output.colour").is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), before);
+    }
+
+    #[test]
+    fn set_value_and_save_keep_comments() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "# Team defaults\n[general]\n# Slow VPN\ntimeout_seconds = 60 # seconds\n",
+        )
+        .unwrap();
+
+        Config::set_value(&path, "general.timeout_seconds", &toml::Value::Integer(90)).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# Team defaults\n[general]\n# Slow VPN\n"));
+        assert!(content.contains("timeout_seconds = 90 # seconds\n"));
+
+        let mut config = Config::load_from(&path).unwrap();
+        config.output.pretty = true;
+        config.save_to(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# Team defaults\n[general]\n# Slow VPN\n"));
+        assert!(content.contains("timeout_seconds = 90 # seconds\n"));
+        assert!(Config::load_from(&path).unwrap().output.pretty);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode, private_file::PRIVATE_MODE);
+        }
     }
 
     #[test]
@@ -891,10 +1311,13 @@ pretty = true
             json: false,
             pretty: false,
             no_color: false,
+            config: None,
             log_level: None,
             json_output: false,
             verbose: false,
             debug_rich: false,
+            output: None,
+            log_root: Vec::new(),
         }
     }
 
@@ -906,15 +1329,27 @@ pretty = true
             account_index: None,
             all_accounts: false,
             no_credits: false,
+            full: false,
+            links: false,
+            explain: false,
+            verify: false,
             status: false,
             source: None,
             web: false,
             timeout: None,
             web_timeout: None,
+            api_keys: Vec::new(),
             web_debug_dump_html: false,
             watch: false,
             interval: 30,
+            record: None,
+            replay: None,
             tui: false,
+            sort: None,
+            filter: Vec::new(),
+            columns: Vec::new(),
+            csv: false,
+            strict: false,
         }
     }
 
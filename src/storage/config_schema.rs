@@ -0,0 +1,311 @@
+//! Unknown-key detection for `config.toml`.
+//!
+//! The config section types deny unknown fields, so a strict parse
+//! ([`Config::load_strict`]) rejects a typo such as `proivders = []`
+//! outright. Normal loading is lenient: the keys found here are dropped
+//! before parsing, and caut warns about them at startup with the nearest
+//! known key, so a typo never stops a command from running.
+
+use std::fmt;
+
+use serde::Serialize;
+use serde::de::{self, DeserializeOwned, Visitor};
+
+use super::config::{Config, GeneralConfig, OutputConfig, ProviderSettings, SessionsConfig};
+use crate::core::local_cost::LocalCostConfig;
+use crate::core::notify::NotifyConfig;
+use crate::core::policy::{PolicyConfig, PolicyRule};
+use crate::core::provider::Provider;
+use crate::core::watchdog::WatchdogConfig;
+use crate::error::{CautError, Result};
+
+/// Key under `[providers]` that is not a provider table.
+const DEFAULT_PROVIDERS_KEY: &str = "default_providers";
+
+/// A config key caut does not recognize.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnknownKey {
+    /// Dotted path, e.g. `general.timout_seconds`.
+    pub path: String,
+    /// The closest known key at the same level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for UnknownKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown config key `{}`", self.path)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean `{suggestion}`?)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that a dotted key such as `general.timeout_seconds` is one caut
+/// recognizes.
+///
+/// # Errors
+/// Returns an error naming the unknown part of the key and the nearest known
+/// key.
+pub fn check_key(key: &str) -> Result<()> {
+    if key.split('.').any(str::is_empty) {
+        return Err(CautError::Config(format!("Invalid config key \"{key}\"")));
+    }
+    let mut parts = key.rsplit('.');
+    let mut table = toml::Table::new();
+    let leaf = parts.next().unwrap_or_default();
+    table.insert(leaf.to_string(), toml::Value::Boolean(true));
+    for part in parts {
+        let mut parent = toml::Table::new();
+        parent.insert(part.to_string(), toml::Value::Table(table));
+        table = parent;
+    }
+    match unknown_keys(&table).into_iter().next() {
+        Some(unknown) => Err(CautError::Config(unknown.to_string())),
+        None => Ok(()),
+    }
+}
+
+/// Unknown keys in a raw config table.
+#[must_use]
+pub fn unknown_keys(table: &toml::Table) -> Vec<UnknownKey> {
+    strip_unknown_keys(&mut table.clone())
+}
+
+/// Remove unknown keys from a raw config table so it parses leniently.
+/// Returns the keys removed.
+pub fn strip_unknown_keys(table: &mut toml::Table) -> Vec<UnknownKey> {
+    let mut found = Vec::new();
+    strip(table, "", fields_of::<Config>(), &mut found);
+
+    let sections = [
+        ("general", fields_of::<GeneralConfig>()),
+        ("output", fields_of::<OutputConfig>()),
+        ("policy", fields_of::<PolicyConfig>()),
+        ("sessions", fields_of::<SessionsConfig>()),
+        ("watchdog", fields_of::<WatchdogConfig>()),
+        ("local_cost", fields_of::<LocalCostConfig>()),
+        ("notify", fields_of::<NotifyConfig>()),
+    ];
+    for (name, fields) in sections {
+        if let Some(toml::Value::Table(section)) = table.get_mut(name) {
+            strip(section, name, fields, &mut found);
+        }
+    }
+
+    if let Some(toml::Value::Array(rules)) = table
+        .get_mut("policy")
+        .and_then(|policy| policy.get_mut("rules"))
+    {
+        for (i, rule) in rules.iter_mut().enumerate() {
+            if let toml::Value::Table(rule) = rule {
+                strip(
+                    rule,
+                    &format!("policy.rules[{i}]"),
+                    fields_of::<PolicyRule>(),
+                    &mut found,
+                );
+            }
+        }
+    }
+
+    if let Some(toml::Value::Table(providers)) = table.get_mut("providers") {
+        strip_providers(providers, &mut found);
+    }
+    found
+}
+
+/// `[providers]` holds `default_providers` and one table per provider.
+fn strip_providers(providers: &mut toml::Table, found: &mut Vec<UnknownKey>) {
+    let mut known: Vec<&str> = vec![DEFAULT_PROVIDERS_KEY];
+    known.extend(Provider::ALL.iter().map(|p| p.cli_name()));
+
+    providers.retain(|key, _| {
+        if key == DEFAULT_PROVIDERS_KEY || Provider::from_cli_name(key).is_ok() {
+            return true;
+        }
+        found.push(UnknownKey {
+            path: format!("providers.{key}"),
+            suggestion: nearest(key, &known).map(str::to_string),
+        });
+        false
+    });
+
+    for (name, settings) in providers.iter_mut() {
+        if let toml::Value::Table(settings) = settings {
+            strip(
+                settings,
+                &format!("providers.{name}"),
+                fields_of::<ProviderSettings>(),
+                found,
+            );
+        }
+    }
+}
+
+fn strip(
+    table: &mut toml::Table,
+    prefix: &str,
+    fields: &[&'static str],
+    found: &mut Vec<UnknownKey>,
+) {
+    table.retain(|key, _| {
+        if fields.contains(&key.as_str()) {
+            return true;
+        }
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        found.push(UnknownKey {
+            path,
+            suggestion: nearest(key, fields).map(str::to_string),
+        });
+        false
+    });
+}
+
+/// The closest candidate within a few edits of `key`.
+fn nearest<'a>(key: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let key = key.to_lowercase();
+    let max_distance = 2.max(key.len() / 3);
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(&key, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance over chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// The field names a struct's derived `Deserialize` accepts.
+///
+/// Derived impls pass their field list to `deserialize_struct`; this
+/// deserializer records it and stops.
+fn fields_of<T: DeserializeOwned>() -> &'static [&'static str] {
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(FieldNames(&mut fields));
+    fields
+}
+
+struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+impl<'de> de::Deserializer<'de> for FieldNames<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        _visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error> {
+        Err(de::Error::custom("expected a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error> {
+        *self.0 = fields;
+        Err(de::Error::custom("field names recorded"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(toml: &str) -> toml::Table {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn finds_unknown_keys_with_suggestions() {
+        let keys = unknown_keys(&table(
+            r#"
+proivders = []
+
+[general]
+timout_seconds = 10
+include_status = true
+
+[providers]
+default_providers = ["claude"]
+
+[providers.claud]
+enabled = false
+
+[providers.codex]
+enabeld = false
+
+[[policy.rules]]
+action = "deny"
+provder = "codex"
+"#,
+        ));
+        let found: Vec<(&str, Option<&str>)> = keys
+            .iter()
+            .map(|k| (k.path.as_str(), k.suggestion.as_deref()))
+            .collect();
+        assert!(found.contains(&("proivders", Some("providers"))));
+        assert!(found.contains(&("general.timout_seconds", Some("timeout_seconds"))));
+        assert!(found.contains(&("providers.claud", Some("claude"))));
+        assert!(found.contains(&("providers.codex.enabeld", Some("enabled"))));
+        assert!(found.contains(&("policy.rules[0].provder", Some("provider"))));
+        assert_eq!(keys.len(), 5);
+        assert_eq!(
+            keys[0].to_string(),
+            "unknown config key `proivders` (did you mean `providers`?)"
+        );
+    }
+
+    #[test]
+    fn stripped_table_parses_and_strict_parse_rejects() {
+        let raw = table("[general]\ntimeout_seconds = 45\ncolour = false\n");
+        let strict: std::result::Result<Config, _> = toml::Value::Table(raw.clone()).try_into();
+        assert!(strict.unwrap_err().to_string().contains("unknown field"));
+
+        let mut lenient = raw;
+        let removed = strip_unknown_keys(&mut lenient);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].suggestion, None);
+        let config: Config = toml::Value::Table(lenient).try_into().unwrap();
+        assert_eq!(config.general.timeout_seconds, 45);
+
+        assert!(unknown_keys(&table("[general]\ntimeout_seconds = 45\n")).is_empty());
+
+        assert!(check_key("general.timeout_seconds").is_ok());
+        assert!(check_key("providers.claude.api_base").is_ok());
+        let err = check_key("output.fromat").unwrap_err().to_string();
+        assert!(err.contains("did you mean `format`"));
+        assert!(check_key("general..timeout_seconds").is_err());
+    }
+}
@@ -13,18 +13,24 @@
 //! Use `HistoryStore::prune()` or `maybe_prune()` to enforce the policy.
 
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
-use rusqlite::{Connection, Row, params};
+use rusqlite::{Connection, OptionalExtension, Row, ToSql, params, params_from_iter};
+use serde::Serialize;
 
-use crate::core::models::UsageSnapshot;
+use crate::core::gateway_import::GatewayUsage;
+use crate::core::history_import::ImportedCost;
+use crate::core::models::{LocalModelStats, UsageSnapshot};
+use crate::core::plan_limits::{PlanLimit, PlanLimitChange, snapshot_limits};
 use crate::core::provider::Provider;
 use crate::error::{CautError, Result};
 use crate::storage::history_schema::{
     DEFAULT_RETENTION_DAYS, cleanup_old_snapshots, run_migrations,
 };
+use crate::storage::multi_account::{Account, MultiAccountDb, SnapshotTrigger};
+use crate::storage::private_file;
 
 /// Default retention for detailed snapshots (days).
 pub const DEFAULT_DETAILED_RETENTION_DAYS: i64 = 30;
@@ -133,17 +139,75 @@ pub struct PruneResult {
     pub size_limit_triggered: bool,
 }
 
+/// Rows one table would lose to a prune.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TablePrune {
+    /// Table name.
+    pub table: &'static str,
+    /// Rows past the retention cutoff.
+    pub rows: usize,
+    /// Oldest affected timestamp or day.
+    pub oldest: Option<String>,
+    /// Newest affected timestamp or day.
+    pub newest: Option<String>,
+}
+
+/// What [`HistoryStore::prune`] would do under a policy, table by table.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrunePreview {
+    /// Age-based deletions per table.
+    pub tables: Vec<TablePrune>,
+    /// Daily aggregates that would be written before snapshots are deleted.
+    pub aggregates_to_create: usize,
+    /// Bytes currently used by live rows.
+    pub db_size_bytes: u64,
+    /// Estimated bytes used after the age-based deletions.
+    pub estimated_size_bytes: u64,
+    /// Additional oldest snapshots the size limit would remove.
+    pub size_limit_snapshots: usize,
+}
+
+impl PrunePreview {
+    /// Total rows that would be deleted across all tables.
+    #[must_use]
+    pub fn total_rows(&self) -> usize {
+        self.tables.iter().map(|t| t.rows).sum::<usize>() + self.size_limit_snapshots
+    }
+}
+
+/// Filters for [`HistoryStore::query_snapshots`].
+///
+/// Unset fields do not constrain the query.
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotFilter {
+    /// Only snapshots for this provider.
+    pub provider: Option<Provider>,
+    /// Account email or label (case-insensitive).
+    pub account: Option<String>,
+    /// Minimum usage percentage in any window.
+    pub min_used_pct: Option<f64>,
+    /// Inclusive lower bound on fetch time.
+    pub from: Option<DateTime<Utc>>,
+    /// Inclusive upper bound on fetch time.
+    pub to: Option<DateTime<Utc>>,
+    /// Maximum number of rows to return.
+    pub limit: Option<usize>,
+}
+
 /// History database access layer.
 pub struct HistoryStore {
     conn: Connection,
 }
 
 impl HistoryStore {
-    /// Create or open a history database at the given path.
+    /// Create or open a history database at the given path, readable only
+    /// by the owner.
     ///
     /// # Errors
     /// Returns an error if the parent directory cannot be created, the database
-    /// cannot be opened, or schema migrations fail.
+    /// cannot be opened or restricted to the owner, or schema migrations fail.
     pub fn open(path: &Path) -> Result<Self> {
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -151,12 +215,29 @@ impl HistoryStore {
 
         let mut conn = Connection::open(path)
             .map_err(|e| CautError::Other(anyhow::anyhow!("open history db: {e}")))?;
+        // SQLite creates its journal files with the database's mode, so
+        // tightening the database first covers sidecars made from now on.
+        for file in Self::database_files(path) {
+            private_file::restrict(&file)?;
+        }
 
         run_migrations(&mut conn)?;
 
         Ok(Self { conn })
     }
 
+    /// The database at `path` and its `-wal` and `-shm` sidecars, which hold
+    /// recent writes.
+    #[must_use]
+    pub fn database_files(path: &Path) -> [PathBuf; 3] {
+        let sidecar = |suffix: &str| {
+            let mut name = path.as_os_str().to_owned();
+            name.push(suffix);
+            PathBuf::from(name)
+        };
+        [path.to_path_buf(), sidecar("-wal"), sidecar("-shm")]
+    }
+
     /// Open an in-memory history database (for testing).
     ///
     /// # Errors
@@ -170,11 +251,25 @@ impl HistoryStore {
         Ok(Self { conn })
     }
 
-    /// Record a usage snapshot for a provider.
+    /// Record a routine (periodic) usage snapshot for a provider.
     ///
     /// # Errors
     /// Returns an error if the INSERT statement cannot be prepared or executed.
     pub fn record_snapshot(&self, snapshot: &UsageSnapshot, provider: &Provider) -> Result<i64> {
+        self.record_snapshot_as(snapshot, provider, SnapshotTrigger::Periodic, None)
+    }
+
+    /// Record a usage snapshot with an explicit trigger and optional note.
+    ///
+    /// # Errors
+    /// Returns an error if the INSERT statement cannot be prepared or executed.
+    pub fn record_snapshot_as(
+        &self,
+        snapshot: &UsageSnapshot,
+        provider: &Provider,
+        trigger: SnapshotTrigger,
+        note: Option<&str>,
+    ) -> Result<i64> {
         let primary = snapshot.primary.as_ref();
         let secondary = snapshot.secondary.as_ref();
         let tertiary = snapshot.tertiary.as_ref();
@@ -188,8 +283,8 @@ impl HistoryStore {
                 secondary_used_pct, secondary_window_minutes, secondary_resets_at, \
                 tertiary_used_pct, tertiary_window_minutes, tertiary_resets_at, \
                 cost_today_usd, cost_mtd_usd, credits_remaining, \
-                account_email, account_org, fetch_duration_ms \
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)"
+                account_email, account_org, fetch_duration_ms, trigger_type, note \
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)"
         )
         .map_err(|e| CautError::Other(anyhow::anyhow!("prepare insert: {e}")))?;
 
@@ -218,12 +313,433 @@ impl HistoryStore {
             identity.and_then(|i| i.account_email.clone()),
             identity.and_then(|i| i.account_organization.clone()),
             Option::<i64>::None,
+            trigger.as_str(),
+            note,
         ])
         .map_err(|e| CautError::Other(anyhow::anyhow!("insert snapshot: {e}")))?;
 
         Ok(self.conn.last_insert_rowid())
     }
 
+    /// Record the window definitions in a snapshot and return any that differ
+    /// from the last ones seen for the provider.
+    ///
+    /// # Errors
+    /// Returns an error if reading or writing the plan limit tables fails.
+    pub fn record_plan_limits(
+        &self,
+        snapshot: &UsageSnapshot,
+        provider: &Provider,
+    ) -> Result<Vec<PlanLimitChange>> {
+        let mut changes = Vec::new();
+
+        for (slot, limit) in snapshot_limits(snapshot) {
+            let previous = self
+                .conn
+                .query_row(
+                    "SELECT window_minutes, limit_value, limit_unit FROM plan_limits \
+                     WHERE provider = ?1 AND window_slot = ?2",
+                    params![provider.cli_name(), slot],
+                    |row| {
+                        Ok(PlanLimit {
+                            window_minutes: row.get(0)?,
+                            limit: row.get(1)?,
+                            unit: row.get(2)?,
+                        })
+                    },
+                )
+                .optional()
+                .map_err(|e| CautError::Other(anyhow::anyhow!("read plan limit: {e}")))?;
+
+            let stored = match &previous {
+                Some(previous) if previous.differs_from(&limit) => {
+                    let change = PlanLimitChange {
+                        provider: *provider,
+                        window: slot.to_string(),
+                        detected_at: snapshot.updated_at,
+                        old: previous.clone(),
+                        new: limit.merged_over(previous),
+                    };
+                    self.conn
+                        .execute(
+                            "INSERT INTO plan_limit_changes ( \
+                                provider, window_slot, detected_at, \
+                                old_window_minutes, new_window_minutes, \
+                                old_limit_value, new_limit_value, limit_unit \
+                            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                            params![
+                                provider.cli_name(),
+                                slot,
+                                change.detected_at.to_rfc3339(),
+                                change.old.window_minutes,
+                                change.new.window_minutes,
+                                change.old.limit,
+                                change.new.limit,
+                                change.new.unit,
+                            ],
+                        )
+                        .map_err(|e| {
+                            CautError::Other(anyhow::anyhow!("insert plan limit change: {e}"))
+                        })?;
+                    let stored = change.new.clone();
+                    changes.push(change);
+                    stored
+                }
+                Some(previous) => limit.merged_over(previous),
+                None => limit,
+            };
+
+            self.conn
+                .execute(
+                    "INSERT INTO plan_limits ( \
+                        provider, window_slot, window_minutes, limit_value, limit_unit, observed_at \
+                    ) VALUES (?1, ?2, ?3, ?4, ?5, ?6) \
+                    ON CONFLICT(provider, window_slot) DO UPDATE SET \
+                        window_minutes = excluded.window_minutes, \
+                        limit_value = excluded.limit_value, \
+                        limit_unit = excluded.limit_unit, \
+                        observed_at = excluded.observed_at",
+                    params![
+                        provider.cli_name(),
+                        slot,
+                        stored.window_minutes,
+                        stored.limit,
+                        stored.unit,
+                        snapshot.updated_at.to_rfc3339(),
+                    ],
+                )
+                .map_err(|e| CautError::Other(anyhow::anyhow!("upsert plan limit: {e}")))?;
+        }
+
+        Ok(changes)
+    }
+
+    /// Plan limit changes detected since `since`, newest first.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn plan_limit_changes(&self, since: DateTime<Utc>) -> Result<Vec<PlanLimitChange>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached(
+                "SELECT provider, window_slot, detected_at, \
+                    old_window_minutes, new_window_minutes, \
+                    old_limit_value, new_limit_value, limit_unit \
+                FROM plan_limit_changes \
+                WHERE detected_at >= ?1 \
+                ORDER BY detected_at DESC, id DESC",
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("prepare plan limit changes: {e}")))?;
+
+        let rows = stmt
+            .query_map([since.to_rfc3339()], |row| {
+                let provider_name: String = row.get(0)?;
+                let provider = Provider::from_cli_name(&provider_name).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        0,
+                        rusqlite::types::Type::Text,
+                        Box::new(e),
+                    )
+                })?;
+                let detected_at = parse_timestamp(&row.get::<_, String>(2)?).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        2,
+                        rusqlite::types::Type::Text,
+                        Box::new(e),
+                    )
+                })?;
+                let unit: Option<String> = row.get(7)?;
+                Ok(PlanLimitChange {
+                    provider,
+                    window: row.get(1)?,
+                    detected_at,
+                    old: PlanLimit {
+                        window_minutes: row.get(3)?,
+                        limit: row.get(5)?,
+                        unit: unit.clone(),
+                    },
+                    new: PlanLimit {
+                        window_minutes: row.get(4)?,
+                        limit: row.get(6)?,
+                        unit,
+                    },
+                })
+            })
+            .map_err(|e| CautError::Other(anyhow::anyhow!("query plan limit changes: {e}")))?;
+
+        let mut changes = Vec::new();
+        for row in rows {
+            changes.push(row.map_err(|e| CautError::Other(anyhow::anyhow!("map row: {e}")))?);
+        }
+        Ok(changes)
+    }
+
+    /// Record a local model throughput measurement.
+    ///
+    /// # Errors
+    /// Returns an error if the INSERT fails.
+    pub fn record_local_model_stats(
+        &self,
+        stats: &LocalModelStats,
+        provider: &Provider,
+        fetched_at: DateTime<Utc>,
+    ) -> Result<i64> {
+        self.conn
+            .execute(
+                "INSERT INTO local_model_stats ( \
+                    provider, model, fetched_at, prompt_tokens, eval_tokens, \
+                    prompt_tokens_per_sec, eval_tokens_per_sec \
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    provider.cli_name(),
+                    stats.model,
+                    fetched_at.to_rfc3339(),
+                    stats.prompt_tokens,
+                    stats.eval_tokens,
+                    stats.prompt_tokens_per_sec,
+                    stats.eval_tokens_per_sec,
+                ],
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("insert local model stats: {e}")))?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Throughput samples recorded for a provider since `since`, oldest first.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn throughput_samples(
+        &self,
+        provider: &Provider,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<ThroughputSample>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached(
+                "SELECT model, fetched_at, prompt_tokens_per_sec, eval_tokens_per_sec \
+                FROM local_model_stats \
+                WHERE provider = ?1 AND fetched_at >= ?2 \
+                ORDER BY fetched_at ASC, id ASC",
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("prepare throughput samples: {e}")))?;
+
+        let rows = stmt
+            .query_map(params![provider.cli_name(), since.to_rfc3339()], |row| {
+                let fetched_at = parse_timestamp(&row.get::<_, String>(1)?).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        1,
+                        rusqlite::types::Type::Text,
+                        Box::new(e),
+                    )
+                })?;
+                Ok(ThroughputSample {
+                    model: row.get(0)?,
+                    fetched_at,
+                    prompt_tokens_per_sec: row.get(2)?,
+                    eval_tokens_per_sec: row.get(3)?,
+                })
+            })
+            .map_err(|e| CautError::Other(anyhow::anyhow!("query throughput samples: {e}")))?;
+
+        let mut samples = Vec::new();
+        for row in rows {
+            samples.push(row.map_err(|e| CautError::Other(anyhow::anyhow!("map row: {e}")))?);
+        }
+        Ok(samples)
+    }
+
+    /// Record a failed usage fetch.
+    ///
+    /// # Errors
+    /// Returns an error if the INSERT fails.
+    pub fn record_fetch_failure(
+        &self,
+        provider: &Provider,
+        error_code: &str,
+        failed_at: DateTime<Utc>,
+    ) -> Result<i64> {
+        self.conn
+            .execute(
+                "INSERT INTO fetch_failures (provider, failed_at, error_code) VALUES (?1, ?2, ?3)",
+                params![provider.cli_name(), failed_at.to_rfc3339(), error_code],
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("insert fetch failure: {e}")))?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Failed fetches per provider since `since`.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn fetch_failure_counts(&self, since: DateTime<Utc>) -> Result<HashMap<Provider, usize>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached(
+                "SELECT provider, COUNT(*) FROM fetch_failures \
+                WHERE failed_at >= ?1 \
+                GROUP BY provider",
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("prepare fetch failures: {e}")))?;
+
+        let rows = stmt
+            .query_map([since.to_rfc3339()], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })
+            .map_err(|e| CautError::Other(anyhow::anyhow!("query fetch failures: {e}")))?;
+
+        let mut counts = HashMap::new();
+        for row in rows {
+            let (name, count) =
+                row.map_err(|e| CautError::Other(anyhow::anyhow!("map row: {e}")))?;
+            if let Ok(provider) = Provider::from_cli_name(&name) {
+                counts.insert(provider, usize::try_from(count).unwrap_or_default());
+            }
+        }
+        Ok(counts)
+    }
+
+    /// Upsert gateway daily rollups, replacing any earlier import of the
+    /// same gateway, day, and model. Returns the number of rows written.
+    ///
+    /// # Errors
+    /// Returns an error if any INSERT fails.
+    pub fn record_gateway_usage(
+        &self,
+        rows: &[GatewayUsage],
+        imported_at: DateTime<Utc>,
+    ) -> Result<usize> {
+        let mut stmt = self
+            .conn
+            .prepare_cached(
+                "INSERT INTO gateway_usage ( \
+                    gateway, day, model, requests, input_tokens, output_tokens, \
+                    cost_usd, imported_at \
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8) \
+                ON CONFLICT(gateway, day, model) DO UPDATE SET \
+                    requests = excluded.requests, \
+                    input_tokens = excluded.input_tokens, \
+                    output_tokens = excluded.output_tokens, \
+                    cost_usd = excluded.cost_usd, \
+                    imported_at = excluded.imported_at",
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("prepare gateway insert: {e}")))?;
+
+        for row in rows {
+            stmt.execute(params![
+                row.gateway,
+                row.day.format("%Y-%m-%d").to_string(),
+                row.model,
+                row.requests,
+                row.input_tokens,
+                row.output_tokens,
+                row.cost_usd,
+                imported_at.to_rfc3339(),
+            ])
+            .map_err(|e| CautError::Other(anyhow::anyhow!("insert gateway usage: {e}")))?;
+        }
+        Ok(rows.len())
+    }
+
+    /// Gateway rollups from `since` onwards, ordered by gateway, day, model.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn gateway_usage(&self, since: NaiveDate) -> Result<Vec<GatewayUsage>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached(
+                "SELECT gateway, day, model, requests, input_tokens, output_tokens, cost_usd \
+                FROM gateway_usage \
+                WHERE day >= ?1 \
+                ORDER BY gateway ASC, day ASC, model ASC",
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("prepare gateway usage: {e}")))?;
+
+        let rows = stmt
+            .query_map([since.format("%Y-%m-%d").to_string()], |row| {
+                let day: String = row.get(1)?;
+                let day = NaiveDate::parse_from_str(&day, "%Y-%m-%d").map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        1,
+                        rusqlite::types::Type::Text,
+                        Box::new(e),
+                    )
+                })?;
+                Ok(GatewayUsage {
+                    gateway: row.get(0)?,
+                    day,
+                    model: row.get(2)?,
+                    requests: row.get(3)?,
+                    input_tokens: row.get(4)?,
+                    output_tokens: row.get(5)?,
+                    cost_usd: row.get(6)?,
+                })
+            })
+            .map_err(|e| CautError::Other(anyhow::anyhow!("query gateway usage: {e}")))?;
+
+        let mut usage = Vec::new();
+        for row in rows {
+            usage.push(row.map_err(|e| CautError::Other(anyhow::anyhow!("map row: {e}")))?);
+        }
+        Ok(usage)
+    }
+
+    /// Daily cost per provider between `from` and `to`: the highest
+    /// `cost_today_usd` reported each UTC day, ordered by day and provider.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn daily_costs(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<DailyCost>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached(
+                "SELECT date(fetched_at) AS day, provider, MAX(cost_today_usd) \
+                FROM usage_snapshots \
+                WHERE fetched_at BETWEEN ?1 AND ?2 AND cost_today_usd IS NOT NULL \
+                GROUP BY day, provider \
+                ORDER BY day ASC, provider ASC",
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("prepare daily costs: {e}")))?;
+
+        let rows = stmt
+            .query_map(params![from.to_rfc3339(), to.to_rfc3339()], |row| {
+                let day: String = row.get(0)?;
+                let day = NaiveDate::parse_from_str(&day, "%Y-%m-%d").map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        0,
+                        rusqlite::types::Type::Text,
+                        Box::new(e),
+                    )
+                })?;
+                Ok(DailyCost {
+                    day,
+                    provider: row.get(1)?,
+                    cost_usd: row.get(2)?,
+                })
+            })
+            .map_err(|e| CautError::Other(anyhow::anyhow!("query daily costs: {e}")))?;
+
+        let mut costs = Vec::new();
+        for row in rows {
+            costs.push(row.map_err(|e| CautError::Other(anyhow::anyhow!("map row: {e}")))?);
+        }
+        Ok(costs)
+    }
+
+    /// All registered accounts, including inactive ones.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn accounts(&self) -> Result<Vec<Account>> {
+        MultiAccountDb::new(&self.conn).list_all_accounts(None)
+    }
+
+    /// Account registry and switch log operations on this database.
+    #[must_use]
+    pub const fn multi_account(&self) -> MultiAccountDb<'_> {
+        MultiAccountDb::new(&self.conn)
+    }
+
     /// Get snapshots for a provider within a time range.
     ///
     /// # Errors
@@ -249,7 +765,7 @@ impl HistoryStore {
                 secondary_used_pct, secondary_window_minutes, secondary_resets_at, \
                 tertiary_used_pct, tertiary_window_minutes, tertiary_resets_at, \
                 cost_today_usd, cost_mtd_usd, credits_remaining, \
-                account_email, account_org, fetch_duration_ms, created_at \
+                account_email, account_org, fetch_duration_ms, created_at, note \
             FROM usage_snapshots \
             WHERE provider = ?1 AND fetched_at BETWEEN ?2 AND ?3 \
             ORDER BY fetched_at DESC",
@@ -271,6 +787,93 @@ impl HistoryStore {
         Ok(snapshots)
     }
 
+    /// Query snapshots matching a filter, newest first.
+    ///
+    /// Each set filter adds a bound `WHERE` clause so the provider/time and
+    /// account indexes can be used.
+    ///
+    /// # Errors
+    /// Returns an error if the time range is invalid (`from > to`) or the query fails.
+    pub fn query_snapshots(&self, filter: &SnapshotFilter) -> Result<Vec<StoredSnapshot>> {
+        if let (Some(from), Some(to)) = (filter.from, filter.to)
+            && from > to
+        {
+            return Err(CautError::Config(
+                "Time range start must be before end".to_string(),
+            ));
+        }
+
+        let mut clauses: Vec<&str> = Vec::new();
+        let mut values: Vec<Box<dyn ToSql>> = Vec::new();
+
+        if let Some(provider) = &filter.provider {
+            clauses.push("provider = ?");
+            values.push(Box::new(provider.cli_name()));
+        }
+        if let Some(from) = filter.from {
+            clauses.push("fetched_at >= ?");
+            values.push(Box::new(from.to_rfc3339()));
+        }
+        if let Some(to) = filter.to {
+            clauses.push("fetched_at <= ?");
+            values.push(Box::new(to.to_rfc3339()));
+        }
+        if let Some(account) = &filter.account {
+            clauses.push(
+                "(account_email = ? COLLATE NOCASE OR account_id IN ( \
+                    SELECT id FROM accounts \
+                    WHERE label = ? COLLATE NOCASE OR email = ? COLLATE NOCASE))",
+            );
+            for _ in 0..3 {
+                values.push(Box::new(account.clone()));
+            }
+        }
+        if let Some(min_used) = filter.min_used_pct {
+            clauses.push(
+                "(primary_used_pct >= ? OR secondary_used_pct >= ? OR tertiary_used_pct >= ?)",
+            );
+            for _ in 0..3 {
+                values.push(Box::new(min_used));
+            }
+        }
+
+        let mut sql = String::from(
+            "SELECT \
+                id, provider, fetched_at, source, \
+                primary_used_pct, primary_window_minutes, primary_resets_at, \
+                secondary_used_pct, secondary_window_minutes, secondary_resets_at, \
+                tertiary_used_pct, tertiary_window_minutes, tertiary_resets_at, \
+                cost_today_usd, cost_mtd_usd, credits_remaining, \
+                account_email, account_org, fetch_duration_ms, created_at, note \
+            FROM usage_snapshots",
+        );
+        if !clauses.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&clauses.join(" AND "));
+        }
+        sql.push_str(" ORDER BY fetched_at DESC");
+        if let Some(limit) = filter.limit {
+            sql.push_str(" LIMIT ?");
+            values.push(Box::new(i64::try_from(limit).unwrap_or(i64::MAX)));
+        }
+
+        let mut stmt = self
+            .conn
+            .prepare(&sql)
+            .map_err(|e| CautError::Other(anyhow::anyhow!("prepare filtered select: {e}")))?;
+
+        let rows = stmt
+            .query_map(params_from_iter(values.iter()), map_row)
+            .map_err(|e| CautError::Other(anyhow::anyhow!("query snapshots: {e}")))?;
+
+        let mut snapshots = Vec::new();
+        for row in rows {
+            snapshots.push(row.map_err(|e| CautError::Other(anyhow::anyhow!("map row: {e}")))?);
+        }
+
+        Ok(snapshots)
+    }
+
     /// Get the latest snapshot for each provider.
     ///
     /// # Errors
@@ -285,7 +888,7 @@ impl HistoryStore {
                 secondary_used_pct, secondary_window_minutes, secondary_resets_at, \
                 tertiary_used_pct, tertiary_window_minutes, tertiary_resets_at, \
                 cost_today_usd, cost_mtd_usd, credits_remaining, \
-                account_email, account_org, fetch_duration_ms, created_at \
+                account_email, account_org, fetch_duration_ms, created_at, note \
             FROM usage_snapshots \
             ORDER BY fetched_at DESC",
             )
@@ -304,6 +907,37 @@ impl HistoryStore {
         Ok(latest)
     }
 
+    /// Get a provider's latest snapshot taken at or before `at`.
+    ///
+    /// # Errors
+    /// Returns an error if the SELECT query cannot be prepared or executed.
+    pub fn get_snapshot_at(
+        &self,
+        provider: &Provider,
+        at: DateTime<Utc>,
+    ) -> Result<Option<StoredSnapshot>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached(
+                "SELECT \
+                id, provider, fetched_at, source, \
+                primary_used_pct, primary_window_minutes, primary_resets_at, \
+                secondary_used_pct, secondary_window_minutes, secondary_resets_at, \
+                tertiary_used_pct, tertiary_window_minutes, tertiary_resets_at, \
+                cost_today_usd, cost_mtd_usd, credits_remaining, \
+                account_email, account_org, fetch_duration_ms, created_at, note \
+            FROM usage_snapshots \
+            WHERE provider = ?1 AND fetched_at <= ?2 \
+            ORDER BY fetched_at DESC \
+            LIMIT 1",
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("prepare select: {e}")))?;
+
+        stmt.query_row(params![provider.cli_name(), at.to_rfc3339()], map_row)
+            .optional()
+            .map_err(|e| CautError::Other(anyhow::anyhow!("query snapshot at: {e}")))
+    }
+
     /// Get usage velocity (% change per hour) over a recent window.
     ///
     /// # Errors
@@ -431,7 +1065,7 @@ impl HistoryStore {
         let current_size = if dry_run {
             initial_size
         } else {
-            self.get_db_size()?
+            self.live_db_size()?
         };
         if current_size > policy.max_size_bytes {
             result.size_limit_triggered = true;
@@ -476,6 +1110,93 @@ impl HistoryStore {
         self.prune(&RetentionPolicy::default(), dry_run)
     }
 
+    /// Preview a prune without changing anything.
+    ///
+    /// Counts the rows each table would lose to the age cutoffs and estimates
+    /// how many further snapshots the size limit would take.
+    ///
+    /// # Errors
+    /// Returns an error if the policy is invalid or any query fails.
+    pub fn prune_preview(&self, policy: &RetentionPolicy) -> Result<PrunePreview> {
+        policy.validate()?;
+        let now = Utc::now();
+        let cutoff = now - Duration::days(policy.detailed_retention_days);
+        let detailed_cutoff = cutoff.to_rfc3339();
+        let agg_cutoff = (now - Duration::days(policy.aggregate_retention_days))
+            .format("%Y-%m-%d")
+            .to_string();
+        let targets = [
+            ("usage_snapshots", "fetched_at", &detailed_cutoff),
+            ("local_model_stats", "fetched_at", &detailed_cutoff),
+            ("fetch_failures", "failed_at", &detailed_cutoff),
+            ("daily_aggregates", "date", &agg_cutoff),
+            ("gateway_usage", "day", &agg_cutoff),
+            ("imported_costs", "day", &agg_cutoff),
+        ];
+
+        let mut tables = Vec::with_capacity(targets.len());
+        let mut total_rows: u64 = 0;
+        for (table, column, cutoff) in targets {
+            let total: i64 = self
+                .conn
+                .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+                    row.get(0)
+                })
+                .map_err(|e| CautError::Other(anyhow::anyhow!("count {table}: {e}")))?;
+            total_rows += total.unsigned_abs();
+            let (rows, oldest, newest): (i64, Option<String>, Option<String>) = self
+                .conn
+                .query_row(
+                    &format!(
+                        "SELECT COUNT(*), MIN({column}), MAX({column}) \
+                         FROM {table} WHERE {column} < ?1"
+                    ),
+                    [cutoff],
+                    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+                )
+                .map_err(|e| CautError::Other(anyhow::anyhow!("preview {table}: {e}")))?;
+            tables.push(TablePrune {
+                table,
+                rows: usize::try_from(rows).unwrap_or(0),
+                oldest,
+                newest,
+            });
+        }
+
+        let aggregates_to_create = self.aggregate_old_snapshots(&cutoff, true)?;
+
+        // Rows vary little in size, so scale the live size by the row count.
+        let db_size_bytes = self.live_db_size()?;
+        let deleted_rows: usize = tables.iter().map(|t| t.rows).sum();
+        let bytes_per_row = if total_rows == 0 {
+            0
+        } else {
+            db_size_bytes / total_rows
+        };
+        let estimated_size_bytes =
+            db_size_bytes.saturating_sub(bytes_per_row * deleted_rows as u64);
+        let size_limit_snapshots =
+            if estimated_size_bytes > policy.max_size_bytes && bytes_per_row > 0 {
+                let excess_rows =
+                    (estimated_size_bytes - policy.max_size_bytes).div_ceil(bytes_per_row);
+                let remaining_snapshots = self
+                    .count_rows("usage_snapshots")?
+                    .unsigned_abs()
+                    .saturating_sub(tables[0].rows as u64);
+                usize::try_from(excess_rows.min(remaining_snapshots)).unwrap_or(usize::MAX)
+            } else {
+                0
+            };
+
+        Ok(PrunePreview {
+            tables,
+            aggregates_to_create,
+            db_size_bytes,
+            estimated_size_bytes,
+            size_limit_snapshots,
+        })
+    }
+
     /// Check if pruning is needed based on the interval.
     ///
     /// # Errors
@@ -500,6 +1221,127 @@ impl HistoryStore {
         }
     }
 
+    /// Store imported daily costs. Every provider/day in `rows` replaces
+    /// what was stored for that day before, from either tool, so overlapping
+    /// imports never count a day twice. Returns the number of rows written.
+    ///
+    /// # Errors
+    /// Returns an error if any DELETE or INSERT fails; nothing is written
+    /// then.
+    pub fn record_imported_costs(
+        &self,
+        rows: &[ImportedCost],
+        imported_at: DateTime<Utc>,
+    ) -> Result<usize> {
+        let tx = self
+            .conn
+            .unchecked_transaction()
+            .map_err(|e| CautError::Other(anyhow::anyhow!("begin cost import: {e}")))?;
+
+        let mut days: Vec<(&str, String)> = rows
+            .iter()
+            .map(|row| {
+                (
+                    row.provider.as_str(),
+                    row.day.format("%Y-%m-%d").to_string(),
+                )
+            })
+            .collect();
+        days.sort_unstable();
+        days.dedup();
+        for (provider, day) in &days {
+            tx.execute(
+                "DELETE FROM imported_costs WHERE provider = ?1 AND day = ?2",
+                params![provider, day],
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("delete imported costs: {e}")))?;
+        }
+
+        {
+            let mut stmt = tx
+                .prepare_cached(
+                    "INSERT INTO imported_costs ( \
+                        provider, day, model, input_tokens, output_tokens, \
+                        cache_read_tokens, cache_creation_tokens, cost_usd, source, imported_at \
+                    ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10) \
+                    ON CONFLICT(provider, day, model) DO UPDATE SET \
+                        input_tokens = COALESCE(input_tokens, 0) + COALESCE(excluded.input_tokens, 0), \
+                        output_tokens = COALESCE(output_tokens, 0) + COALESCE(excluded.output_tokens, 0), \
+                        cache_read_tokens = COALESCE(cache_read_tokens, 0) + COALESCE(excluded.cache_read_tokens, 0), \
+                        cache_creation_tokens = COALESCE(cache_creation_tokens, 0) + COALESCE(excluded.cache_creation_tokens, 0), \
+                        cost_usd = cost_usd + excluded.cost_usd",
+                )
+                .map_err(|e| CautError::Other(anyhow::anyhow!("prepare cost import: {e}")))?;
+            for row in rows {
+                stmt.execute(params![
+                    row.provider,
+                    row.day.format("%Y-%m-%d").to_string(),
+                    row.model,
+                    row.input_tokens,
+                    row.output_tokens,
+                    row.cache_read_tokens,
+                    row.cache_creation_tokens,
+                    row.cost_usd,
+                    row.source,
+                    imported_at.to_rfc3339(),
+                ])
+                .map_err(|e| CautError::Other(anyhow::anyhow!("insert imported cost: {e}")))?;
+            }
+        }
+
+        tx.commit()
+            .map_err(|e| CautError::Other(anyhow::anyhow!("commit cost import: {e}")))?;
+        Ok(rows.len())
+    }
+
+    /// Imported daily costs from `since` onwards, ordered by provider, day,
+    /// model.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn imported_costs(&self, since: NaiveDate) -> Result<Vec<ImportedCost>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached(
+                "SELECT provider, day, model, input_tokens, output_tokens, \
+                    cache_read_tokens, cache_creation_tokens, cost_usd, source \
+                FROM imported_costs \
+                WHERE day >= ?1 \
+                ORDER BY provider ASC, day ASC, model ASC",
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("prepare imported costs: {e}")))?;
+
+        let rows = stmt
+            .query_map([since.format("%Y-%m-%d").to_string()], |row| {
+                let day: String = row.get(1)?;
+                let day = NaiveDate::parse_from_str(&day, "%Y-%m-%d").map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        1,
+                        rusqlite::types::Type::Text,
+                        Box::new(e),
+                    )
+                })?;
+                Ok(ImportedCost {
+                    provider: row.get(0)?,
+                    day,
+                    model: row.get(2)?,
+                    input_tokens: row.get(3)?,
+                    output_tokens: row.get(4)?,
+                    cache_read_tokens: row.get(5)?,
+                    cache_creation_tokens: row.get(6)?,
+                    cost_usd: row.get(7)?,
+                    source: row.get(8)?,
+                })
+            })
+            .map_err(|e| CautError::Other(anyhow::anyhow!("query imported costs: {e}")))?;
+
+        let mut costs = Vec::new();
+        for row in rows {
+            costs.push(row.map_err(|e| CautError::Other(anyhow::anyhow!("map row: {e}")))?);
+        }
+        Ok(costs)
+    }
+
     /// Count rows in a specified table.
     ///
     /// # Errors
@@ -510,6 +1352,8 @@ impl HistoryStore {
             "usage_snapshots",
             "daily_aggregates",
             "prune_history",
+            "gateway_usage",
+            "imported_costs",
             "schema_migrations",
         ];
         if !valid_tables.contains(&table) {
@@ -544,6 +1388,23 @@ impl HistoryStore {
         Ok((page_count * page_size) as u64)
     }
 
+    /// Bytes used by live pages.
+    ///
+    /// Unlike [`Self::get_db_size`] this shrinks as soon as rows are deleted,
+    /// without waiting for a VACUUM to return free pages to the filesystem.
+    fn live_db_size(&self) -> Result<u64> {
+        let free_pages: i64 = self
+            .conn
+            .query_row("PRAGMA freelist_count", [], |row| row.get(0))
+            .map_err(|e| CautError::Other(anyhow::anyhow!("freelist_count: {e}")))?;
+        let page_size: i64 = self
+            .conn
+            .query_row("PRAGMA page_size", [], |row| row.get(0))
+            .map_err(|e| CautError::Other(anyhow::anyhow!("page_size: {e}")))?;
+        let total = self.get_db_size()?;
+        Ok(total.saturating_sub((free_pages * page_size).unsigned_abs()))
+    }
+
     /// Get the last prune timestamp.
     fn get_last_prune_time(&self) -> Option<DateTime<Utc>> {
         let result: Option<String> = self
@@ -714,6 +1575,20 @@ impl HistoryStore {
                 [cutoff.to_rfc3339()],
             )
             .map_err(|e| CautError::Other(anyhow::anyhow!("delete snapshots: {e}")))?;
+        // Throughput samples and failed fetches share the detailed retention
+        // window.
+        self.conn
+            .execute(
+                "DELETE FROM local_model_stats WHERE fetched_at < ?1",
+                [cutoff.to_rfc3339()],
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("delete local model stats: {e}")))?;
+        self.conn
+            .execute(
+                "DELETE FROM fetch_failures WHERE failed_at < ?1",
+                [cutoff.to_rfc3339()],
+            )
+            .map_err(|e| CautError::Other(anyhow::anyhow!("delete fetch failures: {e}")))?;
         Ok(deleted)
     }
 
@@ -739,9 +1614,16 @@ impl HistoryStore {
             .conn
             .execute(
                 "DELETE FROM daily_aggregates WHERE date < ?1",
-                [cutoff_date],
+                [&cutoff_date],
             )
             .map_err(|e| CautError::Other(anyhow::anyhow!("delete aggregates: {e}")))?;
+        // Gateway and cost history imports are daily rollups too.
+        self.conn
+            .execute("DELETE FROM gateway_usage WHERE day < ?1", [&cutoff_date])
+            .map_err(|e| CautError::Other(anyhow::anyhow!("delete gateway usage: {e}")))?;
+        self.conn
+            .execute("DELETE FROM imported_costs WHERE day < ?1", [&cutoff_date])
+            .map_err(|e| CautError::Other(anyhow::anyhow!("delete imported costs: {e}")))?;
         Ok(deleted)
     }
 
@@ -765,7 +1647,7 @@ impl HistoryStore {
     fn enforce_size_limit(&self, max_bytes: u64) -> Result<()> {
         // Delete oldest snapshots in batches until under limit
         loop {
-            let current_size = self.get_db_size()?;
+            let current_size = self.live_db_size()?;
             if current_size <= max_bytes {
                 break;
             }
@@ -856,6 +1738,27 @@ pub struct StoredSnapshot {
 
     pub fetch_duration_ms: Option<i64>,
     pub created_at: Option<DateTime<Utc>>,
+    /// Annotation given to `caut snapshot --note`.
+    pub note: Option<String>,
+}
+
+/// A provider's cost for one day, from recorded snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyCost {
+    pub day: NaiveDate,
+    pub provider: String,
+    pub cost_usd: f64,
+}
+
+/// One recorded local model throughput measurement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThroughputSample {
+    pub model: String,
+    pub fetched_at: DateTime<Utc>,
+    /// Prompt processing speed, in tokens per second.
+    pub prompt_tokens_per_sec: Option<f64>,
+    /// Generation speed, in tokens per second.
+    pub eval_tokens_per_sec: Option<f64>,
 }
 
 /// Aggregated usage statistics.
@@ -958,6 +1861,7 @@ fn map_row(row: &Row<'_>) -> rusqlite::Result<StoredSnapshot> {
 
         fetch_duration_ms: row.get(18)?,
         created_at: parse_optional_timestamp(row.get(19)?),
+        note: row.get(20)?,
     })
 }
 
@@ -1049,6 +1953,40 @@ mod tests {
         assert_eq!(stored.source, "unknown");
     }
 
+    #[test]
+    fn manual_snapshot_keeps_trigger_and_note() {
+        let store = open_temp_store();
+        let now = Utc::now();
+
+        store
+            .record_snapshot(
+                &make_snapshot(now - Duration::minutes(5), 10.0),
+                &Provider::Claude,
+            )
+            .expect("record periodic");
+        store
+            .record_snapshot_as(
+                &make_snapshot(now, 20.0),
+                &Provider::Claude,
+                SnapshotTrigger::Manual,
+                Some("before refactor"),
+            )
+            .expect("record manual");
+
+        let triggers: Vec<String> = store
+            .conn
+            .prepare("SELECT trigger_type FROM usage_snapshots ORDER BY fetched_at")
+            .and_then(|mut stmt| stmt.query_map([], |row| row.get(0))?.collect())
+            .expect("query triggers");
+        assert_eq!(triggers, ["periodic", "manual"]);
+
+        let latest = store.get_latest_all().expect("latest");
+        assert_eq!(
+            latest[&Provider::Claude].note.as_deref(),
+            Some("before refactor")
+        );
+    }
+
     #[test]
     fn latest_snapshot_per_provider() {
         let store = open_temp_store();
@@ -1073,6 +2011,161 @@ mod tests {
         assert_eq!(latest[&Provider::Claude].primary_used_pct, Some(30.0));
     }
 
+    #[test]
+    fn plan_limit_changes_are_recorded_once() {
+        let store = open_temp_store();
+        let now = Utc::now();
+        let with_allowance = |at: DateTime<Utc>, allowance: u32| {
+            let mut snapshot = make_snapshot(at, 10.0);
+            snapshot.primary.as_mut().unwrap().reset_description =
+                Some(format!("50/{allowance} premium requests"));
+            snapshot
+        };
+
+        let first = store
+            .record_plan_limits(
+                &with_allowance(now - Duration::days(2), 500),
+                &Provider::Cursor,
+            )
+            .expect("record first");
+        assert!(first.is_empty());
+        // A window without the allowance in its description keeps the stored limit.
+        store
+            .record_plan_limits(
+                &make_snapshot(now - Duration::days(1), 10.0),
+                &Provider::Cursor,
+            )
+            .expect("record partial");
+
+        let changed = store
+            .record_plan_limits(&with_allowance(now, 300), &Provider::Cursor)
+            .expect("record changed");
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].old.limit, Some(500.0));
+        assert_eq!(changed[0].new.limit, Some(300.0));
+        assert!(
+            store
+                .record_plan_limits(&with_allowance(now, 300), &Provider::Cursor)
+                .expect("record unchanged")
+                .is_empty()
+        );
+
+        let stored = store
+            .plan_limit_changes(now - Duration::days(7))
+            .expect("query changes");
+        assert_eq!(stored, changed);
+        assert!(
+            store
+                .plan_limit_changes(now + Duration::minutes(1))
+                .expect("query later")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn query_snapshots_applies_filters() {
+        let store = open_temp_store();
+        let now = Utc::now();
+
+        store
+            .record_snapshot(
+                &make_snapshot(now - Duration::hours(3), 85.0),
+                &Provider::Claude,
+            )
+            .expect("record old claude");
+        store
+            .record_snapshot(
+                &make_snapshot(now - Duration::hours(1), 40.0),
+                &Provider::Claude,
+            )
+            .expect("record recent claude");
+        store
+            .record_snapshot(&make_snapshot(now, 95.0), &Provider::Codex)
+            .expect("record codex");
+
+        let all = store
+            .query_snapshots(&SnapshotFilter::default())
+            .expect("query all");
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].provider, Provider::Codex);
+
+        let heavy_claude = store
+            .query_snapshots(&SnapshotFilter {
+                provider: Some(Provider::Claude),
+                min_used_pct: Some(80.0),
+                ..SnapshotFilter::default()
+            })
+            .expect("query heavy claude");
+        assert_eq!(heavy_claude.len(), 1);
+        assert_eq!(heavy_claude[0].primary_used_pct, Some(85.0));
+
+        let recent = store
+            .query_snapshots(&SnapshotFilter {
+                from: Some(now - Duration::hours(2)),
+                to: Some(now),
+                limit: Some(1),
+                ..SnapshotFilter::default()
+            })
+            .expect("query recent");
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].provider, Provider::Codex);
+    }
+
+    #[test]
+    fn query_snapshots_matches_account_email_or_label() {
+        let store = open_temp_store();
+        let now = Utc::now();
+
+        let id = store
+            .record_snapshot(&make_snapshot(now, 50.0), &Provider::Claude)
+            .expect("record snapshot");
+        store
+            .conn
+            .execute(
+                "INSERT INTO accounts (id, provider, email, label, added_at) \
+                 VALUES ('acct-1', 'claude', 'user@example.com', 'work', ?1)",
+                params![now.to_rfc3339()],
+            )
+            .expect("insert account");
+        store
+            .conn
+            .execute(
+                "UPDATE usage_snapshots SET account_id = 'acct-1' WHERE id = ?1",
+                params![id],
+            )
+            .expect("link account");
+
+        for account in ["USER@example.com", "Work"] {
+            let matched = store
+                .query_snapshots(&SnapshotFilter {
+                    account: Some(account.to_string()),
+                    ..SnapshotFilter::default()
+                })
+                .expect("query by account");
+            assert_eq!(matched.len(), 1, "account filter {account}");
+        }
+
+        let none = store
+            .query_snapshots(&SnapshotFilter {
+                account: Some("personal".to_string()),
+                ..SnapshotFilter::default()
+            })
+            .expect("query unknown account");
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn query_snapshots_rejects_inverted_range() {
+        let store = open_temp_store();
+        let now = Utc::now();
+        let result = store.query_snapshots(&SnapshotFilter {
+            from: Some(now),
+            to: Some(now - Duration::hours(1)),
+            ..SnapshotFilter::default()
+        });
+        assert!(result.is_err());
+    }
+
     #[test]
     fn velocity_computation() {
         let store = open_temp_store();
@@ -1181,6 +2274,32 @@ mod tests {
         assert!((agg_row - 50.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn prune_preview_counts_rows_without_deleting() {
+        let store = open_temp_store();
+        let now = Utc::now();
+        for days in [10, 9, 1] {
+            store
+                .record_snapshot(
+                    &make_snapshot(now - Duration::days(days), 40.0),
+                    &Provider::Claude,
+                )
+                .expect("record");
+        }
+
+        let policy = RetentionPolicy::default().with_detailed_days(5);
+        let preview = store.prune_preview(&policy).expect("preview");
+
+        let snapshots = &preview.tables[0];
+        assert_eq!(snapshots.table, "usage_snapshots");
+        assert_eq!(snapshots.rows, 2);
+        assert!(snapshots.oldest < snapshots.newest);
+        assert_eq!(preview.aggregates_to_create, 2);
+        assert_eq!(preview.size_limit_snapshots, 0);
+        assert_eq!(store.count_rows("usage_snapshots").expect("count"), 3);
+        assert_eq!(store.count_rows("daily_aggregates").expect("count"), 0);
+    }
+
     #[test]
     fn maybe_prune_respects_interval() {
         let store = open_temp_store();
@@ -1209,4 +2328,163 @@ mod tests {
         let result3 = store.maybe_prune(&policy).expect("maybe prune 3");
         assert!(result3.is_some());
     }
+
+    #[test]
+    fn throughput_samples_round_trip_and_prune() {
+        let store = open_temp_store();
+        let now = Utc::now();
+        let stats = LocalModelStats {
+            model: "qwen2.5-coder:7b".to_string(),
+            loaded_models: vec!["qwen2.5-coder:7b".to_string()],
+            prompt_tokens: 12,
+            eval_tokens: 16,
+            prompt_tokens_per_sec: Some(310.0),
+            eval_tokens_per_sec: Some(42.5),
+        };
+        store
+            .record_local_model_stats(&stats, &Provider::Ollama, now - Duration::days(40))
+            .expect("record old sample");
+        store
+            .record_local_model_stats(&stats, &Provider::Ollama, now)
+            .expect("record sample");
+
+        let samples = store
+            .throughput_samples(&Provider::Ollama, now - Duration::days(60))
+            .expect("query samples");
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[1].model, "qwen2.5-coder:7b");
+        assert_eq!(samples[1].eval_tokens_per_sec, Some(42.5));
+
+        store.prune_default(false).expect("prune");
+        let samples = store
+            .throughput_samples(&Provider::Ollama, now - Duration::days(60))
+            .expect("query samples");
+        assert_eq!(samples.len(), 1);
+    }
+
+    #[test]
+    fn fetch_failures_are_counted_per_provider() {
+        let store = open_temp_store();
+        let now = Utc::now();
+        for at in [now - Duration::days(40), now, now] {
+            store
+                .record_fetch_failure(&Provider::Claude, "CAUT-N002", at)
+                .expect("record failure");
+        }
+
+        let counts = store
+            .fetch_failure_counts(now - Duration::days(7))
+            .expect("count failures");
+        assert_eq!(counts.get(&Provider::Claude), Some(&2));
+        assert_eq!(counts.get(&Provider::Codex), None);
+    }
+
+    #[test]
+    fn gateway_usage_reimport_replaces_rows() {
+        let store = open_temp_store();
+        let day = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let mut row = GatewayUsage {
+            gateway: "helicone".to_string(),
+            day,
+            model: "gpt-4o".to_string(),
+            requests: 10,
+            input_tokens: 5_000,
+            output_tokens: 1_000,
+            cost_usd: 0.0225,
+        };
+        store
+            .record_gateway_usage(std::slice::from_ref(&row), Utc::now())
+            .expect("first import");
+        row.requests = 12;
+        row.cost_usd = 0.03;
+        store
+            .record_gateway_usage(std::slice::from_ref(&row), Utc::now())
+            .expect("second import");
+
+        let usage = store.gateway_usage(day).expect("query gateway usage");
+        assert_eq!(usage, [row]);
+        assert!(
+            store
+                .gateway_usage(day.succ_opt().unwrap())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn imported_costs_replace_overlapping_days() {
+        let store = open_temp_store();
+        let day = |d| NaiveDate::from_ymd_opt(2026, 9, d).unwrap();
+        let row = |d, model: &str, cost_usd, source: &str| ImportedCost {
+            provider: "claude".to_string(),
+            day: day(d),
+            model: model.to_string(),
+            input_tokens: Some(1_000),
+            output_tokens: Some(200),
+            cache_read_tokens: None,
+            cache_creation_tokens: None,
+            cost_usd,
+            source: source.to_string(),
+        };
+        store
+            .record_imported_costs(
+                &[
+                    row(1, "claude-opus-4", 4.0, "ccusage"),
+                    row(2, "claude-opus-4", 3.0, "ccusage"),
+                    row(2, "claude-sonnet-4", 0.5, "ccusage"),
+                ],
+                Utc::now(),
+            )
+            .expect("ccusage import");
+        // CodexBar covers day 2 again, without a model breakdown.
+        let codexbar = row(2, "", 3.25, "codexbar");
+        store
+            .record_imported_costs(std::slice::from_ref(&codexbar), Utc::now())
+            .expect("codexbar import");
+
+        let costs = store.imported_costs(day(1)).expect("query imported costs");
+        assert_eq!(costs, [row(1, "claude-opus-4", 4.0, "ccusage"), codexbar]);
+        assert!(store.imported_costs(day(3)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn daily_costs_keep_the_highest_cost_per_provider_day() {
+        let store = open_temp_store();
+        for (provider, fetched_at, cost) in [
+            ("claude", "2026-10-14T09:00:00+00:00", Some(1.5)),
+            ("claude", "2026-10-14T18:00:00+00:00", Some(4.25)),
+            ("claude", "2026-10-15T08:00:00+00:00", Some(0.75)),
+            ("codex", "2026-10-14T12:00:00+00:00", None),
+        ] {
+            store
+                .conn
+                .execute(
+                    "INSERT INTO usage_snapshots (provider, fetched_at, source, cost_today_usd) \
+                    VALUES (?1, ?2, 'oauth', ?3)",
+                    params![provider, fetched_at, cost],
+                )
+                .expect("insert snapshot");
+        }
+
+        let from = Utc.with_ymd_and_hms(2026, 10, 14, 0, 0, 0).unwrap();
+        let costs = store
+            .daily_costs(from, from + Duration::days(2))
+            .expect("query daily costs");
+        let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        assert_eq!(
+            costs,
+            [
+                DailyCost {
+                    day: day(14),
+                    provider: "claude".to_string(),
+                    cost_usd: 4.25,
+                },
+                DailyCost {
+                    day: day(15),
+                    provider: "claude".to_string(),
+                    cost_usd: 0.75,
+                },
+            ]
+        );
+    }
 }
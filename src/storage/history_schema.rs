@@ -9,7 +9,8 @@ use rusqlite::Connection;
 
 use crate::error::{CautError, Result};
 
-const HISTORY_MIGRATIONS: &[Migration] = &[
+/// Schema migrations for the usage history database, in version order.
+pub const HISTORY_MIGRATIONS: &[Migration] = &[
     Migration {
         version: 1,
         sql: include_str!("../../migrations/001_usage_snapshots.sql"),
@@ -22,6 +23,34 @@ const HISTORY_MIGRATIONS: &[Migration] = &[
         version: 3,
         sql: include_str!("../../migrations/003_multi_account.sql"),
     },
+    Migration {
+        version: 4,
+        sql: include_str!("../../migrations/004_history_filters.sql"),
+    },
+    Migration {
+        version: 5,
+        sql: include_str!("../../migrations/005_plan_limits.sql"),
+    },
+    Migration {
+        version: 6,
+        sql: include_str!("../../migrations/006_local_model_stats.sql"),
+    },
+    Migration {
+        version: 7,
+        sql: include_str!("../../migrations/007_gateway_usage.sql"),
+    },
+    Migration {
+        version: 8,
+        sql: include_str!("../../migrations/008_imported_costs.sql"),
+    },
+    Migration {
+        version: 9,
+        sql: include_str!("../../migrations/009_fetch_failures.sql"),
+    },
+    Migration {
+        version: 10,
+        sql: include_str!("../../migrations/010_snapshot_notes.sql"),
+    },
 ];
 
 /// Default retention window for usage history.
@@ -81,10 +110,13 @@ pub fn cleanup_old_snapshots(conn: &Connection, retention_days: i64) -> Result<u
     Ok(deleted)
 }
 
+/// One schema change, recorded in `schema_migrations` once applied.
 #[derive(Debug, Clone, Copy)]
-struct Migration {
-    version: i32,
-    sql: &'static str,
+pub struct Migration {
+    /// Schema version after this migration.
+    pub version: i32,
+    /// SQL applied in one batch.
+    pub sql: &'static str,
 }
 
 fn ensure_schema_migrations_table(conn: &Connection) -> Result<()> {
@@ -155,7 +187,7 @@ mod tests {
         let mut conn = open_in_memory();
         let version = run_migrations(&mut conn).expect("run migrations");
 
-        assert_eq!(version, 3);
+        assert_eq!(version, 10);
 
         let table_exists: i32 = conn
             .query_row(
@@ -182,15 +214,15 @@ mod tests {
         let version_first = run_migrations(&mut conn).expect("first run");
         let version_second = run_migrations(&mut conn).expect("second run");
 
-        assert_eq!(version_first, 3);
-        assert_eq!(version_second, 3);
+        assert_eq!(version_first, 10);
+        assert_eq!(version_second, 10);
 
         let count: i32 = conn
             .query_row("SELECT COUNT(*) FROM schema_migrations", [], |row| {
                 row.get(0)
             })
             .expect("count migrations");
-        assert_eq!(count, 3);
+        assert_eq!(count, 10);
     }
 
     #[test]
@@ -2,10 +2,15 @@
 
 pub mod cache;
 pub mod config;
+pub mod config_schema;
 pub mod history;
 pub mod history_schema;
 pub mod multi_account;
+pub mod parquet;
 pub mod paths;
+pub mod private_file;
+pub mod remote_config;
+pub mod secrets;
 pub mod token_accounts;
 
 pub use cache::{CacheSource, CacheStaleness, OfflineCache, OfflineCacheConfig, OfflineCacheEntry};
@@ -15,8 +20,9 @@ pub use config::{
 };
 pub use history::{
     DEFAULT_AGGREGATE_RETENTION_DAYS, DEFAULT_DETAILED_RETENTION_DAYS, DEFAULT_MAX_SIZE_BYTES,
-    DEFAULT_PRUNE_INTERVAL_HOURS, HistoryStore, PruneResult, RetentionPolicy, StatsPeriod,
-    StoredSnapshot, UsageStats,
+    DEFAULT_PRUNE_INTERVAL_HOURS, DailyCost, HistoryStore, PrunePreview, PruneResult,
+    RetentionPolicy, SnapshotFilter, StatsPeriod, StoredSnapshot, TablePrune, ThroughputSample,
+    UsageStats,
 };
 pub use history_schema::{DEFAULT_RETENTION_DAYS, cleanup_old_snapshots, run_migrations};
 pub use multi_account::{
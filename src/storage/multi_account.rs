@@ -700,7 +700,8 @@ impl<'a> MultiAccountDb<'a> {
 
     // ===== Switch Log =====
 
-    /// Log an account switch.
+    /// Log an account switch. `rollback` marks a switch back to the account
+    /// an earlier switch left.
     ///
     /// # Errors
     /// Returns an error if the INSERT into the switch log fails.
@@ -713,12 +714,13 @@ impl<'a> MultiAccountDb<'a> {
         trigger: SwitchTrigger,
         trigger_details: Option<&str>,
         success: bool,
+        rollback: bool,
         error_message: Option<&str>,
     ) -> Result<i64> {
         self.conn
             .execute(
-                r"INSERT INTO switch_log (timestamp, provider, from_account_id, to_account_id, trigger_type, trigger_details, success, error_message)
-                  VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                r"INSERT INTO switch_log (timestamp, provider, from_account_id, to_account_id, trigger_type, trigger_details, success, rollback, error_message)
+                  VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
                 params![
                     Utc::now().to_rfc3339(),
                     provider,
@@ -727,6 +729,7 @@ impl<'a> MultiAccountDb<'a> {
                     trigger.as_str(),
                     trigger_details,
                     success,
+                    rollback,
                     error_message,
                 ],
             )
@@ -778,6 +781,36 @@ impl<'a> MultiAccountDb<'a> {
         Ok(entries)
     }
 
+    /// The most recent successful switch for a provider.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn last_switch(&self, provider: &str) -> Result<Option<SwitchLogEntry>> {
+        self.conn
+            .query_row(
+                r"SELECT id, timestamp, provider, from_account_id, to_account_id, trigger_type, trigger_details, success, rollback, error_message
+                  FROM switch_log WHERE provider = ?1 AND success = 1
+                  ORDER BY timestamp DESC, id DESC LIMIT 1",
+                [provider],
+                |row| {
+                    Ok(SwitchLogEntry {
+                        id: row.get(0)?,
+                        timestamp: parse_datetime(&row.get::<_, String>(1)?),
+                        provider: row.get(2)?,
+                        from_account_id: row.get(3)?,
+                        to_account_id: row.get(4)?,
+                        trigger_type: row.get(5)?,
+                        trigger_details: row.get(6)?,
+                        success: row.get(7)?,
+                        rollback: row.get(8)?,
+                        error_message: row.get(9)?,
+                    })
+                },
+            )
+            .optional()
+            .map_err(|e| CautError::Other(anyhow::anyhow!("last switch: {e}")))
+    }
+
     // ===== Provider Health =====
 
     /// Get or create provider health record.
@@ -1482,6 +1515,7 @@ mod tests {
                 SwitchTrigger::Threshold,
                 Some(r#"{"threshold": 90}"#),
                 true,
+                false,
                 None,
             )
             .expect("log switch");
@@ -1493,6 +1527,58 @@ mod tests {
         assert_eq!(log[0].provider, "claude");
         assert_eq!(log[0].trigger_type, "threshold");
         assert!(log[0].success);
+        assert!(!log[0].rollback);
+    }
+
+    #[test]
+    fn test_last_switch_skips_failures_and_keeps_rollback_flag() {
+        let conn = open_test_db();
+        let db = MultiAccountDb::new(&conn);
+
+        let personal = Account::new("claude", "personal");
+        let work = Account::new("claude", "work");
+        db.insert_account(&personal).expect("insert personal");
+        db.insert_account(&work).expect("insert work");
+        assert!(db.last_switch("claude").expect("no switches").is_none());
+
+        db.log_switch(
+            "claude",
+            Some(&personal.id),
+            &work.id,
+            SwitchTrigger::Manual,
+            None,
+            true,
+            false,
+            None,
+        )
+        .expect("log switch");
+        db.log_switch(
+            "claude",
+            Some(&work.id),
+            &personal.id,
+            SwitchTrigger::Manual,
+            None,
+            true,
+            true,
+            None,
+        )
+        .expect("log rollback");
+        db.log_switch(
+            "claude",
+            Some(&personal.id),
+            &work.id,
+            SwitchTrigger::Manual,
+            None,
+            false,
+            false,
+            Some("disk full"),
+        )
+        .expect("log failure");
+
+        let last = db.last_switch("claude").expect("query").expect("a switch");
+        assert_eq!(last.to_account_id, personal.id);
+        assert!(last.rollback);
+        assert!(db.last_switch("codex").expect("query").is_none());
     }
 
     #[test]
@@ -0,0 +1,412 @@
+//! Minimal Parquet writer for flat tables (`caut export --format parquet`).
+//!
+//! Writes a single row group with one uncompressed, PLAIN-encoded data page
+//! per column. Every column is OPTIONAL, with nulls carried in RLE
+//! definition levels. That is all a history export needs, and pandas,
+//! Polars, DuckDB, and Spark read it like any other Parquet file without
+//! caut depending on the Arrow stack.
+//!
+//! Metadata is Thrift compact-protocol encoded, as the format requires; see
+//! <https://github.com/apache/parquet-format>.
+
+use crate::error::{CautError, Result};
+
+const MAGIC: &[u8; 4] = b"PAR1";
+
+// parquet.thrift enum values.
+const TYPE_INT64: i64 = 2;
+const TYPE_DOUBLE: i64 = 5;
+const TYPE_BYTE_ARRAY: i64 = 6;
+const REPETITION_OPTIONAL: i64 = 1;
+const CONVERTED_UTF8: i64 = 0;
+const ENCODING_PLAIN: i64 = 0;
+const ENCODING_RLE: i64 = 3;
+const CODEC_UNCOMPRESSED: i64 = 0;
+const PAGE_DATA: i64 = 0;
+
+/// Values of one column; `None` is a null.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnData {
+    Int64(Vec<Option<i64>>),
+    Double(Vec<Option<f64>>),
+    Utf8(Vec<Option<String>>),
+}
+
+impl ColumnData {
+    /// Number of rows, nulls included.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        match self {
+            Self::Int64(values) => values.len(),
+            Self::Double(values) => values.len(),
+            Self::Utf8(values) => values.len(),
+        }
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    const fn physical_type(&self) -> i64 {
+        match self {
+            Self::Int64(_) => TYPE_INT64,
+            Self::Double(_) => TYPE_DOUBLE,
+            Self::Utf8(_) => TYPE_BYTE_ARRAY,
+        }
+    }
+
+    fn present(&self) -> Vec<bool> {
+        match self {
+            Self::Int64(values) => values.iter().map(Option::is_some).collect(),
+            Self::Double(values) => values.iter().map(Option::is_some).collect(),
+            Self::Utf8(values) => values.iter().map(Option::is_some).collect(),
+        }
+    }
+
+    /// Non-null values, PLAIN encoded.
+    fn plain_values(&self) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+        match self {
+            Self::Int64(values) => {
+                for v in values.iter().flatten() {
+                    out.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+            Self::Double(values) => {
+                for v in values.iter().flatten() {
+                    out.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+            Self::Utf8(values) => {
+                for v in values.iter().flatten() {
+                    let len = u32::try_from(v.len()).map_err(|_| too_large("string value"))?;
+                    out.extend_from_slice(&len.to_le_bytes());
+                    out.extend_from_slice(v.as_bytes());
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// A named column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub name: String,
+    pub data: ColumnData,
+}
+
+impl Column {
+    #[must_use]
+    pub fn new(name: &str, data: ColumnData) -> Self {
+        Self {
+            name: name.to_string(),
+            data,
+        }
+    }
+}
+
+/// Encode columns of equal length as a Parquet file.
+///
+/// # Errors
+/// Returns an error if the columns differ in length or a page exceeds the
+/// format's 2 GiB limit.
+pub fn write_table(columns: &[Column]) -> Result<Vec<u8>> {
+    let num_rows = columns.first().map_or(0, |c| c.data.len());
+    if columns.iter().any(|c| c.data.len() != num_rows) {
+        return Err(CautError::Other(anyhow::anyhow!(
+            "parquet columns must have the same length"
+        )));
+    }
+
+    let mut out = MAGIC.to_vec();
+    // (data page offset, column chunk size) per column
+    let mut chunks = Vec::with_capacity(columns.len());
+    if num_rows > 0 {
+        for column in columns {
+            let offset = out.len();
+            let page = data_page(&column.data)?;
+            out.extend_from_slice(&page);
+            chunks.push((offset, page.len()));
+        }
+    }
+
+    let metadata = file_metadata(columns, num_rows, &chunks)?;
+    let metadata_len = u32::try_from(metadata.len()).map_err(|_| too_large("file metadata"))?;
+    out.extend_from_slice(&metadata);
+    out.extend_from_slice(&metadata_len.to_le_bytes());
+    out.extend_from_slice(MAGIC);
+    Ok(out)
+}
+
+/// Page header followed by definition levels and values.
+fn data_page(data: &ColumnData) -> Result<Vec<u8>> {
+    let levels = rle_levels(&data.present());
+    let levels_len = u32::try_from(levels.len()).map_err(|_| too_large("definition levels"))?;
+    let mut body = levels_len.to_le_bytes().to_vec();
+    body.extend_from_slice(&levels);
+    body.extend_from_slice(&data.plain_values()?);
+    let body_len = size_i64(body.len())?;
+
+    let mut header = Compact::default();
+    header.i32(1, PAGE_DATA);
+    header.i32(2, body_len);
+    header.i32(3, body_len);
+    header.begin_struct(5);
+    header.i32(1, size_i64(data.len())?);
+    header.i32(2, ENCODING_PLAIN);
+    header.i32(3, ENCODING_RLE);
+    header.i32(4, ENCODING_RLE);
+    header.end_struct();
+    header.stop();
+
+    let mut page = header.buf;
+    page.extend_from_slice(&body);
+    Ok(page)
+}
+
+/// RLE/bit-packed hybrid encoding of 1-bit definition levels, as plain runs.
+fn rle_levels(present: &[bool]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut rest = present;
+    while let Some(&first) = rest.first() {
+        let run = rest.iter().take_while(|&&p| p == first).count();
+        varint(&mut out, (run as u64) << 1);
+        out.push(u8::from(first));
+        rest = &rest[run..];
+    }
+    out
+}
+
+fn file_metadata(
+    columns: &[Column],
+    num_rows: usize,
+    chunks: &[(usize, usize)],
+) -> Result<Vec<u8>> {
+    let mut meta = Compact::default();
+    meta.i32(1, 1);
+
+    meta.list(2, TYPE_STRUCT, columns.len() + 1);
+    meta.begin_element();
+    meta.binary(4, b"schema");
+    meta.i32(5, size_i64(columns.len())?);
+    meta.end_struct();
+    for column in columns {
+        meta.begin_element();
+        meta.i32(1, column.data.physical_type());
+        meta.i32(3, REPETITION_OPTIONAL);
+        meta.binary(4, column.name.as_bytes());
+        if matches!(column.data, ColumnData::Utf8(_)) {
+            meta.i32(6, CONVERTED_UTF8);
+        }
+        meta.end_struct();
+    }
+
+    meta.i64(3, size_i64(num_rows)?);
+
+    meta.list(4, TYPE_STRUCT, usize::from(!chunks.is_empty()));
+    if !chunks.is_empty() {
+        meta.begin_element();
+        meta.list(1, TYPE_STRUCT, columns.len());
+        for (column, &(offset, size)) in columns.iter().zip(chunks) {
+            let offset = size_i64(offset)?;
+            let size = size_i64(size)?;
+            meta.begin_element();
+            meta.i64(2, offset);
+            meta.begin_struct(3);
+            meta.i32(1, column.data.physical_type());
+            meta.list(2, TYPE_I32, 2);
+            meta.list_i32(ENCODING_PLAIN);
+            meta.list_i32(ENCODING_RLE);
+            meta.list(3, TYPE_BINARY, 1);
+            meta.list_binary(column.name.as_bytes());
+            meta.i32(4, CODEC_UNCOMPRESSED);
+            meta.i64(5, size_i64(num_rows)?);
+            meta.i64(6, size);
+            meta.i64(7, size);
+            meta.i64(9, offset);
+            meta.end_struct();
+            meta.end_struct();
+        }
+        let total: usize = chunks.iter().map(|&(_, size)| size).sum();
+        meta.i64(2, size_i64(total)?);
+        meta.i64(3, size_i64(num_rows)?);
+        meta.end_struct();
+    }
+
+    meta.binary(
+        6,
+        concat!("caut version ", env!("CARGO_PKG_VERSION")).as_bytes(),
+    );
+    meta.stop();
+    Ok(meta.buf)
+}
+
+/// A size or offset for the metadata. Page sizes are i32 in the format, so
+/// every size is kept within that.
+fn size_i64(n: usize) -> Result<i64> {
+    i32::try_from(n)
+        .map(i64::from)
+        .map_err(|_| too_large("column"))
+}
+
+fn too_large(what: &str) -> CautError {
+    CautError::Other(anyhow::anyhow!("{what} is too large for a Parquet page"))
+}
+
+// Thrift compact protocol field types.
+const TYPE_I32: u8 = 5;
+const TYPE_I64: u8 = 6;
+const TYPE_BINARY: u8 = 8;
+const TYPE_LIST: u8 = 9;
+const TYPE_STRUCT: u8 = 12;
+
+/// Thrift compact protocol encoder, just enough for Parquet metadata.
+#[derive(Default)]
+struct Compact {
+    buf: Vec<u8>,
+    last_field: i16,
+    outer_fields: Vec<i16>,
+}
+
+impl Compact {
+    fn field(&mut self, id: i16, field_type: u8) {
+        if let Ok(delta @ 1..=15) = u8::try_from(id - self.last_field) {
+            self.buf.push(delta << 4 | field_type);
+        } else {
+            self.buf.push(field_type);
+            varint(&mut self.buf, zigzag(i64::from(id)));
+        }
+        self.last_field = id;
+    }
+
+    fn i32(&mut self, id: i16, value: i64) {
+        self.field(id, TYPE_I32);
+        varint(&mut self.buf, zigzag(value));
+    }
+
+    fn i64(&mut self, id: i16, value: i64) {
+        self.field(id, TYPE_I64);
+        varint(&mut self.buf, zigzag(value));
+    }
+
+    fn binary(&mut self, id: i16, value: &[u8]) {
+        self.field(id, TYPE_BINARY);
+        self.list_binary(value);
+    }
+
+    fn list(&mut self, id: i16, element_type: u8, size: usize) {
+        self.field(id, TYPE_LIST);
+        if let Ok(small @ 0..=14) = u8::try_from(size) {
+            self.buf.push(small << 4 | element_type);
+        } else {
+            self.buf.push(0xF0 | element_type);
+            varint(&mut self.buf, size as u64);
+        }
+    }
+
+    fn list_i32(&mut self, value: i64) {
+        varint(&mut self.buf, zigzag(value));
+    }
+
+    fn list_binary(&mut self, value: &[u8]) {
+        varint(&mut self.buf, value.len() as u64);
+        self.buf.extend_from_slice(value);
+    }
+
+    fn begin_struct(&mut self, id: i16) {
+        self.field(id, TYPE_STRUCT);
+        self.begin_element();
+    }
+
+    /// Start a struct that is a list element, which has no field header.
+    fn begin_element(&mut self) {
+        self.outer_fields.push(self.last_field);
+        self.last_field = 0;
+    }
+
+    fn end_struct(&mut self) {
+        self.stop();
+        self.last_field = self.outer_fields.pop().unwrap_or(0);
+    }
+
+    fn stop(&mut self) {
+        self.buf.push(0);
+    }
+}
+
+const fn zigzag(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)).cast_unsigned()
+}
+
+#[allow(clippy::cast_possible_truncation)] // each byte is masked to 7 bits
+fn varint(out: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        out.push((n & 0x7F) as u8 | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_protocol_encodes_fields_lists_and_structs() {
+        let mut c = Compact::default();
+        c.i32(1, 1);
+        c.binary(4, b"id");
+        c.list(2, TYPE_I32, 2);
+        c.list_i32(0);
+        c.list_i32(3);
+        c.begin_struct(20);
+        c.i64(1, -1);
+        c.end_struct();
+        c.stop();
+
+        assert_eq!(
+            c.buf,
+            [
+                0x15, 0x02, // field 1: i32 1
+                0x38, 0x02, b'i', b'd', // field 4 (delta 3): binary "id"
+                0x09, 0x04, 0x25, 0x00, 0x06, // field 2 (long form): list<i32> [0, 3]
+                0x0C, 0x28, // field 20 (long form): struct
+                0x16, 0x01, 0x00, // { field 1: i64 -1 }
+                0x00,
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_a_framed_file_with_null_levels() {
+        let columns = [
+            Column::new("n", ColumnData::Int64(vec![Some(1), None, Some(3)])),
+            Column::new(
+                "s",
+                ColumnData::Utf8(vec![None, Some("a".to_string()), Some("b".to_string())]),
+            ),
+        ];
+        let file = write_table(&columns).unwrap();
+
+        assert_eq!(&file[..4], MAGIC);
+        assert_eq!(&file[file.len() - 4..], MAGIC);
+        let footer_len =
+            u32::from_le_bytes(file[file.len() - 8..file.len() - 4].try_into().unwrap());
+        let footer_start = file.len() - 8 - footer_len as usize;
+        assert_eq!(
+            &file[footer_start..footer_start + 2],
+            [0x15, 0x02],
+            "metadata starts with version 1"
+        );
+        assert_eq!(rle_levels(&[true, false, true]), [2, 1, 2, 0, 2, 1]);
+        assert_eq!(rle_levels(&[false, true, true]), [2, 0, 4, 1]);
+
+        let ragged = [
+            Column::new("a", ColumnData::Double(vec![Some(1.0)])),
+            Column::new("b", ColumnData::Double(vec![])),
+        ];
+        assert!(write_table(&ragged).is_err());
+    }
+}
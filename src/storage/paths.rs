@@ -68,18 +68,66 @@ impl AppPaths {
         self.cache.join(format!("cost-usage/{provider}-v1.json"))
     }
 
+    /// Path to budget limits file.
+    #[must_use]
+    pub fn budgets_file(&self) -> PathBuf {
+        self.config.join("budgets.toml")
+    }
+
+    /// Path to usage alert rules.
+    #[must_use]
+    pub fn alerts_file(&self) -> PathBuf {
+        self.config.join("alerts.toml")
+    }
+
+    /// Path to the record of which alerts have fired.
+    #[must_use]
+    pub fn alert_state_file(&self) -> PathBuf {
+        self.data.join("alert-state.json")
+    }
+
+    /// Path to the canary self-test record (see [`crate::core::canary`]).
+    #[must_use]
+    pub fn canary_file(&self) -> PathBuf {
+        self.data.join("canary.json")
+    }
+
+    /// Path to the last internal error, for `caut report-bug`.
+    #[must_use]
+    pub fn last_error_file(&self) -> PathBuf {
+        self.data.join("last-internal-error.json")
+    }
+
     /// Path to history database file.
     #[must_use]
     pub fn history_db_file(&self) -> PathBuf {
         self.data.join("usage-history.sqlite")
     }
 
+    /// Directory holding cached remote configs.
+    #[must_use]
+    pub fn remote_config_dir(&self) -> PathBuf {
+        self.cache.join("remote-config")
+    }
+
+    /// Path to `caut repl` command history.
+    #[must_use]
+    pub fn repl_history_file(&self) -> PathBuf {
+        self.data.join("repl-history.txt")
+    }
+
     /// Path to shell prompt cache file.
     #[must_use]
     pub fn prompt_cache_file(&self) -> PathBuf {
         self.cache.join("prompt-cache.json")
     }
 
+    /// Lock held while `caut warm` refreshes the caches.
+    #[must_use]
+    pub fn warm_lock_file(&self) -> PathBuf {
+        self.cache.join("warm.lock")
+    }
+
     /// Ensure all directories exist.
     ///
     /// # Errors
@@ -0,0 +1,100 @@
+//! Owner-only files.
+//!
+//! caut's config, budgets, alert rules and state, token accounts, and
+//! history can hold API keys, webhook URLs, and account details. They are
+//! written here with mode 600 from the start, never briefly readable by
+//! other users, and replace the old file in one rename. `caut doctor` flags
+//! any of them that ends up more open than that.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+/// Mode for files holding secrets.
+pub const PRIVATE_MODE: u32 = 0o600;
+
+/// Write `content` to `path` with [`PRIVATE_MODE`], creating the parent
+/// directory if needed.
+///
+/// The content goes to a new sibling file created with the private mode and
+/// is renamed over `path`, so readers never see a partial file.
+///
+/// # Errors
+/// Returns an error if the directory or temporary file cannot be created,
+/// written, or renamed.
+pub fn write(path: &Path, content: impl AsRef<[u8]>) -> std::io::Result<()> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(dir)?;
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(format!(".tmp.{}", std::process::id()));
+    let tmp = dir.join(tmp_name);
+    // Left by a run that died; `create_new` below will not reuse it.
+    let _ = fs::remove_file(&tmp);
+
+    let result = write_new(&tmp, content.as_ref()).and_then(|()| fs::rename(&tmp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Create `path`, failing if it exists, readable only by the owner, and
+/// write `content` to it.
+fn write_new(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(PRIVATE_MODE);
+    }
+    let mut file = options.open(path)?;
+    file.write_all(content)?;
+    file.sync_all()
+}
+
+/// Restrict an existing file to its owner; a missing file is left alone.
+///
+/// # Errors
+/// Returns an error if the file exists but its mode cannot be changed.
+pub fn restrict(path: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        match fs::set_permissions(path, fs::Permissions::from_mode(PRIVATE_MODE)) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            result => return result,
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use tempfile::TempDir;
+
+    fn mode(path: &Path) -> u32 {
+        fs::metadata(path).unwrap().permissions().mode() & 0o777
+    }
+
+    #[test]
+    fn writes_and_replaces_with_private_mode() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested/budgets.toml");
+
+        write(&path, "a = 1\n").unwrap();
+        assert_eq!(mode(&path), PRIVATE_MODE);
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        write(&path, "a = 2\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a = 2\n");
+        assert_eq!(mode(&path), PRIVATE_MODE);
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+
+        restrict(&dir.path().join("missing")).unwrap();
+    }
+}
@@ -0,0 +1,413 @@
+//! Remote team configuration.
+//!
+//! A platform team can publish a shared config (providers, budgets,
+//! thresholds) at a URL and point users at it with `--config <URL>`,
+//! `CAUT_CONFIG=<URL>`, or `include_url` in the local config file. The remote
+//! file is the base layer and the local config file is merged over it, so
+//! local settings always win.
+//!
+//! Config loading is synchronous, so the remote file is fetched into the
+//! cache once at startup by [`refresh_configured`] and read from there. A
+//! failed fetch falls back to the last cached copy.
+//!
+//! Two checks can be required, both when fetched and when loaded:
+//!
+//! - `include_public_key` (or `CAUT_CONFIG_PUBLIC_KEY`): a base64 ed25519
+//!   public key. The team publishes a base64 ed25519 signature of the file
+//!   at `<url>.sig`, so the config can change without every user updating
+//!   a pin.
+//! - `include_sha256` (or `CAUT_CONFIG_SHA256`): the file's exact digest.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use ed25519_dalek::{Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+use super::AppPaths;
+use super::config::Config;
+use crate::core::http::{DEFAULT_TIMEOUT, build_client};
+use crate::error::{CautError, Result};
+
+/// Environment variable with the expected SHA-256 of a `--config` URL.
+pub const ENV_CONFIG_SHA256: &str = "CAUT_CONFIG_SHA256";
+
+/// Environment variable with the public key a `--config` URL is signed with.
+pub const ENV_CONFIG_PUBLIC_KEY: &str = "CAUT_CONFIG_PUBLIC_KEY";
+
+/// How long a fetched remote config is used before fetching it again.
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// A remote config and how to verify it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteSource {
+    pub url: String,
+    /// Expected SHA-256 of the file, hex-encoded.
+    pub sha256: Option<String>,
+    /// Ed25519 public key the file must be signed with, base64-encoded.
+    pub public_key: Option<String>,
+}
+
+impl RemoteSource {
+    /// Remote source for a `--config` / `CAUT_CONFIG` URL.
+    #[must_use]
+    pub fn from_location(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            sha256: std::env::var(ENV_CONFIG_SHA256).ok(),
+            public_key: std::env::var(ENV_CONFIG_PUBLIC_KEY).ok(),
+        }
+    }
+
+    /// Where the detached signature is published.
+    #[must_use]
+    pub fn signature_url(&self) -> String {
+        format!("{}.sig", self.url)
+    }
+
+    /// Cache file holding the last fetched copy.
+    #[must_use]
+    pub fn cache_path(&self) -> PathBuf {
+        let name = &sha256_hex(self.url.as_bytes())[..16];
+        AppPaths::new()
+            .remote_config_dir()
+            .join(format!("{name}.toml"))
+    }
+
+    /// Cache file holding the signature of the last fetched copy.
+    fn signature_cache_path(&self) -> PathBuf {
+        self.cache_path().with_extension("sig")
+    }
+
+    /// Check the content against the public key and the pinned digest,
+    /// whichever are set. `signature` is the base64 detached signature.
+    ///
+    /// # Errors
+    /// Returns an error if a public key is set and the signature is missing
+    /// or invalid, or a digest is pinned and the content does not match.
+    pub fn verify(&self, content: &str, signature: Option<&str>) -> Result<()> {
+        if let Some(public_key) = &self.public_key {
+            let signature = signature.ok_or_else(|| {
+                self.verification_error(format!("no signature at {}", self.signature_url()))
+            })?;
+            verify_signature(public_key, content.as_bytes(), signature)
+                .map_err(|reason| self.verification_error(reason))?;
+        }
+        if let Some(expected) = &self.sha256 {
+            let actual = sha256_hex(content.as_bytes());
+            if !actual.eq_ignore_ascii_case(expected.trim()) {
+                return Err(
+                    self.verification_error(format!("expected sha256 {expected}, got {actual}"))
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn verification_error(&self, reason: String) -> CautError {
+        CautError::Config(format!(
+            "Remote config {} failed verification: {reason}",
+            self.url
+        ))
+    }
+
+    /// Read the cached copy and check it.
+    fn read_cache(&self) -> Result<String> {
+        let content = fs::read_to_string(self.cache_path()).map_err(|_| {
+            CautError::Config(format!(
+                "Remote config {} has not been fetched yet",
+                self.url
+            ))
+        })?;
+        let signature = fs::read_to_string(self.signature_cache_path()).ok();
+        self.verify(&content, signature.as_deref())?;
+        Ok(content)
+    }
+
+    fn is_fresh(&self) -> bool {
+        let recent = fs::metadata(self.cache_path())
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age < REFRESH_INTERVAL);
+        recent && self.read_cache().is_ok()
+    }
+}
+
+/// Check a base64 ed25519 signature of `message`, returning why it failed.
+fn verify_signature(
+    public_key: &str,
+    message: &[u8],
+    signature: &str,
+) -> std::result::Result<(), String> {
+    let key: [u8; 32] = BASE64
+        .decode(public_key.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or("public key is not a base64 ed25519 key")?;
+    let key =
+        VerifyingKey::from_bytes(&key).map_err(|_| "public key is not a valid ed25519 key")?;
+    // `base64` wraps long output, so ignore line breaks.
+    let signature: String = signature.split_whitespace().collect();
+    let signature = BASE64
+        .decode(signature)
+        .ok()
+        .and_then(|bytes| Signature::from_slice(&bytes).ok())
+        .ok_or("signature is not a base64 ed25519 signature")?;
+    key.verify_strict(message, &signature)
+        .map_err(|_| "signature does not match the public key".to_string())
+}
+
+/// Whether a config location is a URL rather than a file path.
+#[must_use]
+pub fn is_remote(location: &str) -> bool {
+    location.starts_with("https://") || location.starts_with("http://")
+}
+
+/// Only allow plain HTTP for loopback hosts (local testing).
+fn check_scheme(url: &str) -> Result<()> {
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|e| CautError::Config(format!("Invalid remote config URL {url}: {e}")))?;
+    let loopback = matches!(parsed.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+    if parsed.scheme() == "https" || (parsed.scheme() == "http" && loopback) {
+        Ok(())
+    } else {
+        Err(CautError::Config(format!(
+            "Remote config URL must use https: {url}"
+        )))
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Read the cached copy of a remote config.
+///
+/// # Errors
+/// Returns an error if the config has never been fetched, fails
+/// verification, or is not valid TOML.
+pub fn load_cached(source: &RemoteSource) -> Result<toml::Table> {
+    let content = source.read_cache()?;
+    parse(source, &content)
+}
+
+fn parse(source: &RemoteSource, content: &str) -> Result<toml::Table> {
+    let mut table: toml::Table = toml::from_str(content)
+        .map_err(|e| CautError::Config(format!("Invalid remote config {}: {e}", source.url)))?;
+    // Remote configs are a single layer; they cannot include further URLs.
+    table.remove("include_url");
+    table.remove("include_sha256");
+    table.remove("include_public_key");
+    Ok(table)
+}
+
+/// Fetch a remote config into the cache unless the cached copy is fresh.
+///
+/// # Errors
+/// Returns an error if the URL is not https, the request fails, or the
+/// response fails verification or is not valid TOML.
+pub async fn refresh(source: &RemoteSource) -> Result<()> {
+    if source.is_fresh() {
+        return Ok(());
+    }
+    check_scheme(&source.url)?;
+
+    let content = fetch_text(&source.url).await?;
+    let signature = match source.public_key {
+        Some(_) => Some(fetch_text(&source.signature_url()).await?),
+        None => None,
+    };
+    source.verify(&content, signature.as_deref())?;
+    parse(source, &content)?;
+
+    let path = source.cache_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // Signature first: a crash in between leaves a copy that fails to
+    // verify rather than an unsigned one that passes.
+    if let Some(signature) = &signature {
+        write_replacing(&source.signature_cache_path(), signature)?;
+    }
+    write_replacing(&path, &content)?;
+    tracing::debug!(url = %source.url, ?path, "Remote config cached");
+    Ok(())
+}
+
+async fn fetch_text(url: &str) -> Result<String> {
+    let client = build_client(DEFAULT_TIMEOUT)?;
+    let response = client.get(url).send().await.map_err(|e| {
+        if e.is_timeout() {
+            CautError::Timeout(DEFAULT_TIMEOUT.as_secs())
+        } else {
+            CautError::Network(e.to_string())
+        }
+    })?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(CautError::Config(format!(
+            "Remote config {url} returned {status}"
+        )));
+    }
+    response
+        .text()
+        .await
+        .map_err(|e| CautError::Network(e.to_string()))
+}
+
+fn write_replacing(path: &std::path::Path, content: &str) -> Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, content)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Refresh the remote config named by `--config`, `CAUT_CONFIG`, or the
+/// local `include_url`, if any.
+///
+/// Failures are logged; loading falls back to the cached copy.
+pub async fn refresh_configured() {
+    let source = match Config::remote_source() {
+        Ok(Some(source)) => source,
+        Ok(None) => return,
+        Err(e) => {
+            tracing::debug!(error = %e, "Could not read local config for include_url");
+            return;
+        }
+    };
+    if let Err(e) = refresh(&source).await {
+        tracing::warn!(url = %source.url, error = %e, "Failed to fetch remote config, using cached copy");
+    }
+}
+
+/// Merge `overlay` into `base`, recursing into tables; other values in
+/// `overlay` replace those in `base`.
+pub fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match value {
+            toml::Value::Table(overlay_table) => match base.get_mut(&key) {
+                Some(toml::Value::Table(base_table)) => merge_tables(base_table, overlay_table),
+                _ => {
+                    base.insert(key, toml::Value::Table(overlay_table));
+                }
+            },
+            value => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(sha256: Option<&str>) -> RemoteSource {
+        RemoteSource {
+            url: "https://config.example.com/team-caut.toml".to_string(),
+            sha256: sha256.map(str::to_string),
+            public_key: None,
+        }
+    }
+
+    #[test]
+    fn detects_remote_locations() {
+        assert!(is_remote("https://example.com/caut.toml"));
+        assert!(is_remote("http://localhost:8080/caut.toml"));
+        assert!(!is_remote("/etc/caut/config.toml"));
+        assert!(!is_remote("config.toml"));
+    }
+
+    #[test]
+    fn plain_http_only_for_loopback() {
+        assert!(check_scheme("https://example.com/caut.toml").is_ok());
+        assert!(check_scheme("http://127.0.0.1:9000/caut.toml").is_ok());
+        assert!(check_scheme("http://example.com/caut.toml").is_err());
+    }
+
+    #[test]
+    fn verifies_pinned_digest() {
+        let content = "[general]\ntimeout_seconds = 10\n";
+        let digest = sha256_hex(content.as_bytes());
+
+        assert!(source(None).verify(content, None).is_ok());
+        assert!(source(Some(&digest)).verify(content, None).is_ok());
+        assert!(
+            source(Some(&digest.to_uppercase()))
+                .verify(content, None)
+                .is_ok()
+        );
+        assert!(source(Some(&digest)).verify("tampered", None).is_err());
+    }
+
+    #[test]
+    fn verifies_ed25519_signature() {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let content = "[general]\ntimeout_seconds = 10\n";
+        let signing_key = SigningKey::from_bytes(&[7; 32]);
+        let signature = BASE64.encode(signing_key.sign(content.as_bytes()).to_bytes());
+        let mut signed = source(None);
+        signed.public_key = Some(BASE64.encode(signing_key.verifying_key().to_bytes()));
+
+        assert!(signed.verify(content, Some(&signature)).is_ok());
+        assert!(signed.verify("tampered", Some(&signature)).is_err());
+        assert!(signed.verify(content, None).is_err());
+        assert!(
+            signed
+                .verify(content, Some("bm90IGEgc2lnbmF0dXJl"))
+                .is_err()
+        );
+
+        signed.public_key =
+            Some(BASE64.encode(SigningKey::from_bytes(&[8; 32]).verifying_key().to_bytes()));
+        assert!(signed.verify(content, Some(&signature)).is_err());
+    }
+
+    #[test]
+    fn remote_includes_are_dropped() {
+        let table = parse(
+            &source(None),
+            "include_url = \"https://other.example.com\"\n[general]\ninclude_status = true\n",
+        )
+        .unwrap();
+        assert!(!table.contains_key("include_url"));
+        assert!(table.contains_key("general"));
+    }
+
+    #[test]
+    fn local_values_override_remote() {
+        let mut base: toml::Table = toml::from_str(
+            r#"
+[general]
+timeout_seconds = 10
+include_status = true
+
+[providers]
+default_providers = ["claude", "codex"]
+"#,
+        )
+        .unwrap();
+        let local: toml::Table = toml::from_str(
+            r#"
+[general]
+timeout_seconds = 45
+
+[providers]
+default_providers = ["claude"]
+"#,
+        )
+        .unwrap();
+
+        merge_tables(&mut base, local);
+        let config: Config = toml::Value::Table(base).try_into().unwrap();
+        assert_eq!(config.general.timeout_seconds, 45);
+        assert!(config.general.include_status);
+        assert_eq!(config.providers.default_providers, ["claude"]);
+    }
+}
@@ -0,0 +1,197 @@
+//! Secret references for credentials kept out of the config file.
+//!
+//! Teams that forbid plaintext secrets on disk can point a provider's
+//! `api_key_ref` at a secret store instead of setting `api_key`:
+//!
+//! - `env:NAME` reads an environment variable.
+//! - `op://vault/item/field` runs `op read` (1Password CLI).
+//! - `vault://path#field` runs `vault kv get -field=<field> <path>`
+//!   (HashiCorp Vault CLI); `field` defaults to `api_key`.
+//!
+//! References are only resolved when a key is actually needed, so an
+//! unreachable store costs nothing for providers that are not fetched.
+//! Each reference is resolved at most once per run.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::process::Command;
+use std::sync::{LazyLock, Mutex};
+
+use crate::error::{CautError, Result};
+
+/// Field read from a Vault secret when the reference names none.
+const DEFAULT_VAULT_FIELD: &str = "api_key";
+
+/// Secrets already resolved during this run, keyed by reference.
+static RESOLVED: LazyLock<Mutex<HashMap<String, String>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Where a secret lives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretRef {
+    /// Environment variable name.
+    Env(String),
+    /// 1Password secret reference (`op://vault/item/field`).
+    OnePassword(String),
+    /// HashiCorp Vault KV path and field.
+    Vault { path: String, field: String },
+}
+
+impl SecretRef {
+    /// Parse a reference string.
+    ///
+    /// # Errors
+    /// Returns an error if the scheme is unknown or the reference is empty.
+    pub fn parse(reference: &str) -> Result<Self> {
+        let reference = reference.trim();
+        let invalid = || {
+            CautError::Config(format!(
+                "Invalid secret reference '{reference}' (expected env:NAME, op://..., or vault://path#field)"
+            ))
+        };
+
+        if let Some(name) = reference.strip_prefix("env:") {
+            if name.is_empty() {
+                return Err(invalid());
+            }
+            return Ok(Self::Env(name.to_string()));
+        }
+        if reference.starts_with("op://") {
+            if reference.len() == "op://".len() {
+                return Err(invalid());
+            }
+            return Ok(Self::OnePassword(reference.to_string()));
+        }
+        if let Some(rest) = reference.strip_prefix("vault://") {
+            let (path, field) = rest.split_once('#').unwrap_or((rest, DEFAULT_VAULT_FIELD));
+            if path.is_empty() || field.is_empty() {
+                return Err(invalid());
+            }
+            return Ok(Self::Vault {
+                path: path.to_string(),
+                field: field.to_string(),
+            });
+        }
+        Err(invalid())
+    }
+
+    /// Fetch the secret value.
+    ///
+    /// # Errors
+    /// Returns an error if the variable is unset, the store's CLI is missing
+    /// or fails, or the secret is empty.
+    pub fn fetch(&self) -> Result<String> {
+        let value = match self {
+            Self::Env(name) => std::env::var(name).map_err(|_| {
+                CautError::Config(format!("Environment variable {name} is not set"))
+            })?,
+            Self::OnePassword(reference) => run_cli("op", &["read", "--no-newline", reference])?,
+            Self::Vault { path, field } => {
+                run_cli("vault", &["kv", "get", &format!("-field={field}"), path])?
+            }
+        };
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(CautError::Config(format!("Secret {self} is empty")));
+        }
+        Ok(value.to_string())
+    }
+}
+
+impl fmt::Display for SecretRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Env(name) => write!(f, "env:{name}"),
+            Self::OnePassword(reference) => f.write_str(reference),
+            Self::Vault { path, field } => write!(f, "vault://{path}#{field}"),
+        }
+    }
+}
+
+/// Resolve a reference string, reusing the value if it was already fetched
+/// this run.
+///
+/// # Errors
+/// Returns an error if the reference is invalid or cannot be fetched.
+pub fn resolve(reference: &str) -> Result<String> {
+    if let Some(value) = RESOLVED
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(reference).cloned())
+    {
+        return Ok(value);
+    }
+
+    let secret = SecretRef::parse(reference)?;
+    tracing::debug!(secret = %secret, "Resolving secret reference");
+    let value = secret.fetch()?;
+    if let Ok(mut cache) = RESOLVED.lock() {
+        cache.insert(reference.to_string(), value.clone());
+    }
+    Ok(value)
+}
+
+/// Run a secret store CLI and return its stdout.
+fn run_cli(program: &str, args: &[&str]) -> Result<String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| CautError::Config(format!("Failed to run {program} to read a secret: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(CautError::Config(format!(
+            "{program} could not read the secret: {}",
+            stderr.trim()
+        )));
+    }
+    String::from_utf8(output.stdout)
+        .map_err(|_| CautError::Config(format!("{program} returned a non-UTF-8 secret")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_scheme() {
+        assert_eq!(
+            SecretRef::parse("env:GROQ_KEY").unwrap(),
+            SecretRef::Env("GROQ_KEY".to_string())
+        );
+        assert_eq!(
+            SecretRef::parse("op://Team/Groq/credential").unwrap(),
+            SecretRef::OnePassword("op://Team/Groq/credential".to_string())
+        );
+        assert_eq!(
+            SecretRef::parse("vault://secret/caut/groq#token").unwrap(),
+            SecretRef::Vault {
+                path: "secret/caut/groq".to_string(),
+                field: "token".to_string()
+            }
+        );
+        assert_eq!(
+            SecretRef::parse("vault://secret/caut/groq")
+                .unwrap()
+                .to_string(),
+            "vault://secret/caut/groq#api_key"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_or_empty_references() {
+        for reference in ["", "gsk_plaintext", "env:", "op://", "vault://#field"] {
+            assert!(SecretRef::parse(reference).is_err(), "{reference}");
+        }
+    }
+
+    #[test]
+    fn resolves_environment_references() {
+        // SAFETY: no other test reads or writes this variable.
+        #[allow(unsafe_code)]
+        unsafe {
+            std::env::set_var("CAUT_TEST_SECRET_REF", " s3cret \n");
+        }
+        assert_eq!(resolve("env:CAUT_TEST_SECRET_REF").unwrap(), "s3cret");
+        assert!(resolve("env:CAUT_TEST_SECRET_REF_UNSET").is_err());
+    }
+}
@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::provider::Provider;
 use crate::error::Result;
+use crate::storage::private_file;
 
 /// A single token account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -138,18 +139,15 @@ impl TokenAccountStore {
             .unwrap_or_default()
     }
 
-    /// Save to file.
+    /// Save to file, readable only by the owner.
     ///
     /// # Errors
     /// Returns an error if the parent directory cannot be created, serialization fails,
     /// or the file cannot be written.
     pub fn save(&self) -> Result<()> {
         if let Some(path) = &self.path {
-            if let Some(parent) = path.parent() {
-                std::fs::create_dir_all(parent)?;
-            }
             let content = serde_json::to_string_pretty(&self.data)?;
-            std::fs::write(path, content)?;
+            private_file::write(path, content)?;
         }
         Ok(())
     }
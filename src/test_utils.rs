@@ -13,15 +13,20 @@
 //! let dir = TestDir::new();
 //! dir.create_file("config.toml", "[general]\ntimeout_seconds = 30");
 //! ```
+//!
+//! The `make_test_*` factories return fixed, realistic data. To build exactly
+//! the payload a test needs, use [`SnapshotBuilder`], [`PayloadBuilder`], and
+//! [`UsageOutputBuilder`]; downstream crates get them with the `test-utils`
+//! feature.
 
-use chrono::{TimeDelta, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use std::fs;
 use std::io::{self, Write as IoWrite};
 use std::path::{Path, PathBuf};
 
 use crate::core::models::{
     CostDailyEntry, CostPayload, CostTotals, CreditEvent, CreditsSnapshot, ProviderIdentity,
-    ProviderPayload, RateWindow, StatusIndicator, StatusPayload, UsageSnapshot,
+    ProviderPayload, RateWindow, RobotOutput, StatusIndicator, StatusPayload, UsageSnapshot,
 };
 
 // =============================================================================
@@ -229,6 +234,10 @@ pub fn make_test_provider_payload(provider: &str, source: &str) -> ProviderPaylo
         },
         antigravity_plan_info: None,
         openai_dashboard: None,
+        copilot_premium: None,
+        task_spend: None,
+        local_model: None,
+        divergences: Vec::new(),
         auth_warning: None,
     }
 }
@@ -248,6 +257,10 @@ pub fn make_test_provider_payload_minimal(provider: &str, source: &str) -> Provi
         credits: None,
         antigravity_plan_info: None,
         openai_dashboard: None,
+        copilot_premium: None,
+        task_spend: None,
+        local_model: None,
+        divergences: Vec::new(),
         auth_warning: None,
     }
 }
@@ -342,6 +355,290 @@ pub fn make_test_provider_identity() -> ProviderIdentity {
     }
 }
 
+// =============================================================================
+// Fluent Builders
+// =============================================================================
+
+/// Builder for a `UsageSnapshot`.
+///
+/// Starts with no windows and no identity, updated now. Windows get the same
+/// realistic defaults as [`make_test_rate_window`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use caut::test_utils::SnapshotBuilder;
+///
+/// let snapshot = SnapshotBuilder::new()
+///     .primary(82.0)
+///     .secondary(40.0)
+///     .email("dev@example.com")
+///     .build();
+/// assert_eq!(snapshot.primary.unwrap().used_percent, 82.0);
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct SnapshotBuilder {
+    snapshot: UsageSnapshot,
+}
+
+impl SnapshotBuilder {
+    /// An empty snapshot.
+    pub fn new() -> Self {
+        Self {
+            snapshot: UsageSnapshot {
+                primary: None,
+                secondary: None,
+                tertiary: None,
+                updated_at: Utc::now(),
+                identity: None,
+            },
+        }
+    }
+
+    /// Set the primary window's used percentage.
+    pub fn primary(mut self, used_percent: f64) -> Self {
+        self.snapshot.primary = Some(make_test_rate_window(used_percent));
+        self
+    }
+
+    /// Set the secondary window's used percentage.
+    pub fn secondary(mut self, used_percent: f64) -> Self {
+        self.snapshot.secondary = Some(make_test_rate_window(used_percent));
+        self
+    }
+
+    /// Set the tertiary window's used percentage.
+    pub fn tertiary(mut self, used_percent: f64) -> Self {
+        self.snapshot.tertiary = Some(make_test_rate_window(used_percent));
+        self
+    }
+
+    /// Set the primary window exactly.
+    pub fn primary_window(mut self, window: RateWindow) -> Self {
+        self.snapshot.primary = Some(window);
+        self
+    }
+
+    /// Set the secondary window exactly.
+    pub fn secondary_window(mut self, window: RateWindow) -> Self {
+        self.snapshot.secondary = Some(window);
+        self
+    }
+
+    /// Set the tertiary window exactly.
+    pub fn tertiary_window(mut self, window: RateWindow) -> Self {
+        self.snapshot.tertiary = Some(window);
+        self
+    }
+
+    /// Make every window set so far reset `after` from now.
+    pub fn resets_in(mut self, after: TimeDelta) -> Self {
+        let resets_at = Utc::now() + after;
+        for window in [
+            &mut self.snapshot.primary,
+            &mut self.snapshot.secondary,
+            &mut self.snapshot.tertiary,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            window.resets_at = Some(resets_at);
+            window.reset_description = None;
+        }
+        self
+    }
+
+    /// Set the account email.
+    pub fn email(mut self, email: &str) -> Self {
+        self.identity().account_email = Some(email.to_string());
+        self
+    }
+
+    /// Set the account organization.
+    pub fn organization(mut self, organization: &str) -> Self {
+        self.identity().account_organization = Some(organization.to_string());
+        self
+    }
+
+    /// Set the login method, e.g. "oauth".
+    pub fn login_method(mut self, method: &str) -> Self {
+        self.identity().login_method = Some(method.to_string());
+        self
+    }
+
+    /// Set when the snapshot was taken.
+    pub const fn updated_at(mut self, updated_at: DateTime<Utc>) -> Self {
+        self.snapshot.updated_at = updated_at;
+        self
+    }
+
+    /// Finish the snapshot.
+    #[must_use]
+    pub fn build(self) -> UsageSnapshot {
+        self.snapshot
+    }
+
+    fn identity(&mut self) -> &mut ProviderIdentity {
+        self.snapshot.identity.get_or_insert(ProviderIdentity {
+            account_email: None,
+            account_organization: None,
+            login_method: None,
+        })
+    }
+}
+
+impl Default for SnapshotBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<SnapshotBuilder> for UsageSnapshot {
+    fn from(builder: SnapshotBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Builder for a `ProviderPayload`.
+///
+/// Starts from [`make_test_provider_payload_minimal`]: source "cli", no
+/// status, credits, or account, and a primary window at 50%.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use caut::test_utils::{PayloadBuilder, SnapshotBuilder};
+/// use caut::core::models::StatusIndicator;
+///
+/// let payload = PayloadBuilder::new("codex")
+///     .source("oauth")
+///     .usage(SnapshotBuilder::new().primary(95.0).email("dev@example.com"))
+///     .credits(12.5)
+///     .status(StatusIndicator::Minor)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct PayloadBuilder {
+    payload: ProviderPayload,
+}
+
+impl PayloadBuilder {
+    /// A minimal payload for `provider` (a CLI name such as "claude").
+    pub fn new(provider: &str) -> Self {
+        Self {
+            payload: make_test_provider_payload_minimal(provider, "cli"),
+        }
+    }
+
+    /// Set the source label, e.g. "oauth" or "web".
+    pub fn source(mut self, source: &str) -> Self {
+        self.payload.source = source.to_string();
+        self
+    }
+
+    /// Set the account shown for the provider.
+    pub fn account(mut self, account: &str) -> Self {
+        self.payload.account = Some(account.to_string());
+        self
+    }
+
+    /// Set the provider CLI version.
+    pub fn version(mut self, version: &str) -> Self {
+        self.payload.version = Some(version.to_string());
+        self
+    }
+
+    /// Replace the usage snapshot.
+    pub fn usage(mut self, usage: impl Into<UsageSnapshot>) -> Self {
+        self.payload.usage = usage.into();
+        self
+    }
+
+    /// Set the credit balance, with no events.
+    pub fn credits(mut self, remaining: f64) -> Self {
+        self.payload.credits = Some(make_test_credits_snapshot_minimal(remaining));
+        self
+    }
+
+    /// Set the credits exactly.
+    pub fn credits_snapshot(mut self, credits: CreditsSnapshot) -> Self {
+        self.payload.credits = Some(credits);
+        self
+    }
+
+    /// Set the provider status page indicator.
+    pub fn status(mut self, indicator: StatusIndicator) -> Self {
+        self.payload.status = Some(make_test_status_payload(indicator));
+        self
+    }
+
+    /// Set an authentication warning.
+    pub fn auth_warning(mut self, warning: &str) -> Self {
+        self.payload.auth_warning = Some(warning.to_string());
+        self
+    }
+
+    /// Finish the payload.
+    #[must_use]
+    pub fn build(self) -> ProviderPayload {
+        self.payload
+    }
+}
+
+impl From<PayloadBuilder> for ProviderPayload {
+    fn from(builder: PayloadBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Builder for the `caut usage --json` envelope, with payloads and
+/// per-provider errors.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use caut::test_utils::{PayloadBuilder, UsageOutputBuilder};
+///
+/// let output = UsageOutputBuilder::new()
+///     .payload(PayloadBuilder::new("claude"))
+///     .error("codex", "Authentication failed")
+///     .build();
+/// assert_eq!(output.errors, vec!["codex: Authentication failed"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct UsageOutputBuilder {
+    payloads: Vec<ProviderPayload>,
+    errors: Vec<String>,
+}
+
+impl UsageOutputBuilder {
+    /// An envelope with no payloads or errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a provider payload.
+    pub fn payload(mut self, payload: impl Into<ProviderPayload>) -> Self {
+        self.payloads.push(payload.into());
+        self
+    }
+
+    /// Add a provider that failed, formatted as `caut usage` reports it.
+    pub fn error(mut self, provider: &str, message: &str) -> Self {
+        self.errors.push(format!("{provider}: {message}"));
+        self
+    }
+
+    /// Finish the envelope.
+    #[must_use]
+    pub fn build(self) -> RobotOutput<Vec<ProviderPayload>> {
+        RobotOutput::usage(self.payloads, self.errors)
+    }
+}
+
 // =============================================================================
 // Temp Directory Utilities
 // =============================================================================
@@ -930,4 +1227,50 @@ mod tests {
         assert_json_valid!(&token_accounts);
         assert!(token_accounts.contains("claude"));
     }
+
+    #[test]
+    fn snapshot_builder_sets_windows_and_identity() {
+        let snapshot = SnapshotBuilder::new()
+            .primary(82.0)
+            .tertiary(10.0)
+            .resets_in(TimeDelta::minutes(30))
+            .email("dev@example.com")
+            .login_method("oauth")
+            .build();
+        assert_float_eq!(snapshot.primary.as_ref().unwrap().used_percent, 82.0);
+        assert!(snapshot.secondary.is_none());
+        let tertiary = snapshot.tertiary.as_ref().unwrap();
+        assert!(tertiary.resets_at.is_some());
+        assert!(tertiary.reset_description.is_none());
+        let identity = snapshot.identity.unwrap();
+        assert_eq!(identity.account_email.as_deref(), Some("dev@example.com"));
+        assert!(identity.account_organization.is_none());
+        assert_eq!(identity.login_method.as_deref(), Some("oauth"));
+    }
+
+    #[test]
+    fn payload_and_output_builders_compose() {
+        let output = UsageOutputBuilder::new()
+            .payload(
+                PayloadBuilder::new("codex")
+                    .source("oauth")
+                    .usage(SnapshotBuilder::new().primary(95.0))
+                    .credits(12.5)
+                    .status(StatusIndicator::Minor)
+                    .auth_warning("token expires soon"),
+            )
+            .error("claude", "Authentication failed")
+            .build();
+        assert_eq!(output.data.len(), 1);
+        let payload = &output.data[0];
+        assert_eq!(payload.provider, "codex");
+        assert_eq!(payload.source, "oauth");
+        assert_float_eq!(payload.usage.primary.as_ref().unwrap().used_percent, 95.0);
+        assert_float_eq!(payload.credits.as_ref().unwrap().remaining, 12.5);
+        assert_eq!(
+            payload.status.as_ref().unwrap().indicator,
+            StatusIndicator::Minor
+        );
+        assert_eq!(output.errors, vec!["claude: Authentication failed"]);
+    }
 }
@@ -2,13 +2,16 @@
 
 use std::time::{Duration, Instant};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, Utc};
 use tokio::sync::mpsc;
 
 use crate::cli::args::UsageArgs;
+use crate::cli::history::{load_profile, render_profile};
 use crate::cli::usage::{UsageResults, fetch_usage};
 use crate::core::models::ProviderPayload;
+use crate::core::provider::Provider;
 use crate::error::{CautError, Result};
+use crate::storage::{AppPaths, HistoryStore};
 
 use super::Tui;
 use super::dashboard::Dashboard;
@@ -35,6 +38,8 @@ pub struct App {
     last_refresh: Instant,
     /// Whether to show help overlay.
     show_help: bool,
+    /// Rendered usage profile overlay, when open.
+    profile: Option<Vec<String>>,
     /// Whether the app should quit.
     should_quit: bool,
     /// Whether a refresh is pending.
@@ -60,6 +65,7 @@ impl App {
                 .checked_sub(Duration::from_secs(refresh_interval_secs + 1))
                 .unwrap(),
             show_help: false,
+            profile: None,
             should_quit: false,
             refresh_pending: true, // Start with a refresh
         }
@@ -87,6 +93,7 @@ impl App {
                         self.selected,
                         self.last_update,
                         self.show_help,
+                        self.profile.as_deref(),
                     );
                     frame.render_widget(dashboard, frame.area());
                 })
@@ -129,6 +136,11 @@ impl App {
             self.show_help = false;
             return;
         }
+        // Likewise for the profile overlay, except that quitting still quits
+        if self.profile.is_some() && !matches!(action, KeyAction::None | KeyAction::Quit) {
+            self.profile = None;
+            return;
+        }
 
         match action {
             KeyAction::Quit => {
@@ -152,6 +164,9 @@ impl App {
             KeyAction::Help => {
                 self.show_help = !self.show_help;
             }
+            KeyAction::Profile => {
+                self.open_profile();
+            }
             KeyAction::Select | KeyAction::None => {}
         }
     }
@@ -196,6 +211,16 @@ impl App {
         }
     }
 
+    /// Open the usage profile of the selected provider.
+    fn open_profile(&mut self) {
+        let lines = match self.payloads.get(self.selected) {
+            Some(payload) => profile_lines(&payload.provider)
+                .unwrap_or_else(|e| vec![format!("Could not load history: {e}")]),
+            None => vec!["No provider selected.".to_string()],
+        };
+        self.profile = Some(lines);
+    }
+
     /// Spawn a background fetch task.
     fn spawn_fetch(&mut self, tx: mpsc::Sender<UsageResults>) {
         if self.refresh_pending {
@@ -218,6 +243,8 @@ impl App {
                         .send(UsageResults {
                             payloads: Vec::new(),
                             errors: vec![e.to_string()],
+                            costs: Vec::new(),
+                            budgets: Vec::new(),
                         })
                         .await;
                 }
@@ -243,3 +270,17 @@ impl App {
         self.errors = results.errors;
     }
 }
+
+/// Render a provider's usage profile from the history database.
+fn profile_lines(provider: &str) -> Result<Vec<String>> {
+    let provider = Provider::from_cli_name(provider)?;
+    let history_path = AppPaths::new().history_db_file();
+    if !history_path.exists() {
+        return Ok(vec![
+            "No history yet. Run `caut usage` to start collecting data.".to_string(),
+        ]);
+    }
+    let store = HistoryStore::open(&history_path)?;
+    let profile = load_profile(&store, provider, 4, &Local::now())?;
+    Ok(render_profile(&profile, false))
+}
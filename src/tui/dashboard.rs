@@ -4,7 +4,7 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Widget},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
 
 use crate::core::models::ProviderPayload;
@@ -23,6 +23,8 @@ pub struct Dashboard<'a> {
     last_update: Option<chrono::DateTime<chrono::Utc>>,
     /// Show help overlay.
     show_help: bool,
+    /// Usage profile overlay lines, when open.
+    profile: Option<&'a [String]>,
 }
 
 impl<'a> Dashboard<'a> {
@@ -34,6 +36,7 @@ impl<'a> Dashboard<'a> {
         selected: usize,
         last_update: Option<chrono::DateTime<chrono::Utc>>,
         show_help: bool,
+        profile: Option<&'a [String]>,
     ) -> Self {
         Self {
             payloads,
@@ -41,6 +44,7 @@ impl<'a> Dashboard<'a> {
             selected,
             last_update,
             show_help,
+            profile,
         }
     }
 
@@ -192,6 +196,7 @@ impl<'a> Dashboard<'a> {
             Line::from(""),
             Line::from("  Actions:"),
             Line::from("    r, F5         Refresh data now"),
+            Line::from("    p             Usage profile of selection"),
             Line::from("    ?, F1         Toggle this help"),
             Line::from("    q, Esc        Quit"),
             Line::from(""),
@@ -203,7 +208,7 @@ impl<'a> Dashboard<'a> {
 
         // Center the help box
         let help_width = 50;
-        let help_height = 15;
+        let help_height = 16;
         let x = area.x + (area.width.saturating_sub(help_width)) / 2;
         let y = area.y + (area.height.saturating_sub(help_height)) / 2;
         let help_area = Rect::new(x, y, help_width, help_height);
@@ -217,6 +222,27 @@ impl<'a> Dashboard<'a> {
         let paragraph = Paragraph::new(help_text).block(block);
         paragraph.render(help_area, buf);
     }
+
+    /// Render the usage profile overlay.
+    #[allow(clippy::cast_possible_truncation)] // clamped to the terminal size
+    fn render_profile(lines: &[String], area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let widest = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let width = (widest + 4).min(area.width as usize) as u16;
+        let height = (lines.len() + 2).min(area.height as usize) as u16;
+        let x = area.x + (area.width - width) / 2;
+        let y = area.y + (area.height - height) / 2;
+        let profile_area = Rect::new(x, y, width, height);
+
+        let text: Vec<Line> = lines.iter().map(|l| Line::from(format!(" {l}"))).collect();
+        let block = Block::default()
+            .title(" Usage Profile ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::Black));
+
+        Clear.render(profile_area, buf);
+        Paragraph::new(text).block(block).render(profile_area, buf);
+    }
 }
 
 impl Widget for Dashboard<'_> {
@@ -271,6 +297,10 @@ impl Widget for Dashboard<'_> {
             self.render_footer(chunks[2], buf);
         }
 
+        if let Some(lines) = self.profile {
+            Self::render_profile(lines, area, buf);
+        }
+
         // Render help overlay if active
         if self.show_help {
             self.render_help(area, buf);
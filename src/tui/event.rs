@@ -72,6 +72,8 @@ pub enum KeyAction {
     Select,
     /// Toggle help.
     Help,
+    /// Toggle the usage profile of the selected provider.
+    Profile,
     /// No action.
     None,
 }
@@ -90,6 +92,7 @@ impl KeyAction {
             KeyCode::Right | KeyCode::Char('l') => Self::Right,
             KeyCode::Enter | KeyCode::Char(' ') => Self::Select,
             KeyCode::Char('?') | KeyCode::F(1) => Self::Help,
+            KeyCode::Char('p') => Self::Profile,
             _ => Self::None,
         }
     }
@@ -7,7 +7,12 @@ use ratatui::{
     widgets::{Block, Borders, Gauge, Paragraph, Widget},
 };
 
-use crate::core::models::ProviderPayload;
+use crate::core::models::{ProviderPayload, RateWindow};
+use crate::util::format::{display_width, truncate_to_width};
+use crate::util::time::humanize_reset;
+
+/// Label shown before the account email.
+const ACCOUNT_LABEL: &str = "Account: ";
 
 /// A panel displaying a single provider's usage information.
 pub struct ProviderPanel<'a> {
@@ -35,18 +40,24 @@ impl<'a> ProviderPanel<'a> {
         }
     }
 
-    /// Format duration to human-readable string.
-    fn format_reset_time(minutes: Option<i32>) -> String {
-        match minutes {
+    /// Humanized reset time, or the window length when the reset time is
+    /// unknown.
+    fn format_reset_time(window: &RateWindow) -> String {
+        if let Some(resets_at) = window.resets_at {
+            return humanize_reset(resets_at);
+        }
+        let length = match window.window_minutes {
             Some(m) if m < 60 => format!("{m}m"),
             Some(m) if m < 1440 => format!("{}h {}m", m / 60, m % 60),
             Some(m) => format!("{}d", m / 1440),
             None => "unknown".to_string(),
-        }
+        };
+        format!("resets {length}")
     }
 
-    /// Build usage lines for display.
-    fn build_usage_lines(&self) -> Vec<Line<'a>> {
+    /// Build usage lines for display, truncating free-form text to `width` columns.
+    fn build_usage_lines(&self, width: u16) -> Vec<Line<'a>> {
+        let width = usize::from(width);
         let mut lines = Vec::new();
         let usage = &self.payload.usage;
 
@@ -54,14 +65,14 @@ impl<'a> ProviderPanel<'a> {
         if let Some(primary) = &usage.primary {
             let remaining = primary.remaining_percent();
             let color = Self::usage_color(remaining);
-            let reset = Self::format_reset_time(primary.window_minutes);
+            let reset = Self::format_reset_time(primary);
             lines.push(Line::from(vec![
                 Span::raw("Session: "),
                 Span::styled(
                     format!("{remaining:.0}%"),
                     Style::default().fg(color).add_modifier(Modifier::BOLD),
                 ),
-                Span::raw(format!(" (resets {reset})")),
+                Span::raw(format!(" ({reset})")),
             ]));
         }
 
@@ -69,14 +80,14 @@ impl<'a> ProviderPanel<'a> {
         if let Some(secondary) = &usage.secondary {
             let remaining = secondary.remaining_percent();
             let color = Self::usage_color(remaining);
-            let reset = Self::format_reset_time(secondary.window_minutes);
+            let reset = Self::format_reset_time(secondary);
             lines.push(Line::from(vec![
                 Span::raw("Weekly:  "),
                 Span::styled(
                     format!("{remaining:.0}%"),
                     Style::default().fg(color).add_modifier(Modifier::BOLD),
                 ),
-                Span::raw(format!(" (resets {reset})")),
+                Span::raw(format!(" ({reset})")),
             ]));
         }
 
@@ -97,9 +108,10 @@ impl<'a> ProviderPanel<'a> {
         if let Some(identity) = &usage.identity
             && let Some(email) = &identity.account_email
         {
+            let available = width.saturating_sub(display_width(ACCOUNT_LABEL));
             lines.push(Line::from(vec![
-                Span::styled("Account: ", Style::default().fg(Color::DarkGray)),
-                Span::raw(email.clone()),
+                Span::styled(ACCOUNT_LABEL, Style::default().fg(Color::DarkGray)),
+                Span::raw(truncate_to_width(email, available)),
             ]));
         }
 
@@ -135,10 +147,11 @@ impl<'a> ProviderPanel<'a> {
                 StatusIndicator::Unknown => ("?", Color::DarkGray),
             };
             let description = status.description.as_deref().unwrap_or("Unknown status");
+            let available = width.saturating_sub(display_width(status_icon) + 1);
             lines.push(Line::from(vec![
                 Span::styled(status_icon, Style::default().fg(status_color)),
                 Span::raw(" "),
-                Span::raw(description.to_string()),
+                Span::raw(truncate_to_width(description, available)),
             ]));
         }
 
@@ -151,7 +164,13 @@ impl Widget for ProviderPanel<'_> {
         // Build the provider name for the title
         let provider_name = &self.payload.provider;
         let source = &self.payload.source;
-        let title = format!(" {provider_name} ({source}) ");
+        let title = format!(
+            " {} ",
+            truncate_to_width(
+                &format!("{provider_name} ({source})"),
+                usize::from(area.width.saturating_sub(4)),
+            )
+        );
 
         // Create the block with appropriate styling
         let border_style = if self.selected {
@@ -195,8 +214,82 @@ impl Widget for ProviderPanel<'_> {
         }
 
         // Render usage details
-        let lines = self.build_usage_lines();
+        let lines = self.build_usage_lines(chunks[1].width);
         let paragraph = Paragraph::new(lines);
         paragraph.render(chunks[1], buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::models::ProviderIdentity;
+    use crate::test_utils::make_test_provider_payload;
+    use ratatui::buffer::Buffer;
+
+    fn render_to_lines(payload: &ProviderPayload, width: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, width, 12);
+        let mut buf = Buffer::empty(area);
+        ProviderPanel::new(payload, false).render(area, &mut buf);
+
+        (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol().to_string())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    fn with_email(email: &str) -> ProviderPayload {
+        let mut payload = make_test_provider_payload("claude", "oauth");
+        payload.usage.identity = Some(ProviderIdentity {
+            account_email: Some(email.to_string()),
+            account_organization: None,
+            login_method: None,
+        });
+        payload
+    }
+
+    #[test]
+    fn wide_email_is_truncated_with_ellipsis() {
+        let payload = with_email("田中太郎田中太郎田中太郎@example.co.jp");
+        let lines = render_to_lines(&payload, 30);
+
+        let account = lines
+            .iter()
+            .find(|l| l.contains("Account:"))
+            .expect("account line rendered");
+        assert!(account.contains('…'), "expected ellipsis in {account:?}");
+        assert!(!account.contains("example"));
+    }
+
+    #[test]
+    fn short_mixed_width_email_is_untouched() {
+        let payload = with_email("🚀@例え.jp");
+        let lines = render_to_lines(&payload, 40);
+
+        let account = lines
+            .iter()
+            .find(|l| l.contains("Account:"))
+            .expect("account line rendered");
+        assert!(!account.contains('…'));
+    }
+
+    #[test]
+    fn usage_lines_fit_requested_width() {
+        let payload = with_email("🚀🚀🚀🚀🚀🚀🚀🚀🚀🚀@example.com");
+        for line in payload_lines(&payload, 20) {
+            assert!(display_width(&line) <= 20, "line too wide: {line:?}");
+        }
+    }
+
+    fn payload_lines(payload: &ProviderPayload, width: u16) -> Vec<String> {
+        ProviderPanel::new(payload, false)
+            .build_usage_lines(width)
+            .iter()
+            .filter(|line| line.spans.iter().any(|s| s.content.contains(ACCOUNT_LABEL)))
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+}
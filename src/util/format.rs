@@ -1,4 +1,8 @@
-//! Number formatting utilities.
+//! Number and text formatting utilities.
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::error::{CautError, Result};
 
 /// Format a percentage with color threshold hints.
 #[must_use]
@@ -32,10 +36,195 @@ pub fn format_tokens(value: i64) -> String {
     }
 }
 
+/// Format a byte count in binary units, e.g. "1.50 MB".
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // byte sizes fit comfortably in f64
+pub fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.2} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.2} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.2} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} bytes")
+    }
+}
+
+/// Parse a size such as `100MB`, `1.5G`, `512k`, or a plain byte count.
+/// Units are binary (1 MB = 1024 KB), matching [`format_bytes`].
+///
+/// # Errors
+/// Returns an error if the number or unit is not recognized.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)] // checked to be finite, non-negative, and below u64::MAX
+pub fn parse_size_bytes(input: &str) -> Result<u64> {
+    let trimmed = input.trim();
+    let split = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(split);
+    let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1024,
+        "m" | "mb" | "mib" => 1024 * 1024,
+        "g" | "gb" | "gib" => 1024 * 1024 * 1024,
+        _ => 0,
+    };
+    let bytes = number.parse::<f64>().ok().map(|n| n * multiplier as f64);
+    match bytes {
+        Some(bytes) if multiplier > 0 && bytes.is_finite() && bytes < u64::MAX as f64 => {
+            Ok(bytes.round() as u64)
+        }
+        _ => Err(CautError::Config(format!(
+            "Unrecognized size '{input}' (e.g. 100MB, 1.5GB, 512KB)"
+        ))),
+    }
+}
+
+/// Terminal display width of a string (CJK and most emoji take two columns).
+#[must_use]
+pub fn display_width(s: &str) -> usize {
+    UnicodeWidthStr::width(s)
+}
+
+/// Truncate a string to at most `max_width` columns, ending with `…` if cut.
+#[must_use]
+pub fn truncate_to_width(s: &str, max_width: usize) -> String {
+    if display_width(s) <= max_width {
+        return s.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    // Reserve one column for the ellipsis.
+    let budget = max_width - 1;
+    let mut used = 0;
+    let mut out = String::new();
+    for c in s.chars() {
+        let w = UnicodeWidthChar::width(c).unwrap_or(0);
+        if used + w > budget {
+            break;
+        }
+        used += w;
+        out.push(c);
+    }
+    out.push('…');
+    out
+}
+
+/// Truncate a string to at most `max_width` columns by eliding its middle.
+///
+/// Keeps both ends visible, which suits IDs and emails where the prefix and
+/// suffix are the distinguishing parts (e.g. `acct_12…90ab`).
+#[must_use]
+pub fn truncate_middle(s: &str, max_width: usize) -> String {
+    if display_width(s) <= max_width {
+        return s.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    // Reserve one column for the ellipsis; give the head any odd column.
+    let budget = max_width - 1;
+    let tail_budget = budget / 2;
+    let head_budget = budget - tail_budget;
+
+    let mut head = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = UnicodeWidthChar::width(c).unwrap_or(0);
+        if used + w > head_budget {
+            break;
+        }
+        used += w;
+        head.push(c);
+    }
+
+    let mut tail: Vec<char> = Vec::new();
+    let mut used = 0;
+    for c in s.chars().rev() {
+        let w = UnicodeWidthChar::width(c).unwrap_or(0);
+        if used + w > tail_budget {
+            break;
+        }
+        used += w;
+        tail.push(c);
+    }
+    tail.reverse();
+
+    format!("{head}…{}", tail.into_iter().collect::<String>())
+}
+
+/// Right-pad a string with spaces to `width` columns.
+///
+/// Strings already at or beyond `width` are returned unchanged.
+#[must_use]
+pub fn pad_to_width(s: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(s));
+    format!("{s}{}", " ".repeat(padding))
+}
+
+/// Wrap `text` in an OSC 8 terminal hyperlink pointing at `url`.
+///
+/// The escape sequences take no columns, so width calculations on the
+/// surrounding layout stay valid. Only emit this when the terminal is known to
+/// support hyperlinks; elsewhere the sequences show up as garbage.
+#[must_use]
+pub fn hyperlink(url: &str, text: &str) -> String {
+    format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+}
+
+/// Quote a CSV field if it contains a comma, quote, or newline.
+#[must_use]
+pub fn csv_escape(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn sizes_parse_in_binary_units() {
+        assert_eq!(parse_size_bytes("100MB").unwrap(), 100 * 1024 * 1024);
+        assert_eq!(parse_size_bytes("1.5g").unwrap(), 1_610_612_736);
+        assert_eq!(parse_size_bytes("512 KiB").unwrap(), 512 * 1024);
+        assert_eq!(parse_size_bytes("4096").unwrap(), 4096);
+        assert!(parse_size_bytes("lots").is_err());
+        assert!(parse_size_bytes("10TB").is_err());
+        assert!(parse_size_bytes("MB").is_err());
+    }
+
+    #[test]
+    fn csv_escape_quotes_only_when_needed() {
+        assert_eq!(csv_escape("claude"), "claude");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_escape("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn hyperlink_wraps_text_in_osc8() {
+        assert_eq!(
+            hyperlink("https://example.com", "example"),
+            "\x1b]8;;https://example.com\x1b\\example\x1b]8;;\x1b\\"
+        );
+    }
+
     #[test]
     fn format_tokens_millions() {
         assert_eq!(format_tokens(1_500_000), "1.5M");
@@ -50,4 +239,59 @@ mod tests {
     fn format_tokens_small() {
         assert_eq!(format_tokens(500), "500");
     }
+
+    #[test]
+    fn display_width_counts_wide_chars() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("田中"), 4);
+        assert_eq!(display_width("🚀x"), 3);
+    }
+
+    #[test]
+    fn truncate_to_width_keeps_short_strings() {
+        assert_eq!(truncate_to_width("dev@example.com", 20), "dev@example.com");
+    }
+
+    #[test]
+    fn truncate_to_width_respects_wide_chars() {
+        let out = truncate_to_width("田中太郎@example.com", 8);
+        assert_eq!(out, "田中太…");
+        assert!(display_width(&out) <= 8);
+
+        // A wide char that would straddle the limit is dropped entirely.
+        let out = truncate_to_width("a田中", 4);
+        assert_eq!(out, "a田…");
+        assert_eq!(display_width(&out), 4);
+    }
+
+    #[test]
+    fn truncate_to_width_handles_emoji_and_zero() {
+        let out = truncate_to_width("🚀🚀🚀🚀", 5);
+        assert_eq!(out, "🚀🚀…");
+        assert_eq!(truncate_to_width("abc", 0), "");
+    }
+
+    #[test]
+    fn truncate_middle_keeps_both_ends() {
+        assert_eq!(truncate_middle("acct_1234567890abcdef", 11), "acct_…bcdef");
+        assert_eq!(truncate_middle("short", 10), "short");
+        assert_eq!(truncate_middle("abcdef", 0), "");
+        assert_eq!(truncate_middle("abcdef", 1), "…");
+    }
+
+    #[test]
+    fn truncate_middle_respects_wide_chars() {
+        let out = truncate_middle("田中太郎@example.co.jp", 12);
+        assert!(display_width(&out) <= 12);
+        assert!(out.starts_with("田中"));
+        assert!(out.ends_with("co.jp"));
+    }
+
+    #[test]
+    fn pad_to_width_uses_display_width() {
+        assert_eq!(pad_to_width("ab", 4), "ab  ");
+        assert_eq!(pad_to_width("田", 4), "田  ");
+        assert_eq!(display_width(&pad_to_width("田中x", 8)), 8);
+        assert_eq!(pad_to_width("toolong", 3), "toolong");
+    }
 }
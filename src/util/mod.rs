@@ -4,5 +4,11 @@ pub mod env;
 pub mod format;
 pub mod time;
 
-pub use format::{format_cost, format_percent, format_tokens};
-pub use time::{format_countdown, format_relative_time};
+pub use format::{
+    display_width, format_cost, format_percent, format_tokens, hyperlink, pad_to_width,
+    truncate_middle, truncate_to_width,
+};
+pub use time::{
+    format_countdown, format_relative_time, parse_duration_secs, parse_humane_datetime,
+    parse_humane_duration, parse_humane_range,
+};
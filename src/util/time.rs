@@ -1,11 +1,24 @@
-//! Time formatting utilities.
+//! Time formatting and parsing utilities.
 
-use chrono::{DateTime, Utc};
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+    Utc, Weekday,
+};
+
+use crate::core::clock_skew;
+use crate::error::{CautError, Result};
+
+/// Examples shown when a date expression cannot be parsed.
+const DATE_EXAMPLES: &str = "e.g. 2026-01-15, today, yesterday 6pm, last friday, mon 9am, \
+                             14:30, 3d, 2h ago, now, or RFC3339";
+
+/// Examples shown when a duration cannot be parsed.
+const DURATION_EXAMPLES: &str = "e.g. 90 (seconds), 45s, 90m, 2h30m, 3d, 1w";
 
 /// Format a countdown to a future time.
 #[must_use]
 pub fn format_countdown(target: DateTime<Utc>) -> String {
-    let now = Utc::now();
+    let now = clock_skew::now();
     let duration = target.signed_duration_since(now);
 
     if duration.num_seconds() <= 0 {
@@ -60,10 +73,491 @@ pub fn format_relative_time(target: DateTime<Utc>) -> String {
     }
 }
 
+/// Describe when a usage window resets, relative to now in local time.
+///
+/// Shared by every renderer so a reset reads the same everywhere:
+/// `resets in 40m`, `resets today at 3pm`, `resets tonight at 11pm`,
+/// `resets tonight at midnight`, `resets tomorrow at 9:30am`,
+/// `resets Monday morning`, or `resets Nov 3` beyond the coming week.
+#[must_use]
+pub fn humanize_reset(target: DateTime<Utc>) -> String {
+    reset_phrase(
+        target.with_timezone(&Local).naive_local(),
+        clock_skew::now().with_timezone(&Local).naive_local(),
+    )
+}
+
+fn reset_phrase(target: NaiveDateTime, now: NaiveDateTime) -> String {
+    let until = target - now;
+    if until <= Duration::zero() {
+        return "resets now".to_string();
+    }
+    if until < Duration::hours(1) {
+        return format!("resets in {}m", until.num_minutes().max(1));
+    }
+
+    let time = target.time();
+    match (target.date() - now.date()).num_days() {
+        0 if time.hour() >= 18 => format!("resets tonight at {}", clock_phrase(time)),
+        0 => format!("resets today at {}", clock_phrase(time)),
+        1 if time == NaiveTime::MIN => "resets tonight at midnight".to_string(),
+        1 => format!("resets tomorrow at {}", clock_phrase(time)),
+        2..=6 if time.hour() < 5 => format!("resets early {} morning", target.format("%A")),
+        2..=6 => format!("resets {} {}", target.format("%A"), part_of_day(time)),
+        _ => format!("resets {}", target.format("%b %-d")),
+    }
+}
+
+/// `11pm`, `9:30am`, `noon`, or `midnight`.
+fn clock_phrase(time: NaiveTime) -> String {
+    match (time.hour(), time.minute()) {
+        (0, 0) => "midnight".to_string(),
+        (12, 0) => "noon".to_string(),
+        (hour, minute) => {
+            let meridiem = if hour < 12 { "am" } else { "pm" };
+            let hour = match hour % 12 {
+                0 => 12,
+                h => h,
+            };
+            if minute == 0 {
+                format!("{hour}{meridiem}")
+            } else {
+                format!("{hour}:{minute:02}{meridiem}")
+            }
+        }
+    }
+}
+
+const fn part_of_day(time: NaiveTime) -> &'static str {
+    match time.hour() {
+        5..=11 => "morning",
+        12..=16 => "afternoon",
+        17..=20 => "evening",
+        _ => "night",
+    }
+}
+
+// =============================================================================
+// Humane Date Parsing
+// =============================================================================
+
+/// How a bare weekday name is resolved relative to a reference date.
+#[derive(Debug, Clone, Copy)]
+enum WeekdayAnchor {
+    /// Most recent such day on or before the date.
+    OnOrBefore(NaiveDate),
+    /// Next such day on or after the date.
+    OnOrAfter(NaiveDate),
+}
+
+/// Parse a humane duration such as `90m`, `2h30m`, `3 days`, or `1w`.
+///
+/// Units are `s`, `m`, `h`, `d`, and `w` (long forms like `mins` and `hours`
+/// also work). A bare number is taken as seconds.
+///
+/// # Errors
+/// Returns an error listing accepted forms if the expression is not recognized.
+pub fn parse_humane_duration(input: &str) -> Result<Duration> {
+    parse_duration_expr(input, true).ok_or_else(|| {
+        CautError::Config(format!(
+            "Unrecognized duration '{input}' ({DURATION_EXAMPLES})"
+        ))
+    })
+}
+
+/// Parse a humane duration into whole seconds.
+///
+/// Suitable as a clap value parser for flags that historically took seconds.
+///
+/// # Errors
+/// Returns an error listing accepted forms if the expression is not recognized.
+pub fn parse_duration_secs(input: &str) -> Result<u64> {
+    let duration = parse_humane_duration(input)?;
+    u64::try_from(duration.num_seconds()).map_err(|_| {
+        CautError::Config(format!(
+            "Unrecognized duration '{input}' ({DURATION_EXAMPLES})"
+        ))
+    })
+}
+
+/// Parse a humane date/time expression in local time.
+///
+/// Accepts RFC3339, `YYYY-MM-DD`, `today`/`yesterday`/`tomorrow`, and weekday
+/// names (`mon`, `last friday`), each optionally followed by a time such as
+/// `9am`, `6:30pm`, `18:00`, `noon`, or `midnight`. A bare time means today,
+/// `now` is the current instant, and weekday names refer to the most recent
+/// such day. Durations (`3d`, `2h ago`) count back from now.
+///
+/// # Errors
+/// Returns an error listing accepted forms if the expression is not recognized.
+pub fn parse_humane_datetime(input: &str) -> Result<DateTime<Utc>> {
+    let today = Local::now().date_naive();
+    parse_instant(input, WeekdayAnchor::OnOrBefore(today), false)
+}
+
+/// Parse a humane `start`/`end` pair, e.g. `"mon 9am"` to `"fri 6pm"`.
+///
+/// The start is parsed like [`parse_humane_datetime`]. Weekday names in the
+/// end resolve to the first such day on or after the start, and an end
+/// without a time covers the whole day.
+///
+/// # Errors
+/// Returns an error if either expression is not recognized or the end is
+/// before the start.
+pub fn parse_humane_range(start: &str, end: &str) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let from = parse_humane_datetime(start)?;
+    let start_date = from.with_timezone(&Local).date_naive();
+    let to = parse_instant(end, WeekdayAnchor::OnOrAfter(start_date), true)?;
+
+    if to < from {
+        return Err(CautError::Config(format!(
+            "Range end '{end}' is before start '{start}'"
+        )));
+    }
+    Ok((from, to))
+}
+
+fn parse_instant(input: &str, anchor: WeekdayAnchor, end_of_day: bool) -> Result<DateTime<Utc>> {
+    let trimmed = input.trim();
+    if trimmed.eq_ignore_ascii_case("now") {
+        return Ok(Utc::now());
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    if let Some(ago) = parse_ago(trimmed) {
+        return Ok(Utc::now() - ago);
+    }
+
+    let today = Local::now().date_naive();
+    let naive =
+        parse_local(trimmed, anchor, today, end_of_day).ok_or_else(|| invalid_date(input))?;
+    local_to_utc(naive, input)
+}
+
+/// Parse `3d`, `2h ago`, or `90 minutes ago` as a duration back from now.
+///
+/// Units are required so bare numbers are not mistaken for dates.
+fn parse_ago(input: &str) -> Option<Duration> {
+    let lower = input.to_ascii_lowercase();
+    let expr = lower.strip_suffix("ago").unwrap_or(&lower);
+    parse_duration_expr(expr, false)
+}
+
+/// Parse a sequence of `<number><unit>` parts.
+fn parse_duration_expr(input: &str, bare_seconds: bool) -> Option<Duration> {
+    let compact: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if compact.is_empty() {
+        return None;
+    }
+    if bare_seconds && compact.bytes().all(|b| b.is_ascii_digit()) {
+        return Duration::try_seconds(compact.parse().ok()?);
+    }
+
+    let mut total: i64 = 0;
+    let mut rest = compact.as_str();
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return None;
+        }
+        let value: i64 = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit = unit_seconds(&rest[..unit_len])?;
+        rest = &rest[unit_len..];
+
+        total = total.checked_add(value.checked_mul(unit)?)?;
+    }
+    Duration::try_seconds(total)
+}
+
+fn unit_seconds(unit: &str) -> Option<i64> {
+    let seconds = match unit {
+        "s" | "sec" | "secs" | "second" | "seconds" => 1,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3_600,
+        "d" | "day" | "days" => 86_400,
+        "w" | "wk" | "wks" | "week" | "weeks" => 604_800,
+        _ => return None,
+    };
+    Some(seconds)
+}
+
+fn invalid_date(input: &str) -> CautError {
+    CautError::Config(format!("Unrecognized date '{input}' ({DATE_EXAMPLES})"))
+}
+
+fn local_to_utc(naive: NaiveDateTime, input: &str) -> Result<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok_or_else(|| invalid_date(input))
+}
+
+/// Parse a local date expression without consulting the clock.
+fn parse_local(
+    input: &str,
+    anchor: WeekdayAnchor,
+    today: NaiveDate,
+    end_of_day: bool,
+) -> Option<NaiveDateTime> {
+    let lower = input.trim().to_ascii_lowercase();
+    let mut tokens: Vec<&str> = lower.split_whitespace().collect();
+    if tokens.is_empty() {
+        return None;
+    }
+
+    let date = if tokens[0] == "last" {
+        // "last friday" is the most recent Friday strictly before today.
+        let weekday = parse_weekday(tokens.get(1)?)?;
+        tokens.drain(..2);
+        resolve_weekday(weekday, WeekdayAnchor::OnOrBefore(today.pred_opt()?))
+    } else if let Some(date) = parse_day(tokens[0], anchor, today) {
+        tokens.remove(0);
+        date
+    } else {
+        today
+    };
+
+    let time = if tokens.is_empty() {
+        if end_of_day {
+            NaiveTime::from_hms_opt(23, 59, 59)?
+        } else {
+            NaiveTime::MIN
+        }
+    } else {
+        parse_time_of_day(&tokens.concat())?
+    };
+
+    Some(date.and_time(time))
+}
+
+fn parse_day(token: &str, anchor: WeekdayAnchor, today: NaiveDate) -> Option<NaiveDate> {
+    match token {
+        "today" => return Some(today),
+        "yesterday" => return today.pred_opt(),
+        "tomorrow" => return today.succ_opt(),
+        _ => {}
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(token, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    parse_weekday(token).map(|weekday| resolve_weekday(weekday, anchor))
+}
+
+fn resolve_weekday(weekday: Weekday, anchor: WeekdayAnchor) -> NaiveDate {
+    match anchor {
+        WeekdayAnchor::OnOrBefore(date) => {
+            let back =
+                (7 + date.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+            date - Duration::days(i64::from(back))
+        }
+        WeekdayAnchor::OnOrAfter(date) => {
+            let forward =
+                (7 + weekday.num_days_from_monday() - date.weekday().num_days_from_monday()) % 7;
+            date + Duration::days(i64::from(forward))
+        }
+    }
+}
+
+fn parse_weekday(token: &str) -> Option<Weekday> {
+    let weekday = match token {
+        "mon" | "monday" => Weekday::Mon,
+        "tue" | "tues" | "tuesday" => Weekday::Tue,
+        "wed" | "wednesday" => Weekday::Wed,
+        "thu" | "thur" | "thurs" | "thursday" => Weekday::Thu,
+        "fri" | "friday" => Weekday::Fri,
+        "sat" | "saturday" => Weekday::Sat,
+        "sun" | "sunday" => Weekday::Sun,
+        _ => return None,
+    };
+    Some(weekday)
+}
+
+/// Parse `9am`, `9:30pm`, `18:00`, `noon`, or `midnight`.
+fn parse_time_of_day(token: &str) -> Option<NaiveTime> {
+    match token {
+        "noon" => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => return Some(NaiveTime::MIN),
+        _ => {}
+    }
+
+    let (clock, meridiem) = if let Some(rest) = token.strip_suffix("am") {
+        (rest, Some(false))
+    } else if let Some(rest) = token.strip_suffix("pm") {
+        (rest, Some(true))
+    } else {
+        (token, None)
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((h, m)) => (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?),
+        None => (clock.parse::<u32>().ok()?, 0),
+    };
+
+    let hour = match meridiem {
+        Some(pm) => {
+            if !(1..=12).contains(&hour) {
+                return None;
+            }
+            (hour % 12) + if pm { 12 } else { 0 }
+        }
+        // A bare number without a colon is too ambiguous to treat as a time.
+        None if !clock.contains(':') => return None,
+        None => hour,
+    };
+
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Duration;
+
+    // 2026-01-14 is a Wednesday.
+    fn wednesday() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 14).unwrap()
+    }
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 1, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn parse_past(input: &str) -> Option<NaiveDateTime> {
+        parse_local(
+            input,
+            WeekdayAnchor::OnOrBefore(wednesday()),
+            wednesday(),
+            false,
+        )
+    }
+
+    #[test]
+    fn parses_weekday_with_time() {
+        assert_eq!(parse_past("mon 9am"), Some(at(12, 9, 0)));
+        assert_eq!(parse_past("Wednesday 6:30pm"), Some(at(14, 18, 30)));
+        assert_eq!(parse_past("fri 18:00"), Some(at(9, 18, 0)));
+    }
+
+    #[test]
+    fn parses_relative_days_and_bare_times() {
+        assert_eq!(parse_past("today"), Some(at(14, 0, 0)));
+        assert_eq!(parse_past("yesterday 6pm"), Some(at(13, 18, 0)));
+        assert_eq!(parse_past("9 am"), Some(at(14, 9, 0)));
+        assert_eq!(parse_past("noon"), Some(at(14, 12, 0)));
+        assert_eq!(parse_past("2026-01-02 12am"), Some(at(2, 0, 0)));
+    }
+
+    #[test]
+    fn weekday_end_resolves_forward_to_end_of_day() {
+        let end = parse_local(
+            "fri",
+            WeekdayAnchor::OnOrAfter(at(12, 0, 0).date()),
+            wednesday(),
+            true,
+        );
+        assert_eq!(
+            end,
+            NaiveDate::from_ymd_opt(2026, 1, 16)
+                .unwrap()
+                .and_hms_opt(23, 59, 59)
+        );
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert_eq!(parse_past("someday"), None);
+        assert_eq!(parse_past("mon 13pm"), None);
+        assert_eq!(parse_past("9"), None);
+        assert_eq!(parse_past(""), None);
+        assert!(parse_humane_datetime("not a date").is_err());
+    }
+
+    #[test]
+    fn last_weekday_is_strictly_before_today() {
+        assert_eq!(parse_past("last wed"), Some(at(7, 0, 0)));
+        assert_eq!(parse_past("last friday 5pm"), Some(at(9, 17, 0)));
+        assert_eq!(parse_past("last"), None);
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_humane_duration("90m").unwrap(), Duration::minutes(90));
+        assert_eq!(
+            parse_humane_duration("2h30m").unwrap(),
+            Duration::minutes(150)
+        );
+        assert_eq!(parse_humane_duration("3 days").unwrap(), Duration::days(3));
+        assert_eq!(parse_humane_duration("1w").unwrap(), Duration::weeks(1));
+        assert_eq!(parse_duration_secs("45").unwrap(), 45);
+        assert_eq!(parse_duration_secs("5m").unwrap(), 300);
+    }
+
+    #[test]
+    fn rejects_bad_durations() {
+        for input in ["", "m", "3x", "h2", "1.5h", "-5m", "-5"] {
+            let err = parse_humane_duration(input).unwrap_err();
+            assert!(err.to_string().contains("90m"), "{input}: {err}");
+        }
+    }
+
+    #[test]
+    fn relative_durations_count_back_from_now() {
+        let before = Utc::now();
+        let three_days = parse_humane_datetime("3d").unwrap();
+        let two_hours = parse_humane_datetime("2 hours ago").unwrap();
+        assert!(three_days <= before - Duration::days(3) + Duration::seconds(5));
+        assert!(three_days >= before - Duration::days(3) - Duration::seconds(5));
+        assert!(two_hours < Utc::now() - Duration::minutes(119));
+    }
+
+    #[test]
+    fn humane_range_orders_weekdays() {
+        let (from, to) = parse_humane_range("mon 9am", "fri 6pm").unwrap();
+        assert!(from < to);
+        assert!(parse_humane_range("2026-01-10", "2026-01-02").is_err());
+    }
+
+    #[test]
+    fn rfc3339_passes_through() {
+        let dt = parse_humane_datetime("2026-01-15T12:00:00Z").unwrap();
+        assert_eq!(dt, Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn reset_phrases_are_relative_to_now() {
+        // Wednesday 2026-01-14, 2pm.
+        let now = at(14, 14, 0);
+        let phrase = |target| reset_phrase(target, now);
+
+        assert_eq!(phrase(at(14, 13, 0)), "resets now");
+        assert_eq!(phrase(at(14, 14, 40)), "resets in 40m");
+        assert_eq!(phrase(at(14, 17, 0)), "resets today at 5pm");
+        assert_eq!(phrase(at(14, 23, 0)), "resets tonight at 11pm");
+        assert_eq!(phrase(at(15, 0, 0)), "resets tonight at midnight");
+        assert_eq!(phrase(at(15, 9, 30)), "resets tomorrow at 9:30am");
+        assert_eq!(phrase(at(15, 12, 0)), "resets tomorrow at noon");
+        assert_eq!(phrase(at(19, 8, 0)), "resets Monday morning");
+        assert_eq!(phrase(at(17, 19, 0)), "resets Saturday evening");
+        assert_eq!(phrase(at(19, 0, 0)), "resets early Monday morning");
+        assert_eq!(phrase(at(28, 9, 0)), "resets Jan 28");
+    }
 
     #[test]
     fn countdown_hours() {
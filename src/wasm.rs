@@ -0,0 +1,208 @@
+//! WebAssembly bindings for the parsing, pricing, and formatting core.
+//!
+//! Built with `--no-default-features --features wasm`, which leaves out
+//! everything that needs the network, the filesystem, tokio, or the keyring
+//! (see the `native` feature). A web dashboard loading exported JSON can then
+//! price sessions and format numbers with exactly the math `caut cost` uses.
+//!
+//! Inputs and outputs are plain JavaScript objects with camelCase keys.
+//! Token counts are accepted as numbers; `priceSession` takes a parsed
+//! session log or an exported daily cost entry (`modelsUsed` is accepted for
+//! `models`).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::core::pricing::{PricingTable, SessionCostCalculator};
+use crate::core::session_logs::{SessionUsage, parse_session_jsonl};
+use crate::util::format;
+use crate::util::time::humanize_reset;
+
+/// Token totals and models for one session.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionInput {
+    #[serde(default, alias = "modelsUsed")]
+    pub models: Vec<String>,
+    #[serde(default)]
+    pub input_tokens: i64,
+    #[serde(default)]
+    pub output_tokens: i64,
+    #[serde(default)]
+    pub cache_read_tokens: i64,
+    #[serde(default)]
+    pub cache_creation_tokens: i64,
+    #[serde(default)]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub ended_at: Option<DateTime<Utc>>,
+    /// Cost the agent logged itself; preferred over the computed total.
+    #[serde(default)]
+    pub reported_cost_usd: Option<f64>,
+}
+
+impl From<SessionUsage> for SessionInput {
+    fn from(usage: SessionUsage) -> Self {
+        let mut models: Vec<String> = usage.models_used.into_iter().collect();
+        models.sort();
+        Self {
+            models,
+            input_tokens: usage.input_tokens,
+            output_tokens: usage.output_tokens,
+            cache_read_tokens: usage.cache_read_tokens,
+            cache_creation_tokens: usage.cache_creation_tokens,
+            started_at: usage.started_at,
+            ended_at: usage.ended_at,
+            reported_cost_usd: usage.reported_cost_usd,
+        }
+    }
+}
+
+impl SessionInput {
+    fn to_usage(&self) -> SessionUsage {
+        SessionUsage {
+            input_tokens: self.input_tokens,
+            output_tokens: self.output_tokens,
+            cache_read_tokens: self.cache_read_tokens,
+            cache_creation_tokens: self.cache_creation_tokens,
+            models_used: self.models.iter().cloned().collect(),
+            started_at: self.started_at,
+            ended_at: self.ended_at,
+            reported_cost_usd: self.reported_cost_usd,
+            ..SessionUsage::default()
+        }
+    }
+}
+
+/// Cost of one session, as computed by `caut cost`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PricedSession {
+    pub total_usd: f64,
+    pub input_cost_usd: f64,
+    pub output_cost_usd: f64,
+    pub cache_read_cost_usd: f64,
+    pub cache_creation_cost_usd: f64,
+    /// Model the session was priced as.
+    pub model: String,
+    /// Whether the model has published pricing (otherwise estimated).
+    pub model_known: bool,
+    /// `high`, `medium`, `low`, or `unknown`.
+    pub confidence: String,
+}
+
+/// Price a session with the built-in pricing table.
+#[must_use]
+pub fn price(session: &SessionInput) -> PricedSession {
+    let cost = SessionCostCalculator::new().calculate(&session.to_usage());
+    PricedSession {
+        total_usd: cost.total_usd,
+        input_cost_usd: cost.breakdown.input_cost_usd,
+        output_cost_usd: cost.breakdown.output_cost_usd,
+        cache_read_cost_usd: cost.breakdown.cache_read_cost_usd,
+        cache_creation_cost_usd: cost.breakdown.cache_creation_cost_usd,
+        model: cost.model,
+        model_known: cost.model_known,
+        confidence: format!("{:?}", cost.confidence).to_lowercase(),
+    }
+}
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    serde_wasm_bindgen::to_value(value).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Total the tokens and models in a Claude or Codex session log (JSONL text).
+///
+/// # Errors
+/// Returns an error if the result cannot be converted to a JavaScript value.
+#[wasm_bindgen(js_name = parseSessionLog)]
+pub fn parse_session_log(text: &str) -> Result<JsValue, JsError> {
+    let usage = parse_session_jsonl(text.as_bytes(), String::new());
+    to_js(&SessionInput::from(usage))
+}
+
+/// Price a session or daily cost entry.
+///
+/// # Errors
+/// Returns an error if `session` is not a session object.
+#[wasm_bindgen(js_name = priceSession)]
+pub fn price_session(session: JsValue) -> Result<JsValue, JsError> {
+    let session: SessionInput =
+        serde_wasm_bindgen::from_value(session).map_err(|e| JsError::new(&e.to_string()))?;
+    to_js(&price(&session))
+}
+
+/// Per-million-token prices for a model, or `undefined` if it has none.
+///
+/// # Errors
+/// Returns an error if the result cannot be converted to a JavaScript value.
+#[wasm_bindgen(js_name = modelPricing)]
+pub fn model_pricing(model: &str) -> Result<JsValue, JsError> {
+    let table = PricingTable::current();
+    let Some(pricing) = table.resolve(model) else {
+        return Ok(JsValue::UNDEFINED);
+    };
+    to_js(&serde_json::json!({
+        "model": model,
+        "inputPerMillion": pricing.input_per_million,
+        "outputPerMillion": pricing.output_per_million,
+        "cacheReadPerMillion": pricing.cache_read_per_million,
+        "cacheCreationPerMillion": pricing.cache_creation_per_million,
+    }))
+}
+
+/// Format a USD amount (`$12.34`).
+#[wasm_bindgen(js_name = formatCost)]
+#[must_use]
+pub fn format_cost(value: f64) -> String {
+    format::format_cost(value)
+}
+
+/// Format a token count compactly (`1.2M`, `45.6K`).
+#[wasm_bindgen(js_name = formatTokens)]
+#[must_use]
+#[allow(clippy::cast_possible_truncation)] // JavaScript numbers carry token counts exactly up to 2^53
+pub fn format_tokens(value: f64) -> String {
+    format::format_tokens(value as i64)
+}
+
+/// Describe a reset time (RFC 3339) relative to now, as usage output does.
+///
+/// # Errors
+/// Returns an error if `resets_at` is not an RFC 3339 timestamp.
+#[wasm_bindgen(js_name = formatReset)]
+pub fn format_reset(resets_at: &str) -> Result<String, JsError> {
+    let resets_at = DateTime::parse_from_rfc3339(resets_at)
+        .map_err(|e| JsError::new(&format!("Invalid reset time: {e}")))?;
+    Ok(humanize_reset(resets_at.with_timezone(&Utc)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prices_exported_daily_entries() {
+        let entry: SessionInput = serde_json::from_str(
+            r#"{"date": "2026-10-15", "inputTokens": 1000000, "outputTokens": 0,
+                "totalCost": 3.0, "modelsUsed": ["claude-sonnet-4"]}"#,
+        )
+        .unwrap();
+        assert_eq!(entry.models, ["claude-sonnet-4"]);
+
+        let priced = price(&entry);
+        let expected = SessionCostCalculator::new().calculate(&entry.to_usage());
+        assert!((priced.total_usd - expected.total_usd).abs() < f64::EPSILON);
+        assert!(priced.model_known);
+    }
+
+    #[test]
+    fn parsed_logs_round_trip_to_inputs() {
+        let log = r#"{"timestamp":"2026-10-15T09:00:00Z","message":{"model":"claude-sonnet-4","usage":{"input_tokens":120,"output_tokens":30}}}"#;
+        let session = SessionInput::from(parse_session_jsonl(log.as_bytes(), String::new()));
+        assert_eq!(session.input_tokens, 120);
+        assert_eq!(session.output_tokens, 30);
+        assert_eq!(session.models, ["claude-sonnet-4"]);
+    }
+}
@@ -207,6 +207,10 @@ pub fn provider_payload(provider: &str, source: &str, usage: UsageSnapshot) -> P
         },
         antigravity_plan_info: None,
         openai_dashboard: None,
+        copilot_premium: None,
+        task_spend: None,
+        local_model: None,
+        divergences: Vec::new(),
         auth_warning: None,
     }
 }
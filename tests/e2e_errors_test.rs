@@ -6,6 +6,8 @@
 //! - Conflicting flags
 //! - Help/version output
 //! - Corrupted config behavior (no panic)
+//! - `--output` replacing files only on success
+//! - Unknown config keys reported in the JSON `warnings` array
 
 use assert_cmd::Command;
 use predicates::prelude::*;
@@ -182,3 +184,83 @@ fn corrupted_config_does_not_panic() {
 
     log.finish_ok();
 }
+
+#[test]
+#[allow(deprecated)]
+fn output_file_is_replaced_only_on_success() {
+    let log = TestLogger::new("output_file_is_replaced_only_on_success");
+    log.phase("setup");
+
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let report = dir.path().join("report.json");
+    std::fs::write(&report, "previous report").expect("write previous report");
+
+    log.phase("execute failing command");
+    Command::cargo_bin("caut")
+        .unwrap()
+        .args(["usage", "--provider", "invalid_provider_xyz", "--output"])
+        .arg(&report)
+        .assert()
+        .failure();
+    assert_eq!(std::fs::read_to_string(&report).unwrap(), "previous report");
+
+    log.phase("execute succeeding command");
+    Command::cargo_bin("caut")
+        .unwrap()
+        .args(["simulate", "--primary", "95", "--json", "--output"])
+        .arg(&report)
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    let written = std::fs::read_to_string(&report).unwrap();
+    assert!(written.trim_start().starts_with('{'));
+    assert_eq!(
+        std::fs::read_dir(dir.path()).unwrap().count(),
+        1,
+        "temporary file should be renamed into place"
+    );
+
+    log.finish_ok();
+}
+
+#[test]
+#[allow(deprecated)]
+fn unknown_config_keys_go_to_json_warnings() {
+    let log = TestLogger::new("unknown_config_keys_go_to_json_warnings");
+    log.phase("setup");
+
+    let mut temp_config = NamedTempFile::new().expect("create temp config");
+    writeln!(temp_config, "[general]\ntimout_seconds = 5").expect("write temp config");
+    let config = temp_config.path().to_str().expect("config path");
+
+    log.phase("execute json");
+    let output = Command::cargo_bin("caut")
+        .unwrap()
+        .env("CAUT_CONFIG", config)
+        .args(["simulate", "--primary", "50", "--json"])
+        .output()
+        .expect("run caut simulate --json");
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("JSON stdout");
+    let warnings = json["warnings"].as_array().expect("warnings array");
+    assert!(
+        warnings.iter().any(|w| w
+            .as_str()
+            .is_some_and(|w| w.contains("general.timout_seconds"))),
+        "{warnings:?}"
+    );
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("Warning:"));
+
+    log.phase("execute human");
+    Command::cargo_bin("caut")
+        .unwrap()
+        .env("CAUT_CONFIG", config)
+        .args(["simulate", "--primary", "50"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Warning: unknown config key `general.timout_seconds`",
+        ));
+
+    log.finish_ok();
+}
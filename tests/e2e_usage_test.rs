@@ -80,10 +80,13 @@ const fn make_test_cli() -> Cli {
         json: false,
         pretty: false,
         no_color: false,
+        config: None,
         log_level: None,
         json_output: false,
         verbose: false,
         debug_rich: false,
+        output: None,
+        log_root: Vec::new(),
     }
 }
 
@@ -94,15 +97,25 @@ const fn make_test_usage_args() -> UsageArgs {
         account_index: None,
         all_accounts: false,
         no_credits: false,
+        full: false,
+        links: false,
         status: false,
         source: None,
         web: false,
         timeout: None,
         web_timeout: None,
+        api_keys: Vec::new(),
         web_debug_dump_html: false,
         watch: false,
         interval: 30,
+        record: None,
+        replay: None,
         tui: false,
+        sort: None,
+        filter: Vec::new(),
+        columns: Vec::new(),
+        csv: false,
+        strict: false,
     }
 }
 
@@ -757,6 +770,7 @@ fn doctor_config_detects_xdg_config_home() {
     let stdout_str = String::from_utf8_lossy(&output.stdout);
     let json: serde_json::Value = serde_json::from_str(&stdout_str).expect("doctor json output");
     let config_status = &json["configStatus"];
+    assert_eq!(config_status["id"].as_str(), Some("config.load"));
     let status = config_status["status"]["status"].as_str().unwrap_or("");
     assert_eq!(status, "pass");
 
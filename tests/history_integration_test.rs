@@ -19,6 +19,7 @@ use tempfile::TempDir;
 use caut::core::models::{ProviderIdentity, RateWindow, UsageSnapshot};
 use caut::core::provider::Provider;
 use caut::storage::history::{HistoryStore, RetentionPolicy, StatsPeriod};
+use caut::storage::history_schema::HISTORY_MIGRATIONS;
 
 mod common;
 
@@ -143,7 +144,11 @@ fn test_migration_idempotence() {
         let count = store
             .count_rows("schema_migrations")
             .expect("count migrations");
-        assert_eq!(count, 2, "Should have exactly 2 migrations after run {i}");
+        assert_eq!(
+            count,
+            i64::try_from(HISTORY_MIGRATIONS.len()).unwrap(),
+            "Should have each migration exactly once after run {i}"
+        );
     }
 }
 
@@ -0,0 +1,379 @@
+//! Golden-file tests for usage and cost renderers.
+//!
+//! Each renderer is exercised with representative payloads (full data,
+//! partial failure, empty) using fixed timestamps so output is stable.
+//! Update with `cargo insta test --review` after intentional changes.
+
+use caut::core::models::{
+    CostDailyEntry, CostPayload, CostTotals, CreditsSnapshot, ProviderIdentity, ProviderPayload,
+    RateWindow, RobotOutput, StatusIndicator, StatusPayload, UsageSnapshot,
+};
+use caut::render::{human, robot};
+use caut::test_utils::strip_ansi_codes;
+use chrono::{DateTime, TimeZone, Utc};
+
+// =============================================================================
+// Fixtures
+// =============================================================================
+
+fn fixed_time(hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 15, hour, 0, 0).unwrap()
+}
+
+fn window(used_percent: f64, window_minutes: i32, reset: &str) -> RateWindow {
+    RateWindow {
+        used_percent,
+        window_minutes: Some(window_minutes),
+        resets_at: None,
+        reset_description: Some(reset.to_string()),
+    }
+}
+
+fn payload(provider: &str, source: &str, usage: UsageSnapshot) -> ProviderPayload {
+    ProviderPayload {
+        provider: provider.to_string(),
+        account: None,
+        version: None,
+        source: source.to_string(),
+        status: None,
+        usage,
+        credits: None,
+        antigravity_plan_info: None,
+        openai_dashboard: None,
+        copilot_premium: None,
+        task_spend: None,
+        local_model: None,
+        divergences: Vec::new(),
+        auth_warning: None,
+    }
+}
+
+/// Every field populated.
+fn full_payload() -> ProviderPayload {
+    let mut primary = window(28.0, 300, "in 3h");
+    primary.resets_at = Some(fixed_time(15));
+
+    let usage = UsageSnapshot {
+        primary: Some(primary),
+        secondary: Some(window(61.0, 10_080, "Fri 09:00")),
+        tertiary: Some(window(92.0, 10_080, "Fri 09:00")),
+        updated_at: fixed_time(12),
+        identity: Some(ProviderIdentity {
+            account_email: Some("dev@example.com".to_string()),
+            account_organization: None,
+            login_method: None,
+        }),
+    };
+
+    let mut payload = payload("claude", "oauth", usage);
+    payload.account = Some("dev@example.com".to_string());
+    payload.version = Some("1.2.3".to_string());
+    payload.status = Some(StatusPayload {
+        indicator: StatusIndicator::None,
+        description: Some("All systems normal".to_string()),
+        updated_at: None,
+        url: "https://status.anthropic.com".to_string(),
+    });
+    payload.credits = Some(CreditsSnapshot {
+        remaining: 250.0,
+        events: Vec::new(),
+        updated_at: fixed_time(12),
+    });
+    payload.auth_warning = Some("Token expires soon".to_string());
+    payload
+}
+
+/// Only the primary window is known.
+fn partial_payload() -> ProviderPayload {
+    let usage = UsageSnapshot {
+        primary: Some(window(90.0, 300, "in 42m")),
+        secondary: None,
+        tertiary: None,
+        updated_at: fixed_time(12),
+        identity: None,
+    };
+    payload("codex", "cli", usage)
+}
+
+/// No usage data at all.
+fn empty_payload() -> ProviderPayload {
+    let usage = UsageSnapshot {
+        primary: None,
+        secondary: None,
+        tertiary: None,
+        updated_at: fixed_time(12),
+        identity: None,
+    };
+    payload("gemini", "web", usage)
+}
+
+fn full_cost() -> CostPayload {
+    CostPayload {
+        provider: "claude".to_string(),
+        source: "local".to_string(),
+        updated_at: fixed_time(12),
+        session_tokens: Some(1234),
+        session_cost_usd: Some(3.25),
+        last_30_days_tokens: Some(98_765),
+        last_30_days_cost_usd: Some(41.5),
+        daily: vec![CostDailyEntry {
+            date: "2026-01-15".to_string(),
+            input_tokens: None,
+            output_tokens: None,
+            cache_read_tokens: None,
+            cache_creation_tokens: None,
+            total_tokens: Some(1234),
+            total_cost: Some(3.25),
+            models_used: None,
+        }],
+        totals: Some(CostTotals {
+            input_tokens: None,
+            output_tokens: None,
+            cache_read_tokens: None,
+            cache_creation_tokens: None,
+            total_tokens: Some(98_765),
+            total_cost: Some(41.5),
+        }),
+    }
+}
+
+fn empty_cost() -> CostPayload {
+    CostPayload {
+        provider: "codex".to_string(),
+        source: "local".to_string(),
+        updated_at: fixed_time(12),
+        session_tokens: None,
+        session_cost_usd: None,
+        last_30_days_tokens: None,
+        last_30_days_cost_usd: None,
+        daily: Vec::new(),
+        totals: None,
+    }
+}
+
+/// Reduce human output to its text content.
+///
+/// Box-drawing and block glyphs (panel borders, progress bars) are dropped and
+/// whitespace collapsed, so snapshots track what is said rather than exact
+/// terminal geometry.
+fn normalize_human(output: &str) -> String {
+    strip_ansi_codes(output)
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| {
+                    if ('\u{2500}'..='\u{259F}').contains(&c) {
+                        ' '
+                    } else {
+                        c
+                    }
+                })
+                .collect::<String>()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn pretty_json<T: serde::Serialize>(mut output: RobotOutput<T>) -> String {
+    output.generated_at = fixed_time(12);
+    robot::render_json_pretty(&output).unwrap()
+}
+
+// =============================================================================
+// Usage Snapshots
+// =============================================================================
+
+#[test]
+fn usage_human_snapshot() {
+    // Human output turns `resets_at` into a countdown from the current local
+    // time, so leave it out and render the provider's own description.
+    let mut full = full_payload();
+    if let Some(primary) = full.usage.primary.as_mut() {
+        primary.resets_at = None;
+    }
+    let output = human::render_usage(&[full, partial_payload(), empty_payload()], true).unwrap();
+
+    insta::assert_snapshot!(normalize_human(&output), @r"
+    claude 1.2.3 (oauth)
+    Session: 72% in 3h
+    Weekly: 39% Fri 09:00
+    Opus/Sonnet: 8% Fri 09:00
+    Credits: 250.0 left
+    Account: dev@example.com
+    Status: Operational – All systems normal
+    ⚠ Token expires soon
+    codex (cli)
+    Session: 10% in 42m
+    gemini (web)
+    No usage data available
+    ");
+}
+
+#[test]
+fn usage_markdown_snapshot() {
+    let output =
+        robot::render_markdown_usage(&[full_payload(), partial_payload(), empty_payload()])
+            .unwrap();
+
+    insta::assert_snapshot!(output, @r"
+    ## claude (oauth)
+    - session_left: 72%
+    - resets_session: 2026-01-15 15:00:00 UTC
+    - weekly_left: 39%
+    - credits_left: 250.0
+    - status: None
+
+    ## codex (cli)
+    - session_left: 10%
+
+    ## gemini (web)
+    ");
+}
+
+#[test]
+fn usage_json_partial_failure_snapshot() {
+    let output = RobotOutput::usage(
+        vec![partial_payload()],
+        vec!["claude: No available fetch strategy".to_string()],
+    );
+
+    insta::assert_snapshot!(pretty_json(output), @r#"
+    {
+      "schemaVersion": "caut.v1",
+      "generatedAt": "2026-01-15T12:00:00Z",
+      "command": "usage",
+      "data": [
+        {
+          "provider": "codex",
+          "source": "cli",
+          "usage": {
+            "primary": {
+              "usedPercent": 90.0,
+              "windowMinutes": 300,
+              "resetDescription": "in 42m"
+            },
+            "secondary": null,
+            "updatedAt": "2026-01-15T12:00:00Z"
+          }
+        }
+      ],
+      "errors": [
+        "claude: No available fetch strategy"
+      ],
+      "meta": {
+        "format": "json",
+        "flags": [],
+        "runtime": "cli"
+      }
+    }
+    "#);
+}
+
+#[test]
+fn usage_json_empty_snapshot() {
+    let output = RobotOutput::usage(Vec::new(), Vec::new());
+
+    insta::assert_snapshot!(pretty_json(output), @r#"
+    {
+      "schemaVersion": "caut.v1",
+      "generatedAt": "2026-01-15T12:00:00Z",
+      "command": "usage",
+      "data": [],
+      "errors": [],
+      "meta": {
+        "format": "json",
+        "flags": [],
+        "runtime": "cli"
+      }
+    }
+    "#);
+}
+
+// =============================================================================
+// Cost Snapshots
+// =============================================================================
+
+#[test]
+fn cost_human_snapshot() {
+    let output = human::render_cost(&[full_cost(), empty_cost()], true).unwrap();
+
+    insta::assert_snapshot!(normalize_human(&output), @r"
+    claude Cost (local)
+    Today: $3.25 · 1,234 messages
+    Last 30 days: $41.50 · 98,765 messages
+    codex Cost (local)
+    Today: No activity
+    Last 30 days: No activity
+    ");
+}
+
+#[test]
+fn cost_markdown_snapshot() {
+    let output = robot::render_markdown_cost(&[full_cost(), empty_cost()]).unwrap();
+
+    insta::assert_snapshot!(output, @r"
+    ## claude Cost (local)
+
+    ### Summary
+    - today_cost_usd: 3.25
+    - today_messages: 1234
+    - last_30d_cost_usd: 41.50
+    - last_30d_messages: 98765
+
+    ### Daily (last 7 days)
+    | date | messages | cost |
+    |------|----------|------|
+    | 2026-01-15 | 1234 | $3.25 |
+
+    ## codex Cost (local)
+
+    ### Summary
+    - today: no_activity
+    - last_30d: no_activity
+    ");
+}
+
+#[test]
+fn cost_json_snapshot() {
+    let output = RobotOutput::cost(vec![full_cost()], Vec::new());
+
+    insta::assert_snapshot!(pretty_json(output), @r#"
+    {
+      "schemaVersion": "caut.v1",
+      "generatedAt": "2026-01-15T12:00:00Z",
+      "command": "cost",
+      "data": [
+        {
+          "provider": "claude",
+          "source": "local",
+          "updatedAt": "2026-01-15T12:00:00Z",
+          "sessionTokens": 1234,
+          "sessionCostUsd": 3.25,
+          "last30DaysTokens": 98765,
+          "last30DaysCostUsd": 41.5,
+          "daily": [
+            {
+              "date": "2026-01-15",
+              "totalTokens": 1234,
+              "totalCost": 3.25
+            }
+          ],
+          "totals": {
+            "totalTokens": 98765,
+            "totalCost": 41.5
+          }
+        }
+      ],
+      "errors": [],
+      "meta": {
+        "format": "json",
+        "flags": [],
+        "runtime": "cli"
+      }
+    }
+    "#);
+}